@@ -305,12 +305,116 @@ pub fn generate_function(function: &Function) -> TokenStream {
 pub fn generate_extern(_link: &String, api: &Vec<Function>) -> TokenStream {
     let functions = api.iter().map(generate_function);
     quote! {
+        #[cfg(not(any(feature = "dynamic", feature = "stub")))]
         extern "C" {
             #(#functions)*
         }
     }
 }
 
+pub fn generate_function_pointer(function: &Function) -> TokenStream {
+    let name = format_ident!("{}", function.name);
+    let arguments = function.arguments.iter().map(generate_argument);
+    let return_type = map_c_type(&function.return_type);
+    quote! {
+        #name: unsafe extern "C" fn(#(#arguments),*) -> #return_type
+    }
+}
+
+pub fn generate_function_symbol(link: &String, function: &Function) -> TokenStream {
+    let name = format_ident!("{}", function.name);
+    let library = format_ident!("{}", link);
+    let symbol = Literal::byte_string(format!("{}\0", function.name).as_bytes());
+    quote! {
+        #name: *#library.get(#symbol)?
+    }
+}
+
+pub fn generate_function_wrapper(function: &Function) -> TokenStream {
+    let name = format_ident!("{}", function.name);
+    let arguments = function.arguments.iter().map(generate_argument);
+    let inputs = function
+        .arguments
+        .iter()
+        .map(|argument| format_rust_ident(&argument.name));
+    let return_type = map_c_type(&function.return_type);
+    let not_loaded = match &function.return_type {
+        Type::UserType(name) if name == "FMOD_RESULT" => quote! { LIBRARY_NOT_LOADED },
+        _ => quote! { Default::default() },
+    };
+    quote! {
+        #[cfg(feature = "dynamic")]
+        #[inline]
+        pub unsafe fn #name(#(#arguments),*) -> #return_type {
+            match library() {
+                Ok(library) => (library.#name)(#(#inputs),*),
+                Err(_) => #not_loaded,
+            }
+        }
+    }
+}
+
+pub fn generate_library(api: &Api) -> TokenStream {
+    let mut pointers = vec![];
+    let mut symbols = vec![];
+    let mut wrappers = vec![];
+    let mut fields = vec![];
+    let mut handles = vec![];
+    let mut loads = vec![];
+    // core library goes first, studio library depends on it
+    for (link, functions) in api.functions.iter().rev() {
+        let library = format_ident!("{}", link);
+        let handle = format_ident!("_{}", link);
+        loads.push(quote! {
+            let #library = libloading::Library::new(path(#link))?;
+        });
+        fields.push(quote! { #handle: libloading::Library });
+        handles.push(quote! { #handle: #library });
+        for function in functions {
+            pointers.push(generate_function_pointer(function));
+            symbols.push(generate_function_symbol(link, function));
+            wrappers.push(generate_function_wrapper(function));
+        }
+    }
+    quote! {
+        #[cfg(feature = "dynamic")]
+        pub struct Library {
+            #(#fields),*,
+            #(#pointers),*
+        }
+
+        #[cfg(feature = "dynamic")]
+        impl Library {
+            pub unsafe fn load<F>(path: F) -> Result<Self, libloading::Error>
+            where
+                F: Fn(&str) -> std::path::PathBuf,
+            {
+                #(#loads)*
+                Ok(Self {
+                    #(#symbols),*,
+                    #(#handles),*
+                })
+            }
+        }
+
+        #[cfg(feature = "dynamic")]
+        pub static LIBRARY: std::sync::OnceLock<Library> = std::sync::OnceLock::new();
+
+        #[cfg(feature = "dynamic")]
+        pub const LIBRARY_NOT_LOADED: FMOD_RESULT = -1;
+
+        #[cfg(feature = "dynamic")]
+        #[inline]
+        pub fn library() -> Result<&'static Library, crate::Error> {
+            LIBRARY
+                .get()
+                .ok_or_else(|| crate::Error::Library("not loaded".to_string()))
+        }
+
+        #(#wrappers)*
+    }
+}
+
 pub fn generate_preset(structure: &Structure, preset: &Preset) -> Result<TokenStream, Error> {
     let name = format_ident!("{}", preset.name);
     let mut fields: Vec<TokenStream> = vec![];
@@ -369,6 +473,7 @@ pub fn generate_ffi_code(api: &Api) -> Result<TokenStream, Error> {
     for (link, functions) in &api.functions {
         libraries.push(generate_extern(link, functions));
     }
+    libraries.push(generate_library(api));
 
     let mut presets = vec![];
     if let Some(structure) = api
@@ -385,6 +490,7 @@ pub fn generate_ffi_code(api: &Api) -> Result<TokenStream, Error> {
         #![allow(non_camel_case_types)]
         #![allow(non_snake_case)]
         #![allow(unused_parens)]
+        #![allow(clippy::missing_safety_doc, clippy::too_many_arguments, clippy::type_complexity)]
         use std::os::raw::{c_char, c_float, c_int, c_longlong, c_short, c_uchar, c_uint, c_ulonglong, c_ushort, c_void};
 
         #(#opaque_types)*
@@ -396,6 +502,9 @@ pub fn generate_ffi_code(api: &Api) -> Result<TokenStream, Error> {
         #(#presets)*
         #(#callbacks)*
         #(#libraries)*
+
+        #[cfg(feature = "stub")]
+        pub use crate::stub::api::*;
     })
 }
 
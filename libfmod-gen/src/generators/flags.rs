@@ -40,7 +40,14 @@ fn get_shared_prefix(flags: &Vec<Flag>) -> String {
     words.join("_")
 }
 
-pub fn generate_flags(flags: &Flags) -> (String, String) {
+fn format_doc(api: &Api, key: &str, indent: &str) -> String {
+    match api.doc_literal(key) {
+        None => String::new(),
+        Some(text) => format!("{indent}#[doc = {text}]\n"),
+    }
+}
+
+pub fn generate_flags(flags: &Flags, api: &Api) -> (String, String) {
     let prefix = get_shared_prefix(&flags.flags);
     let name = format_flags_struct_ident(&prefix);
 
@@ -68,14 +75,16 @@ pub fn generate_flags(flags: &Flags) -> (String, String) {
             name = "FMOD_3D".to_string();
         }
         let ffi = &flag.name;
-        variants.push(format!("        const {name} = ffi::{ffi};"));
+        let doc = format_doc(api, ffi, "        ");
+        variants.push(format!("{doc}        const {name} = ffi::{ffi};"));
     }
 
+    let doc = format_doc(api, &flags.name, "    ");
     let flags = &flags.name;
     let variants = variants.join("\n");
     let definition = format!(
         r#"
-    pub struct {name}: ffi::{flags} {{
+{doc}    pub struct {name}: ffi::{flags} {{
 {variants}
     }}
     "#
@@ -106,7 +115,7 @@ bitflags! {
     let mut into_traits = String::new();
 
     for flag in api.flags.iter() {
-        let (code, into) = generate_flags(flag);
+        let (code, into) = generate_flags(flag, api);
         source += &code;
         into_traits += &into;
     }
@@ -120,3 +129,45 @@ bitflags! {
 pub fn generate_to_file(api: &Api) -> Result<String, Error> {
     generate_flags_code(api)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Type::FundamentalType;
+
+    #[test]
+    fn test_flags_docs_are_emitted_as_doc_attributes() {
+        let mut api = Api::default();
+        api.docs.insert(
+            "FMOD_TIMEUNIT".to_string(),
+            "Time types used for position or length.".to_string(),
+        );
+        api.docs.insert(
+            "FMOD_TIMEUNIT_MS".to_string(),
+            "Milliseconds, \"ms\" for short.".to_string(),
+        );
+        let flags = Flags {
+            flags_type: FundamentalType("unsigned int".to_string()),
+            name: "FMOD_TIMEUNIT".to_string(),
+            flags: vec![
+                Flag {
+                    name: "FMOD_TIMEUNIT_MS".to_string(),
+                    value: "0x00000001".to_string(),
+                },
+                Flag {
+                    name: "FMOD_TIMEUNIT_PCM".to_string(),
+                    value: "0x00000002".to_string(),
+                },
+            ],
+        };
+        let (definition, _) = generate_flags(&flags, &api);
+        assert!(definition.contains(
+            "    #[doc = \" Time types used for position or length.\"]\n    pub struct TimeUnit"
+        ));
+        assert!(definition.contains(
+            "        #[doc = \" Milliseconds, \\\"ms\\\" for short.\"]\n        const MS = ffi::FMOD_TIMEUNIT_MS;"
+        ));
+        assert!(definition.contains("\n        const PCM = ffi::FMOD_TIMEUNIT_PCM;"));
+        assert!(!definition.contains("///"));
+    }
+}
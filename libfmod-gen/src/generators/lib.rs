@@ -3,10 +3,11 @@ use std::ops::AddAssign;
 use std::str::FromStr;
 
 use convert_case::{Case, Casing};
-use quote::__private::{Ident, TokenStream};
+use quote::__private::{Ident, Literal, TokenStream};
 
 use crate::ffi;
 use crate::ffi::describe_pointer;
+use crate::generators::traits;
 use crate::models::Type::{FundamentalType, UserType};
 use crate::models::{
     Api, Argument, Enumeration, Error, Field, Function, Modifier, Pointer, Structure, Type,
@@ -156,8 +157,9 @@ pub fn format_rust_type(
     }
 }
 
-pub fn generate_enumeration(enumeration: &Enumeration) -> TokenStream {
+pub fn generate_enumeration(enumeration: &Enumeration, api: &Api) -> TokenStream {
     let name = format_struct_ident(&enumeration.name);
+    let doc = api.generate_doc(&enumeration.name);
 
     let mut variants = vec![];
     let mut enumerator_arms = vec![];
@@ -168,16 +170,18 @@ pub fn generate_enumeration(enumeration: &Enumeration) -> TokenStream {
             continue;
         }
         let variant = format_variant(&enumeration.name, &enumerator.name);
+        let variant_doc = api.generate_doc(&enumerator.name);
         let enumerator = format_ident!("{}", enumerator.name);
         enumerator_arms.push(quote! {#name::#variant => ffi::#enumerator});
         variant_arms.push(quote! {ffi::#enumerator => Ok(#name::#variant)});
-        variants.push(variant);
+        variants.push(quote! {#variant_doc #variant});
     }
 
     let enumeration_name = &enumeration.name;
     let enumeration = format_ident!("{}", enumeration_name);
 
     quote! {
+        #doc
         #[derive(Debug, Clone, Copy, PartialEq)]
         pub enum #name {
             #(#variants),*
@@ -203,8 +207,10 @@ pub fn generate_enumeration(enumeration: &Enumeration) -> TokenStream {
 }
 
 pub fn generate_field(structure: &Structure, field: &Field, api: &Api) -> TokenStream {
+    let doc = api.generate_doc(&format!("{}+{}", structure.name, field.name));
     match api.patch_rust_struct_field_definition(&structure.name[..], &field.name[..]) {
-        Some(definition) => return definition,
+        Some(definition) if definition.is_empty() => return definition,
+        Some(definition) => return quote! { #doc #definition },
         _ => {}
     };
 
@@ -231,6 +237,7 @@ pub fn generate_field(structure: &Structure, field: &Field, api: &Api) -> TokenS
         &api,
     );
     quote! {
+        #doc
         pub #name: #field_type
     }
 }
@@ -414,11 +421,13 @@ pub fn generate_structure(structure: &Structure, api: &Api) -> TokenStream {
         });
         derive = quote! { Clone };
     }
+    let doc = api.generate_doc(&structure.name);
     let presets = generate_presets(structure, api);
     let into = generate_structure_into(structure, api);
     let try_from = generate_structure_try_from(structure, api);
     let conversions = api.structure_patches.get(&structure.name);
     quote! {
+        #doc
         #[derive(#derive)]
         pub struct #name {
             #(#fields),*
@@ -480,7 +489,7 @@ fn map_optional(argument: &Argument, api: &Api) -> InArgument {
             },
             "*const:char" => InArgument {
                 param: quote! { #name: Option<String> },
-                input: quote! { #name.map(|value| CString::new(value).map(|value| value.as_ptr())).unwrap_or(Ok(null_mut()))? },
+                input: quote! { #name.map(CString::new).transpose()?.as_deref().map_or(null(), CStr::as_ptr) },
             },
             "*mut:void" => InArgument {
                 param: quote! { #name: Option<*mut c_void> },
@@ -781,11 +790,50 @@ impl AddAssign<OutArgument> for Signature {
     }
 }
 
+pub fn generate_method_doc(function: &Function, api: &Api) -> TokenStream {
+    let mut lines = vec![];
+    if let Some(summary) = api.docs.get(&function.name) {
+        lines.push(format!(" {}", summary));
+    }
+    let mut arguments = vec![];
+    let mut outputs = vec![];
+    for argument in &function.arguments {
+        let key = format!("{}+{}", function.name, argument.name);
+        if let Some(description) = api.docs.get(&key) {
+            let name = format_argument_ident(&argument.name);
+            let line = format!(" * `{}` - {}", name, description);
+            match api.get_modifier(&function.name, &argument.name) {
+                Modifier::Out => outputs.push(line),
+                _ => arguments.push(line),
+            }
+        }
+    }
+    if !arguments.is_empty() {
+        lines.push("".into());
+        lines.push(" # Arguments".into());
+        lines.push("".into());
+        lines.extend(arguments);
+    }
+    if !outputs.is_empty() {
+        lines.push("".into());
+        lines.push(" # Returns".into());
+        lines.push("".into());
+        lines.extend(outputs);
+    }
+    quote! {
+        #(#[doc = #lines])*
+    }
+}
+
 pub fn generate_method(owner: &str, function: &Function, api: &Api) -> TokenStream {
     let mut signature = Signature::new();
+    let doc = generate_method_doc(function, api);
 
     if let Some(overriding) = api.function_patches.get(&function.name) {
-        return overriding.clone();
+        return quote! {
+            #doc
+            #overriding
+        };
     }
 
     for argument in &function.arguments {
@@ -805,6 +853,7 @@ pub fn generate_method(owner: &str, function: &Function, api: &Api) -> TokenStre
     let function = format_ident!("{}", function_name);
 
     quote! {
+        #doc
         pub fn #method( #(#arguments),* ) -> Result<#returns, Error> {
             unsafe {
                 #(#out)*
@@ -826,6 +875,13 @@ pub fn generate_opaque_type(key: &String, methods: &Vec<&Function>, api: &Api) -
         .map(|method| generate_method(key, method, api))
         .collect();
 
+    let signatures = traits::parse_methods(quote! { #(#methods)* });
+    let api_trait = if signatures.is_empty() {
+        quote! {}
+    } else {
+        traits::generate_trait(&name, &signatures)
+    };
+
     quote! {
         #[derive(Debug, Clone, Copy)]
         pub struct #name {
@@ -847,6 +903,8 @@ pub fn generate_opaque_type(key: &String, methods: &Vec<&Function>, api: &Api) -
             }
             #(#methods)*
         }
+
+        #api_trait
     }
 }
 
@@ -919,6 +977,19 @@ impl Api {
         }
     }
 
+    pub fn generate_doc(&self, key: &str) -> TokenStream {
+        match self.doc_literal(key) {
+            None => quote! {},
+            Some(text) => quote! { #[doc = #text] },
+        }
+    }
+
+    pub fn doc_literal(&self, key: &str) -> Option<Literal> {
+        self.docs
+            .get(key)
+            .map(|text| Literal::string(&format!(" {}", text)))
+    }
+
     pub fn get_modifier(&self, function: &str, argument: &str) -> Modifier {
         let key = format!("{}+{}", function, argument);
         match self.modifiers.get(&key) {
@@ -937,7 +1008,7 @@ impl Type {
     }
 }
 
-pub fn generate_lib_code(api: &Api) -> Result<TokenStream, Error> {
+fn group_methods(api: &Api) -> BTreeMap<String, Vec<&Function>> {
     let functions: Vec<&Function> = api
         .functions
         .iter()
@@ -967,14 +1038,36 @@ pub fn generate_lib_code(api: &Api) -> Result<TokenStream, Error> {
             println!("Global function: {}", function.name);
         }
     }
+    types
+}
 
-    let types: Vec<TokenStream> = types
+fn generate_signatures(key: &str, methods: &[&Function], api: &Api) -> Vec<traits::Method> {
+    let methods: Vec<TokenStream> = methods
         .iter()
-        .map(|(key, methods)| generate_opaque_type(key, methods, api))
+        .map(|method| generate_method(key, method, api))
         .collect();
+    traits::parse_methods(quote! { #(#methods)* })
+}
+
+pub fn generate_lib_code(api: &Api) -> Result<TokenStream, Error> {
+    let groups = group_methods(api);
+    let mut types: Vec<TokenStream> = vec![];
+    for (key, methods) in &groups {
+        if key == "FMOD_CHANNELCONTROL" {
+            let channel = generate_signatures("FMOD_CHANNEL", &groups["FMOD_CHANNEL"], api);
+            let channel_group =
+                generate_signatures("FMOD_CHANNELGROUP", &groups["FMOD_CHANNELGROUP"], api);
+            types.push(traits::generate_channel_control(&channel, &channel_group));
+        } else {
+            types.push(generate_opaque_type(key, methods, api));
+        }
+    }
 
-    let enumerations: Vec<TokenStream> =
-        api.enumerations.iter().map(generate_enumeration).collect();
+    let enumerations: Vec<TokenStream> = api
+        .enumerations
+        .iter()
+        .map(|enumeration| generate_enumeration(enumeration, api))
+        .collect();
 
     let mut structures: Vec<TokenStream> = vec![];
     for structure in &api.structures {
@@ -995,6 +1088,12 @@ pub fn generate_lib_code(api: &Api) -> Result<TokenStream, Error> {
         mod flags;
         #[cfg(feature = "flags")]
         pub use flags::*;
+        #[cfg(feature = "stub")]
+        pub mod stub;
+        #[cfg(feature = "mock")]
+        pub mod mock;
+        #[cfg(all(feature = "dynamic", feature = "stub"))]
+        compile_error!("features `dynamic` and `stub` are mutually exclusive");
 
         #[derive(Debug)]
         pub enum Error {
@@ -1009,7 +1108,13 @@ pub fn generate_lib_code(api: &Api) -> Result<TokenStream, Error> {
             },
             String(IntoStringError),
             StringNul(NulError),
-            NotDspFft
+            NotDspFft,
+            Library(String),
+            Io(std::io::Error),
+            Validation {
+                function: String,
+                message: String,
+            }
         }
 
         impl Display for Error {
@@ -1034,6 +1139,15 @@ pub fn generate_lib_code(api: &Api) -> Result<TokenStream, Error> {
                     Error::NotDspFft => {
                         write!(f, "trying get FFT from DSP which not FFT")
                     }
+                    Error::Library(message) => {
+                        write!(f, "unable to load FMOD library, {}", message)
+                    }
+                    Error::Io(error) => {
+                        write!(f, "I/O error, {}", error)
+                    }
+                    Error::Validation { function, message } => {
+                        write!(f, "{}: {}", function, message)
+                    }
                 }
             }
         }
@@ -1046,12 +1160,31 @@ pub fn generate_lib_code(api: &Api) -> Result<TokenStream, Error> {
             }
         }
 
+        impl From<std::io::Error> for Error {
+            fn from(error: std::io::Error) -> Self {
+                Error::Io(error)
+            }
+        }
+
         macro_rules! err_fmod {
             ($ function : expr , $ code : expr) => {
-                Error::Fmod {
+                match $code {
+                    #[cfg(feature = "dynamic")]
+                    ffi::LIBRARY_NOT_LOADED => Error::Library("not loaded".to_string()),
+                    code => Error::Fmod {
+                        function: $function.to_string(),
+                        code,
+                        message: errors::map_fmod_error(code).to_string(),
+                    },
+                }
+            };
+        }
+
+        macro_rules! err_validation {
+            ($ function : expr , $ ($ message : tt) +) => {
+                Error::Validation {
                     function: $function.to_string(),
-                    code: $code,
-                    message: errors::map_fmod_error($code).to_string(),
+                    message: format!($ ($ message) +),
                 }
             };
         }
@@ -1125,6 +1258,46 @@ pub fn generate_lib_code(api: &Api) -> Result<TokenStream, Error> {
             }
         }
 
+        mod mix_matrix;
+        pub use mix_matrix::*;
+        mod speaker_levels;
+        pub use speaker_levels::SpeakerLevels;
+        use speaker_levels::validate_input_levels;
+        mod scheduler;
+        pub use scheduler::Scheduler;
+        mod dsp_graph;
+        pub use dsp_graph::*;
+        mod dsp_chain;
+        pub use dsp_chain::*;
+        mod geometry_obj;
+        pub use geometry_obj::*;
+        mod occlusion;
+        pub use occlusion::*;
+        mod reverb_zones;
+        pub use reverb_zones::ReverbZones;
+        mod output_plugin;
+        pub use output_plugin::*;
+        mod codec_plugin;
+        pub use codec_plugin::*;
+        mod offline_renderer;
+        mod wav;
+        pub use offline_renderer::OfflineRenderer;
+        mod drivers;
+        use drivers::forget_followed_driver;
+        pub use drivers::{DriverInfo, RecordDriverInfo};
+        mod record_stream;
+        pub use record_stream::RecordStream;
+        mod meter;
+        pub use meter::{ChannelLevel, Meter, MeterSource};
+        mod loudness_meter;
+        pub use loudness_meter::{LoudnessInfo, LoudnessMeter};
+        mod fft_analyzer;
+        pub use fft_analyzer::{Band, BandScale, FftAnalyzer, FftSpectrum};
+        mod dsp_data;
+        pub use dsp_data::DspData;
+        mod dsp_parameters;
+        pub use dsp_parameters::{DspParameter, DspParameterKind, DspParameterValue};
+
         pub fn attr3d_array8(values: Vec<Attributes3d>) -> [Attributes3d; ffi::FMOD_MAX_LISTENERS as usize] {
             values.try_into().expect("slice with incorrect length")
         }
@@ -1141,6 +1314,23 @@ pub fn generate_lib_code(api: &Api) -> Result<TokenStream, Error> {
             value
         }
 
+        #[cfg(feature = "dynamic")]
+        pub fn load(directory: impl AsRef<std::path::Path>) -> Result<(), Error> {
+            if ffi::LIBRARY.get().is_some() {
+                return Ok(());
+            }
+            let directory = directory.as_ref();
+            let suffix = if cfg!(feature = "logging") { "L" } else { "" };
+            let library = unsafe {
+                ffi::Library::load(|name| {
+                    directory.join(libloading::library_filename(format!("{name}{suffix}")))
+                })
+            }
+            .map_err(|error| Error::Library(error.to_string()))?;
+            let _ = ffi::LIBRARY.set(library);
+            Ok(())
+        }
+
         #(#enumerations)*
         #(#structures)*
         #(#types)*
@@ -1150,3 +1340,18 @@ pub fn generate_lib_code(api: &Api) -> Result<TokenStream, Error> {
 pub fn generate(api: &Api) -> Result<String, Error> {
     generate_lib_code(api).map(|code| code.to_string())
 }
+
+pub fn generate_mock_code(api: &Api) -> Result<TokenStream, Error> {
+    let mut types = vec![];
+    for (key, methods) in group_methods(api) {
+        let signatures = generate_signatures(&key, &methods, api);
+        if !signatures.is_empty() {
+            types.push((format_struct_ident(&key), signatures));
+        }
+    }
+    Ok(traits::generate_mock_code(&types))
+}
+
+pub fn generate_mock(api: &Api) -> Result<String, Error> {
+    generate_mock_code(api).map(|code| code.to_string())
+}
@@ -2,3 +2,4 @@ pub mod ffi;
 pub mod flags;
 pub mod lib;
 pub mod errors;
+pub mod traits;
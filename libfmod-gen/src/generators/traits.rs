@@ -0,0 +1,492 @@
+use quote::__private::{Delimiter, Ident, Spacing, TokenStream, TokenTree};
+
+#[derive(Debug, Clone)]
+pub struct Method {
+    pub attributes: Vec<TokenStream>,
+    pub name: Ident,
+    pub signature: TokenStream,
+    pub arguments: Vec<Ident>,
+    pub types: Vec<TokenStream>,
+    pub returns: TokenStream,
+}
+
+fn split_arguments(arguments: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut segments = vec![];
+    let mut segment = vec![];
+    let mut depth = 0;
+    let mut arrow = false;
+    for token in arguments {
+        match &token {
+            TokenTree::Punct(punct) if punct.as_char() == '<' => depth += 1,
+            TokenTree::Punct(punct) if punct.as_char() == '>' && !arrow => depth -= 1,
+            TokenTree::Punct(punct) if punct.as_char() == ',' && depth == 0 => {
+                segments.push(segment);
+                segment = vec![];
+                continue;
+            }
+            _ => {}
+        }
+        arrow = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '-' && punct.spacing() == Spacing::Joint);
+        segment.push(token);
+    }
+    if !segment.is_empty() {
+        segments.push(segment);
+    }
+    segments
+}
+
+fn is_receiver(segment: &[TokenTree]) -> bool {
+    segment
+        .iter()
+        .any(|token| matches!(token, TokenTree::Ident(ident) if ident == "self"))
+}
+
+fn argument_name(segment: &[TokenTree]) -> Option<Ident> {
+    segment.iter().find_map(|token| match token {
+        TokenTree::Ident(ident) if ident != "mut" => Some(ident.clone()),
+        _ => None,
+    })
+}
+
+fn argument_type(segment: &[TokenTree]) -> TokenStream {
+    segment
+        .iter()
+        .skip_while(|token| !matches!(token, TokenTree::Punct(punct) if punct.as_char() == ':'))
+        .skip(1)
+        .cloned()
+        .collect()
+}
+
+/// Extracts signatures of methods with `&self` receiver from generated wrapper methods.
+pub fn parse_methods(methods: TokenStream) -> Vec<Method> {
+    let mut result = vec![];
+    let mut attributes = vec![];
+    let mut tokens = methods.into_iter();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == '#' => {
+                if let Some(TokenTree::Group(group)) = tokens.next() {
+                    attributes.push(quote! { #punct #group });
+                }
+            }
+            TokenTree::Ident(ident) if ident == "fn" => {
+                let mut signature = vec![TokenTree::Ident(ident)];
+                let mut name = None;
+                let mut arguments = None;
+                let mut returns = vec![];
+                for token in tokens.by_ref() {
+                    match &token {
+                        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => break,
+                        TokenTree::Ident(ident) if name.is_none() => name = Some(ident.clone()),
+                        TokenTree::Group(group)
+                            if group.delimiter() == Delimiter::Parenthesis
+                                && arguments.is_none() =>
+                        {
+                            arguments = Some(split_arguments(group.stream()))
+                        }
+                        _ if arguments.is_some() => returns.push(token.clone()),
+                        _ => {}
+                    }
+                    signature.push(token);
+                }
+                let attributes = std::mem::take(&mut attributes);
+                let (name, arguments) = match (name, arguments) {
+                    (Some(name), Some(arguments)) => (name, arguments),
+                    _ => continue,
+                };
+                if !arguments
+                    .first()
+                    .map(|first| is_receiver(first))
+                    .unwrap_or(false)
+                {
+                    continue;
+                }
+                // skips "->" of return type
+                let returns: TokenStream = returns.into_iter().skip(2).collect();
+                result.push(Method {
+                    attributes,
+                    name,
+                    signature: signature.into_iter().collect(),
+                    arguments: arguments[1..]
+                        .iter()
+                        .filter_map(|segment| argument_name(segment))
+                        .collect(),
+                    types: arguments[1..]
+                        .iter()
+                        .map(|segment| argument_type(segment))
+                        .collect(),
+                    returns,
+                });
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+pub fn format_trait_ident(name: &Ident) -> Ident {
+    format_ident!("{}Api", name)
+}
+
+pub fn format_mock_ident(name: &Ident) -> Ident {
+    format_ident!("Mock{}", name)
+}
+
+pub fn generate_trait(name: &Ident, methods: &[Method]) -> TokenStream {
+    let api = format_trait_ident(name);
+    let doc = format!(
+        " Methods of [`{}`] as trait, so code using them can be tested with mock.",
+        name
+    );
+    let declarations = methods.iter().map(|method| {
+        let attributes = &method.attributes;
+        let signature = &method.signature;
+        quote! {
+            #(#attributes)*
+            #signature;
+        }
+    });
+    let implementations = methods.iter().map(|method| {
+        let signature = &method.signature;
+        let method_name = &method.name;
+        let arguments = &method.arguments;
+        quote! {
+            #[inline]
+            #signature {
+                #name::#method_name(self, #(#arguments),*)
+            }
+        }
+    });
+    quote! {
+        #[doc = #doc]
+        #[allow(clippy::too_many_arguments)]
+        pub trait #api {
+            #(#declarations)*
+        }
+
+        impl #api for #name {
+            #(#implementations)*
+        }
+    }
+}
+
+fn is_unit_result(returns: &TokenStream) -> bool {
+    returns.to_string().replace(' ', "") == "Result<(),Error>"
+}
+
+/// Target type of `impl Into<T>` argument, such argument is converted before recording.
+fn into_target(type_: &TokenStream) -> Option<TokenStream> {
+    let tokens: Vec<TokenTree> = type_.clone().into_iter().collect();
+    match &tokens[..] {
+        [TokenTree::Ident(keyword), TokenTree::Ident(into), TokenTree::Punct(_), target @ .., TokenTree::Punct(_)]
+            if keyword == "impl" && into == "Into" =>
+        {
+            Some(target.iter().cloned().collect())
+        }
+        _ => None,
+    }
+}
+
+pub fn generate_mock(name: &Ident, methods: &[Method]) -> TokenStream {
+    let api = format_trait_ident(name);
+    let mock = format_mock_ident(name);
+    let doc = format!(" Recording mock of [`{}`].", api);
+    let implementations = methods.iter().map(|method| {
+        let signature = &method.signature;
+        let method_name = method.name.to_string();
+        let arguments = &method.arguments;
+        let conversions = arguments
+            .iter()
+            .zip(&method.types)
+            .filter_map(|(argument, type_)| {
+                let target = into_target(type_)?;
+                Some(quote! { let #argument: #target = #argument.into(); })
+            });
+        if is_unit_result(&method.returns) {
+            quote! {
+                #signature {
+                    #(#conversions)*
+                    self.mock.call_or(#method_name, &[#(&#arguments),*], Ok(()))
+                }
+            }
+        } else {
+            quote! {
+                #signature {
+                    #(#conversions)*
+                    self.mock.call(#method_name, &[#(&#arguments),*])
+                }
+            }
+        }
+    });
+    let scripting = methods.iter().map(|method| {
+        let method_name = method.name.to_string();
+        let function = format_ident!("{}_returns", method.name);
+        let returns = &method.returns;
+        let doc = format!(" Scripts value returned by next `{}` call.", method_name);
+        quote! {
+            #[doc = #doc]
+            pub fn #function(&self, value: #returns) -> &Self {
+                self.mock.returns(#method_name, value);
+                self
+            }
+        }
+    });
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Default)]
+        pub struct #mock {
+            mock: Mock,
+        }
+
+        impl #mock {
+            pub fn new() -> Self {
+                Self::default()
+            }
+            #(#scripting)*
+        }
+
+        impl Deref for #mock {
+            type Target = Mock;
+
+            fn deref(&self) -> &Self::Target {
+                &self.mock
+            }
+        }
+
+        impl #api for #mock {
+            #(#implementations)*
+        }
+    }
+}
+
+pub fn generate_mock_code(types: &[(Ident, Vec<Method>)]) -> TokenStream {
+    let mocks: Vec<TokenStream> = types
+        .iter()
+        .map(|(name, methods)| generate_mock(name, methods))
+        .collect();
+    quote! {
+        #![allow(unused_variables)]
+        use std::any::{type_name, Any};
+        use std::cell::RefCell;
+        use std::collections::{HashMap, VecDeque};
+        use std::fmt::{Debug, Display, Formatter};
+        use std::ops::Deref;
+        use crate::*;
+
+        #[doc = " Method call recorded by mock, arguments are formatted with `Debug`."]
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Call {
+            pub method: &'static str,
+            pub arguments: Vec<String>,
+        }
+
+        impl Display for Call {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}({})", self.method, self.arguments.join(", "))
+            }
+        }
+
+        #[doc = " Records method calls and returns scripted values."]
+        #[doc = ""]
+        #[doc = " Methods returning `Result<(), Error>` return `Ok(())` unless other value is scripted,"]
+        #[doc = " other methods panic if called without scripted value."]
+        #[derive(Default)]
+        pub struct Mock {
+            calls: RefCell<Vec<Call>>,
+            returns: RefCell<HashMap<&'static str, VecDeque<Box<dyn Any>>>>,
+        }
+
+        impl Debug for Mock {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct("Mock").field("calls", &self.calls.borrow()).finish()
+            }
+        }
+
+        impl Mock {
+            fn returns<T: 'static>(&self, method: &'static str, value: T) {
+                self.returns
+                    .borrow_mut()
+                    .entry(method)
+                    .or_default()
+                    .push_back(Box::new(value));
+            }
+
+            fn scripted<T: 'static>(&self, method: &'static str, arguments: &[&dyn Debug]) -> Option<T> {
+                self.calls.borrow_mut().push(Call {
+                    method,
+                    arguments: arguments.iter().map(|argument| format!("{:?}", argument)).collect(),
+                });
+                let value = self.returns.borrow_mut().get_mut(method)?.pop_front()?;
+                match value.downcast::<T>() {
+                    Ok(value) => Some(*value),
+                    Err(_) => panic!("{} returns {}, but other type was scripted", method, type_name::<T>()),
+                }
+            }
+
+            fn call<T: 'static>(&self, method: &'static str, arguments: &[&dyn Debug]) -> T {
+                match self.scripted(method, arguments) {
+                    Some(value) => value,
+                    None => panic!("{} is called, but return value is not scripted", method),
+                }
+            }
+
+            fn call_or<T: 'static>(&self, method: &'static str, arguments: &[&dyn Debug], default: T) -> T {
+                self.scripted(method, arguments).unwrap_or(default)
+            }
+
+            #[doc = " Returns called methods with their arguments in order of calls."]
+            pub fn calls(&self) -> Vec<Call> {
+                self.calls.borrow().clone()
+            }
+
+            #[doc = " Asserts that exactly given methods were called in given order."]
+            #[doc = ""]
+            #[doc = " Call is matched by method name, e.g. `\"set_volume\"`, or by name with arguments,"]
+            #[doc = " e.g. `\"set_volume(0.5)\"`."]
+            pub fn assert_calls(&self, expected: &[&str]) {
+                let calls: Vec<String> = self
+                    .calls()
+                    .iter()
+                    .enumerate()
+                    .map(|(index, call)| match expected.get(index) {
+                        Some(expected) if !expected.contains('(') => call.method.to_string(),
+                        _ => call.to_string(),
+                    })
+                    .collect();
+                assert_eq!(calls, expected, "unexpected call sequence");
+            }
+
+            #[doc = " Forgets recorded calls and scripted values."]
+            pub fn reset(&self) {
+                self.calls.borrow_mut().clear();
+                self.returns.borrow_mut().clear();
+            }
+        }
+
+        #(#mocks)*
+    }
+}
+
+fn shared_methods<'a>(channel: &'a [Method], channel_group: &[Method]) -> Vec<&'a Method> {
+    channel
+        .iter()
+        .filter(|method| {
+            let signature = method.signature.to_string();
+            channel_group
+                .iter()
+                .any(|other| other.signature.to_string() == signature)
+        })
+        .collect()
+}
+
+pub fn generate_channel_control(channel: &[Method], channel_group: &[Method]) -> TokenStream {
+    let methods = shared_methods(channel, channel_group);
+    let defaults = methods.iter().map(|method| {
+        let attributes = &method.attributes;
+        let signature = &method.signature;
+        let method_name = &method.name;
+        let arguments = &method.arguments;
+        quote! {
+            #(#attributes)*
+            #signature {
+                match self.downcast()? {
+                    ChannelControlHandle::Channel(channel) => channel.#method_name(#(#arguments),*),
+                    ChannelControlHandle::ChannelGroup(group) => group.#method_name(#(#arguments),*),
+                }
+            }
+        }
+    });
+    let implementations = ["Channel", "ChannelGroup"].iter().map(|name| {
+        let name = format_ident!("{}", name);
+        quote! {
+            impl ChannelControl for #name {
+                #[inline]
+                fn as_channel_control(&self) -> *mut ffi::FMOD_CHANNELCONTROL {
+                    self.pointer as *mut ffi::FMOD_CHANNELCONTROL
+                }
+                #[inline]
+                fn control_type(&self) -> ChannelControlType {
+                    ChannelControlType::#name
+                }
+            }
+        }
+    });
+    quote! {
+        #[doc = " Methods shared by [`Channel`] and [`ChannelGroup`], so mixing code can be written once for both."]
+        #[doc = ""]
+        #[doc = " Implementors only provide `FMOD_CHANNELCONTROL` pointer and its type, every method"]
+        #[doc = " downcasts the pointer and calls the same method of channel or channel group."]
+        #[allow(clippy::too_many_arguments)]
+        pub trait ChannelControl {
+            #[doc = " Pointer to underlying object as `FMOD_CHANNELCONTROL`, the same one FMOD passes to callbacks."]
+            fn as_channel_control(&self) -> *mut ffi::FMOD_CHANNELCONTROL;
+            fn control_type(&self) -> ChannelControlType;
+            #[doc = " Channel or channel group behind `FMOD_CHANNELCONTROL` pointer."]
+            #[inline]
+            fn downcast(&self) -> Result<ChannelControlHandle, Error> {
+                ChannelControlHandle::from_raw(self.as_channel_control(), self.control_type())
+            }
+            #(#defaults)*
+        }
+
+        #(#implementations)*
+
+        #[doc = " Channel or channel group behind `FMOD_CHANNELCONTROL` pointer."]
+        #[derive(Debug, Clone, Copy)]
+        pub enum ChannelControlHandle {
+            Channel(Channel),
+            ChannelGroup(ChannelGroup),
+        }
+
+        impl ChannelControlHandle {
+            #[doc = " Downcasts pointer received from FMOD, e.g. in channel control callback."]
+            pub fn from_raw(
+                pointer: *mut ffi::FMOD_CHANNELCONTROL,
+                control_type: ChannelControlType,
+            ) -> Result<ChannelControlHandle, Error> {
+                match control_type {
+                    ChannelControlType::Channel => Ok(ChannelControlHandle::Channel(Channel::from(
+                        pointer as *mut ffi::FMOD_CHANNEL,
+                    ))),
+                    ChannelControlType::ChannelGroup => Ok(ChannelControlHandle::ChannelGroup(
+                        ChannelGroup::from(pointer as *mut ffi::FMOD_CHANNELGROUP),
+                    )),
+                    ChannelControlType::Max => {
+                        Err(err_enum!("FMOD_CHANNELCONTROL_TYPE", ffi::FMOD_CHANNELCONTROL_MAX))
+                    }
+                }
+            }
+            pub fn as_channel(&self) -> Option<Channel> {
+                match self {
+                    ChannelControlHandle::Channel(channel) => Some(*channel),
+                    ChannelControlHandle::ChannelGroup(_) => None,
+                }
+            }
+            pub fn as_channel_group(&self) -> Option<ChannelGroup> {
+                match self {
+                    ChannelControlHandle::Channel(_) => None,
+                    ChannelControlHandle::ChannelGroup(group) => Some(*group),
+                }
+            }
+        }
+
+        impl ChannelControl for ChannelControlHandle {
+            fn as_channel_control(&self) -> *mut ffi::FMOD_CHANNELCONTROL {
+                match self {
+                    ChannelControlHandle::Channel(channel) => channel.as_channel_control(),
+                    ChannelControlHandle::ChannelGroup(group) => group.as_channel_control(),
+                }
+            }
+            fn control_type(&self) -> ChannelControlType {
+                match self {
+                    ChannelControlHandle::Channel(_) => ChannelControlType::Channel,
+                    ChannelControlHandle::ChannelGroup(_) => ChannelControlType::ChannelGroup,
+                }
+            }
+            #[inline]
+            fn downcast(&self) -> Result<ChannelControlHandle, Error> {
+                Ok(*self)
+            }
+        }
+    }
+}
@@ -99,7 +99,7 @@ fn generate_lib_fmod(source: &str, destination: &str) -> Result<(), Error> {
     let header = fmod_errors::parse(&data)?;
     api.errors = header.mapping.clone();
 
-    api.modifiers = fmod_docs::parse_parameter_modifiers(&[
+    let manual = [
         source.join("doc/FMOD API User Manual/core-api-system.html"),
         source.join("doc/FMOD API User Manual/core-api-soundgroup.html"),
         source.join("doc/FMOD API User Manual/core-api-sound.html"),
@@ -111,6 +111,7 @@ fn generate_lib_fmod(source: &str, destination: &str) -> Result<(), Error> {
         source.join("doc/FMOD API User Manual/core-api-channelcontrol.html"),
         source.join("doc/FMOD API User Manual/core-api-channel.html"),
         source.join("doc/FMOD API User Manual/core-api-common.html"),
+        source.join("doc/FMOD API User Manual/core-api-common-dsp-effects.html"),
         source.join("doc/FMOD API User Manual/plugin-api-codec.html"),
         source.join("doc/FMOD API User Manual/plugin-api-dsp.html"),
         source.join("doc/FMOD API User Manual/plugin-api-output.html"),
@@ -122,7 +123,9 @@ fn generate_lib_fmod(source: &str, destination: &str) -> Result<(), Error> {
         source.join("doc/FMOD API User Manual/studio-api-eventinstance.html"),
         source.join("doc/FMOD API User Manual/studio-api-system.html"),
         source.join("doc/FMOD API User Manual/studio-api-vca.html"),
-    ])?;
+    ];
+    api.modifiers = fmod_docs::parse_parameter_modifiers(&manual)?;
+    api.docs = fmod_docs::parse_docs(&manual)?;
 
     println!("FMOD API");
     println!("Opaque Types: {}", api.opaque_types.len());
@@ -163,6 +166,7 @@ fn generate_lib_fmod(source: &str, destination: &str) -> Result<(), Error> {
             .count()
     );
     println!("Parameter Modifiers: {}", api.modifiers.len());
+    println!("Docs: {}", api.docs.len());
     println!("Errors: {}", api.errors.errors.len());
 
     api.patch_all();
@@ -177,6 +181,8 @@ fn generate_lib_fmod(source: &str, destination: &str) -> Result<(), Error> {
     fs::write(destination.join("src/ffi.rs"), code)?;
     let code = lib::generate(&api)?;
     fs::write(destination.join("src/lib.rs"), code)?;
+    let code = lib::generate_mock(&api)?;
+    fs::write(destination.join("src/mock.rs"), code)?;
     let code = flags::generate_to_file(&api)?;
     fs::write(destination.join("src/flags.rs"), code)?;
     let code = errors::generate_to_file(&api)?;
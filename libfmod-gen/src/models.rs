@@ -163,6 +163,7 @@ pub struct Api {
     pub presets: Vec<Preset>,
     pub errors: ErrorStringMapping,
     pub modifiers: HashMap<String, Modifier>,
+    pub docs: HashMap<String, String>,
     pub structure_patches: HashMap<String, TokenStream>,
     pub structure_derives: HashMap<String, TokenStream>,
     pub function_patches: HashMap<String, TokenStream>,
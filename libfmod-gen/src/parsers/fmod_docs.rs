@@ -54,3 +54,167 @@ pub fn parse_parameter_modifiers(paths: &[PathBuf]) -> Result<HashMap<String, Mo
     }
     Ok(output)
 }
+
+fn format_text(html: &str) -> String {
+    lazy_static! {
+        static ref TAG: Regex = Regex::new("<[^>]+>").unwrap();
+        static ref SPACE: Regex = Regex::new("\\s+").unwrap();
+    }
+    let text = TAG.replace_all(html, "");
+    let text = text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&");
+    SPACE.replace_all(text.trim(), " ").to_string()
+}
+
+pub fn parse_docs_fragment(content: &str) -> Result<HashMap<String, String>, Error> {
+    lazy_static! {
+        static ref SECTION: Regex =
+            Regex::new("<h2 api=\"(\\w+)\" id=\"[^\"]*\"><a href=\"[^\"]*\">([^<]+)</a></h2>")
+                .unwrap();
+        static ref FUNCTION: Regex = Regex::new("<span class=\"nf\">(\\w+)</span>").unwrap();
+        static ref SUMMARY: Regex = Regex::new("^<p>(.+)</p>$").unwrap();
+        static ref TERM: Regex = Regex::new("^<dt(?: id=\"[^\"]*\")?>(\\w+)").unwrap();
+    }
+    let mut docs = HashMap::new();
+
+    // <h2 api="struct" id="fmod_vector"><a href="#fmod_vector">FMOD_VECTOR</a></h2>
+    // <p>Structure describing a point, vector or direction.</p>
+    // ...
+    // <dt>x</dt>
+    // <dd>X coordinate in 3D space.</dd>
+
+    let mut kind = String::new();
+    let mut owner = String::new();
+    let mut functions: Vec<String> = vec![];
+    let mut summary: Option<String> = None;
+    let mut term: Option<String> = None;
+    let mut description: Option<String> = None;
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(captures) = SECTION.captures(line) {
+            kind = captures.get(1).unwrap().as_str().to_string();
+            owner = captures.get(2).unwrap().as_str().to_string();
+            functions = vec![];
+            summary = None;
+            term = None;
+            continue;
+        }
+        if kind.is_empty() {
+            continue;
+        }
+        if let Some(text) = description.as_mut() {
+            text.push(' ');
+            text.push_str(line);
+        } else if line.starts_with("<dd>") {
+            description = Some(line.to_string());
+        } else if let Some(captures) = TERM.captures(line) {
+            term = Some(captures.get(1).unwrap().as_str().to_string());
+            continue;
+        } else if summary.is_none() {
+            if let Some(captures) = SUMMARY.captures(line) {
+                let text = format_text(captures.get(1).unwrap().as_str());
+                match &kind[..] {
+                    "function" => {}
+                    _ => {
+                        docs.insert(owner.clone(), text.clone());
+                    }
+                }
+                summary = Some(text);
+            }
+        }
+        if kind == "function" {
+            for captures in FUNCTION.captures_iter(line) {
+                let function = captures.get(1).unwrap().as_str().to_string();
+                if let Some(summary) = &summary {
+                    docs.insert(function.clone(), summary.clone());
+                }
+                functions.push(function);
+            }
+        }
+        if description.is_some() && line.ends_with("</dd>") {
+            let text = format_text(&description.take().unwrap());
+            if let Some(term) = term.take() {
+                match &kind[..] {
+                    "function" => {
+                        for function in &functions {
+                            docs.insert(format!("{}+{}", function, term), text.clone());
+                        }
+                    }
+                    "struct" => {
+                        docs.insert(format!("{}+{}", owner, term), text);
+                    }
+                    // enumerators and flags are global constants, other terms are meaningless without owner
+                    "enum" | "flags" | "define" => {
+                        docs.insert(term, text);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    Ok(docs)
+}
+
+pub fn parse_docs(paths: &[PathBuf]) -> Result<HashMap<String, String>, Error> {
+    let mut output = HashMap::new();
+    for path in paths {
+        let html = fs::read_to_string(path)?;
+        output.extend(parse_docs_fragment(&html)?)
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_docs_fragment;
+
+    const FIXTURE: &str = include_str!("../../tests/fixtures/core-api-docs.html");
+
+    #[test]
+    fn test_function_docs() {
+        let docs = parse_docs_fragment(FIXTURE).unwrap();
+        assert_eq!(
+            docs["FMOD_System_SetDSPBufferSize"],
+            "Sets the buffer size for the FMOD software mixing engine."
+        );
+        assert_eq!(
+            docs["FMOD_System_SetDSPBufferSize+numbuffers"],
+            "The mixer engine number of buffers used, added to the block size."
+        );
+        assert!(!docs.contains_key("System::setDSPBufferSize"));
+    }
+
+    #[test]
+    fn test_struct_docs() {
+        let docs = parse_docs_fragment(FIXTURE).unwrap();
+        assert_eq!(
+            docs["FMOD_VECTOR"],
+            "Structure describing a point, vector or direction."
+        );
+        assert_eq!(docs["FMOD_VECTOR+x"], "X coordinate in 3D space.");
+    }
+
+    #[test]
+    fn test_enumerator_and_flag_docs() {
+        let docs = parse_docs_fragment(FIXTURE).unwrap();
+        assert_eq!(docs["FMOD_SPEAKERMODE"], "Speaker mode types.");
+        assert_eq!(
+            docs["FMOD_SPEAKERMODE_STEREO"],
+            "2 speaker setup (left, right)."
+        );
+        assert_eq!(docs["FMOD_INIT_NORMAL"], "Initialize normally.");
+    }
+
+    #[test]
+    fn test_callback_parameters_ignored() {
+        let docs = parse_docs_fragment(FIXTURE).unwrap();
+        assert_eq!(docs["FMOD_SYSTEM_CALLBACK"], "Callback for System events.");
+        assert!(!docs.contains_key("system"));
+        assert!(!docs.contains_key("FMOD_SYSTEM_CALLBACK+system"));
+    }
+}
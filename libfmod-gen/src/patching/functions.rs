@@ -3,6 +3,26 @@ use crate::generators::lib::Signature;
 use crate::models::{Argument, Function};
 use crate::Api;
 
+/// Functions which write C string into buffer allocated by caller: function, buffer, buffer length.
+const STRING_BUFFERS: [(&str, &str, &str); 16] = [
+    ("FMOD_DSP_GetParameterFloat", "valuestr", "valuestrlen"),
+    ("FMOD_DSP_GetParameterInt", "valuestr", "valuestrlen"),
+    ("FMOD_DSP_GetParameterBool", "valuestr", "valuestrlen"),
+    ("FMOD_DSP_GetParameterData", "valuestr", "valuestrlen"),
+    ("FMOD_Sound_GetSyncPointInfo", "name", "namelen"),
+    ("FMOD_Studio_Bank_GetStringInfo", "path", "size"),
+    ("FMOD_Studio_CommandReplay_GetCommandString", "buffer", "length"),
+    ("FMOD_Studio_EventDescription_GetParameterLabelByIndex", "label", "size"),
+    ("FMOD_Studio_EventDescription_GetParameterLabelByName", "label", "size"),
+    ("FMOD_Studio_EventDescription_GetParameterLabelByID", "label", "size"),
+    ("FMOD_Studio_System_GetParameterLabelByName", "label", "size"),
+    ("FMOD_Studio_System_GetParameterLabelByID", "label", "size"),
+    ("FMOD_System_GetDriverInfo", "name", "namelen"),
+    ("FMOD_System_GetPluginInfo", "name", "namelen"),
+    ("FMOD_System_GetRecordDriverInfo", "name", "namelen"),
+    ("FMOD_System_GetNetworkProxy", "proxy", "proxylen"),
+];
+
 impl Signature {
     pub fn patch_function_signature(
         &mut self,
@@ -216,12 +236,351 @@ impl Signature {
             return true;
         }
 
+        for (name, buffer, length) in STRING_BUFFERS {
+            if function.name != name {
+                continue;
+            }
+            let buffer = format_ident!("{}", buffer);
+            let length = format_ident!("{}", length);
+            if argument.name == buffer.to_string() {
+                self.targets
+                    .push(quote! { let mut #buffer = vec![0 as c_char; #length.max(1) as usize]; });
+                self.inputs.push(quote! { #buffer.as_mut_ptr() });
+                self.outputs.push(
+                    quote! { CStr::from_ptr(#buffer.as_ptr()).to_string_lossy().into_owned() },
+                );
+                self.return_types.push(quote! { String });
+                return true;
+            }
+            if argument.name == length.to_string() {
+                self.arguments.push(quote! { #length: i32 });
+                self.inputs.push(quote! { #buffer.len() as i32 });
+                return true;
+            }
+        }
+
         return false;
     }
 }
 
 impl Api {
+    fn patch_mix_matrix(&mut self, owner: &str) {
+        let set_name = format!("{}_SetMixMatrix", owner);
+        let get_name = format!("{}_GetMixMatrix", owner);
+        let set_function = format_ident!("{}", set_name);
+        let get_function = format_ident!("{}", get_name);
+        self.function_patches.insert(
+            set_name.clone(),
+            quote! {
+                pub fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error> {
+                    unsafe {
+                        match ffi::#set_function(
+                            self.pointer,
+                            matrix.as_slice().as_ptr() as *mut f32,
+                            matrix.out_channels() as i32,
+                            matrix.in_channels() as i32,
+                            0,
+                        ) {
+                            ffi::FMOD_OK => Ok(()),
+                            error => Err(err_fmod!(#set_name, error)),
+                        }
+                    }
+                }
+            },
+        );
+        self.function_patches.insert(
+            get_name.clone(),
+            quote! {
+                pub fn get_mix_matrix(&self) -> Result<MixMatrix, Error> {
+                    unsafe {
+                        let mut outchannels = i32::default();
+                        let mut inchannels = i32::default();
+                        match ffi::#get_function(
+                            self.pointer,
+                            null_mut(),
+                            &mut outchannels,
+                            &mut inchannels,
+                            0,
+                        ) {
+                            ffi::FMOD_OK => {
+                                let mut matrix =
+                                    MixMatrix::new(outchannels as usize, inchannels as usize);
+                                match ffi::#get_function(
+                                    self.pointer,
+                                    matrix.as_mut_ptr(),
+                                    &mut outchannels,
+                                    &mut inchannels,
+                                    0,
+                                ) {
+                                    ffi::FMOD_OK => Ok(matrix),
+                                    error => Err(err_fmod!(#get_name, error)),
+                                }
+                            }
+                            error => Err(err_fmod!(#get_name, error)),
+                        }
+                    }
+                }
+            },
+        );
+    }
+
+    fn patch_mix_levels(&mut self, owner: &str) {
+        let output_name = format!("{}_SetMixLevelsOutput", owner);
+        let input_name = format!("{}_SetMixLevelsInput", owner);
+        let output_function = format_ident!("{}", output_name);
+        let input_function = format_ident!("{}", input_name);
+        self.function_patches.insert(
+            output_name.clone(),
+            quote! {
+                pub fn set_mix_levels_output(&self, levels: &SpeakerLevels) -> Result<(), Error> {
+                    let (_, speakermode, _) = self.get_system_object()?.get_software_format()?;
+                    levels.validate(speakermode)?;
+                    unsafe {
+                        match ffi::#output_function(
+                            self.pointer,
+                            levels.get(Speaker::FrontLeft),
+                            levels.get(Speaker::FrontRight),
+                            levels.get(Speaker::FrontCenter),
+                            levels.get(Speaker::LowFrequency),
+                            levels.get(Speaker::SurroundLeft),
+                            levels.get(Speaker::SurroundRight),
+                            levels.get(Speaker::BackLeft),
+                            levels.get(Speaker::BackRight),
+                        ) {
+                            ffi::FMOD_OK => Ok(()),
+                            error => Err(err_fmod!(#output_name, error)),
+                        }
+                    }
+                }
+            },
+        );
+        self.function_patches.insert(
+            input_name.clone(),
+            quote! {
+                pub fn set_mix_levels_input(&self, levels: &[f32]) -> Result<(), Error> {
+                    validate_input_levels(levels)?;
+                    unsafe {
+                        match ffi::#input_function(
+                            self.pointer,
+                            levels.as_ptr() as *mut f32,
+                            levels.len() as i32,
+                        ) {
+                            ffi::FMOD_OK => Ok(()),
+                            error => Err(err_fmod!(#input_name, error)),
+                        }
+                    }
+                }
+            },
+        );
+    }
+
+    fn patch_fade_points(&mut self, owner: &str) {
+        let name = format!("{}_GetFadePoints", owner);
+        let function = format_ident!("{}", name);
+        self.function_patches.insert(
+            name.clone(),
+            quote! {
+                pub fn get_fade_points(&self) -> Result<Vec<(u64, f32)>, Error> {
+                    unsafe {
+                        let mut numpoints = u32::default();
+                        match ffi::#function(self.pointer, &mut numpoints, null_mut(), null_mut()) {
+                            ffi::FMOD_OK => {
+                                let mut clocks = vec![0u64; numpoints as usize];
+                                let mut volumes = vec![0.0f32; numpoints as usize];
+                                match ffi::#function(
+                                    self.pointer,
+                                    &mut numpoints,
+                                    clocks.as_mut_ptr(),
+                                    volumes.as_mut_ptr(),
+                                ) {
+                                    ffi::FMOD_OK => Ok(clocks.into_iter().zip(volumes).collect()),
+                                    error => Err(err_fmod!(#name, error)),
+                                }
+                            }
+                            error => Err(err_fmod!(#name, error)),
+                        }
+                    }
+                }
+            },
+        );
+    }
+
+    fn patch_get_name(&mut self, owner: &str) {
+        let name = format!("{}_GetName", owner);
+        let function = format_ident!("{}", name);
+        self.function_patches.insert(
+            name.clone(),
+            quote! {
+                pub fn get_name(&self, namelen: i32) -> Result<String, Error> {
+                    unsafe {
+                        let mut name = vec![0 as c_char; namelen.max(1) as usize];
+                        match ffi::#function(self.pointer, name.as_mut_ptr(), name.len() as i32) {
+                            ffi::FMOD_OK => Ok(CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned()),
+                            error => Err(err_fmod!(#name, error)),
+                        }
+                    }
+                }
+            },
+        );
+    }
+
     pub fn patch_functions(&mut self) {
+        for owner in ["FMOD_Channel", "FMOD_ChannelGroup", "FMOD_DSPConnection"] {
+            self.patch_mix_matrix(owner);
+        }
+        for owner in ["FMOD_Channel", "FMOD_ChannelGroup"] {
+            self.patch_mix_levels(owner);
+            self.patch_fade_points(owner);
+        }
+        for owner in ["FMOD_Sound", "FMOD_ChannelGroup", "FMOD_SoundGroup"] {
+            self.patch_get_name(owner);
+        }
+        self.function_patches.insert(
+            "FMOD_System_GetDefaultMixMatrix".to_string(),
+            quote! {
+                pub fn get_default_mix_matrix(
+                    &self,
+                    sourcespeakermode: SpeakerMode,
+                    targetspeakermode: SpeakerMode,
+                ) -> Result<MixMatrix, Error> {
+                    let inchannels = self.get_speaker_mode_channels(sourcespeakermode)?;
+                    let outchannels = self.get_speaker_mode_channels(targetspeakermode)?;
+                    let mut matrix = MixMatrix::new(outchannels as usize, inchannels as usize);
+                    unsafe {
+                        match ffi::FMOD_System_GetDefaultMixMatrix(
+                            self.pointer,
+                            sourcespeakermode.into(),
+                            targetspeakermode.into(),
+                            matrix.as_mut_ptr(),
+                            0,
+                        ) {
+                            ffi::FMOD_OK => Ok(matrix),
+                            error => Err(err_fmod!("FMOD_System_GetDefaultMixMatrix", error)),
+                        }
+                    }
+                }
+            },
+        );
+        self.function_patches.insert(
+            "FMOD_Geometry_AddPolygon".to_string(),
+            quote! {
+                pub fn add_polygon(
+                    &self,
+                    directocclusion: f32,
+                    reverbocclusion: f32,
+                    doublesided: bool,
+                    vertices: &[Vector],
+                ) -> Result<i32, Error> {
+                    let vertices: Vec<ffi::FMOD_VECTOR> =
+                        vertices.iter().map(|vertex| (*vertex).into()).collect();
+                    unsafe {
+                        let mut polygonindex = i32::default();
+                        match ffi::FMOD_Geometry_AddPolygon(
+                            self.pointer,
+                            directocclusion,
+                            reverbocclusion,
+                            from_bool!(doublesided),
+                            vertices.len() as i32,
+                            vertices.as_ptr(),
+                            &mut polygonindex,
+                        ) {
+                            ffi::FMOD_OK => Ok(polygonindex),
+                            error => Err(err_fmod!("FMOD_Geometry_AddPolygon", error)),
+                        }
+                    }
+                }
+            },
+        );
+        self.function_patches.insert(
+            "FMOD_Geometry_Save".to_string(),
+            quote! {
+                pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+                    unsafe {
+                        let mut datasize = i32::default();
+                        match ffi::FMOD_Geometry_Save(self.pointer, null_mut(), &mut datasize) {
+                            ffi::FMOD_OK => {
+                                let mut data = vec![0u8; datasize as usize];
+                                match ffi::FMOD_Geometry_Save(
+                                    self.pointer,
+                                    data.as_mut_ptr() as *mut c_void,
+                                    &mut datasize,
+                                ) {
+                                    ffi::FMOD_OK => Ok(data),
+                                    error => Err(err_fmod!("FMOD_Geometry_Save", error)),
+                                }
+                            }
+                            error => Err(err_fmod!("FMOD_Geometry_Save", error)),
+                        }
+                    }
+                }
+            },
+        );
+        self.function_patches.insert(
+            "FMOD_System_LoadGeometry".to_string(),
+            quote! {
+                pub fn load_geometry_bytes(&self, data: &[u8]) -> Result<Geometry, Error> {
+                    unsafe {
+                        let mut geometry = null_mut();
+                        match ffi::FMOD_System_LoadGeometry(
+                            self.pointer,
+                            data.as_ptr() as *const c_void,
+                            data.len() as i32,
+                            &mut geometry,
+                        ) {
+                            ffi::FMOD_OK => Ok(Geometry::from(geometry)),
+                            error => Err(err_fmod!("FMOD_System_LoadGeometry", error)),
+                        }
+                    }
+                }
+            },
+        );
+        self.function_patches.insert(
+            "FMOD_DSP_GetInfo".to_string(),
+            quote! {
+                pub fn get_info(&self) -> Result<(String, u32, i32, i32, i32), Error> {
+                    unsafe {
+                        let mut name = [0 as c_char; 32];
+                        let mut version = u32::default();
+                        let mut channels = i32::default();
+                        let mut configwidth = i32::default();
+                        let mut configheight = i32::default();
+                        match ffi::FMOD_DSP_GetInfo(
+                            self.pointer,
+                            name.as_mut_ptr(),
+                            &mut version,
+                            &mut channels,
+                            &mut configwidth,
+                            &mut configheight,
+                        ) {
+                            ffi::FMOD_OK => Ok((
+                                CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned(),
+                                version,
+                                channels,
+                                configwidth,
+                                configheight,
+                            )),
+                            error => Err(err_fmod!("FMOD_DSP_GetInfo", error)),
+                        }
+                    }
+                }
+            },
+        );
+        self.function_patches.insert(
+            "FMOD_System_Release".to_string(),
+            quote! {
+                pub fn release(&self) -> Result<(), Error> {
+                    unsafe {
+                        match ffi::FMOD_System_Release(self.pointer) {
+                            ffi::FMOD_OK => {
+                                forget_followed_driver(self.pointer);
+                                Ok(())
+                            }
+                            error => Err(err_fmod!("FMOD_System_Release", error)),
+                        }
+                    }
+                }
+            },
+        );
         self.function_patches.insert(
             "FMOD_System_CreateStream".to_string(),
             quote! {
@@ -32,6 +32,21 @@ impl Api {
                 }
             },
         );
+        self.structure_patches.insert(
+            "FMOD_DSP_DESCRIPTION".to_string(),
+            quote! {
+                impl std::fmt::Debug for DspDescription {
+                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        let name: Vec<u8> = self.name.iter().take_while(|&&char| char != 0).map(|&char| char as u8).collect();
+                        f.debug_struct("DspDescription")
+                            .field("name", &String::from_utf8_lossy(&name))
+                            .field("version", &self.version)
+                            .field("numparameters", &self.paramdesc.len())
+                            .finish_non_exhaustive()
+                    }
+                }
+            },
+        );
         self.structure_patches.insert(
             "FMOD_GUID".to_string(),
             quote! {
@@ -1,7 +1,23 @@
 use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 fn main() {
+    println!("cargo:rerun-if-env-changed=FMOD_SDK_DIR");
+    println!("cargo:rerun-if-env-changed=FMOD_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=FMOD_RPATH");
+    println!("cargo:rerun-if-env-changed=FMOD_COPY_LIBS");
+    if env::var("CARGO_FEATURE_DYNAMIC").is_ok() {
+        // libraries are loaded at runtime by libfmod::load
+        return;
+    }
+    if env::var("CARGO_FEATURE_STUB").is_ok() {
+        // FMOD API is implemented by libfmod::stub, nothing to link
+        return;
+    }
     let target_family = env::var("CARGO_CFG_TARGET_FAMILY").unwrap();
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap();
+    let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
     let logging = env::var("CARGO_FEATURE_LOGGING").is_ok();
     let [core, studio] = match (logging, target_family.as_str()) {
         (false, "windows") => ["fmod_vc", "fmodstudio_vc"],
@@ -9,6 +25,100 @@ fn main() {
         (true, _) => ["fmodL", "fmodstudioL"],
         _ => ["fmod", "fmodstudio"],
     };
+
+    let os_lib_dir = format!("FMOD_LIB_DIR_{}", target_os.to_uppercase());
+    println!("cargo:rerun-if-env-changed={os_lib_dir}");
+    let directories =
+        if let Some(directory) = env::var_os(&os_lib_dir).or(env::var_os("FMOD_LIB_DIR")) {
+            let directory = PathBuf::from(directory);
+            Some([directory.clone(), directory])
+        } else if let Some(sdk) = env::var_os("FMOD_SDK_DIR") {
+            let sdk = PathBuf::from(sdk);
+            let arch = match (target_os.as_str(), target_arch.as_str()) {
+                ("macos", _) | ("ios", _) => "",
+                ("windows", "x86_64") => "x64",
+                (_, "aarch64") => "arm64",
+                (_, arch) => arch,
+            };
+            Some([
+                sdk.join("api/core/lib").join(arch),
+                sdk.join("api/studio/lib").join(arch),
+            ])
+        } else {
+            None
+        };
+
+    if let Some(directories) = directories {
+        for (directory, name) in directories.iter().zip([core, studio]) {
+            let library = directory.join(link_file_name(&target_os, name));
+            if !library.exists() {
+                panic!(
+                    "FMOD library {} not found, make sure FMOD_SDK_DIR points to FMOD Engine \
+                    installation folder (with api/core and api/studio) or FMOD_LIB_DIR to folder \
+                    with FMOD libraries for {} {}",
+                    library.display(),
+                    target_os,
+                    target_arch
+                );
+            }
+            println!("cargo:rustc-link-search=native={}", directory.display());
+            // link args are applied only to binaries of this package (tests, examples)
+            if env::var("FMOD_RPATH").is_ok() && target_os == "linux" {
+                println!("cargo:rustc-link-arg=-Wl,-rpath,{}", directory.display());
+            }
+            if env::var("FMOD_COPY_LIBS").is_ok() {
+                copy_shared_libraries(directory, name.trim_end_matches("_vc"));
+            }
+        }
+        // dependent build scripts get it as DEP_FMOD_LIB_DIR (e.g. to set up their own rpath)
+        println!("cargo:lib_dir={}", directories[0].display());
+    }
+
     println!("cargo:rustc-flags=-l {core}");
     println!("cargo:rustc-flags=-l {studio}");
 }
+
+fn link_file_name(target_os: &str, name: &str) -> String {
+    match target_os {
+        "windows" => format!("{name}.lib"),
+        "macos" | "ios" => format!("lib{name}.dylib"),
+        _ => format!("lib{name}.so"),
+    }
+}
+
+fn is_shared_library(file_name: &str, name: &str) -> bool {
+    file_name == format!("{name}.dll")
+        || file_name == format!("lib{name}.dylib")
+        || file_name == format!("lib{name}.so")
+        || file_name.starts_with(&format!("lib{name}.so."))
+}
+
+/// Copies shared libraries next to the binaries of current profile (target/debug, target/debug/deps),
+/// so applications and tests can be started without FMOD installation.
+fn copy_shared_libraries(directory: &Path, name: &str) {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    // OUT_DIR is target/<profile>/build/libfmod-<hash>/out
+    let profile_dir = match out_dir.ancestors().nth(3) {
+        Some(profile_dir) => profile_dir.to_path_buf(),
+        None => return,
+    };
+    let entries = fs::read_dir(directory)
+        .unwrap_or_else(|error| panic!("unable to read {}, {}", directory.display(), error));
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if !is_shared_library(&file_name, name) {
+            continue;
+        }
+        for destination in [profile_dir.clone(), profile_dir.join("deps")] {
+            fs::create_dir_all(&destination).ok();
+            fs::copy(entry.path(), destination.join(&file_name)).unwrap_or_else(|error| {
+                panic!(
+                    "unable to copy {} to {}, {}",
+                    entry.path().display(),
+                    destination.display(),
+                    error
+                )
+            });
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use std::ffi::{c_int, c_uint, c_void, CStr, CString};
+
+use crate::output_plugin::result_code;
+use crate::{errors, ffi, CodecWaveformat, Error, System};
+
+/// File of the sound being opened, it is read through FMOD file system,
+/// so user file callbacks and memory sounds work the same way.
+#[derive(Debug)]
+pub struct CodecFile {
+    state: *mut ffi::FMOD_CODEC_STATE,
+}
+
+impl CodecFile {
+    /// Reads up to `buffer.len()` bytes, returns number of bytes read, 0 at the end of file.
+    pub fn read(&mut self, buffer: &mut [u8]) -> Result<usize, Error> {
+        let read = self.functions("CodecFile::read")?.read;
+        let Some(read) = read else {
+            return Err(err_fmod!("CodecFile::read", ffi::FMOD_ERR_UNSUPPORTED));
+        };
+        let mut bytes = 0;
+        match unsafe {
+            read(
+                self.state,
+                buffer.as_mut_ptr().cast(),
+                buffer.len() as c_uint,
+                &mut bytes,
+            )
+        } {
+            ffi::FMOD_OK | ffi::FMOD_ERR_FILE_EOF => Ok(bytes as usize),
+            error => Err(err_fmod!("FMOD_CODEC_FILE_READ_FUNC", error)),
+        }
+    }
+
+    /// Fills whole buffer, fails with `FMOD_ERR_FILE_EOF` if file ends before.
+    pub fn read_exact(&mut self, buffer: &mut [u8]) -> Result<(), Error> {
+        let mut filled = 0;
+        while filled < buffer.len() {
+            match self.read(&mut buffer[filled..])? {
+                0 => return Err(err_fmod!("CodecFile::read_exact", ffi::FMOD_ERR_FILE_EOF)),
+                bytes => filled += bytes,
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves to position in bytes from the start of file.
+    pub fn seek(&mut self, position: u32) -> Result<(), Error> {
+        let seek = self.functions("CodecFile::seek")?.seek;
+        let Some(seek) = seek else {
+            return Err(err_fmod!("CodecFile::seek", ffi::FMOD_ERR_UNSUPPORTED));
+        };
+        match unsafe { seek(self.state, position, ffi::FMOD_CODEC_SEEK_METHOD_SET) } {
+            ffi::FMOD_OK => Ok(()),
+            error => Err(err_fmod!("FMOD_CODEC_FILE_SEEK_FUNC", error)),
+        }
+    }
+
+    pub fn tell(&mut self) -> Result<u32, Error> {
+        let tell = self.functions("CodecFile::tell")?.tell;
+        let Some(tell) = tell else {
+            return Err(err_fmod!("CodecFile::tell", ffi::FMOD_ERR_UNSUPPORTED));
+        };
+        let mut position = 0;
+        match unsafe { tell(self.state, &mut position) } {
+            ffi::FMOD_OK => Ok(position),
+            error => Err(err_fmod!("FMOD_CODEC_FILE_TELL_FUNC", error)),
+        }
+    }
+
+    /// Size of file in bytes.
+    pub fn size(&mut self) -> Result<u32, Error> {
+        let size = self.functions("CodecFile::size")?.size;
+        let Some(size) = size else {
+            return Err(err_fmod!("CodecFile::size", ffi::FMOD_ERR_UNSUPPORTED));
+        };
+        let mut value = 0;
+        match unsafe { size(self.state, &mut value) } {
+            ffi::FMOD_OK => Ok(value),
+            error => Err(err_fmod!("FMOD_CODEC_FILE_SIZE_FUNC", error)),
+        }
+    }
+
+    fn functions(&self, function: &str) -> Result<ffi::FMOD_CODEC_STATE_FUNCTIONS, Error> {
+        unsafe { (*self.state).functions.as_ref() }
+            .copied()
+            .ok_or_else(|| err_fmod!(function, ffi::FMOD_ERR_INTERNAL))
+    }
+}
+
+/// Decoder of audio format implemented in Rust, e.g. proprietary compressed voice.
+///
+/// FMOD creates plugin with `Default` for every opened sound and tries registered codecs
+/// by priority, codec refuses file of unknown format with `FMOD_ERR_FORMAT` from `open`.
+///
+/// ```ignore
+/// #[derive(Default)]
+/// struct VoiceCodec { decoder: Decoder }
+///
+/// impl CodecPlugin for VoiceCodec {
+///     const NAME: &'static CStr = c"voice";
+///
+///     fn open(&mut self, file: &mut CodecFile) -> Result<Vec<CodecWaveformat>, Error> {
+///         let header = self.decoder.read_header(file)?;
+///         Ok(vec![header.waveformat()])
+///     }
+///     ...
+/// }
+///
+/// system.register_codec_plugin::<VoiceCodec>(0)?;
+/// let sound = system.create_sound("speech.voice", Mode::DEFAULT, None)?;
+/// ```
+pub trait CodecPlugin: Default + Send + 'static {
+    const NAME: &'static CStr;
+
+    /// Units accepted by `set_position` and `get_length`.
+    const TIME_UNITS: ffi::FMOD_TIMEUNIT = ffi::FMOD_TIMEUNIT_PCM;
+
+    /// Reads header and describes sound, every waveformat after the first one is subsound.
+    fn open(&mut self, file: &mut CodecFile) -> Result<Vec<CodecWaveformat>, Error>;
+
+    /// Decodes PCM data of current subsound into buffer (in its waveformat),
+    /// returns number of samples per channel written, 0 at the end of sound.
+    fn read(&mut self, file: &mut CodecFile, buffer: &mut [u8]) -> Result<u32, Error>;
+
+    /// Seeks subsound, the next `read` continues from the position.
+    fn set_position(
+        &mut self,
+        file: &mut CodecFile,
+        subsound: i32,
+        position: u32,
+        unit: ffi::FMOD_TIMEUNIT,
+    ) -> Result<(), Error>;
+
+    fn get_length(&mut self, unit: ffi::FMOD_TIMEUNIT) -> Result<u32, Error>;
+}
+
+/// Plugin with waveformats FMOD reads from codec state, owned by the state from open to close.
+struct CodecHost<P> {
+    plugin: P,
+    // keeps waveformat names alive
+    _names: Vec<CString>,
+    waveformats: Vec<ffi::FMOD_CODEC_WAVEFORMAT>,
+    current: usize,
+}
+
+impl<P> CodecHost<P> {
+    /// Bytes of one sample of every channel, compressed formats can't be read.
+    fn frame_size(&self) -> Option<usize> {
+        let waveformat = self.waveformats.get(self.current)?;
+        let size = match waveformat.format {
+            ffi::FMOD_SOUND_FORMAT_PCM8 => 1,
+            ffi::FMOD_SOUND_FORMAT_PCM16 => 2,
+            ffi::FMOD_SOUND_FORMAT_PCM24 => 3,
+            ffi::FMOD_SOUND_FORMAT_PCM32 | ffi::FMOD_SOUND_FORMAT_PCMFLOAT => 4,
+            _ => return None,
+        };
+        Some(size * waveformat.channels.max(1) as usize)
+    }
+}
+
+unsafe fn host<'a, P>(codec_state: *mut ffi::FMOD_CODEC_STATE) -> Option<&'a mut CodecHost<P>> {
+    ((*codec_state).plugindata as *mut CodecHost<P>).as_mut()
+}
+
+unsafe extern "C" fn open<P: CodecPlugin>(
+    codec_state: *mut ffi::FMOD_CODEC_STATE,
+    _usermode: ffi::FMOD_MODE,
+    _userexinfo: *mut ffi::FMOD_CREATESOUNDEXINFO,
+) -> ffi::FMOD_RESULT {
+    let mut plugin = P::default();
+    let mut file = CodecFile { state: codec_state };
+    let waveformats = match plugin.open(&mut file) {
+        Ok(waveformats) if !waveformats.is_empty() => waveformats,
+        Ok(_) => return ffi::FMOD_ERR_FORMAT,
+        Err(error) => return result_code(Err(error), ffi::FMOD_ERR_FORMAT),
+    };
+    let mut names = Vec::with_capacity(waveformats.len());
+    let mut converted = Vec::with_capacity(waveformats.len());
+    for waveformat in waveformats {
+        let Ok(name) = CString::new(waveformat.name.as_str()) else {
+            return ffi::FMOD_ERR_INVALID_STRING;
+        };
+        let mut waveformat: ffi::FMOD_CODEC_WAVEFORMAT = waveformat.into();
+        waveformat.name = name.as_ptr();
+        names.push(name);
+        converted.push(waveformat);
+    }
+    let mut host = Box::new(CodecHost {
+        plugin,
+        _names: names,
+        waveformats: converted,
+        current: 0,
+    });
+    // single sound is described by numsubsounds 0
+    let count = host.waveformats.len();
+    (*codec_state).numsubsounds = if count > 1 { count as c_int } else { 0 };
+    (*codec_state).waveformat = host.waveformats.as_mut_ptr();
+    (*codec_state).plugindata = Box::into_raw(host).cast();
+    ffi::FMOD_OK
+}
+
+unsafe extern "C" fn close<P: CodecPlugin>(
+    codec_state: *mut ffi::FMOD_CODEC_STATE,
+) -> ffi::FMOD_RESULT {
+    let host = std::mem::replace(&mut (*codec_state).plugindata, std::ptr::null_mut());
+    if !host.is_null() {
+        (*codec_state).waveformat = std::ptr::null_mut();
+        drop(Box::from_raw(host as *mut CodecHost<P>));
+    }
+    ffi::FMOD_OK
+}
+
+unsafe extern "C" fn read<P: CodecPlugin>(
+    codec_state: *mut ffi::FMOD_CODEC_STATE,
+    buffer: *mut c_void,
+    samples_in: c_uint,
+    samples_out: *mut c_uint,
+) -> ffi::FMOD_RESULT {
+    let Some(host) = host::<P>(codec_state) else {
+        return ffi::FMOD_ERR_INVALID_PARAM;
+    };
+    let Some(frame_size) = host.frame_size() else {
+        return ffi::FMOD_ERR_FORMAT;
+    };
+    let buffer =
+        std::slice::from_raw_parts_mut(buffer as *mut u8, samples_in as usize * frame_size);
+    let mut file = CodecFile { state: codec_state };
+    match host.plugin.read(&mut file, buffer) {
+        Ok(samples) => {
+            *samples_out = samples.min(samples_in);
+            ffi::FMOD_OK
+        }
+        Err(error) => result_code(Err(error), ffi::FMOD_ERR_FILE_BAD),
+    }
+}
+
+unsafe extern "C" fn get_length<P: CodecPlugin>(
+    codec_state: *mut ffi::FMOD_CODEC_STATE,
+    length: *mut c_uint,
+    lengthtype: ffi::FMOD_TIMEUNIT,
+) -> ffi::FMOD_RESULT {
+    let Some(host) = host::<P>(codec_state) else {
+        return ffi::FMOD_ERR_INVALID_PARAM;
+    };
+    match host.plugin.get_length(lengthtype) {
+        Ok(value) => {
+            *length = value;
+            ffi::FMOD_OK
+        }
+        Err(error) => result_code(Err(error), ffi::FMOD_ERR_FORMAT),
+    }
+}
+
+unsafe extern "C" fn set_position<P: CodecPlugin>(
+    codec_state: *mut ffi::FMOD_CODEC_STATE,
+    subsound: c_int,
+    position: c_uint,
+    postype: ffi::FMOD_TIMEUNIT,
+) -> ffi::FMOD_RESULT {
+    let Some(host) = host::<P>(codec_state) else {
+        return ffi::FMOD_ERR_INVALID_PARAM;
+    };
+    let mut file = CodecFile { state: codec_state };
+    let result = host
+        .plugin
+        .set_position(&mut file, subsound, position, postype);
+    if result.is_ok() && subsound >= 0 && (subsound as usize) < host.waveformats.len() {
+        host.current = subsound as usize;
+    }
+    result_code(result, ffi::FMOD_ERR_FILE_COULDNOTSEEK)
+}
+
+unsafe extern "C" fn get_waveformat<P: CodecPlugin>(
+    codec_state: *mut ffi::FMOD_CODEC_STATE,
+    index: c_int,
+    waveformat: *mut ffi::FMOD_CODEC_WAVEFORMAT,
+) -> ffi::FMOD_RESULT {
+    let Some(host) = host::<P>(codec_state) else {
+        return ffi::FMOD_ERR_INVALID_PARAM;
+    };
+    match host.waveformats.get(index as usize) {
+        Some(value) if index >= 0 => {
+            *waveformat = *value;
+            ffi::FMOD_OK
+        }
+        _ => ffi::FMOD_ERR_INVALID_PARAM,
+    }
+}
+
+fn description<P: CodecPlugin>() -> ffi::FMOD_CODEC_DESCRIPTION {
+    ffi::FMOD_CODEC_DESCRIPTION {
+        apiversion: ffi::FMOD_CODEC_PLUGIN_VERSION,
+        name: P::NAME.as_ptr(),
+        version: 1,
+        defaultasstream: 0,
+        timeunits: P::TIME_UNITS,
+        open: Some(open::<P>),
+        close: Some(close::<P>),
+        read: Some(read::<P>),
+        getlength: Some(get_length::<P>),
+        setposition: Some(set_position::<P>),
+        getposition: None,
+        soundcreate: None,
+        getwaveformat: Some(get_waveformat::<P>),
+    }
+}
+
+impl System {
+    /// Registers Rust codec, sounds are opened with codec of the lowest priority value
+    /// that accepts the file. Returns codec handle.
+    pub fn register_codec_plugin<P: CodecPlugin>(&self, priority: u32) -> Result<u32, Error> {
+        let mut handle = 0;
+        unsafe {
+            match ffi::FMOD_System_RegisterCodec(
+                self.as_mut_ptr(),
+                &mut description::<P>(),
+                &mut handle,
+                priority,
+            ) {
+                ffi::FMOD_OK => Ok(handle),
+                error => Err(err_fmod!("FMOD_System_RegisterCodec", error)),
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use crate::{ffi, Error, Guid, SpeakerMode, System};
+
+/// Output or record device reported by the system, `id` is its index for `System::set_driver`.
+#[derive(Debug, Clone)]
+pub struct DriverInfo {
+    pub id: i32,
+    pub name: String,
+    pub guid: Guid,
+    pub system_rate: i32,
+    pub speaker_mode: SpeakerMode,
+    pub speaker_mode_channels: i32,
+}
+
+/// Record device with its `FMOD_DRIVER_STATE` decoded.
+#[derive(Debug, Clone)]
+pub struct RecordDriverInfo {
+    pub driver: DriverInfo,
+    pub connected: bool,
+    pub default: bool,
+}
+
+/// Buffer length for device names, FMOD truncates longer names.
+const NAME_LENGTH: i32 = 256;
+
+fn same_guid(a: &Guid, b: &Guid) -> bool {
+    a.data_1 == b.data_1 && a.data_2 == b.data_2 && a.data_3 == b.data_3 && a.data_4 == b.data_4
+}
+
+/// Preferred devices of systems which follow them, keyed by system pointer,
+/// so the user data of system stays available to the application.
+fn followed() -> &'static Mutex<HashMap<usize, Guid>> {
+    static FOLLOWED: OnceLock<Mutex<HashMap<usize, Guid>>> = OnceLock::new();
+    FOLLOWED.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Forgets preferred device of released system, so the entry doesn't outlive it
+/// and isn't applied to another system created at the same address.
+pub(crate) fn forget_followed_driver(system: *mut ffi::FMOD_SYSTEM) {
+    if let Ok(mut followed) = followed().lock() {
+        followed.remove(&(system as usize));
+    }
+}
+
+unsafe extern "C" fn device_changed(
+    system: *mut ffi::FMOD_SYSTEM,
+    type_: ffi::FMOD_SYSTEM_CALLBACK_TYPE,
+    _commanddata1: *mut c_void,
+    _commanddata2: *mut c_void,
+    _userdata: *mut c_void,
+) -> ffi::FMOD_RESULT {
+    let preferred = match followed().lock() {
+        Ok(followed) => followed.get(&(system as usize)).cloned(),
+        Err(_) => None,
+    };
+    let Some(preferred) = preferred else {
+        return ffi::FMOD_OK;
+    };
+    let system = System::from(system);
+    if system.set_driver_by_guid(&preferred).is_err()
+        && type_ & ffi::FMOD_SYSTEM_CALLBACK_DEVICELOST != 0
+    {
+        // the output is gone and preferred device is not back yet, so use the default one
+        let _ = system.set_driver(0);
+    }
+    ffi::FMOD_OK
+}
+
+impl System {
+    /// All output devices, ordered by their driver index.
+    pub fn drivers(&self) -> Result<Vec<DriverInfo>, Error> {
+        (0..self.get_num_drivers()?)
+            .map(|id| {
+                let (name, guid, system_rate, speaker_mode, speaker_mode_channels) =
+                    self.get_driver_info(id, NAME_LENGTH)?;
+                Ok(DriverInfo {
+                    id,
+                    name,
+                    guid,
+                    system_rate,
+                    speaker_mode,
+                    speaker_mode_channels,
+                })
+            })
+            .collect()
+    }
+
+    /// All record devices, including disconnected ones, ordered by their driver index.
+    pub fn record_drivers(&self) -> Result<Vec<RecordDriverInfo>, Error> {
+        let (count, _) = self.get_record_num_drivers()?;
+        (0..count)
+            .map(|id| {
+                let (name, guid, system_rate, speaker_mode, speaker_mode_channels, state) =
+                    self.get_record_driver_info(id, NAME_LENGTH)?;
+                Ok(RecordDriverInfo {
+                    driver: DriverInfo {
+                        id,
+                        name,
+                        guid,
+                        system_rate,
+                        speaker_mode,
+                        speaker_mode_channels,
+                    },
+                    connected: state & ffi::FMOD_DRIVER_STATE_CONNECTED != 0,
+                    default: state & ffi::FMOD_DRIVER_STATE_DEFAULT != 0,
+                })
+            })
+            .collect()
+    }
+
+    /// Selects output device with given GUID and returns its driver index,
+    /// fails with `Error::Validation` if the device is not present.
+    pub fn set_driver_by_guid(&self, guid: &Guid) -> Result<i32, Error> {
+        let driver = self
+            .drivers()?
+            .into_iter()
+            .find(|driver| same_guid(&driver.guid, guid))
+            .ok_or_else(|| {
+                err_validation!("System::set_driver_by_guid", "device is not present")
+            })?;
+        self.set_driver(driver.id)?;
+        Ok(driver.id)
+    }
+
+    /// Keeps output on preferred device: it is selected now if present and again whenever
+    /// it reappears in device list. If current device is lost, output falls back to the default one.
+    ///
+    /// Device changes are handled in `System::update` by system callback, so this replaces
+    /// callback set by `System::set_callback` until `System::unfollow_driver`.
+    pub fn follow_driver(&self, preferred: Guid) -> Result<(), Error> {
+        let _ = self.set_driver_by_guid(&preferred);
+        followed()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(self.as_mut_ptr() as usize, preferred);
+        self.set_callback(
+            Some(device_changed),
+            ffi::FMOD_SYSTEM_CALLBACK_DEVICELISTCHANGED | ffi::FMOD_SYSTEM_CALLBACK_DEVICELOST,
+        )
+    }
+
+    /// Stops following preferred device and removes system callback.
+    pub fn unfollow_driver(&self) -> Result<(), Error> {
+        forget_followed_driver(self.as_mut_ptr());
+        self.set_callback(None, 0u32)
+    }
+
+    /// Preferred device set by `follow_driver`, `None` if system doesn't follow any device.
+    pub fn followed_driver(&self) -> Option<Guid> {
+        followed()
+            .lock()
+            .ok()?
+            .get(&(self.as_mut_ptr() as usize))
+            .cloned()
+    }
+}
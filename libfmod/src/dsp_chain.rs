@@ -0,0 +1,240 @@
+use crate::{
+    ffi, ChannelControl, ChannelControlDspIndex, ChannelControlHandle, Dsp, DspConnection,
+    DspConnectionType, DspParameterValue, DspType, Error, System,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum Source {
+    Type(DspType),
+    Custom(Dsp),
+}
+
+#[derive(Debug, Clone)]
+struct Stage {
+    source: Source,
+    parameters: Vec<(i32, DspParameterValue)>,
+    outputs: Vec<(Dsp, DspConnectionType)>,
+}
+
+/// Builder of DSP units attached to channel or channel group as one unit.
+///
+/// Signal goes through units in order they are added. Nothing is created until `attach`,
+/// if any step fails everything created so far is torn down.
+///
+/// ```ignore
+/// let chain = DspChain::new(&system)
+///     .then(DspType::Highpass)
+///     .float(ffi::FMOD_DSP_HIGHPASS_CUTOFF, 500.0)
+///     .then_custom(my_dsp)
+///     .send_to(&reverb_return)
+///     .attach(&group, ChannelControlDspIndex::Head)?;
+/// // ...
+/// chain.release()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct DspChain {
+    system: System,
+    stages: Vec<Stage>,
+}
+
+impl DspChain {
+    pub fn new(system: &System) -> Self {
+        Self {
+            system: *system,
+            stages: vec![],
+        }
+    }
+
+    /// Adds DSP unit of built-in type, it is created on attach and released with the chain.
+    pub fn then(self, dsp_type: DspType) -> Self {
+        self.stage(Source::Type(dsp_type))
+    }
+
+    /// Adds DSP unit created by caller, it is only detached when the chain is released.
+    pub fn then_custom(self, dsp: Dsp) -> Self {
+        self.stage(Source::Custom(dsp))
+    }
+
+    /// Sets initial value of parameter of the last unit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the chain has no units yet.
+    pub fn parameter(mut self, index: i32, value: impl Into<DspParameterValue>) -> Self {
+        self.last().parameters.push((index, value.into()));
+        self
+    }
+
+    pub fn float(self, index: i32, value: f32) -> Self {
+        self.parameter(index, DspParameterValue::Float(value))
+    }
+
+    pub fn int(self, index: i32, value: i32) -> Self {
+        self.parameter(index, DspParameterValue::Int(value))
+    }
+
+    pub fn bool(self, index: i32, value: bool) -> Self {
+        self.parameter(index, DspParameterValue::Bool(value))
+    }
+
+    /// Sends signal of the last unit to target, e.g. to DSP of `DspType::Return` type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the chain has no units yet.
+    pub fn send_to(self, target: &Dsp) -> Self {
+        self.connect(target, DspConnectionType::Send)
+    }
+
+    /// Uses signal of the last unit as sidechain of target, e.g. of compressor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the chain has no units yet.
+    pub fn sidechain_to(self, target: &Dsp) -> Self {
+        self.connect(target, DspConnectionType::Sidechain)
+    }
+
+    /// Creates units and inserts them into channel or channel group at given position,
+    /// the last unit ends up closest to the head.
+    pub fn attach(
+        self,
+        control: &impl ChannelControl,
+        index: ChannelControlDspIndex,
+    ) -> Result<AttachedDspChain, Error> {
+        let mut chain = AttachedDspChain {
+            control: control.downcast()?,
+            units: vec![],
+            connections: vec![],
+        };
+        match self.build(control, index, &mut chain) {
+            Ok(()) => Ok(chain),
+            Err(error) => {
+                let _ = chain.release();
+                Err(error)
+            }
+        }
+    }
+
+    fn build(
+        self,
+        control: &impl ChannelControl,
+        index: ChannelControlDspIndex,
+        chain: &mut AttachedDspChain,
+    ) -> Result<(), Error> {
+        // inserting at fixed position moves previous units towards the tail
+        let position = match index {
+            ChannelControlDspIndex::Head => 0,
+            ChannelControlDspIndex::Fader => {
+                control.get_dsp_index(control.get_dsp(ffi::FMOD_CHANNELCONTROL_DSP_FADER)?)?
+            }
+            ChannelControlDspIndex::Tail => control.get_num_ds_ps()?,
+        };
+        for stage in self.stages {
+            let unit = match stage.source {
+                Source::Type(dsp_type) => Unit {
+                    dsp: self.system.create_dsp_by_type(dsp_type)?,
+                    owned: true,
+                    attached: false,
+                },
+                Source::Custom(dsp) => Unit {
+                    dsp,
+                    owned: false,
+                    attached: false,
+                },
+            };
+            chain.units.push(unit);
+            let dsp = unit.dsp;
+            for (index, value) in stage.parameters {
+                match value {
+                    DspParameterValue::Float(value) => dsp.set_parameter_float(index, value)?,
+                    DspParameterValue::Int(value) => dsp.set_parameter_int(index, value)?,
+                    DspParameterValue::Bool(value) => dsp.set_parameter_bool(index, value)?,
+                    DspParameterValue::Data(value) => dsp.set_data_parameter(index, value)?,
+                }
+            }
+            control.add_dsp(position, dsp)?;
+            if let Some(unit) = chain.units.last_mut() {
+                unit.attached = true;
+            }
+            for (target, connection_type) in stage.outputs {
+                let connection = target.add_input(dsp, connection_type)?;
+                chain.connections.push((target, dsp, connection));
+            }
+        }
+        Ok(())
+    }
+
+    fn stage(mut self, source: Source) -> Self {
+        self.stages.push(Stage {
+            source,
+            parameters: vec![],
+            outputs: vec![],
+        });
+        self
+    }
+
+    fn connect(mut self, target: &Dsp, connection_type: DspConnectionType) -> Self {
+        self.last().outputs.push((*target, connection_type));
+        self
+    }
+
+    fn last(&mut self) -> &mut Stage {
+        self.stages
+            .last_mut()
+            .expect("DspChain has no DSP units, call `then` or `then_custom` first")
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Unit {
+    dsp: Dsp,
+    owned: bool,
+    attached: bool,
+}
+
+/// DSP units attached by `DspChain::attach`.
+#[derive(Debug)]
+pub struct AttachedDspChain {
+    control: ChannelControlHandle,
+    units: Vec<Unit>,
+    connections: Vec<(Dsp, Dsp, DspConnection)>,
+}
+
+impl AttachedDspChain {
+    /// Units in order of signal flow.
+    pub fn dsps(&self) -> Vec<Dsp> {
+        self.units.iter().map(|unit| unit.dsp).collect()
+    }
+
+    pub fn control(&self) -> ChannelControlHandle {
+        self.control
+    }
+
+    /// Disconnects sends and sidechains, removes units from channel control and releases
+    /// units created by the chain. Teardown goes on after failure, the first error is returned.
+    pub fn release(self) -> Result<(), Error> {
+        let mut result = Ok(());
+        for (target, source, connection) in &self.connections {
+            keep_first_error(
+                &mut result,
+                target.disconnect_from(Some(*source), Some(*connection)),
+            );
+        }
+        for unit in self.units.iter().rev() {
+            if unit.attached {
+                keep_first_error(&mut result, self.control.remove_dsp(unit.dsp));
+            }
+            if unit.owned {
+                keep_first_error(&mut result, unit.dsp.release());
+            }
+        }
+        result
+    }
+}
+
+fn keep_first_error(result: &mut Result<(), Error>, outcome: Result<(), Error>) {
+    if result.is_ok() {
+        *result = outcome;
+    }
+}
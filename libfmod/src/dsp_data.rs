@@ -0,0 +1,199 @@
+use std::ffi::c_void;
+use std::mem::size_of;
+use std::ptr::null_mut;
+
+use crate::{
+    errors, ffi, Dsp, DspParameterAttenuationRange, DspParameterAttributes3d,
+    DspParameterAttributes3dMulti, DspParameterDataType, DspParameterFft, DspParameterOverallgain,
+    DspParameterSidechain, Error,
+};
+
+/// Value of DSP data parameter typed by its declared data type.
+#[derive(Debug, Clone)]
+pub enum DspData {
+    /// Data of plugin specific type, e.g. loudness meter info, copied as is.
+    User(Vec<u8>),
+    OverallGain(DspParameterOverallgain),
+    Attributes3d(DspParameterAttributes3d),
+    Sidechain(DspParameterSidechain),
+    /// Spectrum of FFT DSP, it can't be set.
+    Fft(DspParameterFft),
+    Attributes3dMulti(Box<DspParameterAttributes3dMulti>),
+    AttenuationRange(DspParameterAttenuationRange),
+}
+
+impl DspData {
+    pub fn data_type(&self) -> DspParameterDataType {
+        match self {
+            DspData::User(_) => DspParameterDataType::User,
+            DspData::OverallGain(_) => DspParameterDataType::OverallGain,
+            DspData::Attributes3d(_) => DspParameterDataType::Attributes3D,
+            DspData::Sidechain(_) => DspParameterDataType::Sidechain,
+            DspData::Fft(_) => DspParameterDataType::Fft,
+            DspData::Attributes3dMulti(_) => DspParameterDataType::AttributesMulti3D,
+            DspData::AttenuationRange(_) => DspParameterDataType::AttenuationRange,
+        }
+    }
+}
+
+/// Copies FMOD struct from parameter data, `None` if the data is too short.
+unsafe fn read<T: Copy>(data: *mut c_void, length: u32) -> Option<T> {
+    if data.is_null() || (length as usize) < size_of::<T>() {
+        return None;
+    }
+    Some(std::ptr::read_unaligned(data as *const T))
+}
+
+impl Dsp {
+    /// Declared data type of data parameter, plugin specific types are reported as `User`.
+    pub fn get_data_parameter_type(&self, index: i32) -> Result<DspParameterDataType, Error> {
+        let desc = self.parameter_desc(index)?;
+        if desc.type_ != ffi::FMOD_DSP_PARAMETER_TYPE_DATA {
+            return Err(err_validation!(
+                "Dsp::get_data_parameter_type",
+                "parameter {} is not data parameter",
+                index
+            ));
+        }
+        let datatype = unsafe { desc.union.datadesc.datatype };
+        if datatype >= ffi::FMOD_DSP_PARAMETER_DATA_TYPE_USER {
+            return Ok(DspParameterDataType::User);
+        }
+        DspParameterDataType::from(datatype)
+    }
+
+    /// Reads data parameter as its declared data type.
+    pub fn get_data_parameter(&self, index: i32) -> Result<DspData, Error> {
+        let (data, length, _) = self.get_parameter_data(index, 0)?;
+        self.decode_data_parameter(index, data, length)
+    }
+
+    /// Converts data returned by `get_parameter_data` to declared data type of the parameter.
+    pub(crate) fn decode_data_parameter(
+        &self,
+        index: i32,
+        data: *mut c_void,
+        length: u32,
+    ) -> Result<DspData, Error> {
+        let datatype = self.get_data_parameter_type(index)?;
+        let value = unsafe {
+            match datatype {
+                DspParameterDataType::User => {
+                    let bytes = if data.is_null() {
+                        vec![]
+                    } else {
+                        std::slice::from_raw_parts(data as *const u8, length as usize).to_vec()
+                    };
+                    Some(DspData::User(bytes))
+                }
+                DspParameterDataType::OverallGain => {
+                    read::<ffi::FMOD_DSP_PARAMETER_OVERALLGAIN>(data, length)
+                        .map(DspParameterOverallgain::try_from)
+                        .transpose()?
+                        .map(DspData::OverallGain)
+                }
+                DspParameterDataType::Attributes3D => {
+                    read::<ffi::FMOD_DSP_PARAMETER_3DATTRIBUTES>(data, length)
+                        .map(DspParameterAttributes3d::try_from)
+                        .transpose()?
+                        .map(DspData::Attributes3d)
+                }
+                DspParameterDataType::Sidechain => {
+                    read::<ffi::FMOD_DSP_PARAMETER_SIDECHAIN>(data, length)
+                        .map(DspParameterSidechain::try_from)
+                        .transpose()?
+                        .map(DspData::Sidechain)
+                }
+                DspParameterDataType::Fft => read::<ffi::FMOD_DSP_PARAMETER_FFT>(data, length)
+                    .map(DspParameterFft::try_from)
+                    .transpose()?
+                    .map(DspData::Fft),
+                DspParameterDataType::AttributesMulti3D => {
+                    read::<ffi::FMOD_DSP_PARAMETER_3DATTRIBUTES_MULTI>(data, length)
+                        .map(DspParameterAttributes3dMulti::try_from)
+                        .transpose()?
+                        .map(|value| DspData::Attributes3dMulti(Box::new(value)))
+                }
+                DspParameterDataType::AttenuationRange => {
+                    read::<ffi::FMOD_DSP_PARAMETER_ATTENUATION_RANGE>(data, length)
+                        .map(DspParameterAttenuationRange::try_from)
+                        .transpose()?
+                        .map(DspData::AttenuationRange)
+                }
+            }
+        };
+        value.ok_or_else(|| {
+            err_validation!(
+                "Dsp::get_data_parameter",
+                "data of parameter {} is shorter than its type",
+                index
+            )
+        })
+    }
+
+    /// Sets data parameter, the value must match declared data type of the parameter.
+    pub fn set_data_parameter(&self, index: i32, value: DspData) -> Result<(), Error> {
+        if self.get_data_parameter_type(index)? != value.data_type() {
+            return Err(err_validation!(
+                "Dsp::set_data_parameter",
+                "value doesn't match data type of parameter {}",
+                index
+            ));
+        }
+        match value {
+            DspData::User(mut bytes) => self.set_data(index, bytes.as_mut_ptr(), bytes.len()),
+            DspData::OverallGain(value) => {
+                self.set_struct::<ffi::FMOD_DSP_PARAMETER_OVERALLGAIN>(index, value.into())
+            }
+            DspData::Attributes3d(value) => {
+                self.set_struct::<ffi::FMOD_DSP_PARAMETER_3DATTRIBUTES>(index, value.into())
+            }
+            DspData::Sidechain(value) => {
+                self.set_struct::<ffi::FMOD_DSP_PARAMETER_SIDECHAIN>(index, value.into())
+            }
+            DspData::Fft(_) => Err(err_validation!(
+                "Dsp::set_data_parameter",
+                "FFT data is read only"
+            )),
+            DspData::Attributes3dMulti(value) => self
+                .set_struct::<ffi::FMOD_DSP_PARAMETER_3DATTRIBUTES_MULTI>(index, (*value).into()),
+            DspData::AttenuationRange(value) => {
+                self.set_struct::<ffi::FMOD_DSP_PARAMETER_ATTENUATION_RANGE>(index, value.into())
+            }
+        }
+    }
+
+    fn set_struct<T>(&self, index: i32, mut value: T) -> Result<(), Error> {
+        self.set_data(index, &mut value as *mut T as *mut u8, size_of::<T>())
+    }
+
+    fn set_data(&self, index: i32, data: *mut u8, length: usize) -> Result<(), Error> {
+        self.set_parameter_data(index, data as *mut c_void, length as u32)
+    }
+
+    /// Copies FMOD struct from data parameter, fails if the data is too short.
+    pub(crate) fn parameter_struct<T: Copy>(&self, index: i32) -> Result<T, Error> {
+        let (data, length, _) = self.get_parameter_data(index, 0)?;
+        unsafe { read(data, length) }.ok_or_else(|| {
+            err_validation!(
+                "Dsp::parameter_struct",
+                "data of parameter {} is shorter than its type",
+                index
+            )
+        })
+    }
+
+    /// Raw description, generated `get_parameter_info` copies it into struct with union field.
+    pub(crate) fn parameter_desc(&self, index: i32) -> Result<ffi::FMOD_DSP_PARAMETER_DESC, Error> {
+        let mut desc = null_mut();
+        match unsafe { ffi::FMOD_DSP_GetParameterInfo(self.as_mut_ptr(), index, &mut desc) } {
+            ffi::FMOD_OK if !desc.is_null() => Ok(unsafe { *desc }),
+            ffi::FMOD_OK => Err(err_validation!(
+                "Dsp::parameter_desc",
+                "parameter {} has no description",
+                index
+            )),
+            error => Err(err_fmod!("FMOD_DSP_GetParameterInfo", error)),
+        }
+    }
+}
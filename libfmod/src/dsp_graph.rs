@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::{ffi, Dsp, DspConnectionType, DspMeteringInfo, DspType, Error, System};
+
+/// DSP unit in snapshot of the mixer graph.
+#[derive(Debug, Clone)]
+pub struct DspNode {
+    pub dsp: Dsp,
+    pub dsp_type: DspType,
+    /// Name reported by `Dsp::get_info`.
+    pub name: String,
+    pub active: bool,
+    pub bypass: bool,
+    pub idle: bool,
+    /// Pre wet, post wet and dry levels.
+    pub wet_dry: (f32, f32, f32),
+    /// Input and output levels, present only if metering is enabled for the unit.
+    pub metering: (Option<DspMeteringInfo>, Option<DspMeteringInfo>),
+}
+
+/// Connection in snapshot of the mixer graph, signal goes from input node to output node.
+#[derive(Debug, Clone)]
+pub struct DspEdge {
+    /// Index of node producing signal.
+    pub input: usize,
+    /// Index of node receiving signal.
+    pub output: usize,
+    pub connection_type: DspConnectionType,
+    pub mix: f32,
+}
+
+/// Snapshot of DSP units and connections between them.
+///
+/// Helps to find out why a bus is silent, dump it as Graphviz DOT and render:
+///
+/// ```ignore
+/// std::fs::write("mixer.dot", system.dsp_graph()?.to_dot())?;
+/// // dot -Tsvg mixer.dot -o mixer.svg
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DspGraph {
+    /// Nodes in order of traversal, the first one is the root.
+    pub nodes: Vec<DspNode>,
+    pub edges: Vec<DspEdge>,
+}
+
+impl System {
+    /// Takes snapshot of the mixer graph starting from head DSP of master channel group.
+    pub fn dsp_graph(&self) -> Result<DspGraph, Error> {
+        let master = self.get_master_channel_group()?;
+        DspGraph::from_root(master.get_dsp(ffi::FMOD_CHANNELCONTROL_DSP_HEAD)?)
+    }
+}
+
+impl DspGraph {
+    /// Walks inputs of DSP unit recursively, units reachable by several paths are listed once.
+    pub fn from_root(root: Dsp) -> Result<Self, Error> {
+        let mut graph = DspGraph::default();
+        let mut indices = HashMap::new();
+        graph.visit(root, &mut indices)?;
+        let mut index = 0;
+        while index < graph.nodes.len() {
+            let dsp = graph.nodes[index].dsp;
+            for input in 0..dsp.get_num_inputs()? {
+                let (source, connection) = dsp.get_input(input)?;
+                let source = graph.visit(source, &mut indices)?;
+                graph.edges.push(DspEdge {
+                    input: source,
+                    output: index,
+                    connection_type: connection.get_type()?,
+                    mix: connection.get_mix()?,
+                });
+            }
+            index += 1;
+        }
+        Ok(graph)
+    }
+
+    fn visit(
+        &mut self,
+        dsp: Dsp,
+        indices: &mut HashMap<*mut ffi::FMOD_DSP, usize>,
+    ) -> Result<usize, Error> {
+        if let Some(index) = indices.get(&dsp.as_mut_ptr()) {
+            return Ok(*index);
+        }
+        let (input_metering, output_metering) = dsp.get_metering_enabled()?;
+        let metering = if input_metering || output_metering {
+            let (input, output) = dsp.get_metering_info()?;
+            (
+                Some(input).filter(|_| input_metering),
+                Some(output).filter(|_| output_metering),
+            )
+        } else {
+            (None, None)
+        };
+        let (name, ..) = dsp.get_info()?;
+        self.nodes.push(DspNode {
+            dsp,
+            dsp_type: dsp.get_type()?,
+            name,
+            active: dsp.get_active()?,
+            bypass: dsp.get_bypass()?,
+            idle: dsp.get_idle()?,
+            wet_dry: dsp.get_wet_dry_mix()?,
+            metering,
+        });
+        let index = self.nodes.len() - 1;
+        indices.insert(dsp.as_mut_ptr(), index);
+        Ok(index)
+    }
+
+    /// Graphviz representation, bypassed units are dashed, inactive dotted and idle gray.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph dsp {\n    rankdir=LR;\n    node [shape=box];\n");
+        for (index, node) in self.nodes.iter().enumerate() {
+            let mut label = format!("{}\\n{:?}", dot_escape(&node.name), node.dsp_type);
+            if let Some(peak) = node.metering.1.as_ref().map(peak_level) {
+                let _ = write!(label, "\\npeak {:.2}", peak);
+            }
+            let style = match (node.bypass, node.active) {
+                (true, _) => "dashed",
+                (false, false) => "dotted",
+                (false, true) => "solid",
+            };
+            let color = if node.idle { "gray" } else { "black" };
+            let _ = writeln!(
+                dot,
+                "    n{} [label=\"{}\", style={}, color={}];",
+                index, label, style, color
+            );
+        }
+        for edge in &self.edges {
+            let _ = writeln!(
+                dot,
+                "    n{} -> n{} [label=\"{:?} {:.2}\"];",
+                edge.input, edge.output, edge.connection_type, edge.mix
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// JSON representation, nodes are referenced by index in edges.
+    pub fn to_json(&self) -> String {
+        let nodes: Vec<String> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| {
+                format!(
+                    "{{\"id\":{},\"type\":\"{:?}\",\"name\":{},\"active\":{},\"bypass\":{},\"idle\":{},\"wet_dry\":[{},{},{}],\"metering\":{{\"input\":{},\"output\":{}}}}}",
+                    index,
+                    node.dsp_type,
+                    json_string(&node.name),
+                    node.active,
+                    node.bypass,
+                    node.idle,
+                    json_number(node.wet_dry.0),
+                    json_number(node.wet_dry.1),
+                    json_number(node.wet_dry.2),
+                    json_metering(&node.metering.0),
+                    json_metering(&node.metering.1),
+                )
+            })
+            .collect();
+        let edges: Vec<String> = self
+            .edges
+            .iter()
+            .map(|edge| {
+                format!(
+                    "{{\"input\":{},\"output\":{},\"type\":\"{:?}\",\"mix\":{}}}",
+                    edge.input,
+                    edge.output,
+                    edge.connection_type,
+                    json_number(edge.mix)
+                )
+            })
+            .collect();
+        format!(
+            "{{\"nodes\":[{}],\"edges\":[{}]}}",
+            nodes.join(","),
+            edges.join(",")
+        )
+    }
+}
+
+fn levels(info: &DspMeteringInfo) -> (&[f32], &[f32]) {
+    let channels = (info.numchannels.max(0) as usize).min(info.peaklevel.len());
+    (&info.peaklevel[..channels], &info.rmslevel[..channels])
+}
+
+fn peak_level(info: &DspMeteringInfo) -> f32 {
+    levels(info).0.iter().copied().fold(0.0, f32::max)
+}
+
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::from("\"");
+    for char in value.chars() {
+        match char {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            char if char.is_control() => {
+                let _ = write!(json, "\\u{:04x}", char as u32);
+            }
+            char => json.push(char),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn json_number(value: f32) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+fn json_metering(info: &Option<DspMeteringInfo>) -> String {
+    match info {
+        None => "null".to_string(),
+        Some(info) => {
+            let (peak, rms) = levels(info);
+            let peak: Vec<String> = peak.iter().copied().map(json_number).collect();
+            let rms: Vec<String> = rms.iter().copied().map(json_number).collect();
+            format!(
+                "{{\"numsamples\":{},\"peak\":[{}],\"rms\":[{}]}}",
+                info.numsamples,
+                peak.join(","),
+                rms.join(",")
+            )
+        }
+    }
+}
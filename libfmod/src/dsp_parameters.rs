@@ -0,0 +1,264 @@
+use std::ffi::{c_char, CStr};
+
+use crate::{
+    ffi, Dsp, DspData, DspParameterDataType, DspParameterFloatMapping,
+    DspParameterFloatMappingPiecewiseLinear, DspParameterFloatMappingType, Error,
+};
+
+/// Range and default value of DSP parameter by its type.
+#[derive(Debug, Clone)]
+pub enum DspParameterKind {
+    Float {
+        min: f32,
+        max: f32,
+        default: f32,
+        /// How values are distributed along UI control, e.g. frequency is shown on log scale.
+        mapping: DspParameterFloatMapping,
+    },
+    Int {
+        min: i32,
+        max: i32,
+        default: i32,
+        /// The maximum value stands for infinity, e.g. infinite loop count.
+        goes_to_infinity: bool,
+        /// Names of values from minimum to maximum, empty if values are plain numbers.
+        value_names: Vec<String>,
+    },
+    Bool {
+        default: bool,
+        /// Names of false and true values, empty if not specified.
+        value_names: Vec<String>,
+    },
+    Data {
+        data_type: DspParameterDataType,
+    },
+}
+
+/// Value of DSP parameter, converted from plain values to be set by name.
+#[derive(Debug, Clone)]
+pub enum DspParameterValue {
+    Float(f32),
+    Int(i32),
+    Bool(bool),
+    Data(DspData),
+}
+
+impl From<f32> for DspParameterValue {
+    fn from(value: f32) -> Self {
+        DspParameterValue::Float(value)
+    }
+}
+
+impl From<i32> for DspParameterValue {
+    fn from(value: i32) -> Self {
+        DspParameterValue::Int(value)
+    }
+}
+
+impl From<bool> for DspParameterValue {
+    fn from(value: bool) -> Self {
+        DspParameterValue::Bool(value)
+    }
+}
+
+impl From<DspData> for DspParameterValue {
+    fn from(value: DspData) -> Self {
+        DspParameterValue::Data(value)
+    }
+}
+
+/// DSP parameter description with its current value, e.g. to build effect editor UI.
+#[derive(Debug, Clone)]
+pub struct DspParameter {
+    pub index: i32,
+    pub name: String,
+    /// Unit of the value, e.g. "dB" or "Hz".
+    pub label: String,
+    pub description: String,
+    pub kind: DspParameterKind,
+    /// Current value, `None` if data parameter can't be read, e.g. plugin data is too short.
+    pub value: Option<DspParameterValue>,
+    /// Value formatted by DSP, e.g. "-6.00" or "Hamming".
+    pub value_string: String,
+}
+
+impl DspParameter {
+    /// Name of the current value if parameter has value names.
+    pub fn value_name(&self) -> Option<&str> {
+        let (names, index) = match (&self.kind, self.value.as_ref()?) {
+            (
+                DspParameterKind::Int {
+                    min, value_names, ..
+                },
+                DspParameterValue::Int(value),
+            ) => (value_names, usize::try_from(value - min).ok()?),
+            (DspParameterKind::Bool { value_names, .. }, DspParameterValue::Bool(value)) => {
+                (value_names, *value as usize)
+            }
+            _ => return None,
+        };
+        names.get(index).map(String::as_str)
+    }
+}
+
+/// Converts C string of parameter name or value, it may be not terminated if fills the array.
+fn text(chars: &[c_char]) -> String {
+    let bytes: Vec<u8> = chars
+        .iter()
+        .map(|&char| char as u8)
+        .take_while(|&byte| byte != 0)
+        .collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+unsafe fn string(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        return String::new();
+    }
+    CStr::from_ptr(ptr).to_string_lossy().into_owned()
+}
+
+unsafe fn names(ptr: *const *const c_char, count: usize) -> Vec<String> {
+    if ptr.is_null() {
+        return vec![];
+    }
+    (0..count).map(|index| string(*ptr.add(index))).collect()
+}
+
+unsafe fn mapping(
+    value: ffi::FMOD_DSP_PARAMETER_FLOAT_MAPPING,
+) -> Result<DspParameterFloatMapping, Error> {
+    let type_ = DspParameterFloatMappingType::from(value.type_)?;
+    let linear = value.piecewiselinearmapping;
+    let points = |ptr: *mut f32| {
+        if type_ != DspParameterFloatMappingType::PiecewiseLinear || ptr.is_null() {
+            return vec![];
+        }
+        std::slice::from_raw_parts(ptr, linear.numpoints.max(0) as usize).to_vec()
+    };
+    Ok(DspParameterFloatMapping {
+        type_,
+        piecewiselinearmapping: DspParameterFloatMappingPiecewiseLinear {
+            numpoints: linear.numpoints,
+            pointparamvalues: points(linear.pointparamvalues),
+            pointpositions: points(linear.pointpositions),
+        },
+    })
+}
+
+impl Dsp {
+    /// Describes all parameters with their current values.
+    pub fn parameters(&self) -> Result<Vec<DspParameter>, Error> {
+        (0..self.get_num_parameters()?)
+            .map(|index| self.parameter(index))
+            .collect()
+    }
+
+    pub fn parameter(&self, index: i32) -> Result<DspParameter, Error> {
+        let desc = self.parameter_desc(index)?;
+        let length = ffi::FMOD_DSP_GETPARAM_VALUESTR_LENGTH as i32;
+        let (kind, value, value_string) = unsafe {
+            match desc.type_ {
+                ffi::FMOD_DSP_PARAMETER_TYPE_FLOAT => {
+                    let float = desc.union.floatdesc;
+                    let kind = DspParameterKind::Float {
+                        min: float.min,
+                        max: float.max,
+                        default: float.defaultval,
+                        mapping: mapping(float.mapping)?,
+                    };
+                    let (value, value_string) = self.get_parameter_float(index, length)?;
+                    (kind, Some(DspParameterValue::Float(value)), value_string)
+                }
+                ffi::FMOD_DSP_PARAMETER_TYPE_INT => {
+                    let int = desc.union.intdesc;
+                    let count = (int.max - int.min + 1).max(0) as usize;
+                    let kind = DspParameterKind::Int {
+                        min: int.min,
+                        max: int.max,
+                        default: int.defaultval,
+                        goes_to_infinity: int.goestoinf != 0,
+                        value_names: names(int.valuenames, count),
+                    };
+                    let (value, value_string) = self.get_parameter_int(index, length)?;
+                    (kind, Some(DspParameterValue::Int(value)), value_string)
+                }
+                ffi::FMOD_DSP_PARAMETER_TYPE_BOOL => {
+                    let bool = desc.union.booldesc;
+                    let kind = DspParameterKind::Bool {
+                        default: bool.defaultval != 0,
+                        value_names: names(bool.valuenames, 2),
+                    };
+                    let (value, value_string) = self.get_parameter_bool(index, length)?;
+                    (kind, Some(DspParameterValue::Bool(value)), value_string)
+                }
+                _ => {
+                    let kind = DspParameterKind::Data {
+                        data_type: self.get_data_parameter_type(index)?,
+                    };
+                    let (data, size, value_string) = self.get_parameter_data(index, length)?;
+                    let value = self.decode_data_parameter(index, data, size).ok();
+                    (kind, value.map(DspParameterValue::Data), value_string)
+                }
+            }
+        };
+        Ok(DspParameter {
+            index,
+            name: text(&desc.name),
+            label: text(&desc.label),
+            description: unsafe { string(desc.description) },
+            kind,
+            value,
+            value_string,
+        })
+    }
+
+    /// Index of parameter with given name as shown by `parameters`.
+    pub fn parameter_index(&self, name: &str) -> Result<i32, Error> {
+        for index in 0..self.get_num_parameters()? {
+            if text(&self.parameter_desc(index)?.name) == name {
+                return Ok(index);
+            }
+        }
+        Err(err_validation!(
+            "Dsp::parameter_index",
+            "parameter {} is not found",
+            name
+        ))
+    }
+
+    /// Sets parameter found by name, the value must match parameter type.
+    ///
+    /// ```ignore
+    /// equalizer.set_by_name("Low Gain", -6.0)?;
+    /// equalizer.set_by_name("Crossover Slope", 2)?;
+    /// ```
+    pub fn set_by_name(
+        &self,
+        name: &str,
+        value: impl Into<DspParameterValue>,
+    ) -> Result<(), Error> {
+        let index = self.parameter_index(name)?;
+        let type_ = self.parameter_desc(index)?.type_;
+        match (type_, value.into()) {
+            (ffi::FMOD_DSP_PARAMETER_TYPE_FLOAT, DspParameterValue::Float(value)) => {
+                self.set_parameter_float(index, value)
+            }
+            (ffi::FMOD_DSP_PARAMETER_TYPE_INT, DspParameterValue::Int(value)) => {
+                self.set_parameter_int(index, value)
+            }
+            (ffi::FMOD_DSP_PARAMETER_TYPE_BOOL, DspParameterValue::Bool(value)) => {
+                self.set_parameter_bool(index, value)
+            }
+            (ffi::FMOD_DSP_PARAMETER_TYPE_DATA, DspParameterValue::Data(value)) => {
+                self.set_data_parameter(index, value)
+            }
+            (_, value) => Err(err_validation!(
+                "Dsp::set_by_name",
+                "{:?} doesn't match type of parameter {}",
+                value,
+                name
+            )),
+        }
+    }
+}
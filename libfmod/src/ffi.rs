@@ -1,6 +1,11 @@
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
 #![allow(unused_parens)]
+#![allow(
+    clippy::missing_safety_doc,
+    clippy::too_many_arguments,
+    clippy::type_complexity
+)]
 use std::os::raw::{
     c_char, c_float, c_int, c_longlong, c_short, c_uchar, c_uint, c_ulonglong, c_ushort, c_void,
 };
@@ -3057,6 +3062,7 @@ pub type FMOD_DSP_PAN_GETROLLOFFGAIN_FUNC = Option<
         gain: *mut c_float,
     ) -> FMOD_RESULT,
 >;
+#[cfg(not(any(feature = "dynamic", feature = "stub")))]
 extern "C" {
     pub fn FMOD_Studio_ParseID(idstring: *const c_char, id: *mut FMOD_GUID) -> FMOD_RESULT;
     pub fn FMOD_Studio_System_Create(
@@ -3864,6 +3870,7 @@ extern "C" {
         userdata: *mut c_void,
     ) -> FMOD_RESULT;
 }
+#[cfg(not(any(feature = "dynamic", feature = "stub")))]
 extern "C" {
     pub fn FMOD_Memory_Initialize(
         poolmem: *mut c_void,
@@ -5530,3 +5537,10245 @@ extern "C" {
         userdata: *mut *mut c_void,
     ) -> FMOD_RESULT;
 }
+#[cfg(feature = "dynamic")]
+pub struct Library {
+    _fmod: libloading::Library,
+    _fmodstudio: libloading::Library,
+    FMOD_Memory_Initialize: unsafe extern "C" fn(
+        poolmem: *mut c_void,
+        poollen: c_int,
+        useralloc: FMOD_MEMORY_ALLOC_CALLBACK,
+        userrealloc: FMOD_MEMORY_REALLOC_CALLBACK,
+        userfree: FMOD_MEMORY_FREE_CALLBACK,
+        memtypeflags: FMOD_MEMORY_TYPE,
+    ) -> FMOD_RESULT,
+    FMOD_Memory_GetStats: unsafe extern "C" fn(
+        currentalloced: *mut c_int,
+        maxalloced: *mut c_int,
+        blocking: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Debug_Initialize: unsafe extern "C" fn(
+        flags: FMOD_DEBUG_FLAGS,
+        mode: FMOD_DEBUG_MODE,
+        callback: FMOD_DEBUG_CALLBACK,
+        filename: *const c_char,
+    ) -> FMOD_RESULT,
+    FMOD_File_SetDiskBusy: unsafe extern "C" fn(busy: c_int) -> FMOD_RESULT,
+    FMOD_File_GetDiskBusy: unsafe extern "C" fn(busy: *mut c_int) -> FMOD_RESULT,
+    FMOD_Thread_SetAttributes: unsafe extern "C" fn(
+        type_: FMOD_THREAD_TYPE,
+        affinity: FMOD_THREAD_AFFINITY,
+        priority: FMOD_THREAD_PRIORITY,
+        stacksize: FMOD_THREAD_STACK_SIZE,
+    ) -> FMOD_RESULT,
+    FMOD_System_Create:
+        unsafe extern "C" fn(system: *mut *mut FMOD_SYSTEM, headerversion: c_uint) -> FMOD_RESULT,
+    FMOD_System_Release: unsafe extern "C" fn(system: *mut FMOD_SYSTEM) -> FMOD_RESULT,
+    FMOD_System_SetOutput:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, output: FMOD_OUTPUTTYPE) -> FMOD_RESULT,
+    FMOD_System_GetOutput:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, output: *mut FMOD_OUTPUTTYPE) -> FMOD_RESULT,
+    FMOD_System_GetNumDrivers:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, numdrivers: *mut c_int) -> FMOD_RESULT,
+    FMOD_System_GetDriverInfo: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        id: c_int,
+        name: *mut c_char,
+        namelen: c_int,
+        guid: *mut FMOD_GUID,
+        systemrate: *mut c_int,
+        speakermode: *mut FMOD_SPEAKERMODE,
+        speakermodechannels: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_SetDriver:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, driver: c_int) -> FMOD_RESULT,
+    FMOD_System_GetDriver:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, driver: *mut c_int) -> FMOD_RESULT,
+    FMOD_System_SetSoftwareChannels:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, numsoftwarechannels: c_int) -> FMOD_RESULT,
+    FMOD_System_GetSoftwareChannels: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        numsoftwarechannels: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_SetSoftwareFormat: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        samplerate: c_int,
+        speakermode: FMOD_SPEAKERMODE,
+        numrawspeakers: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetSoftwareFormat: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        samplerate: *mut c_int,
+        speakermode: *mut FMOD_SPEAKERMODE,
+        numrawspeakers: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_SetDSPBufferSize: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        bufferlength: c_uint,
+        numbuffers: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetDSPBufferSize: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        bufferlength: *mut c_uint,
+        numbuffers: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_SetFileSystem: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        useropen: FMOD_FILE_OPEN_CALLBACK,
+        userclose: FMOD_FILE_CLOSE_CALLBACK,
+        userread: FMOD_FILE_READ_CALLBACK,
+        userseek: FMOD_FILE_SEEK_CALLBACK,
+        userasyncread: FMOD_FILE_ASYNCREAD_CALLBACK,
+        userasynccancel: FMOD_FILE_ASYNCCANCEL_CALLBACK,
+        blockalign: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_AttachFileSystem: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        useropen: FMOD_FILE_OPEN_CALLBACK,
+        userclose: FMOD_FILE_CLOSE_CALLBACK,
+        userread: FMOD_FILE_READ_CALLBACK,
+        userseek: FMOD_FILE_SEEK_CALLBACK,
+    ) -> FMOD_RESULT,
+    FMOD_System_SetAdvancedSettings: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        settings: *mut FMOD_ADVANCEDSETTINGS,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetAdvancedSettings: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        settings: *mut FMOD_ADVANCEDSETTINGS,
+    ) -> FMOD_RESULT,
+    FMOD_System_SetCallback: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        callback: FMOD_SYSTEM_CALLBACK,
+        callbackmask: FMOD_SYSTEM_CALLBACK_TYPE,
+    ) -> FMOD_RESULT,
+    FMOD_System_SetPluginPath:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, path: *const c_char) -> FMOD_RESULT,
+    FMOD_System_LoadPlugin: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        filename: *const c_char,
+        handle: *mut c_uint,
+        priority: c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_System_UnloadPlugin:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, handle: c_uint) -> FMOD_RESULT,
+    FMOD_System_GetNumNestedPlugins: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        handle: c_uint,
+        count: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetNestedPlugin: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        handle: c_uint,
+        index: c_int,
+        nestedhandle: *mut c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetNumPlugins: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        plugintype: FMOD_PLUGINTYPE,
+        numplugins: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetPluginHandle: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        plugintype: FMOD_PLUGINTYPE,
+        index: c_int,
+        handle: *mut c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetPluginInfo: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        handle: c_uint,
+        plugintype: *mut FMOD_PLUGINTYPE,
+        name: *mut c_char,
+        namelen: c_int,
+        version: *mut c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_System_SetOutputByPlugin:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, handle: c_uint) -> FMOD_RESULT,
+    FMOD_System_GetOutputByPlugin:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, handle: *mut c_uint) -> FMOD_RESULT,
+    FMOD_System_CreateDSPByPlugin: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        handle: c_uint,
+        dsp: *mut *mut FMOD_DSP,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetDSPInfoByPlugin: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        handle: c_uint,
+        description: *const *const FMOD_DSP_DESCRIPTION,
+    ) -> FMOD_RESULT,
+    FMOD_System_RegisterCodec: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        description: *mut FMOD_CODEC_DESCRIPTION,
+        handle: *mut c_uint,
+        priority: c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_System_RegisterDSP: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        description: *const FMOD_DSP_DESCRIPTION,
+        handle: *mut c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_System_RegisterOutput: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        description: *const FMOD_OUTPUT_DESCRIPTION,
+        handle: *mut c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_System_Init: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        maxchannels: c_int,
+        flags: FMOD_INITFLAGS,
+        extradriverdata: *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_System_Close: unsafe extern "C" fn(system: *mut FMOD_SYSTEM) -> FMOD_RESULT,
+    FMOD_System_Update: unsafe extern "C" fn(system: *mut FMOD_SYSTEM) -> FMOD_RESULT,
+    FMOD_System_SetSpeakerPosition: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        speaker: FMOD_SPEAKER,
+        x: c_float,
+        y: c_float,
+        active: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetSpeakerPosition: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        speaker: FMOD_SPEAKER,
+        x: *mut c_float,
+        y: *mut c_float,
+        active: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_System_SetStreamBufferSize: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        filebuffersize: c_uint,
+        filebuffersizetype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetStreamBufferSize: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        filebuffersize: *mut c_uint,
+        filebuffersizetype: *mut FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT,
+    FMOD_System_Set3DSettings: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        dopplerscale: c_float,
+        distancefactor: c_float,
+        rolloffscale: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_System_Get3DSettings: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        dopplerscale: *mut c_float,
+        distancefactor: *mut c_float,
+        rolloffscale: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_System_Set3DNumListeners:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, numlisteners: c_int) -> FMOD_RESULT,
+    FMOD_System_Get3DNumListeners:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, numlisteners: *mut c_int) -> FMOD_RESULT,
+    FMOD_System_Set3DListenerAttributes: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        listener: c_int,
+        pos: *const FMOD_VECTOR,
+        vel: *const FMOD_VECTOR,
+        forward: *const FMOD_VECTOR,
+        up: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_System_Get3DListenerAttributes: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        listener: c_int,
+        pos: *mut FMOD_VECTOR,
+        vel: *mut FMOD_VECTOR,
+        forward: *mut FMOD_VECTOR,
+        up: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_System_Set3DRolloffCallback: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        callback: FMOD_3D_ROLLOFF_CALLBACK,
+    ) -> FMOD_RESULT,
+    FMOD_System_MixerSuspend: unsafe extern "C" fn(system: *mut FMOD_SYSTEM) -> FMOD_RESULT,
+    FMOD_System_MixerResume: unsafe extern "C" fn(system: *mut FMOD_SYSTEM) -> FMOD_RESULT,
+    FMOD_System_GetDefaultMixMatrix: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        sourcespeakermode: FMOD_SPEAKERMODE,
+        targetspeakermode: FMOD_SPEAKERMODE,
+        matrix: *mut c_float,
+        matrixhop: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetSpeakerModeChannels: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        mode: FMOD_SPEAKERMODE,
+        channels: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetVersion:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, version: *mut c_uint) -> FMOD_RESULT,
+    FMOD_System_GetOutputHandle:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, handle: *mut *mut c_void) -> FMOD_RESULT,
+    FMOD_System_GetChannelsPlaying: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        channels: *mut c_int,
+        realchannels: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetCPUUsage:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, usage: *mut FMOD_CPU_USAGE) -> FMOD_RESULT,
+    FMOD_System_GetFileUsage: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        sampleBytesRead: *mut c_longlong,
+        streamBytesRead: *mut c_longlong,
+        otherBytesRead: *mut c_longlong,
+    ) -> FMOD_RESULT,
+    FMOD_System_CreateSound: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        name_or_data: *const c_char,
+        mode: FMOD_MODE,
+        exinfo: *mut FMOD_CREATESOUNDEXINFO,
+        sound: *mut *mut FMOD_SOUND,
+    ) -> FMOD_RESULT,
+    FMOD_System_CreateStream: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        name_or_data: *const c_char,
+        mode: FMOD_MODE,
+        exinfo: *mut FMOD_CREATESOUNDEXINFO,
+        sound: *mut *mut FMOD_SOUND,
+    ) -> FMOD_RESULT,
+    FMOD_System_CreateDSP: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        description: *const FMOD_DSP_DESCRIPTION,
+        dsp: *mut *mut FMOD_DSP,
+    ) -> FMOD_RESULT,
+    FMOD_System_CreateDSPByType: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        type_: FMOD_DSP_TYPE,
+        dsp: *mut *mut FMOD_DSP,
+    ) -> FMOD_RESULT,
+    FMOD_System_CreateChannelGroup: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        name: *const c_char,
+        channelgroup: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT,
+    FMOD_System_CreateSoundGroup: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        name: *const c_char,
+        soundgroup: *mut *mut FMOD_SOUNDGROUP,
+    ) -> FMOD_RESULT,
+    FMOD_System_CreateReverb3D: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        reverb: *mut *mut FMOD_REVERB3D,
+    ) -> FMOD_RESULT,
+    FMOD_System_PlaySound: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        sound: *mut FMOD_SOUND,
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        paused: FMOD_BOOL,
+        channel: *mut *mut FMOD_CHANNEL,
+    ) -> FMOD_RESULT,
+    FMOD_System_PlayDSP: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        dsp: *mut FMOD_DSP,
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        paused: FMOD_BOOL,
+        channel: *mut *mut FMOD_CHANNEL,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetChannel: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        channelid: c_int,
+        channel: *mut *mut FMOD_CHANNEL,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetDSPInfoByType: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        type_: FMOD_DSP_TYPE,
+        description: *const *const FMOD_DSP_DESCRIPTION,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetMasterChannelGroup: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        channelgroup: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetMasterSoundGroup: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        soundgroup: *mut *mut FMOD_SOUNDGROUP,
+    ) -> FMOD_RESULT,
+    FMOD_System_AttachChannelGroupToPort: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        portType: FMOD_PORT_TYPE,
+        portIndex: FMOD_PORT_INDEX,
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        passThru: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_System_DetachChannelGroupFromPort: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        channelgroup: *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT,
+    FMOD_System_SetReverbProperties: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        instance: c_int,
+        prop: *const FMOD_REVERB_PROPERTIES,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetReverbProperties: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        instance: c_int,
+        prop: *mut FMOD_REVERB_PROPERTIES,
+    ) -> FMOD_RESULT,
+    FMOD_System_LockDSP: unsafe extern "C" fn(system: *mut FMOD_SYSTEM) -> FMOD_RESULT,
+    FMOD_System_UnlockDSP: unsafe extern "C" fn(system: *mut FMOD_SYSTEM) -> FMOD_RESULT,
+    FMOD_System_GetRecordNumDrivers: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        numdrivers: *mut c_int,
+        numconnected: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetRecordDriverInfo: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        id: c_int,
+        name: *mut c_char,
+        namelen: c_int,
+        guid: *mut FMOD_GUID,
+        systemrate: *mut c_int,
+        speakermode: *mut FMOD_SPEAKERMODE,
+        speakermodechannels: *mut c_int,
+        state: *mut FMOD_DRIVER_STATE,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetRecordPosition: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        id: c_int,
+        position: *mut c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_System_RecordStart: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        id: c_int,
+        sound: *mut FMOD_SOUND,
+        loop_: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_System_RecordStop:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, id: c_int) -> FMOD_RESULT,
+    FMOD_System_IsRecording: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        id: c_int,
+        recording: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_System_CreateGeometry: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        maxpolygons: c_int,
+        maxvertices: c_int,
+        geometry: *mut *mut FMOD_GEOMETRY,
+    ) -> FMOD_RESULT,
+    FMOD_System_SetGeometrySettings:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, maxworldsize: c_float) -> FMOD_RESULT,
+    FMOD_System_GetGeometrySettings:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, maxworldsize: *mut c_float) -> FMOD_RESULT,
+    FMOD_System_LoadGeometry: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        data: *const c_void,
+        datasize: c_int,
+        geometry: *mut *mut FMOD_GEOMETRY,
+    ) -> FMOD_RESULT,
+    FMOD_System_GetGeometryOcclusion: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        listener: *const FMOD_VECTOR,
+        source: *const FMOD_VECTOR,
+        direct: *mut c_float,
+        reverb: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_System_SetNetworkProxy:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, proxy: *const c_char) -> FMOD_RESULT,
+    FMOD_System_GetNetworkProxy: unsafe extern "C" fn(
+        system: *mut FMOD_SYSTEM,
+        proxy: *mut c_char,
+        proxylen: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_System_SetNetworkTimeout:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, timeout: c_int) -> FMOD_RESULT,
+    FMOD_System_GetNetworkTimeout:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, timeout: *mut c_int) -> FMOD_RESULT,
+    FMOD_System_SetUserData:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, userdata: *mut c_void) -> FMOD_RESULT,
+    FMOD_System_GetUserData:
+        unsafe extern "C" fn(system: *mut FMOD_SYSTEM, userdata: *mut *mut c_void) -> FMOD_RESULT,
+    FMOD_Sound_Release: unsafe extern "C" fn(sound: *mut FMOD_SOUND) -> FMOD_RESULT,
+    FMOD_Sound_GetSystemObject:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, system: *mut *mut FMOD_SYSTEM) -> FMOD_RESULT,
+    FMOD_Sound_Lock: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        offset: c_uint,
+        length: c_uint,
+        ptr1: *mut *mut c_void,
+        ptr2: *mut *mut c_void,
+        len1: *mut c_uint,
+        len2: *mut c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_Unlock: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        ptr1: *mut c_void,
+        ptr2: *mut c_void,
+        len1: c_uint,
+        len2: c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_SetDefaults: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        frequency: c_float,
+        priority: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetDefaults: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        frequency: *mut c_float,
+        priority: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_Set3DMinMaxDistance:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, min: c_float, max: c_float) -> FMOD_RESULT,
+    FMOD_Sound_Get3DMinMaxDistance: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        min: *mut c_float,
+        max: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_Set3DConeSettings: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        insideconeangle: c_float,
+        outsideconeangle: c_float,
+        outsidevolume: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_Get3DConeSettings: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        insideconeangle: *mut c_float,
+        outsideconeangle: *mut c_float,
+        outsidevolume: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_Set3DCustomRolloff: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        points: *mut FMOD_VECTOR,
+        numpoints: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_Get3DCustomRolloff: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        points: *mut *mut FMOD_VECTOR,
+        numpoints: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetSubSound: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        index: c_int,
+        subsound: *mut *mut FMOD_SOUND,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetSubSoundParent: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        parentsound: *mut *mut FMOD_SOUND,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetName: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        name: *mut c_char,
+        namelen: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetLength: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        length: *mut c_uint,
+        lengthtype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetFormat: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        type_: *mut FMOD_SOUND_TYPE,
+        format: *mut FMOD_SOUND_FORMAT,
+        channels: *mut c_int,
+        bits: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetNumSubSounds:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, numsubsounds: *mut c_int) -> FMOD_RESULT,
+    FMOD_Sound_GetNumTags: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        numtags: *mut c_int,
+        numtagsupdated: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetTag: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        name: *const c_char,
+        index: c_int,
+        tag: *mut FMOD_TAG,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetOpenState: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        openstate: *mut FMOD_OPENSTATE,
+        percentbuffered: *mut c_uint,
+        starving: *mut FMOD_BOOL,
+        diskbusy: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_ReadData: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        buffer: *mut c_void,
+        length: c_uint,
+        read: *mut c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_SeekData: unsafe extern "C" fn(sound: *mut FMOD_SOUND, pcm: c_uint) -> FMOD_RESULT,
+    FMOD_Sound_SetSoundGroup: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        soundgroup: *mut FMOD_SOUNDGROUP,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetSoundGroup: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        soundgroup: *mut *mut FMOD_SOUNDGROUP,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetNumSyncPoints:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, numsyncpoints: *mut c_int) -> FMOD_RESULT,
+    FMOD_Sound_GetSyncPoint: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        index: c_int,
+        point: *mut *mut FMOD_SYNCPOINT,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetSyncPointInfo: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        point: *mut FMOD_SYNCPOINT,
+        name: *mut c_char,
+        namelen: c_int,
+        offset: *mut c_uint,
+        offsettype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_AddSyncPoint: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        offset: c_uint,
+        offsettype: FMOD_TIMEUNIT,
+        name: *const c_char,
+        point: *mut *mut FMOD_SYNCPOINT,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_DeleteSyncPoint:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, point: *mut FMOD_SYNCPOINT) -> FMOD_RESULT,
+    FMOD_Sound_SetMode:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, mode: FMOD_MODE) -> FMOD_RESULT,
+    FMOD_Sound_GetMode:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, mode: *mut FMOD_MODE) -> FMOD_RESULT,
+    FMOD_Sound_SetLoopCount:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, loopcount: c_int) -> FMOD_RESULT,
+    FMOD_Sound_GetLoopCount:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, loopcount: *mut c_int) -> FMOD_RESULT,
+    FMOD_Sound_SetLoopPoints: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        loopstart: c_uint,
+        loopstarttype: FMOD_TIMEUNIT,
+        loopend: c_uint,
+        loopendtype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetLoopPoints: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        loopstart: *mut c_uint,
+        loopstarttype: FMOD_TIMEUNIT,
+        loopend: *mut c_uint,
+        loopendtype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetMusicNumChannels:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, numchannels: *mut c_int) -> FMOD_RESULT,
+    FMOD_Sound_SetMusicChannelVolume: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        channel: c_int,
+        volume: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_GetMusicChannelVolume: unsafe extern "C" fn(
+        sound: *mut FMOD_SOUND,
+        channel: c_int,
+        volume: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Sound_SetMusicSpeed:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, speed: c_float) -> FMOD_RESULT,
+    FMOD_Sound_GetMusicSpeed:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, speed: *mut c_float) -> FMOD_RESULT,
+    FMOD_Sound_SetUserData:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, userdata: *mut c_void) -> FMOD_RESULT,
+    FMOD_Sound_GetUserData:
+        unsafe extern "C" fn(sound: *mut FMOD_SOUND, userdata: *mut *mut c_void) -> FMOD_RESULT,
+    FMOD_Channel_GetSystemObject: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        system: *mut *mut FMOD_SYSTEM,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Stop: unsafe extern "C" fn(channel: *mut FMOD_CHANNEL) -> FMOD_RESULT,
+    FMOD_Channel_SetPaused:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, paused: FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Channel_GetPaused:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, paused: *mut FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Channel_SetVolume:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, volume: c_float) -> FMOD_RESULT,
+    FMOD_Channel_GetVolume:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, volume: *mut c_float) -> FMOD_RESULT,
+    FMOD_Channel_SetVolumeRamp:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, ramp: FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Channel_GetVolumeRamp:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, ramp: *mut FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Channel_GetAudibility:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, audibility: *mut c_float) -> FMOD_RESULT,
+    FMOD_Channel_SetPitch:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, pitch: c_float) -> FMOD_RESULT,
+    FMOD_Channel_GetPitch:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, pitch: *mut c_float) -> FMOD_RESULT,
+    FMOD_Channel_SetMute:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, mute: FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Channel_GetMute:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, mute: *mut FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Channel_SetReverbProperties: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        instance: c_int,
+        wet: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_GetReverbProperties: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        instance: c_int,
+        wet: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_SetLowPassGain:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, gain: c_float) -> FMOD_RESULT,
+    FMOD_Channel_GetLowPassGain:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, gain: *mut c_float) -> FMOD_RESULT,
+    FMOD_Channel_SetMode:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, mode: FMOD_MODE) -> FMOD_RESULT,
+    FMOD_Channel_GetMode:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, mode: *mut FMOD_MODE) -> FMOD_RESULT,
+    FMOD_Channel_SetCallback: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        callback: FMOD_CHANNELCONTROL_CALLBACK,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_IsPlaying:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, isplaying: *mut FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Channel_SetPan:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, pan: c_float) -> FMOD_RESULT,
+    FMOD_Channel_SetMixLevelsOutput: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        frontleft: c_float,
+        frontright: c_float,
+        center: c_float,
+        lfe: c_float,
+        surroundleft: c_float,
+        surroundright: c_float,
+        backleft: c_float,
+        backright: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_SetMixLevelsInput: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        levels: *mut c_float,
+        numlevels: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_SetMixMatrix: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        matrix: *mut c_float,
+        outchannels: c_int,
+        inchannels: c_int,
+        inchannel_hop: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_GetMixMatrix: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        matrix: *mut c_float,
+        outchannels: *mut c_int,
+        inchannels: *mut c_int,
+        inchannel_hop: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_GetDSPClock: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        dspclock: *mut c_ulonglong,
+        parentclock: *mut c_ulonglong,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_SetDelay: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        dspclock_start: c_ulonglong,
+        dspclock_end: c_ulonglong,
+        stopchannels: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_GetDelay: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        dspclock_start: *mut c_ulonglong,
+        dspclock_end: *mut c_ulonglong,
+        stopchannels: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_AddFadePoint: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        dspclock: c_ulonglong,
+        volume: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_SetFadePointRamp: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        dspclock: c_ulonglong,
+        volume: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_RemoveFadePoints: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        dspclock_start: c_ulonglong,
+        dspclock_end: c_ulonglong,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_GetFadePoints: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        numpoints: *mut c_uint,
+        point_dspclock: *mut c_ulonglong,
+        point_volume: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_GetDSP: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        index: c_int,
+        dsp: *mut *mut FMOD_DSP,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_AddDSP: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        index: c_int,
+        dsp: *mut FMOD_DSP,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_RemoveDSP:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, dsp: *mut FMOD_DSP) -> FMOD_RESULT,
+    FMOD_Channel_GetNumDSPs:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, numdsps: *mut c_int) -> FMOD_RESULT,
+    FMOD_Channel_SetDSPIndex: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        dsp: *mut FMOD_DSP,
+        index: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_GetDSPIndex: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        dsp: *mut FMOD_DSP,
+        index: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Set3DAttributes: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        pos: *const FMOD_VECTOR,
+        vel: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Get3DAttributes: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        pos: *mut FMOD_VECTOR,
+        vel: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Set3DMinMaxDistance: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        mindistance: c_float,
+        maxdistance: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Get3DMinMaxDistance: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        mindistance: *mut c_float,
+        maxdistance: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Set3DConeSettings: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        insideconeangle: c_float,
+        outsideconeangle: c_float,
+        outsidevolume: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Get3DConeSettings: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        insideconeangle: *mut c_float,
+        outsideconeangle: *mut c_float,
+        outsidevolume: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Set3DConeOrientation: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        orientation: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Get3DConeOrientation: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        orientation: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Set3DCustomRolloff: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        points: *mut FMOD_VECTOR,
+        numpoints: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Get3DCustomRolloff: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        points: *mut *mut FMOD_VECTOR,
+        numpoints: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Set3DOcclusion: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        directocclusion: c_float,
+        reverbocclusion: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Get3DOcclusion: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        directocclusion: *mut c_float,
+        reverbocclusion: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Set3DSpread:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, angle: c_float) -> FMOD_RESULT,
+    FMOD_Channel_Get3DSpread:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, angle: *mut c_float) -> FMOD_RESULT,
+    FMOD_Channel_Set3DLevel:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, level: c_float) -> FMOD_RESULT,
+    FMOD_Channel_Get3DLevel:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, level: *mut c_float) -> FMOD_RESULT,
+    FMOD_Channel_Set3DDopplerLevel:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, level: c_float) -> FMOD_RESULT,
+    FMOD_Channel_Get3DDopplerLevel:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, level: *mut c_float) -> FMOD_RESULT,
+    FMOD_Channel_Set3DDistanceFilter: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        custom: FMOD_BOOL,
+        customLevel: c_float,
+        centerFreq: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_Get3DDistanceFilter: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        custom: *mut FMOD_BOOL,
+        customLevel: *mut c_float,
+        centerFreq: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_SetUserData:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, userdata: *mut c_void) -> FMOD_RESULT,
+    FMOD_Channel_GetUserData:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, userdata: *mut *mut c_void) -> FMOD_RESULT,
+    FMOD_Channel_SetFrequency:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, frequency: c_float) -> FMOD_RESULT,
+    FMOD_Channel_GetFrequency:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, frequency: *mut c_float) -> FMOD_RESULT,
+    FMOD_Channel_SetPriority:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, priority: c_int) -> FMOD_RESULT,
+    FMOD_Channel_GetPriority:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, priority: *mut c_int) -> FMOD_RESULT,
+    FMOD_Channel_SetPosition: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        position: c_uint,
+        postype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_GetPosition: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        position: *mut c_uint,
+        postype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_SetChannelGroup: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        channelgroup: *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_GetChannelGroup: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        channelgroup: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_SetLoopCount:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, loopcount: c_int) -> FMOD_RESULT,
+    FMOD_Channel_GetLoopCount:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, loopcount: *mut c_int) -> FMOD_RESULT,
+    FMOD_Channel_SetLoopPoints: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        loopstart: c_uint,
+        loopstarttype: FMOD_TIMEUNIT,
+        loopend: c_uint,
+        loopendtype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_GetLoopPoints: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        loopstart: *mut c_uint,
+        loopstarttype: FMOD_TIMEUNIT,
+        loopend: *mut c_uint,
+        loopendtype: FMOD_TIMEUNIT,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_IsVirtual:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, isvirtual: *mut FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Channel_GetCurrentSound: unsafe extern "C" fn(
+        channel: *mut FMOD_CHANNEL,
+        sound: *mut *mut FMOD_SOUND,
+    ) -> FMOD_RESULT,
+    FMOD_Channel_GetIndex:
+        unsafe extern "C" fn(channel: *mut FMOD_CHANNEL, index: *mut c_int) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetSystemObject: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        system: *mut *mut FMOD_SYSTEM,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Stop:
+        unsafe extern "C" fn(channelgroup: *mut FMOD_CHANNELGROUP) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetPaused: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        paused: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetPaused: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        paused: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetVolume:
+        unsafe extern "C" fn(channelgroup: *mut FMOD_CHANNELGROUP, volume: c_float) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetVolume: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        volume: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetVolumeRamp:
+        unsafe extern "C" fn(channelgroup: *mut FMOD_CHANNELGROUP, ramp: FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetVolumeRamp: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        ramp: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetAudibility: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        audibility: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetPitch:
+        unsafe extern "C" fn(channelgroup: *mut FMOD_CHANNELGROUP, pitch: c_float) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetPitch: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        pitch: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetMute:
+        unsafe extern "C" fn(channelgroup: *mut FMOD_CHANNELGROUP, mute: FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetMute: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        mute: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetReverbProperties: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        instance: c_int,
+        wet: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetReverbProperties: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        instance: c_int,
+        wet: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetLowPassGain:
+        unsafe extern "C" fn(channelgroup: *mut FMOD_CHANNELGROUP, gain: c_float) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetLowPassGain: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        gain: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetMode:
+        unsafe extern "C" fn(channelgroup: *mut FMOD_CHANNELGROUP, mode: FMOD_MODE) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetMode: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        mode: *mut FMOD_MODE,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetCallback: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        callback: FMOD_CHANNELCONTROL_CALLBACK,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_IsPlaying: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        isplaying: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetPan:
+        unsafe extern "C" fn(channelgroup: *mut FMOD_CHANNELGROUP, pan: c_float) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetMixLevelsOutput: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        frontleft: c_float,
+        frontright: c_float,
+        center: c_float,
+        lfe: c_float,
+        surroundleft: c_float,
+        surroundright: c_float,
+        backleft: c_float,
+        backright: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetMixLevelsInput: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        levels: *mut c_float,
+        numlevels: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetMixMatrix: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        matrix: *mut c_float,
+        outchannels: c_int,
+        inchannels: c_int,
+        inchannel_hop: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetMixMatrix: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        matrix: *mut c_float,
+        outchannels: *mut c_int,
+        inchannels: *mut c_int,
+        inchannel_hop: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetDSPClock: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        dspclock: *mut c_ulonglong,
+        parentclock: *mut c_ulonglong,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetDelay: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        dspclock_start: c_ulonglong,
+        dspclock_end: c_ulonglong,
+        stopchannels: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetDelay: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        dspclock_start: *mut c_ulonglong,
+        dspclock_end: *mut c_ulonglong,
+        stopchannels: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_AddFadePoint: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        dspclock: c_ulonglong,
+        volume: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetFadePointRamp: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        dspclock: c_ulonglong,
+        volume: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_RemoveFadePoints: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        dspclock_start: c_ulonglong,
+        dspclock_end: c_ulonglong,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetFadePoints: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        numpoints: *mut c_uint,
+        point_dspclock: *mut c_ulonglong,
+        point_volume: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetDSP: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        index: c_int,
+        dsp: *mut *mut FMOD_DSP,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_AddDSP: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        index: c_int,
+        dsp: *mut FMOD_DSP,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_RemoveDSP: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        dsp: *mut FMOD_DSP,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetNumDSPs: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        numdsps: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetDSPIndex: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        dsp: *mut FMOD_DSP,
+        index: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetDSPIndex: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        dsp: *mut FMOD_DSP,
+        index: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Set3DAttributes: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        pos: *const FMOD_VECTOR,
+        vel: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Get3DAttributes: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        pos: *mut FMOD_VECTOR,
+        vel: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Set3DMinMaxDistance: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        mindistance: c_float,
+        maxdistance: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Get3DMinMaxDistance: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        mindistance: *mut c_float,
+        maxdistance: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Set3DConeSettings: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        insideconeangle: c_float,
+        outsideconeangle: c_float,
+        outsidevolume: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Get3DConeSettings: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        insideconeangle: *mut c_float,
+        outsideconeangle: *mut c_float,
+        outsidevolume: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Set3DConeOrientation: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        orientation: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Get3DConeOrientation: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        orientation: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Set3DCustomRolloff: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        points: *mut FMOD_VECTOR,
+        numpoints: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Get3DCustomRolloff: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        points: *mut *mut FMOD_VECTOR,
+        numpoints: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Set3DOcclusion: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        directocclusion: c_float,
+        reverbocclusion: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Get3DOcclusion: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        directocclusion: *mut c_float,
+        reverbocclusion: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Set3DSpread:
+        unsafe extern "C" fn(channelgroup: *mut FMOD_CHANNELGROUP, angle: c_float) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Get3DSpread: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        angle: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Set3DLevel:
+        unsafe extern "C" fn(channelgroup: *mut FMOD_CHANNELGROUP, level: c_float) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Get3DLevel: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        level: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Set3DDopplerLevel:
+        unsafe extern "C" fn(channelgroup: *mut FMOD_CHANNELGROUP, level: c_float) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Get3DDopplerLevel: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        level: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Set3DDistanceFilter: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        custom: FMOD_BOOL,
+        customLevel: c_float,
+        centerFreq: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Get3DDistanceFilter: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        custom: *mut FMOD_BOOL,
+        customLevel: *mut c_float,
+        centerFreq: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_SetUserData: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        userdata: *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetUserData: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        userdata: *mut *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_Release:
+        unsafe extern "C" fn(channelgroup: *mut FMOD_CHANNELGROUP) -> FMOD_RESULT,
+    FMOD_ChannelGroup_AddGroup: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        group: *mut FMOD_CHANNELGROUP,
+        propagatedspclock: FMOD_BOOL,
+        connection: *mut *mut FMOD_DSPCONNECTION,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetNumGroups: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        numgroups: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetGroup: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        index: c_int,
+        group: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetParentGroup: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        group: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetName: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        name: *mut c_char,
+        namelen: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetNumChannels: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        numchannels: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_ChannelGroup_GetChannel: unsafe extern "C" fn(
+        channelgroup: *mut FMOD_CHANNELGROUP,
+        index: c_int,
+        channel: *mut *mut FMOD_CHANNEL,
+    ) -> FMOD_RESULT,
+    FMOD_SoundGroup_Release: unsafe extern "C" fn(soundgroup: *mut FMOD_SOUNDGROUP) -> FMOD_RESULT,
+    FMOD_SoundGroup_GetSystemObject: unsafe extern "C" fn(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        system: *mut *mut FMOD_SYSTEM,
+    ) -> FMOD_RESULT,
+    FMOD_SoundGroup_SetMaxAudible:
+        unsafe extern "C" fn(soundgroup: *mut FMOD_SOUNDGROUP, maxaudible: c_int) -> FMOD_RESULT,
+    FMOD_SoundGroup_GetMaxAudible: unsafe extern "C" fn(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        maxaudible: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_SoundGroup_SetMaxAudibleBehavior: unsafe extern "C" fn(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        behavior: FMOD_SOUNDGROUP_BEHAVIOR,
+    ) -> FMOD_RESULT,
+    FMOD_SoundGroup_GetMaxAudibleBehavior: unsafe extern "C" fn(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        behavior: *mut FMOD_SOUNDGROUP_BEHAVIOR,
+    ) -> FMOD_RESULT,
+    FMOD_SoundGroup_SetMuteFadeSpeed:
+        unsafe extern "C" fn(soundgroup: *mut FMOD_SOUNDGROUP, speed: c_float) -> FMOD_RESULT,
+    FMOD_SoundGroup_GetMuteFadeSpeed:
+        unsafe extern "C" fn(soundgroup: *mut FMOD_SOUNDGROUP, speed: *mut c_float) -> FMOD_RESULT,
+    FMOD_SoundGroup_SetVolume:
+        unsafe extern "C" fn(soundgroup: *mut FMOD_SOUNDGROUP, volume: c_float) -> FMOD_RESULT,
+    FMOD_SoundGroup_GetVolume:
+        unsafe extern "C" fn(soundgroup: *mut FMOD_SOUNDGROUP, volume: *mut c_float) -> FMOD_RESULT,
+    FMOD_SoundGroup_Stop: unsafe extern "C" fn(soundgroup: *mut FMOD_SOUNDGROUP) -> FMOD_RESULT,
+    FMOD_SoundGroup_GetName: unsafe extern "C" fn(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        name: *mut c_char,
+        namelen: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_SoundGroup_GetNumSounds: unsafe extern "C" fn(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        numsounds: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_SoundGroup_GetSound: unsafe extern "C" fn(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        index: c_int,
+        sound: *mut *mut FMOD_SOUND,
+    ) -> FMOD_RESULT,
+    FMOD_SoundGroup_GetNumPlaying: unsafe extern "C" fn(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        numplaying: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_SoundGroup_SetUserData: unsafe extern "C" fn(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        userdata: *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_SoundGroup_GetUserData: unsafe extern "C" fn(
+        soundgroup: *mut FMOD_SOUNDGROUP,
+        userdata: *mut *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_Release: unsafe extern "C" fn(dsp: *mut FMOD_DSP) -> FMOD_RESULT,
+    FMOD_DSP_GetSystemObject:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, system: *mut *mut FMOD_SYSTEM) -> FMOD_RESULT,
+    FMOD_DSP_AddInput: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        input: *mut FMOD_DSP,
+        connection: *mut *mut FMOD_DSPCONNECTION,
+        type_: FMOD_DSPCONNECTION_TYPE,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_DisconnectFrom: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        target: *mut FMOD_DSP,
+        connection: *mut FMOD_DSPCONNECTION,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_DisconnectAll: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        inputs: FMOD_BOOL,
+        outputs: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetNumInputs:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, numinputs: *mut c_int) -> FMOD_RESULT,
+    FMOD_DSP_GetNumOutputs:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, numoutputs: *mut c_int) -> FMOD_RESULT,
+    FMOD_DSP_GetInput: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        index: c_int,
+        input: *mut *mut FMOD_DSP,
+        inputconnection: *mut *mut FMOD_DSPCONNECTION,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetOutput: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        index: c_int,
+        output: *mut *mut FMOD_DSP,
+        outputconnection: *mut *mut FMOD_DSPCONNECTION,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_SetActive: unsafe extern "C" fn(dsp: *mut FMOD_DSP, active: FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_DSP_GetActive:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, active: *mut FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_DSP_SetBypass: unsafe extern "C" fn(dsp: *mut FMOD_DSP, bypass: FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_DSP_GetBypass:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, bypass: *mut FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_DSP_SetWetDryMix: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        prewet: c_float,
+        postwet: c_float,
+        dry: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetWetDryMix: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        prewet: *mut c_float,
+        postwet: *mut c_float,
+        dry: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_SetChannelFormat: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        channelmask: FMOD_CHANNELMASK,
+        numchannels: c_int,
+        source_speakermode: FMOD_SPEAKERMODE,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetChannelFormat: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        channelmask: *mut FMOD_CHANNELMASK,
+        numchannels: *mut c_int,
+        source_speakermode: *mut FMOD_SPEAKERMODE,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetOutputChannelFormat: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        inmask: FMOD_CHANNELMASK,
+        inchannels: c_int,
+        inspeakermode: FMOD_SPEAKERMODE,
+        outmask: *mut FMOD_CHANNELMASK,
+        outchannels: *mut c_int,
+        outspeakermode: *mut FMOD_SPEAKERMODE,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_Reset: unsafe extern "C" fn(dsp: *mut FMOD_DSP) -> FMOD_RESULT,
+    FMOD_DSP_SetCallback:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, callback: FMOD_DSP_CALLBACK) -> FMOD_RESULT,
+    FMOD_DSP_SetParameterFloat:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, index: c_int, value: c_float) -> FMOD_RESULT,
+    FMOD_DSP_SetParameterInt:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, index: c_int, value: c_int) -> FMOD_RESULT,
+    FMOD_DSP_SetParameterBool:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, index: c_int, value: FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_DSP_SetParameterData: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        index: c_int,
+        data: *mut c_void,
+        length: c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetParameterFloat: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        index: c_int,
+        value: *mut c_float,
+        valuestr: *mut c_char,
+        valuestrlen: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetParameterInt: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        index: c_int,
+        value: *mut c_int,
+        valuestr: *mut c_char,
+        valuestrlen: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetParameterBool: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        index: c_int,
+        value: *mut FMOD_BOOL,
+        valuestr: *mut c_char,
+        valuestrlen: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetParameterData: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        index: c_int,
+        data: *mut *mut c_void,
+        length: *mut c_uint,
+        valuestr: *mut c_char,
+        valuestrlen: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetNumParameters:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, numparams: *mut c_int) -> FMOD_RESULT,
+    FMOD_DSP_GetParameterInfo: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        index: c_int,
+        desc: *mut *mut FMOD_DSP_PARAMETER_DESC,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetDataParameterIndex:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, datatype: c_int, index: *mut c_int) -> FMOD_RESULT,
+    FMOD_DSP_ShowConfigDialog:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, hwnd: *mut c_void, show: FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_DSP_GetInfo: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        name: *mut c_char,
+        version: *mut c_uint,
+        channels: *mut c_int,
+        configwidth: *mut c_int,
+        configheight: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetType:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, type_: *mut FMOD_DSP_TYPE) -> FMOD_RESULT,
+    FMOD_DSP_GetIdle: unsafe extern "C" fn(dsp: *mut FMOD_DSP, idle: *mut FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_DSP_SetUserData:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, userdata: *mut c_void) -> FMOD_RESULT,
+    FMOD_DSP_GetUserData:
+        unsafe extern "C" fn(dsp: *mut FMOD_DSP, userdata: *mut *mut c_void) -> FMOD_RESULT,
+    FMOD_DSP_SetMeteringEnabled: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        inputEnabled: FMOD_BOOL,
+        outputEnabled: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetMeteringEnabled: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        inputEnabled: *mut FMOD_BOOL,
+        outputEnabled: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetMeteringInfo: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        inputInfo: *mut FMOD_DSP_METERING_INFO,
+        outputInfo: *mut FMOD_DSP_METERING_INFO,
+    ) -> FMOD_RESULT,
+    FMOD_DSP_GetCPUUsage: unsafe extern "C" fn(
+        dsp: *mut FMOD_DSP,
+        exclusive: *mut c_uint,
+        inclusive: *mut c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_DSPConnection_GetInput: unsafe extern "C" fn(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        input: *mut *mut FMOD_DSP,
+    ) -> FMOD_RESULT,
+    FMOD_DSPConnection_GetOutput: unsafe extern "C" fn(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        output: *mut *mut FMOD_DSP,
+    ) -> FMOD_RESULT,
+    FMOD_DSPConnection_SetMix: unsafe extern "C" fn(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        volume: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_DSPConnection_GetMix: unsafe extern "C" fn(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        volume: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_DSPConnection_SetMixMatrix: unsafe extern "C" fn(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        matrix: *mut c_float,
+        outchannels: c_int,
+        inchannels: c_int,
+        inchannel_hop: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_DSPConnection_GetMixMatrix: unsafe extern "C" fn(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        matrix: *mut c_float,
+        outchannels: *mut c_int,
+        inchannels: *mut c_int,
+        inchannel_hop: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_DSPConnection_GetType: unsafe extern "C" fn(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        type_: *mut FMOD_DSPCONNECTION_TYPE,
+    ) -> FMOD_RESULT,
+    FMOD_DSPConnection_SetUserData: unsafe extern "C" fn(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        userdata: *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_DSPConnection_GetUserData: unsafe extern "C" fn(
+        dspconnection: *mut FMOD_DSPCONNECTION,
+        userdata: *mut *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_Release: unsafe extern "C" fn(geometry: *mut FMOD_GEOMETRY) -> FMOD_RESULT,
+    FMOD_Geometry_AddPolygon: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        directocclusion: c_float,
+        reverbocclusion: c_float,
+        doublesided: FMOD_BOOL,
+        numvertices: c_int,
+        vertices: *const FMOD_VECTOR,
+        polygonindex: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_GetNumPolygons:
+        unsafe extern "C" fn(geometry: *mut FMOD_GEOMETRY, numpolygons: *mut c_int) -> FMOD_RESULT,
+    FMOD_Geometry_GetMaxPolygons: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        maxpolygons: *mut c_int,
+        maxvertices: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_GetPolygonNumVertices: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        index: c_int,
+        numvertices: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_SetPolygonVertex: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        index: c_int,
+        vertexindex: c_int,
+        vertex: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_GetPolygonVertex: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        index: c_int,
+        vertexindex: c_int,
+        vertex: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_SetPolygonAttributes: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        index: c_int,
+        directocclusion: c_float,
+        reverbocclusion: c_float,
+        doublesided: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_GetPolygonAttributes: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        index: c_int,
+        directocclusion: *mut c_float,
+        reverbocclusion: *mut c_float,
+        doublesided: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_SetActive:
+        unsafe extern "C" fn(geometry: *mut FMOD_GEOMETRY, active: FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Geometry_GetActive:
+        unsafe extern "C" fn(geometry: *mut FMOD_GEOMETRY, active: *mut FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Geometry_SetRotation: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        forward: *const FMOD_VECTOR,
+        up: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_GetRotation: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        forward: *mut FMOD_VECTOR,
+        up: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_SetPosition: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        position: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_GetPosition: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        position: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_SetScale: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        scale: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_GetScale:
+        unsafe extern "C" fn(geometry: *mut FMOD_GEOMETRY, scale: *mut FMOD_VECTOR) -> FMOD_RESULT,
+    FMOD_Geometry_Save: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        data: *mut c_void,
+        datasize: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Geometry_SetUserData:
+        unsafe extern "C" fn(geometry: *mut FMOD_GEOMETRY, userdata: *mut c_void) -> FMOD_RESULT,
+    FMOD_Geometry_GetUserData: unsafe extern "C" fn(
+        geometry: *mut FMOD_GEOMETRY,
+        userdata: *mut *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_Reverb3D_Release: unsafe extern "C" fn(reverb3d: *mut FMOD_REVERB3D) -> FMOD_RESULT,
+    FMOD_Reverb3D_Set3DAttributes: unsafe extern "C" fn(
+        reverb3d: *mut FMOD_REVERB3D,
+        position: *const FMOD_VECTOR,
+        mindistance: c_float,
+        maxdistance: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Reverb3D_Get3DAttributes: unsafe extern "C" fn(
+        reverb3d: *mut FMOD_REVERB3D,
+        position: *mut FMOD_VECTOR,
+        mindistance: *mut c_float,
+        maxdistance: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Reverb3D_SetProperties: unsafe extern "C" fn(
+        reverb3d: *mut FMOD_REVERB3D,
+        properties: *const FMOD_REVERB_PROPERTIES,
+    ) -> FMOD_RESULT,
+    FMOD_Reverb3D_GetProperties: unsafe extern "C" fn(
+        reverb3d: *mut FMOD_REVERB3D,
+        properties: *mut FMOD_REVERB_PROPERTIES,
+    ) -> FMOD_RESULT,
+    FMOD_Reverb3D_SetActive:
+        unsafe extern "C" fn(reverb3d: *mut FMOD_REVERB3D, active: FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Reverb3D_GetActive:
+        unsafe extern "C" fn(reverb3d: *mut FMOD_REVERB3D, active: *mut FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Reverb3D_SetUserData:
+        unsafe extern "C" fn(reverb3d: *mut FMOD_REVERB3D, userdata: *mut c_void) -> FMOD_RESULT,
+    FMOD_Reverb3D_GetUserData: unsafe extern "C" fn(
+        reverb3d: *mut FMOD_REVERB3D,
+        userdata: *mut *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_ParseID:
+        unsafe extern "C" fn(idstring: *const c_char, id: *mut FMOD_GUID) -> FMOD_RESULT,
+    FMOD_Studio_System_Create: unsafe extern "C" fn(
+        system: *mut *mut FMOD_STUDIO_SYSTEM,
+        headerversion: c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_IsValid: unsafe extern "C" fn(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_BOOL,
+    FMOD_Studio_System_SetAdvancedSettings: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        settings: *mut FMOD_STUDIO_ADVANCEDSETTINGS,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetAdvancedSettings: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        settings: *mut FMOD_STUDIO_ADVANCEDSETTINGS,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_Initialize: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        maxchannels: c_int,
+        studioflags: FMOD_STUDIO_INITFLAGS,
+        flags: FMOD_INITFLAGS,
+        extradriverdata: *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_Release:
+        unsafe extern "C" fn(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT,
+    FMOD_Studio_System_Update: unsafe extern "C" fn(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT,
+    FMOD_Studio_System_GetCoreSystem: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        coresystem: *mut *mut FMOD_SYSTEM,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetEvent: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        pathOrID: *const c_char,
+        event: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetBus: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        pathOrID: *const c_char,
+        bus: *mut *mut FMOD_STUDIO_BUS,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetVCA: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        pathOrID: *const c_char,
+        vca: *mut *mut FMOD_STUDIO_VCA,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetBank: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        pathOrID: *const c_char,
+        bank: *mut *mut FMOD_STUDIO_BANK,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetEventByID: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: *const FMOD_GUID,
+        event: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetBusByID: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: *const FMOD_GUID,
+        bus: *mut *mut FMOD_STUDIO_BUS,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetVCAByID: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: *const FMOD_GUID,
+        vca: *mut *mut FMOD_STUDIO_VCA,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetBankByID: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: *const FMOD_GUID,
+        bank: *mut *mut FMOD_STUDIO_BANK,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetSoundInfo: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        key: *const c_char,
+        info: *mut FMOD_STUDIO_SOUND_INFO,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetParameterDescriptionByName: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        name: *const c_char,
+        parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetParameterDescriptionByID: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetParameterLabelByName: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        name: *const c_char,
+        labelindex: c_int,
+        label: *mut c_char,
+        size: c_int,
+        retrieved: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetParameterLabelByID: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        labelindex: c_int,
+        label: *mut c_char,
+        size: c_int,
+        retrieved: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetParameterByID: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        value: *mut c_float,
+        finalvalue: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_SetParameterByID: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        value: c_float,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_SetParameterByIDWithLabel: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        label: *const c_char,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_SetParametersByIDs: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        ids: *const FMOD_STUDIO_PARAMETER_ID,
+        values: *mut c_float,
+        count: c_int,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetParameterByName: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        name: *const c_char,
+        value: *mut c_float,
+        finalvalue: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_SetParameterByName: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        name: *const c_char,
+        value: c_float,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_SetParameterByNameWithLabel: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        name: *const c_char,
+        label: *const c_char,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_LookupID: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        path: *const c_char,
+        id: *mut FMOD_GUID,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_LookupPath: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        id: *const FMOD_GUID,
+        path: *mut c_char,
+        size: c_int,
+        retrieved: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetNumListeners: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        numlisteners: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_SetNumListeners:
+        unsafe extern "C" fn(system: *mut FMOD_STUDIO_SYSTEM, numlisteners: c_int) -> FMOD_RESULT,
+    FMOD_Studio_System_GetListenerAttributes: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        index: c_int,
+        attributes: *mut FMOD_3D_ATTRIBUTES,
+        attenuationposition: *mut FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_SetListenerAttributes: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        index: c_int,
+        attributes: *const FMOD_3D_ATTRIBUTES,
+        attenuationposition: *const FMOD_VECTOR,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetListenerWeight: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        index: c_int,
+        weight: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_SetListenerWeight: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        index: c_int,
+        weight: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_LoadBankFile: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        filename: *const c_char,
+        flags: FMOD_STUDIO_LOAD_BANK_FLAGS,
+        bank: *mut *mut FMOD_STUDIO_BANK,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_LoadBankMemory: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        buffer: *const c_char,
+        length: c_int,
+        mode: FMOD_STUDIO_LOAD_MEMORY_MODE,
+        flags: FMOD_STUDIO_LOAD_BANK_FLAGS,
+        bank: *mut *mut FMOD_STUDIO_BANK,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_LoadBankCustom: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        info: *const FMOD_STUDIO_BANK_INFO,
+        flags: FMOD_STUDIO_LOAD_BANK_FLAGS,
+        bank: *mut *mut FMOD_STUDIO_BANK,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_RegisterPlugin: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        description: *const FMOD_DSP_DESCRIPTION,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_UnregisterPlugin:
+        unsafe extern "C" fn(system: *mut FMOD_STUDIO_SYSTEM, name: *const c_char) -> FMOD_RESULT,
+    FMOD_Studio_System_UnloadAll:
+        unsafe extern "C" fn(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT,
+    FMOD_Studio_System_FlushCommands:
+        unsafe extern "C" fn(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT,
+    FMOD_Studio_System_FlushSampleLoading:
+        unsafe extern "C" fn(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT,
+    FMOD_Studio_System_StartCommandCapture: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        filename: *const c_char,
+        flags: FMOD_STUDIO_COMMANDCAPTURE_FLAGS,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_StopCommandCapture:
+        unsafe extern "C" fn(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT,
+    FMOD_Studio_System_LoadCommandReplay: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        filename: *const c_char,
+        flags: FMOD_STUDIO_COMMANDREPLAY_FLAGS,
+        replay: *mut *mut FMOD_STUDIO_COMMANDREPLAY,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetBankCount:
+        unsafe extern "C" fn(system: *mut FMOD_STUDIO_SYSTEM, count: *mut c_int) -> FMOD_RESULT,
+    FMOD_Studio_System_GetBankList: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        array: *mut *mut FMOD_STUDIO_BANK,
+        capacity: c_int,
+        count: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetParameterDescriptionCount:
+        unsafe extern "C" fn(system: *mut FMOD_STUDIO_SYSTEM, count: *mut c_int) -> FMOD_RESULT,
+    FMOD_Studio_System_GetParameterDescriptionList: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        array: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+        capacity: c_int,
+        count: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetCPUUsage: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        usage: *mut FMOD_STUDIO_CPU_USAGE,
+        usage_core: *mut FMOD_CPU_USAGE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetBufferUsage: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        usage: *mut FMOD_STUDIO_BUFFER_USAGE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_ResetBufferUsage:
+        unsafe extern "C" fn(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT,
+    FMOD_Studio_System_SetCallback: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        callback: FMOD_STUDIO_SYSTEM_CALLBACK,
+        callbackmask: FMOD_STUDIO_SYSTEM_CALLBACK_TYPE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_SetUserData:
+        unsafe extern "C" fn(system: *mut FMOD_STUDIO_SYSTEM, userdata: *mut c_void) -> FMOD_RESULT,
+    FMOD_Studio_System_GetUserData: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        userdata: *mut *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_System_GetMemoryUsage: unsafe extern "C" fn(
+        system: *mut FMOD_STUDIO_SYSTEM,
+        memoryusage: *mut FMOD_STUDIO_MEMORY_USAGE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_IsValid:
+        unsafe extern "C" fn(eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION) -> FMOD_BOOL,
+    FMOD_Studio_EventDescription_GetID: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        id: *mut FMOD_GUID,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetPath: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        path: *mut c_char,
+        size: c_int,
+        retrieved: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetParameterDescriptionCount: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        count: *mut c_int,
+    )
+        -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetParameterDescriptionByIndex:
+        unsafe extern "C" fn(
+            eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+            index: c_int,
+            parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+        ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetParameterDescriptionByName: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        name: *const c_char,
+        parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+    )
+        -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetParameterDescriptionByID: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+    )
+        -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetParameterLabelByIndex: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        index: c_int,
+        labelindex: c_int,
+        label: *mut c_char,
+        size: c_int,
+        retrieved: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetParameterLabelByName: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        name: *const c_char,
+        labelindex: c_int,
+        label: *mut c_char,
+        size: c_int,
+        retrieved: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetParameterLabelByID: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        labelindex: c_int,
+        label: *mut c_char,
+        size: c_int,
+        retrieved: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetUserPropertyCount: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        count: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetUserPropertyByIndex: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        index: c_int,
+        property: *mut FMOD_STUDIO_USER_PROPERTY,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetUserProperty: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        name: *const c_char,
+        property: *mut FMOD_STUDIO_USER_PROPERTY,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetLength: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        length: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetMinMaxDistance: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        min: *mut c_float,
+        max: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetSoundSize: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        size: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_IsSnapshot: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        snapshot: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_IsOneshot: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        oneshot: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_IsStream: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        isStream: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_Is3D: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        is3D: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_IsDopplerEnabled: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        doppler: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_HasSustainPoint: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        sustainPoint: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_CreateInstance: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        instance: *mut *mut FMOD_STUDIO_EVENTINSTANCE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetInstanceCount: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        count: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetInstanceList: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        array: *mut *mut FMOD_STUDIO_EVENTINSTANCE,
+        capacity: c_int,
+        count: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_LoadSampleData:
+        unsafe extern "C" fn(eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_UnloadSampleData:
+        unsafe extern "C" fn(eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetSampleLoadingState: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        state: *mut FMOD_STUDIO_LOADING_STATE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_ReleaseAllInstances:
+        unsafe extern "C" fn(eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_SetCallback: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        callback: FMOD_STUDIO_EVENT_CALLBACK,
+        callbackmask: FMOD_STUDIO_EVENT_CALLBACK_TYPE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_GetUserData: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        userdata: *mut *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventDescription_SetUserData: unsafe extern "C" fn(
+        eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        userdata: *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_IsValid:
+        unsafe extern "C" fn(eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE) -> FMOD_BOOL,
+    FMOD_Studio_EventInstance_GetDescription: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        description: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetVolume: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        volume: *mut c_float,
+        finalvolume: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetVolume: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        volume: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetPitch: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        pitch: *mut c_float,
+        finalpitch: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetPitch: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        pitch: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_Get3DAttributes: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        attributes: *mut FMOD_3D_ATTRIBUTES,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_Set3DAttributes: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        attributes: *mut FMOD_3D_ATTRIBUTES,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetListenerMask: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        mask: *mut c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetListenerMask: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        mask: c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetProperty: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        index: FMOD_STUDIO_EVENT_PROPERTY,
+        value: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetProperty: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        index: FMOD_STUDIO_EVENT_PROPERTY,
+        value: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetReverbLevel: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        index: c_int,
+        level: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetReverbLevel: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        index: c_int,
+        level: c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetPaused: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        paused: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetPaused: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        paused: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_Start:
+        unsafe extern "C" fn(eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_Stop: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        mode: FMOD_STUDIO_STOP_MODE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetTimelinePosition: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        position: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetTimelinePosition: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        position: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetPlaybackState: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        state: *mut FMOD_STUDIO_PLAYBACK_STATE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetChannelGroup: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        group: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetMinMaxDistance: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        min: *mut c_float,
+        max: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_Release:
+        unsafe extern "C" fn(eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_IsVirtual: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        virtualstate: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetParameterByName: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        name: *const c_char,
+        value: *mut c_float,
+        finalvalue: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetParameterByName: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        name: *const c_char,
+        value: c_float,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetParameterByNameWithLabel: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        name: *const c_char,
+        label: *const c_char,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetParameterByID: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        value: *mut c_float,
+        finalvalue: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetParameterByID: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        value: c_float,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetParameterByIDWithLabel: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        id: FMOD_STUDIO_PARAMETER_ID,
+        label: *const c_char,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetParametersByIDs: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        ids: *const FMOD_STUDIO_PARAMETER_ID,
+        values: *mut c_float,
+        count: c_int,
+        ignoreseekspeed: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_KeyOff:
+        unsafe extern "C" fn(eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetCallback: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        callback: FMOD_STUDIO_EVENT_CALLBACK,
+        callbackmask: FMOD_STUDIO_EVENT_CALLBACK_TYPE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetUserData: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        userdata: *mut *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_SetUserData: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        userdata: *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetCPUUsage: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        exclusive: *mut c_uint,
+        inclusive: *mut c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_EventInstance_GetMemoryUsage: unsafe extern "C" fn(
+        eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+        memoryusage: *mut FMOD_STUDIO_MEMORY_USAGE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_Bus_IsValid: unsafe extern "C" fn(bus: *mut FMOD_STUDIO_BUS) -> FMOD_BOOL,
+    FMOD_Studio_Bus_GetID:
+        unsafe extern "C" fn(bus: *mut FMOD_STUDIO_BUS, id: *mut FMOD_GUID) -> FMOD_RESULT,
+    FMOD_Studio_Bus_GetPath: unsafe extern "C" fn(
+        bus: *mut FMOD_STUDIO_BUS,
+        path: *mut c_char,
+        size: c_int,
+        retrieved: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_Bus_GetVolume: unsafe extern "C" fn(
+        bus: *mut FMOD_STUDIO_BUS,
+        volume: *mut c_float,
+        finalvolume: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_Bus_SetVolume:
+        unsafe extern "C" fn(bus: *mut FMOD_STUDIO_BUS, volume: c_float) -> FMOD_RESULT,
+    FMOD_Studio_Bus_GetPaused:
+        unsafe extern "C" fn(bus: *mut FMOD_STUDIO_BUS, paused: *mut FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Studio_Bus_SetPaused:
+        unsafe extern "C" fn(bus: *mut FMOD_STUDIO_BUS, paused: FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Studio_Bus_GetMute:
+        unsafe extern "C" fn(bus: *mut FMOD_STUDIO_BUS, mute: *mut FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Studio_Bus_SetMute:
+        unsafe extern "C" fn(bus: *mut FMOD_STUDIO_BUS, mute: FMOD_BOOL) -> FMOD_RESULT,
+    FMOD_Studio_Bus_StopAllEvents:
+        unsafe extern "C" fn(bus: *mut FMOD_STUDIO_BUS, mode: FMOD_STUDIO_STOP_MODE) -> FMOD_RESULT,
+    FMOD_Studio_Bus_GetPortIndex:
+        unsafe extern "C" fn(bus: *mut FMOD_STUDIO_BUS, index: *mut FMOD_PORT_INDEX) -> FMOD_RESULT,
+    FMOD_Studio_Bus_SetPortIndex:
+        unsafe extern "C" fn(bus: *mut FMOD_STUDIO_BUS, index: FMOD_PORT_INDEX) -> FMOD_RESULT,
+    FMOD_Studio_Bus_LockChannelGroup:
+        unsafe extern "C" fn(bus: *mut FMOD_STUDIO_BUS) -> FMOD_RESULT,
+    FMOD_Studio_Bus_UnlockChannelGroup:
+        unsafe extern "C" fn(bus: *mut FMOD_STUDIO_BUS) -> FMOD_RESULT,
+    FMOD_Studio_Bus_GetChannelGroup: unsafe extern "C" fn(
+        bus: *mut FMOD_STUDIO_BUS,
+        group: *mut *mut FMOD_CHANNELGROUP,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_Bus_GetCPUUsage: unsafe extern "C" fn(
+        bus: *mut FMOD_STUDIO_BUS,
+        exclusive: *mut c_uint,
+        inclusive: *mut c_uint,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_Bus_GetMemoryUsage: unsafe extern "C" fn(
+        bus: *mut FMOD_STUDIO_BUS,
+        memoryusage: *mut FMOD_STUDIO_MEMORY_USAGE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_VCA_IsValid: unsafe extern "C" fn(vca: *mut FMOD_STUDIO_VCA) -> FMOD_BOOL,
+    FMOD_Studio_VCA_GetID:
+        unsafe extern "C" fn(vca: *mut FMOD_STUDIO_VCA, id: *mut FMOD_GUID) -> FMOD_RESULT,
+    FMOD_Studio_VCA_GetPath: unsafe extern "C" fn(
+        vca: *mut FMOD_STUDIO_VCA,
+        path: *mut c_char,
+        size: c_int,
+        retrieved: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_VCA_GetVolume: unsafe extern "C" fn(
+        vca: *mut FMOD_STUDIO_VCA,
+        volume: *mut c_float,
+        finalvolume: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_VCA_SetVolume:
+        unsafe extern "C" fn(vca: *mut FMOD_STUDIO_VCA, volume: c_float) -> FMOD_RESULT,
+    FMOD_Studio_Bank_IsValid: unsafe extern "C" fn(bank: *mut FMOD_STUDIO_BANK) -> FMOD_BOOL,
+    FMOD_Studio_Bank_GetID:
+        unsafe extern "C" fn(bank: *mut FMOD_STUDIO_BANK, id: *mut FMOD_GUID) -> FMOD_RESULT,
+    FMOD_Studio_Bank_GetPath: unsafe extern "C" fn(
+        bank: *mut FMOD_STUDIO_BANK,
+        path: *mut c_char,
+        size: c_int,
+        retrieved: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_Bank_Unload: unsafe extern "C" fn(bank: *mut FMOD_STUDIO_BANK) -> FMOD_RESULT,
+    FMOD_Studio_Bank_LoadSampleData:
+        unsafe extern "C" fn(bank: *mut FMOD_STUDIO_BANK) -> FMOD_RESULT,
+    FMOD_Studio_Bank_UnloadSampleData:
+        unsafe extern "C" fn(bank: *mut FMOD_STUDIO_BANK) -> FMOD_RESULT,
+    FMOD_Studio_Bank_GetLoadingState: unsafe extern "C" fn(
+        bank: *mut FMOD_STUDIO_BANK,
+        state: *mut FMOD_STUDIO_LOADING_STATE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_Bank_GetSampleLoadingState: unsafe extern "C" fn(
+        bank: *mut FMOD_STUDIO_BANK,
+        state: *mut FMOD_STUDIO_LOADING_STATE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_Bank_GetStringCount:
+        unsafe extern "C" fn(bank: *mut FMOD_STUDIO_BANK, count: *mut c_int) -> FMOD_RESULT,
+    FMOD_Studio_Bank_GetStringInfo: unsafe extern "C" fn(
+        bank: *mut FMOD_STUDIO_BANK,
+        index: c_int,
+        id: *mut FMOD_GUID,
+        path: *mut c_char,
+        size: c_int,
+        retrieved: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_Bank_GetEventCount:
+        unsafe extern "C" fn(bank: *mut FMOD_STUDIO_BANK, count: *mut c_int) -> FMOD_RESULT,
+    FMOD_Studio_Bank_GetEventList: unsafe extern "C" fn(
+        bank: *mut FMOD_STUDIO_BANK,
+        array: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+        capacity: c_int,
+        count: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_Bank_GetBusCount:
+        unsafe extern "C" fn(bank: *mut FMOD_STUDIO_BANK, count: *mut c_int) -> FMOD_RESULT,
+    FMOD_Studio_Bank_GetBusList: unsafe extern "C" fn(
+        bank: *mut FMOD_STUDIO_BANK,
+        array: *mut *mut FMOD_STUDIO_BUS,
+        capacity: c_int,
+        count: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_Bank_GetVCACount:
+        unsafe extern "C" fn(bank: *mut FMOD_STUDIO_BANK, count: *mut c_int) -> FMOD_RESULT,
+    FMOD_Studio_Bank_GetVCAList: unsafe extern "C" fn(
+        bank: *mut FMOD_STUDIO_BANK,
+        array: *mut *mut FMOD_STUDIO_VCA,
+        capacity: c_int,
+        count: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_Bank_GetUserData: unsafe extern "C" fn(
+        bank: *mut FMOD_STUDIO_BANK,
+        userdata: *mut *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_Bank_SetUserData:
+        unsafe extern "C" fn(bank: *mut FMOD_STUDIO_BANK, userdata: *mut c_void) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_IsValid:
+        unsafe extern "C" fn(replay: *mut FMOD_STUDIO_COMMANDREPLAY) -> FMOD_BOOL,
+    FMOD_Studio_CommandReplay_GetSystem: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        system: *mut *mut FMOD_STUDIO_SYSTEM,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_GetLength: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        length: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_GetCommandCount: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        count: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_GetCommandInfo: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        commandindex: c_int,
+        info: *mut FMOD_STUDIO_COMMAND_INFO,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_GetCommandString: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        commandindex: c_int,
+        buffer: *mut c_char,
+        length: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_GetCommandAtTime: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        time: c_float,
+        commandindex: *mut c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_SetBankPath: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        bankPath: *const c_char,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_Start:
+        unsafe extern "C" fn(replay: *mut FMOD_STUDIO_COMMANDREPLAY) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_Stop:
+        unsafe extern "C" fn(replay: *mut FMOD_STUDIO_COMMANDREPLAY) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_SeekToTime:
+        unsafe extern "C" fn(replay: *mut FMOD_STUDIO_COMMANDREPLAY, time: c_float) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_SeekToCommand: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        commandindex: c_int,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_GetPaused: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        paused: *mut FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_SetPaused: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        paused: FMOD_BOOL,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_GetPlaybackState: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        state: *mut FMOD_STUDIO_PLAYBACK_STATE,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_GetCurrentCommand: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        commandindex: *mut c_int,
+        currenttime: *mut c_float,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_Release:
+        unsafe extern "C" fn(replay: *mut FMOD_STUDIO_COMMANDREPLAY) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_SetFrameCallback: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        callback: FMOD_STUDIO_COMMANDREPLAY_FRAME_CALLBACK,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_SetLoadBankCallback: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        callback: FMOD_STUDIO_COMMANDREPLAY_LOAD_BANK_CALLBACK,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_SetCreateInstanceCallback: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        callback: FMOD_STUDIO_COMMANDREPLAY_CREATE_INSTANCE_CALLBACK,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_GetUserData: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        userdata: *mut *mut c_void,
+    ) -> FMOD_RESULT,
+    FMOD_Studio_CommandReplay_SetUserData: unsafe extern "C" fn(
+        replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+        userdata: *mut c_void,
+    ) -> FMOD_RESULT,
+}
+#[cfg(feature = "dynamic")]
+impl Library {
+    pub unsafe fn load<F>(path: F) -> Result<Self, libloading::Error>
+    where
+        F: Fn(&str) -> std::path::PathBuf,
+    {
+        let fmod = libloading::Library::new(path("fmod"))?;
+        let fmodstudio = libloading::Library::new(path("fmodstudio"))?;
+        Ok(Self {
+            FMOD_Memory_Initialize: *fmod.get(b"FMOD_Memory_Initialize\0")?,
+            FMOD_Memory_GetStats: *fmod.get(b"FMOD_Memory_GetStats\0")?,
+            FMOD_Debug_Initialize: *fmod.get(b"FMOD_Debug_Initialize\0")?,
+            FMOD_File_SetDiskBusy: *fmod.get(b"FMOD_File_SetDiskBusy\0")?,
+            FMOD_File_GetDiskBusy: *fmod.get(b"FMOD_File_GetDiskBusy\0")?,
+            FMOD_Thread_SetAttributes: *fmod.get(b"FMOD_Thread_SetAttributes\0")?,
+            FMOD_System_Create: *fmod.get(b"FMOD_System_Create\0")?,
+            FMOD_System_Release: *fmod.get(b"FMOD_System_Release\0")?,
+            FMOD_System_SetOutput: *fmod.get(b"FMOD_System_SetOutput\0")?,
+            FMOD_System_GetOutput: *fmod.get(b"FMOD_System_GetOutput\0")?,
+            FMOD_System_GetNumDrivers: *fmod.get(b"FMOD_System_GetNumDrivers\0")?,
+            FMOD_System_GetDriverInfo: *fmod.get(b"FMOD_System_GetDriverInfo\0")?,
+            FMOD_System_SetDriver: *fmod.get(b"FMOD_System_SetDriver\0")?,
+            FMOD_System_GetDriver: *fmod.get(b"FMOD_System_GetDriver\0")?,
+            FMOD_System_SetSoftwareChannels: *fmod.get(b"FMOD_System_SetSoftwareChannels\0")?,
+            FMOD_System_GetSoftwareChannels: *fmod.get(b"FMOD_System_GetSoftwareChannels\0")?,
+            FMOD_System_SetSoftwareFormat: *fmod.get(b"FMOD_System_SetSoftwareFormat\0")?,
+            FMOD_System_GetSoftwareFormat: *fmod.get(b"FMOD_System_GetSoftwareFormat\0")?,
+            FMOD_System_SetDSPBufferSize: *fmod.get(b"FMOD_System_SetDSPBufferSize\0")?,
+            FMOD_System_GetDSPBufferSize: *fmod.get(b"FMOD_System_GetDSPBufferSize\0")?,
+            FMOD_System_SetFileSystem: *fmod.get(b"FMOD_System_SetFileSystem\0")?,
+            FMOD_System_AttachFileSystem: *fmod.get(b"FMOD_System_AttachFileSystem\0")?,
+            FMOD_System_SetAdvancedSettings: *fmod.get(b"FMOD_System_SetAdvancedSettings\0")?,
+            FMOD_System_GetAdvancedSettings: *fmod.get(b"FMOD_System_GetAdvancedSettings\0")?,
+            FMOD_System_SetCallback: *fmod.get(b"FMOD_System_SetCallback\0")?,
+            FMOD_System_SetPluginPath: *fmod.get(b"FMOD_System_SetPluginPath\0")?,
+            FMOD_System_LoadPlugin: *fmod.get(b"FMOD_System_LoadPlugin\0")?,
+            FMOD_System_UnloadPlugin: *fmod.get(b"FMOD_System_UnloadPlugin\0")?,
+            FMOD_System_GetNumNestedPlugins: *fmod.get(b"FMOD_System_GetNumNestedPlugins\0")?,
+            FMOD_System_GetNestedPlugin: *fmod.get(b"FMOD_System_GetNestedPlugin\0")?,
+            FMOD_System_GetNumPlugins: *fmod.get(b"FMOD_System_GetNumPlugins\0")?,
+            FMOD_System_GetPluginHandle: *fmod.get(b"FMOD_System_GetPluginHandle\0")?,
+            FMOD_System_GetPluginInfo: *fmod.get(b"FMOD_System_GetPluginInfo\0")?,
+            FMOD_System_SetOutputByPlugin: *fmod.get(b"FMOD_System_SetOutputByPlugin\0")?,
+            FMOD_System_GetOutputByPlugin: *fmod.get(b"FMOD_System_GetOutputByPlugin\0")?,
+            FMOD_System_CreateDSPByPlugin: *fmod.get(b"FMOD_System_CreateDSPByPlugin\0")?,
+            FMOD_System_GetDSPInfoByPlugin: *fmod.get(b"FMOD_System_GetDSPInfoByPlugin\0")?,
+            FMOD_System_RegisterCodec: *fmod.get(b"FMOD_System_RegisterCodec\0")?,
+            FMOD_System_RegisterDSP: *fmod.get(b"FMOD_System_RegisterDSP\0")?,
+            FMOD_System_RegisterOutput: *fmod.get(b"FMOD_System_RegisterOutput\0")?,
+            FMOD_System_Init: *fmod.get(b"FMOD_System_Init\0")?,
+            FMOD_System_Close: *fmod.get(b"FMOD_System_Close\0")?,
+            FMOD_System_Update: *fmod.get(b"FMOD_System_Update\0")?,
+            FMOD_System_SetSpeakerPosition: *fmod.get(b"FMOD_System_SetSpeakerPosition\0")?,
+            FMOD_System_GetSpeakerPosition: *fmod.get(b"FMOD_System_GetSpeakerPosition\0")?,
+            FMOD_System_SetStreamBufferSize: *fmod.get(b"FMOD_System_SetStreamBufferSize\0")?,
+            FMOD_System_GetStreamBufferSize: *fmod.get(b"FMOD_System_GetStreamBufferSize\0")?,
+            FMOD_System_Set3DSettings: *fmod.get(b"FMOD_System_Set3DSettings\0")?,
+            FMOD_System_Get3DSettings: *fmod.get(b"FMOD_System_Get3DSettings\0")?,
+            FMOD_System_Set3DNumListeners: *fmod.get(b"FMOD_System_Set3DNumListeners\0")?,
+            FMOD_System_Get3DNumListeners: *fmod.get(b"FMOD_System_Get3DNumListeners\0")?,
+            FMOD_System_Set3DListenerAttributes: *fmod
+                .get(b"FMOD_System_Set3DListenerAttributes\0")?,
+            FMOD_System_Get3DListenerAttributes: *fmod
+                .get(b"FMOD_System_Get3DListenerAttributes\0")?,
+            FMOD_System_Set3DRolloffCallback: *fmod.get(b"FMOD_System_Set3DRolloffCallback\0")?,
+            FMOD_System_MixerSuspend: *fmod.get(b"FMOD_System_MixerSuspend\0")?,
+            FMOD_System_MixerResume: *fmod.get(b"FMOD_System_MixerResume\0")?,
+            FMOD_System_GetDefaultMixMatrix: *fmod.get(b"FMOD_System_GetDefaultMixMatrix\0")?,
+            FMOD_System_GetSpeakerModeChannels: *fmod
+                .get(b"FMOD_System_GetSpeakerModeChannels\0")?,
+            FMOD_System_GetVersion: *fmod.get(b"FMOD_System_GetVersion\0")?,
+            FMOD_System_GetOutputHandle: *fmod.get(b"FMOD_System_GetOutputHandle\0")?,
+            FMOD_System_GetChannelsPlaying: *fmod.get(b"FMOD_System_GetChannelsPlaying\0")?,
+            FMOD_System_GetCPUUsage: *fmod.get(b"FMOD_System_GetCPUUsage\0")?,
+            FMOD_System_GetFileUsage: *fmod.get(b"FMOD_System_GetFileUsage\0")?,
+            FMOD_System_CreateSound: *fmod.get(b"FMOD_System_CreateSound\0")?,
+            FMOD_System_CreateStream: *fmod.get(b"FMOD_System_CreateStream\0")?,
+            FMOD_System_CreateDSP: *fmod.get(b"FMOD_System_CreateDSP\0")?,
+            FMOD_System_CreateDSPByType: *fmod.get(b"FMOD_System_CreateDSPByType\0")?,
+            FMOD_System_CreateChannelGroup: *fmod.get(b"FMOD_System_CreateChannelGroup\0")?,
+            FMOD_System_CreateSoundGroup: *fmod.get(b"FMOD_System_CreateSoundGroup\0")?,
+            FMOD_System_CreateReverb3D: *fmod.get(b"FMOD_System_CreateReverb3D\0")?,
+            FMOD_System_PlaySound: *fmod.get(b"FMOD_System_PlaySound\0")?,
+            FMOD_System_PlayDSP: *fmod.get(b"FMOD_System_PlayDSP\0")?,
+            FMOD_System_GetChannel: *fmod.get(b"FMOD_System_GetChannel\0")?,
+            FMOD_System_GetDSPInfoByType: *fmod.get(b"FMOD_System_GetDSPInfoByType\0")?,
+            FMOD_System_GetMasterChannelGroup: *fmod.get(b"FMOD_System_GetMasterChannelGroup\0")?,
+            FMOD_System_GetMasterSoundGroup: *fmod.get(b"FMOD_System_GetMasterSoundGroup\0")?,
+            FMOD_System_AttachChannelGroupToPort: *fmod
+                .get(b"FMOD_System_AttachChannelGroupToPort\0")?,
+            FMOD_System_DetachChannelGroupFromPort: *fmod
+                .get(b"FMOD_System_DetachChannelGroupFromPort\0")?,
+            FMOD_System_SetReverbProperties: *fmod.get(b"FMOD_System_SetReverbProperties\0")?,
+            FMOD_System_GetReverbProperties: *fmod.get(b"FMOD_System_GetReverbProperties\0")?,
+            FMOD_System_LockDSP: *fmod.get(b"FMOD_System_LockDSP\0")?,
+            FMOD_System_UnlockDSP: *fmod.get(b"FMOD_System_UnlockDSP\0")?,
+            FMOD_System_GetRecordNumDrivers: *fmod.get(b"FMOD_System_GetRecordNumDrivers\0")?,
+            FMOD_System_GetRecordDriverInfo: *fmod.get(b"FMOD_System_GetRecordDriverInfo\0")?,
+            FMOD_System_GetRecordPosition: *fmod.get(b"FMOD_System_GetRecordPosition\0")?,
+            FMOD_System_RecordStart: *fmod.get(b"FMOD_System_RecordStart\0")?,
+            FMOD_System_RecordStop: *fmod.get(b"FMOD_System_RecordStop\0")?,
+            FMOD_System_IsRecording: *fmod.get(b"FMOD_System_IsRecording\0")?,
+            FMOD_System_CreateGeometry: *fmod.get(b"FMOD_System_CreateGeometry\0")?,
+            FMOD_System_SetGeometrySettings: *fmod.get(b"FMOD_System_SetGeometrySettings\0")?,
+            FMOD_System_GetGeometrySettings: *fmod.get(b"FMOD_System_GetGeometrySettings\0")?,
+            FMOD_System_LoadGeometry: *fmod.get(b"FMOD_System_LoadGeometry\0")?,
+            FMOD_System_GetGeometryOcclusion: *fmod.get(b"FMOD_System_GetGeometryOcclusion\0")?,
+            FMOD_System_SetNetworkProxy: *fmod.get(b"FMOD_System_SetNetworkProxy\0")?,
+            FMOD_System_GetNetworkProxy: *fmod.get(b"FMOD_System_GetNetworkProxy\0")?,
+            FMOD_System_SetNetworkTimeout: *fmod.get(b"FMOD_System_SetNetworkTimeout\0")?,
+            FMOD_System_GetNetworkTimeout: *fmod.get(b"FMOD_System_GetNetworkTimeout\0")?,
+            FMOD_System_SetUserData: *fmod.get(b"FMOD_System_SetUserData\0")?,
+            FMOD_System_GetUserData: *fmod.get(b"FMOD_System_GetUserData\0")?,
+            FMOD_Sound_Release: *fmod.get(b"FMOD_Sound_Release\0")?,
+            FMOD_Sound_GetSystemObject: *fmod.get(b"FMOD_Sound_GetSystemObject\0")?,
+            FMOD_Sound_Lock: *fmod.get(b"FMOD_Sound_Lock\0")?,
+            FMOD_Sound_Unlock: *fmod.get(b"FMOD_Sound_Unlock\0")?,
+            FMOD_Sound_SetDefaults: *fmod.get(b"FMOD_Sound_SetDefaults\0")?,
+            FMOD_Sound_GetDefaults: *fmod.get(b"FMOD_Sound_GetDefaults\0")?,
+            FMOD_Sound_Set3DMinMaxDistance: *fmod.get(b"FMOD_Sound_Set3DMinMaxDistance\0")?,
+            FMOD_Sound_Get3DMinMaxDistance: *fmod.get(b"FMOD_Sound_Get3DMinMaxDistance\0")?,
+            FMOD_Sound_Set3DConeSettings: *fmod.get(b"FMOD_Sound_Set3DConeSettings\0")?,
+            FMOD_Sound_Get3DConeSettings: *fmod.get(b"FMOD_Sound_Get3DConeSettings\0")?,
+            FMOD_Sound_Set3DCustomRolloff: *fmod.get(b"FMOD_Sound_Set3DCustomRolloff\0")?,
+            FMOD_Sound_Get3DCustomRolloff: *fmod.get(b"FMOD_Sound_Get3DCustomRolloff\0")?,
+            FMOD_Sound_GetSubSound: *fmod.get(b"FMOD_Sound_GetSubSound\0")?,
+            FMOD_Sound_GetSubSoundParent: *fmod.get(b"FMOD_Sound_GetSubSoundParent\0")?,
+            FMOD_Sound_GetName: *fmod.get(b"FMOD_Sound_GetName\0")?,
+            FMOD_Sound_GetLength: *fmod.get(b"FMOD_Sound_GetLength\0")?,
+            FMOD_Sound_GetFormat: *fmod.get(b"FMOD_Sound_GetFormat\0")?,
+            FMOD_Sound_GetNumSubSounds: *fmod.get(b"FMOD_Sound_GetNumSubSounds\0")?,
+            FMOD_Sound_GetNumTags: *fmod.get(b"FMOD_Sound_GetNumTags\0")?,
+            FMOD_Sound_GetTag: *fmod.get(b"FMOD_Sound_GetTag\0")?,
+            FMOD_Sound_GetOpenState: *fmod.get(b"FMOD_Sound_GetOpenState\0")?,
+            FMOD_Sound_ReadData: *fmod.get(b"FMOD_Sound_ReadData\0")?,
+            FMOD_Sound_SeekData: *fmod.get(b"FMOD_Sound_SeekData\0")?,
+            FMOD_Sound_SetSoundGroup: *fmod.get(b"FMOD_Sound_SetSoundGroup\0")?,
+            FMOD_Sound_GetSoundGroup: *fmod.get(b"FMOD_Sound_GetSoundGroup\0")?,
+            FMOD_Sound_GetNumSyncPoints: *fmod.get(b"FMOD_Sound_GetNumSyncPoints\0")?,
+            FMOD_Sound_GetSyncPoint: *fmod.get(b"FMOD_Sound_GetSyncPoint\0")?,
+            FMOD_Sound_GetSyncPointInfo: *fmod.get(b"FMOD_Sound_GetSyncPointInfo\0")?,
+            FMOD_Sound_AddSyncPoint: *fmod.get(b"FMOD_Sound_AddSyncPoint\0")?,
+            FMOD_Sound_DeleteSyncPoint: *fmod.get(b"FMOD_Sound_DeleteSyncPoint\0")?,
+            FMOD_Sound_SetMode: *fmod.get(b"FMOD_Sound_SetMode\0")?,
+            FMOD_Sound_GetMode: *fmod.get(b"FMOD_Sound_GetMode\0")?,
+            FMOD_Sound_SetLoopCount: *fmod.get(b"FMOD_Sound_SetLoopCount\0")?,
+            FMOD_Sound_GetLoopCount: *fmod.get(b"FMOD_Sound_GetLoopCount\0")?,
+            FMOD_Sound_SetLoopPoints: *fmod.get(b"FMOD_Sound_SetLoopPoints\0")?,
+            FMOD_Sound_GetLoopPoints: *fmod.get(b"FMOD_Sound_GetLoopPoints\0")?,
+            FMOD_Sound_GetMusicNumChannels: *fmod.get(b"FMOD_Sound_GetMusicNumChannels\0")?,
+            FMOD_Sound_SetMusicChannelVolume: *fmod.get(b"FMOD_Sound_SetMusicChannelVolume\0")?,
+            FMOD_Sound_GetMusicChannelVolume: *fmod.get(b"FMOD_Sound_GetMusicChannelVolume\0")?,
+            FMOD_Sound_SetMusicSpeed: *fmod.get(b"FMOD_Sound_SetMusicSpeed\0")?,
+            FMOD_Sound_GetMusicSpeed: *fmod.get(b"FMOD_Sound_GetMusicSpeed\0")?,
+            FMOD_Sound_SetUserData: *fmod.get(b"FMOD_Sound_SetUserData\0")?,
+            FMOD_Sound_GetUserData: *fmod.get(b"FMOD_Sound_GetUserData\0")?,
+            FMOD_Channel_GetSystemObject: *fmod.get(b"FMOD_Channel_GetSystemObject\0")?,
+            FMOD_Channel_Stop: *fmod.get(b"FMOD_Channel_Stop\0")?,
+            FMOD_Channel_SetPaused: *fmod.get(b"FMOD_Channel_SetPaused\0")?,
+            FMOD_Channel_GetPaused: *fmod.get(b"FMOD_Channel_GetPaused\0")?,
+            FMOD_Channel_SetVolume: *fmod.get(b"FMOD_Channel_SetVolume\0")?,
+            FMOD_Channel_GetVolume: *fmod.get(b"FMOD_Channel_GetVolume\0")?,
+            FMOD_Channel_SetVolumeRamp: *fmod.get(b"FMOD_Channel_SetVolumeRamp\0")?,
+            FMOD_Channel_GetVolumeRamp: *fmod.get(b"FMOD_Channel_GetVolumeRamp\0")?,
+            FMOD_Channel_GetAudibility: *fmod.get(b"FMOD_Channel_GetAudibility\0")?,
+            FMOD_Channel_SetPitch: *fmod.get(b"FMOD_Channel_SetPitch\0")?,
+            FMOD_Channel_GetPitch: *fmod.get(b"FMOD_Channel_GetPitch\0")?,
+            FMOD_Channel_SetMute: *fmod.get(b"FMOD_Channel_SetMute\0")?,
+            FMOD_Channel_GetMute: *fmod.get(b"FMOD_Channel_GetMute\0")?,
+            FMOD_Channel_SetReverbProperties: *fmod.get(b"FMOD_Channel_SetReverbProperties\0")?,
+            FMOD_Channel_GetReverbProperties: *fmod.get(b"FMOD_Channel_GetReverbProperties\0")?,
+            FMOD_Channel_SetLowPassGain: *fmod.get(b"FMOD_Channel_SetLowPassGain\0")?,
+            FMOD_Channel_GetLowPassGain: *fmod.get(b"FMOD_Channel_GetLowPassGain\0")?,
+            FMOD_Channel_SetMode: *fmod.get(b"FMOD_Channel_SetMode\0")?,
+            FMOD_Channel_GetMode: *fmod.get(b"FMOD_Channel_GetMode\0")?,
+            FMOD_Channel_SetCallback: *fmod.get(b"FMOD_Channel_SetCallback\0")?,
+            FMOD_Channel_IsPlaying: *fmod.get(b"FMOD_Channel_IsPlaying\0")?,
+            FMOD_Channel_SetPan: *fmod.get(b"FMOD_Channel_SetPan\0")?,
+            FMOD_Channel_SetMixLevelsOutput: *fmod.get(b"FMOD_Channel_SetMixLevelsOutput\0")?,
+            FMOD_Channel_SetMixLevelsInput: *fmod.get(b"FMOD_Channel_SetMixLevelsInput\0")?,
+            FMOD_Channel_SetMixMatrix: *fmod.get(b"FMOD_Channel_SetMixMatrix\0")?,
+            FMOD_Channel_GetMixMatrix: *fmod.get(b"FMOD_Channel_GetMixMatrix\0")?,
+            FMOD_Channel_GetDSPClock: *fmod.get(b"FMOD_Channel_GetDSPClock\0")?,
+            FMOD_Channel_SetDelay: *fmod.get(b"FMOD_Channel_SetDelay\0")?,
+            FMOD_Channel_GetDelay: *fmod.get(b"FMOD_Channel_GetDelay\0")?,
+            FMOD_Channel_AddFadePoint: *fmod.get(b"FMOD_Channel_AddFadePoint\0")?,
+            FMOD_Channel_SetFadePointRamp: *fmod.get(b"FMOD_Channel_SetFadePointRamp\0")?,
+            FMOD_Channel_RemoveFadePoints: *fmod.get(b"FMOD_Channel_RemoveFadePoints\0")?,
+            FMOD_Channel_GetFadePoints: *fmod.get(b"FMOD_Channel_GetFadePoints\0")?,
+            FMOD_Channel_GetDSP: *fmod.get(b"FMOD_Channel_GetDSP\0")?,
+            FMOD_Channel_AddDSP: *fmod.get(b"FMOD_Channel_AddDSP\0")?,
+            FMOD_Channel_RemoveDSP: *fmod.get(b"FMOD_Channel_RemoveDSP\0")?,
+            FMOD_Channel_GetNumDSPs: *fmod.get(b"FMOD_Channel_GetNumDSPs\0")?,
+            FMOD_Channel_SetDSPIndex: *fmod.get(b"FMOD_Channel_SetDSPIndex\0")?,
+            FMOD_Channel_GetDSPIndex: *fmod.get(b"FMOD_Channel_GetDSPIndex\0")?,
+            FMOD_Channel_Set3DAttributes: *fmod.get(b"FMOD_Channel_Set3DAttributes\0")?,
+            FMOD_Channel_Get3DAttributes: *fmod.get(b"FMOD_Channel_Get3DAttributes\0")?,
+            FMOD_Channel_Set3DMinMaxDistance: *fmod.get(b"FMOD_Channel_Set3DMinMaxDistance\0")?,
+            FMOD_Channel_Get3DMinMaxDistance: *fmod.get(b"FMOD_Channel_Get3DMinMaxDistance\0")?,
+            FMOD_Channel_Set3DConeSettings: *fmod.get(b"FMOD_Channel_Set3DConeSettings\0")?,
+            FMOD_Channel_Get3DConeSettings: *fmod.get(b"FMOD_Channel_Get3DConeSettings\0")?,
+            FMOD_Channel_Set3DConeOrientation: *fmod.get(b"FMOD_Channel_Set3DConeOrientation\0")?,
+            FMOD_Channel_Get3DConeOrientation: *fmod.get(b"FMOD_Channel_Get3DConeOrientation\0")?,
+            FMOD_Channel_Set3DCustomRolloff: *fmod.get(b"FMOD_Channel_Set3DCustomRolloff\0")?,
+            FMOD_Channel_Get3DCustomRolloff: *fmod.get(b"FMOD_Channel_Get3DCustomRolloff\0")?,
+            FMOD_Channel_Set3DOcclusion: *fmod.get(b"FMOD_Channel_Set3DOcclusion\0")?,
+            FMOD_Channel_Get3DOcclusion: *fmod.get(b"FMOD_Channel_Get3DOcclusion\0")?,
+            FMOD_Channel_Set3DSpread: *fmod.get(b"FMOD_Channel_Set3DSpread\0")?,
+            FMOD_Channel_Get3DSpread: *fmod.get(b"FMOD_Channel_Get3DSpread\0")?,
+            FMOD_Channel_Set3DLevel: *fmod.get(b"FMOD_Channel_Set3DLevel\0")?,
+            FMOD_Channel_Get3DLevel: *fmod.get(b"FMOD_Channel_Get3DLevel\0")?,
+            FMOD_Channel_Set3DDopplerLevel: *fmod.get(b"FMOD_Channel_Set3DDopplerLevel\0")?,
+            FMOD_Channel_Get3DDopplerLevel: *fmod.get(b"FMOD_Channel_Get3DDopplerLevel\0")?,
+            FMOD_Channel_Set3DDistanceFilter: *fmod.get(b"FMOD_Channel_Set3DDistanceFilter\0")?,
+            FMOD_Channel_Get3DDistanceFilter: *fmod.get(b"FMOD_Channel_Get3DDistanceFilter\0")?,
+            FMOD_Channel_SetUserData: *fmod.get(b"FMOD_Channel_SetUserData\0")?,
+            FMOD_Channel_GetUserData: *fmod.get(b"FMOD_Channel_GetUserData\0")?,
+            FMOD_Channel_SetFrequency: *fmod.get(b"FMOD_Channel_SetFrequency\0")?,
+            FMOD_Channel_GetFrequency: *fmod.get(b"FMOD_Channel_GetFrequency\0")?,
+            FMOD_Channel_SetPriority: *fmod.get(b"FMOD_Channel_SetPriority\0")?,
+            FMOD_Channel_GetPriority: *fmod.get(b"FMOD_Channel_GetPriority\0")?,
+            FMOD_Channel_SetPosition: *fmod.get(b"FMOD_Channel_SetPosition\0")?,
+            FMOD_Channel_GetPosition: *fmod.get(b"FMOD_Channel_GetPosition\0")?,
+            FMOD_Channel_SetChannelGroup: *fmod.get(b"FMOD_Channel_SetChannelGroup\0")?,
+            FMOD_Channel_GetChannelGroup: *fmod.get(b"FMOD_Channel_GetChannelGroup\0")?,
+            FMOD_Channel_SetLoopCount: *fmod.get(b"FMOD_Channel_SetLoopCount\0")?,
+            FMOD_Channel_GetLoopCount: *fmod.get(b"FMOD_Channel_GetLoopCount\0")?,
+            FMOD_Channel_SetLoopPoints: *fmod.get(b"FMOD_Channel_SetLoopPoints\0")?,
+            FMOD_Channel_GetLoopPoints: *fmod.get(b"FMOD_Channel_GetLoopPoints\0")?,
+            FMOD_Channel_IsVirtual: *fmod.get(b"FMOD_Channel_IsVirtual\0")?,
+            FMOD_Channel_GetCurrentSound: *fmod.get(b"FMOD_Channel_GetCurrentSound\0")?,
+            FMOD_Channel_GetIndex: *fmod.get(b"FMOD_Channel_GetIndex\0")?,
+            FMOD_ChannelGroup_GetSystemObject: *fmod.get(b"FMOD_ChannelGroup_GetSystemObject\0")?,
+            FMOD_ChannelGroup_Stop: *fmod.get(b"FMOD_ChannelGroup_Stop\0")?,
+            FMOD_ChannelGroup_SetPaused: *fmod.get(b"FMOD_ChannelGroup_SetPaused\0")?,
+            FMOD_ChannelGroup_GetPaused: *fmod.get(b"FMOD_ChannelGroup_GetPaused\0")?,
+            FMOD_ChannelGroup_SetVolume: *fmod.get(b"FMOD_ChannelGroup_SetVolume\0")?,
+            FMOD_ChannelGroup_GetVolume: *fmod.get(b"FMOD_ChannelGroup_GetVolume\0")?,
+            FMOD_ChannelGroup_SetVolumeRamp: *fmod.get(b"FMOD_ChannelGroup_SetVolumeRamp\0")?,
+            FMOD_ChannelGroup_GetVolumeRamp: *fmod.get(b"FMOD_ChannelGroup_GetVolumeRamp\0")?,
+            FMOD_ChannelGroup_GetAudibility: *fmod.get(b"FMOD_ChannelGroup_GetAudibility\0")?,
+            FMOD_ChannelGroup_SetPitch: *fmod.get(b"FMOD_ChannelGroup_SetPitch\0")?,
+            FMOD_ChannelGroup_GetPitch: *fmod.get(b"FMOD_ChannelGroup_GetPitch\0")?,
+            FMOD_ChannelGroup_SetMute: *fmod.get(b"FMOD_ChannelGroup_SetMute\0")?,
+            FMOD_ChannelGroup_GetMute: *fmod.get(b"FMOD_ChannelGroup_GetMute\0")?,
+            FMOD_ChannelGroup_SetReverbProperties: *fmod
+                .get(b"FMOD_ChannelGroup_SetReverbProperties\0")?,
+            FMOD_ChannelGroup_GetReverbProperties: *fmod
+                .get(b"FMOD_ChannelGroup_GetReverbProperties\0")?,
+            FMOD_ChannelGroup_SetLowPassGain: *fmod.get(b"FMOD_ChannelGroup_SetLowPassGain\0")?,
+            FMOD_ChannelGroup_GetLowPassGain: *fmod.get(b"FMOD_ChannelGroup_GetLowPassGain\0")?,
+            FMOD_ChannelGroup_SetMode: *fmod.get(b"FMOD_ChannelGroup_SetMode\0")?,
+            FMOD_ChannelGroup_GetMode: *fmod.get(b"FMOD_ChannelGroup_GetMode\0")?,
+            FMOD_ChannelGroup_SetCallback: *fmod.get(b"FMOD_ChannelGroup_SetCallback\0")?,
+            FMOD_ChannelGroup_IsPlaying: *fmod.get(b"FMOD_ChannelGroup_IsPlaying\0")?,
+            FMOD_ChannelGroup_SetPan: *fmod.get(b"FMOD_ChannelGroup_SetPan\0")?,
+            FMOD_ChannelGroup_SetMixLevelsOutput: *fmod
+                .get(b"FMOD_ChannelGroup_SetMixLevelsOutput\0")?,
+            FMOD_ChannelGroup_SetMixLevelsInput: *fmod
+                .get(b"FMOD_ChannelGroup_SetMixLevelsInput\0")?,
+            FMOD_ChannelGroup_SetMixMatrix: *fmod.get(b"FMOD_ChannelGroup_SetMixMatrix\0")?,
+            FMOD_ChannelGroup_GetMixMatrix: *fmod.get(b"FMOD_ChannelGroup_GetMixMatrix\0")?,
+            FMOD_ChannelGroup_GetDSPClock: *fmod.get(b"FMOD_ChannelGroup_GetDSPClock\0")?,
+            FMOD_ChannelGroup_SetDelay: *fmod.get(b"FMOD_ChannelGroup_SetDelay\0")?,
+            FMOD_ChannelGroup_GetDelay: *fmod.get(b"FMOD_ChannelGroup_GetDelay\0")?,
+            FMOD_ChannelGroup_AddFadePoint: *fmod.get(b"FMOD_ChannelGroup_AddFadePoint\0")?,
+            FMOD_ChannelGroup_SetFadePointRamp: *fmod
+                .get(b"FMOD_ChannelGroup_SetFadePointRamp\0")?,
+            FMOD_ChannelGroup_RemoveFadePoints: *fmod
+                .get(b"FMOD_ChannelGroup_RemoveFadePoints\0")?,
+            FMOD_ChannelGroup_GetFadePoints: *fmod.get(b"FMOD_ChannelGroup_GetFadePoints\0")?,
+            FMOD_ChannelGroup_GetDSP: *fmod.get(b"FMOD_ChannelGroup_GetDSP\0")?,
+            FMOD_ChannelGroup_AddDSP: *fmod.get(b"FMOD_ChannelGroup_AddDSP\0")?,
+            FMOD_ChannelGroup_RemoveDSP: *fmod.get(b"FMOD_ChannelGroup_RemoveDSP\0")?,
+            FMOD_ChannelGroup_GetNumDSPs: *fmod.get(b"FMOD_ChannelGroup_GetNumDSPs\0")?,
+            FMOD_ChannelGroup_SetDSPIndex: *fmod.get(b"FMOD_ChannelGroup_SetDSPIndex\0")?,
+            FMOD_ChannelGroup_GetDSPIndex: *fmod.get(b"FMOD_ChannelGroup_GetDSPIndex\0")?,
+            FMOD_ChannelGroup_Set3DAttributes: *fmod.get(b"FMOD_ChannelGroup_Set3DAttributes\0")?,
+            FMOD_ChannelGroup_Get3DAttributes: *fmod.get(b"FMOD_ChannelGroup_Get3DAttributes\0")?,
+            FMOD_ChannelGroup_Set3DMinMaxDistance: *fmod
+                .get(b"FMOD_ChannelGroup_Set3DMinMaxDistance\0")?,
+            FMOD_ChannelGroup_Get3DMinMaxDistance: *fmod
+                .get(b"FMOD_ChannelGroup_Get3DMinMaxDistance\0")?,
+            FMOD_ChannelGroup_Set3DConeSettings: *fmod
+                .get(b"FMOD_ChannelGroup_Set3DConeSettings\0")?,
+            FMOD_ChannelGroup_Get3DConeSettings: *fmod
+                .get(b"FMOD_ChannelGroup_Get3DConeSettings\0")?,
+            FMOD_ChannelGroup_Set3DConeOrientation: *fmod
+                .get(b"FMOD_ChannelGroup_Set3DConeOrientation\0")?,
+            FMOD_ChannelGroup_Get3DConeOrientation: *fmod
+                .get(b"FMOD_ChannelGroup_Get3DConeOrientation\0")?,
+            FMOD_ChannelGroup_Set3DCustomRolloff: *fmod
+                .get(b"FMOD_ChannelGroup_Set3DCustomRolloff\0")?,
+            FMOD_ChannelGroup_Get3DCustomRolloff: *fmod
+                .get(b"FMOD_ChannelGroup_Get3DCustomRolloff\0")?,
+            FMOD_ChannelGroup_Set3DOcclusion: *fmod.get(b"FMOD_ChannelGroup_Set3DOcclusion\0")?,
+            FMOD_ChannelGroup_Get3DOcclusion: *fmod.get(b"FMOD_ChannelGroup_Get3DOcclusion\0")?,
+            FMOD_ChannelGroup_Set3DSpread: *fmod.get(b"FMOD_ChannelGroup_Set3DSpread\0")?,
+            FMOD_ChannelGroup_Get3DSpread: *fmod.get(b"FMOD_ChannelGroup_Get3DSpread\0")?,
+            FMOD_ChannelGroup_Set3DLevel: *fmod.get(b"FMOD_ChannelGroup_Set3DLevel\0")?,
+            FMOD_ChannelGroup_Get3DLevel: *fmod.get(b"FMOD_ChannelGroup_Get3DLevel\0")?,
+            FMOD_ChannelGroup_Set3DDopplerLevel: *fmod
+                .get(b"FMOD_ChannelGroup_Set3DDopplerLevel\0")?,
+            FMOD_ChannelGroup_Get3DDopplerLevel: *fmod
+                .get(b"FMOD_ChannelGroup_Get3DDopplerLevel\0")?,
+            FMOD_ChannelGroup_Set3DDistanceFilter: *fmod
+                .get(b"FMOD_ChannelGroup_Set3DDistanceFilter\0")?,
+            FMOD_ChannelGroup_Get3DDistanceFilter: *fmod
+                .get(b"FMOD_ChannelGroup_Get3DDistanceFilter\0")?,
+            FMOD_ChannelGroup_SetUserData: *fmod.get(b"FMOD_ChannelGroup_SetUserData\0")?,
+            FMOD_ChannelGroup_GetUserData: *fmod.get(b"FMOD_ChannelGroup_GetUserData\0")?,
+            FMOD_ChannelGroup_Release: *fmod.get(b"FMOD_ChannelGroup_Release\0")?,
+            FMOD_ChannelGroup_AddGroup: *fmod.get(b"FMOD_ChannelGroup_AddGroup\0")?,
+            FMOD_ChannelGroup_GetNumGroups: *fmod.get(b"FMOD_ChannelGroup_GetNumGroups\0")?,
+            FMOD_ChannelGroup_GetGroup: *fmod.get(b"FMOD_ChannelGroup_GetGroup\0")?,
+            FMOD_ChannelGroup_GetParentGroup: *fmod.get(b"FMOD_ChannelGroup_GetParentGroup\0")?,
+            FMOD_ChannelGroup_GetName: *fmod.get(b"FMOD_ChannelGroup_GetName\0")?,
+            FMOD_ChannelGroup_GetNumChannels: *fmod.get(b"FMOD_ChannelGroup_GetNumChannels\0")?,
+            FMOD_ChannelGroup_GetChannel: *fmod.get(b"FMOD_ChannelGroup_GetChannel\0")?,
+            FMOD_SoundGroup_Release: *fmod.get(b"FMOD_SoundGroup_Release\0")?,
+            FMOD_SoundGroup_GetSystemObject: *fmod.get(b"FMOD_SoundGroup_GetSystemObject\0")?,
+            FMOD_SoundGroup_SetMaxAudible: *fmod.get(b"FMOD_SoundGroup_SetMaxAudible\0")?,
+            FMOD_SoundGroup_GetMaxAudible: *fmod.get(b"FMOD_SoundGroup_GetMaxAudible\0")?,
+            FMOD_SoundGroup_SetMaxAudibleBehavior: *fmod
+                .get(b"FMOD_SoundGroup_SetMaxAudibleBehavior\0")?,
+            FMOD_SoundGroup_GetMaxAudibleBehavior: *fmod
+                .get(b"FMOD_SoundGroup_GetMaxAudibleBehavior\0")?,
+            FMOD_SoundGroup_SetMuteFadeSpeed: *fmod.get(b"FMOD_SoundGroup_SetMuteFadeSpeed\0")?,
+            FMOD_SoundGroup_GetMuteFadeSpeed: *fmod.get(b"FMOD_SoundGroup_GetMuteFadeSpeed\0")?,
+            FMOD_SoundGroup_SetVolume: *fmod.get(b"FMOD_SoundGroup_SetVolume\0")?,
+            FMOD_SoundGroup_GetVolume: *fmod.get(b"FMOD_SoundGroup_GetVolume\0")?,
+            FMOD_SoundGroup_Stop: *fmod.get(b"FMOD_SoundGroup_Stop\0")?,
+            FMOD_SoundGroup_GetName: *fmod.get(b"FMOD_SoundGroup_GetName\0")?,
+            FMOD_SoundGroup_GetNumSounds: *fmod.get(b"FMOD_SoundGroup_GetNumSounds\0")?,
+            FMOD_SoundGroup_GetSound: *fmod.get(b"FMOD_SoundGroup_GetSound\0")?,
+            FMOD_SoundGroup_GetNumPlaying: *fmod.get(b"FMOD_SoundGroup_GetNumPlaying\0")?,
+            FMOD_SoundGroup_SetUserData: *fmod.get(b"FMOD_SoundGroup_SetUserData\0")?,
+            FMOD_SoundGroup_GetUserData: *fmod.get(b"FMOD_SoundGroup_GetUserData\0")?,
+            FMOD_DSP_Release: *fmod.get(b"FMOD_DSP_Release\0")?,
+            FMOD_DSP_GetSystemObject: *fmod.get(b"FMOD_DSP_GetSystemObject\0")?,
+            FMOD_DSP_AddInput: *fmod.get(b"FMOD_DSP_AddInput\0")?,
+            FMOD_DSP_DisconnectFrom: *fmod.get(b"FMOD_DSP_DisconnectFrom\0")?,
+            FMOD_DSP_DisconnectAll: *fmod.get(b"FMOD_DSP_DisconnectAll\0")?,
+            FMOD_DSP_GetNumInputs: *fmod.get(b"FMOD_DSP_GetNumInputs\0")?,
+            FMOD_DSP_GetNumOutputs: *fmod.get(b"FMOD_DSP_GetNumOutputs\0")?,
+            FMOD_DSP_GetInput: *fmod.get(b"FMOD_DSP_GetInput\0")?,
+            FMOD_DSP_GetOutput: *fmod.get(b"FMOD_DSP_GetOutput\0")?,
+            FMOD_DSP_SetActive: *fmod.get(b"FMOD_DSP_SetActive\0")?,
+            FMOD_DSP_GetActive: *fmod.get(b"FMOD_DSP_GetActive\0")?,
+            FMOD_DSP_SetBypass: *fmod.get(b"FMOD_DSP_SetBypass\0")?,
+            FMOD_DSP_GetBypass: *fmod.get(b"FMOD_DSP_GetBypass\0")?,
+            FMOD_DSP_SetWetDryMix: *fmod.get(b"FMOD_DSP_SetWetDryMix\0")?,
+            FMOD_DSP_GetWetDryMix: *fmod.get(b"FMOD_DSP_GetWetDryMix\0")?,
+            FMOD_DSP_SetChannelFormat: *fmod.get(b"FMOD_DSP_SetChannelFormat\0")?,
+            FMOD_DSP_GetChannelFormat: *fmod.get(b"FMOD_DSP_GetChannelFormat\0")?,
+            FMOD_DSP_GetOutputChannelFormat: *fmod.get(b"FMOD_DSP_GetOutputChannelFormat\0")?,
+            FMOD_DSP_Reset: *fmod.get(b"FMOD_DSP_Reset\0")?,
+            FMOD_DSP_SetCallback: *fmod.get(b"FMOD_DSP_SetCallback\0")?,
+            FMOD_DSP_SetParameterFloat: *fmod.get(b"FMOD_DSP_SetParameterFloat\0")?,
+            FMOD_DSP_SetParameterInt: *fmod.get(b"FMOD_DSP_SetParameterInt\0")?,
+            FMOD_DSP_SetParameterBool: *fmod.get(b"FMOD_DSP_SetParameterBool\0")?,
+            FMOD_DSP_SetParameterData: *fmod.get(b"FMOD_DSP_SetParameterData\0")?,
+            FMOD_DSP_GetParameterFloat: *fmod.get(b"FMOD_DSP_GetParameterFloat\0")?,
+            FMOD_DSP_GetParameterInt: *fmod.get(b"FMOD_DSP_GetParameterInt\0")?,
+            FMOD_DSP_GetParameterBool: *fmod.get(b"FMOD_DSP_GetParameterBool\0")?,
+            FMOD_DSP_GetParameterData: *fmod.get(b"FMOD_DSP_GetParameterData\0")?,
+            FMOD_DSP_GetNumParameters: *fmod.get(b"FMOD_DSP_GetNumParameters\0")?,
+            FMOD_DSP_GetParameterInfo: *fmod.get(b"FMOD_DSP_GetParameterInfo\0")?,
+            FMOD_DSP_GetDataParameterIndex: *fmod.get(b"FMOD_DSP_GetDataParameterIndex\0")?,
+            FMOD_DSP_ShowConfigDialog: *fmod.get(b"FMOD_DSP_ShowConfigDialog\0")?,
+            FMOD_DSP_GetInfo: *fmod.get(b"FMOD_DSP_GetInfo\0")?,
+            FMOD_DSP_GetType: *fmod.get(b"FMOD_DSP_GetType\0")?,
+            FMOD_DSP_GetIdle: *fmod.get(b"FMOD_DSP_GetIdle\0")?,
+            FMOD_DSP_SetUserData: *fmod.get(b"FMOD_DSP_SetUserData\0")?,
+            FMOD_DSP_GetUserData: *fmod.get(b"FMOD_DSP_GetUserData\0")?,
+            FMOD_DSP_SetMeteringEnabled: *fmod.get(b"FMOD_DSP_SetMeteringEnabled\0")?,
+            FMOD_DSP_GetMeteringEnabled: *fmod.get(b"FMOD_DSP_GetMeteringEnabled\0")?,
+            FMOD_DSP_GetMeteringInfo: *fmod.get(b"FMOD_DSP_GetMeteringInfo\0")?,
+            FMOD_DSP_GetCPUUsage: *fmod.get(b"FMOD_DSP_GetCPUUsage\0")?,
+            FMOD_DSPConnection_GetInput: *fmod.get(b"FMOD_DSPConnection_GetInput\0")?,
+            FMOD_DSPConnection_GetOutput: *fmod.get(b"FMOD_DSPConnection_GetOutput\0")?,
+            FMOD_DSPConnection_SetMix: *fmod.get(b"FMOD_DSPConnection_SetMix\0")?,
+            FMOD_DSPConnection_GetMix: *fmod.get(b"FMOD_DSPConnection_GetMix\0")?,
+            FMOD_DSPConnection_SetMixMatrix: *fmod.get(b"FMOD_DSPConnection_SetMixMatrix\0")?,
+            FMOD_DSPConnection_GetMixMatrix: *fmod.get(b"FMOD_DSPConnection_GetMixMatrix\0")?,
+            FMOD_DSPConnection_GetType: *fmod.get(b"FMOD_DSPConnection_GetType\0")?,
+            FMOD_DSPConnection_SetUserData: *fmod.get(b"FMOD_DSPConnection_SetUserData\0")?,
+            FMOD_DSPConnection_GetUserData: *fmod.get(b"FMOD_DSPConnection_GetUserData\0")?,
+            FMOD_Geometry_Release: *fmod.get(b"FMOD_Geometry_Release\0")?,
+            FMOD_Geometry_AddPolygon: *fmod.get(b"FMOD_Geometry_AddPolygon\0")?,
+            FMOD_Geometry_GetNumPolygons: *fmod.get(b"FMOD_Geometry_GetNumPolygons\0")?,
+            FMOD_Geometry_GetMaxPolygons: *fmod.get(b"FMOD_Geometry_GetMaxPolygons\0")?,
+            FMOD_Geometry_GetPolygonNumVertices: *fmod
+                .get(b"FMOD_Geometry_GetPolygonNumVertices\0")?,
+            FMOD_Geometry_SetPolygonVertex: *fmod.get(b"FMOD_Geometry_SetPolygonVertex\0")?,
+            FMOD_Geometry_GetPolygonVertex: *fmod.get(b"FMOD_Geometry_GetPolygonVertex\0")?,
+            FMOD_Geometry_SetPolygonAttributes: *fmod
+                .get(b"FMOD_Geometry_SetPolygonAttributes\0")?,
+            FMOD_Geometry_GetPolygonAttributes: *fmod
+                .get(b"FMOD_Geometry_GetPolygonAttributes\0")?,
+            FMOD_Geometry_SetActive: *fmod.get(b"FMOD_Geometry_SetActive\0")?,
+            FMOD_Geometry_GetActive: *fmod.get(b"FMOD_Geometry_GetActive\0")?,
+            FMOD_Geometry_SetRotation: *fmod.get(b"FMOD_Geometry_SetRotation\0")?,
+            FMOD_Geometry_GetRotation: *fmod.get(b"FMOD_Geometry_GetRotation\0")?,
+            FMOD_Geometry_SetPosition: *fmod.get(b"FMOD_Geometry_SetPosition\0")?,
+            FMOD_Geometry_GetPosition: *fmod.get(b"FMOD_Geometry_GetPosition\0")?,
+            FMOD_Geometry_SetScale: *fmod.get(b"FMOD_Geometry_SetScale\0")?,
+            FMOD_Geometry_GetScale: *fmod.get(b"FMOD_Geometry_GetScale\0")?,
+            FMOD_Geometry_Save: *fmod.get(b"FMOD_Geometry_Save\0")?,
+            FMOD_Geometry_SetUserData: *fmod.get(b"FMOD_Geometry_SetUserData\0")?,
+            FMOD_Geometry_GetUserData: *fmod.get(b"FMOD_Geometry_GetUserData\0")?,
+            FMOD_Reverb3D_Release: *fmod.get(b"FMOD_Reverb3D_Release\0")?,
+            FMOD_Reverb3D_Set3DAttributes: *fmod.get(b"FMOD_Reverb3D_Set3DAttributes\0")?,
+            FMOD_Reverb3D_Get3DAttributes: *fmod.get(b"FMOD_Reverb3D_Get3DAttributes\0")?,
+            FMOD_Reverb3D_SetProperties: *fmod.get(b"FMOD_Reverb3D_SetProperties\0")?,
+            FMOD_Reverb3D_GetProperties: *fmod.get(b"FMOD_Reverb3D_GetProperties\0")?,
+            FMOD_Reverb3D_SetActive: *fmod.get(b"FMOD_Reverb3D_SetActive\0")?,
+            FMOD_Reverb3D_GetActive: *fmod.get(b"FMOD_Reverb3D_GetActive\0")?,
+            FMOD_Reverb3D_SetUserData: *fmod.get(b"FMOD_Reverb3D_SetUserData\0")?,
+            FMOD_Reverb3D_GetUserData: *fmod.get(b"FMOD_Reverb3D_GetUserData\0")?,
+            FMOD_Studio_ParseID: *fmodstudio.get(b"FMOD_Studio_ParseID\0")?,
+            FMOD_Studio_System_Create: *fmodstudio.get(b"FMOD_Studio_System_Create\0")?,
+            FMOD_Studio_System_IsValid: *fmodstudio.get(b"FMOD_Studio_System_IsValid\0")?,
+            FMOD_Studio_System_SetAdvancedSettings: *fmodstudio
+                .get(b"FMOD_Studio_System_SetAdvancedSettings\0")?,
+            FMOD_Studio_System_GetAdvancedSettings: *fmodstudio
+                .get(b"FMOD_Studio_System_GetAdvancedSettings\0")?,
+            FMOD_Studio_System_Initialize: *fmodstudio.get(b"FMOD_Studio_System_Initialize\0")?,
+            FMOD_Studio_System_Release: *fmodstudio.get(b"FMOD_Studio_System_Release\0")?,
+            FMOD_Studio_System_Update: *fmodstudio.get(b"FMOD_Studio_System_Update\0")?,
+            FMOD_Studio_System_GetCoreSystem: *fmodstudio
+                .get(b"FMOD_Studio_System_GetCoreSystem\0")?,
+            FMOD_Studio_System_GetEvent: *fmodstudio.get(b"FMOD_Studio_System_GetEvent\0")?,
+            FMOD_Studio_System_GetBus: *fmodstudio.get(b"FMOD_Studio_System_GetBus\0")?,
+            FMOD_Studio_System_GetVCA: *fmodstudio.get(b"FMOD_Studio_System_GetVCA\0")?,
+            FMOD_Studio_System_GetBank: *fmodstudio.get(b"FMOD_Studio_System_GetBank\0")?,
+            FMOD_Studio_System_GetEventByID: *fmodstudio
+                .get(b"FMOD_Studio_System_GetEventByID\0")?,
+            FMOD_Studio_System_GetBusByID: *fmodstudio.get(b"FMOD_Studio_System_GetBusByID\0")?,
+            FMOD_Studio_System_GetVCAByID: *fmodstudio.get(b"FMOD_Studio_System_GetVCAByID\0")?,
+            FMOD_Studio_System_GetBankByID: *fmodstudio.get(b"FMOD_Studio_System_GetBankByID\0")?,
+            FMOD_Studio_System_GetSoundInfo: *fmodstudio
+                .get(b"FMOD_Studio_System_GetSoundInfo\0")?,
+            FMOD_Studio_System_GetParameterDescriptionByName: *fmodstudio
+                .get(b"FMOD_Studio_System_GetParameterDescriptionByName\0")?,
+            FMOD_Studio_System_GetParameterDescriptionByID: *fmodstudio
+                .get(b"FMOD_Studio_System_GetParameterDescriptionByID\0")?,
+            FMOD_Studio_System_GetParameterLabelByName: *fmodstudio
+                .get(b"FMOD_Studio_System_GetParameterLabelByName\0")?,
+            FMOD_Studio_System_GetParameterLabelByID: *fmodstudio
+                .get(b"FMOD_Studio_System_GetParameterLabelByID\0")?,
+            FMOD_Studio_System_GetParameterByID: *fmodstudio
+                .get(b"FMOD_Studio_System_GetParameterByID\0")?,
+            FMOD_Studio_System_SetParameterByID: *fmodstudio
+                .get(b"FMOD_Studio_System_SetParameterByID\0")?,
+            FMOD_Studio_System_SetParameterByIDWithLabel: *fmodstudio
+                .get(b"FMOD_Studio_System_SetParameterByIDWithLabel\0")?,
+            FMOD_Studio_System_SetParametersByIDs: *fmodstudio
+                .get(b"FMOD_Studio_System_SetParametersByIDs\0")?,
+            FMOD_Studio_System_GetParameterByName: *fmodstudio
+                .get(b"FMOD_Studio_System_GetParameterByName\0")?,
+            FMOD_Studio_System_SetParameterByName: *fmodstudio
+                .get(b"FMOD_Studio_System_SetParameterByName\0")?,
+            FMOD_Studio_System_SetParameterByNameWithLabel: *fmodstudio
+                .get(b"FMOD_Studio_System_SetParameterByNameWithLabel\0")?,
+            FMOD_Studio_System_LookupID: *fmodstudio.get(b"FMOD_Studio_System_LookupID\0")?,
+            FMOD_Studio_System_LookupPath: *fmodstudio.get(b"FMOD_Studio_System_LookupPath\0")?,
+            FMOD_Studio_System_GetNumListeners: *fmodstudio
+                .get(b"FMOD_Studio_System_GetNumListeners\0")?,
+            FMOD_Studio_System_SetNumListeners: *fmodstudio
+                .get(b"FMOD_Studio_System_SetNumListeners\0")?,
+            FMOD_Studio_System_GetListenerAttributes: *fmodstudio
+                .get(b"FMOD_Studio_System_GetListenerAttributes\0")?,
+            FMOD_Studio_System_SetListenerAttributes: *fmodstudio
+                .get(b"FMOD_Studio_System_SetListenerAttributes\0")?,
+            FMOD_Studio_System_GetListenerWeight: *fmodstudio
+                .get(b"FMOD_Studio_System_GetListenerWeight\0")?,
+            FMOD_Studio_System_SetListenerWeight: *fmodstudio
+                .get(b"FMOD_Studio_System_SetListenerWeight\0")?,
+            FMOD_Studio_System_LoadBankFile: *fmodstudio
+                .get(b"FMOD_Studio_System_LoadBankFile\0")?,
+            FMOD_Studio_System_LoadBankMemory: *fmodstudio
+                .get(b"FMOD_Studio_System_LoadBankMemory\0")?,
+            FMOD_Studio_System_LoadBankCustom: *fmodstudio
+                .get(b"FMOD_Studio_System_LoadBankCustom\0")?,
+            FMOD_Studio_System_RegisterPlugin: *fmodstudio
+                .get(b"FMOD_Studio_System_RegisterPlugin\0")?,
+            FMOD_Studio_System_UnregisterPlugin: *fmodstudio
+                .get(b"FMOD_Studio_System_UnregisterPlugin\0")?,
+            FMOD_Studio_System_UnloadAll: *fmodstudio.get(b"FMOD_Studio_System_UnloadAll\0")?,
+            FMOD_Studio_System_FlushCommands: *fmodstudio
+                .get(b"FMOD_Studio_System_FlushCommands\0")?,
+            FMOD_Studio_System_FlushSampleLoading: *fmodstudio
+                .get(b"FMOD_Studio_System_FlushSampleLoading\0")?,
+            FMOD_Studio_System_StartCommandCapture: *fmodstudio
+                .get(b"FMOD_Studio_System_StartCommandCapture\0")?,
+            FMOD_Studio_System_StopCommandCapture: *fmodstudio
+                .get(b"FMOD_Studio_System_StopCommandCapture\0")?,
+            FMOD_Studio_System_LoadCommandReplay: *fmodstudio
+                .get(b"FMOD_Studio_System_LoadCommandReplay\0")?,
+            FMOD_Studio_System_GetBankCount: *fmodstudio
+                .get(b"FMOD_Studio_System_GetBankCount\0")?,
+            FMOD_Studio_System_GetBankList: *fmodstudio.get(b"FMOD_Studio_System_GetBankList\0")?,
+            FMOD_Studio_System_GetParameterDescriptionCount: *fmodstudio
+                .get(b"FMOD_Studio_System_GetParameterDescriptionCount\0")?,
+            FMOD_Studio_System_GetParameterDescriptionList: *fmodstudio
+                .get(b"FMOD_Studio_System_GetParameterDescriptionList\0")?,
+            FMOD_Studio_System_GetCPUUsage: *fmodstudio.get(b"FMOD_Studio_System_GetCPUUsage\0")?,
+            FMOD_Studio_System_GetBufferUsage: *fmodstudio
+                .get(b"FMOD_Studio_System_GetBufferUsage\0")?,
+            FMOD_Studio_System_ResetBufferUsage: *fmodstudio
+                .get(b"FMOD_Studio_System_ResetBufferUsage\0")?,
+            FMOD_Studio_System_SetCallback: *fmodstudio.get(b"FMOD_Studio_System_SetCallback\0")?,
+            FMOD_Studio_System_SetUserData: *fmodstudio.get(b"FMOD_Studio_System_SetUserData\0")?,
+            FMOD_Studio_System_GetUserData: *fmodstudio.get(b"FMOD_Studio_System_GetUserData\0")?,
+            FMOD_Studio_System_GetMemoryUsage: *fmodstudio
+                .get(b"FMOD_Studio_System_GetMemoryUsage\0")?,
+            FMOD_Studio_EventDescription_IsValid: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_IsValid\0")?,
+            FMOD_Studio_EventDescription_GetID: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetID\0")?,
+            FMOD_Studio_EventDescription_GetPath: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetPath\0")?,
+            FMOD_Studio_EventDescription_GetParameterDescriptionCount: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetParameterDescriptionCount\0")?,
+            FMOD_Studio_EventDescription_GetParameterDescriptionByIndex: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetParameterDescriptionByIndex\0")?,
+            FMOD_Studio_EventDescription_GetParameterDescriptionByName: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetParameterDescriptionByName\0")?,
+            FMOD_Studio_EventDescription_GetParameterDescriptionByID: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetParameterDescriptionByID\0")?,
+            FMOD_Studio_EventDescription_GetParameterLabelByIndex: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetParameterLabelByIndex\0")?,
+            FMOD_Studio_EventDescription_GetParameterLabelByName: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetParameterLabelByName\0")?,
+            FMOD_Studio_EventDescription_GetParameterLabelByID: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetParameterLabelByID\0")?,
+            FMOD_Studio_EventDescription_GetUserPropertyCount: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetUserPropertyCount\0")?,
+            FMOD_Studio_EventDescription_GetUserPropertyByIndex: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetUserPropertyByIndex\0")?,
+            FMOD_Studio_EventDescription_GetUserProperty: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetUserProperty\0")?,
+            FMOD_Studio_EventDescription_GetLength: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetLength\0")?,
+            FMOD_Studio_EventDescription_GetMinMaxDistance: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetMinMaxDistance\0")?,
+            FMOD_Studio_EventDescription_GetSoundSize: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetSoundSize\0")?,
+            FMOD_Studio_EventDescription_IsSnapshot: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_IsSnapshot\0")?,
+            FMOD_Studio_EventDescription_IsOneshot: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_IsOneshot\0")?,
+            FMOD_Studio_EventDescription_IsStream: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_IsStream\0")?,
+            FMOD_Studio_EventDescription_Is3D: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_Is3D\0")?,
+            FMOD_Studio_EventDescription_IsDopplerEnabled: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_IsDopplerEnabled\0")?,
+            FMOD_Studio_EventDescription_HasSustainPoint: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_HasSustainPoint\0")?,
+            FMOD_Studio_EventDescription_CreateInstance: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_CreateInstance\0")?,
+            FMOD_Studio_EventDescription_GetInstanceCount: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetInstanceCount\0")?,
+            FMOD_Studio_EventDescription_GetInstanceList: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetInstanceList\0")?,
+            FMOD_Studio_EventDescription_LoadSampleData: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_LoadSampleData\0")?,
+            FMOD_Studio_EventDescription_UnloadSampleData: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_UnloadSampleData\0")?,
+            FMOD_Studio_EventDescription_GetSampleLoadingState: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetSampleLoadingState\0")?,
+            FMOD_Studio_EventDescription_ReleaseAllInstances: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_ReleaseAllInstances\0")?,
+            FMOD_Studio_EventDescription_SetCallback: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_SetCallback\0")?,
+            FMOD_Studio_EventDescription_GetUserData: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_GetUserData\0")?,
+            FMOD_Studio_EventDescription_SetUserData: *fmodstudio
+                .get(b"FMOD_Studio_EventDescription_SetUserData\0")?,
+            FMOD_Studio_EventInstance_IsValid: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_IsValid\0")?,
+            FMOD_Studio_EventInstance_GetDescription: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetDescription\0")?,
+            FMOD_Studio_EventInstance_GetVolume: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetVolume\0")?,
+            FMOD_Studio_EventInstance_SetVolume: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetVolume\0")?,
+            FMOD_Studio_EventInstance_GetPitch: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetPitch\0")?,
+            FMOD_Studio_EventInstance_SetPitch: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetPitch\0")?,
+            FMOD_Studio_EventInstance_Get3DAttributes: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_Get3DAttributes\0")?,
+            FMOD_Studio_EventInstance_Set3DAttributes: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_Set3DAttributes\0")?,
+            FMOD_Studio_EventInstance_GetListenerMask: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetListenerMask\0")?,
+            FMOD_Studio_EventInstance_SetListenerMask: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetListenerMask\0")?,
+            FMOD_Studio_EventInstance_GetProperty: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetProperty\0")?,
+            FMOD_Studio_EventInstance_SetProperty: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetProperty\0")?,
+            FMOD_Studio_EventInstance_GetReverbLevel: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetReverbLevel\0")?,
+            FMOD_Studio_EventInstance_SetReverbLevel: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetReverbLevel\0")?,
+            FMOD_Studio_EventInstance_GetPaused: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetPaused\0")?,
+            FMOD_Studio_EventInstance_SetPaused: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetPaused\0")?,
+            FMOD_Studio_EventInstance_Start: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_Start\0")?,
+            FMOD_Studio_EventInstance_Stop: *fmodstudio.get(b"FMOD_Studio_EventInstance_Stop\0")?,
+            FMOD_Studio_EventInstance_GetTimelinePosition: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetTimelinePosition\0")?,
+            FMOD_Studio_EventInstance_SetTimelinePosition: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetTimelinePosition\0")?,
+            FMOD_Studio_EventInstance_GetPlaybackState: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetPlaybackState\0")?,
+            FMOD_Studio_EventInstance_GetChannelGroup: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetChannelGroup\0")?,
+            FMOD_Studio_EventInstance_GetMinMaxDistance: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetMinMaxDistance\0")?,
+            FMOD_Studio_EventInstance_Release: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_Release\0")?,
+            FMOD_Studio_EventInstance_IsVirtual: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_IsVirtual\0")?,
+            FMOD_Studio_EventInstance_GetParameterByName: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetParameterByName\0")?,
+            FMOD_Studio_EventInstance_SetParameterByName: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetParameterByName\0")?,
+            FMOD_Studio_EventInstance_SetParameterByNameWithLabel: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetParameterByNameWithLabel\0")?,
+            FMOD_Studio_EventInstance_GetParameterByID: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetParameterByID\0")?,
+            FMOD_Studio_EventInstance_SetParameterByID: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetParameterByID\0")?,
+            FMOD_Studio_EventInstance_SetParameterByIDWithLabel: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetParameterByIDWithLabel\0")?,
+            FMOD_Studio_EventInstance_SetParametersByIDs: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetParametersByIDs\0")?,
+            FMOD_Studio_EventInstance_KeyOff: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_KeyOff\0")?,
+            FMOD_Studio_EventInstance_SetCallback: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetCallback\0")?,
+            FMOD_Studio_EventInstance_GetUserData: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetUserData\0")?,
+            FMOD_Studio_EventInstance_SetUserData: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_SetUserData\0")?,
+            FMOD_Studio_EventInstance_GetCPUUsage: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetCPUUsage\0")?,
+            FMOD_Studio_EventInstance_GetMemoryUsage: *fmodstudio
+                .get(b"FMOD_Studio_EventInstance_GetMemoryUsage\0")?,
+            FMOD_Studio_Bus_IsValid: *fmodstudio.get(b"FMOD_Studio_Bus_IsValid\0")?,
+            FMOD_Studio_Bus_GetID: *fmodstudio.get(b"FMOD_Studio_Bus_GetID\0")?,
+            FMOD_Studio_Bus_GetPath: *fmodstudio.get(b"FMOD_Studio_Bus_GetPath\0")?,
+            FMOD_Studio_Bus_GetVolume: *fmodstudio.get(b"FMOD_Studio_Bus_GetVolume\0")?,
+            FMOD_Studio_Bus_SetVolume: *fmodstudio.get(b"FMOD_Studio_Bus_SetVolume\0")?,
+            FMOD_Studio_Bus_GetPaused: *fmodstudio.get(b"FMOD_Studio_Bus_GetPaused\0")?,
+            FMOD_Studio_Bus_SetPaused: *fmodstudio.get(b"FMOD_Studio_Bus_SetPaused\0")?,
+            FMOD_Studio_Bus_GetMute: *fmodstudio.get(b"FMOD_Studio_Bus_GetMute\0")?,
+            FMOD_Studio_Bus_SetMute: *fmodstudio.get(b"FMOD_Studio_Bus_SetMute\0")?,
+            FMOD_Studio_Bus_StopAllEvents: *fmodstudio.get(b"FMOD_Studio_Bus_StopAllEvents\0")?,
+            FMOD_Studio_Bus_GetPortIndex: *fmodstudio.get(b"FMOD_Studio_Bus_GetPortIndex\0")?,
+            FMOD_Studio_Bus_SetPortIndex: *fmodstudio.get(b"FMOD_Studio_Bus_SetPortIndex\0")?,
+            FMOD_Studio_Bus_LockChannelGroup: *fmodstudio
+                .get(b"FMOD_Studio_Bus_LockChannelGroup\0")?,
+            FMOD_Studio_Bus_UnlockChannelGroup: *fmodstudio
+                .get(b"FMOD_Studio_Bus_UnlockChannelGroup\0")?,
+            FMOD_Studio_Bus_GetChannelGroup: *fmodstudio
+                .get(b"FMOD_Studio_Bus_GetChannelGroup\0")?,
+            FMOD_Studio_Bus_GetCPUUsage: *fmodstudio.get(b"FMOD_Studio_Bus_GetCPUUsage\0")?,
+            FMOD_Studio_Bus_GetMemoryUsage: *fmodstudio.get(b"FMOD_Studio_Bus_GetMemoryUsage\0")?,
+            FMOD_Studio_VCA_IsValid: *fmodstudio.get(b"FMOD_Studio_VCA_IsValid\0")?,
+            FMOD_Studio_VCA_GetID: *fmodstudio.get(b"FMOD_Studio_VCA_GetID\0")?,
+            FMOD_Studio_VCA_GetPath: *fmodstudio.get(b"FMOD_Studio_VCA_GetPath\0")?,
+            FMOD_Studio_VCA_GetVolume: *fmodstudio.get(b"FMOD_Studio_VCA_GetVolume\0")?,
+            FMOD_Studio_VCA_SetVolume: *fmodstudio.get(b"FMOD_Studio_VCA_SetVolume\0")?,
+            FMOD_Studio_Bank_IsValid: *fmodstudio.get(b"FMOD_Studio_Bank_IsValid\0")?,
+            FMOD_Studio_Bank_GetID: *fmodstudio.get(b"FMOD_Studio_Bank_GetID\0")?,
+            FMOD_Studio_Bank_GetPath: *fmodstudio.get(b"FMOD_Studio_Bank_GetPath\0")?,
+            FMOD_Studio_Bank_Unload: *fmodstudio.get(b"FMOD_Studio_Bank_Unload\0")?,
+            FMOD_Studio_Bank_LoadSampleData: *fmodstudio
+                .get(b"FMOD_Studio_Bank_LoadSampleData\0")?,
+            FMOD_Studio_Bank_UnloadSampleData: *fmodstudio
+                .get(b"FMOD_Studio_Bank_UnloadSampleData\0")?,
+            FMOD_Studio_Bank_GetLoadingState: *fmodstudio
+                .get(b"FMOD_Studio_Bank_GetLoadingState\0")?,
+            FMOD_Studio_Bank_GetSampleLoadingState: *fmodstudio
+                .get(b"FMOD_Studio_Bank_GetSampleLoadingState\0")?,
+            FMOD_Studio_Bank_GetStringCount: *fmodstudio
+                .get(b"FMOD_Studio_Bank_GetStringCount\0")?,
+            FMOD_Studio_Bank_GetStringInfo: *fmodstudio.get(b"FMOD_Studio_Bank_GetStringInfo\0")?,
+            FMOD_Studio_Bank_GetEventCount: *fmodstudio.get(b"FMOD_Studio_Bank_GetEventCount\0")?,
+            FMOD_Studio_Bank_GetEventList: *fmodstudio.get(b"FMOD_Studio_Bank_GetEventList\0")?,
+            FMOD_Studio_Bank_GetBusCount: *fmodstudio.get(b"FMOD_Studio_Bank_GetBusCount\0")?,
+            FMOD_Studio_Bank_GetBusList: *fmodstudio.get(b"FMOD_Studio_Bank_GetBusList\0")?,
+            FMOD_Studio_Bank_GetVCACount: *fmodstudio.get(b"FMOD_Studio_Bank_GetVCACount\0")?,
+            FMOD_Studio_Bank_GetVCAList: *fmodstudio.get(b"FMOD_Studio_Bank_GetVCAList\0")?,
+            FMOD_Studio_Bank_GetUserData: *fmodstudio.get(b"FMOD_Studio_Bank_GetUserData\0")?,
+            FMOD_Studio_Bank_SetUserData: *fmodstudio.get(b"FMOD_Studio_Bank_SetUserData\0")?,
+            FMOD_Studio_CommandReplay_IsValid: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_IsValid\0")?,
+            FMOD_Studio_CommandReplay_GetSystem: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_GetSystem\0")?,
+            FMOD_Studio_CommandReplay_GetLength: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_GetLength\0")?,
+            FMOD_Studio_CommandReplay_GetCommandCount: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_GetCommandCount\0")?,
+            FMOD_Studio_CommandReplay_GetCommandInfo: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_GetCommandInfo\0")?,
+            FMOD_Studio_CommandReplay_GetCommandString: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_GetCommandString\0")?,
+            FMOD_Studio_CommandReplay_GetCommandAtTime: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_GetCommandAtTime\0")?,
+            FMOD_Studio_CommandReplay_SetBankPath: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_SetBankPath\0")?,
+            FMOD_Studio_CommandReplay_Start: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_Start\0")?,
+            FMOD_Studio_CommandReplay_Stop: *fmodstudio.get(b"FMOD_Studio_CommandReplay_Stop\0")?,
+            FMOD_Studio_CommandReplay_SeekToTime: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_SeekToTime\0")?,
+            FMOD_Studio_CommandReplay_SeekToCommand: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_SeekToCommand\0")?,
+            FMOD_Studio_CommandReplay_GetPaused: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_GetPaused\0")?,
+            FMOD_Studio_CommandReplay_SetPaused: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_SetPaused\0")?,
+            FMOD_Studio_CommandReplay_GetPlaybackState: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_GetPlaybackState\0")?,
+            FMOD_Studio_CommandReplay_GetCurrentCommand: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_GetCurrentCommand\0")?,
+            FMOD_Studio_CommandReplay_Release: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_Release\0")?,
+            FMOD_Studio_CommandReplay_SetFrameCallback: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_SetFrameCallback\0")?,
+            FMOD_Studio_CommandReplay_SetLoadBankCallback: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_SetLoadBankCallback\0")?,
+            FMOD_Studio_CommandReplay_SetCreateInstanceCallback: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_SetCreateInstanceCallback\0")?,
+            FMOD_Studio_CommandReplay_GetUserData: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_GetUserData\0")?,
+            FMOD_Studio_CommandReplay_SetUserData: *fmodstudio
+                .get(b"FMOD_Studio_CommandReplay_SetUserData\0")?,
+            _fmod: fmod,
+            _fmodstudio: fmodstudio,
+        })
+    }
+}
+#[cfg(feature = "dynamic")]
+pub static LIBRARY: std::sync::OnceLock<Library> = std::sync::OnceLock::new();
+#[cfg(feature = "dynamic")]
+pub const LIBRARY_NOT_LOADED: FMOD_RESULT = -1;
+#[cfg(feature = "dynamic")]
+#[inline]
+pub fn library() -> Result<&'static Library, crate::Error> {
+    LIBRARY
+        .get()
+        .ok_or_else(|| crate::Error::Library("not loaded".to_string()))
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Memory_Initialize(
+    poolmem: *mut c_void,
+    poollen: c_int,
+    useralloc: FMOD_MEMORY_ALLOC_CALLBACK,
+    userrealloc: FMOD_MEMORY_REALLOC_CALLBACK,
+    userfree: FMOD_MEMORY_FREE_CALLBACK,
+    memtypeflags: FMOD_MEMORY_TYPE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Memory_Initialize)(
+            poolmem,
+            poollen,
+            useralloc,
+            userrealloc,
+            userfree,
+            memtypeflags,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Memory_GetStats(
+    currentalloced: *mut c_int,
+    maxalloced: *mut c_int,
+    blocking: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Memory_GetStats)(currentalloced, maxalloced, blocking),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Debug_Initialize(
+    flags: FMOD_DEBUG_FLAGS,
+    mode: FMOD_DEBUG_MODE,
+    callback: FMOD_DEBUG_CALLBACK,
+    filename: *const c_char,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Debug_Initialize)(flags, mode, callback, filename),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_File_SetDiskBusy(busy: c_int) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_File_SetDiskBusy)(busy),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_File_GetDiskBusy(busy: *mut c_int) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_File_GetDiskBusy)(busy),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Thread_SetAttributes(
+    type_: FMOD_THREAD_TYPE,
+    affinity: FMOD_THREAD_AFFINITY,
+    priority: FMOD_THREAD_PRIORITY,
+    stacksize: FMOD_THREAD_STACK_SIZE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Thread_SetAttributes)(type_, affinity, priority, stacksize),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_Create(
+    system: *mut *mut FMOD_SYSTEM,
+    headerversion: c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_Create)(system, headerversion),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_Release(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_Release)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetOutput(
+    system: *mut FMOD_SYSTEM,
+    output: FMOD_OUTPUTTYPE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetOutput)(system, output),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetOutput(
+    system: *mut FMOD_SYSTEM,
+    output: *mut FMOD_OUTPUTTYPE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetOutput)(system, output),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetNumDrivers(
+    system: *mut FMOD_SYSTEM,
+    numdrivers: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetNumDrivers)(system, numdrivers),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetDriverInfo(
+    system: *mut FMOD_SYSTEM,
+    id: c_int,
+    name: *mut c_char,
+    namelen: c_int,
+    guid: *mut FMOD_GUID,
+    systemrate: *mut c_int,
+    speakermode: *mut FMOD_SPEAKERMODE,
+    speakermodechannels: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetDriverInfo)(
+            system,
+            id,
+            name,
+            namelen,
+            guid,
+            systemrate,
+            speakermode,
+            speakermodechannels,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetDriver(system: *mut FMOD_SYSTEM, driver: c_int) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetDriver)(system, driver),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetDriver(system: *mut FMOD_SYSTEM, driver: *mut c_int) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetDriver)(system, driver),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetSoftwareChannels(
+    system: *mut FMOD_SYSTEM,
+    numsoftwarechannels: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetSoftwareChannels)(system, numsoftwarechannels),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetSoftwareChannels(
+    system: *mut FMOD_SYSTEM,
+    numsoftwarechannels: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetSoftwareChannels)(system, numsoftwarechannels),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetSoftwareFormat(
+    system: *mut FMOD_SYSTEM,
+    samplerate: c_int,
+    speakermode: FMOD_SPEAKERMODE,
+    numrawspeakers: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_SetSoftwareFormat)(system, samplerate, speakermode, numrawspeakers)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetSoftwareFormat(
+    system: *mut FMOD_SYSTEM,
+    samplerate: *mut c_int,
+    speakermode: *mut FMOD_SPEAKERMODE,
+    numrawspeakers: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_GetSoftwareFormat)(system, samplerate, speakermode, numrawspeakers)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetDSPBufferSize(
+    system: *mut FMOD_SYSTEM,
+    bufferlength: c_uint,
+    numbuffers: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetDSPBufferSize)(system, bufferlength, numbuffers),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetDSPBufferSize(
+    system: *mut FMOD_SYSTEM,
+    bufferlength: *mut c_uint,
+    numbuffers: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetDSPBufferSize)(system, bufferlength, numbuffers),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetFileSystem(
+    system: *mut FMOD_SYSTEM,
+    useropen: FMOD_FILE_OPEN_CALLBACK,
+    userclose: FMOD_FILE_CLOSE_CALLBACK,
+    userread: FMOD_FILE_READ_CALLBACK,
+    userseek: FMOD_FILE_SEEK_CALLBACK,
+    userasyncread: FMOD_FILE_ASYNCREAD_CALLBACK,
+    userasynccancel: FMOD_FILE_ASYNCCANCEL_CALLBACK,
+    blockalign: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetFileSystem)(
+            system,
+            useropen,
+            userclose,
+            userread,
+            userseek,
+            userasyncread,
+            userasynccancel,
+            blockalign,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_AttachFileSystem(
+    system: *mut FMOD_SYSTEM,
+    useropen: FMOD_FILE_OPEN_CALLBACK,
+    userclose: FMOD_FILE_CLOSE_CALLBACK,
+    userread: FMOD_FILE_READ_CALLBACK,
+    userseek: FMOD_FILE_SEEK_CALLBACK,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_AttachFileSystem)(system, useropen, userclose, userread, userseek)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetAdvancedSettings(
+    system: *mut FMOD_SYSTEM,
+    settings: *mut FMOD_ADVANCEDSETTINGS,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetAdvancedSettings)(system, settings),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetAdvancedSettings(
+    system: *mut FMOD_SYSTEM,
+    settings: *mut FMOD_ADVANCEDSETTINGS,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetAdvancedSettings)(system, settings),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetCallback(
+    system: *mut FMOD_SYSTEM,
+    callback: FMOD_SYSTEM_CALLBACK,
+    callbackmask: FMOD_SYSTEM_CALLBACK_TYPE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetCallback)(system, callback, callbackmask),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetPluginPath(
+    system: *mut FMOD_SYSTEM,
+    path: *const c_char,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetPluginPath)(system, path),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_LoadPlugin(
+    system: *mut FMOD_SYSTEM,
+    filename: *const c_char,
+    handle: *mut c_uint,
+    priority: c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_LoadPlugin)(system, filename, handle, priority),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_UnloadPlugin(system: *mut FMOD_SYSTEM, handle: c_uint) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_UnloadPlugin)(system, handle),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetNumNestedPlugins(
+    system: *mut FMOD_SYSTEM,
+    handle: c_uint,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetNumNestedPlugins)(system, handle, count),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetNestedPlugin(
+    system: *mut FMOD_SYSTEM,
+    handle: c_uint,
+    index: c_int,
+    nestedhandle: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetNestedPlugin)(system, handle, index, nestedhandle),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetNumPlugins(
+    system: *mut FMOD_SYSTEM,
+    plugintype: FMOD_PLUGINTYPE,
+    numplugins: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetNumPlugins)(system, plugintype, numplugins),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetPluginHandle(
+    system: *mut FMOD_SYSTEM,
+    plugintype: FMOD_PLUGINTYPE,
+    index: c_int,
+    handle: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetPluginHandle)(system, plugintype, index, handle),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetPluginInfo(
+    system: *mut FMOD_SYSTEM,
+    handle: c_uint,
+    plugintype: *mut FMOD_PLUGINTYPE,
+    name: *mut c_char,
+    namelen: c_int,
+    version: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_GetPluginInfo)(system, handle, plugintype, name, namelen, version)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetOutputByPlugin(
+    system: *mut FMOD_SYSTEM,
+    handle: c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetOutputByPlugin)(system, handle),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetOutputByPlugin(
+    system: *mut FMOD_SYSTEM,
+    handle: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetOutputByPlugin)(system, handle),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_CreateDSPByPlugin(
+    system: *mut FMOD_SYSTEM,
+    handle: c_uint,
+    dsp: *mut *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_CreateDSPByPlugin)(system, handle, dsp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetDSPInfoByPlugin(
+    system: *mut FMOD_SYSTEM,
+    handle: c_uint,
+    description: *const *const FMOD_DSP_DESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetDSPInfoByPlugin)(system, handle, description),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_RegisterCodec(
+    system: *mut FMOD_SYSTEM,
+    description: *mut FMOD_CODEC_DESCRIPTION,
+    handle: *mut c_uint,
+    priority: c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_RegisterCodec)(system, description, handle, priority),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_RegisterDSP(
+    system: *mut FMOD_SYSTEM,
+    description: *const FMOD_DSP_DESCRIPTION,
+    handle: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_RegisterDSP)(system, description, handle),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_RegisterOutput(
+    system: *mut FMOD_SYSTEM,
+    description: *const FMOD_OUTPUT_DESCRIPTION,
+    handle: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_RegisterOutput)(system, description, handle),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_Init(
+    system: *mut FMOD_SYSTEM,
+    maxchannels: c_int,
+    flags: FMOD_INITFLAGS,
+    extradriverdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_Init)(system, maxchannels, flags, extradriverdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_Close(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_Close)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_Update(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_Update)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetSpeakerPosition(
+    system: *mut FMOD_SYSTEM,
+    speaker: FMOD_SPEAKER,
+    x: c_float,
+    y: c_float,
+    active: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetSpeakerPosition)(system, speaker, x, y, active),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetSpeakerPosition(
+    system: *mut FMOD_SYSTEM,
+    speaker: FMOD_SPEAKER,
+    x: *mut c_float,
+    y: *mut c_float,
+    active: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetSpeakerPosition)(system, speaker, x, y, active),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetStreamBufferSize(
+    system: *mut FMOD_SYSTEM,
+    filebuffersize: c_uint,
+    filebuffersizetype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_SetStreamBufferSize)(system, filebuffersize, filebuffersizetype)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetStreamBufferSize(
+    system: *mut FMOD_SYSTEM,
+    filebuffersize: *mut c_uint,
+    filebuffersizetype: *mut FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_GetStreamBufferSize)(system, filebuffersize, filebuffersizetype)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_Set3DSettings(
+    system: *mut FMOD_SYSTEM,
+    dopplerscale: c_float,
+    distancefactor: c_float,
+    rolloffscale: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_Set3DSettings)(system, dopplerscale, distancefactor, rolloffscale)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_Get3DSettings(
+    system: *mut FMOD_SYSTEM,
+    dopplerscale: *mut c_float,
+    distancefactor: *mut c_float,
+    rolloffscale: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_Get3DSettings)(system, dopplerscale, distancefactor, rolloffscale)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_Set3DNumListeners(
+    system: *mut FMOD_SYSTEM,
+    numlisteners: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_Set3DNumListeners)(system, numlisteners),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_Get3DNumListeners(
+    system: *mut FMOD_SYSTEM,
+    numlisteners: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_Get3DNumListeners)(system, numlisteners),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_Set3DListenerAttributes(
+    system: *mut FMOD_SYSTEM,
+    listener: c_int,
+    pos: *const FMOD_VECTOR,
+    vel: *const FMOD_VECTOR,
+    forward: *const FMOD_VECTOR,
+    up: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_Set3DListenerAttributes)(system, listener, pos, vel, forward, up)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_Get3DListenerAttributes(
+    system: *mut FMOD_SYSTEM,
+    listener: c_int,
+    pos: *mut FMOD_VECTOR,
+    vel: *mut FMOD_VECTOR,
+    forward: *mut FMOD_VECTOR,
+    up: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_Get3DListenerAttributes)(system, listener, pos, vel, forward, up)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_Set3DRolloffCallback(
+    system: *mut FMOD_SYSTEM,
+    callback: FMOD_3D_ROLLOFF_CALLBACK,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_Set3DRolloffCallback)(system, callback),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_MixerSuspend(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_MixerSuspend)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_MixerResume(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_MixerResume)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetDefaultMixMatrix(
+    system: *mut FMOD_SYSTEM,
+    sourcespeakermode: FMOD_SPEAKERMODE,
+    targetspeakermode: FMOD_SPEAKERMODE,
+    matrix: *mut c_float,
+    matrixhop: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetDefaultMixMatrix)(
+            system,
+            sourcespeakermode,
+            targetspeakermode,
+            matrix,
+            matrixhop,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetSpeakerModeChannels(
+    system: *mut FMOD_SYSTEM,
+    mode: FMOD_SPEAKERMODE,
+    channels: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetSpeakerModeChannels)(system, mode, channels),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetVersion(
+    system: *mut FMOD_SYSTEM,
+    version: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetVersion)(system, version),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetOutputHandle(
+    system: *mut FMOD_SYSTEM,
+    handle: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetOutputHandle)(system, handle),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetChannelsPlaying(
+    system: *mut FMOD_SYSTEM,
+    channels: *mut c_int,
+    realchannels: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetChannelsPlaying)(system, channels, realchannels),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetCPUUsage(
+    system: *mut FMOD_SYSTEM,
+    usage: *mut FMOD_CPU_USAGE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetCPUUsage)(system, usage),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetFileUsage(
+    system: *mut FMOD_SYSTEM,
+    sampleBytesRead: *mut c_longlong,
+    streamBytesRead: *mut c_longlong,
+    otherBytesRead: *mut c_longlong,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetFileUsage)(
+            system,
+            sampleBytesRead,
+            streamBytesRead,
+            otherBytesRead,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_CreateSound(
+    system: *mut FMOD_SYSTEM,
+    name_or_data: *const c_char,
+    mode: FMOD_MODE,
+    exinfo: *mut FMOD_CREATESOUNDEXINFO,
+    sound: *mut *mut FMOD_SOUND,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_CreateSound)(system, name_or_data, mode, exinfo, sound),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_CreateStream(
+    system: *mut FMOD_SYSTEM,
+    name_or_data: *const c_char,
+    mode: FMOD_MODE,
+    exinfo: *mut FMOD_CREATESOUNDEXINFO,
+    sound: *mut *mut FMOD_SOUND,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_CreateStream)(system, name_or_data, mode, exinfo, sound)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_CreateDSP(
+    system: *mut FMOD_SYSTEM,
+    description: *const FMOD_DSP_DESCRIPTION,
+    dsp: *mut *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_CreateDSP)(system, description, dsp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_CreateDSPByType(
+    system: *mut FMOD_SYSTEM,
+    type_: FMOD_DSP_TYPE,
+    dsp: *mut *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_CreateDSPByType)(system, type_, dsp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_CreateChannelGroup(
+    system: *mut FMOD_SYSTEM,
+    name: *const c_char,
+    channelgroup: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_CreateChannelGroup)(system, name, channelgroup),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_CreateSoundGroup(
+    system: *mut FMOD_SYSTEM,
+    name: *const c_char,
+    soundgroup: *mut *mut FMOD_SOUNDGROUP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_CreateSoundGroup)(system, name, soundgroup),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_CreateReverb3D(
+    system: *mut FMOD_SYSTEM,
+    reverb: *mut *mut FMOD_REVERB3D,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_CreateReverb3D)(system, reverb),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_PlaySound(
+    system: *mut FMOD_SYSTEM,
+    sound: *mut FMOD_SOUND,
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    paused: FMOD_BOOL,
+    channel: *mut *mut FMOD_CHANNEL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_PlaySound)(system, sound, channelgroup, paused, channel)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_PlayDSP(
+    system: *mut FMOD_SYSTEM,
+    dsp: *mut FMOD_DSP,
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    paused: FMOD_BOOL,
+    channel: *mut *mut FMOD_CHANNEL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_PlayDSP)(system, dsp, channelgroup, paused, channel),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetChannel(
+    system: *mut FMOD_SYSTEM,
+    channelid: c_int,
+    channel: *mut *mut FMOD_CHANNEL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetChannel)(system, channelid, channel),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetDSPInfoByType(
+    system: *mut FMOD_SYSTEM,
+    type_: FMOD_DSP_TYPE,
+    description: *const *const FMOD_DSP_DESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetDSPInfoByType)(system, type_, description),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetMasterChannelGroup(
+    system: *mut FMOD_SYSTEM,
+    channelgroup: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetMasterChannelGroup)(system, channelgroup),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetMasterSoundGroup(
+    system: *mut FMOD_SYSTEM,
+    soundgroup: *mut *mut FMOD_SOUNDGROUP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetMasterSoundGroup)(system, soundgroup),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_AttachChannelGroupToPort(
+    system: *mut FMOD_SYSTEM,
+    portType: FMOD_PORT_TYPE,
+    portIndex: FMOD_PORT_INDEX,
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    passThru: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_AttachChannelGroupToPort)(
+            system,
+            portType,
+            portIndex,
+            channelgroup,
+            passThru,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_DetachChannelGroupFromPort(
+    system: *mut FMOD_SYSTEM,
+    channelgroup: *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_DetachChannelGroupFromPort)(system, channelgroup),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetReverbProperties(
+    system: *mut FMOD_SYSTEM,
+    instance: c_int,
+    prop: *const FMOD_REVERB_PROPERTIES,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetReverbProperties)(system, instance, prop),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetReverbProperties(
+    system: *mut FMOD_SYSTEM,
+    instance: c_int,
+    prop: *mut FMOD_REVERB_PROPERTIES,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetReverbProperties)(system, instance, prop),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_LockDSP(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_LockDSP)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_UnlockDSP(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_UnlockDSP)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetRecordNumDrivers(
+    system: *mut FMOD_SYSTEM,
+    numdrivers: *mut c_int,
+    numconnected: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetRecordNumDrivers)(system, numdrivers, numconnected),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetRecordDriverInfo(
+    system: *mut FMOD_SYSTEM,
+    id: c_int,
+    name: *mut c_char,
+    namelen: c_int,
+    guid: *mut FMOD_GUID,
+    systemrate: *mut c_int,
+    speakermode: *mut FMOD_SPEAKERMODE,
+    speakermodechannels: *mut c_int,
+    state: *mut FMOD_DRIVER_STATE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetRecordDriverInfo)(
+            system,
+            id,
+            name,
+            namelen,
+            guid,
+            systemrate,
+            speakermode,
+            speakermodechannels,
+            state,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetRecordPosition(
+    system: *mut FMOD_SYSTEM,
+    id: c_int,
+    position: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetRecordPosition)(system, id, position),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_RecordStart(
+    system: *mut FMOD_SYSTEM,
+    id: c_int,
+    sound: *mut FMOD_SOUND,
+    loop_: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_RecordStart)(system, id, sound, loop_),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_RecordStop(system: *mut FMOD_SYSTEM, id: c_int) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_RecordStop)(system, id),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_IsRecording(
+    system: *mut FMOD_SYSTEM,
+    id: c_int,
+    recording: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_IsRecording)(system, id, recording),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_CreateGeometry(
+    system: *mut FMOD_SYSTEM,
+    maxpolygons: c_int,
+    maxvertices: c_int,
+    geometry: *mut *mut FMOD_GEOMETRY,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_CreateGeometry)(system, maxpolygons, maxvertices, geometry)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetGeometrySettings(
+    system: *mut FMOD_SYSTEM,
+    maxworldsize: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetGeometrySettings)(system, maxworldsize),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetGeometrySettings(
+    system: *mut FMOD_SYSTEM,
+    maxworldsize: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetGeometrySettings)(system, maxworldsize),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_LoadGeometry(
+    system: *mut FMOD_SYSTEM,
+    data: *const c_void,
+    datasize: c_int,
+    geometry: *mut *mut FMOD_GEOMETRY,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_LoadGeometry)(system, data, datasize, geometry),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetGeometryOcclusion(
+    system: *mut FMOD_SYSTEM,
+    listener: *const FMOD_VECTOR,
+    source: *const FMOD_VECTOR,
+    direct: *mut c_float,
+    reverb: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_System_GetGeometryOcclusion)(system, listener, source, direct, reverb)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetNetworkProxy(
+    system: *mut FMOD_SYSTEM,
+    proxy: *const c_char,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetNetworkProxy)(system, proxy),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetNetworkProxy(
+    system: *mut FMOD_SYSTEM,
+    proxy: *mut c_char,
+    proxylen: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetNetworkProxy)(system, proxy, proxylen),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetNetworkTimeout(
+    system: *mut FMOD_SYSTEM,
+    timeout: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetNetworkTimeout)(system, timeout),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetNetworkTimeout(
+    system: *mut FMOD_SYSTEM,
+    timeout: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetNetworkTimeout)(system, timeout),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_SetUserData(
+    system: *mut FMOD_SYSTEM,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_SetUserData)(system, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_System_GetUserData(
+    system: *mut FMOD_SYSTEM,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_System_GetUserData)(system, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_Release(sound: *mut FMOD_SOUND) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_Release)(sound),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetSystemObject(
+    sound: *mut FMOD_SOUND,
+    system: *mut *mut FMOD_SYSTEM,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetSystemObject)(sound, system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_Lock(
+    sound: *mut FMOD_SOUND,
+    offset: c_uint,
+    length: c_uint,
+    ptr1: *mut *mut c_void,
+    ptr2: *mut *mut c_void,
+    len1: *mut c_uint,
+    len2: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_Lock)(sound, offset, length, ptr1, ptr2, len1, len2),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_Unlock(
+    sound: *mut FMOD_SOUND,
+    ptr1: *mut c_void,
+    ptr2: *mut c_void,
+    len1: c_uint,
+    len2: c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_Unlock)(sound, ptr1, ptr2, len1, len2),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_SetDefaults(
+    sound: *mut FMOD_SOUND,
+    frequency: c_float,
+    priority: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_SetDefaults)(sound, frequency, priority),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetDefaults(
+    sound: *mut FMOD_SOUND,
+    frequency: *mut c_float,
+    priority: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetDefaults)(sound, frequency, priority),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_Set3DMinMaxDistance(
+    sound: *mut FMOD_SOUND,
+    min: c_float,
+    max: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_Set3DMinMaxDistance)(sound, min, max),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_Get3DMinMaxDistance(
+    sound: *mut FMOD_SOUND,
+    min: *mut c_float,
+    max: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_Get3DMinMaxDistance)(sound, min, max),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_Set3DConeSettings(
+    sound: *mut FMOD_SOUND,
+    insideconeangle: c_float,
+    outsideconeangle: c_float,
+    outsidevolume: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_Set3DConeSettings)(
+            sound,
+            insideconeangle,
+            outsideconeangle,
+            outsidevolume,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_Get3DConeSettings(
+    sound: *mut FMOD_SOUND,
+    insideconeangle: *mut c_float,
+    outsideconeangle: *mut c_float,
+    outsidevolume: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_Get3DConeSettings)(
+            sound,
+            insideconeangle,
+            outsideconeangle,
+            outsidevolume,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_Set3DCustomRolloff(
+    sound: *mut FMOD_SOUND,
+    points: *mut FMOD_VECTOR,
+    numpoints: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_Set3DCustomRolloff)(sound, points, numpoints),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_Get3DCustomRolloff(
+    sound: *mut FMOD_SOUND,
+    points: *mut *mut FMOD_VECTOR,
+    numpoints: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_Get3DCustomRolloff)(sound, points, numpoints),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetSubSound(
+    sound: *mut FMOD_SOUND,
+    index: c_int,
+    subsound: *mut *mut FMOD_SOUND,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetSubSound)(sound, index, subsound),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetSubSoundParent(
+    sound: *mut FMOD_SOUND,
+    parentsound: *mut *mut FMOD_SOUND,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetSubSoundParent)(sound, parentsound),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetName(
+    sound: *mut FMOD_SOUND,
+    name: *mut c_char,
+    namelen: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetName)(sound, name, namelen),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetLength(
+    sound: *mut FMOD_SOUND,
+    length: *mut c_uint,
+    lengthtype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetLength)(sound, length, lengthtype),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetFormat(
+    sound: *mut FMOD_SOUND,
+    type_: *mut FMOD_SOUND_TYPE,
+    format: *mut FMOD_SOUND_FORMAT,
+    channels: *mut c_int,
+    bits: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetFormat)(sound, type_, format, channels, bits),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetNumSubSounds(
+    sound: *mut FMOD_SOUND,
+    numsubsounds: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetNumSubSounds)(sound, numsubsounds),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetNumTags(
+    sound: *mut FMOD_SOUND,
+    numtags: *mut c_int,
+    numtagsupdated: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetNumTags)(sound, numtags, numtagsupdated),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetTag(
+    sound: *mut FMOD_SOUND,
+    name: *const c_char,
+    index: c_int,
+    tag: *mut FMOD_TAG,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetTag)(sound, name, index, tag),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetOpenState(
+    sound: *mut FMOD_SOUND,
+    openstate: *mut FMOD_OPENSTATE,
+    percentbuffered: *mut c_uint,
+    starving: *mut FMOD_BOOL,
+    diskbusy: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Sound_GetOpenState)(sound, openstate, percentbuffered, starving, diskbusy)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_ReadData(
+    sound: *mut FMOD_SOUND,
+    buffer: *mut c_void,
+    length: c_uint,
+    read: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_ReadData)(sound, buffer, length, read),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_SeekData(sound: *mut FMOD_SOUND, pcm: c_uint) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_SeekData)(sound, pcm),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_SetSoundGroup(
+    sound: *mut FMOD_SOUND,
+    soundgroup: *mut FMOD_SOUNDGROUP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_SetSoundGroup)(sound, soundgroup),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetSoundGroup(
+    sound: *mut FMOD_SOUND,
+    soundgroup: *mut *mut FMOD_SOUNDGROUP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetSoundGroup)(sound, soundgroup),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetNumSyncPoints(
+    sound: *mut FMOD_SOUND,
+    numsyncpoints: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetNumSyncPoints)(sound, numsyncpoints),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetSyncPoint(
+    sound: *mut FMOD_SOUND,
+    index: c_int,
+    point: *mut *mut FMOD_SYNCPOINT,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetSyncPoint)(sound, index, point),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetSyncPointInfo(
+    sound: *mut FMOD_SOUND,
+    point: *mut FMOD_SYNCPOINT,
+    name: *mut c_char,
+    namelen: c_int,
+    offset: *mut c_uint,
+    offsettype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Sound_GetSyncPointInfo)(sound, point, name, namelen, offset, offsettype)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_AddSyncPoint(
+    sound: *mut FMOD_SOUND,
+    offset: c_uint,
+    offsettype: FMOD_TIMEUNIT,
+    name: *const c_char,
+    point: *mut *mut FMOD_SYNCPOINT,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_AddSyncPoint)(sound, offset, offsettype, name, point),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_DeleteSyncPoint(
+    sound: *mut FMOD_SOUND,
+    point: *mut FMOD_SYNCPOINT,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_DeleteSyncPoint)(sound, point),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_SetMode(sound: *mut FMOD_SOUND, mode: FMOD_MODE) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_SetMode)(sound, mode),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetMode(sound: *mut FMOD_SOUND, mode: *mut FMOD_MODE) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetMode)(sound, mode),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_SetLoopCount(sound: *mut FMOD_SOUND, loopcount: c_int) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_SetLoopCount)(sound, loopcount),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetLoopCount(
+    sound: *mut FMOD_SOUND,
+    loopcount: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetLoopCount)(sound, loopcount),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_SetLoopPoints(
+    sound: *mut FMOD_SOUND,
+    loopstart: c_uint,
+    loopstarttype: FMOD_TIMEUNIT,
+    loopend: c_uint,
+    loopendtype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_SetLoopPoints)(
+            sound,
+            loopstart,
+            loopstarttype,
+            loopend,
+            loopendtype,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetLoopPoints(
+    sound: *mut FMOD_SOUND,
+    loopstart: *mut c_uint,
+    loopstarttype: FMOD_TIMEUNIT,
+    loopend: *mut c_uint,
+    loopendtype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetLoopPoints)(
+            sound,
+            loopstart,
+            loopstarttype,
+            loopend,
+            loopendtype,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetMusicNumChannels(
+    sound: *mut FMOD_SOUND,
+    numchannels: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetMusicNumChannels)(sound, numchannels),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_SetMusicChannelVolume(
+    sound: *mut FMOD_SOUND,
+    channel: c_int,
+    volume: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_SetMusicChannelVolume)(sound, channel, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetMusicChannelVolume(
+    sound: *mut FMOD_SOUND,
+    channel: c_int,
+    volume: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetMusicChannelVolume)(sound, channel, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_SetMusicSpeed(sound: *mut FMOD_SOUND, speed: c_float) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_SetMusicSpeed)(sound, speed),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetMusicSpeed(sound: *mut FMOD_SOUND, speed: *mut c_float) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetMusicSpeed)(sound, speed),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_SetUserData(sound: *mut FMOD_SOUND, userdata: *mut c_void) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_SetUserData)(sound, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Sound_GetUserData(
+    sound: *mut FMOD_SOUND,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Sound_GetUserData)(sound, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetSystemObject(
+    channel: *mut FMOD_CHANNEL,
+    system: *mut *mut FMOD_SYSTEM,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetSystemObject)(channel, system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Stop(channel: *mut FMOD_CHANNEL) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Stop)(channel),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetPaused(channel: *mut FMOD_CHANNEL, paused: FMOD_BOOL) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetPaused)(channel, paused),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetPaused(
+    channel: *mut FMOD_CHANNEL,
+    paused: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetPaused)(channel, paused),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetVolume(channel: *mut FMOD_CHANNEL, volume: c_float) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetVolume)(channel, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetVolume(
+    channel: *mut FMOD_CHANNEL,
+    volume: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetVolume)(channel, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetVolumeRamp(
+    channel: *mut FMOD_CHANNEL,
+    ramp: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetVolumeRamp)(channel, ramp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetVolumeRamp(
+    channel: *mut FMOD_CHANNEL,
+    ramp: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetVolumeRamp)(channel, ramp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetAudibility(
+    channel: *mut FMOD_CHANNEL,
+    audibility: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetAudibility)(channel, audibility),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetPitch(channel: *mut FMOD_CHANNEL, pitch: c_float) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetPitch)(channel, pitch),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetPitch(
+    channel: *mut FMOD_CHANNEL,
+    pitch: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetPitch)(channel, pitch),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetMute(channel: *mut FMOD_CHANNEL, mute: FMOD_BOOL) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetMute)(channel, mute),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetMute(
+    channel: *mut FMOD_CHANNEL,
+    mute: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetMute)(channel, mute),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetReverbProperties(
+    channel: *mut FMOD_CHANNEL,
+    instance: c_int,
+    wet: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetReverbProperties)(channel, instance, wet),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetReverbProperties(
+    channel: *mut FMOD_CHANNEL,
+    instance: c_int,
+    wet: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetReverbProperties)(channel, instance, wet),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetLowPassGain(
+    channel: *mut FMOD_CHANNEL,
+    gain: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetLowPassGain)(channel, gain),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetLowPassGain(
+    channel: *mut FMOD_CHANNEL,
+    gain: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetLowPassGain)(channel, gain),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetMode(channel: *mut FMOD_CHANNEL, mode: FMOD_MODE) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetMode)(channel, mode),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetMode(
+    channel: *mut FMOD_CHANNEL,
+    mode: *mut FMOD_MODE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetMode)(channel, mode),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetCallback(
+    channel: *mut FMOD_CHANNEL,
+    callback: FMOD_CHANNELCONTROL_CALLBACK,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetCallback)(channel, callback),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_IsPlaying(
+    channel: *mut FMOD_CHANNEL,
+    isplaying: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_IsPlaying)(channel, isplaying),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetPan(channel: *mut FMOD_CHANNEL, pan: c_float) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetPan)(channel, pan),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetMixLevelsOutput(
+    channel: *mut FMOD_CHANNEL,
+    frontleft: c_float,
+    frontright: c_float,
+    center: c_float,
+    lfe: c_float,
+    surroundleft: c_float,
+    surroundright: c_float,
+    backleft: c_float,
+    backright: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetMixLevelsOutput)(
+            channel,
+            frontleft,
+            frontright,
+            center,
+            lfe,
+            surroundleft,
+            surroundright,
+            backleft,
+            backright,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetMixLevelsInput(
+    channel: *mut FMOD_CHANNEL,
+    levels: *mut c_float,
+    numlevels: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetMixLevelsInput)(channel, levels, numlevels),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetMixMatrix(
+    channel: *mut FMOD_CHANNEL,
+    matrix: *mut c_float,
+    outchannels: c_int,
+    inchannels: c_int,
+    inchannel_hop: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetMixMatrix)(
+            channel,
+            matrix,
+            outchannels,
+            inchannels,
+            inchannel_hop,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetMixMatrix(
+    channel: *mut FMOD_CHANNEL,
+    matrix: *mut c_float,
+    outchannels: *mut c_int,
+    inchannels: *mut c_int,
+    inchannel_hop: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetMixMatrix)(
+            channel,
+            matrix,
+            outchannels,
+            inchannels,
+            inchannel_hop,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetDSPClock(
+    channel: *mut FMOD_CHANNEL,
+    dspclock: *mut c_ulonglong,
+    parentclock: *mut c_ulonglong,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetDSPClock)(channel, dspclock, parentclock),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetDelay(
+    channel: *mut FMOD_CHANNEL,
+    dspclock_start: c_ulonglong,
+    dspclock_end: c_ulonglong,
+    stopchannels: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Channel_SetDelay)(channel, dspclock_start, dspclock_end, stopchannels)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetDelay(
+    channel: *mut FMOD_CHANNEL,
+    dspclock_start: *mut c_ulonglong,
+    dspclock_end: *mut c_ulonglong,
+    stopchannels: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Channel_GetDelay)(channel, dspclock_start, dspclock_end, stopchannels)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_AddFadePoint(
+    channel: *mut FMOD_CHANNEL,
+    dspclock: c_ulonglong,
+    volume: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_AddFadePoint)(channel, dspclock, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetFadePointRamp(
+    channel: *mut FMOD_CHANNEL,
+    dspclock: c_ulonglong,
+    volume: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetFadePointRamp)(channel, dspclock, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_RemoveFadePoints(
+    channel: *mut FMOD_CHANNEL,
+    dspclock_start: c_ulonglong,
+    dspclock_end: c_ulonglong,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Channel_RemoveFadePoints)(channel, dspclock_start, dspclock_end)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetFadePoints(
+    channel: *mut FMOD_CHANNEL,
+    numpoints: *mut c_uint,
+    point_dspclock: *mut c_ulonglong,
+    point_volume: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Channel_GetFadePoints)(channel, numpoints, point_dspclock, point_volume)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetDSP(
+    channel: *mut FMOD_CHANNEL,
+    index: c_int,
+    dsp: *mut *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetDSP)(channel, index, dsp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_AddDSP(
+    channel: *mut FMOD_CHANNEL,
+    index: c_int,
+    dsp: *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_AddDSP)(channel, index, dsp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_RemoveDSP(
+    channel: *mut FMOD_CHANNEL,
+    dsp: *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_RemoveDSP)(channel, dsp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetNumDSPs(
+    channel: *mut FMOD_CHANNEL,
+    numdsps: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetNumDSPs)(channel, numdsps),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetDSPIndex(
+    channel: *mut FMOD_CHANNEL,
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetDSPIndex)(channel, dsp, index),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetDSPIndex(
+    channel: *mut FMOD_CHANNEL,
+    dsp: *mut FMOD_DSP,
+    index: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetDSPIndex)(channel, dsp, index),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Set3DAttributes(
+    channel: *mut FMOD_CHANNEL,
+    pos: *const FMOD_VECTOR,
+    vel: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Set3DAttributes)(channel, pos, vel),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Get3DAttributes(
+    channel: *mut FMOD_CHANNEL,
+    pos: *mut FMOD_VECTOR,
+    vel: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Get3DAttributes)(channel, pos, vel),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Set3DMinMaxDistance(
+    channel: *mut FMOD_CHANNEL,
+    mindistance: c_float,
+    maxdistance: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Channel_Set3DMinMaxDistance)(channel, mindistance, maxdistance)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Get3DMinMaxDistance(
+    channel: *mut FMOD_CHANNEL,
+    mindistance: *mut c_float,
+    maxdistance: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Channel_Get3DMinMaxDistance)(channel, mindistance, maxdistance)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Set3DConeSettings(
+    channel: *mut FMOD_CHANNEL,
+    insideconeangle: c_float,
+    outsideconeangle: c_float,
+    outsidevolume: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Set3DConeSettings)(
+            channel,
+            insideconeangle,
+            outsideconeangle,
+            outsidevolume,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Get3DConeSettings(
+    channel: *mut FMOD_CHANNEL,
+    insideconeangle: *mut c_float,
+    outsideconeangle: *mut c_float,
+    outsidevolume: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Get3DConeSettings)(
+            channel,
+            insideconeangle,
+            outsideconeangle,
+            outsidevolume,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Set3DConeOrientation(
+    channel: *mut FMOD_CHANNEL,
+    orientation: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Set3DConeOrientation)(channel, orientation),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Get3DConeOrientation(
+    channel: *mut FMOD_CHANNEL,
+    orientation: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Get3DConeOrientation)(channel, orientation),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Set3DCustomRolloff(
+    channel: *mut FMOD_CHANNEL,
+    points: *mut FMOD_VECTOR,
+    numpoints: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Set3DCustomRolloff)(channel, points, numpoints),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Get3DCustomRolloff(
+    channel: *mut FMOD_CHANNEL,
+    points: *mut *mut FMOD_VECTOR,
+    numpoints: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Get3DCustomRolloff)(channel, points, numpoints),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Set3DOcclusion(
+    channel: *mut FMOD_CHANNEL,
+    directocclusion: c_float,
+    reverbocclusion: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Channel_Set3DOcclusion)(channel, directocclusion, reverbocclusion)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Get3DOcclusion(
+    channel: *mut FMOD_CHANNEL,
+    directocclusion: *mut c_float,
+    reverbocclusion: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Channel_Get3DOcclusion)(channel, directocclusion, reverbocclusion)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Set3DSpread(channel: *mut FMOD_CHANNEL, angle: c_float) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Set3DSpread)(channel, angle),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Get3DSpread(
+    channel: *mut FMOD_CHANNEL,
+    angle: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Get3DSpread)(channel, angle),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Set3DLevel(channel: *mut FMOD_CHANNEL, level: c_float) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Set3DLevel)(channel, level),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Get3DLevel(
+    channel: *mut FMOD_CHANNEL,
+    level: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Get3DLevel)(channel, level),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Set3DDopplerLevel(
+    channel: *mut FMOD_CHANNEL,
+    level: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Set3DDopplerLevel)(channel, level),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Get3DDopplerLevel(
+    channel: *mut FMOD_CHANNEL,
+    level: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_Get3DDopplerLevel)(channel, level),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Set3DDistanceFilter(
+    channel: *mut FMOD_CHANNEL,
+    custom: FMOD_BOOL,
+    customLevel: c_float,
+    centerFreq: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Channel_Set3DDistanceFilter)(channel, custom, customLevel, centerFreq)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_Get3DDistanceFilter(
+    channel: *mut FMOD_CHANNEL,
+    custom: *mut FMOD_BOOL,
+    customLevel: *mut c_float,
+    centerFreq: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Channel_Get3DDistanceFilter)(channel, custom, customLevel, centerFreq)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetUserData(
+    channel: *mut FMOD_CHANNEL,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetUserData)(channel, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetUserData(
+    channel: *mut FMOD_CHANNEL,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetUserData)(channel, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetFrequency(
+    channel: *mut FMOD_CHANNEL,
+    frequency: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetFrequency)(channel, frequency),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetFrequency(
+    channel: *mut FMOD_CHANNEL,
+    frequency: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetFrequency)(channel, frequency),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetPriority(channel: *mut FMOD_CHANNEL, priority: c_int) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetPriority)(channel, priority),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetPriority(
+    channel: *mut FMOD_CHANNEL,
+    priority: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetPriority)(channel, priority),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetPosition(
+    channel: *mut FMOD_CHANNEL,
+    position: c_uint,
+    postype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetPosition)(channel, position, postype),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetPosition(
+    channel: *mut FMOD_CHANNEL,
+    position: *mut c_uint,
+    postype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetPosition)(channel, position, postype),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetChannelGroup(
+    channel: *mut FMOD_CHANNEL,
+    channelgroup: *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetChannelGroup)(channel, channelgroup),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetChannelGroup(
+    channel: *mut FMOD_CHANNEL,
+    channelgroup: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetChannelGroup)(channel, channelgroup),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetLoopCount(
+    channel: *mut FMOD_CHANNEL,
+    loopcount: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetLoopCount)(channel, loopcount),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetLoopCount(
+    channel: *mut FMOD_CHANNEL,
+    loopcount: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetLoopCount)(channel, loopcount),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_SetLoopPoints(
+    channel: *mut FMOD_CHANNEL,
+    loopstart: c_uint,
+    loopstarttype: FMOD_TIMEUNIT,
+    loopend: c_uint,
+    loopendtype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_SetLoopPoints)(
+            channel,
+            loopstart,
+            loopstarttype,
+            loopend,
+            loopendtype,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetLoopPoints(
+    channel: *mut FMOD_CHANNEL,
+    loopstart: *mut c_uint,
+    loopstarttype: FMOD_TIMEUNIT,
+    loopend: *mut c_uint,
+    loopendtype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetLoopPoints)(
+            channel,
+            loopstart,
+            loopstarttype,
+            loopend,
+            loopendtype,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_IsVirtual(
+    channel: *mut FMOD_CHANNEL,
+    isvirtual: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_IsVirtual)(channel, isvirtual),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetCurrentSound(
+    channel: *mut FMOD_CHANNEL,
+    sound: *mut *mut FMOD_SOUND,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetCurrentSound)(channel, sound),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Channel_GetIndex(channel: *mut FMOD_CHANNEL, index: *mut c_int) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Channel_GetIndex)(channel, index),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetSystemObject(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    system: *mut *mut FMOD_SYSTEM,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetSystemObject)(channelgroup, system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Stop(channelgroup: *mut FMOD_CHANNELGROUP) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Stop)(channelgroup),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetPaused(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    paused: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetPaused)(channelgroup, paused),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetPaused(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    paused: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetPaused)(channelgroup, paused),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetVolume(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    volume: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetVolume)(channelgroup, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetVolume(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    volume: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetVolume)(channelgroup, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetVolumeRamp(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    ramp: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetVolumeRamp)(channelgroup, ramp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetVolumeRamp(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    ramp: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetVolumeRamp)(channelgroup, ramp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetAudibility(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    audibility: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetAudibility)(channelgroup, audibility),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetPitch(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    pitch: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetPitch)(channelgroup, pitch),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetPitch(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    pitch: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetPitch)(channelgroup, pitch),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetMute(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    mute: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetMute)(channelgroup, mute),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetMute(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    mute: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetMute)(channelgroup, mute),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetReverbProperties(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    instance: c_int,
+    wet: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetReverbProperties)(channelgroup, instance, wet),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetReverbProperties(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    instance: c_int,
+    wet: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetReverbProperties)(channelgroup, instance, wet),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetLowPassGain(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    gain: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetLowPassGain)(channelgroup, gain),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetLowPassGain(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    gain: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetLowPassGain)(channelgroup, gain),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetMode(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    mode: FMOD_MODE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetMode)(channelgroup, mode),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetMode(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    mode: *mut FMOD_MODE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetMode)(channelgroup, mode),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetCallback(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    callback: FMOD_CHANNELCONTROL_CALLBACK,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetCallback)(channelgroup, callback),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_IsPlaying(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    isplaying: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_IsPlaying)(channelgroup, isplaying),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetPan(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    pan: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetPan)(channelgroup, pan),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetMixLevelsOutput(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    frontleft: c_float,
+    frontright: c_float,
+    center: c_float,
+    lfe: c_float,
+    surroundleft: c_float,
+    surroundright: c_float,
+    backleft: c_float,
+    backright: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetMixLevelsOutput)(
+            channelgroup,
+            frontleft,
+            frontright,
+            center,
+            lfe,
+            surroundleft,
+            surroundright,
+            backleft,
+            backright,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetMixLevelsInput(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    levels: *mut c_float,
+    numlevels: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_ChannelGroup_SetMixLevelsInput)(channelgroup, levels, numlevels)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetMixMatrix(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    matrix: *mut c_float,
+    outchannels: c_int,
+    inchannels: c_int,
+    inchannel_hop: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetMixMatrix)(
+            channelgroup,
+            matrix,
+            outchannels,
+            inchannels,
+            inchannel_hop,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetMixMatrix(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    matrix: *mut c_float,
+    outchannels: *mut c_int,
+    inchannels: *mut c_int,
+    inchannel_hop: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetMixMatrix)(
+            channelgroup,
+            matrix,
+            outchannels,
+            inchannels,
+            inchannel_hop,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetDSPClock(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    dspclock: *mut c_ulonglong,
+    parentclock: *mut c_ulonglong,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetDSPClock)(channelgroup, dspclock, parentclock),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetDelay(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    dspclock_start: c_ulonglong,
+    dspclock_end: c_ulonglong,
+    stopchannels: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetDelay)(
+            channelgroup,
+            dspclock_start,
+            dspclock_end,
+            stopchannels,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetDelay(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    dspclock_start: *mut c_ulonglong,
+    dspclock_end: *mut c_ulonglong,
+    stopchannels: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetDelay)(
+            channelgroup,
+            dspclock_start,
+            dspclock_end,
+            stopchannels,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_AddFadePoint(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    dspclock: c_ulonglong,
+    volume: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_AddFadePoint)(channelgroup, dspclock, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetFadePointRamp(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    dspclock: c_ulonglong,
+    volume: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetFadePointRamp)(channelgroup, dspclock, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_RemoveFadePoints(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    dspclock_start: c_ulonglong,
+    dspclock_end: c_ulonglong,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_ChannelGroup_RemoveFadePoints)(channelgroup, dspclock_start, dspclock_end)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetFadePoints(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    numpoints: *mut c_uint,
+    point_dspclock: *mut c_ulonglong,
+    point_volume: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetFadePoints)(
+            channelgroup,
+            numpoints,
+            point_dspclock,
+            point_volume,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetDSP(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    index: c_int,
+    dsp: *mut *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetDSP)(channelgroup, index, dsp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_AddDSP(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    index: c_int,
+    dsp: *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_AddDSP)(channelgroup, index, dsp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_RemoveDSP(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    dsp: *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_RemoveDSP)(channelgroup, dsp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetNumDSPs(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    numdsps: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetNumDSPs)(channelgroup, numdsps),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetDSPIndex(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetDSPIndex)(channelgroup, dsp, index),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetDSPIndex(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    dsp: *mut FMOD_DSP,
+    index: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetDSPIndex)(channelgroup, dsp, index),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Set3DAttributes(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    pos: *const FMOD_VECTOR,
+    vel: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Set3DAttributes)(channelgroup, pos, vel),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Get3DAttributes(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    pos: *mut FMOD_VECTOR,
+    vel: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Get3DAttributes)(channelgroup, pos, vel),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Set3DMinMaxDistance(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    mindistance: c_float,
+    maxdistance: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_ChannelGroup_Set3DMinMaxDistance)(channelgroup, mindistance, maxdistance)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Get3DMinMaxDistance(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    mindistance: *mut c_float,
+    maxdistance: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_ChannelGroup_Get3DMinMaxDistance)(channelgroup, mindistance, maxdistance)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Set3DConeSettings(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    insideconeangle: c_float,
+    outsideconeangle: c_float,
+    outsidevolume: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Set3DConeSettings)(
+            channelgroup,
+            insideconeangle,
+            outsideconeangle,
+            outsidevolume,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Get3DConeSettings(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    insideconeangle: *mut c_float,
+    outsideconeangle: *mut c_float,
+    outsidevolume: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Get3DConeSettings)(
+            channelgroup,
+            insideconeangle,
+            outsideconeangle,
+            outsidevolume,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Set3DConeOrientation(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    orientation: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Set3DConeOrientation)(channelgroup, orientation),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Get3DConeOrientation(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    orientation: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Get3DConeOrientation)(channelgroup, orientation),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Set3DCustomRolloff(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    points: *mut FMOD_VECTOR,
+    numpoints: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_ChannelGroup_Set3DCustomRolloff)(channelgroup, points, numpoints)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Get3DCustomRolloff(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    points: *mut *mut FMOD_VECTOR,
+    numpoints: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_ChannelGroup_Get3DCustomRolloff)(channelgroup, points, numpoints)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Set3DOcclusion(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    directocclusion: c_float,
+    reverbocclusion: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Set3DOcclusion)(
+            channelgroup,
+            directocclusion,
+            reverbocclusion,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Get3DOcclusion(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    directocclusion: *mut c_float,
+    reverbocclusion: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Get3DOcclusion)(
+            channelgroup,
+            directocclusion,
+            reverbocclusion,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Set3DSpread(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    angle: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Set3DSpread)(channelgroup, angle),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Get3DSpread(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    angle: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Get3DSpread)(channelgroup, angle),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Set3DLevel(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    level: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Set3DLevel)(channelgroup, level),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Get3DLevel(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    level: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Get3DLevel)(channelgroup, level),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Set3DDopplerLevel(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    level: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Set3DDopplerLevel)(channelgroup, level),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Get3DDopplerLevel(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    level: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Get3DDopplerLevel)(channelgroup, level),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Set3DDistanceFilter(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    custom: FMOD_BOOL,
+    customLevel: c_float,
+    centerFreq: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Set3DDistanceFilter)(
+            channelgroup,
+            custom,
+            customLevel,
+            centerFreq,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Get3DDistanceFilter(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    custom: *mut FMOD_BOOL,
+    customLevel: *mut c_float,
+    centerFreq: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Get3DDistanceFilter)(
+            channelgroup,
+            custom,
+            customLevel,
+            centerFreq,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_SetUserData(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_SetUserData)(channelgroup, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetUserData(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetUserData)(channelgroup, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_Release(channelgroup: *mut FMOD_CHANNELGROUP) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_Release)(channelgroup),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_AddGroup(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    group: *mut FMOD_CHANNELGROUP,
+    propagatedspclock: FMOD_BOOL,
+    connection: *mut *mut FMOD_DSPCONNECTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_ChannelGroup_AddGroup)(channelgroup, group, propagatedspclock, connection)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetNumGroups(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    numgroups: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetNumGroups)(channelgroup, numgroups),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetGroup(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    index: c_int,
+    group: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetGroup)(channelgroup, index, group),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetParentGroup(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    group: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetParentGroup)(channelgroup, group),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetName(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    name: *mut c_char,
+    namelen: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetName)(channelgroup, name, namelen),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetNumChannels(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    numchannels: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetNumChannels)(channelgroup, numchannels),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_ChannelGroup_GetChannel(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    index: c_int,
+    channel: *mut *mut FMOD_CHANNEL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_ChannelGroup_GetChannel)(channelgroup, index, channel),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_Release(soundgroup: *mut FMOD_SOUNDGROUP) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_Release)(soundgroup),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_GetSystemObject(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    system: *mut *mut FMOD_SYSTEM,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_GetSystemObject)(soundgroup, system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_SetMaxAudible(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    maxaudible: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_SetMaxAudible)(soundgroup, maxaudible),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_GetMaxAudible(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    maxaudible: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_GetMaxAudible)(soundgroup, maxaudible),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_SetMaxAudibleBehavior(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    behavior: FMOD_SOUNDGROUP_BEHAVIOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_SetMaxAudibleBehavior)(soundgroup, behavior),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_GetMaxAudibleBehavior(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    behavior: *mut FMOD_SOUNDGROUP_BEHAVIOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_GetMaxAudibleBehavior)(soundgroup, behavior),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_SetMuteFadeSpeed(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    speed: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_SetMuteFadeSpeed)(soundgroup, speed),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_GetMuteFadeSpeed(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    speed: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_GetMuteFadeSpeed)(soundgroup, speed),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_SetVolume(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    volume: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_SetVolume)(soundgroup, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_GetVolume(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    volume: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_GetVolume)(soundgroup, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_Stop(soundgroup: *mut FMOD_SOUNDGROUP) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_Stop)(soundgroup),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_GetName(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    name: *mut c_char,
+    namelen: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_GetName)(soundgroup, name, namelen),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_GetNumSounds(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    numsounds: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_GetNumSounds)(soundgroup, numsounds),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_GetSound(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    index: c_int,
+    sound: *mut *mut FMOD_SOUND,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_GetSound)(soundgroup, index, sound),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_GetNumPlaying(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    numplaying: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_GetNumPlaying)(soundgroup, numplaying),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_SetUserData(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_SetUserData)(soundgroup, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_SoundGroup_GetUserData(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_SoundGroup_GetUserData)(soundgroup, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_Release(dsp: *mut FMOD_DSP) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_Release)(dsp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetSystemObject(
+    dsp: *mut FMOD_DSP,
+    system: *mut *mut FMOD_SYSTEM,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetSystemObject)(dsp, system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_AddInput(
+    dsp: *mut FMOD_DSP,
+    input: *mut FMOD_DSP,
+    connection: *mut *mut FMOD_DSPCONNECTION,
+    type_: FMOD_DSPCONNECTION_TYPE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_AddInput)(dsp, input, connection, type_),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_DisconnectFrom(
+    dsp: *mut FMOD_DSP,
+    target: *mut FMOD_DSP,
+    connection: *mut FMOD_DSPCONNECTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_DisconnectFrom)(dsp, target, connection),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_DisconnectAll(
+    dsp: *mut FMOD_DSP,
+    inputs: FMOD_BOOL,
+    outputs: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_DisconnectAll)(dsp, inputs, outputs),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetNumInputs(dsp: *mut FMOD_DSP, numinputs: *mut c_int) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetNumInputs)(dsp, numinputs),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetNumOutputs(dsp: *mut FMOD_DSP, numoutputs: *mut c_int) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetNumOutputs)(dsp, numoutputs),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetInput(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    input: *mut *mut FMOD_DSP,
+    inputconnection: *mut *mut FMOD_DSPCONNECTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetInput)(dsp, index, input, inputconnection),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetOutput(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    output: *mut *mut FMOD_DSP,
+    outputconnection: *mut *mut FMOD_DSPCONNECTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetOutput)(dsp, index, output, outputconnection),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_SetActive(dsp: *mut FMOD_DSP, active: FMOD_BOOL) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_SetActive)(dsp, active),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetActive(dsp: *mut FMOD_DSP, active: *mut FMOD_BOOL) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetActive)(dsp, active),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_SetBypass(dsp: *mut FMOD_DSP, bypass: FMOD_BOOL) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_SetBypass)(dsp, bypass),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetBypass(dsp: *mut FMOD_DSP, bypass: *mut FMOD_BOOL) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetBypass)(dsp, bypass),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_SetWetDryMix(
+    dsp: *mut FMOD_DSP,
+    prewet: c_float,
+    postwet: c_float,
+    dry: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_SetWetDryMix)(dsp, prewet, postwet, dry),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetWetDryMix(
+    dsp: *mut FMOD_DSP,
+    prewet: *mut c_float,
+    postwet: *mut c_float,
+    dry: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetWetDryMix)(dsp, prewet, postwet, dry),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_SetChannelFormat(
+    dsp: *mut FMOD_DSP,
+    channelmask: FMOD_CHANNELMASK,
+    numchannels: c_int,
+    source_speakermode: FMOD_SPEAKERMODE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_DSP_SetChannelFormat)(dsp, channelmask, numchannels, source_speakermode)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetChannelFormat(
+    dsp: *mut FMOD_DSP,
+    channelmask: *mut FMOD_CHANNELMASK,
+    numchannels: *mut c_int,
+    source_speakermode: *mut FMOD_SPEAKERMODE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_DSP_GetChannelFormat)(dsp, channelmask, numchannels, source_speakermode)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetOutputChannelFormat(
+    dsp: *mut FMOD_DSP,
+    inmask: FMOD_CHANNELMASK,
+    inchannels: c_int,
+    inspeakermode: FMOD_SPEAKERMODE,
+    outmask: *mut FMOD_CHANNELMASK,
+    outchannels: *mut c_int,
+    outspeakermode: *mut FMOD_SPEAKERMODE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetOutputChannelFormat)(
+            dsp,
+            inmask,
+            inchannels,
+            inspeakermode,
+            outmask,
+            outchannels,
+            outspeakermode,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_Reset(dsp: *mut FMOD_DSP) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_Reset)(dsp),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_SetCallback(dsp: *mut FMOD_DSP, callback: FMOD_DSP_CALLBACK) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_SetCallback)(dsp, callback),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_SetParameterFloat(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    value: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_SetParameterFloat)(dsp, index, value),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_SetParameterInt(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    value: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_SetParameterInt)(dsp, index, value),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_SetParameterBool(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    value: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_SetParameterBool)(dsp, index, value),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_SetParameterData(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    data: *mut c_void,
+    length: c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_SetParameterData)(dsp, index, data, length),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetParameterFloat(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    value: *mut c_float,
+    valuestr: *mut c_char,
+    valuestrlen: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_DSP_GetParameterFloat)(dsp, index, value, valuestr, valuestrlen)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetParameterInt(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    value: *mut c_int,
+    valuestr: *mut c_char,
+    valuestrlen: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetParameterInt)(dsp, index, value, valuestr, valuestrlen),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetParameterBool(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    value: *mut FMOD_BOOL,
+    valuestr: *mut c_char,
+    valuestrlen: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_DSP_GetParameterBool)(dsp, index, value, valuestr, valuestrlen)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetParameterData(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    data: *mut *mut c_void,
+    length: *mut c_uint,
+    valuestr: *mut c_char,
+    valuestrlen: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_DSP_GetParameterData)(dsp, index, data, length, valuestr, valuestrlen)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetNumParameters(dsp: *mut FMOD_DSP, numparams: *mut c_int) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetNumParameters)(dsp, numparams),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetParameterInfo(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    desc: *mut *mut FMOD_DSP_PARAMETER_DESC,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetParameterInfo)(dsp, index, desc),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetDataParameterIndex(
+    dsp: *mut FMOD_DSP,
+    datatype: c_int,
+    index: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetDataParameterIndex)(dsp, datatype, index),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_ShowConfigDialog(
+    dsp: *mut FMOD_DSP,
+    hwnd: *mut c_void,
+    show: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_ShowConfigDialog)(dsp, hwnd, show),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetInfo(
+    dsp: *mut FMOD_DSP,
+    name: *mut c_char,
+    version: *mut c_uint,
+    channels: *mut c_int,
+    configwidth: *mut c_int,
+    configheight: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_DSP_GetInfo)(dsp, name, version, channels, configwidth, configheight)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetType(dsp: *mut FMOD_DSP, type_: *mut FMOD_DSP_TYPE) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetType)(dsp, type_),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetIdle(dsp: *mut FMOD_DSP, idle: *mut FMOD_BOOL) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetIdle)(dsp, idle),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_SetUserData(dsp: *mut FMOD_DSP, userdata: *mut c_void) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_SetUserData)(dsp, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetUserData(dsp: *mut FMOD_DSP, userdata: *mut *mut c_void) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetUserData)(dsp, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_SetMeteringEnabled(
+    dsp: *mut FMOD_DSP,
+    inputEnabled: FMOD_BOOL,
+    outputEnabled: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_SetMeteringEnabled)(dsp, inputEnabled, outputEnabled),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetMeteringEnabled(
+    dsp: *mut FMOD_DSP,
+    inputEnabled: *mut FMOD_BOOL,
+    outputEnabled: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetMeteringEnabled)(dsp, inputEnabled, outputEnabled),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetMeteringInfo(
+    dsp: *mut FMOD_DSP,
+    inputInfo: *mut FMOD_DSP_METERING_INFO,
+    outputInfo: *mut FMOD_DSP_METERING_INFO,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetMeteringInfo)(dsp, inputInfo, outputInfo),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSP_GetCPUUsage(
+    dsp: *mut FMOD_DSP,
+    exclusive: *mut c_uint,
+    inclusive: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSP_GetCPUUsage)(dsp, exclusive, inclusive),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSPConnection_GetInput(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    input: *mut *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSPConnection_GetInput)(dspconnection, input),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSPConnection_GetOutput(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    output: *mut *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSPConnection_GetOutput)(dspconnection, output),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSPConnection_SetMix(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    volume: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSPConnection_SetMix)(dspconnection, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSPConnection_GetMix(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    volume: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSPConnection_GetMix)(dspconnection, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSPConnection_SetMixMatrix(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    matrix: *mut c_float,
+    outchannels: c_int,
+    inchannels: c_int,
+    inchannel_hop: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSPConnection_SetMixMatrix)(
+            dspconnection,
+            matrix,
+            outchannels,
+            inchannels,
+            inchannel_hop,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSPConnection_GetMixMatrix(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    matrix: *mut c_float,
+    outchannels: *mut c_int,
+    inchannels: *mut c_int,
+    inchannel_hop: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSPConnection_GetMixMatrix)(
+            dspconnection,
+            matrix,
+            outchannels,
+            inchannels,
+            inchannel_hop,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSPConnection_GetType(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    type_: *mut FMOD_DSPCONNECTION_TYPE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSPConnection_GetType)(dspconnection, type_),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSPConnection_SetUserData(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSPConnection_SetUserData)(dspconnection, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_DSPConnection_GetUserData(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_DSPConnection_GetUserData)(dspconnection, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_Release(geometry: *mut FMOD_GEOMETRY) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_Release)(geometry),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_AddPolygon(
+    geometry: *mut FMOD_GEOMETRY,
+    directocclusion: c_float,
+    reverbocclusion: c_float,
+    doublesided: FMOD_BOOL,
+    numvertices: c_int,
+    vertices: *const FMOD_VECTOR,
+    polygonindex: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_AddPolygon)(
+            geometry,
+            directocclusion,
+            reverbocclusion,
+            doublesided,
+            numvertices,
+            vertices,
+            polygonindex,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_GetNumPolygons(
+    geometry: *mut FMOD_GEOMETRY,
+    numpolygons: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_GetNumPolygons)(geometry, numpolygons),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_GetMaxPolygons(
+    geometry: *mut FMOD_GEOMETRY,
+    maxpolygons: *mut c_int,
+    maxvertices: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_GetMaxPolygons)(geometry, maxpolygons, maxvertices),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_GetPolygonNumVertices(
+    geometry: *mut FMOD_GEOMETRY,
+    index: c_int,
+    numvertices: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_GetPolygonNumVertices)(geometry, index, numvertices),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_SetPolygonVertex(
+    geometry: *mut FMOD_GEOMETRY,
+    index: c_int,
+    vertexindex: c_int,
+    vertex: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Geometry_SetPolygonVertex)(geometry, index, vertexindex, vertex)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_GetPolygonVertex(
+    geometry: *mut FMOD_GEOMETRY,
+    index: c_int,
+    vertexindex: c_int,
+    vertex: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Geometry_GetPolygonVertex)(geometry, index, vertexindex, vertex)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_SetPolygonAttributes(
+    geometry: *mut FMOD_GEOMETRY,
+    index: c_int,
+    directocclusion: c_float,
+    reverbocclusion: c_float,
+    doublesided: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_SetPolygonAttributes)(
+            geometry,
+            index,
+            directocclusion,
+            reverbocclusion,
+            doublesided,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_GetPolygonAttributes(
+    geometry: *mut FMOD_GEOMETRY,
+    index: c_int,
+    directocclusion: *mut c_float,
+    reverbocclusion: *mut c_float,
+    doublesided: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_GetPolygonAttributes)(
+            geometry,
+            index,
+            directocclusion,
+            reverbocclusion,
+            doublesided,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_SetActive(
+    geometry: *mut FMOD_GEOMETRY,
+    active: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_SetActive)(geometry, active),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_GetActive(
+    geometry: *mut FMOD_GEOMETRY,
+    active: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_GetActive)(geometry, active),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_SetRotation(
+    geometry: *mut FMOD_GEOMETRY,
+    forward: *const FMOD_VECTOR,
+    up: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_SetRotation)(geometry, forward, up),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_GetRotation(
+    geometry: *mut FMOD_GEOMETRY,
+    forward: *mut FMOD_VECTOR,
+    up: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_GetRotation)(geometry, forward, up),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_SetPosition(
+    geometry: *mut FMOD_GEOMETRY,
+    position: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_SetPosition)(geometry, position),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_GetPosition(
+    geometry: *mut FMOD_GEOMETRY,
+    position: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_GetPosition)(geometry, position),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_SetScale(
+    geometry: *mut FMOD_GEOMETRY,
+    scale: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_SetScale)(geometry, scale),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_GetScale(
+    geometry: *mut FMOD_GEOMETRY,
+    scale: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_GetScale)(geometry, scale),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_Save(
+    geometry: *mut FMOD_GEOMETRY,
+    data: *mut c_void,
+    datasize: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_Save)(geometry, data, datasize),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_SetUserData(
+    geometry: *mut FMOD_GEOMETRY,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_SetUserData)(geometry, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Geometry_GetUserData(
+    geometry: *mut FMOD_GEOMETRY,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Geometry_GetUserData)(geometry, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Reverb3D_Release(reverb3d: *mut FMOD_REVERB3D) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Reverb3D_Release)(reverb3d),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Reverb3D_Set3DAttributes(
+    reverb3d: *mut FMOD_REVERB3D,
+    position: *const FMOD_VECTOR,
+    mindistance: c_float,
+    maxdistance: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Reverb3D_Set3DAttributes)(reverb3d, position, mindistance, maxdistance)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Reverb3D_Get3DAttributes(
+    reverb3d: *mut FMOD_REVERB3D,
+    position: *mut FMOD_VECTOR,
+    mindistance: *mut c_float,
+    maxdistance: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Reverb3D_Get3DAttributes)(reverb3d, position, mindistance, maxdistance)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Reverb3D_SetProperties(
+    reverb3d: *mut FMOD_REVERB3D,
+    properties: *const FMOD_REVERB_PROPERTIES,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Reverb3D_SetProperties)(reverb3d, properties),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Reverb3D_GetProperties(
+    reverb3d: *mut FMOD_REVERB3D,
+    properties: *mut FMOD_REVERB_PROPERTIES,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Reverb3D_GetProperties)(reverb3d, properties),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Reverb3D_SetActive(
+    reverb3d: *mut FMOD_REVERB3D,
+    active: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Reverb3D_SetActive)(reverb3d, active),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Reverb3D_GetActive(
+    reverb3d: *mut FMOD_REVERB3D,
+    active: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Reverb3D_GetActive)(reverb3d, active),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Reverb3D_SetUserData(
+    reverb3d: *mut FMOD_REVERB3D,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Reverb3D_SetUserData)(reverb3d, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Reverb3D_GetUserData(
+    reverb3d: *mut FMOD_REVERB3D,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Reverb3D_GetUserData)(reverb3d, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_ParseID(idstring: *const c_char, id: *mut FMOD_GUID) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_ParseID)(idstring, id),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_Create(
+    system: *mut *mut FMOD_STUDIO_SYSTEM,
+    headerversion: c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_Create)(system, headerversion),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_IsValid(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_BOOL {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_IsValid)(system),
+        Err(_) => Default::default(),
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_SetAdvancedSettings(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    settings: *mut FMOD_STUDIO_ADVANCEDSETTINGS,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_SetAdvancedSettings)(system, settings),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetAdvancedSettings(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    settings: *mut FMOD_STUDIO_ADVANCEDSETTINGS,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetAdvancedSettings)(system, settings),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_Initialize(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    maxchannels: c_int,
+    studioflags: FMOD_STUDIO_INITFLAGS,
+    flags: FMOD_INITFLAGS,
+    extradriverdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_Initialize)(
+            system,
+            maxchannels,
+            studioflags,
+            flags,
+            extradriverdata,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_Release(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_Release)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_Update(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_Update)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetCoreSystem(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    coresystem: *mut *mut FMOD_SYSTEM,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetCoreSystem)(system, coresystem),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetEvent(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    pathOrID: *const c_char,
+    event: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetEvent)(system, pathOrID, event),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetBus(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    pathOrID: *const c_char,
+    bus: *mut *mut FMOD_STUDIO_BUS,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetBus)(system, pathOrID, bus),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetVCA(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    pathOrID: *const c_char,
+    vca: *mut *mut FMOD_STUDIO_VCA,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetVCA)(system, pathOrID, vca),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetBank(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    pathOrID: *const c_char,
+    bank: *mut *mut FMOD_STUDIO_BANK,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetBank)(system, pathOrID, bank),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetEventByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: *const FMOD_GUID,
+    event: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetEventByID)(system, id, event),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetBusByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: *const FMOD_GUID,
+    bus: *mut *mut FMOD_STUDIO_BUS,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetBusByID)(system, id, bus),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetVCAByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: *const FMOD_GUID,
+    vca: *mut *mut FMOD_STUDIO_VCA,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetVCAByID)(system, id, vca),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetBankByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: *const FMOD_GUID,
+    bank: *mut *mut FMOD_STUDIO_BANK,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetBankByID)(system, id, bank),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetSoundInfo(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    key: *const c_char,
+    info: *mut FMOD_STUDIO_SOUND_INFO,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetSoundInfo)(system, key, info),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetParameterDescriptionByName(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    name: *const c_char,
+    parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_System_GetParameterDescriptionByName)(system, name, parameter)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetParameterDescriptionByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_System_GetParameterDescriptionByID)(system, id, parameter)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetParameterLabelByName(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    name: *const c_char,
+    labelindex: c_int,
+    label: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetParameterLabelByName)(
+            system, name, labelindex, label, size, retrieved,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetParameterLabelByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    labelindex: c_int,
+    label: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetParameterLabelByID)(
+            system, id, labelindex, label, size, retrieved,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetParameterByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    value: *mut c_float,
+    finalvalue: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetParameterByID)(system, id, value, finalvalue),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_SetParameterByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    value: c_float,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_System_SetParameterByID)(system, id, value, ignoreseekspeed)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_SetParameterByIDWithLabel(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    label: *const c_char,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_SetParameterByIDWithLabel)(
+            system,
+            id,
+            label,
+            ignoreseekspeed,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_SetParametersByIDs(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    ids: *const FMOD_STUDIO_PARAMETER_ID,
+    values: *mut c_float,
+    count: c_int,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_SetParametersByIDs)(
+            system,
+            ids,
+            values,
+            count,
+            ignoreseekspeed,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetParameterByName(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    name: *const c_char,
+    value: *mut c_float,
+    finalvalue: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_System_GetParameterByName)(system, name, value, finalvalue)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_SetParameterByName(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    name: *const c_char,
+    value: c_float,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_System_SetParameterByName)(system, name, value, ignoreseekspeed)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_SetParameterByNameWithLabel(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    name: *const c_char,
+    label: *const c_char,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_SetParameterByNameWithLabel)(
+            system,
+            name,
+            label,
+            ignoreseekspeed,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_LookupID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    path: *const c_char,
+    id: *mut FMOD_GUID,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_LookupID)(system, path, id),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_LookupPath(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: *const FMOD_GUID,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_LookupPath)(system, id, path, size, retrieved),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetNumListeners(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    numlisteners: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetNumListeners)(system, numlisteners),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_SetNumListeners(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    numlisteners: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_SetNumListeners)(system, numlisteners),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetListenerAttributes(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    index: c_int,
+    attributes: *mut FMOD_3D_ATTRIBUTES,
+    attenuationposition: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetListenerAttributes)(
+            system,
+            index,
+            attributes,
+            attenuationposition,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_SetListenerAttributes(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    index: c_int,
+    attributes: *const FMOD_3D_ATTRIBUTES,
+    attenuationposition: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_SetListenerAttributes)(
+            system,
+            index,
+            attributes,
+            attenuationposition,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetListenerWeight(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    index: c_int,
+    weight: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetListenerWeight)(system, index, weight),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_SetListenerWeight(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    index: c_int,
+    weight: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_SetListenerWeight)(system, index, weight),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_LoadBankFile(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    filename: *const c_char,
+    flags: FMOD_STUDIO_LOAD_BANK_FLAGS,
+    bank: *mut *mut FMOD_STUDIO_BANK,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_LoadBankFile)(system, filename, flags, bank),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_LoadBankMemory(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    buffer: *const c_char,
+    length: c_int,
+    mode: FMOD_STUDIO_LOAD_MEMORY_MODE,
+    flags: FMOD_STUDIO_LOAD_BANK_FLAGS,
+    bank: *mut *mut FMOD_STUDIO_BANK,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_System_LoadBankMemory)(system, buffer, length, mode, flags, bank)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_LoadBankCustom(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    info: *const FMOD_STUDIO_BANK_INFO,
+    flags: FMOD_STUDIO_LOAD_BANK_FLAGS,
+    bank: *mut *mut FMOD_STUDIO_BANK,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_LoadBankCustom)(system, info, flags, bank),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_RegisterPlugin(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    description: *const FMOD_DSP_DESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_RegisterPlugin)(system, description),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_UnregisterPlugin(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    name: *const c_char,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_UnregisterPlugin)(system, name),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_UnloadAll(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_UnloadAll)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_FlushCommands(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_FlushCommands)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_FlushSampleLoading(
+    system: *mut FMOD_STUDIO_SYSTEM,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_FlushSampleLoading)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_StartCommandCapture(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    filename: *const c_char,
+    flags: FMOD_STUDIO_COMMANDCAPTURE_FLAGS,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_StartCommandCapture)(system, filename, flags),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_StopCommandCapture(
+    system: *mut FMOD_STUDIO_SYSTEM,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_StopCommandCapture)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_LoadCommandReplay(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    filename: *const c_char,
+    flags: FMOD_STUDIO_COMMANDREPLAY_FLAGS,
+    replay: *mut *mut FMOD_STUDIO_COMMANDREPLAY,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_System_LoadCommandReplay)(system, filename, flags, replay)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetBankCount(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetBankCount)(system, count),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetBankList(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    array: *mut *mut FMOD_STUDIO_BANK,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetBankList)(system, array, capacity, count),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetParameterDescriptionCount(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetParameterDescriptionCount)(system, count),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetParameterDescriptionList(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    array: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_System_GetParameterDescriptionList)(system, array, capacity, count)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetCPUUsage(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    usage: *mut FMOD_STUDIO_CPU_USAGE,
+    usage_core: *mut FMOD_CPU_USAGE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetCPUUsage)(system, usage, usage_core),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetBufferUsage(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    usage: *mut FMOD_STUDIO_BUFFER_USAGE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetBufferUsage)(system, usage),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_ResetBufferUsage(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_ResetBufferUsage)(system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_SetCallback(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    callback: FMOD_STUDIO_SYSTEM_CALLBACK,
+    callbackmask: FMOD_STUDIO_SYSTEM_CALLBACK_TYPE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_SetCallback)(system, callback, callbackmask),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_SetUserData(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_SetUserData)(system, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetUserData(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetUserData)(system, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_System_GetMemoryUsage(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    memoryusage: *mut FMOD_STUDIO_MEMORY_USAGE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_System_GetMemoryUsage)(system, memoryusage),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_IsValid(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_BOOL {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_IsValid)(eventdescription),
+        Err(_) => Default::default(),
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetID(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    id: *mut FMOD_GUID,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_GetID)(eventdescription, id),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetPath(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventDescription_GetPath)(eventdescription, path, size, retrieved)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterDescriptionCount(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_GetParameterDescriptionCount)(
+            eventdescription,
+            count,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterDescriptionByIndex(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    index: c_int,
+    parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_GetParameterDescriptionByIndex)(
+            eventdescription,
+            index,
+            parameter,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterDescriptionByName(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    name: *const c_char,
+    parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_GetParameterDescriptionByName)(
+            eventdescription,
+            name,
+            parameter,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterDescriptionByID(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_GetParameterDescriptionByID)(
+            eventdescription,
+            id,
+            parameter,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterLabelByIndex(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    index: c_int,
+    labelindex: c_int,
+    label: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_GetParameterLabelByIndex)(
+            eventdescription,
+            index,
+            labelindex,
+            label,
+            size,
+            retrieved,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterLabelByName(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    name: *const c_char,
+    labelindex: c_int,
+    label: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_GetParameterLabelByName)(
+            eventdescription,
+            name,
+            labelindex,
+            label,
+            size,
+            retrieved,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterLabelByID(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    labelindex: c_int,
+    label: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_GetParameterLabelByID)(
+            eventdescription,
+            id,
+            labelindex,
+            label,
+            size,
+            retrieved,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetUserPropertyCount(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventDescription_GetUserPropertyCount)(eventdescription, count)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetUserPropertyByIndex(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    index: c_int,
+    property: *mut FMOD_STUDIO_USER_PROPERTY,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_GetUserPropertyByIndex)(
+            eventdescription,
+            index,
+            property,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetUserProperty(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    name: *const c_char,
+    property: *mut FMOD_STUDIO_USER_PROPERTY,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventDescription_GetUserProperty)(eventdescription, name, property)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetLength(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    length: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_GetLength)(eventdescription, length),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetMinMaxDistance(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    min: *mut c_float,
+    max: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventDescription_GetMinMaxDistance)(eventdescription, min, max)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetSoundSize(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    size: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_GetSoundSize)(eventdescription, size),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_IsSnapshot(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    snapshot: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventDescription_IsSnapshot)(eventdescription, snapshot)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_IsOneshot(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    oneshot: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_IsOneshot)(eventdescription, oneshot),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_IsStream(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    isStream: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_IsStream)(eventdescription, isStream),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_Is3D(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    is3D: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_Is3D)(eventdescription, is3D),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_IsDopplerEnabled(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    doppler: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventDescription_IsDopplerEnabled)(eventdescription, doppler)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_HasSustainPoint(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    sustainPoint: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventDescription_HasSustainPoint)(eventdescription, sustainPoint)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_CreateInstance(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    instance: *mut *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventDescription_CreateInstance)(eventdescription, instance)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetInstanceCount(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventDescription_GetInstanceCount)(eventdescription, count)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetInstanceList(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    array: *mut *mut FMOD_STUDIO_EVENTINSTANCE,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_GetInstanceList)(
+            eventdescription,
+            array,
+            capacity,
+            count,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_LoadSampleData(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_LoadSampleData)(eventdescription),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_UnloadSampleData(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_UnloadSampleData)(eventdescription),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetSampleLoadingState(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    state: *mut FMOD_STUDIO_LOADING_STATE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventDescription_GetSampleLoadingState)(eventdescription, state)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_ReleaseAllInstances(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_ReleaseAllInstances)(eventdescription),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_SetCallback(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    callback: FMOD_STUDIO_EVENT_CALLBACK,
+    callbackmask: FMOD_STUDIO_EVENT_CALLBACK_TYPE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventDescription_SetCallback)(
+            eventdescription,
+            callback,
+            callbackmask,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_GetUserData(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventDescription_GetUserData)(eventdescription, userdata)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventDescription_SetUserData(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventDescription_SetUserData)(eventdescription, userdata)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_IsValid(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_BOOL {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_IsValid)(eventinstance),
+        Err(_) => Default::default(),
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetDescription(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    description: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventInstance_GetDescription)(eventinstance, description)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetVolume(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    volume: *mut c_float,
+    finalvolume: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventInstance_GetVolume)(eventinstance, volume, finalvolume)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetVolume(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    volume: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_SetVolume)(eventinstance, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetPitch(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    pitch: *mut c_float,
+    finalpitch: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventInstance_GetPitch)(eventinstance, pitch, finalpitch)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetPitch(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    pitch: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_SetPitch)(eventinstance, pitch),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_Get3DAttributes(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    attributes: *mut FMOD_3D_ATTRIBUTES,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventInstance_Get3DAttributes)(eventinstance, attributes)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_Set3DAttributes(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    attributes: *mut FMOD_3D_ATTRIBUTES,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventInstance_Set3DAttributes)(eventinstance, attributes)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetListenerMask(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    mask: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_GetListenerMask)(eventinstance, mask),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetListenerMask(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    mask: c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_SetListenerMask)(eventinstance, mask),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetProperty(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    index: FMOD_STUDIO_EVENT_PROPERTY,
+    value: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_GetProperty)(eventinstance, index, value),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetProperty(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    index: FMOD_STUDIO_EVENT_PROPERTY,
+    value: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_SetProperty)(eventinstance, index, value),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetReverbLevel(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    index: c_int,
+    level: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventInstance_GetReverbLevel)(eventinstance, index, level)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetReverbLevel(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    index: c_int,
+    level: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventInstance_SetReverbLevel)(eventinstance, index, level)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetPaused(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    paused: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_GetPaused)(eventinstance, paused),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetPaused(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    paused: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_SetPaused)(eventinstance, paused),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_Start(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_Start)(eventinstance),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_Stop(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    mode: FMOD_STUDIO_STOP_MODE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_Stop)(eventinstance, mode),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetTimelinePosition(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    position: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventInstance_GetTimelinePosition)(eventinstance, position)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetTimelinePosition(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    position: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventInstance_SetTimelinePosition)(eventinstance, position)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetPlaybackState(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    state: *mut FMOD_STUDIO_PLAYBACK_STATE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_GetPlaybackState)(eventinstance, state),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetChannelGroup(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    group: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_GetChannelGroup)(eventinstance, group),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetMinMaxDistance(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    min: *mut c_float,
+    max: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventInstance_GetMinMaxDistance)(eventinstance, min, max)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_Release(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_Release)(eventinstance),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_IsVirtual(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    virtualstate: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_IsVirtual)(eventinstance, virtualstate),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetParameterByName(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    name: *const c_char,
+    value: *mut c_float,
+    finalvalue: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_GetParameterByName)(
+            eventinstance,
+            name,
+            value,
+            finalvalue,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetParameterByName(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    name: *const c_char,
+    value: c_float,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_SetParameterByName)(
+            eventinstance,
+            name,
+            value,
+            ignoreseekspeed,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetParameterByNameWithLabel(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    name: *const c_char,
+    label: *const c_char,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_SetParameterByNameWithLabel)(
+            eventinstance,
+            name,
+            label,
+            ignoreseekspeed,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetParameterByID(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    value: *mut c_float,
+    finalvalue: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_GetParameterByID)(
+            eventinstance,
+            id,
+            value,
+            finalvalue,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetParameterByID(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    value: c_float,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_SetParameterByID)(
+            eventinstance,
+            id,
+            value,
+            ignoreseekspeed,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetParameterByIDWithLabel(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    label: *const c_char,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_SetParameterByIDWithLabel)(
+            eventinstance,
+            id,
+            label,
+            ignoreseekspeed,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetParametersByIDs(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    ids: *const FMOD_STUDIO_PARAMETER_ID,
+    values: *mut c_float,
+    count: c_int,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_SetParametersByIDs)(
+            eventinstance,
+            ids,
+            values,
+            count,
+            ignoreseekspeed,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_KeyOff(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_KeyOff)(eventinstance),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetCallback(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    callback: FMOD_STUDIO_EVENT_CALLBACK,
+    callbackmask: FMOD_STUDIO_EVENT_CALLBACK_TYPE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventInstance_SetCallback)(eventinstance, callback, callbackmask)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetUserData(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_GetUserData)(eventinstance, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_SetUserData(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_EventInstance_SetUserData)(eventinstance, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetCPUUsage(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    exclusive: *mut c_uint,
+    inclusive: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventInstance_GetCPUUsage)(eventinstance, exclusive, inclusive)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_EventInstance_GetMemoryUsage(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    memoryusage: *mut FMOD_STUDIO_MEMORY_USAGE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_EventInstance_GetMemoryUsage)(eventinstance, memoryusage)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_IsValid(bus: *mut FMOD_STUDIO_BUS) -> FMOD_BOOL {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_IsValid)(bus),
+        Err(_) => Default::default(),
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_GetID(bus: *mut FMOD_STUDIO_BUS, id: *mut FMOD_GUID) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_GetID)(bus, id),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_GetPath(
+    bus: *mut FMOD_STUDIO_BUS,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_GetPath)(bus, path, size, retrieved),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_GetVolume(
+    bus: *mut FMOD_STUDIO_BUS,
+    volume: *mut c_float,
+    finalvolume: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_GetVolume)(bus, volume, finalvolume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_SetVolume(bus: *mut FMOD_STUDIO_BUS, volume: c_float) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_SetVolume)(bus, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_GetPaused(
+    bus: *mut FMOD_STUDIO_BUS,
+    paused: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_GetPaused)(bus, paused),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_SetPaused(
+    bus: *mut FMOD_STUDIO_BUS,
+    paused: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_SetPaused)(bus, paused),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_GetMute(
+    bus: *mut FMOD_STUDIO_BUS,
+    mute: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_GetMute)(bus, mute),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_SetMute(bus: *mut FMOD_STUDIO_BUS, mute: FMOD_BOOL) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_SetMute)(bus, mute),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_StopAllEvents(
+    bus: *mut FMOD_STUDIO_BUS,
+    mode: FMOD_STUDIO_STOP_MODE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_StopAllEvents)(bus, mode),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_GetPortIndex(
+    bus: *mut FMOD_STUDIO_BUS,
+    index: *mut FMOD_PORT_INDEX,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_GetPortIndex)(bus, index),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_SetPortIndex(
+    bus: *mut FMOD_STUDIO_BUS,
+    index: FMOD_PORT_INDEX,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_SetPortIndex)(bus, index),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_LockChannelGroup(bus: *mut FMOD_STUDIO_BUS) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_LockChannelGroup)(bus),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_UnlockChannelGroup(bus: *mut FMOD_STUDIO_BUS) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_UnlockChannelGroup)(bus),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_GetChannelGroup(
+    bus: *mut FMOD_STUDIO_BUS,
+    group: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_GetChannelGroup)(bus, group),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_GetCPUUsage(
+    bus: *mut FMOD_STUDIO_BUS,
+    exclusive: *mut c_uint,
+    inclusive: *mut c_uint,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_GetCPUUsage)(bus, exclusive, inclusive),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bus_GetMemoryUsage(
+    bus: *mut FMOD_STUDIO_BUS,
+    memoryusage: *mut FMOD_STUDIO_MEMORY_USAGE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bus_GetMemoryUsage)(bus, memoryusage),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_VCA_IsValid(vca: *mut FMOD_STUDIO_VCA) -> FMOD_BOOL {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_VCA_IsValid)(vca),
+        Err(_) => Default::default(),
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_VCA_GetID(vca: *mut FMOD_STUDIO_VCA, id: *mut FMOD_GUID) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_VCA_GetID)(vca, id),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_VCA_GetPath(
+    vca: *mut FMOD_STUDIO_VCA,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_VCA_GetPath)(vca, path, size, retrieved),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_VCA_GetVolume(
+    vca: *mut FMOD_STUDIO_VCA,
+    volume: *mut c_float,
+    finalvolume: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_VCA_GetVolume)(vca, volume, finalvolume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_VCA_SetVolume(vca: *mut FMOD_STUDIO_VCA, volume: c_float) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_VCA_SetVolume)(vca, volume),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_IsValid(bank: *mut FMOD_STUDIO_BANK) -> FMOD_BOOL {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_IsValid)(bank),
+        Err(_) => Default::default(),
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_GetID(
+    bank: *mut FMOD_STUDIO_BANK,
+    id: *mut FMOD_GUID,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_GetID)(bank, id),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_GetPath(
+    bank: *mut FMOD_STUDIO_BANK,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_GetPath)(bank, path, size, retrieved),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_Unload(bank: *mut FMOD_STUDIO_BANK) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_Unload)(bank),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_LoadSampleData(bank: *mut FMOD_STUDIO_BANK) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_LoadSampleData)(bank),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_UnloadSampleData(bank: *mut FMOD_STUDIO_BANK) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_UnloadSampleData)(bank),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_GetLoadingState(
+    bank: *mut FMOD_STUDIO_BANK,
+    state: *mut FMOD_STUDIO_LOADING_STATE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_GetLoadingState)(bank, state),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_GetSampleLoadingState(
+    bank: *mut FMOD_STUDIO_BANK,
+    state: *mut FMOD_STUDIO_LOADING_STATE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_GetSampleLoadingState)(bank, state),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_GetStringCount(
+    bank: *mut FMOD_STUDIO_BANK,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_GetStringCount)(bank, count),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_GetStringInfo(
+    bank: *mut FMOD_STUDIO_BANK,
+    index: c_int,
+    id: *mut FMOD_GUID,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_Bank_GetStringInfo)(bank, index, id, path, size, retrieved)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_GetEventCount(
+    bank: *mut FMOD_STUDIO_BANK,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_GetEventCount)(bank, count),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_GetEventList(
+    bank: *mut FMOD_STUDIO_BANK,
+    array: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_GetEventList)(bank, array, capacity, count),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_GetBusCount(
+    bank: *mut FMOD_STUDIO_BANK,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_GetBusCount)(bank, count),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_GetBusList(
+    bank: *mut FMOD_STUDIO_BANK,
+    array: *mut *mut FMOD_STUDIO_BUS,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_GetBusList)(bank, array, capacity, count),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_GetVCACount(
+    bank: *mut FMOD_STUDIO_BANK,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_GetVCACount)(bank, count),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_GetVCAList(
+    bank: *mut FMOD_STUDIO_BANK,
+    array: *mut *mut FMOD_STUDIO_VCA,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_GetVCAList)(bank, array, capacity, count),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_GetUserData(
+    bank: *mut FMOD_STUDIO_BANK,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_GetUserData)(bank, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_Bank_SetUserData(
+    bank: *mut FMOD_STUDIO_BANK,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_Bank_SetUserData)(bank, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_IsValid(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+) -> FMOD_BOOL {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_IsValid)(replay),
+        Err(_) => Default::default(),
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_GetSystem(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    system: *mut *mut FMOD_STUDIO_SYSTEM,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_GetSystem)(replay, system),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_GetLength(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    length: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_GetLength)(replay, length),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_GetCommandCount(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_GetCommandCount)(replay, count),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_GetCommandInfo(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    commandindex: c_int,
+    info: *mut FMOD_STUDIO_COMMAND_INFO,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_CommandReplay_GetCommandInfo)(replay, commandindex, info)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_GetCommandString(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    commandindex: c_int,
+    buffer: *mut c_char,
+    length: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_GetCommandString)(
+            replay,
+            commandindex,
+            buffer,
+            length,
+        ),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_GetCommandAtTime(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    time: c_float,
+    commandindex: *mut c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_CommandReplay_GetCommandAtTime)(replay, time, commandindex)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_SetBankPath(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    bankPath: *const c_char,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_SetBankPath)(replay, bankPath),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_Start(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_Start)(replay),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_Stop(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_Stop)(replay),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_SeekToTime(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    time: c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_SeekToTime)(replay, time),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_SeekToCommand(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    commandindex: c_int,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_SeekToCommand)(replay, commandindex),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_GetPaused(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    paused: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_GetPaused)(replay, paused),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_SetPaused(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    paused: FMOD_BOOL,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_SetPaused)(replay, paused),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_GetPlaybackState(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    state: *mut FMOD_STUDIO_PLAYBACK_STATE,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_GetPlaybackState)(replay, state),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_GetCurrentCommand(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    commandindex: *mut c_int,
+    currenttime: *mut c_float,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_CommandReplay_GetCurrentCommand)(replay, commandindex, currenttime)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_Release(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_Release)(replay),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_SetFrameCallback(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    callback: FMOD_STUDIO_COMMANDREPLAY_FRAME_CALLBACK,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_SetFrameCallback)(replay, callback),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_SetLoadBankCallback(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    callback: FMOD_STUDIO_COMMANDREPLAY_LOAD_BANK_CALLBACK,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_SetLoadBankCallback)(replay, callback),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_SetCreateInstanceCallback(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    callback: FMOD_STUDIO_COMMANDREPLAY_CREATE_INSTANCE_CALLBACK,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => {
+            (library.FMOD_Studio_CommandReplay_SetCreateInstanceCallback)(replay, callback)
+        }
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_GetUserData(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_GetUserData)(replay, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "dynamic")]
+#[inline]
+pub unsafe fn FMOD_Studio_CommandReplay_SetUserData(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    match library() {
+        Ok(library) => (library.FMOD_Studio_CommandReplay_SetUserData)(replay, userdata),
+        Err(_) => LIBRARY_NOT_LOADED,
+    }
+}
+#[cfg(feature = "stub")]
+pub use crate::stub::api::*;
@@ -0,0 +1,341 @@
+use crate::{ffi, ChannelGroup, Dsp, DspFftWindow, DspType, Error};
+
+/// Frequency scale of spectrum bands.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BandScale {
+    /// Octave bands with standard centers from 31.5 Hz to 16 kHz.
+    Octave,
+    /// Third-octave bands with standard centers from 25 Hz to 20 kHz.
+    ThirdOctave,
+    /// Given number of bands of equal width in mels from 0 Hz to Nyquist frequency.
+    Mel(usize),
+}
+
+/// Frequency range of spectrum in Hz with mean magnitude of its bins.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Band {
+    pub low: f32,
+    pub center: f32,
+    pub high: f32,
+    pub magnitude: f32,
+}
+
+/// Magnitudes of FFT bins per channel, bin `i` is at frequency `i * rate / window_size`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FftSpectrum {
+    /// Mixer sample rate the spectrum is calculated at.
+    pub rate: i32,
+    pub window_size: usize,
+    pub channels: Vec<Vec<f32>>,
+}
+
+impl FftSpectrum {
+    /// Frequency distance between neighbour bins.
+    pub fn bin_width(&self) -> f32 {
+        self.rate as f32 / self.window_size.max(1) as f32
+    }
+
+    pub fn frequency(&self, bin: usize) -> f32 {
+        bin as f32 * self.bin_width()
+    }
+
+    pub fn frequencies(&self) -> Vec<f32> {
+        (0..self.bins()).map(|bin| self.frequency(bin)).collect()
+    }
+
+    pub fn bins(&self) -> usize {
+        self.channels.first().map_or(0, |channel| channel.len())
+    }
+
+    /// Magnitudes averaged over all channels.
+    pub fn mono(&self) -> Vec<f32> {
+        let mut mono = vec![0.0; self.bins()];
+        for channel in &self.channels {
+            for (sum, magnitude) in mono.iter_mut().zip(channel) {
+                *sum += magnitude / self.channels.len() as f32;
+            }
+        }
+        mono
+    }
+
+    /// Aggregates mono spectrum into bands below Nyquist frequency, e.g. for visualiser bars.
+    ///
+    /// Band narrower than bin takes magnitude of the bin its center falls into.
+    pub fn bands(&self, scale: BandScale) -> Vec<Band> {
+        let nyquist = self.rate as f32 / 2.0;
+        let ranges: Vec<(f32, f32, f32)> = match scale {
+            BandScale::Octave => (-5..=4)
+                .map(|octave| Self::fractional_band(octave, 1.0))
+                .collect(),
+            BandScale::ThirdOctave => (-16..=13)
+                .map(|third| Self::fractional_band(third, 3.0))
+                .collect(),
+            BandScale::Mel(count) => {
+                let top = Self::mel(nyquist);
+                let edge = |index: usize| Self::hz(top * index as f32 / count as f32);
+                (0..count)
+                    .map(|index| {
+                        let (low, high) = (edge(index), edge(index + 1));
+                        (
+                            low,
+                            Self::hz((Self::mel(low) + Self::mel(high)) / 2.0),
+                            high,
+                        )
+                    })
+                    .collect()
+            }
+        };
+        let mono = self.mono();
+        let width = self.bin_width();
+        ranges
+            .into_iter()
+            .filter(|(low, _, _)| *low < nyquist)
+            .map(|(low, center, high)| {
+                let first = (low / width).ceil() as usize;
+                let last = ((high / width).ceil() as usize).min(mono.len());
+                let magnitude = if first < last {
+                    mono[first..last].iter().sum::<f32>() / (last - first) as f32
+                } else {
+                    let bin = (center / width).round() as usize;
+                    mono.get(bin).copied().unwrap_or_default()
+                };
+                Band {
+                    low,
+                    center,
+                    high: high.min(nyquist),
+                    magnitude,
+                }
+            })
+            .collect()
+    }
+
+    /// Band of 1/fraction octave with center at given step from 1 kHz.
+    fn fractional_band(step: i32, fraction: f32) -> (f32, f32, f32) {
+        let center = 1000.0 * 2f32.powf(step as f32 / fraction);
+        let half = 2f32.powf(0.5 / fraction);
+        (center / half, center, center * half)
+    }
+
+    fn mel(hz: f32) -> f32 {
+        2595.0 * (1.0 + hz / 700.0).log10()
+    }
+
+    fn hz(mel: f32) -> f32 {
+        700.0 * (10f32.powf(mel / 2595.0) - 1.0)
+    }
+
+    /// Frequency of the loudest bin, `None` for silence.
+    pub fn dominant_frequency(&self) -> Option<f32> {
+        let mono = self.mono();
+        let (bin, magnitude) = mono.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1))?;
+        (*magnitude > 0.0).then(|| self.frequency(bin))
+    }
+
+    /// Magnitude weighted mean frequency, the "brightness" of sound, `None` for silence.
+    pub fn centroid(&self) -> Option<f32> {
+        let mono = self.mono();
+        let total: f32 = mono.iter().sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let weighted: f32 = mono
+            .iter()
+            .enumerate()
+            .map(|(bin, magnitude)| self.frequency(bin) * magnitude)
+            .sum();
+        Some(weighted / total)
+    }
+}
+
+/// FFT DSP placed at the head of channel group, so it analyses the final signal of the group.
+/// The DSP is removed and released when the analyzer is dropped.
+///
+/// ```ignore
+/// let analyzer = FftAnalyzer::attach(&system.get_master_channel_group()?)?;
+/// analyzer.set_window_size(1024)?;
+/// let spectrum = analyzer.spectrum()?;
+/// for band in spectrum.bands(BandScale::Octave) {
+///     draw_bar(band.center, band.magnitude);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct FftAnalyzer {
+    group: ChannelGroup,
+    dsp: Dsp,
+}
+
+impl FftAnalyzer {
+    pub fn attach(group: &ChannelGroup) -> Result<Self, Error> {
+        let dsp = group
+            .get_system_object()?
+            .create_dsp_by_type(DspType::Fft)?;
+        if let Err(error) = group.add_dsp(ffi::FMOD_CHANNELCONTROL_DSP_HEAD, dsp) {
+            let _ = dsp.release();
+            return Err(error);
+        }
+        Ok(Self { group: *group, dsp })
+    }
+
+    pub fn dsp(&self) -> Dsp {
+        self.dsp
+    }
+
+    pub fn set_window(&self, window: DspFftWindow) -> Result<(), Error> {
+        self.dsp
+            .set_parameter_int(ffi::FMOD_DSP_FFT_WINDOWTYPE, window.into())
+    }
+
+    pub fn window(&self) -> Result<DspFftWindow, Error> {
+        let (window, _) = self
+            .dsp
+            .get_parameter_int(ffi::FMOD_DSP_FFT_WINDOWTYPE, 0)?;
+        DspFftWindow::from(window)
+    }
+
+    /// Sets number of samples analysed, power of two from 128 to 16384.
+    /// Larger window gives finer frequency resolution but reacts slower.
+    pub fn set_window_size(&self, size: u32) -> Result<(), Error> {
+        if !size.is_power_of_two() || !(128..=16384).contains(&size) {
+            return Err(err_validation!(
+                "FftAnalyzer::set_window_size",
+                "window size {} is not power of two from 128 to 16384",
+                size
+            ));
+        }
+        self.dsp
+            .set_parameter_int(ffi::FMOD_DSP_FFT_WINDOWSIZE, size as i32)
+    }
+
+    pub fn window_size(&self) -> Result<u32, Error> {
+        let (size, _) = self
+            .dsp
+            .get_parameter_int(ffi::FMOD_DSP_FFT_WINDOWSIZE, 0)?;
+        Ok(size as u32)
+    }
+
+    /// Dominant frequency in Hz as detected by FMOD.
+    pub fn dominant_frequency(&self) -> Result<f32, Error> {
+        let (frequency, _) = self
+            .dsp
+            .get_parameter_float(ffi::FMOD_DSP_FFT_DOMINANT_FREQ, 0)?;
+        Ok(frequency)
+    }
+
+    /// Spectrum of the last analysed window, bins are mapped to frequencies by mixer rate.
+    pub fn spectrum(&self) -> Result<FftSpectrum, Error> {
+        let (rate, _, _) = self.group.get_system_object()?.get_software_format()?;
+        let fft: ffi::FMOD_DSP_PARAMETER_FFT =
+            self.dsp.parameter_struct(ffi::FMOD_DSP_FFT_SPECTRUMDATA)?;
+        let bins = fft.length.max(0) as usize;
+        let channels = fft.spectrum[..fft.numchannels.clamp(0, 32) as usize]
+            .iter()
+            .map(|&ptr| {
+                if ptr.is_null() {
+                    vec![0.0; bins]
+                } else {
+                    unsafe { std::slice::from_raw_parts(ptr, bins) }.to_vec()
+                }
+            })
+            .collect();
+        Ok(FftSpectrum {
+            rate,
+            window_size: bins * 2,
+            channels,
+        })
+    }
+}
+
+impl Drop for FftAnalyzer {
+    fn drop(&mut self) {
+        let _ = self.group.remove_dsp(self.dsp);
+        let _ = self.dsp.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BandScale, FftSpectrum};
+
+    /// Stereo spectrum of 48 kHz mix with 1024 window, a tone of given level at bin 64 (3 kHz).
+    fn tone(level: f32) -> FftSpectrum {
+        let mut channel = vec![0.0; 512];
+        channel[64] = level;
+        FftSpectrum {
+            rate: 48000,
+            window_size: 1024,
+            channels: vec![channel.clone(), channel],
+        }
+    }
+
+    #[test]
+    fn test_bin_frequencies() {
+        let spectrum = tone(1.0);
+        assert_eq!(spectrum.bins(), 512);
+        assert_eq!(spectrum.bin_width(), 46.875);
+        assert_eq!(spectrum.frequencies()[64], 3000.0);
+    }
+
+    #[test]
+    fn test_dominant_frequency_and_centroid_of_tone() {
+        let spectrum = tone(1.0);
+        assert_eq!(spectrum.dominant_frequency(), Some(3000.0));
+        assert_eq!(spectrum.centroid(), Some(3000.0));
+    }
+
+    #[test]
+    fn test_silence_has_no_frequency() {
+        let spectrum = tone(0.0);
+        assert_eq!(spectrum.dominant_frequency(), None);
+        assert_eq!(spectrum.centroid(), None);
+    }
+
+    #[test]
+    fn test_mono_averages_channels() {
+        let mut spectrum = tone(1.0);
+        spectrum.channels[1][64] = 0.5;
+        assert_eq!(spectrum.mono()[64], 0.75);
+    }
+
+    #[test]
+    fn test_octave_bands() {
+        let octaves = tone(1.0).bands(BandScale::Octave);
+        assert_eq!(octaves.len(), 10);
+        assert!((octaves[0].center - 31.25).abs() < 0.01);
+        let loudest = octaves
+            .iter()
+            .max_by(|a, b| a.magnitude.total_cmp(&b.magnitude))
+            .unwrap();
+        assert_eq!(loudest.center, 4000.0);
+        assert!(loudest.low < 3000.0 && loudest.high > 3000.0);
+    }
+
+    #[test]
+    fn test_third_octave_bands_below_nyquist() {
+        let thirds = tone(1.0).bands(BandScale::ThirdOctave);
+        assert_eq!(thirds.len(), 30);
+        assert!((thirds[0].center - 24.8).abs() < 0.1);
+        assert!(thirds.iter().all(|band| band.high <= 24000.0));
+    }
+
+    #[test]
+    fn test_narrow_band_takes_magnitude_of_bin() {
+        let mut spectrum = tone(0.0);
+        spectrum.channels = vec![vec![0.0, 0.5, 0.0, 0.0]];
+        spectrum.window_size = 8;
+        // 6 kHz wide bins, octave band around 4 kHz falls into bin 1
+        let octaves = spectrum.bands(BandScale::Octave);
+        let band = octaves.iter().find(|band| band.center == 4000.0).unwrap();
+        assert_eq!(band.magnitude, 0.5);
+    }
+
+    #[test]
+    fn test_mel_bands_cover_whole_spectrum() {
+        let mels = tone(1.0).bands(BandScale::Mel(40));
+        assert_eq!(mels.len(), 40);
+        assert_eq!(mels[0].low, 0.0);
+        assert!((mels[39].high - 24000.0).abs() < 1.0);
+        for pair in mels.windows(2) {
+            assert_eq!(pair[0].high, pair[1].low);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use std::collections::HashMap;
+
+use crate::{Error, Geometry, System, Vector};
+
+/// Occlusion of polygons imported from OBJ mesh.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeshMaterial {
+    pub direct: f32,
+    pub reverb: f32,
+    pub double_sided: bool,
+}
+
+impl MeshMaterial {
+    /// Double sided material, occlusion values are in range 0 (open) to 1 (blocked).
+    pub fn new(direct: f32, reverb: f32) -> Self {
+        Self {
+            direct,
+            reverb,
+            double_sided: true,
+        }
+    }
+}
+
+impl Default for MeshMaterial {
+    fn default() -> Self {
+        Self::new(1.0, 1.0)
+    }
+}
+
+/// Polygon of imported mesh, ready for `Geometry::add_polygon`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshPolygon {
+    pub material: MeshMaterial,
+    pub vertices: Vec<Vector>,
+}
+
+/// Faces of Wavefront OBJ mesh with occlusion assigned from material names.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjMesh {
+    pub polygons: Vec<MeshPolygon>,
+}
+
+impl ObjMesh {
+    pub fn num_vertices(&self) -> usize {
+        self.polygons
+            .iter()
+            .map(|polygon| polygon.vertices.len())
+            .sum()
+    }
+
+    /// Creates geometry which fits all polygons of the mesh.
+    pub fn to_geometry(&self, system: &System) -> Result<Geometry, Error> {
+        let geometry =
+            system.create_geometry(self.polygons.len() as i32, self.num_vertices() as i32)?;
+        for polygon in &self.polygons {
+            let material = polygon.material;
+            geometry.add_polygon(
+                material.direct,
+                material.reverb,
+                material.double_sided,
+                &polygon.vertices,
+            )?;
+        }
+        Ok(geometry)
+    }
+}
+
+/// Imports audio occlusion meshes exported as Wavefront OBJ.
+///
+/// Faces get material by name of the last `usemtl` statement, it stays in effect across groups
+/// (`g` or `o`) as in OBJ. Until the first `usemtl` faces get material by name of their group.
+/// Unknown names get default material.
+/// Texture coordinates, normals and other statements are ignored.
+///
+/// ```ignore
+/// let geometry = ObjImporter::new()
+///     .material("concrete", MeshMaterial::new(1.0, 0.8))
+///     .material("glass", MeshMaterial::new(0.3, 0.2))
+///     .load(&system, &std::fs::read_to_string("level.obj")?)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ObjImporter {
+    materials: HashMap<String, MeshMaterial>,
+    default: MeshMaterial,
+}
+
+impl ObjImporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn material(mut self, name: &str, material: MeshMaterial) -> Self {
+        self.materials.insert(name.to_string(), material);
+        self
+    }
+
+    pub fn default_material(mut self, material: MeshMaterial) -> Self {
+        self.default = material;
+        self
+    }
+
+    /// Parses mesh, malformed statements are reported as `Error::Validation` with line number.
+    pub fn parse(&self, source: &str) -> Result<ObjMesh, Error> {
+        let mut positions: Vec<Vector> = vec![];
+        let mut group: Option<&str> = None;
+        let mut material: Option<&str> = None;
+        let mut mesh = ObjMesh::default();
+        for (number, line) in source.lines().enumerate() {
+            let error = || {
+                err_validation!(
+                    "ObjImporter::parse",
+                    "malformed statement at line {}",
+                    number + 1
+                )
+            };
+            let line = line.split('#').next().unwrap_or_default();
+            let mut tokens = line.split_whitespace();
+            match tokens.next() {
+                Some("v") => {
+                    let mut coordinate = || -> Result<f32, Error> {
+                        tokens
+                            .next()
+                            .and_then(|token| token.parse().ok())
+                            .ok_or_else(error)
+                    };
+                    positions.push(Vector {
+                        x: coordinate()?,
+                        y: coordinate()?,
+                        z: coordinate()?,
+                    });
+                }
+                Some("f") => {
+                    let vertices = tokens
+                        .map(|token| {
+                            vertex_index(token, positions.len()).map(|index| positions[index])
+                        })
+                        .collect::<Option<Vec<Vector>>>()
+                        .filter(|vertices| vertices.len() >= 3)
+                        .ok_or_else(error)?;
+                    let name = material.or(group).unwrap_or_default();
+                    mesh.polygons.push(MeshPolygon {
+                        material: self.materials.get(name).copied().unwrap_or(self.default),
+                        vertices,
+                    });
+                }
+                Some("g") | Some("o") => group = tokens.next(),
+                Some("usemtl") => material = Some(tokens.next().ok_or_else(error)?),
+                _ => {}
+            }
+        }
+        Ok(mesh)
+    }
+
+    /// Parses mesh and creates geometry from it.
+    pub fn load(&self, system: &System, source: &str) -> Result<Geometry, Error> {
+        self.parse(source)?.to_geometry(system)
+    }
+}
+
+/// Resolves position of face vertex like `3`, `3/1`, `3//2` or relative `-1`.
+fn vertex_index(token: &str, count: usize) -> Option<usize> {
+    let index: i64 = token.split('/').next()?.parse().ok()?;
+    let index = match index {
+        0 => return None,
+        index if index > 0 => index - 1,
+        index => count as i64 + index,
+    };
+    (0..count as i64).contains(&index).then_some(index as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{vertex_index, MeshMaterial, ObjImporter};
+    use crate::{Error, Vector};
+
+    fn importer() -> ObjImporter {
+        ObjImporter::new()
+            .material("concrete", MeshMaterial::new(1.0, 0.8))
+            .material("window", MeshMaterial::new(0.3, 0.2))
+            .default_material(MeshMaterial::new(0.5, 0.5))
+    }
+
+    #[test]
+    fn test_vertex_index_forms() {
+        assert_eq!(vertex_index("3", 4), Some(2));
+        assert_eq!(vertex_index("3/1", 4), Some(2));
+        assert_eq!(vertex_index("3//2", 4), Some(2));
+        assert_eq!(vertex_index("-1", 4), Some(3));
+        assert_eq!(vertex_index("0", 4), None);
+        assert_eq!(vertex_index("5", 4), None);
+        assert_eq!(vertex_index("-5", 4), None);
+        assert_eq!(vertex_index("x", 4), None);
+    }
+
+    #[test]
+    fn test_faces_resolved_to_positions() {
+        let mesh = importer()
+            .parse("v 0 0 0\nv 4 0 0 # corner\nv 4 3 0\nf 1 2 -1\n")
+            .unwrap();
+        assert_eq!(mesh.polygons.len(), 1);
+        assert_eq!(
+            mesh.polygons[0].vertices[2],
+            Vector {
+                x: 4.0,
+                y: 3.0,
+                z: 0.0
+            }
+        );
+        assert_eq!(mesh.num_vertices(), 3);
+    }
+
+    #[test]
+    fn test_material_by_group_name_until_usemtl() {
+        let source = "v 0 0 0\nv 1 0 0\nv 1 1 0\n\
+            o window\nf 1 2 3\n\
+            g wall\nusemtl concrete\nf 1 2 3\n\
+            o pillar\nf 1 2 3\n\
+            g door\nusemtl wood\nf 1 2 3\n";
+        let materials: Vec<MeshMaterial> = importer()
+            .parse(source)
+            .unwrap()
+            .polygons
+            .iter()
+            .map(|polygon| polygon.material)
+            .collect();
+        assert_eq!(
+            materials,
+            [
+                MeshMaterial::new(0.3, 0.2),
+                MeshMaterial::new(1.0, 0.8),
+                MeshMaterial::new(1.0, 0.8),
+                MeshMaterial::new(0.5, 0.5)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_statements_ignored() {
+        let source =
+            "mtllib level.mtl\nv 0 0 0\nvt 0 0\nvn 0 0 1\nv 1 0 0\nv 1 1 0\ns off\nf 1 2 3\n";
+        assert_eq!(importer().parse(source).unwrap().polygons.len(), 1);
+    }
+
+    #[test]
+    fn test_malformed_statements_reported_with_line() {
+        let result = importer().parse("v 0 0 0\nv 1 0 0\nf 1 2 5\n");
+        assert!(
+            matches!(result, Err(Error::Validation { message, .. }) if message.ends_with("line 3"))
+        );
+        assert!(importer().parse("v 0 x 0").is_err());
+        assert!(importer().parse("v 0 0").is_err());
+        assert!(importer().parse("v 0 0 0\nv 1 0 0\nf 1 2\n").is_err());
+        assert!(importer().parse("usemtl").is_err());
+    }
+}
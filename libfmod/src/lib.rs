@@ -11,6 +11,12 @@ pub mod ffi;
 mod flags;
 #[cfg(feature = "flags")]
 pub use flags::*;
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg(feature = "stub")]
+pub mod stub;
+#[cfg(all(feature = "dynamic", feature = "stub"))]
+compile_error!("features `dynamic` and `stub` are mutually exclusive");
 #[derive(Debug)]
 pub enum Error {
     Fmod {
@@ -25,6 +31,12 @@ pub enum Error {
     String(IntoStringError),
     StringNul(NulError),
     NotDspFft,
+    Library(String),
+    Io(std::io::Error),
+    Validation {
+        function: String,
+        message: String,
+    },
 }
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -55,6 +67,15 @@ impl Display for Error {
             Error::NotDspFft => {
                 write!(f, "trying get FFT from DSP which not FFT")
             }
+            Error::Library(message) => {
+                write!(f, "unable to load FMOD library, {}", message)
+            }
+            Error::Io(error) => {
+                write!(f, "I/O error, {}", error)
+            }
+            Error::Validation { function, message } => {
+                write!(f, "{}: {}", function, message)
+            }
         }
     }
 }
@@ -64,12 +85,29 @@ impl From<NulError> for Error {
         Error::StringNul(error)
     }
 }
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Io(error)
+    }
+}
 macro_rules! err_fmod {
     ($ function : expr , $ code : expr) => {
-        Error::Fmod {
+        match $code {
+            #[cfg(feature = "dynamic")]
+            ffi::LIBRARY_NOT_LOADED => Error::Library("not loaded".to_string()),
+            code => Error::Fmod {
+                function: $function.to_string(),
+                code,
+                message: errors::map_fmod_error(code).to_string(),
+            },
+        }
+    };
+}
+macro_rules! err_validation {
+    ($ function : expr , $ ($ message : tt) +) => {
+        Error::Validation {
             function: $function.to_string(),
-            code: $code,
-            message: errors::map_fmod_error($code).to_string(),
+            message: format!($ ($ message) +),
         }
     };
 }
@@ -142,6 +180,45 @@ macro_rules! from_bool {
         }
     };
 }
+mod mix_matrix;
+pub use mix_matrix::*;
+mod speaker_levels;
+use speaker_levels::validate_input_levels;
+pub use speaker_levels::SpeakerLevels;
+mod scheduler;
+pub use scheduler::Scheduler;
+mod dsp_graph;
+pub use dsp_graph::*;
+mod dsp_chain;
+pub use dsp_chain::*;
+mod geometry_obj;
+pub use geometry_obj::*;
+mod occlusion;
+pub use occlusion::*;
+mod reverb_zones;
+pub use reverb_zones::ReverbZones;
+mod output_plugin;
+pub use output_plugin::*;
+mod codec_plugin;
+pub use codec_plugin::*;
+mod offline_renderer;
+mod wav;
+pub use offline_renderer::OfflineRenderer;
+mod drivers;
+use drivers::forget_followed_driver;
+pub use drivers::{DriverInfo, RecordDriverInfo};
+mod record_stream;
+pub use record_stream::RecordStream;
+mod meter;
+pub use meter::{ChannelLevel, Meter, MeterSource};
+mod loudness_meter;
+pub use loudness_meter::{LoudnessInfo, LoudnessMeter};
+mod fft_analyzer;
+pub use fft_analyzer::{Band, BandScale, FftAnalyzer, FftSpectrum};
+mod dsp_data;
+pub use dsp_data::DspData;
+mod dsp_parameters;
+pub use dsp_parameters::{DspParameter, DspParameterKind, DspParameterValue};
 pub fn attr3d_array8(
     values: Vec<Attributes3d>,
 ) -> [Attributes3d; ffi::FMOD_MAX_LISTENERS as usize] {
@@ -159,6 +236,22 @@ where
 const fn from_ref<T: ?Sized>(value: &T) -> *const T {
     value
 }
+#[cfg(feature = "dynamic")]
+pub fn load(directory: impl AsRef<std::path::Path>) -> Result<(), Error> {
+    if ffi::LIBRARY.get().is_some() {
+        return Ok(());
+    }
+    let directory = directory.as_ref();
+    let suffix = if cfg!(feature = "logging") { "L" } else { "" };
+    let library = unsafe {
+        ffi::Library::load(|name| {
+            directory.join(libloading::library_filename(format!("{name}{suffix}")))
+        })
+    }
+    .map_err(|error| Error::Library(error.to_string()))?;
+    let _ = ffi::LIBRARY.set(library);
+    Ok(())
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LoadingState {
     Unloading,
@@ -5593,6 +5686,21 @@ impl TryFrom<ffi::FMOD_DSP_DESCRIPTION> for DspDescription {
         }
     }
 }
+impl std::fmt::Debug for DspDescription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name: Vec<u8> = self
+            .name
+            .iter()
+            .take_while(|&&char| char != 0)
+            .map(|&char| char as u8)
+            .collect();
+        f.debug_struct("DspDescription")
+            .field("name", &String::from_utf8_lossy(&name))
+            .field("version", &self.version)
+            .field("numparameters", &self.paramdesc.len())
+            .finish_non_exhaustive()
+    }
+}
 impl Into<ffi::FMOD_DSP_DESCRIPTION> for DspDescription {
     fn into(self) -> ffi::FMOD_DSP_DESCRIPTION {
         ffi::FMOD_DSP_DESCRIPTION {
@@ -6076,75 +6184,77 @@ impl Channel {
             }
         }
     }
-    pub fn set_mix_levels_output(
-        &self,
-        frontleft: f32,
-        frontright: f32,
-        center: f32,
-        lfe: f32,
-        surroundleft: f32,
-        surroundright: f32,
-        backleft: f32,
-        backright: f32,
-    ) -> Result<(), Error> {
+    pub fn set_mix_levels_output(&self, levels: &SpeakerLevels) -> Result<(), Error> {
+        let (_, speakermode, _) = self.get_system_object()?.get_software_format()?;
+        levels.validate(speakermode)?;
         unsafe {
             match ffi::FMOD_Channel_SetMixLevelsOutput(
                 self.pointer,
-                frontleft,
-                frontright,
-                center,
-                lfe,
-                surroundleft,
-                surroundright,
-                backleft,
-                backright,
+                levels.get(Speaker::FrontLeft),
+                levels.get(Speaker::FrontRight),
+                levels.get(Speaker::FrontCenter),
+                levels.get(Speaker::LowFrequency),
+                levels.get(Speaker::SurroundLeft),
+                levels.get(Speaker::SurroundRight),
+                levels.get(Speaker::BackLeft),
+                levels.get(Speaker::BackRight),
             ) {
                 ffi::FMOD_OK => Ok(()),
                 error => Err(err_fmod!("FMOD_Channel_SetMixLevelsOutput", error)),
             }
         }
     }
-    pub fn set_mix_levels_input(&self, levels: *mut f32, numlevels: i32) -> Result<(), Error> {
+    pub fn set_mix_levels_input(&self, levels: &[f32]) -> Result<(), Error> {
+        validate_input_levels(levels)?;
         unsafe {
-            match ffi::FMOD_Channel_SetMixLevelsInput(self.pointer, levels, numlevels) {
+            match ffi::FMOD_Channel_SetMixLevelsInput(
+                self.pointer,
+                levels.as_ptr() as *mut f32,
+                levels.len() as i32,
+            ) {
                 ffi::FMOD_OK => Ok(()),
                 error => Err(err_fmod!("FMOD_Channel_SetMixLevelsInput", error)),
             }
         }
     }
-    pub fn set_mix_matrix(
-        &self,
-        matrix: Option<*mut f32>,
-        outchannels: i32,
-        inchannels: i32,
-        inchannel_hop: Option<i32>,
-    ) -> Result<(), Error> {
+    pub fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error> {
         unsafe {
             match ffi::FMOD_Channel_SetMixMatrix(
                 self.pointer,
-                matrix.unwrap_or(null_mut()),
-                outchannels,
-                inchannels,
-                inchannel_hop.unwrap_or(0),
+                matrix.as_slice().as_ptr() as *mut f32,
+                matrix.out_channels() as i32,
+                matrix.in_channels() as i32,
+                0,
             ) {
                 ffi::FMOD_OK => Ok(()),
                 error => Err(err_fmod!("FMOD_Channel_SetMixMatrix", error)),
             }
         }
     }
-    pub fn get_mix_matrix(&self, inchannel_hop: i32) -> Result<(f32, i32, i32), Error> {
+    pub fn get_mix_matrix(&self) -> Result<MixMatrix, Error> {
         unsafe {
-            let mut matrix = f32::default();
             let mut outchannels = i32::default();
             let mut inchannels = i32::default();
             match ffi::FMOD_Channel_GetMixMatrix(
                 self.pointer,
-                &mut matrix,
+                null_mut(),
                 &mut outchannels,
                 &mut inchannels,
-                inchannel_hop,
+                0,
             ) {
-                ffi::FMOD_OK => Ok((matrix, outchannels, inchannels)),
+                ffi::FMOD_OK => {
+                    let mut matrix = MixMatrix::new(outchannels as usize, inchannels as usize);
+                    match ffi::FMOD_Channel_GetMixMatrix(
+                        self.pointer,
+                        matrix.as_mut_ptr(),
+                        &mut outchannels,
+                        &mut inchannels,
+                        0,
+                    ) {
+                        ffi::FMOD_OK => Ok(matrix),
+                        error => Err(err_fmod!("FMOD_Channel_GetMixMatrix", error)),
+                    }
+                }
                 error => Err(err_fmod!("FMOD_Channel_GetMixMatrix", error)),
             }
         }
@@ -6217,18 +6327,28 @@ impl Channel {
             }
         }
     }
-    pub fn get_fade_points(&self) -> Result<(u32, u64, f32), Error> {
+    pub fn get_fade_points(&self) -> Result<Vec<(u64, f32)>, Error> {
         unsafe {
             let mut numpoints = u32::default();
-            let mut point_dspclock = u64::default();
-            let mut point_volume = f32::default();
             match ffi::FMOD_Channel_GetFadePoints(
                 self.pointer,
                 &mut numpoints,
-                &mut point_dspclock,
-                &mut point_volume,
+                null_mut(),
+                null_mut(),
             ) {
-                ffi::FMOD_OK => Ok((numpoints, point_dspclock, point_volume)),
+                ffi::FMOD_OK => {
+                    let mut clocks = vec![0u64; numpoints as usize];
+                    let mut volumes = vec![0.0f32; numpoints as usize];
+                    match ffi::FMOD_Channel_GetFadePoints(
+                        self.pointer,
+                        &mut numpoints,
+                        clocks.as_mut_ptr(),
+                        volumes.as_mut_ptr(),
+                    ) {
+                        ffi::FMOD_OK => Ok(clocks.into_iter().zip(volumes).collect()),
+                        error => Err(err_fmod!("FMOD_Channel_GetFadePoints", error)),
+                    }
+                }
                 error => Err(err_fmod!("FMOD_Channel_GetFadePoints", error)),
             }
         }
@@ -6693,5175 +6813,10000 @@ impl Channel {
         }
     }
 }
-#[derive(Debug, Clone, Copy)]
-pub struct ChannelControl {
-    pointer: *mut ffi::FMOD_CHANNELCONTROL,
+#[doc = " Methods of [`Channel`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait ChannelApi {
+    fn get_system_object(&self) -> Result<System, Error>;
+    fn stop(&self) -> Result<(), Error>;
+    fn set_paused(&self, paused: bool) -> Result<(), Error>;
+    fn get_paused(&self) -> Result<bool, Error>;
+    fn set_volume(&self, volume: f32) -> Result<(), Error>;
+    fn get_volume(&self) -> Result<f32, Error>;
+    fn set_volume_ramp(&self, ramp: bool) -> Result<(), Error>;
+    fn get_volume_ramp(&self) -> Result<bool, Error>;
+    fn get_audibility(&self) -> Result<f32, Error>;
+    fn set_pitch(&self, pitch: f32) -> Result<(), Error>;
+    fn get_pitch(&self) -> Result<f32, Error>;
+    fn set_mute(&self, mute: bool) -> Result<(), Error>;
+    fn get_mute(&self) -> Result<bool, Error>;
+    fn set_reverb_properties(&self, instance: i32, wet: f32) -> Result<(), Error>;
+    fn get_reverb_properties(&self, instance: i32) -> Result<f32, Error>;
+    fn set_low_pass_gain(&self, gain: f32) -> Result<(), Error>;
+    fn get_low_pass_gain(&self) -> Result<f32, Error>;
+    fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error>;
+    fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error>;
+    fn set_callback(&self, callback: ffi::FMOD_CHANNELCONTROL_CALLBACK) -> Result<(), Error>;
+    fn is_playing(&self) -> Result<bool, Error>;
+    fn set_pan(&self, pan: f32) -> Result<(), Error>;
+    fn set_mix_levels_output(&self, levels: &SpeakerLevels) -> Result<(), Error>;
+    fn set_mix_levels_input(&self, levels: &[f32]) -> Result<(), Error>;
+    fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error>;
+    fn get_mix_matrix(&self) -> Result<MixMatrix, Error>;
+    fn get_dsp_clock(&self) -> Result<(u64, u64), Error>;
+    fn set_delay(
+        &self,
+        dspclock_start: Option<u64>,
+        dspclock_end: Option<u64>,
+        stopchannels: bool,
+    ) -> Result<(), Error>;
+    fn get_delay(&self) -> Result<(u64, u64, bool), Error>;
+    fn add_fade_point(&self, dspclock: u64, volume: f32) -> Result<(), Error>;
+    fn set_fade_point_ramp(&self, dspclock: u64, volume: f32) -> Result<(), Error>;
+    fn remove_fade_points(&self, dspclock_start: u64, dspclock_end: u64) -> Result<(), Error>;
+    fn get_fade_points(&self) -> Result<Vec<(u64, f32)>, Error>;
+    fn get_dsp(&self, index: i32) -> Result<Dsp, Error>;
+    fn add_dsp(&self, index: i32, dsp: Dsp) -> Result<(), Error>;
+    fn remove_dsp(&self, dsp: Dsp) -> Result<(), Error>;
+    fn get_num_ds_ps(&self) -> Result<i32, Error>;
+    fn set_dsp_index(&self, dsp: Dsp, index: i32) -> Result<(), Error>;
+    fn get_dsp_index(&self, dsp: Dsp) -> Result<i32, Error>;
+    fn set_3d_attributes(&self, pos: Option<Vector>, vel: Option<Vector>) -> Result<(), Error>;
+    fn get_3d_attributes(&self) -> Result<(Vector, Vector), Error>;
+    fn set_3d_min_max_distance(&self, mindistance: f32, maxdistance: f32) -> Result<(), Error>;
+    fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error>;
+    fn set_3d_cone_settings(
+        &self,
+        insideconeangle: f32,
+        outsideconeangle: f32,
+        outsidevolume: f32,
+    ) -> Result<(), Error>;
+    fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error>;
+    fn set_3d_cone_orientation(&self, orientation: Vector) -> Result<(), Error>;
+    fn get_3d_cone_orientation(&self) -> Result<Vector, Error>;
+    fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error>;
+    fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error>;
+    fn set_3d_occlusion(&self, directocclusion: f32, reverbocclusion: f32) -> Result<(), Error>;
+    fn get_3d_occlusion(&self) -> Result<(f32, f32), Error>;
+    fn set_3d_spread(&self, angle: f32) -> Result<(), Error>;
+    fn get_3d_spread(&self) -> Result<f32, Error>;
+    fn set_3d_level(&self, level: f32) -> Result<(), Error>;
+    fn get_3d_level(&self) -> Result<f32, Error>;
+    fn set_3d_doppler_level(&self, level: f32) -> Result<(), Error>;
+    fn get_3d_doppler_level(&self) -> Result<f32, Error>;
+    fn set_3d_distance_filter(
+        &self,
+        custom: bool,
+        custom_level: f32,
+        center_freq: Option<f32>,
+    ) -> Result<(), Error>;
+    fn get_3d_distance_filter(&self) -> Result<(bool, f32, f32), Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+    fn set_frequency(&self, frequency: f32) -> Result<(), Error>;
+    fn get_frequency(&self) -> Result<f32, Error>;
+    fn set_priority(&self, priority: i32) -> Result<(), Error>;
+    fn get_priority(&self) -> Result<i32, Error>;
+    fn set_position(
+        &self,
+        position: u32,
+        postype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(), Error>;
+    fn get_position(&self, postype: impl Into<ffi::FMOD_TIMEUNIT>) -> Result<u32, Error>;
+    fn set_channel_group(&self, channelgroup: ChannelGroup) -> Result<(), Error>;
+    fn get_channel_group(&self) -> Result<ChannelGroup, Error>;
+    fn set_loop_count(&self, loopcount: i32) -> Result<(), Error>;
+    fn get_loop_count(&self) -> Result<i32, Error>;
+    fn set_loop_points(
+        &self,
+        loopstart: u32,
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopend: u32,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(), Error>;
+    fn get_loop_points(
+        &self,
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(u32, u32), Error>;
+    fn is_virtual(&self) -> Result<bool, Error>;
+    fn get_current_sound(&self) -> Result<Sound, Error>;
+    fn get_index(&self) -> Result<i32, Error>;
 }
-unsafe impl Send for ChannelControl {}
-unsafe impl Sync for ChannelControl {}
-impl ChannelControl {
+impl ChannelApi for Channel {
     #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_CHANNELCONTROL) -> Self {
-        Self { pointer }
+    fn get_system_object(&self) -> Result<System, Error> {
+        Channel::get_system_object(self)
     }
     #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_CHANNELCONTROL {
-        self.pointer
+    fn stop(&self) -> Result<(), Error> {
+        Channel::stop(self)
     }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct ChannelGroup {
-    pointer: *mut ffi::FMOD_CHANNELGROUP,
-}
-unsafe impl Send for ChannelGroup {}
-unsafe impl Sync for ChannelGroup {}
-impl ChannelGroup {
     #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_CHANNELGROUP) -> Self {
-        Self { pointer }
+    fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        Channel::set_paused(self, paused)
     }
     #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_CHANNELGROUP {
-        self.pointer
+    fn get_paused(&self) -> Result<bool, Error> {
+        Channel::get_paused(self)
     }
-    pub fn get_system_object(&self) -> Result<System, Error> {
-        unsafe {
-            let mut system = null_mut();
-            match ffi::FMOD_ChannelGroup_GetSystemObject(self.pointer, &mut system) {
-                ffi::FMOD_OK => Ok(System::from(system)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetSystemObject", error)),
-            }
-        }
+    #[inline]
+    fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        Channel::set_volume(self, volume)
     }
-    pub fn stop(&self) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_Stop(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Stop", error)),
-            }
-        }
+    #[inline]
+    fn get_volume(&self) -> Result<f32, Error> {
+        Channel::get_volume(self)
     }
-    pub fn set_paused(&self, paused: bool) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetPaused(self.pointer, from_bool!(paused)) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetPaused", error)),
-            }
-        }
+    #[inline]
+    fn set_volume_ramp(&self, ramp: bool) -> Result<(), Error> {
+        Channel::set_volume_ramp(self, ramp)
     }
-    pub fn get_paused(&self) -> Result<bool, Error> {
-        unsafe {
-            let mut paused = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_ChannelGroup_GetPaused(self.pointer, &mut paused) {
-                ffi::FMOD_OK => Ok(to_bool!(paused)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetPaused", error)),
-            }
-        }
+    #[inline]
+    fn get_volume_ramp(&self) -> Result<bool, Error> {
+        Channel::get_volume_ramp(self)
     }
-    pub fn set_volume(&self, volume: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetVolume(self.pointer, volume) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetVolume", error)),
-            }
-        }
+    #[inline]
+    fn get_audibility(&self) -> Result<f32, Error> {
+        Channel::get_audibility(self)
     }
-    pub fn get_volume(&self) -> Result<f32, Error> {
-        unsafe {
-            let mut volume = f32::default();
-            match ffi::FMOD_ChannelGroup_GetVolume(self.pointer, &mut volume) {
-                ffi::FMOD_OK => Ok(volume),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetVolume", error)),
-            }
-        }
+    #[inline]
+    fn set_pitch(&self, pitch: f32) -> Result<(), Error> {
+        Channel::set_pitch(self, pitch)
     }
-    pub fn set_volume_ramp(&self, ramp: bool) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetVolumeRamp(self.pointer, from_bool!(ramp)) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetVolumeRamp", error)),
-            }
-        }
+    #[inline]
+    fn get_pitch(&self) -> Result<f32, Error> {
+        Channel::get_pitch(self)
     }
-    pub fn get_volume_ramp(&self) -> Result<bool, Error> {
-        unsafe {
-            let mut ramp = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_ChannelGroup_GetVolumeRamp(self.pointer, &mut ramp) {
-                ffi::FMOD_OK => Ok(to_bool!(ramp)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetVolumeRamp", error)),
-            }
-        }
+    #[inline]
+    fn set_mute(&self, mute: bool) -> Result<(), Error> {
+        Channel::set_mute(self, mute)
     }
-    pub fn get_audibility(&self) -> Result<f32, Error> {
-        unsafe {
-            let mut audibility = f32::default();
-            match ffi::FMOD_ChannelGroup_GetAudibility(self.pointer, &mut audibility) {
-                ffi::FMOD_OK => Ok(audibility),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetAudibility", error)),
-            }
-        }
+    #[inline]
+    fn get_mute(&self) -> Result<bool, Error> {
+        Channel::get_mute(self)
     }
-    pub fn set_pitch(&self, pitch: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetPitch(self.pointer, pitch) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetPitch", error)),
-            }
-        }
+    #[inline]
+    fn set_reverb_properties(&self, instance: i32, wet: f32) -> Result<(), Error> {
+        Channel::set_reverb_properties(self, instance, wet)
     }
-    pub fn get_pitch(&self) -> Result<f32, Error> {
-        unsafe {
-            let mut pitch = f32::default();
-            match ffi::FMOD_ChannelGroup_GetPitch(self.pointer, &mut pitch) {
-                ffi::FMOD_OK => Ok(pitch),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetPitch", error)),
-            }
-        }
+    #[inline]
+    fn get_reverb_properties(&self, instance: i32) -> Result<f32, Error> {
+        Channel::get_reverb_properties(self, instance)
     }
-    pub fn set_mute(&self, mute: bool) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetMute(self.pointer, from_bool!(mute)) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetMute", error)),
-            }
-        }
+    #[inline]
+    fn set_low_pass_gain(&self, gain: f32) -> Result<(), Error> {
+        Channel::set_low_pass_gain(self, gain)
     }
-    pub fn get_mute(&self) -> Result<bool, Error> {
-        unsafe {
-            let mut mute = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_ChannelGroup_GetMute(self.pointer, &mut mute) {
-                ffi::FMOD_OK => Ok(to_bool!(mute)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetMute", error)),
-            }
-        }
+    #[inline]
+    fn get_low_pass_gain(&self) -> Result<f32, Error> {
+        Channel::get_low_pass_gain(self)
     }
-    pub fn set_reverb_properties(&self, instance: i32, wet: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetReverbProperties(self.pointer, instance, wet) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetReverbProperties", error)),
-            }
-        }
+    #[inline]
+    fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error> {
+        Channel::set_mode(self, mode)
     }
-    pub fn get_reverb_properties(&self, instance: i32) -> Result<f32, Error> {
-        unsafe {
-            let mut wet = f32::default();
-            match ffi::FMOD_ChannelGroup_GetReverbProperties(self.pointer, instance, &mut wet) {
-                ffi::FMOD_OK => Ok(wet),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetReverbProperties", error)),
-            }
-        }
+    #[inline]
+    fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error> {
+        Channel::get_mode(self)
     }
-    pub fn set_low_pass_gain(&self, gain: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetLowPassGain(self.pointer, gain) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetLowPassGain", error)),
-            }
-        }
+    #[inline]
+    fn set_callback(&self, callback: ffi::FMOD_CHANNELCONTROL_CALLBACK) -> Result<(), Error> {
+        Channel::set_callback(self, callback)
     }
-    pub fn get_low_pass_gain(&self) -> Result<f32, Error> {
-        unsafe {
-            let mut gain = f32::default();
-            match ffi::FMOD_ChannelGroup_GetLowPassGain(self.pointer, &mut gain) {
-                ffi::FMOD_OK => Ok(gain),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetLowPassGain", error)),
-            }
-        }
+    #[inline]
+    fn is_playing(&self) -> Result<bool, Error> {
+        Channel::is_playing(self)
     }
-    pub fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetMode(self.pointer, mode.into()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetMode", error)),
-            }
-        }
+    #[inline]
+    fn set_pan(&self, pan: f32) -> Result<(), Error> {
+        Channel::set_pan(self, pan)
     }
-    pub fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error> {
-        unsafe {
-            let mut mode = ffi::FMOD_MODE::default();
-            match ffi::FMOD_ChannelGroup_GetMode(self.pointer, &mut mode) {
-                ffi::FMOD_OK => Ok(mode),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetMode", error)),
-            }
-        }
+    #[inline]
+    fn set_mix_levels_output(&self, levels: &SpeakerLevels) -> Result<(), Error> {
+        Channel::set_mix_levels_output(self, levels)
     }
-    pub fn set_callback(&self, callback: ffi::FMOD_CHANNELCONTROL_CALLBACK) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetCallback(self.pointer, callback) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetCallback", error)),
-            }
-        }
+    #[inline]
+    fn set_mix_levels_input(&self, levels: &[f32]) -> Result<(), Error> {
+        Channel::set_mix_levels_input(self, levels)
     }
-    pub fn is_playing(&self) -> Result<bool, Error> {
-        unsafe {
-            let mut isplaying = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_ChannelGroup_IsPlaying(self.pointer, &mut isplaying) {
-                ffi::FMOD_OK => Ok(to_bool!(isplaying)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_IsPlaying", error)),
-            }
-        }
+    #[inline]
+    fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error> {
+        Channel::set_mix_matrix(self, matrix)
     }
-    pub fn set_pan(&self, pan: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetPan(self.pointer, pan) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetPan", error)),
-            }
-        }
+    #[inline]
+    fn get_mix_matrix(&self) -> Result<MixMatrix, Error> {
+        Channel::get_mix_matrix(self)
+    }
+    #[inline]
+    fn get_dsp_clock(&self) -> Result<(u64, u64), Error> {
+        Channel::get_dsp_clock(self)
     }
-    pub fn set_mix_levels_output(
+    #[inline]
+    fn set_delay(
         &self,
-        frontleft: f32,
-        frontright: f32,
-        center: f32,
-        lfe: f32,
-        surroundleft: f32,
-        surroundright: f32,
-        backleft: f32,
-        backright: f32,
+        dspclock_start: Option<u64>,
+        dspclock_end: Option<u64>,
+        stopchannels: bool,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetMixLevelsOutput(
-                self.pointer,
-                frontleft,
-                frontright,
-                center,
-                lfe,
-                surroundleft,
-                surroundright,
-                backleft,
-                backright,
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetMixLevelsOutput", error)),
-            }
-        }
+        Channel::set_delay(self, dspclock_start, dspclock_end, stopchannels)
     }
-    pub fn set_mix_levels_input(&self, levels: *mut f32, numlevels: i32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetMixLevelsInput(self.pointer, levels, numlevels) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetMixLevelsInput", error)),
-            }
-        }
+    #[inline]
+    fn get_delay(&self) -> Result<(u64, u64, bool), Error> {
+        Channel::get_delay(self)
+    }
+    #[inline]
+    fn add_fade_point(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
+        Channel::add_fade_point(self, dspclock, volume)
+    }
+    #[inline]
+    fn set_fade_point_ramp(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
+        Channel::set_fade_point_ramp(self, dspclock, volume)
+    }
+    #[inline]
+    fn remove_fade_points(&self, dspclock_start: u64, dspclock_end: u64) -> Result<(), Error> {
+        Channel::remove_fade_points(self, dspclock_start, dspclock_end)
+    }
+    #[inline]
+    fn get_fade_points(&self) -> Result<Vec<(u64, f32)>, Error> {
+        Channel::get_fade_points(self)
+    }
+    #[inline]
+    fn get_dsp(&self, index: i32) -> Result<Dsp, Error> {
+        Channel::get_dsp(self, index)
+    }
+    #[inline]
+    fn add_dsp(&self, index: i32, dsp: Dsp) -> Result<(), Error> {
+        Channel::add_dsp(self, index, dsp)
+    }
+    #[inline]
+    fn remove_dsp(&self, dsp: Dsp) -> Result<(), Error> {
+        Channel::remove_dsp(self, dsp)
+    }
+    #[inline]
+    fn get_num_ds_ps(&self) -> Result<i32, Error> {
+        Channel::get_num_ds_ps(self)
+    }
+    #[inline]
+    fn set_dsp_index(&self, dsp: Dsp, index: i32) -> Result<(), Error> {
+        Channel::set_dsp_index(self, dsp, index)
+    }
+    #[inline]
+    fn get_dsp_index(&self, dsp: Dsp) -> Result<i32, Error> {
+        Channel::get_dsp_index(self, dsp)
+    }
+    #[inline]
+    fn set_3d_attributes(&self, pos: Option<Vector>, vel: Option<Vector>) -> Result<(), Error> {
+        Channel::set_3d_attributes(self, pos, vel)
+    }
+    #[inline]
+    fn get_3d_attributes(&self) -> Result<(Vector, Vector), Error> {
+        Channel::get_3d_attributes(self)
+    }
+    #[inline]
+    fn set_3d_min_max_distance(&self, mindistance: f32, maxdistance: f32) -> Result<(), Error> {
+        Channel::set_3d_min_max_distance(self, mindistance, maxdistance)
+    }
+    #[inline]
+    fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        Channel::get_3d_min_max_distance(self)
     }
-    pub fn set_mix_matrix(
+    #[inline]
+    fn set_3d_cone_settings(
         &self,
-        matrix: Option<*mut f32>,
-        outchannels: i32,
-        inchannels: i32,
-        inchannel_hop: Option<i32>,
+        insideconeangle: f32,
+        outsideconeangle: f32,
+        outsidevolume: f32,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetMixMatrix(
-                self.pointer,
-                matrix.unwrap_or(null_mut()),
-                outchannels,
-                inchannels,
-                inchannel_hop.unwrap_or(0),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetMixMatrix", error)),
-            }
-        }
+        Channel::set_3d_cone_settings(self, insideconeangle, outsideconeangle, outsidevolume)
     }
-    pub fn get_mix_matrix(&self, inchannel_hop: i32) -> Result<(f32, i32, i32), Error> {
-        unsafe {
-            let mut matrix = f32::default();
-            let mut outchannels = i32::default();
-            let mut inchannels = i32::default();
-            match ffi::FMOD_ChannelGroup_GetMixMatrix(
-                self.pointer,
-                &mut matrix,
-                &mut outchannels,
-                &mut inchannels,
-                inchannel_hop,
-            ) {
-                ffi::FMOD_OK => Ok((matrix, outchannels, inchannels)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetMixMatrix", error)),
-            }
-        }
+    #[inline]
+    fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error> {
+        Channel::get_3d_cone_settings(self)
     }
-    pub fn get_dsp_clock(&self) -> Result<(u64, u64), Error> {
-        unsafe {
-            let mut dspclock = u64::default();
-            let mut parentclock = u64::default();
-            match ffi::FMOD_ChannelGroup_GetDSPClock(self.pointer, &mut dspclock, &mut parentclock)
-            {
-                ffi::FMOD_OK => Ok((dspclock, parentclock)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetDSPClock", error)),
-            }
-        }
+    #[inline]
+    fn set_3d_cone_orientation(&self, orientation: Vector) -> Result<(), Error> {
+        Channel::set_3d_cone_orientation(self, orientation)
     }
-    pub fn set_delay(
+    #[inline]
+    fn get_3d_cone_orientation(&self) -> Result<Vector, Error> {
+        Channel::get_3d_cone_orientation(self)
+    }
+    #[inline]
+    fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error> {
+        Channel::set_3d_custom_rolloff(self, points)
+    }
+    #[inline]
+    fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error> {
+        Channel::get_3d_custom_rolloff(self)
+    }
+    #[inline]
+    fn set_3d_occlusion(&self, directocclusion: f32, reverbocclusion: f32) -> Result<(), Error> {
+        Channel::set_3d_occlusion(self, directocclusion, reverbocclusion)
+    }
+    #[inline]
+    fn get_3d_occlusion(&self) -> Result<(f32, f32), Error> {
+        Channel::get_3d_occlusion(self)
+    }
+    #[inline]
+    fn set_3d_spread(&self, angle: f32) -> Result<(), Error> {
+        Channel::set_3d_spread(self, angle)
+    }
+    #[inline]
+    fn get_3d_spread(&self) -> Result<f32, Error> {
+        Channel::get_3d_spread(self)
+    }
+    #[inline]
+    fn set_3d_level(&self, level: f32) -> Result<(), Error> {
+        Channel::set_3d_level(self, level)
+    }
+    #[inline]
+    fn get_3d_level(&self) -> Result<f32, Error> {
+        Channel::get_3d_level(self)
+    }
+    #[inline]
+    fn set_3d_doppler_level(&self, level: f32) -> Result<(), Error> {
+        Channel::set_3d_doppler_level(self, level)
+    }
+    #[inline]
+    fn get_3d_doppler_level(&self) -> Result<f32, Error> {
+        Channel::get_3d_doppler_level(self)
+    }
+    #[inline]
+    fn set_3d_distance_filter(
         &self,
-        dspclock_start: Option<u64>,
-        dspclock_end: Option<u64>,
-        stopchannels: bool,
+        custom: bool,
+        custom_level: f32,
+        center_freq: Option<f32>,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetDelay(
-                self.pointer,
-                dspclock_start.unwrap_or(0),
-                dspclock_end.unwrap_or(0),
-                from_bool!(stopchannels),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetDelay", error)),
-            }
+        Channel::set_3d_distance_filter(self, custom, custom_level, center_freq)
+    }
+    #[inline]
+    fn get_3d_distance_filter(&self) -> Result<(bool, f32, f32), Error> {
+        Channel::get_3d_distance_filter(self)
+    }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        Channel::set_user_data(self, userdata)
+    }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        Channel::get_user_data(self)
+    }
+    #[inline]
+    fn set_frequency(&self, frequency: f32) -> Result<(), Error> {
+        Channel::set_frequency(self, frequency)
+    }
+    #[inline]
+    fn get_frequency(&self) -> Result<f32, Error> {
+        Channel::get_frequency(self)
+    }
+    #[inline]
+    fn set_priority(&self, priority: i32) -> Result<(), Error> {
+        Channel::set_priority(self, priority)
+    }
+    #[inline]
+    fn get_priority(&self) -> Result<i32, Error> {
+        Channel::get_priority(self)
+    }
+    #[inline]
+    fn set_position(
+        &self,
+        position: u32,
+        postype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(), Error> {
+        Channel::set_position(self, position, postype)
+    }
+    #[inline]
+    fn get_position(&self, postype: impl Into<ffi::FMOD_TIMEUNIT>) -> Result<u32, Error> {
+        Channel::get_position(self, postype)
+    }
+    #[inline]
+    fn set_channel_group(&self, channelgroup: ChannelGroup) -> Result<(), Error> {
+        Channel::set_channel_group(self, channelgroup)
+    }
+    #[inline]
+    fn get_channel_group(&self) -> Result<ChannelGroup, Error> {
+        Channel::get_channel_group(self)
+    }
+    #[inline]
+    fn set_loop_count(&self, loopcount: i32) -> Result<(), Error> {
+        Channel::set_loop_count(self, loopcount)
+    }
+    #[inline]
+    fn get_loop_count(&self) -> Result<i32, Error> {
+        Channel::get_loop_count(self)
+    }
+    #[inline]
+    fn set_loop_points(
+        &self,
+        loopstart: u32,
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopend: u32,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(), Error> {
+        Channel::set_loop_points(self, loopstart, loopstarttype, loopend, loopendtype)
+    }
+    #[inline]
+    fn get_loop_points(
+        &self,
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(u32, u32), Error> {
+        Channel::get_loop_points(self, loopstarttype, loopendtype)
+    }
+    #[inline]
+    fn is_virtual(&self) -> Result<bool, Error> {
+        Channel::is_virtual(self)
+    }
+    #[inline]
+    fn get_current_sound(&self) -> Result<Sound, Error> {
+        Channel::get_current_sound(self)
+    }
+    #[inline]
+    fn get_index(&self) -> Result<i32, Error> {
+        Channel::get_index(self)
+    }
+}
+#[doc = " Methods shared by [`Channel`] and [`ChannelGroup`], so mixing code can be written once for both."]
+#[doc = ""]
+#[doc = " Implementors only provide `FMOD_CHANNELCONTROL` pointer and its type, every method"]
+#[doc = " downcasts the pointer and calls the same method of channel or channel group."]
+#[allow(clippy::too_many_arguments)]
+pub trait ChannelControl {
+    #[doc = " Pointer to underlying object as `FMOD_CHANNELCONTROL`, the same one FMOD passes to callbacks."]
+    fn as_channel_control(&self) -> *mut ffi::FMOD_CHANNELCONTROL;
+    fn control_type(&self) -> ChannelControlType;
+    #[doc = " Channel or channel group behind `FMOD_CHANNELCONTROL` pointer."]
+    #[inline]
+    fn downcast(&self) -> Result<ChannelControlHandle, Error> {
+        ChannelControlHandle::from_raw(self.as_channel_control(), self.control_type())
+    }
+    fn get_system_object(&self) -> Result<System, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_system_object(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_system_object(),
         }
     }
-    pub fn get_delay(&self) -> Result<(u64, u64, bool), Error> {
-        unsafe {
-            let mut dspclock_start = u64::default();
-            let mut dspclock_end = u64::default();
-            let mut stopchannels = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_ChannelGroup_GetDelay(
-                self.pointer,
-                &mut dspclock_start,
-                &mut dspclock_end,
-                &mut stopchannels,
-            ) {
-                ffi::FMOD_OK => Ok((dspclock_start, dspclock_end, to_bool!(stopchannels))),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetDelay", error)),
-            }
+    fn stop(&self) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.stop(),
+            ChannelControlHandle::ChannelGroup(group) => group.stop(),
         }
     }
-    pub fn add_fade_point(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_AddFadePoint(self.pointer, dspclock, volume) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_AddFadePoint", error)),
-            }
+    fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_paused(paused),
+            ChannelControlHandle::ChannelGroup(group) => group.set_paused(paused),
         }
     }
-    pub fn set_fade_point_ramp(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetFadePointRamp(self.pointer, dspclock, volume) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetFadePointRamp", error)),
-            }
+    fn get_paused(&self) -> Result<bool, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_paused(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_paused(),
         }
     }
-    pub fn remove_fade_points(&self, dspclock_start: u64, dspclock_end: u64) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_RemoveFadePoints(
-                self.pointer,
-                dspclock_start,
-                dspclock_end,
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_RemoveFadePoints", error)),
-            }
+    fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_volume(volume),
+            ChannelControlHandle::ChannelGroup(group) => group.set_volume(volume),
         }
     }
-    pub fn get_fade_points(&self) -> Result<(u32, u64, f32), Error> {
-        unsafe {
-            let mut numpoints = u32::default();
-            let mut point_dspclock = u64::default();
-            let mut point_volume = f32::default();
-            match ffi::FMOD_ChannelGroup_GetFadePoints(
-                self.pointer,
-                &mut numpoints,
-                &mut point_dspclock,
-                &mut point_volume,
-            ) {
-                ffi::FMOD_OK => Ok((numpoints, point_dspclock, point_volume)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetFadePoints", error)),
-            }
+    fn get_volume(&self) -> Result<f32, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_volume(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_volume(),
         }
     }
-    pub fn get_dsp(&self, index: i32) -> Result<Dsp, Error> {
-        unsafe {
-            let mut dsp = null_mut();
-            match ffi::FMOD_ChannelGroup_GetDSP(self.pointer, index, &mut dsp) {
-                ffi::FMOD_OK => Ok(Dsp::from(dsp)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetDSP", error)),
-            }
+    fn set_volume_ramp(&self, ramp: bool) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_volume_ramp(ramp),
+            ChannelControlHandle::ChannelGroup(group) => group.set_volume_ramp(ramp),
         }
     }
-    pub fn add_dsp(&self, index: i32, dsp: Dsp) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_AddDSP(self.pointer, index, dsp.as_mut_ptr()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_AddDSP", error)),
-            }
+    fn get_volume_ramp(&self) -> Result<bool, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_volume_ramp(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_volume_ramp(),
         }
     }
-    pub fn remove_dsp(&self, dsp: Dsp) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_RemoveDSP(self.pointer, dsp.as_mut_ptr()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_RemoveDSP", error)),
-            }
+    fn get_audibility(&self) -> Result<f32, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_audibility(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_audibility(),
         }
     }
-    pub fn get_num_ds_ps(&self) -> Result<i32, Error> {
-        unsafe {
-            let mut numdsps = i32::default();
-            match ffi::FMOD_ChannelGroup_GetNumDSPs(self.pointer, &mut numdsps) {
-                ffi::FMOD_OK => Ok(numdsps),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetNumDSPs", error)),
-            }
+    fn set_pitch(&self, pitch: f32) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_pitch(pitch),
+            ChannelControlHandle::ChannelGroup(group) => group.set_pitch(pitch),
         }
     }
-    pub fn set_dsp_index(&self, dsp: Dsp, index: i32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetDSPIndex(self.pointer, dsp.as_mut_ptr(), index) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetDSPIndex", error)),
-            }
+    fn get_pitch(&self) -> Result<f32, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_pitch(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_pitch(),
         }
     }
-    pub fn get_dsp_index(&self, dsp: Dsp) -> Result<i32, Error> {
-        unsafe {
-            let mut index = i32::default();
-            match ffi::FMOD_ChannelGroup_GetDSPIndex(self.pointer, dsp.as_mut_ptr(), &mut index) {
-                ffi::FMOD_OK => Ok(index),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetDSPIndex", error)),
-            }
+    fn set_mute(&self, mute: bool) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_mute(mute),
+            ChannelControlHandle::ChannelGroup(group) => group.set_mute(mute),
         }
     }
-    pub fn set_3d_attributes(&self, pos: Option<Vector>, vel: Option<Vector>) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_Set3DAttributes(
-                self.pointer,
-                pos.map(Vector::into)
-                    .as_ref()
-                    .map(from_ref)
-                    .unwrap_or_else(null),
-                vel.map(Vector::into)
-                    .as_ref()
-                    .map(from_ref)
-                    .unwrap_or_else(null),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DAttributes", error)),
+    fn get_mute(&self) -> Result<bool, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_mute(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_mute(),
+        }
+    }
+    fn set_reverb_properties(&self, instance: i32, wet: f32) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_reverb_properties(instance, wet),
+            ChannelControlHandle::ChannelGroup(group) => group.set_reverb_properties(instance, wet),
+        }
+    }
+    fn get_reverb_properties(&self, instance: i32) -> Result<f32, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_reverb_properties(instance),
+            ChannelControlHandle::ChannelGroup(group) => group.get_reverb_properties(instance),
+        }
+    }
+    fn set_low_pass_gain(&self, gain: f32) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_low_pass_gain(gain),
+            ChannelControlHandle::ChannelGroup(group) => group.set_low_pass_gain(gain),
+        }
+    }
+    fn get_low_pass_gain(&self) -> Result<f32, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_low_pass_gain(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_low_pass_gain(),
+        }
+    }
+    fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_mode(mode),
+            ChannelControlHandle::ChannelGroup(group) => group.set_mode(mode),
+        }
+    }
+    fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_mode(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_mode(),
+        }
+    }
+    fn set_callback(&self, callback: ffi::FMOD_CHANNELCONTROL_CALLBACK) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_callback(callback),
+            ChannelControlHandle::ChannelGroup(group) => group.set_callback(callback),
+        }
+    }
+    fn is_playing(&self) -> Result<bool, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.is_playing(),
+            ChannelControlHandle::ChannelGroup(group) => group.is_playing(),
+        }
+    }
+    fn set_pan(&self, pan: f32) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_pan(pan),
+            ChannelControlHandle::ChannelGroup(group) => group.set_pan(pan),
+        }
+    }
+    fn set_mix_levels_output(&self, levels: &SpeakerLevels) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_mix_levels_output(levels),
+            ChannelControlHandle::ChannelGroup(group) => group.set_mix_levels_output(levels),
+        }
+    }
+    fn set_mix_levels_input(&self, levels: &[f32]) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_mix_levels_input(levels),
+            ChannelControlHandle::ChannelGroup(group) => group.set_mix_levels_input(levels),
+        }
+    }
+    fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_mix_matrix(matrix),
+            ChannelControlHandle::ChannelGroup(group) => group.set_mix_matrix(matrix),
+        }
+    }
+    fn get_mix_matrix(&self) -> Result<MixMatrix, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_mix_matrix(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_mix_matrix(),
+        }
+    }
+    fn get_dsp_clock(&self) -> Result<(u64, u64), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_dsp_clock(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_dsp_clock(),
+        }
+    }
+    fn set_delay(
+        &self,
+        dspclock_start: Option<u64>,
+        dspclock_end: Option<u64>,
+        stopchannels: bool,
+    ) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => {
+                channel.set_delay(dspclock_start, dspclock_end, stopchannels)
+            }
+            ChannelControlHandle::ChannelGroup(group) => {
+                group.set_delay(dspclock_start, dspclock_end, stopchannels)
             }
         }
     }
-    pub fn get_3d_attributes(&self) -> Result<(Vector, Vector), Error> {
-        unsafe {
-            let mut pos = ffi::FMOD_VECTOR::default();
-            let mut vel = ffi::FMOD_VECTOR::default();
-            match ffi::FMOD_ChannelGroup_Get3DAttributes(self.pointer, &mut pos, &mut vel) {
-                ffi::FMOD_OK => Ok((Vector::try_from(pos)?, Vector::try_from(vel)?)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DAttributes", error)),
+    fn get_delay(&self) -> Result<(u64, u64, bool), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_delay(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_delay(),
+        }
+    }
+    fn add_fade_point(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.add_fade_point(dspclock, volume),
+            ChannelControlHandle::ChannelGroup(group) => group.add_fade_point(dspclock, volume),
+        }
+    }
+    fn set_fade_point_ramp(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_fade_point_ramp(dspclock, volume),
+            ChannelControlHandle::ChannelGroup(group) => {
+                group.set_fade_point_ramp(dspclock, volume)
             }
         }
     }
-    pub fn set_3d_min_max_distance(&self, mindistance: f32, maxdistance: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_Set3DMinMaxDistance(self.pointer, mindistance, maxdistance)
-            {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DMinMaxDistance", error)),
+    fn remove_fade_points(&self, dspclock_start: u64, dspclock_end: u64) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => {
+                channel.remove_fade_points(dspclock_start, dspclock_end)
+            }
+            ChannelControlHandle::ChannelGroup(group) => {
+                group.remove_fade_points(dspclock_start, dspclock_end)
             }
         }
     }
-    pub fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error> {
-        unsafe {
-            let mut mindistance = f32::default();
-            let mut maxdistance = f32::default();
-            match ffi::FMOD_ChannelGroup_Get3DMinMaxDistance(
-                self.pointer,
-                &mut mindistance,
-                &mut maxdistance,
-            ) {
-                ffi::FMOD_OK => Ok((mindistance, maxdistance)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DMinMaxDistance", error)),
+    fn get_fade_points(&self) -> Result<Vec<(u64, f32)>, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_fade_points(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_fade_points(),
+        }
+    }
+    fn get_dsp(&self, index: i32) -> Result<Dsp, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_dsp(index),
+            ChannelControlHandle::ChannelGroup(group) => group.get_dsp(index),
+        }
+    }
+    fn add_dsp(&self, index: i32, dsp: Dsp) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.add_dsp(index, dsp),
+            ChannelControlHandle::ChannelGroup(group) => group.add_dsp(index, dsp),
+        }
+    }
+    fn remove_dsp(&self, dsp: Dsp) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.remove_dsp(dsp),
+            ChannelControlHandle::ChannelGroup(group) => group.remove_dsp(dsp),
+        }
+    }
+    fn get_num_ds_ps(&self) -> Result<i32, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_num_ds_ps(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_num_ds_ps(),
+        }
+    }
+    fn set_dsp_index(&self, dsp: Dsp, index: i32) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_dsp_index(dsp, index),
+            ChannelControlHandle::ChannelGroup(group) => group.set_dsp_index(dsp, index),
+        }
+    }
+    fn get_dsp_index(&self, dsp: Dsp) -> Result<i32, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_dsp_index(dsp),
+            ChannelControlHandle::ChannelGroup(group) => group.get_dsp_index(dsp),
+        }
+    }
+    fn set_3d_attributes(&self, pos: Option<Vector>, vel: Option<Vector>) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_3d_attributes(pos, vel),
+            ChannelControlHandle::ChannelGroup(group) => group.set_3d_attributes(pos, vel),
+        }
+    }
+    fn get_3d_attributes(&self) -> Result<(Vector, Vector), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_3d_attributes(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_3d_attributes(),
+        }
+    }
+    fn set_3d_min_max_distance(&self, mindistance: f32, maxdistance: f32) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => {
+                channel.set_3d_min_max_distance(mindistance, maxdistance)
+            }
+            ChannelControlHandle::ChannelGroup(group) => {
+                group.set_3d_min_max_distance(mindistance, maxdistance)
             }
         }
     }
-    pub fn set_3d_cone_settings(
+    fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_3d_min_max_distance(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_3d_min_max_distance(),
+        }
+    }
+    fn set_3d_cone_settings(
         &self,
         insideconeangle: f32,
         outsideconeangle: f32,
         outsidevolume: f32,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_Set3DConeSettings(
-                self.pointer,
-                insideconeangle,
-                outsideconeangle,
-                outsidevolume,
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DConeSettings", error)),
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => {
+                channel.set_3d_cone_settings(insideconeangle, outsideconeangle, outsidevolume)
+            }
+            ChannelControlHandle::ChannelGroup(group) => {
+                group.set_3d_cone_settings(insideconeangle, outsideconeangle, outsidevolume)
             }
         }
     }
-    pub fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error> {
-        unsafe {
-            let mut insideconeangle = f32::default();
-            let mut outsideconeangle = f32::default();
-            let mut outsidevolume = f32::default();
-            match ffi::FMOD_ChannelGroup_Get3DConeSettings(
-                self.pointer,
-                &mut insideconeangle,
-                &mut outsideconeangle,
-                &mut outsidevolume,
-            ) {
-                ffi::FMOD_OK => Ok((insideconeangle, outsideconeangle, outsidevolume)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DConeSettings", error)),
-            }
+    fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_3d_cone_settings(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_3d_cone_settings(),
         }
     }
-    pub fn set_3d_cone_orientation(&self, orientation: Vector) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_Set3DConeOrientation(self.pointer, &mut orientation.into())
-            {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DConeOrientation", error)),
-            }
+    fn set_3d_cone_orientation(&self, orientation: Vector) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_3d_cone_orientation(orientation),
+            ChannelControlHandle::ChannelGroup(group) => group.set_3d_cone_orientation(orientation),
         }
     }
-    pub fn get_3d_cone_orientation(&self) -> Result<Vector, Error> {
-        unsafe {
-            let mut orientation = ffi::FMOD_VECTOR::default();
-            match ffi::FMOD_ChannelGroup_Get3DConeOrientation(self.pointer, &mut orientation) {
-                ffi::FMOD_OK => Ok(Vector::try_from(orientation)?),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DConeOrientation", error)),
-            }
+    fn get_3d_cone_orientation(&self) -> Result<Vector, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_3d_cone_orientation(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_3d_cone_orientation(),
         }
     }
-    pub fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error> {
-        unsafe {
-            let numpoints = points.len() as i32;
-            match ffi::FMOD_ChannelGroup_Set3DCustomRolloff(
-                self.pointer,
-                vec_as_mut_ptr(points, |point| point.into()),
-                numpoints,
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DCustomRolloff", error)),
-            }
+    fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_3d_custom_rolloff(points),
+            ChannelControlHandle::ChannelGroup(group) => group.set_3d_custom_rolloff(points),
         }
     }
-    pub fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error> {
-        unsafe {
-            let mut points = null_mut();
-            let mut numpoints = i32::default();
-            match ffi::FMOD_ChannelGroup_Get3DCustomRolloff(
-                self.pointer,
-                &mut points,
-                &mut numpoints,
-            ) {
-                ffi::FMOD_OK => Ok(to_vec!(points, numpoints, Vector::try_from)?),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DCustomRolloff", error)),
-            }
+    fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_3d_custom_rolloff(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_3d_custom_rolloff(),
         }
     }
-    pub fn set_3d_occlusion(
-        &self,
-        directocclusion: f32,
-        reverbocclusion: f32,
-    ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_Set3DOcclusion(
-                self.pointer,
-                directocclusion,
-                reverbocclusion,
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DOcclusion", error)),
+    fn set_3d_occlusion(&self, directocclusion: f32, reverbocclusion: f32) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => {
+                channel.set_3d_occlusion(directocclusion, reverbocclusion)
+            }
+            ChannelControlHandle::ChannelGroup(group) => {
+                group.set_3d_occlusion(directocclusion, reverbocclusion)
             }
         }
     }
-    pub fn get_3d_occlusion(&self) -> Result<(f32, f32), Error> {
-        unsafe {
-            let mut directocclusion = f32::default();
-            let mut reverbocclusion = f32::default();
-            match ffi::FMOD_ChannelGroup_Get3DOcclusion(
-                self.pointer,
-                &mut directocclusion,
-                &mut reverbocclusion,
-            ) {
-                ffi::FMOD_OK => Ok((directocclusion, reverbocclusion)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DOcclusion", error)),
-            }
+    fn get_3d_occlusion(&self) -> Result<(f32, f32), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_3d_occlusion(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_3d_occlusion(),
         }
     }
-    pub fn set_3d_spread(&self, angle: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_Set3DSpread(self.pointer, angle) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DSpread", error)),
-            }
+    fn set_3d_spread(&self, angle: f32) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_3d_spread(angle),
+            ChannelControlHandle::ChannelGroup(group) => group.set_3d_spread(angle),
         }
     }
-    pub fn get_3d_spread(&self) -> Result<f32, Error> {
-        unsafe {
-            let mut angle = f32::default();
-            match ffi::FMOD_ChannelGroup_Get3DSpread(self.pointer, &mut angle) {
-                ffi::FMOD_OK => Ok(angle),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DSpread", error)),
-            }
+    fn get_3d_spread(&self) -> Result<f32, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_3d_spread(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_3d_spread(),
         }
     }
-    pub fn set_3d_level(&self, level: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_Set3DLevel(self.pointer, level) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DLevel", error)),
-            }
+    fn set_3d_level(&self, level: f32) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_3d_level(level),
+            ChannelControlHandle::ChannelGroup(group) => group.set_3d_level(level),
         }
     }
-    pub fn get_3d_level(&self) -> Result<f32, Error> {
-        unsafe {
-            let mut level = f32::default();
-            match ffi::FMOD_ChannelGroup_Get3DLevel(self.pointer, &mut level) {
-                ffi::FMOD_OK => Ok(level),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DLevel", error)),
-            }
+    fn get_3d_level(&self) -> Result<f32, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_3d_level(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_3d_level(),
         }
     }
-    pub fn set_3d_doppler_level(&self, level: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_Set3DDopplerLevel(self.pointer, level) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DDopplerLevel", error)),
-            }
+    fn set_3d_doppler_level(&self, level: f32) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_3d_doppler_level(level),
+            ChannelControlHandle::ChannelGroup(group) => group.set_3d_doppler_level(level),
         }
     }
-    pub fn get_3d_doppler_level(&self) -> Result<f32, Error> {
-        unsafe {
-            let mut level = f32::default();
-            match ffi::FMOD_ChannelGroup_Get3DDopplerLevel(self.pointer, &mut level) {
-                ffi::FMOD_OK => Ok(level),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DDopplerLevel", error)),
-            }
+    fn get_3d_doppler_level(&self) -> Result<f32, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_3d_doppler_level(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_3d_doppler_level(),
         }
     }
-    pub fn set_3d_distance_filter(
+    fn set_3d_distance_filter(
         &self,
         custom: bool,
         custom_level: f32,
         center_freq: Option<f32>,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_Set3DDistanceFilter(
-                self.pointer,
-                from_bool!(custom),
-                custom_level,
-                center_freq.unwrap_or(0.0),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DDistanceFilter", error)),
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => {
+                channel.set_3d_distance_filter(custom, custom_level, center_freq)
+            }
+            ChannelControlHandle::ChannelGroup(group) => {
+                group.set_3d_distance_filter(custom, custom_level, center_freq)
             }
         }
     }
-    pub fn get_3d_distance_filter(&self) -> Result<(bool, f32, f32), Error> {
-        unsafe {
-            let mut custom = ffi::FMOD_BOOL::default();
-            let mut custom_level = f32::default();
-            let mut center_freq = f32::default();
-            match ffi::FMOD_ChannelGroup_Get3DDistanceFilter(
-                self.pointer,
-                &mut custom,
-                &mut custom_level,
-                &mut center_freq,
-            ) {
-                ffi::FMOD_OK => Ok((to_bool!(custom), custom_level, center_freq)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DDistanceFilter", error)),
-            }
+    fn get_3d_distance_filter(&self) -> Result<(bool, f32, f32), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_3d_distance_filter(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_3d_distance_filter(),
         }
     }
-    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_SetUserData(self.pointer, userdata) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_SetUserData", error)),
-            }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.set_user_data(userdata),
+            ChannelControlHandle::ChannelGroup(group) => group.set_user_data(userdata),
         }
     }
-    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
-        unsafe {
-            let mut userdata = null_mut();
-            match ffi::FMOD_ChannelGroup_GetUserData(self.pointer, &mut userdata) {
-                ffi::FMOD_OK => Ok(userdata),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetUserData", error)),
-            }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        match self.downcast()? {
+            ChannelControlHandle::Channel(channel) => channel.get_user_data(),
+            ChannelControlHandle::ChannelGroup(group) => group.get_user_data(),
         }
     }
-    pub fn release(&self) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_ChannelGroup_Release(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_ChannelGroup_Release", error)),
-            }
-        }
+}
+impl ChannelControl for Channel {
+    #[inline]
+    fn as_channel_control(&self) -> *mut ffi::FMOD_CHANNELCONTROL {
+        self.pointer as *mut ffi::FMOD_CHANNELCONTROL
     }
-    pub fn add_group(
-        &self,
-        group: ChannelGroup,
-        propagatedspclock: bool,
-    ) -> Result<DspConnection, Error> {
-        unsafe {
-            let mut connection = null_mut();
-            match ffi::FMOD_ChannelGroup_AddGroup(
-                self.pointer,
-                group.as_mut_ptr(),
-                from_bool!(propagatedspclock),
-                &mut connection,
-            ) {
-                ffi::FMOD_OK => Ok(DspConnection::from(connection)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_AddGroup", error)),
-            }
-        }
+    #[inline]
+    fn control_type(&self) -> ChannelControlType {
+        ChannelControlType::Channel
     }
-    pub fn get_num_groups(&self) -> Result<i32, Error> {
-        unsafe {
-            let mut numgroups = i32::default();
-            match ffi::FMOD_ChannelGroup_GetNumGroups(self.pointer, &mut numgroups) {
-                ffi::FMOD_OK => Ok(numgroups),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetNumGroups", error)),
-            }
-        }
+}
+impl ChannelControl for ChannelGroup {
+    #[inline]
+    fn as_channel_control(&self) -> *mut ffi::FMOD_CHANNELCONTROL {
+        self.pointer as *mut ffi::FMOD_CHANNELCONTROL
     }
-    pub fn get_group(&self, index: i32) -> Result<ChannelGroup, Error> {
-        unsafe {
-            let mut group = null_mut();
-            match ffi::FMOD_ChannelGroup_GetGroup(self.pointer, index, &mut group) {
-                ffi::FMOD_OK => Ok(ChannelGroup::from(group)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetGroup", error)),
-            }
-        }
+    #[inline]
+    fn control_type(&self) -> ChannelControlType {
+        ChannelControlType::ChannelGroup
     }
-    pub fn get_parent_group(&self) -> Result<ChannelGroup, Error> {
-        unsafe {
-            let mut group = null_mut();
-            match ffi::FMOD_ChannelGroup_GetParentGroup(self.pointer, &mut group) {
-                ffi::FMOD_OK => Ok(ChannelGroup::from(group)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetParentGroup", error)),
-            }
+}
+#[doc = " Channel or channel group behind `FMOD_CHANNELCONTROL` pointer."]
+#[derive(Debug, Clone, Copy)]
+pub enum ChannelControlHandle {
+    Channel(Channel),
+    ChannelGroup(ChannelGroup),
+}
+impl ChannelControlHandle {
+    #[doc = " Downcasts pointer received from FMOD, e.g. in channel control callback."]
+    pub fn from_raw(
+        pointer: *mut ffi::FMOD_CHANNELCONTROL,
+        control_type: ChannelControlType,
+    ) -> Result<ChannelControlHandle, Error> {
+        match control_type {
+            ChannelControlType::Channel => Ok(ChannelControlHandle::Channel(Channel::from(
+                pointer as *mut ffi::FMOD_CHANNEL,
+            ))),
+            ChannelControlType::ChannelGroup => Ok(ChannelControlHandle::ChannelGroup(
+                ChannelGroup::from(pointer as *mut ffi::FMOD_CHANNELGROUP),
+            )),
+            ChannelControlType::Max => Err(err_enum!(
+                "FMOD_CHANNELCONTROL_TYPE",
+                ffi::FMOD_CHANNELCONTROL_MAX
+            )),
+        }
+    }
+    pub fn as_channel(&self) -> Option<Channel> {
+        match self {
+            ChannelControlHandle::Channel(channel) => Some(*channel),
+            ChannelControlHandle::ChannelGroup(_) => None,
         }
     }
-    pub fn get_name(&self, namelen: i32) -> Result<String, Error> {
-        unsafe {
-            let name = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            match ffi::FMOD_ChannelGroup_GetName(self.pointer, name, namelen) {
-                ffi::FMOD_OK => Ok(CString::from_raw(name)
-                    .into_string()
-                    .map_err(Error::String)?),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetName", error)),
-            }
+    pub fn as_channel_group(&self) -> Option<ChannelGroup> {
+        match self {
+            ChannelControlHandle::Channel(_) => None,
+            ChannelControlHandle::ChannelGroup(group) => Some(*group),
         }
     }
-    pub fn get_num_channels(&self) -> Result<i32, Error> {
-        unsafe {
-            let mut numchannels = i32::default();
-            match ffi::FMOD_ChannelGroup_GetNumChannels(self.pointer, &mut numchannels) {
-                ffi::FMOD_OK => Ok(numchannels),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetNumChannels", error)),
-            }
+}
+impl ChannelControl for ChannelControlHandle {
+    fn as_channel_control(&self) -> *mut ffi::FMOD_CHANNELCONTROL {
+        match self {
+            ChannelControlHandle::Channel(channel) => channel.as_channel_control(),
+            ChannelControlHandle::ChannelGroup(group) => group.as_channel_control(),
         }
     }
-    pub fn get_channel(&self, index: i32) -> Result<Channel, Error> {
-        unsafe {
-            let mut channel = null_mut();
-            match ffi::FMOD_ChannelGroup_GetChannel(self.pointer, index, &mut channel) {
-                ffi::FMOD_OK => Ok(Channel::from(channel)),
-                error => Err(err_fmod!("FMOD_ChannelGroup_GetChannel", error)),
-            }
+    fn control_type(&self) -> ChannelControlType {
+        match self {
+            ChannelControlHandle::Channel(_) => ChannelControlType::Channel,
+            ChannelControlHandle::ChannelGroup(_) => ChannelControlType::ChannelGroup,
         }
     }
+    #[inline]
+    fn downcast(&self) -> Result<ChannelControlHandle, Error> {
+        Ok(*self)
+    }
 }
 #[derive(Debug, Clone, Copy)]
-pub struct Dsp {
-    pointer: *mut ffi::FMOD_DSP,
+pub struct ChannelGroup {
+    pointer: *mut ffi::FMOD_CHANNELGROUP,
 }
-unsafe impl Send for Dsp {}
-unsafe impl Sync for Dsp {}
-impl Dsp {
+unsafe impl Send for ChannelGroup {}
+unsafe impl Sync for ChannelGroup {}
+impl ChannelGroup {
     #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_DSP) -> Self {
+    pub fn from(pointer: *mut ffi::FMOD_CHANNELGROUP) -> Self {
         Self { pointer }
     }
     #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_DSP {
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_CHANNELGROUP {
         self.pointer
     }
-    pub fn release(&self) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_DSP_Release(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_Release", error)),
-            }
-        }
-    }
     pub fn get_system_object(&self) -> Result<System, Error> {
         unsafe {
             let mut system = null_mut();
-            match ffi::FMOD_DSP_GetSystemObject(self.pointer, &mut system) {
+            match ffi::FMOD_ChannelGroup_GetSystemObject(self.pointer, &mut system) {
                 ffi::FMOD_OK => Ok(System::from(system)),
-                error => Err(err_fmod!("FMOD_DSP_GetSystemObject", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetSystemObject", error)),
             }
         }
     }
-    pub fn add_input(&self, input: Dsp, type_: DspConnectionType) -> Result<DspConnection, Error> {
+    pub fn stop(&self) -> Result<(), Error> {
         unsafe {
-            let mut connection = null_mut();
-            match ffi::FMOD_DSP_AddInput(
-                self.pointer,
-                input.as_mut_ptr(),
-                &mut connection,
-                type_.into(),
-            ) {
-                ffi::FMOD_OK => Ok(DspConnection::from(connection)),
-                error => Err(err_fmod!("FMOD_DSP_AddInput", error)),
+            match ffi::FMOD_ChannelGroup_Stop(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Stop", error)),
             }
         }
     }
-    pub fn disconnect_from(
-        &self,
-        target: Option<Dsp>,
-        connection: Option<DspConnection>,
-    ) -> Result<(), Error> {
+    pub fn set_paused(&self, paused: bool) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_DSP_DisconnectFrom(
-                self.pointer,
-                target.map(|value| value.as_mut_ptr()).unwrap_or(null_mut()),
-                connection
-                    .map(|value| value.as_mut_ptr())
-                    .unwrap_or(null_mut()),
-            ) {
+            match ffi::FMOD_ChannelGroup_SetPaused(self.pointer, from_bool!(paused)) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_DisconnectFrom", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetPaused", error)),
             }
         }
     }
-    pub fn disconnect_all(&self, inputs: bool, outputs: bool) -> Result<(), Error> {
+    pub fn get_paused(&self) -> Result<bool, Error> {
         unsafe {
-            match ffi::FMOD_DSP_DisconnectAll(self.pointer, from_bool!(inputs), from_bool!(outputs))
-            {
+            let mut paused = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_ChannelGroup_GetPaused(self.pointer, &mut paused) {
+                ffi::FMOD_OK => Ok(to_bool!(paused)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetPaused", error)),
+            }
+        }
+    }
+    pub fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_ChannelGroup_SetVolume(self.pointer, volume) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_DisconnectAll", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetVolume", error)),
             }
         }
     }
-    pub fn get_num_inputs(&self) -> Result<i32, Error> {
+    pub fn get_volume(&self) -> Result<f32, Error> {
         unsafe {
-            let mut numinputs = i32::default();
-            match ffi::FMOD_DSP_GetNumInputs(self.pointer, &mut numinputs) {
-                ffi::FMOD_OK => Ok(numinputs),
-                error => Err(err_fmod!("FMOD_DSP_GetNumInputs", error)),
+            let mut volume = f32::default();
+            match ffi::FMOD_ChannelGroup_GetVolume(self.pointer, &mut volume) {
+                ffi::FMOD_OK => Ok(volume),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetVolume", error)),
             }
         }
     }
-    pub fn get_num_outputs(&self) -> Result<i32, Error> {
+    pub fn set_volume_ramp(&self, ramp: bool) -> Result<(), Error> {
         unsafe {
-            let mut numoutputs = i32::default();
-            match ffi::FMOD_DSP_GetNumOutputs(self.pointer, &mut numoutputs) {
-                ffi::FMOD_OK => Ok(numoutputs),
-                error => Err(err_fmod!("FMOD_DSP_GetNumOutputs", error)),
+            match ffi::FMOD_ChannelGroup_SetVolumeRamp(self.pointer, from_bool!(ramp)) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetVolumeRamp", error)),
             }
         }
     }
-    pub fn get_input(&self, index: i32) -> Result<(Dsp, DspConnection), Error> {
+    pub fn get_volume_ramp(&self) -> Result<bool, Error> {
         unsafe {
-            let mut input = null_mut();
-            let mut inputconnection = null_mut();
-            match ffi::FMOD_DSP_GetInput(self.pointer, index, &mut input, &mut inputconnection) {
-                ffi::FMOD_OK => Ok((Dsp::from(input), DspConnection::from(inputconnection))),
-                error => Err(err_fmod!("FMOD_DSP_GetInput", error)),
+            let mut ramp = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_ChannelGroup_GetVolumeRamp(self.pointer, &mut ramp) {
+                ffi::FMOD_OK => Ok(to_bool!(ramp)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetVolumeRamp", error)),
             }
         }
     }
-    pub fn get_output(&self, index: i32) -> Result<(Dsp, DspConnection), Error> {
+    pub fn get_audibility(&self) -> Result<f32, Error> {
         unsafe {
-            let mut output = null_mut();
-            let mut outputconnection = null_mut();
-            match ffi::FMOD_DSP_GetOutput(self.pointer, index, &mut output, &mut outputconnection) {
-                ffi::FMOD_OK => Ok((Dsp::from(output), DspConnection::from(outputconnection))),
-                error => Err(err_fmod!("FMOD_DSP_GetOutput", error)),
+            let mut audibility = f32::default();
+            match ffi::FMOD_ChannelGroup_GetAudibility(self.pointer, &mut audibility) {
+                ffi::FMOD_OK => Ok(audibility),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetAudibility", error)),
             }
         }
     }
-    pub fn set_active(&self, active: bool) -> Result<(), Error> {
+    pub fn set_pitch(&self, pitch: f32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_DSP_SetActive(self.pointer, from_bool!(active)) {
+            match ffi::FMOD_ChannelGroup_SetPitch(self.pointer, pitch) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_SetActive", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetPitch", error)),
             }
         }
     }
-    pub fn get_active(&self) -> Result<bool, Error> {
+    pub fn get_pitch(&self) -> Result<f32, Error> {
         unsafe {
-            let mut active = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_DSP_GetActive(self.pointer, &mut active) {
-                ffi::FMOD_OK => Ok(to_bool!(active)),
-                error => Err(err_fmod!("FMOD_DSP_GetActive", error)),
+            let mut pitch = f32::default();
+            match ffi::FMOD_ChannelGroup_GetPitch(self.pointer, &mut pitch) {
+                ffi::FMOD_OK => Ok(pitch),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetPitch", error)),
             }
         }
     }
-    pub fn set_bypass(&self, bypass: bool) -> Result<(), Error> {
+    pub fn set_mute(&self, mute: bool) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_DSP_SetBypass(self.pointer, from_bool!(bypass)) {
+            match ffi::FMOD_ChannelGroup_SetMute(self.pointer, from_bool!(mute)) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_SetBypass", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetMute", error)),
             }
         }
     }
-    pub fn get_bypass(&self) -> Result<bool, Error> {
+    pub fn get_mute(&self) -> Result<bool, Error> {
         unsafe {
-            let mut bypass = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_DSP_GetBypass(self.pointer, &mut bypass) {
-                ffi::FMOD_OK => Ok(to_bool!(bypass)),
-                error => Err(err_fmod!("FMOD_DSP_GetBypass", error)),
+            let mut mute = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_ChannelGroup_GetMute(self.pointer, &mut mute) {
+                ffi::FMOD_OK => Ok(to_bool!(mute)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetMute", error)),
             }
         }
     }
-    pub fn set_wet_dry_mix(&self, prewet: f32, postwet: f32, dry: f32) -> Result<(), Error> {
+    pub fn set_reverb_properties(&self, instance: i32, wet: f32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_DSP_SetWetDryMix(self.pointer, prewet, postwet, dry) {
+            match ffi::FMOD_ChannelGroup_SetReverbProperties(self.pointer, instance, wet) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_SetWetDryMix", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetReverbProperties", error)),
             }
         }
     }
-    pub fn get_wet_dry_mix(&self) -> Result<(f32, f32, f32), Error> {
+    pub fn get_reverb_properties(&self, instance: i32) -> Result<f32, Error> {
         unsafe {
-            let mut prewet = f32::default();
-            let mut postwet = f32::default();
-            let mut dry = f32::default();
-            match ffi::FMOD_DSP_GetWetDryMix(self.pointer, &mut prewet, &mut postwet, &mut dry) {
-                ffi::FMOD_OK => Ok((prewet, postwet, dry)),
-                error => Err(err_fmod!("FMOD_DSP_GetWetDryMix", error)),
+            let mut wet = f32::default();
+            match ffi::FMOD_ChannelGroup_GetReverbProperties(self.pointer, instance, &mut wet) {
+                ffi::FMOD_OK => Ok(wet),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetReverbProperties", error)),
             }
         }
     }
-    pub fn set_channel_format(
-        &self,
-        channelmask: impl Into<ffi::FMOD_CHANNELMASK>,
-        numchannels: i32,
-        source_speakermode: SpeakerMode,
-    ) -> Result<(), Error> {
+    pub fn set_low_pass_gain(&self, gain: f32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_DSP_SetChannelFormat(
-                self.pointer,
-                channelmask.into(),
-                numchannels,
-                source_speakermode.into(),
-            ) {
+            match ffi::FMOD_ChannelGroup_SetLowPassGain(self.pointer, gain) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_SetChannelFormat", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetLowPassGain", error)),
             }
         }
     }
-    pub fn get_channel_format(&self) -> Result<(ffi::FMOD_CHANNELMASK, i32, SpeakerMode), Error> {
+    pub fn get_low_pass_gain(&self) -> Result<f32, Error> {
         unsafe {
-            let mut channelmask = ffi::FMOD_CHANNELMASK::default();
-            let mut numchannels = i32::default();
-            let mut source_speakermode = ffi::FMOD_SPEAKERMODE::default();
-            match ffi::FMOD_DSP_GetChannelFormat(
-                self.pointer,
-                &mut channelmask,
-                &mut numchannels,
-                &mut source_speakermode,
-            ) {
-                ffi::FMOD_OK => Ok((
-                    channelmask,
-                    numchannels,
-                    SpeakerMode::from(source_speakermode)?,
-                )),
-                error => Err(err_fmod!("FMOD_DSP_GetChannelFormat", error)),
+            let mut gain = f32::default();
+            match ffi::FMOD_ChannelGroup_GetLowPassGain(self.pointer, &mut gain) {
+                ffi::FMOD_OK => Ok(gain),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetLowPassGain", error)),
             }
         }
     }
-    pub fn get_output_channel_format(
-        &self,
-        inmask: impl Into<ffi::FMOD_CHANNELMASK>,
-        inchannels: i32,
-        inspeakermode: SpeakerMode,
-    ) -> Result<(ffi::FMOD_CHANNELMASK, i32, SpeakerMode), Error> {
+    pub fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error> {
         unsafe {
-            let mut outmask = ffi::FMOD_CHANNELMASK::default();
-            let mut outchannels = i32::default();
-            let mut outspeakermode = ffi::FMOD_SPEAKERMODE::default();
-            match ffi::FMOD_DSP_GetOutputChannelFormat(
-                self.pointer,
-                inmask.into(),
-                inchannels,
-                inspeakermode.into(),
-                &mut outmask,
-                &mut outchannels,
-                &mut outspeakermode,
-            ) {
-                ffi::FMOD_OK => Ok((outmask, outchannels, SpeakerMode::from(outspeakermode)?)),
-                error => Err(err_fmod!("FMOD_DSP_GetOutputChannelFormat", error)),
+            match ffi::FMOD_ChannelGroup_SetMode(self.pointer, mode.into()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetMode", error)),
             }
         }
     }
-    pub fn reset(&self) -> Result<(), Error> {
+    pub fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error> {
         unsafe {
-            match ffi::FMOD_DSP_Reset(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_Reset", error)),
+            let mut mode = ffi::FMOD_MODE::default();
+            match ffi::FMOD_ChannelGroup_GetMode(self.pointer, &mut mode) {
+                ffi::FMOD_OK => Ok(mode),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetMode", error)),
             }
         }
     }
-    pub fn set_callback(&self, callback: ffi::FMOD_DSP_CALLBACK) -> Result<(), Error> {
+    pub fn set_callback(&self, callback: ffi::FMOD_CHANNELCONTROL_CALLBACK) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_DSP_SetCallback(self.pointer, callback) {
+            match ffi::FMOD_ChannelGroup_SetCallback(self.pointer, callback) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_SetCallback", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetCallback", error)),
             }
         }
     }
-    pub fn set_parameter_float(&self, index: i32, value: f32) -> Result<(), Error> {
+    pub fn is_playing(&self) -> Result<bool, Error> {
         unsafe {
-            match ffi::FMOD_DSP_SetParameterFloat(self.pointer, index, value) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_SetParameterFloat", error)),
+            let mut isplaying = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_ChannelGroup_IsPlaying(self.pointer, &mut isplaying) {
+                ffi::FMOD_OK => Ok(to_bool!(isplaying)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_IsPlaying", error)),
             }
         }
     }
-    pub fn set_parameter_int(&self, index: i32, value: i32) -> Result<(), Error> {
+    pub fn set_pan(&self, pan: f32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_DSP_SetParameterInt(self.pointer, index, value) {
+            match ffi::FMOD_ChannelGroup_SetPan(self.pointer, pan) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_SetParameterInt", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetPan", error)),
             }
         }
     }
-    pub fn set_parameter_bool(&self, index: i32, value: bool) -> Result<(), Error> {
+    pub fn set_mix_levels_output(&self, levels: &SpeakerLevels) -> Result<(), Error> {
+        let (_, speakermode, _) = self.get_system_object()?.get_software_format()?;
+        levels.validate(speakermode)?;
         unsafe {
-            match ffi::FMOD_DSP_SetParameterBool(self.pointer, index, from_bool!(value)) {
+            match ffi::FMOD_ChannelGroup_SetMixLevelsOutput(
+                self.pointer,
+                levels.get(Speaker::FrontLeft),
+                levels.get(Speaker::FrontRight),
+                levels.get(Speaker::FrontCenter),
+                levels.get(Speaker::LowFrequency),
+                levels.get(Speaker::SurroundLeft),
+                levels.get(Speaker::SurroundRight),
+                levels.get(Speaker::BackLeft),
+                levels.get(Speaker::BackRight),
+            ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_SetParameterBool", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetMixLevelsOutput", error)),
             }
         }
     }
-    pub fn set_parameter_data(
-        &self,
-        index: i32,
-        data: *mut c_void,
-        length: u32,
-    ) -> Result<(), Error> {
+    pub fn set_mix_levels_input(&self, levels: &[f32]) -> Result<(), Error> {
+        validate_input_levels(levels)?;
         unsafe {
-            match ffi::FMOD_DSP_SetParameterData(self.pointer, index, data, length) {
+            match ffi::FMOD_ChannelGroup_SetMixLevelsInput(
+                self.pointer,
+                levels.as_ptr() as *mut f32,
+                levels.len() as i32,
+            ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_SetParameterData", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetMixLevelsInput", error)),
             }
         }
     }
-    pub fn get_parameter_float(
-        &self,
-        index: i32,
-        valuestrlen: i32,
-    ) -> Result<(f32, String), Error> {
+    pub fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error> {
         unsafe {
-            let mut value = f32::default();
-            let valuestr = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            match ffi::FMOD_DSP_GetParameterFloat(
+            match ffi::FMOD_ChannelGroup_SetMixMatrix(
                 self.pointer,
-                index,
-                &mut value,
-                valuestr,
-                valuestrlen,
+                matrix.as_slice().as_ptr() as *mut f32,
+                matrix.out_channels() as i32,
+                matrix.in_channels() as i32,
+                0,
             ) {
-                ffi::FMOD_OK => Ok((
-                    value,
-                    CString::from_raw(valuestr)
-                        .into_string()
-                        .map_err(Error::String)?,
-                )),
-                error => Err(err_fmod!("FMOD_DSP_GetParameterFloat", error)),
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetMixMatrix", error)),
             }
         }
     }
-    pub fn get_parameter_int(&self, index: i32, valuestrlen: i32) -> Result<(i32, String), Error> {
+    pub fn get_mix_matrix(&self) -> Result<MixMatrix, Error> {
         unsafe {
-            let mut value = i32::default();
-            let valuestr = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            match ffi::FMOD_DSP_GetParameterInt(
+            let mut outchannels = i32::default();
+            let mut inchannels = i32::default();
+            match ffi::FMOD_ChannelGroup_GetMixMatrix(
                 self.pointer,
-                index,
-                &mut value,
-                valuestr,
-                valuestrlen,
+                null_mut(),
+                &mut outchannels,
+                &mut inchannels,
+                0,
             ) {
-                ffi::FMOD_OK => Ok((
-                    value,
-                    CString::from_raw(valuestr)
-                        .into_string()
-                        .map_err(Error::String)?,
-                )),
-                error => Err(err_fmod!("FMOD_DSP_GetParameterInt", error)),
+                ffi::FMOD_OK => {
+                    let mut matrix = MixMatrix::new(outchannels as usize, inchannels as usize);
+                    match ffi::FMOD_ChannelGroup_GetMixMatrix(
+                        self.pointer,
+                        matrix.as_mut_ptr(),
+                        &mut outchannels,
+                        &mut inchannels,
+                        0,
+                    ) {
+                        ffi::FMOD_OK => Ok(matrix),
+                        error => Err(err_fmod!("FMOD_ChannelGroup_GetMixMatrix", error)),
+                    }
+                }
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetMixMatrix", error)),
             }
         }
     }
-    pub fn get_parameter_bool(
-        &self,
-        index: i32,
-        valuestrlen: i32,
-    ) -> Result<(bool, String), Error> {
+    pub fn get_dsp_clock(&self) -> Result<(u64, u64), Error> {
         unsafe {
-            let mut value = ffi::FMOD_BOOL::default();
-            let valuestr = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            match ffi::FMOD_DSP_GetParameterBool(
-                self.pointer,
-                index,
-                &mut value,
-                valuestr,
-                valuestrlen,
-            ) {
-                ffi::FMOD_OK => Ok((
-                    to_bool!(value),
-                    CString::from_raw(valuestr)
-                        .into_string()
-                        .map_err(Error::String)?,
-                )),
-                error => Err(err_fmod!("FMOD_DSP_GetParameterBool", error)),
+            let mut dspclock = u64::default();
+            let mut parentclock = u64::default();
+            match ffi::FMOD_ChannelGroup_GetDSPClock(self.pointer, &mut dspclock, &mut parentclock)
+            {
+                ffi::FMOD_OK => Ok((dspclock, parentclock)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetDSPClock", error)),
             }
         }
     }
-    pub fn get_parameter_data(
+    pub fn set_delay(
         &self,
-        index: i32,
-        valuestrlen: i32,
-    ) -> Result<(*mut c_void, u32, String), Error> {
+        dspclock_start: Option<u64>,
+        dspclock_end: Option<u64>,
+        stopchannels: bool,
+    ) -> Result<(), Error> {
         unsafe {
-            let mut data = null_mut();
-            let mut length = u32::default();
-            let valuestr = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            match ffi::FMOD_DSP_GetParameterData(
+            match ffi::FMOD_ChannelGroup_SetDelay(
                 self.pointer,
-                index,
-                &mut data,
-                &mut length,
-                valuestr,
-                valuestrlen,
+                dspclock_start.unwrap_or(0),
+                dspclock_end.unwrap_or(0),
+                from_bool!(stopchannels),
             ) {
-                ffi::FMOD_OK => Ok((
-                    data,
-                    length,
-                    CString::from_raw(valuestr)
-                        .into_string()
-                        .map_err(Error::String)?,
-                )),
-                error => Err(err_fmod!("FMOD_DSP_GetParameterData", error)),
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetDelay", error)),
             }
         }
     }
-    pub fn get_num_parameters(&self) -> Result<i32, Error> {
+    pub fn get_delay(&self) -> Result<(u64, u64, bool), Error> {
         unsafe {
-            let mut numparams = i32::default();
-            match ffi::FMOD_DSP_GetNumParameters(self.pointer, &mut numparams) {
-                ffi::FMOD_OK => Ok(numparams),
-                error => Err(err_fmod!("FMOD_DSP_GetNumParameters", error)),
+            let mut dspclock_start = u64::default();
+            let mut dspclock_end = u64::default();
+            let mut stopchannels = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_ChannelGroup_GetDelay(
+                self.pointer,
+                &mut dspclock_start,
+                &mut dspclock_end,
+                &mut stopchannels,
+            ) {
+                ffi::FMOD_OK => Ok((dspclock_start, dspclock_end, to_bool!(stopchannels))),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetDelay", error)),
             }
         }
     }
-    pub fn get_parameter_info(&self, index: i32) -> Result<DspParameterDesc, Error> {
+    pub fn add_fade_point(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
         unsafe {
-            let mut desc = null_mut();
-            match ffi::FMOD_DSP_GetParameterInfo(self.pointer, index, &mut desc) {
-                ffi::FMOD_OK => Ok(DspParameterDesc::try_from(*desc)?),
-                error => Err(err_fmod!("FMOD_DSP_GetParameterInfo", error)),
+            match ffi::FMOD_ChannelGroup_AddFadePoint(self.pointer, dspclock, volume) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_AddFadePoint", error)),
             }
         }
     }
-    pub fn get_data_parameter_index(&self, datatype: i32) -> Result<i32, Error> {
+    pub fn set_fade_point_ramp(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
         unsafe {
-            let mut index = i32::default();
-            match ffi::FMOD_DSP_GetDataParameterIndex(self.pointer, datatype, &mut index) {
-                ffi::FMOD_OK => Ok(index),
-                error => Err(err_fmod!("FMOD_DSP_GetDataParameterIndex", error)),
+            match ffi::FMOD_ChannelGroup_SetFadePointRamp(self.pointer, dspclock, volume) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetFadePointRamp", error)),
             }
         }
     }
-    pub fn show_config_dialog(&self, hwnd: *mut c_void, show: bool) -> Result<(), Error> {
+    pub fn remove_fade_points(&self, dspclock_start: u64, dspclock_end: u64) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_DSP_ShowConfigDialog(self.pointer, hwnd, from_bool!(show)) {
+            match ffi::FMOD_ChannelGroup_RemoveFadePoints(
+                self.pointer,
+                dspclock_start,
+                dspclock_end,
+            ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_ShowConfigDialog", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_RemoveFadePoints", error)),
             }
         }
     }
-    pub fn get_info(&self) -> Result<(String, u32, i32, i32, i32), Error> {
+    pub fn get_fade_points(&self) -> Result<Vec<(u64, f32)>, Error> {
         unsafe {
-            let name = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            let mut version = u32::default();
-            let mut channels = i32::default();
-            let mut configwidth = i32::default();
-            let mut configheight = i32::default();
-            match ffi::FMOD_DSP_GetInfo(
+            let mut numpoints = u32::default();
+            match ffi::FMOD_ChannelGroup_GetFadePoints(
                 self.pointer,
-                name,
-                &mut version,
-                &mut channels,
-                &mut configwidth,
-                &mut configheight,
+                &mut numpoints,
+                null_mut(),
+                null_mut(),
             ) {
-                ffi::FMOD_OK => Ok((
-                    CString::from_raw(name)
-                        .into_string()
-                        .map_err(Error::String)?,
-                    version,
-                    channels,
-                    configwidth,
-                    configheight,
-                )),
-                error => Err(err_fmod!("FMOD_DSP_GetInfo", error)),
+                ffi::FMOD_OK => {
+                    let mut clocks = vec![0u64; numpoints as usize];
+                    let mut volumes = vec![0.0f32; numpoints as usize];
+                    match ffi::FMOD_ChannelGroup_GetFadePoints(
+                        self.pointer,
+                        &mut numpoints,
+                        clocks.as_mut_ptr(),
+                        volumes.as_mut_ptr(),
+                    ) {
+                        ffi::FMOD_OK => Ok(clocks.into_iter().zip(volumes).collect()),
+                        error => Err(err_fmod!("FMOD_ChannelGroup_GetFadePoints", error)),
+                    }
+                }
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetFadePoints", error)),
             }
         }
     }
-    pub fn get_type(&self) -> Result<DspType, Error> {
+    pub fn get_dsp(&self, index: i32) -> Result<Dsp, Error> {
         unsafe {
-            let mut type_ = ffi::FMOD_DSP_TYPE::default();
-            match ffi::FMOD_DSP_GetType(self.pointer, &mut type_) {
-                ffi::FMOD_OK => Ok(DspType::from(type_)?),
-                error => Err(err_fmod!("FMOD_DSP_GetType", error)),
+            let mut dsp = null_mut();
+            match ffi::FMOD_ChannelGroup_GetDSP(self.pointer, index, &mut dsp) {
+                ffi::FMOD_OK => Ok(Dsp::from(dsp)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetDSP", error)),
             }
         }
     }
-    pub fn get_idle(&self) -> Result<bool, Error> {
+    pub fn add_dsp(&self, index: i32, dsp: Dsp) -> Result<(), Error> {
         unsafe {
-            let mut idle = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_DSP_GetIdle(self.pointer, &mut idle) {
-                ffi::FMOD_OK => Ok(to_bool!(idle)),
-                error => Err(err_fmod!("FMOD_DSP_GetIdle", error)),
+            match ffi::FMOD_ChannelGroup_AddDSP(self.pointer, index, dsp.as_mut_ptr()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_AddDSP", error)),
             }
         }
     }
-    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+    pub fn remove_dsp(&self, dsp: Dsp) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_DSP_SetUserData(self.pointer, userdata) {
+            match ffi::FMOD_ChannelGroup_RemoveDSP(self.pointer, dsp.as_mut_ptr()) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_SetUserData", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_RemoveDSP", error)),
             }
         }
     }
-    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+    pub fn get_num_ds_ps(&self) -> Result<i32, Error> {
         unsafe {
-            let mut userdata = null_mut();
-            match ffi::FMOD_DSP_GetUserData(self.pointer, &mut userdata) {
-                ffi::FMOD_OK => Ok(userdata),
-                error => Err(err_fmod!("FMOD_DSP_GetUserData", error)),
+            let mut numdsps = i32::default();
+            match ffi::FMOD_ChannelGroup_GetNumDSPs(self.pointer, &mut numdsps) {
+                ffi::FMOD_OK => Ok(numdsps),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetNumDSPs", error)),
             }
         }
     }
-    pub fn set_metering_enabled(
-        &self,
-        input_enabled: bool,
-        output_enabled: bool,
-    ) -> Result<(), Error> {
+    pub fn set_dsp_index(&self, dsp: Dsp, index: i32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_DSP_SetMeteringEnabled(
-                self.pointer,
-                from_bool!(input_enabled),
-                from_bool!(output_enabled),
-            ) {
+            match ffi::FMOD_ChannelGroup_SetDSPIndex(self.pointer, dsp.as_mut_ptr(), index) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSP_SetMeteringEnabled", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetDSPIndex", error)),
             }
         }
     }
-    pub fn get_metering_enabled(&self) -> Result<(bool, bool), Error> {
+    pub fn get_dsp_index(&self, dsp: Dsp) -> Result<i32, Error> {
         unsafe {
-            let mut input_enabled = ffi::FMOD_BOOL::default();
-            let mut output_enabled = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_DSP_GetMeteringEnabled(
+            let mut index = i32::default();
+            match ffi::FMOD_ChannelGroup_GetDSPIndex(self.pointer, dsp.as_mut_ptr(), &mut index) {
+                ffi::FMOD_OK => Ok(index),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetDSPIndex", error)),
+            }
+        }
+    }
+    pub fn set_3d_attributes(&self, pos: Option<Vector>, vel: Option<Vector>) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_ChannelGroup_Set3DAttributes(
                 self.pointer,
-                &mut input_enabled,
-                &mut output_enabled,
+                pos.map(Vector::into)
+                    .as_ref()
+                    .map(from_ref)
+                    .unwrap_or_else(null),
+                vel.map(Vector::into)
+                    .as_ref()
+                    .map(from_ref)
+                    .unwrap_or_else(null),
             ) {
-                ffi::FMOD_OK => Ok((to_bool!(input_enabled), to_bool!(output_enabled))),
-                error => Err(err_fmod!("FMOD_DSP_GetMeteringEnabled", error)),
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DAttributes", error)),
             }
         }
     }
-    pub fn get_metering_info(&self) -> Result<(DspMeteringInfo, DspMeteringInfo), Error> {
+    pub fn get_3d_attributes(&self) -> Result<(Vector, Vector), Error> {
         unsafe {
-            let mut input_info = ffi::FMOD_DSP_METERING_INFO::default();
-            let mut output_info = ffi::FMOD_DSP_METERING_INFO::default();
-            match ffi::FMOD_DSP_GetMeteringInfo(self.pointer, &mut input_info, &mut output_info) {
-                ffi::FMOD_OK => Ok((
-                    DspMeteringInfo::try_from(input_info)?,
-                    DspMeteringInfo::try_from(output_info)?,
-                )),
-                error => Err(err_fmod!("FMOD_DSP_GetMeteringInfo", error)),
+            let mut pos = ffi::FMOD_VECTOR::default();
+            let mut vel = ffi::FMOD_VECTOR::default();
+            match ffi::FMOD_ChannelGroup_Get3DAttributes(self.pointer, &mut pos, &mut vel) {
+                ffi::FMOD_OK => Ok((Vector::try_from(pos)?, Vector::try_from(vel)?)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DAttributes", error)),
             }
         }
     }
-    pub fn get_cpu_usage(&self) -> Result<(u32, u32), Error> {
+    pub fn set_3d_min_max_distance(&self, mindistance: f32, maxdistance: f32) -> Result<(), Error> {
         unsafe {
-            let mut exclusive = u32::default();
-            let mut inclusive = u32::default();
-            match ffi::FMOD_DSP_GetCPUUsage(self.pointer, &mut exclusive, &mut inclusive) {
-                ffi::FMOD_OK => Ok((exclusive, inclusive)),
-                error => Err(err_fmod!("FMOD_DSP_GetCPUUsage", error)),
+            match ffi::FMOD_ChannelGroup_Set3DMinMaxDistance(self.pointer, mindistance, maxdistance)
+            {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DMinMaxDistance", error)),
             }
         }
     }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct DspConnection {
-    pointer: *mut ffi::FMOD_DSPCONNECTION,
-}
-unsafe impl Send for DspConnection {}
-unsafe impl Sync for DspConnection {}
-impl DspConnection {
-    #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_DSPCONNECTION) -> Self {
-        Self { pointer }
-    }
-    #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_DSPCONNECTION {
-        self.pointer
+    pub fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        unsafe {
+            let mut mindistance = f32::default();
+            let mut maxdistance = f32::default();
+            match ffi::FMOD_ChannelGroup_Get3DMinMaxDistance(
+                self.pointer,
+                &mut mindistance,
+                &mut maxdistance,
+            ) {
+                ffi::FMOD_OK => Ok((mindistance, maxdistance)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DMinMaxDistance", error)),
+            }
+        }
     }
-    pub fn get_input(&self) -> Result<Dsp, Error> {
+    pub fn set_3d_cone_settings(
+        &self,
+        insideconeangle: f32,
+        outsideconeangle: f32,
+        outsidevolume: f32,
+    ) -> Result<(), Error> {
         unsafe {
-            let mut input = null_mut();
-            match ffi::FMOD_DSPConnection_GetInput(self.pointer, &mut input) {
-                ffi::FMOD_OK => Ok(Dsp::from(input)),
-                error => Err(err_fmod!("FMOD_DSPConnection_GetInput", error)),
+            match ffi::FMOD_ChannelGroup_Set3DConeSettings(
+                self.pointer,
+                insideconeangle,
+                outsideconeangle,
+                outsidevolume,
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DConeSettings", error)),
             }
         }
     }
-    pub fn get_output(&self) -> Result<Dsp, Error> {
+    pub fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error> {
         unsafe {
-            let mut output = null_mut();
-            match ffi::FMOD_DSPConnection_GetOutput(self.pointer, &mut output) {
-                ffi::FMOD_OK => Ok(Dsp::from(output)),
-                error => Err(err_fmod!("FMOD_DSPConnection_GetOutput", error)),
+            let mut insideconeangle = f32::default();
+            let mut outsideconeangle = f32::default();
+            let mut outsidevolume = f32::default();
+            match ffi::FMOD_ChannelGroup_Get3DConeSettings(
+                self.pointer,
+                &mut insideconeangle,
+                &mut outsideconeangle,
+                &mut outsidevolume,
+            ) {
+                ffi::FMOD_OK => Ok((insideconeangle, outsideconeangle, outsidevolume)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DConeSettings", error)),
             }
         }
     }
-    pub fn set_mix(&self, volume: f32) -> Result<(), Error> {
+    pub fn set_3d_cone_orientation(&self, orientation: Vector) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_DSPConnection_SetMix(self.pointer, volume) {
+            match ffi::FMOD_ChannelGroup_Set3DConeOrientation(self.pointer, &mut orientation.into())
+            {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSPConnection_SetMix", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DConeOrientation", error)),
             }
         }
     }
-    pub fn get_mix(&self) -> Result<f32, Error> {
+    pub fn get_3d_cone_orientation(&self) -> Result<Vector, Error> {
         unsafe {
-            let mut volume = f32::default();
-            match ffi::FMOD_DSPConnection_GetMix(self.pointer, &mut volume) {
-                ffi::FMOD_OK => Ok(volume),
-                error => Err(err_fmod!("FMOD_DSPConnection_GetMix", error)),
+            let mut orientation = ffi::FMOD_VECTOR::default();
+            match ffi::FMOD_ChannelGroup_Get3DConeOrientation(self.pointer, &mut orientation) {
+                ffi::FMOD_OK => Ok(Vector::try_from(orientation)?),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DConeOrientation", error)),
             }
         }
     }
-    pub fn set_mix_matrix(
-        &self,
-        matrix: Option<*mut f32>,
-        outchannels: i32,
-        inchannels: i32,
-        inchannel_hop: Option<i32>,
-    ) -> Result<(), Error> {
+    pub fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_DSPConnection_SetMixMatrix(
+            let numpoints = points.len() as i32;
+            match ffi::FMOD_ChannelGroup_Set3DCustomRolloff(
                 self.pointer,
-                matrix.unwrap_or(null_mut()),
-                outchannels,
-                inchannels,
-                inchannel_hop.unwrap_or(0),
+                vec_as_mut_ptr(points, |point| point.into()),
+                numpoints,
             ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSPConnection_SetMixMatrix", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DCustomRolloff", error)),
             }
         }
     }
-    pub fn get_mix_matrix(&self, inchannel_hop: Option<i32>) -> Result<(f32, i32, i32), Error> {
+    pub fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error> {
         unsafe {
-            let mut matrix = f32::default();
-            let mut outchannels = i32::default();
-            let mut inchannels = i32::default();
-            match ffi::FMOD_DSPConnection_GetMixMatrix(
+            let mut points = null_mut();
+            let mut numpoints = i32::default();
+            match ffi::FMOD_ChannelGroup_Get3DCustomRolloff(
                 self.pointer,
-                &mut matrix,
-                &mut outchannels,
-                &mut inchannels,
-                inchannel_hop.unwrap_or(0),
+                &mut points,
+                &mut numpoints,
             ) {
-                ffi::FMOD_OK => Ok((matrix, outchannels, inchannels)),
-                error => Err(err_fmod!("FMOD_DSPConnection_GetMixMatrix", error)),
+                ffi::FMOD_OK => Ok(to_vec!(points, numpoints, Vector::try_from)?),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DCustomRolloff", error)),
             }
         }
     }
-    pub fn get_type(&self) -> Result<DspConnectionType, Error> {
+    pub fn set_3d_occlusion(
+        &self,
+        directocclusion: f32,
+        reverbocclusion: f32,
+    ) -> Result<(), Error> {
         unsafe {
-            let mut type_ = ffi::FMOD_DSPCONNECTION_TYPE::default();
-            match ffi::FMOD_DSPConnection_GetType(self.pointer, &mut type_) {
-                ffi::FMOD_OK => Ok(DspConnectionType::from(type_)?),
-                error => Err(err_fmod!("FMOD_DSPConnection_GetType", error)),
+            match ffi::FMOD_ChannelGroup_Set3DOcclusion(
+                self.pointer,
+                directocclusion,
+                reverbocclusion,
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DOcclusion", error)),
             }
         }
     }
-    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+    pub fn get_3d_occlusion(&self) -> Result<(f32, f32), Error> {
         unsafe {
-            match ffi::FMOD_DSPConnection_SetUserData(self.pointer, userdata) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_DSPConnection_SetUserData", error)),
+            let mut directocclusion = f32::default();
+            let mut reverbocclusion = f32::default();
+            match ffi::FMOD_ChannelGroup_Get3DOcclusion(
+                self.pointer,
+                &mut directocclusion,
+                &mut reverbocclusion,
+            ) {
+                ffi::FMOD_OK => Ok((directocclusion, reverbocclusion)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DOcclusion", error)),
             }
         }
     }
-    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+    pub fn set_3d_spread(&self, angle: f32) -> Result<(), Error> {
         unsafe {
-            let mut userdata = null_mut();
-            match ffi::FMOD_DSPConnection_GetUserData(self.pointer, &mut userdata) {
-                ffi::FMOD_OK => Ok(userdata),
-                error => Err(err_fmod!("FMOD_DSPConnection_GetUserData", error)),
+            match ffi::FMOD_ChannelGroup_Set3DSpread(self.pointer, angle) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DSpread", error)),
             }
         }
     }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct Geometry {
-    pointer: *mut ffi::FMOD_GEOMETRY,
-}
-unsafe impl Send for Geometry {}
-unsafe impl Sync for Geometry {}
-impl Geometry {
-    #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_GEOMETRY) -> Self {
-        Self { pointer }
-    }
-    #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_GEOMETRY {
-        self.pointer
-    }
-    pub fn release(&self) -> Result<(), Error> {
+    pub fn get_3d_spread(&self) -> Result<f32, Error> {
         unsafe {
-            match ffi::FMOD_Geometry_Release(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Geometry_Release", error)),
+            let mut angle = f32::default();
+            match ffi::FMOD_ChannelGroup_Get3DSpread(self.pointer, &mut angle) {
+                ffi::FMOD_OK => Ok(angle),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DSpread", error)),
             }
         }
     }
-    pub fn add_polygon(
-        &self,
-        directocclusion: f32,
-        reverbocclusion: f32,
-        doublesided: bool,
-        numvertices: i32,
-        vertices: Vector,
-    ) -> Result<i32, Error> {
+    pub fn set_3d_level(&self, level: f32) -> Result<(), Error> {
         unsafe {
-            let mut polygonindex = i32::default();
-            match ffi::FMOD_Geometry_AddPolygon(
-                self.pointer,
-                directocclusion,
-                reverbocclusion,
-                from_bool!(doublesided),
-                numvertices,
-                &vertices.into(),
-                &mut polygonindex,
-            ) {
-                ffi::FMOD_OK => Ok(polygonindex),
-                error => Err(err_fmod!("FMOD_Geometry_AddPolygon", error)),
+            match ffi::FMOD_ChannelGroup_Set3DLevel(self.pointer, level) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DLevel", error)),
             }
         }
     }
-    pub fn get_num_polygons(&self) -> Result<i32, Error> {
+    pub fn get_3d_level(&self) -> Result<f32, Error> {
         unsafe {
-            let mut numpolygons = i32::default();
-            match ffi::FMOD_Geometry_GetNumPolygons(self.pointer, &mut numpolygons) {
-                ffi::FMOD_OK => Ok(numpolygons),
-                error => Err(err_fmod!("FMOD_Geometry_GetNumPolygons", error)),
+            let mut level = f32::default();
+            match ffi::FMOD_ChannelGroup_Get3DLevel(self.pointer, &mut level) {
+                ffi::FMOD_OK => Ok(level),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DLevel", error)),
             }
         }
     }
-    pub fn get_max_polygons(&self) -> Result<(i32, i32), Error> {
+    pub fn set_3d_doppler_level(&self, level: f32) -> Result<(), Error> {
         unsafe {
-            let mut maxpolygons = i32::default();
-            let mut maxvertices = i32::default();
-            match ffi::FMOD_Geometry_GetMaxPolygons(
-                self.pointer,
-                &mut maxpolygons,
-                &mut maxvertices,
-            ) {
-                ffi::FMOD_OK => Ok((maxpolygons, maxvertices)),
-                error => Err(err_fmod!("FMOD_Geometry_GetMaxPolygons", error)),
+            match ffi::FMOD_ChannelGroup_Set3DDopplerLevel(self.pointer, level) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DDopplerLevel", error)),
             }
         }
     }
-    pub fn get_polygon_num_vertices(&self, index: i32) -> Result<i32, Error> {
+    pub fn get_3d_doppler_level(&self) -> Result<f32, Error> {
         unsafe {
-            let mut numvertices = i32::default();
-            match ffi::FMOD_Geometry_GetPolygonNumVertices(self.pointer, index, &mut numvertices) {
-                ffi::FMOD_OK => Ok(numvertices),
-                error => Err(err_fmod!("FMOD_Geometry_GetPolygonNumVertices", error)),
+            let mut level = f32::default();
+            match ffi::FMOD_ChannelGroup_Get3DDopplerLevel(self.pointer, &mut level) {
+                ffi::FMOD_OK => Ok(level),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DDopplerLevel", error)),
             }
         }
     }
-    pub fn set_polygon_vertex(
+    pub fn set_3d_distance_filter(
         &self,
-        index: i32,
-        vertexindex: i32,
-        vertex: Vector,
+        custom: bool,
+        custom_level: f32,
+        center_freq: Option<f32>,
     ) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Geometry_SetPolygonVertex(
+            match ffi::FMOD_ChannelGroup_Set3DDistanceFilter(
                 self.pointer,
-                index,
-                vertexindex,
-                &vertex.into(),
+                from_bool!(custom),
+                custom_level,
+                center_freq.unwrap_or(0.0),
             ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Geometry_SetPolygonVertex", error)),
-            }
-        }
-    }
-    pub fn get_polygon_vertex(&self, index: i32, vertexindex: i32) -> Result<Vector, Error> {
-        unsafe {
-            let mut vertex = ffi::FMOD_VECTOR::default();
-            match ffi::FMOD_Geometry_GetPolygonVertex(self.pointer, index, vertexindex, &mut vertex)
-            {
-                ffi::FMOD_OK => Ok(Vector::try_from(vertex)?),
-                error => Err(err_fmod!("FMOD_Geometry_GetPolygonVertex", error)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Set3DDistanceFilter", error)),
             }
         }
     }
-    pub fn set_polygon_attributes(
-        &self,
-        index: i32,
-        directocclusion: f32,
-        reverbocclusion: f32,
-        doublesided: bool,
-    ) -> Result<(), Error> {
+    pub fn get_3d_distance_filter(&self) -> Result<(bool, f32, f32), Error> {
         unsafe {
-            match ffi::FMOD_Geometry_SetPolygonAttributes(
+            let mut custom = ffi::FMOD_BOOL::default();
+            let mut custom_level = f32::default();
+            let mut center_freq = f32::default();
+            match ffi::FMOD_ChannelGroup_Get3DDistanceFilter(
                 self.pointer,
-                index,
-                directocclusion,
-                reverbocclusion,
-                from_bool!(doublesided),
+                &mut custom,
+                &mut custom_level,
+                &mut center_freq,
             ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Geometry_SetPolygonAttributes", error)),
+                ffi::FMOD_OK => Ok((to_bool!(custom), custom_level, center_freq)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Get3DDistanceFilter", error)),
             }
         }
     }
-    pub fn get_polygon_attributes(&self, index: i32) -> Result<(f32, f32, bool), Error> {
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
         unsafe {
-            let mut directocclusion = f32::default();
-            let mut reverbocclusion = f32::default();
-            let mut doublesided = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Geometry_GetPolygonAttributes(
-                self.pointer,
-                index,
-                &mut directocclusion,
-                &mut reverbocclusion,
-                &mut doublesided,
-            ) {
-                ffi::FMOD_OK => Ok((directocclusion, reverbocclusion, to_bool!(doublesided))),
-                error => Err(err_fmod!("FMOD_Geometry_GetPolygonAttributes", error)),
+            match ffi::FMOD_ChannelGroup_SetUserData(self.pointer, userdata) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_SetUserData", error)),
             }
         }
     }
-    pub fn set_active(&self, active: bool) -> Result<(), Error> {
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
         unsafe {
-            match ffi::FMOD_Geometry_SetActive(self.pointer, from_bool!(active)) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Geometry_SetActive", error)),
+            let mut userdata = null_mut();
+            match ffi::FMOD_ChannelGroup_GetUserData(self.pointer, &mut userdata) {
+                ffi::FMOD_OK => Ok(userdata),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetUserData", error)),
             }
         }
     }
-    pub fn get_active(&self) -> Result<bool, Error> {
+    pub fn release(&self) -> Result<(), Error> {
         unsafe {
-            let mut active = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Geometry_GetActive(self.pointer, &mut active) {
-                ffi::FMOD_OK => Ok(to_bool!(active)),
-                error => Err(err_fmod!("FMOD_Geometry_GetActive", error)),
+            match ffi::FMOD_ChannelGroup_Release(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_Release", error)),
             }
         }
     }
-    pub fn set_rotation(&self, forward: Option<Vector>, up: Option<Vector>) -> Result<(), Error> {
+    pub fn add_group(
+        &self,
+        group: ChannelGroup,
+        propagatedspclock: bool,
+    ) -> Result<DspConnection, Error> {
         unsafe {
-            match ffi::FMOD_Geometry_SetRotation(
+            let mut connection = null_mut();
+            match ffi::FMOD_ChannelGroup_AddGroup(
                 self.pointer,
-                forward
-                    .map(Vector::into)
-                    .as_ref()
-                    .map(from_ref)
-                    .unwrap_or_else(null),
-                up.map(Vector::into)
-                    .as_ref()
-                    .map(from_ref)
-                    .unwrap_or_else(null),
+                group.as_mut_ptr(),
+                from_bool!(propagatedspclock),
+                &mut connection,
             ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Geometry_SetRotation", error)),
+                ffi::FMOD_OK => Ok(DspConnection::from(connection)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_AddGroup", error)),
             }
         }
     }
-    pub fn get_rotation(&self) -> Result<(Vector, Vector), Error> {
+    pub fn get_num_groups(&self) -> Result<i32, Error> {
         unsafe {
-            let mut forward = ffi::FMOD_VECTOR::default();
-            let mut up = ffi::FMOD_VECTOR::default();
-            match ffi::FMOD_Geometry_GetRotation(self.pointer, &mut forward, &mut up) {
-                ffi::FMOD_OK => Ok((Vector::try_from(forward)?, Vector::try_from(up)?)),
-                error => Err(err_fmod!("FMOD_Geometry_GetRotation", error)),
+            let mut numgroups = i32::default();
+            match ffi::FMOD_ChannelGroup_GetNumGroups(self.pointer, &mut numgroups) {
+                ffi::FMOD_OK => Ok(numgroups),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetNumGroups", error)),
             }
         }
     }
-    pub fn set_position(&self, position: Vector) -> Result<(), Error> {
+    pub fn get_group(&self, index: i32) -> Result<ChannelGroup, Error> {
         unsafe {
-            match ffi::FMOD_Geometry_SetPosition(self.pointer, &position.into()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Geometry_SetPosition", error)),
+            let mut group = null_mut();
+            match ffi::FMOD_ChannelGroup_GetGroup(self.pointer, index, &mut group) {
+                ffi::FMOD_OK => Ok(ChannelGroup::from(group)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetGroup", error)),
             }
         }
     }
-    pub fn get_position(&self) -> Result<Vector, Error> {
+    pub fn get_parent_group(&self) -> Result<ChannelGroup, Error> {
         unsafe {
-            let mut position = ffi::FMOD_VECTOR::default();
-            match ffi::FMOD_Geometry_GetPosition(self.pointer, &mut position) {
-                ffi::FMOD_OK => Ok(Vector::try_from(position)?),
-                error => Err(err_fmod!("FMOD_Geometry_GetPosition", error)),
+            let mut group = null_mut();
+            match ffi::FMOD_ChannelGroup_GetParentGroup(self.pointer, &mut group) {
+                ffi::FMOD_OK => Ok(ChannelGroup::from(group)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetParentGroup", error)),
             }
         }
     }
-    pub fn set_scale(&self, scale: Vector) -> Result<(), Error> {
+    pub fn get_name(&self, namelen: i32) -> Result<String, Error> {
         unsafe {
-            match ffi::FMOD_Geometry_SetScale(self.pointer, &scale.into()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Geometry_SetScale", error)),
+            let mut name = vec![0 as c_char; namelen.max(1) as usize];
+            match ffi::FMOD_ChannelGroup_GetName(self.pointer, name.as_mut_ptr(), name.len() as i32)
+            {
+                ffi::FMOD_OK => Ok(CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned()),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetName", error)),
             }
         }
     }
-    pub fn get_scale(&self) -> Result<Vector, Error> {
+    pub fn get_num_channels(&self) -> Result<i32, Error> {
         unsafe {
-            let mut scale = ffi::FMOD_VECTOR::default();
-            match ffi::FMOD_Geometry_GetScale(self.pointer, &mut scale) {
-                ffi::FMOD_OK => Ok(Vector::try_from(scale)?),
-                error => Err(err_fmod!("FMOD_Geometry_GetScale", error)),
+            let mut numchannels = i32::default();
+            match ffi::FMOD_ChannelGroup_GetNumChannels(self.pointer, &mut numchannels) {
+                ffi::FMOD_OK => Ok(numchannels),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetNumChannels", error)),
             }
         }
     }
-    pub fn save(&self) -> Result<(*mut c_void, i32), Error> {
+    pub fn get_channel(&self, index: i32) -> Result<Channel, Error> {
         unsafe {
-            let data = null_mut();
-            let mut datasize = i32::default();
-            match ffi::FMOD_Geometry_Save(self.pointer, data, &mut datasize) {
-                ffi::FMOD_OK => Ok((data, datasize)),
-                error => Err(err_fmod!("FMOD_Geometry_Save", error)),
+            let mut channel = null_mut();
+            match ffi::FMOD_ChannelGroup_GetChannel(self.pointer, index, &mut channel) {
+                ffi::FMOD_OK => Ok(Channel::from(channel)),
+                error => Err(err_fmod!("FMOD_ChannelGroup_GetChannel", error)),
             }
         }
     }
-    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Geometry_SetUserData(self.pointer, userdata) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Geometry_SetUserData", error)),
-            }
-        }
+}
+#[doc = " Methods of [`ChannelGroup`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait ChannelGroupApi {
+    fn get_system_object(&self) -> Result<System, Error>;
+    fn stop(&self) -> Result<(), Error>;
+    fn set_paused(&self, paused: bool) -> Result<(), Error>;
+    fn get_paused(&self) -> Result<bool, Error>;
+    fn set_volume(&self, volume: f32) -> Result<(), Error>;
+    fn get_volume(&self) -> Result<f32, Error>;
+    fn set_volume_ramp(&self, ramp: bool) -> Result<(), Error>;
+    fn get_volume_ramp(&self) -> Result<bool, Error>;
+    fn get_audibility(&self) -> Result<f32, Error>;
+    fn set_pitch(&self, pitch: f32) -> Result<(), Error>;
+    fn get_pitch(&self) -> Result<f32, Error>;
+    fn set_mute(&self, mute: bool) -> Result<(), Error>;
+    fn get_mute(&self) -> Result<bool, Error>;
+    fn set_reverb_properties(&self, instance: i32, wet: f32) -> Result<(), Error>;
+    fn get_reverb_properties(&self, instance: i32) -> Result<f32, Error>;
+    fn set_low_pass_gain(&self, gain: f32) -> Result<(), Error>;
+    fn get_low_pass_gain(&self) -> Result<f32, Error>;
+    fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error>;
+    fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error>;
+    fn set_callback(&self, callback: ffi::FMOD_CHANNELCONTROL_CALLBACK) -> Result<(), Error>;
+    fn is_playing(&self) -> Result<bool, Error>;
+    fn set_pan(&self, pan: f32) -> Result<(), Error>;
+    fn set_mix_levels_output(&self, levels: &SpeakerLevels) -> Result<(), Error>;
+    fn set_mix_levels_input(&self, levels: &[f32]) -> Result<(), Error>;
+    fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error>;
+    fn get_mix_matrix(&self) -> Result<MixMatrix, Error>;
+    fn get_dsp_clock(&self) -> Result<(u64, u64), Error>;
+    fn set_delay(
+        &self,
+        dspclock_start: Option<u64>,
+        dspclock_end: Option<u64>,
+        stopchannels: bool,
+    ) -> Result<(), Error>;
+    fn get_delay(&self) -> Result<(u64, u64, bool), Error>;
+    fn add_fade_point(&self, dspclock: u64, volume: f32) -> Result<(), Error>;
+    fn set_fade_point_ramp(&self, dspclock: u64, volume: f32) -> Result<(), Error>;
+    fn remove_fade_points(&self, dspclock_start: u64, dspclock_end: u64) -> Result<(), Error>;
+    fn get_fade_points(&self) -> Result<Vec<(u64, f32)>, Error>;
+    fn get_dsp(&self, index: i32) -> Result<Dsp, Error>;
+    fn add_dsp(&self, index: i32, dsp: Dsp) -> Result<(), Error>;
+    fn remove_dsp(&self, dsp: Dsp) -> Result<(), Error>;
+    fn get_num_ds_ps(&self) -> Result<i32, Error>;
+    fn set_dsp_index(&self, dsp: Dsp, index: i32) -> Result<(), Error>;
+    fn get_dsp_index(&self, dsp: Dsp) -> Result<i32, Error>;
+    fn set_3d_attributes(&self, pos: Option<Vector>, vel: Option<Vector>) -> Result<(), Error>;
+    fn get_3d_attributes(&self) -> Result<(Vector, Vector), Error>;
+    fn set_3d_min_max_distance(&self, mindistance: f32, maxdistance: f32) -> Result<(), Error>;
+    fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error>;
+    fn set_3d_cone_settings(
+        &self,
+        insideconeangle: f32,
+        outsideconeangle: f32,
+        outsidevolume: f32,
+    ) -> Result<(), Error>;
+    fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error>;
+    fn set_3d_cone_orientation(&self, orientation: Vector) -> Result<(), Error>;
+    fn get_3d_cone_orientation(&self) -> Result<Vector, Error>;
+    fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error>;
+    fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error>;
+    fn set_3d_occlusion(&self, directocclusion: f32, reverbocclusion: f32) -> Result<(), Error>;
+    fn get_3d_occlusion(&self) -> Result<(f32, f32), Error>;
+    fn set_3d_spread(&self, angle: f32) -> Result<(), Error>;
+    fn get_3d_spread(&self) -> Result<f32, Error>;
+    fn set_3d_level(&self, level: f32) -> Result<(), Error>;
+    fn get_3d_level(&self) -> Result<f32, Error>;
+    fn set_3d_doppler_level(&self, level: f32) -> Result<(), Error>;
+    fn get_3d_doppler_level(&self) -> Result<f32, Error>;
+    fn set_3d_distance_filter(
+        &self,
+        custom: bool,
+        custom_level: f32,
+        center_freq: Option<f32>,
+    ) -> Result<(), Error>;
+    fn get_3d_distance_filter(&self) -> Result<(bool, f32, f32), Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+    fn release(&self) -> Result<(), Error>;
+    fn add_group(
+        &self,
+        group: ChannelGroup,
+        propagatedspclock: bool,
+    ) -> Result<DspConnection, Error>;
+    fn get_num_groups(&self) -> Result<i32, Error>;
+    fn get_group(&self, index: i32) -> Result<ChannelGroup, Error>;
+    fn get_parent_group(&self) -> Result<ChannelGroup, Error>;
+    fn get_name(&self, namelen: i32) -> Result<String, Error>;
+    fn get_num_channels(&self) -> Result<i32, Error>;
+    fn get_channel(&self, index: i32) -> Result<Channel, Error>;
+}
+impl ChannelGroupApi for ChannelGroup {
+    #[inline]
+    fn get_system_object(&self) -> Result<System, Error> {
+        ChannelGroup::get_system_object(self)
     }
-    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
-        unsafe {
-            let mut userdata = null_mut();
-            match ffi::FMOD_Geometry_GetUserData(self.pointer, &mut userdata) {
-                ffi::FMOD_OK => Ok(userdata),
-                error => Err(err_fmod!("FMOD_Geometry_GetUserData", error)),
-            }
-        }
+    #[inline]
+    fn stop(&self) -> Result<(), Error> {
+        ChannelGroup::stop(self)
     }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct Polygon {
-    pointer: *mut ffi::FMOD_POLYGON,
-}
-unsafe impl Send for Polygon {}
-unsafe impl Sync for Polygon {}
-impl Polygon {
     #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_POLYGON) -> Self {
-        Self { pointer }
+    fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        ChannelGroup::set_paused(self, paused)
     }
     #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_POLYGON {
-        self.pointer
+    fn get_paused(&self) -> Result<bool, Error> {
+        ChannelGroup::get_paused(self)
     }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct Reverb3d {
-    pointer: *mut ffi::FMOD_REVERB3D,
-}
-unsafe impl Send for Reverb3d {}
-unsafe impl Sync for Reverb3d {}
-impl Reverb3d {
     #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_REVERB3D) -> Self {
-        Self { pointer }
+    fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        ChannelGroup::set_volume(self, volume)
     }
     #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_REVERB3D {
-        self.pointer
+    fn get_volume(&self) -> Result<f32, Error> {
+        ChannelGroup::get_volume(self)
     }
-    pub fn release(&self) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Reverb3D_Release(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Reverb3D_Release", error)),
-            }
-        }
+    #[inline]
+    fn set_volume_ramp(&self, ramp: bool) -> Result<(), Error> {
+        ChannelGroup::set_volume_ramp(self, ramp)
     }
-    pub fn set_3d_attributes(
-        &self,
-        position: Option<Vector>,
-        mindistance: f32,
-        maxdistance: f32,
-    ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Reverb3D_Set3DAttributes(
-                self.pointer,
-                position
-                    .map(Vector::into)
-                    .as_ref()
-                    .map(from_ref)
-                    .unwrap_or_else(null),
-                mindistance,
-                maxdistance,
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Reverb3D_Set3DAttributes", error)),
-            }
-        }
+    #[inline]
+    fn get_volume_ramp(&self) -> Result<bool, Error> {
+        ChannelGroup::get_volume_ramp(self)
     }
-    pub fn get_3d_attributes(&self) -> Result<(Vector, f32, f32), Error> {
-        unsafe {
-            let mut position = ffi::FMOD_VECTOR::default();
-            let mut mindistance = f32::default();
-            let mut maxdistance = f32::default();
-            match ffi::FMOD_Reverb3D_Get3DAttributes(
-                self.pointer,
-                &mut position,
-                &mut mindistance,
-                &mut maxdistance,
-            ) {
-                ffi::FMOD_OK => Ok((Vector::try_from(position)?, mindistance, maxdistance)),
-                error => Err(err_fmod!("FMOD_Reverb3D_Get3DAttributes", error)),
-            }
-        }
+    #[inline]
+    fn get_audibility(&self) -> Result<f32, Error> {
+        ChannelGroup::get_audibility(self)
     }
-    pub fn set_properties(&self, properties: ReverbProperties) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Reverb3D_SetProperties(self.pointer, &properties.into()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Reverb3D_SetProperties", error)),
-            }
-        }
+    #[inline]
+    fn set_pitch(&self, pitch: f32) -> Result<(), Error> {
+        ChannelGroup::set_pitch(self, pitch)
     }
-    pub fn get_properties(&self) -> Result<ReverbProperties, Error> {
-        unsafe {
-            let mut properties = ffi::FMOD_REVERB_PROPERTIES::default();
-            match ffi::FMOD_Reverb3D_GetProperties(self.pointer, &mut properties) {
-                ffi::FMOD_OK => Ok(ReverbProperties::try_from(properties)?),
-                error => Err(err_fmod!("FMOD_Reverb3D_GetProperties", error)),
-            }
-        }
+    #[inline]
+    fn get_pitch(&self) -> Result<f32, Error> {
+        ChannelGroup::get_pitch(self)
     }
-    pub fn set_active(&self, active: bool) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Reverb3D_SetActive(self.pointer, from_bool!(active)) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Reverb3D_SetActive", error)),
-            }
-        }
+    #[inline]
+    fn set_mute(&self, mute: bool) -> Result<(), Error> {
+        ChannelGroup::set_mute(self, mute)
     }
-    pub fn get_active(&self) -> Result<bool, Error> {
-        unsafe {
-            let mut active = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Reverb3D_GetActive(self.pointer, &mut active) {
-                ffi::FMOD_OK => Ok(to_bool!(active)),
-                error => Err(err_fmod!("FMOD_Reverb3D_GetActive", error)),
-            }
-        }
+    #[inline]
+    fn get_mute(&self) -> Result<bool, Error> {
+        ChannelGroup::get_mute(self)
     }
-    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Reverb3D_SetUserData(self.pointer, userdata) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Reverb3D_SetUserData", error)),
-            }
-        }
+    #[inline]
+    fn set_reverb_properties(&self, instance: i32, wet: f32) -> Result<(), Error> {
+        ChannelGroup::set_reverb_properties(self, instance, wet)
     }
-    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
-        unsafe {
-            let mut userdata = null_mut();
-            match ffi::FMOD_Reverb3D_GetUserData(self.pointer, &mut userdata) {
-                ffi::FMOD_OK => Ok(userdata),
-                error => Err(err_fmod!("FMOD_Reverb3D_GetUserData", error)),
-            }
-        }
+    #[inline]
+    fn get_reverb_properties(&self, instance: i32) -> Result<f32, Error> {
+        ChannelGroup::get_reverb_properties(self, instance)
     }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct Sound {
-    pointer: *mut ffi::FMOD_SOUND,
-}
-unsafe impl Send for Sound {}
-unsafe impl Sync for Sound {}
-impl Sound {
     #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_SOUND) -> Self {
-        Self { pointer }
+    fn set_low_pass_gain(&self, gain: f32) -> Result<(), Error> {
+        ChannelGroup::set_low_pass_gain(self, gain)
     }
     #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_SOUND {
-        self.pointer
+    fn get_low_pass_gain(&self) -> Result<f32, Error> {
+        ChannelGroup::get_low_pass_gain(self)
     }
-    pub fn release(&self) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Sound_Release(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_Release", error)),
-            }
-        }
+    #[inline]
+    fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error> {
+        ChannelGroup::set_mode(self, mode)
     }
-    pub fn get_system_object(&self) -> Result<System, Error> {
-        unsafe {
-            let mut system = null_mut();
-            match ffi::FMOD_Sound_GetSystemObject(self.pointer, &mut system) {
-                ffi::FMOD_OK => Ok(System::from(system)),
-                error => Err(err_fmod!("FMOD_Sound_GetSystemObject", error)),
-            }
-        }
+    #[inline]
+    fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error> {
+        ChannelGroup::get_mode(self)
     }
-    pub fn lock(
-        &self,
-        offset: u32,
-        length: u32,
-    ) -> Result<(*mut c_void, *mut c_void, u32, u32), Error> {
-        unsafe {
-            let mut ptr_1 = null_mut();
-            let mut ptr_2 = null_mut();
-            let mut len_1 = u32::default();
-            let mut len_2 = u32::default();
-            match ffi::FMOD_Sound_Lock(
-                self.pointer,
-                offset,
-                length,
-                &mut ptr_1,
-                &mut ptr_2,
-                &mut len_1,
-                &mut len_2,
-            ) {
-                ffi::FMOD_OK => Ok((ptr_1, ptr_2, len_1, len_2)),
-                error => Err(err_fmod!("FMOD_Sound_Lock", error)),
-            }
-        }
+    #[inline]
+    fn set_callback(&self, callback: ffi::FMOD_CHANNELCONTROL_CALLBACK) -> Result<(), Error> {
+        ChannelGroup::set_callback(self, callback)
     }
-    pub fn unlock(
-        &self,
-        ptr_1: *mut c_void,
-        ptr_2: *mut c_void,
-        len_1: u32,
-        len_2: u32,
-    ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Sound_Unlock(self.pointer, ptr_1, ptr_2, len_1, len_2) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_Unlock", error)),
-            }
-        }
+    #[inline]
+    fn is_playing(&self) -> Result<bool, Error> {
+        ChannelGroup::is_playing(self)
     }
-    pub fn set_defaults(&self, frequency: f32, priority: i32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Sound_SetDefaults(self.pointer, frequency, priority) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_SetDefaults", error)),
-            }
-        }
+    #[inline]
+    fn set_pan(&self, pan: f32) -> Result<(), Error> {
+        ChannelGroup::set_pan(self, pan)
     }
-    pub fn get_defaults(&self) -> Result<(f32, i32), Error> {
-        unsafe {
-            let mut frequency = f32::default();
-            let mut priority = i32::default();
-            match ffi::FMOD_Sound_GetDefaults(self.pointer, &mut frequency, &mut priority) {
-                ffi::FMOD_OK => Ok((frequency, priority)),
-                error => Err(err_fmod!("FMOD_Sound_GetDefaults", error)),
-            }
-        }
+    #[inline]
+    fn set_mix_levels_output(&self, levels: &SpeakerLevels) -> Result<(), Error> {
+        ChannelGroup::set_mix_levels_output(self, levels)
     }
-    pub fn set_3d_min_max_distance(&self, min: f32, max: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Sound_Set3DMinMaxDistance(self.pointer, min, max) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_Set3DMinMaxDistance", error)),
-            }
-        }
+    #[inline]
+    fn set_mix_levels_input(&self, levels: &[f32]) -> Result<(), Error> {
+        ChannelGroup::set_mix_levels_input(self, levels)
     }
-    pub fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error> {
-        unsafe {
-            let mut min = f32::default();
-            let mut max = f32::default();
-            match ffi::FMOD_Sound_Get3DMinMaxDistance(self.pointer, &mut min, &mut max) {
-                ffi::FMOD_OK => Ok((min, max)),
-                error => Err(err_fmod!("FMOD_Sound_Get3DMinMaxDistance", error)),
-            }
-        }
+    #[inline]
+    fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error> {
+        ChannelGroup::set_mix_matrix(self, matrix)
     }
-    pub fn set_3d_cone_settings(
+    #[inline]
+    fn get_mix_matrix(&self) -> Result<MixMatrix, Error> {
+        ChannelGroup::get_mix_matrix(self)
+    }
+    #[inline]
+    fn get_dsp_clock(&self) -> Result<(u64, u64), Error> {
+        ChannelGroup::get_dsp_clock(self)
+    }
+    #[inline]
+    fn set_delay(
+        &self,
+        dspclock_start: Option<u64>,
+        dspclock_end: Option<u64>,
+        stopchannels: bool,
+    ) -> Result<(), Error> {
+        ChannelGroup::set_delay(self, dspclock_start, dspclock_end, stopchannels)
+    }
+    #[inline]
+    fn get_delay(&self) -> Result<(u64, u64, bool), Error> {
+        ChannelGroup::get_delay(self)
+    }
+    #[inline]
+    fn add_fade_point(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
+        ChannelGroup::add_fade_point(self, dspclock, volume)
+    }
+    #[inline]
+    fn set_fade_point_ramp(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
+        ChannelGroup::set_fade_point_ramp(self, dspclock, volume)
+    }
+    #[inline]
+    fn remove_fade_points(&self, dspclock_start: u64, dspclock_end: u64) -> Result<(), Error> {
+        ChannelGroup::remove_fade_points(self, dspclock_start, dspclock_end)
+    }
+    #[inline]
+    fn get_fade_points(&self) -> Result<Vec<(u64, f32)>, Error> {
+        ChannelGroup::get_fade_points(self)
+    }
+    #[inline]
+    fn get_dsp(&self, index: i32) -> Result<Dsp, Error> {
+        ChannelGroup::get_dsp(self, index)
+    }
+    #[inline]
+    fn add_dsp(&self, index: i32, dsp: Dsp) -> Result<(), Error> {
+        ChannelGroup::add_dsp(self, index, dsp)
+    }
+    #[inline]
+    fn remove_dsp(&self, dsp: Dsp) -> Result<(), Error> {
+        ChannelGroup::remove_dsp(self, dsp)
+    }
+    #[inline]
+    fn get_num_ds_ps(&self) -> Result<i32, Error> {
+        ChannelGroup::get_num_ds_ps(self)
+    }
+    #[inline]
+    fn set_dsp_index(&self, dsp: Dsp, index: i32) -> Result<(), Error> {
+        ChannelGroup::set_dsp_index(self, dsp, index)
+    }
+    #[inline]
+    fn get_dsp_index(&self, dsp: Dsp) -> Result<i32, Error> {
+        ChannelGroup::get_dsp_index(self, dsp)
+    }
+    #[inline]
+    fn set_3d_attributes(&self, pos: Option<Vector>, vel: Option<Vector>) -> Result<(), Error> {
+        ChannelGroup::set_3d_attributes(self, pos, vel)
+    }
+    #[inline]
+    fn get_3d_attributes(&self) -> Result<(Vector, Vector), Error> {
+        ChannelGroup::get_3d_attributes(self)
+    }
+    #[inline]
+    fn set_3d_min_max_distance(&self, mindistance: f32, maxdistance: f32) -> Result<(), Error> {
+        ChannelGroup::set_3d_min_max_distance(self, mindistance, maxdistance)
+    }
+    #[inline]
+    fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        ChannelGroup::get_3d_min_max_distance(self)
+    }
+    #[inline]
+    fn set_3d_cone_settings(
         &self,
         insideconeangle: f32,
         outsideconeangle: f32,
         outsidevolume: f32,
     ) -> Result<(), Error> {
+        ChannelGroup::set_3d_cone_settings(self, insideconeangle, outsideconeangle, outsidevolume)
+    }
+    #[inline]
+    fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error> {
+        ChannelGroup::get_3d_cone_settings(self)
+    }
+    #[inline]
+    fn set_3d_cone_orientation(&self, orientation: Vector) -> Result<(), Error> {
+        ChannelGroup::set_3d_cone_orientation(self, orientation)
+    }
+    #[inline]
+    fn get_3d_cone_orientation(&self) -> Result<Vector, Error> {
+        ChannelGroup::get_3d_cone_orientation(self)
+    }
+    #[inline]
+    fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error> {
+        ChannelGroup::set_3d_custom_rolloff(self, points)
+    }
+    #[inline]
+    fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error> {
+        ChannelGroup::get_3d_custom_rolloff(self)
+    }
+    #[inline]
+    fn set_3d_occlusion(&self, directocclusion: f32, reverbocclusion: f32) -> Result<(), Error> {
+        ChannelGroup::set_3d_occlusion(self, directocclusion, reverbocclusion)
+    }
+    #[inline]
+    fn get_3d_occlusion(&self) -> Result<(f32, f32), Error> {
+        ChannelGroup::get_3d_occlusion(self)
+    }
+    #[inline]
+    fn set_3d_spread(&self, angle: f32) -> Result<(), Error> {
+        ChannelGroup::set_3d_spread(self, angle)
+    }
+    #[inline]
+    fn get_3d_spread(&self) -> Result<f32, Error> {
+        ChannelGroup::get_3d_spread(self)
+    }
+    #[inline]
+    fn set_3d_level(&self, level: f32) -> Result<(), Error> {
+        ChannelGroup::set_3d_level(self, level)
+    }
+    #[inline]
+    fn get_3d_level(&self) -> Result<f32, Error> {
+        ChannelGroup::get_3d_level(self)
+    }
+    #[inline]
+    fn set_3d_doppler_level(&self, level: f32) -> Result<(), Error> {
+        ChannelGroup::set_3d_doppler_level(self, level)
+    }
+    #[inline]
+    fn get_3d_doppler_level(&self) -> Result<f32, Error> {
+        ChannelGroup::get_3d_doppler_level(self)
+    }
+    #[inline]
+    fn set_3d_distance_filter(
+        &self,
+        custom: bool,
+        custom_level: f32,
+        center_freq: Option<f32>,
+    ) -> Result<(), Error> {
+        ChannelGroup::set_3d_distance_filter(self, custom, custom_level, center_freq)
+    }
+    #[inline]
+    fn get_3d_distance_filter(&self) -> Result<(bool, f32, f32), Error> {
+        ChannelGroup::get_3d_distance_filter(self)
+    }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        ChannelGroup::set_user_data(self, userdata)
+    }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        ChannelGroup::get_user_data(self)
+    }
+    #[inline]
+    fn release(&self) -> Result<(), Error> {
+        ChannelGroup::release(self)
+    }
+    #[inline]
+    fn add_group(
+        &self,
+        group: ChannelGroup,
+        propagatedspclock: bool,
+    ) -> Result<DspConnection, Error> {
+        ChannelGroup::add_group(self, group, propagatedspclock)
+    }
+    #[inline]
+    fn get_num_groups(&self) -> Result<i32, Error> {
+        ChannelGroup::get_num_groups(self)
+    }
+    #[inline]
+    fn get_group(&self, index: i32) -> Result<ChannelGroup, Error> {
+        ChannelGroup::get_group(self, index)
+    }
+    #[inline]
+    fn get_parent_group(&self) -> Result<ChannelGroup, Error> {
+        ChannelGroup::get_parent_group(self)
+    }
+    #[inline]
+    fn get_name(&self, namelen: i32) -> Result<String, Error> {
+        ChannelGroup::get_name(self, namelen)
+    }
+    #[inline]
+    fn get_num_channels(&self) -> Result<i32, Error> {
+        ChannelGroup::get_num_channels(self)
+    }
+    #[inline]
+    fn get_channel(&self, index: i32) -> Result<Channel, Error> {
+        ChannelGroup::get_channel(self, index)
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct Dsp {
+    pointer: *mut ffi::FMOD_DSP,
+}
+unsafe impl Send for Dsp {}
+unsafe impl Sync for Dsp {}
+impl Dsp {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_DSP) -> Self {
+        Self { pointer }
+    }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_DSP {
+        self.pointer
+    }
+    pub fn release(&self) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Sound_Set3DConeSettings(
-                self.pointer,
-                insideconeangle,
-                outsideconeangle,
-                outsidevolume,
-            ) {
+            match ffi::FMOD_DSP_Release(self.pointer) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_Set3DConeSettings", error)),
+                error => Err(err_fmod!("FMOD_DSP_Release", error)),
             }
         }
     }
-    pub fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error> {
+    pub fn get_system_object(&self) -> Result<System, Error> {
         unsafe {
-            let mut insideconeangle = f32::default();
-            let mut outsideconeangle = f32::default();
-            let mut outsidevolume = f32::default();
-            match ffi::FMOD_Sound_Get3DConeSettings(
+            let mut system = null_mut();
+            match ffi::FMOD_DSP_GetSystemObject(self.pointer, &mut system) {
+                ffi::FMOD_OK => Ok(System::from(system)),
+                error => Err(err_fmod!("FMOD_DSP_GetSystemObject", error)),
+            }
+        }
+    }
+    pub fn add_input(&self, input: Dsp, type_: DspConnectionType) -> Result<DspConnection, Error> {
+        unsafe {
+            let mut connection = null_mut();
+            match ffi::FMOD_DSP_AddInput(
                 self.pointer,
-                &mut insideconeangle,
-                &mut outsideconeangle,
-                &mut outsidevolume,
+                input.as_mut_ptr(),
+                &mut connection,
+                type_.into(),
             ) {
-                ffi::FMOD_OK => Ok((insideconeangle, outsideconeangle, outsidevolume)),
-                error => Err(err_fmod!("FMOD_Sound_Get3DConeSettings", error)),
+                ffi::FMOD_OK => Ok(DspConnection::from(connection)),
+                error => Err(err_fmod!("FMOD_DSP_AddInput", error)),
             }
         }
     }
-    pub fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error> {
+    pub fn disconnect_from(
+        &self,
+        target: Option<Dsp>,
+        connection: Option<DspConnection>,
+    ) -> Result<(), Error> {
         unsafe {
-            let numpoints = points.len() as i32;
-            match ffi::FMOD_Sound_Set3DCustomRolloff(
+            match ffi::FMOD_DSP_DisconnectFrom(
                 self.pointer,
-                vec_as_mut_ptr(points, |point| point.into()),
-                numpoints,
+                target.map(|value| value.as_mut_ptr()).unwrap_or(null_mut()),
+                connection
+                    .map(|value| value.as_mut_ptr())
+                    .unwrap_or(null_mut()),
             ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_Set3DCustomRolloff", error)),
+                error => Err(err_fmod!("FMOD_DSP_DisconnectFrom", error)),
             }
         }
     }
-    pub fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error> {
+    pub fn disconnect_all(&self, inputs: bool, outputs: bool) -> Result<(), Error> {
         unsafe {
-            let mut points = null_mut();
-            let mut numpoints = i32::default();
-            match ffi::FMOD_Sound_Get3DCustomRolloff(self.pointer, &mut points, &mut numpoints) {
-                ffi::FMOD_OK => Ok(to_vec!(points, numpoints, Vector::try_from)?),
-                error => Err(err_fmod!("FMOD_Sound_Get3DCustomRolloff", error)),
+            match ffi::FMOD_DSP_DisconnectAll(self.pointer, from_bool!(inputs), from_bool!(outputs))
+            {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_DSP_DisconnectAll", error)),
             }
         }
     }
-    pub fn get_sub_sound(&self, index: i32) -> Result<Sound, Error> {
+    pub fn get_num_inputs(&self) -> Result<i32, Error> {
         unsafe {
-            let mut subsound = null_mut();
-            match ffi::FMOD_Sound_GetSubSound(self.pointer, index, &mut subsound) {
-                ffi::FMOD_OK => Ok(Sound::from(subsound)),
-                error => Err(err_fmod!("FMOD_Sound_GetSubSound", error)),
+            let mut numinputs = i32::default();
+            match ffi::FMOD_DSP_GetNumInputs(self.pointer, &mut numinputs) {
+                ffi::FMOD_OK => Ok(numinputs),
+                error => Err(err_fmod!("FMOD_DSP_GetNumInputs", error)),
             }
         }
     }
-    pub fn get_sub_sound_parent(&self) -> Result<Sound, Error> {
+    pub fn get_num_outputs(&self) -> Result<i32, Error> {
         unsafe {
-            let mut parentsound = null_mut();
-            match ffi::FMOD_Sound_GetSubSoundParent(self.pointer, &mut parentsound) {
-                ffi::FMOD_OK => Ok(Sound::from(parentsound)),
-                error => Err(err_fmod!("FMOD_Sound_GetSubSoundParent", error)),
+            let mut numoutputs = i32::default();
+            match ffi::FMOD_DSP_GetNumOutputs(self.pointer, &mut numoutputs) {
+                ffi::FMOD_OK => Ok(numoutputs),
+                error => Err(err_fmod!("FMOD_DSP_GetNumOutputs", error)),
             }
         }
     }
-    pub fn get_name(&self, namelen: i32) -> Result<String, Error> {
+    pub fn get_input(&self, index: i32) -> Result<(Dsp, DspConnection), Error> {
         unsafe {
-            let name = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            match ffi::FMOD_Sound_GetName(self.pointer, name, namelen) {
-                ffi::FMOD_OK => Ok(CString::from_raw(name)
-                    .into_string()
-                    .map_err(Error::String)?),
-                error => Err(err_fmod!("FMOD_Sound_GetName", error)),
+            let mut input = null_mut();
+            let mut inputconnection = null_mut();
+            match ffi::FMOD_DSP_GetInput(self.pointer, index, &mut input, &mut inputconnection) {
+                ffi::FMOD_OK => Ok((Dsp::from(input), DspConnection::from(inputconnection))),
+                error => Err(err_fmod!("FMOD_DSP_GetInput", error)),
             }
         }
     }
-    pub fn get_length(&self, lengthtype: impl Into<ffi::FMOD_TIMEUNIT>) -> Result<u32, Error> {
+    pub fn get_output(&self, index: i32) -> Result<(Dsp, DspConnection), Error> {
         unsafe {
-            let mut length = u32::default();
-            match ffi::FMOD_Sound_GetLength(self.pointer, &mut length, lengthtype.into()) {
-                ffi::FMOD_OK => Ok(length),
-                error => Err(err_fmod!("FMOD_Sound_GetLength", error)),
+            let mut output = null_mut();
+            let mut outputconnection = null_mut();
+            match ffi::FMOD_DSP_GetOutput(self.pointer, index, &mut output, &mut outputconnection) {
+                ffi::FMOD_OK => Ok((Dsp::from(output), DspConnection::from(outputconnection))),
+                error => Err(err_fmod!("FMOD_DSP_GetOutput", error)),
             }
         }
     }
-    pub fn get_format(&self) -> Result<(SoundType, SoundFormat, i32, i32), Error> {
+    pub fn set_active(&self, active: bool) -> Result<(), Error> {
         unsafe {
-            let mut type_ = ffi::FMOD_SOUND_TYPE::default();
-            let mut format = ffi::FMOD_SOUND_FORMAT::default();
-            let mut channels = i32::default();
-            let mut bits = i32::default();
-            match ffi::FMOD_Sound_GetFormat(
-                self.pointer,
-                &mut type_,
-                &mut format,
-                &mut channels,
-                &mut bits,
-            ) {
-                ffi::FMOD_OK => Ok((
-                    SoundType::from(type_)?,
-                    SoundFormat::from(format)?,
-                    channels,
-                    bits,
-                )),
-                error => Err(err_fmod!("FMOD_Sound_GetFormat", error)),
+            match ffi::FMOD_DSP_SetActive(self.pointer, from_bool!(active)) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_DSP_SetActive", error)),
             }
         }
     }
-    pub fn get_num_sub_sounds(&self) -> Result<i32, Error> {
+    pub fn get_active(&self) -> Result<bool, Error> {
         unsafe {
-            let mut numsubsounds = i32::default();
-            match ffi::FMOD_Sound_GetNumSubSounds(self.pointer, &mut numsubsounds) {
-                ffi::FMOD_OK => Ok(numsubsounds),
-                error => Err(err_fmod!("FMOD_Sound_GetNumSubSounds", error)),
-            }
-        }
-    }
-    pub fn get_num_tags(&self) -> Result<(i32, i32), Error> {
-        unsafe {
-            let mut numtags = i32::default();
-            let mut numtagsupdated = i32::default();
-            match ffi::FMOD_Sound_GetNumTags(self.pointer, &mut numtags, &mut numtagsupdated) {
-                ffi::FMOD_OK => Ok((numtags, numtagsupdated)),
-                error => Err(err_fmod!("FMOD_Sound_GetNumTags", error)),
-            }
-        }
-    }
-    pub fn get_tag(&self, name: &str, index: Option<i32>) -> Result<Tag, Error> {
-        unsafe {
-            let mut tag = ffi::FMOD_TAG::default();
-            match ffi::FMOD_Sound_GetTag(
-                self.pointer,
-                CString::new(name)?.as_ptr(),
-                index.unwrap_or(0),
-                &mut tag,
-            ) {
-                ffi::FMOD_OK => Ok(Tag::try_from(tag)?),
-                error => Err(err_fmod!("FMOD_Sound_GetTag", error)),
-            }
-        }
-    }
-    pub fn get_open_state(&self) -> Result<(OpenState, u32, bool, bool), Error> {
-        unsafe {
-            let mut openstate = ffi::FMOD_OPENSTATE::default();
-            let mut percentbuffered = u32::default();
-            let mut starving = ffi::FMOD_BOOL::default();
-            let mut diskbusy = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Sound_GetOpenState(
-                self.pointer,
-                &mut openstate,
-                &mut percentbuffered,
-                &mut starving,
-                &mut diskbusy,
-            ) {
-                ffi::FMOD_OK => Ok((
-                    OpenState::from(openstate)?,
-                    percentbuffered,
-                    to_bool!(starving),
-                    to_bool!(diskbusy),
-                )),
-                error => Err(err_fmod!("FMOD_Sound_GetOpenState", error)),
-            }
-        }
-    }
-    pub fn read_data(&self, buffer: *mut c_void, length: u32) -> Result<u32, Error> {
-        unsafe {
-            let mut read = u32::default();
-            match ffi::FMOD_Sound_ReadData(self.pointer, buffer, length, &mut read) {
-                ffi::FMOD_OK => Ok(read),
-                error => Err(err_fmod!("FMOD_Sound_ReadData", error)),
+            let mut active = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_DSP_GetActive(self.pointer, &mut active) {
+                ffi::FMOD_OK => Ok(to_bool!(active)),
+                error => Err(err_fmod!("FMOD_DSP_GetActive", error)),
             }
         }
     }
-    pub fn seek_data(&self, pcm: u32) -> Result<(), Error> {
+    pub fn set_bypass(&self, bypass: bool) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Sound_SeekData(self.pointer, pcm) {
+            match ffi::FMOD_DSP_SetBypass(self.pointer, from_bool!(bypass)) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_SeekData", error)),
+                error => Err(err_fmod!("FMOD_DSP_SetBypass", error)),
             }
         }
     }
-    pub fn set_sound_group(&self, soundgroup: SoundGroup) -> Result<(), Error> {
+    pub fn get_bypass(&self) -> Result<bool, Error> {
         unsafe {
-            match ffi::FMOD_Sound_SetSoundGroup(self.pointer, soundgroup.as_mut_ptr()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_SetSoundGroup", error)),
+            let mut bypass = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_DSP_GetBypass(self.pointer, &mut bypass) {
+                ffi::FMOD_OK => Ok(to_bool!(bypass)),
+                error => Err(err_fmod!("FMOD_DSP_GetBypass", error)),
             }
         }
     }
-    pub fn get_sound_group(&self) -> Result<SoundGroup, Error> {
+    pub fn set_wet_dry_mix(&self, prewet: f32, postwet: f32, dry: f32) -> Result<(), Error> {
         unsafe {
-            let mut soundgroup = null_mut();
-            match ffi::FMOD_Sound_GetSoundGroup(self.pointer, &mut soundgroup) {
-                ffi::FMOD_OK => Ok(SoundGroup::from(soundgroup)),
-                error => Err(err_fmod!("FMOD_Sound_GetSoundGroup", error)),
+            match ffi::FMOD_DSP_SetWetDryMix(self.pointer, prewet, postwet, dry) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_DSP_SetWetDryMix", error)),
             }
         }
     }
-    pub fn get_num_sync_points(&self) -> Result<i32, Error> {
+    pub fn get_wet_dry_mix(&self) -> Result<(f32, f32, f32), Error> {
         unsafe {
-            let mut numsyncpoints = i32::default();
-            match ffi::FMOD_Sound_GetNumSyncPoints(self.pointer, &mut numsyncpoints) {
-                ffi::FMOD_OK => Ok(numsyncpoints),
-                error => Err(err_fmod!("FMOD_Sound_GetNumSyncPoints", error)),
+            let mut prewet = f32::default();
+            let mut postwet = f32::default();
+            let mut dry = f32::default();
+            match ffi::FMOD_DSP_GetWetDryMix(self.pointer, &mut prewet, &mut postwet, &mut dry) {
+                ffi::FMOD_OK => Ok((prewet, postwet, dry)),
+                error => Err(err_fmod!("FMOD_DSP_GetWetDryMix", error)),
             }
         }
     }
-    pub fn get_sync_point(&self, index: i32) -> Result<SyncPoint, Error> {
+    pub fn set_channel_format(
+        &self,
+        channelmask: impl Into<ffi::FMOD_CHANNELMASK>,
+        numchannels: i32,
+        source_speakermode: SpeakerMode,
+    ) -> Result<(), Error> {
         unsafe {
-            let mut point = null_mut();
-            match ffi::FMOD_Sound_GetSyncPoint(self.pointer, index, &mut point) {
-                ffi::FMOD_OK => Ok(SyncPoint::from(point)),
-                error => Err(err_fmod!("FMOD_Sound_GetSyncPoint", error)),
+            match ffi::FMOD_DSP_SetChannelFormat(
+                self.pointer,
+                channelmask.into(),
+                numchannels,
+                source_speakermode.into(),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_DSP_SetChannelFormat", error)),
             }
         }
     }
-    pub fn get_sync_point_info(
-        &self,
-        point: SyncPoint,
-        namelen: i32,
-        offsettype: impl Into<ffi::FMOD_TIMEUNIT>,
-    ) -> Result<(String, u32), Error> {
+    pub fn get_channel_format(&self) -> Result<(ffi::FMOD_CHANNELMASK, i32, SpeakerMode), Error> {
         unsafe {
-            let name = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            let mut offset = u32::default();
-            match ffi::FMOD_Sound_GetSyncPointInfo(
+            let mut channelmask = ffi::FMOD_CHANNELMASK::default();
+            let mut numchannels = i32::default();
+            let mut source_speakermode = ffi::FMOD_SPEAKERMODE::default();
+            match ffi::FMOD_DSP_GetChannelFormat(
                 self.pointer,
-                point.as_mut_ptr(),
-                name,
-                namelen,
-                &mut offset,
-                offsettype.into(),
+                &mut channelmask,
+                &mut numchannels,
+                &mut source_speakermode,
             ) {
                 ffi::FMOD_OK => Ok((
-                    CString::from_raw(name)
-                        .into_string()
-                        .map_err(Error::String)?,
-                    offset,
+                    channelmask,
+                    numchannels,
+                    SpeakerMode::from(source_speakermode)?,
                 )),
-                error => Err(err_fmod!("FMOD_Sound_GetSyncPointInfo", error)),
+                error => Err(err_fmod!("FMOD_DSP_GetChannelFormat", error)),
             }
         }
     }
-    pub fn add_sync_point(
+    pub fn get_output_channel_format(
         &self,
-        offset: u32,
-        offsettype: impl Into<ffi::FMOD_TIMEUNIT>,
-        name: Option<String>,
-    ) -> Result<SyncPoint, Error> {
+        inmask: impl Into<ffi::FMOD_CHANNELMASK>,
+        inchannels: i32,
+        inspeakermode: SpeakerMode,
+    ) -> Result<(ffi::FMOD_CHANNELMASK, i32, SpeakerMode), Error> {
         unsafe {
-            let mut point = null_mut();
-            match ffi::FMOD_Sound_AddSyncPoint(
+            let mut outmask = ffi::FMOD_CHANNELMASK::default();
+            let mut outchannels = i32::default();
+            let mut outspeakermode = ffi::FMOD_SPEAKERMODE::default();
+            match ffi::FMOD_DSP_GetOutputChannelFormat(
                 self.pointer,
-                offset,
-                offsettype.into(),
-                name.map(|value| CString::new(value).map(|value| value.as_ptr()))
-                    .unwrap_or(Ok(null_mut()))?,
-                &mut point,
+                inmask.into(),
+                inchannels,
+                inspeakermode.into(),
+                &mut outmask,
+                &mut outchannels,
+                &mut outspeakermode,
             ) {
-                ffi::FMOD_OK => Ok(SyncPoint::from(point)),
-                error => Err(err_fmod!("FMOD_Sound_AddSyncPoint", error)),
+                ffi::FMOD_OK => Ok((outmask, outchannels, SpeakerMode::from(outspeakermode)?)),
+                error => Err(err_fmod!("FMOD_DSP_GetOutputChannelFormat", error)),
             }
         }
     }
-    pub fn delete_sync_point(&self, point: SyncPoint) -> Result<(), Error> {
+    pub fn reset(&self) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Sound_DeleteSyncPoint(self.pointer, point.as_mut_ptr()) {
+            match ffi::FMOD_DSP_Reset(self.pointer) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_DeleteSyncPoint", error)),
+                error => Err(err_fmod!("FMOD_DSP_Reset", error)),
             }
         }
     }
-    pub fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error> {
+    pub fn set_callback(&self, callback: ffi::FMOD_DSP_CALLBACK) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Sound_SetMode(self.pointer, mode.into()) {
+            match ffi::FMOD_DSP_SetCallback(self.pointer, callback) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_SetMode", error)),
+                error => Err(err_fmod!("FMOD_DSP_SetCallback", error)),
             }
         }
     }
-    pub fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error> {
+    pub fn set_parameter_float(&self, index: i32, value: f32) -> Result<(), Error> {
         unsafe {
-            let mut mode = ffi::FMOD_MODE::default();
-            match ffi::FMOD_Sound_GetMode(self.pointer, &mut mode) {
-                ffi::FMOD_OK => Ok(mode),
-                error => Err(err_fmod!("FMOD_Sound_GetMode", error)),
+            match ffi::FMOD_DSP_SetParameterFloat(self.pointer, index, value) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_DSP_SetParameterFloat", error)),
             }
         }
     }
-    pub fn set_loop_count(&self, loopcount: i32) -> Result<(), Error> {
+    pub fn set_parameter_int(&self, index: i32, value: i32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Sound_SetLoopCount(self.pointer, loopcount) {
+            match ffi::FMOD_DSP_SetParameterInt(self.pointer, index, value) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_SetLoopCount", error)),
+                error => Err(err_fmod!("FMOD_DSP_SetParameterInt", error)),
             }
         }
     }
-    pub fn get_loop_count(&self) -> Result<i32, Error> {
+    pub fn set_parameter_bool(&self, index: i32, value: bool) -> Result<(), Error> {
         unsafe {
-            let mut loopcount = i32::default();
-            match ffi::FMOD_Sound_GetLoopCount(self.pointer, &mut loopcount) {
-                ffi::FMOD_OK => Ok(loopcount),
-                error => Err(err_fmod!("FMOD_Sound_GetLoopCount", error)),
+            match ffi::FMOD_DSP_SetParameterBool(self.pointer, index, from_bool!(value)) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_DSP_SetParameterBool", error)),
             }
         }
     }
-    pub fn set_loop_points(
+    pub fn set_parameter_data(
         &self,
-        loopstart: u32,
-        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
-        loopend: u32,
-        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+        index: i32,
+        data: *mut c_void,
+        length: u32,
     ) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Sound_SetLoopPoints(
-                self.pointer,
-                loopstart,
-                loopstarttype.into(),
-                loopend,
-                loopendtype.into(),
-            ) {
+            match ffi::FMOD_DSP_SetParameterData(self.pointer, index, data, length) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_SetLoopPoints", error)),
+                error => Err(err_fmod!("FMOD_DSP_SetParameterData", error)),
             }
         }
     }
-    pub fn get_loop_points(
+    pub fn get_parameter_float(
         &self,
-        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
-        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
-    ) -> Result<(u32, u32), Error> {
+        index: i32,
+        valuestrlen: i32,
+    ) -> Result<(f32, String), Error> {
         unsafe {
-            let mut loopstart = u32::default();
-            let mut loopend = u32::default();
-            match ffi::FMOD_Sound_GetLoopPoints(
+            let mut value = f32::default();
+            let mut valuestr = vec![0 as c_char; valuestrlen.max(1) as usize];
+            match ffi::FMOD_DSP_GetParameterFloat(
                 self.pointer,
-                &mut loopstart,
-                loopstarttype.into(),
-                &mut loopend,
-                loopendtype.into(),
+                index,
+                &mut value,
+                valuestr.as_mut_ptr(),
+                valuestr.len() as i32,
             ) {
-                ffi::FMOD_OK => Ok((loopstart, loopend)),
-                error => Err(err_fmod!("FMOD_Sound_GetLoopPoints", error)),
+                ffi::FMOD_OK => Ok((
+                    value,
+                    CStr::from_ptr(valuestr.as_ptr())
+                        .to_string_lossy()
+                        .into_owned(),
+                )),
+                error => Err(err_fmod!("FMOD_DSP_GetParameterFloat", error)),
             }
         }
     }
-    pub fn get_music_num_channels(&self) -> Result<i32, Error> {
+    pub fn get_parameter_int(&self, index: i32, valuestrlen: i32) -> Result<(i32, String), Error> {
         unsafe {
-            let mut numchannels = i32::default();
-            match ffi::FMOD_Sound_GetMusicNumChannels(self.pointer, &mut numchannels) {
-                ffi::FMOD_OK => Ok(numchannels),
-                error => Err(err_fmod!("FMOD_Sound_GetMusicNumChannels", error)),
-            }
-        }
-    }
-    pub fn set_music_channel_volume(&self, channel: i32, volume: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Sound_SetMusicChannelVolume(self.pointer, channel, volume) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_SetMusicChannelVolume", error)),
+            let mut value = i32::default();
+            let mut valuestr = vec![0 as c_char; valuestrlen.max(1) as usize];
+            match ffi::FMOD_DSP_GetParameterInt(
+                self.pointer,
+                index,
+                &mut value,
+                valuestr.as_mut_ptr(),
+                valuestr.len() as i32,
+            ) {
+                ffi::FMOD_OK => Ok((
+                    value,
+                    CStr::from_ptr(valuestr.as_ptr())
+                        .to_string_lossy()
+                        .into_owned(),
+                )),
+                error => Err(err_fmod!("FMOD_DSP_GetParameterInt", error)),
             }
         }
     }
-    pub fn get_music_channel_volume(&self, channel: i32) -> Result<f32, Error> {
+    pub fn get_parameter_bool(
+        &self,
+        index: i32,
+        valuestrlen: i32,
+    ) -> Result<(bool, String), Error> {
         unsafe {
-            let mut volume = f32::default();
-            match ffi::FMOD_Sound_GetMusicChannelVolume(self.pointer, channel, &mut volume) {
-                ffi::FMOD_OK => Ok(volume),
-                error => Err(err_fmod!("FMOD_Sound_GetMusicChannelVolume", error)),
+            let mut value = ffi::FMOD_BOOL::default();
+            let mut valuestr = vec![0 as c_char; valuestrlen.max(1) as usize];
+            match ffi::FMOD_DSP_GetParameterBool(
+                self.pointer,
+                index,
+                &mut value,
+                valuestr.as_mut_ptr(),
+                valuestr.len() as i32,
+            ) {
+                ffi::FMOD_OK => Ok((
+                    to_bool!(value),
+                    CStr::from_ptr(valuestr.as_ptr())
+                        .to_string_lossy()
+                        .into_owned(),
+                )),
+                error => Err(err_fmod!("FMOD_DSP_GetParameterBool", error)),
             }
         }
     }
-    pub fn set_music_speed(&self, speed: f32) -> Result<(), Error> {
+    pub fn get_parameter_data(
+        &self,
+        index: i32,
+        valuestrlen: i32,
+    ) -> Result<(*mut c_void, u32, String), Error> {
         unsafe {
-            match ffi::FMOD_Sound_SetMusicSpeed(self.pointer, speed) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_SetMusicSpeed", error)),
+            let mut data = null_mut();
+            let mut length = u32::default();
+            let mut valuestr = vec![0 as c_char; valuestrlen.max(1) as usize];
+            match ffi::FMOD_DSP_GetParameterData(
+                self.pointer,
+                index,
+                &mut data,
+                &mut length,
+                valuestr.as_mut_ptr(),
+                valuestr.len() as i32,
+            ) {
+                ffi::FMOD_OK => Ok((
+                    data,
+                    length,
+                    CStr::from_ptr(valuestr.as_ptr())
+                        .to_string_lossy()
+                        .into_owned(),
+                )),
+                error => Err(err_fmod!("FMOD_DSP_GetParameterData", error)),
             }
         }
     }
-    pub fn get_music_speed(&self) -> Result<f32, Error> {
+    pub fn get_num_parameters(&self) -> Result<i32, Error> {
         unsafe {
-            let mut speed = f32::default();
-            match ffi::FMOD_Sound_GetMusicSpeed(self.pointer, &mut speed) {
-                ffi::FMOD_OK => Ok(speed),
-                error => Err(err_fmod!("FMOD_Sound_GetMusicSpeed", error)),
+            let mut numparams = i32::default();
+            match ffi::FMOD_DSP_GetNumParameters(self.pointer, &mut numparams) {
+                ffi::FMOD_OK => Ok(numparams),
+                error => Err(err_fmod!("FMOD_DSP_GetNumParameters", error)),
             }
         }
     }
-    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+    pub fn get_parameter_info(&self, index: i32) -> Result<DspParameterDesc, Error> {
         unsafe {
-            match ffi::FMOD_Sound_SetUserData(self.pointer, userdata) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Sound_SetUserData", error)),
+            let mut desc = null_mut();
+            match ffi::FMOD_DSP_GetParameterInfo(self.pointer, index, &mut desc) {
+                ffi::FMOD_OK => Ok(DspParameterDesc::try_from(*desc)?),
+                error => Err(err_fmod!("FMOD_DSP_GetParameterInfo", error)),
             }
         }
     }
-    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+    pub fn get_data_parameter_index(&self, datatype: i32) -> Result<i32, Error> {
         unsafe {
-            let mut userdata = null_mut();
-            match ffi::FMOD_Sound_GetUserData(self.pointer, &mut userdata) {
-                ffi::FMOD_OK => Ok(userdata),
-                error => Err(err_fmod!("FMOD_Sound_GetUserData", error)),
+            let mut index = i32::default();
+            match ffi::FMOD_DSP_GetDataParameterIndex(self.pointer, datatype, &mut index) {
+                ffi::FMOD_OK => Ok(index),
+                error => Err(err_fmod!("FMOD_DSP_GetDataParameterIndex", error)),
             }
         }
     }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct SoundGroup {
-    pointer: *mut ffi::FMOD_SOUNDGROUP,
-}
-unsafe impl Send for SoundGroup {}
-unsafe impl Sync for SoundGroup {}
-impl SoundGroup {
-    #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_SOUNDGROUP) -> Self {
-        Self { pointer }
-    }
-    #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_SOUNDGROUP {
-        self.pointer
-    }
-    pub fn release(&self) -> Result<(), Error> {
+    pub fn show_config_dialog(&self, hwnd: *mut c_void, show: bool) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_SoundGroup_Release(self.pointer) {
+            match ffi::FMOD_DSP_ShowConfigDialog(self.pointer, hwnd, from_bool!(show)) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_SoundGroup_Release", error)),
+                error => Err(err_fmod!("FMOD_DSP_ShowConfigDialog", error)),
             }
         }
     }
-    pub fn get_system_object(&self) -> Result<System, Error> {
+    pub fn get_info(&self) -> Result<(String, u32, i32, i32, i32), Error> {
         unsafe {
-            let mut system = null_mut();
-            match ffi::FMOD_SoundGroup_GetSystemObject(self.pointer, &mut system) {
-                ffi::FMOD_OK => Ok(System::from(system)),
-                error => Err(err_fmod!("FMOD_SoundGroup_GetSystemObject", error)),
+            let mut name = [0 as c_char; 32];
+            let mut version = u32::default();
+            let mut channels = i32::default();
+            let mut configwidth = i32::default();
+            let mut configheight = i32::default();
+            match ffi::FMOD_DSP_GetInfo(
+                self.pointer,
+                name.as_mut_ptr(),
+                &mut version,
+                &mut channels,
+                &mut configwidth,
+                &mut configheight,
+            ) {
+                ffi::FMOD_OK => Ok((
+                    CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned(),
+                    version,
+                    channels,
+                    configwidth,
+                    configheight,
+                )),
+                error => Err(err_fmod!("FMOD_DSP_GetInfo", error)),
             }
         }
     }
-    pub fn set_max_audible(&self, maxaudible: i32) -> Result<(), Error> {
+    pub fn get_type(&self) -> Result<DspType, Error> {
         unsafe {
-            match ffi::FMOD_SoundGroup_SetMaxAudible(self.pointer, maxaudible) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_SoundGroup_SetMaxAudible", error)),
+            let mut type_ = ffi::FMOD_DSP_TYPE::default();
+            match ffi::FMOD_DSP_GetType(self.pointer, &mut type_) {
+                ffi::FMOD_OK => Ok(DspType::from(type_)?),
+                error => Err(err_fmod!("FMOD_DSP_GetType", error)),
             }
         }
     }
-    pub fn get_max_audible(&self) -> Result<i32, Error> {
+    pub fn get_idle(&self) -> Result<bool, Error> {
         unsafe {
-            let mut maxaudible = i32::default();
-            match ffi::FMOD_SoundGroup_GetMaxAudible(self.pointer, &mut maxaudible) {
-                ffi::FMOD_OK => Ok(maxaudible),
-                error => Err(err_fmod!("FMOD_SoundGroup_GetMaxAudible", error)),
+            let mut idle = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_DSP_GetIdle(self.pointer, &mut idle) {
+                ffi::FMOD_OK => Ok(to_bool!(idle)),
+                error => Err(err_fmod!("FMOD_DSP_GetIdle", error)),
             }
         }
     }
-    pub fn set_max_audible_behavior(&self, behavior: SoundGroupBehavior) -> Result<(), Error> {
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_SoundGroup_SetMaxAudibleBehavior(self.pointer, behavior.into()) {
+            match ffi::FMOD_DSP_SetUserData(self.pointer, userdata) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_SoundGroup_SetMaxAudibleBehavior", error)),
+                error => Err(err_fmod!("FMOD_DSP_SetUserData", error)),
             }
         }
     }
-    pub fn get_max_audible_behavior(&self) -> Result<SoundGroupBehavior, Error> {
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
         unsafe {
-            let mut behavior = ffi::FMOD_SOUNDGROUP_BEHAVIOR::default();
-            match ffi::FMOD_SoundGroup_GetMaxAudibleBehavior(self.pointer, &mut behavior) {
-                ffi::FMOD_OK => Ok(SoundGroupBehavior::from(behavior)?),
-                error => Err(err_fmod!("FMOD_SoundGroup_GetMaxAudibleBehavior", error)),
+            let mut userdata = null_mut();
+            match ffi::FMOD_DSP_GetUserData(self.pointer, &mut userdata) {
+                ffi::FMOD_OK => Ok(userdata),
+                error => Err(err_fmod!("FMOD_DSP_GetUserData", error)),
             }
         }
     }
-    pub fn set_mute_fade_speed(&self, speed: f32) -> Result<(), Error> {
+    pub fn set_metering_enabled(
+        &self,
+        input_enabled: bool,
+        output_enabled: bool,
+    ) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_SoundGroup_SetMuteFadeSpeed(self.pointer, speed) {
+            match ffi::FMOD_DSP_SetMeteringEnabled(
+                self.pointer,
+                from_bool!(input_enabled),
+                from_bool!(output_enabled),
+            ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_SoundGroup_SetMuteFadeSpeed", error)),
+                error => Err(err_fmod!("FMOD_DSP_SetMeteringEnabled", error)),
             }
         }
     }
-    pub fn get_mute_fade_speed(&self) -> Result<f32, Error> {
+    pub fn get_metering_enabled(&self) -> Result<(bool, bool), Error> {
         unsafe {
-            let mut speed = f32::default();
-            match ffi::FMOD_SoundGroup_GetMuteFadeSpeed(self.pointer, &mut speed) {
-                ffi::FMOD_OK => Ok(speed),
-                error => Err(err_fmod!("FMOD_SoundGroup_GetMuteFadeSpeed", error)),
+            let mut input_enabled = ffi::FMOD_BOOL::default();
+            let mut output_enabled = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_DSP_GetMeteringEnabled(
+                self.pointer,
+                &mut input_enabled,
+                &mut output_enabled,
+            ) {
+                ffi::FMOD_OK => Ok((to_bool!(input_enabled), to_bool!(output_enabled))),
+                error => Err(err_fmod!("FMOD_DSP_GetMeteringEnabled", error)),
             }
         }
     }
-    pub fn set_volume(&self, volume: f32) -> Result<(), Error> {
+    pub fn get_metering_info(&self) -> Result<(DspMeteringInfo, DspMeteringInfo), Error> {
         unsafe {
-            match ffi::FMOD_SoundGroup_SetVolume(self.pointer, volume) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_SoundGroup_SetVolume", error)),
+            let mut input_info = ffi::FMOD_DSP_METERING_INFO::default();
+            let mut output_info = ffi::FMOD_DSP_METERING_INFO::default();
+            match ffi::FMOD_DSP_GetMeteringInfo(self.pointer, &mut input_info, &mut output_info) {
+                ffi::FMOD_OK => Ok((
+                    DspMeteringInfo::try_from(input_info)?,
+                    DspMeteringInfo::try_from(output_info)?,
+                )),
+                error => Err(err_fmod!("FMOD_DSP_GetMeteringInfo", error)),
             }
         }
     }
-    pub fn get_volume(&self) -> Result<f32, Error> {
+    pub fn get_cpu_usage(&self) -> Result<(u32, u32), Error> {
         unsafe {
-            let mut volume = f32::default();
-            match ffi::FMOD_SoundGroup_GetVolume(self.pointer, &mut volume) {
-                ffi::FMOD_OK => Ok(volume),
-                error => Err(err_fmod!("FMOD_SoundGroup_GetVolume", error)),
+            let mut exclusive = u32::default();
+            let mut inclusive = u32::default();
+            match ffi::FMOD_DSP_GetCPUUsage(self.pointer, &mut exclusive, &mut inclusive) {
+                ffi::FMOD_OK => Ok((exclusive, inclusive)),
+                error => Err(err_fmod!("FMOD_DSP_GetCPUUsage", error)),
             }
         }
     }
-    pub fn stop(&self) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_SoundGroup_Stop(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_SoundGroup_Stop", error)),
-            }
-        }
+}
+#[doc = " Methods of [`Dsp`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait DspApi {
+    fn release(&self) -> Result<(), Error>;
+    fn get_system_object(&self) -> Result<System, Error>;
+    fn add_input(&self, input: Dsp, type_: DspConnectionType) -> Result<DspConnection, Error>;
+    fn disconnect_from(
+        &self,
+        target: Option<Dsp>,
+        connection: Option<DspConnection>,
+    ) -> Result<(), Error>;
+    fn disconnect_all(&self, inputs: bool, outputs: bool) -> Result<(), Error>;
+    fn get_num_inputs(&self) -> Result<i32, Error>;
+    fn get_num_outputs(&self) -> Result<i32, Error>;
+    fn get_input(&self, index: i32) -> Result<(Dsp, DspConnection), Error>;
+    fn get_output(&self, index: i32) -> Result<(Dsp, DspConnection), Error>;
+    fn set_active(&self, active: bool) -> Result<(), Error>;
+    fn get_active(&self) -> Result<bool, Error>;
+    fn set_bypass(&self, bypass: bool) -> Result<(), Error>;
+    fn get_bypass(&self) -> Result<bool, Error>;
+    fn set_wet_dry_mix(&self, prewet: f32, postwet: f32, dry: f32) -> Result<(), Error>;
+    fn get_wet_dry_mix(&self) -> Result<(f32, f32, f32), Error>;
+    fn set_channel_format(
+        &self,
+        channelmask: impl Into<ffi::FMOD_CHANNELMASK>,
+        numchannels: i32,
+        source_speakermode: SpeakerMode,
+    ) -> Result<(), Error>;
+    fn get_channel_format(&self) -> Result<(ffi::FMOD_CHANNELMASK, i32, SpeakerMode), Error>;
+    fn get_output_channel_format(
+        &self,
+        inmask: impl Into<ffi::FMOD_CHANNELMASK>,
+        inchannels: i32,
+        inspeakermode: SpeakerMode,
+    ) -> Result<(ffi::FMOD_CHANNELMASK, i32, SpeakerMode), Error>;
+    fn reset(&self) -> Result<(), Error>;
+    fn set_callback(&self, callback: ffi::FMOD_DSP_CALLBACK) -> Result<(), Error>;
+    fn set_parameter_float(&self, index: i32, value: f32) -> Result<(), Error>;
+    fn set_parameter_int(&self, index: i32, value: i32) -> Result<(), Error>;
+    fn set_parameter_bool(&self, index: i32, value: bool) -> Result<(), Error>;
+    fn set_parameter_data(&self, index: i32, data: *mut c_void, length: u32) -> Result<(), Error>;
+    fn get_parameter_float(&self, index: i32, valuestrlen: i32) -> Result<(f32, String), Error>;
+    fn get_parameter_int(&self, index: i32, valuestrlen: i32) -> Result<(i32, String), Error>;
+    fn get_parameter_bool(&self, index: i32, valuestrlen: i32) -> Result<(bool, String), Error>;
+    fn get_parameter_data(
+        &self,
+        index: i32,
+        valuestrlen: i32,
+    ) -> Result<(*mut c_void, u32, String), Error>;
+    fn get_num_parameters(&self) -> Result<i32, Error>;
+    fn get_parameter_info(&self, index: i32) -> Result<DspParameterDesc, Error>;
+    fn get_data_parameter_index(&self, datatype: i32) -> Result<i32, Error>;
+    fn show_config_dialog(&self, hwnd: *mut c_void, show: bool) -> Result<(), Error>;
+    fn get_info(&self) -> Result<(String, u32, i32, i32, i32), Error>;
+    fn get_type(&self) -> Result<DspType, Error>;
+    fn get_idle(&self) -> Result<bool, Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+    fn set_metering_enabled(&self, input_enabled: bool, output_enabled: bool) -> Result<(), Error>;
+    fn get_metering_enabled(&self) -> Result<(bool, bool), Error>;
+    fn get_metering_info(&self) -> Result<(DspMeteringInfo, DspMeteringInfo), Error>;
+    fn get_cpu_usage(&self) -> Result<(u32, u32), Error>;
+}
+impl DspApi for Dsp {
+    #[inline]
+    fn release(&self) -> Result<(), Error> {
+        Dsp::release(self)
     }
-    pub fn get_name(&self, namelen: i32) -> Result<String, Error> {
-        unsafe {
-            let name = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            match ffi::FMOD_SoundGroup_GetName(self.pointer, name, namelen) {
-                ffi::FMOD_OK => Ok(CString::from_raw(name)
-                    .into_string()
-                    .map_err(Error::String)?),
-                error => Err(err_fmod!("FMOD_SoundGroup_GetName", error)),
-            }
-        }
+    #[inline]
+    fn get_system_object(&self) -> Result<System, Error> {
+        Dsp::get_system_object(self)
     }
-    pub fn get_num_sounds(&self) -> Result<i32, Error> {
-        unsafe {
-            let mut numsounds = i32::default();
-            match ffi::FMOD_SoundGroup_GetNumSounds(self.pointer, &mut numsounds) {
-                ffi::FMOD_OK => Ok(numsounds),
-                error => Err(err_fmod!("FMOD_SoundGroup_GetNumSounds", error)),
-            }
-        }
+    #[inline]
+    fn add_input(&self, input: Dsp, type_: DspConnectionType) -> Result<DspConnection, Error> {
+        Dsp::add_input(self, input, type_)
     }
-    pub fn get_sound(&self, index: i32) -> Result<Sound, Error> {
-        unsafe {
-            let mut sound = null_mut();
-            match ffi::FMOD_SoundGroup_GetSound(self.pointer, index, &mut sound) {
-                ffi::FMOD_OK => Ok(Sound::from(sound)),
-                error => Err(err_fmod!("FMOD_SoundGroup_GetSound", error)),
-            }
-        }
+    #[inline]
+    fn disconnect_from(
+        &self,
+        target: Option<Dsp>,
+        connection: Option<DspConnection>,
+    ) -> Result<(), Error> {
+        Dsp::disconnect_from(self, target, connection)
     }
-    pub fn get_num_playing(&self) -> Result<i32, Error> {
-        unsafe {
-            let mut numplaying = i32::default();
-            match ffi::FMOD_SoundGroup_GetNumPlaying(self.pointer, &mut numplaying) {
-                ffi::FMOD_OK => Ok(numplaying),
-                error => Err(err_fmod!("FMOD_SoundGroup_GetNumPlaying", error)),
-            }
-        }
+    #[inline]
+    fn disconnect_all(&self, inputs: bool, outputs: bool) -> Result<(), Error> {
+        Dsp::disconnect_all(self, inputs, outputs)
     }
-    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_SoundGroup_SetUserData(self.pointer, userdata) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_SoundGroup_SetUserData", error)),
-            }
-        }
+    #[inline]
+    fn get_num_inputs(&self) -> Result<i32, Error> {
+        Dsp::get_num_inputs(self)
     }
-    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
-        unsafe {
-            let mut userdata = null_mut();
-            match ffi::FMOD_SoundGroup_GetUserData(self.pointer, &mut userdata) {
-                ffi::FMOD_OK => Ok(userdata),
-                error => Err(err_fmod!("FMOD_SoundGroup_GetUserData", error)),
-            }
-        }
+    #[inline]
+    fn get_num_outputs(&self) -> Result<i32, Error> {
+        Dsp::get_num_outputs(self)
+    }
+    #[inline]
+    fn get_input(&self, index: i32) -> Result<(Dsp, DspConnection), Error> {
+        Dsp::get_input(self, index)
+    }
+    #[inline]
+    fn get_output(&self, index: i32) -> Result<(Dsp, DspConnection), Error> {
+        Dsp::get_output(self, index)
+    }
+    #[inline]
+    fn set_active(&self, active: bool) -> Result<(), Error> {
+        Dsp::set_active(self, active)
+    }
+    #[inline]
+    fn get_active(&self) -> Result<bool, Error> {
+        Dsp::get_active(self)
+    }
+    #[inline]
+    fn set_bypass(&self, bypass: bool) -> Result<(), Error> {
+        Dsp::set_bypass(self, bypass)
+    }
+    #[inline]
+    fn get_bypass(&self) -> Result<bool, Error> {
+        Dsp::get_bypass(self)
+    }
+    #[inline]
+    fn set_wet_dry_mix(&self, prewet: f32, postwet: f32, dry: f32) -> Result<(), Error> {
+        Dsp::set_wet_dry_mix(self, prewet, postwet, dry)
+    }
+    #[inline]
+    fn get_wet_dry_mix(&self) -> Result<(f32, f32, f32), Error> {
+        Dsp::get_wet_dry_mix(self)
+    }
+    #[inline]
+    fn set_channel_format(
+        &self,
+        channelmask: impl Into<ffi::FMOD_CHANNELMASK>,
+        numchannels: i32,
+        source_speakermode: SpeakerMode,
+    ) -> Result<(), Error> {
+        Dsp::set_channel_format(self, channelmask, numchannels, source_speakermode)
+    }
+    #[inline]
+    fn get_channel_format(&self) -> Result<(ffi::FMOD_CHANNELMASK, i32, SpeakerMode), Error> {
+        Dsp::get_channel_format(self)
+    }
+    #[inline]
+    fn get_output_channel_format(
+        &self,
+        inmask: impl Into<ffi::FMOD_CHANNELMASK>,
+        inchannels: i32,
+        inspeakermode: SpeakerMode,
+    ) -> Result<(ffi::FMOD_CHANNELMASK, i32, SpeakerMode), Error> {
+        Dsp::get_output_channel_format(self, inmask, inchannels, inspeakermode)
+    }
+    #[inline]
+    fn reset(&self) -> Result<(), Error> {
+        Dsp::reset(self)
+    }
+    #[inline]
+    fn set_callback(&self, callback: ffi::FMOD_DSP_CALLBACK) -> Result<(), Error> {
+        Dsp::set_callback(self, callback)
+    }
+    #[inline]
+    fn set_parameter_float(&self, index: i32, value: f32) -> Result<(), Error> {
+        Dsp::set_parameter_float(self, index, value)
+    }
+    #[inline]
+    fn set_parameter_int(&self, index: i32, value: i32) -> Result<(), Error> {
+        Dsp::set_parameter_int(self, index, value)
+    }
+    #[inline]
+    fn set_parameter_bool(&self, index: i32, value: bool) -> Result<(), Error> {
+        Dsp::set_parameter_bool(self, index, value)
+    }
+    #[inline]
+    fn set_parameter_data(&self, index: i32, data: *mut c_void, length: u32) -> Result<(), Error> {
+        Dsp::set_parameter_data(self, index, data, length)
+    }
+    #[inline]
+    fn get_parameter_float(&self, index: i32, valuestrlen: i32) -> Result<(f32, String), Error> {
+        Dsp::get_parameter_float(self, index, valuestrlen)
+    }
+    #[inline]
+    fn get_parameter_int(&self, index: i32, valuestrlen: i32) -> Result<(i32, String), Error> {
+        Dsp::get_parameter_int(self, index, valuestrlen)
+    }
+    #[inline]
+    fn get_parameter_bool(&self, index: i32, valuestrlen: i32) -> Result<(bool, String), Error> {
+        Dsp::get_parameter_bool(self, index, valuestrlen)
+    }
+    #[inline]
+    fn get_parameter_data(
+        &self,
+        index: i32,
+        valuestrlen: i32,
+    ) -> Result<(*mut c_void, u32, String), Error> {
+        Dsp::get_parameter_data(self, index, valuestrlen)
+    }
+    #[inline]
+    fn get_num_parameters(&self) -> Result<i32, Error> {
+        Dsp::get_num_parameters(self)
+    }
+    #[inline]
+    fn get_parameter_info(&self, index: i32) -> Result<DspParameterDesc, Error> {
+        Dsp::get_parameter_info(self, index)
+    }
+    #[inline]
+    fn get_data_parameter_index(&self, datatype: i32) -> Result<i32, Error> {
+        Dsp::get_data_parameter_index(self, datatype)
+    }
+    #[inline]
+    fn show_config_dialog(&self, hwnd: *mut c_void, show: bool) -> Result<(), Error> {
+        Dsp::show_config_dialog(self, hwnd, show)
+    }
+    #[inline]
+    fn get_info(&self) -> Result<(String, u32, i32, i32, i32), Error> {
+        Dsp::get_info(self)
+    }
+    #[inline]
+    fn get_type(&self) -> Result<DspType, Error> {
+        Dsp::get_type(self)
+    }
+    #[inline]
+    fn get_idle(&self) -> Result<bool, Error> {
+        Dsp::get_idle(self)
+    }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        Dsp::set_user_data(self, userdata)
+    }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        Dsp::get_user_data(self)
+    }
+    #[inline]
+    fn set_metering_enabled(&self, input_enabled: bool, output_enabled: bool) -> Result<(), Error> {
+        Dsp::set_metering_enabled(self, input_enabled, output_enabled)
+    }
+    #[inline]
+    fn get_metering_enabled(&self) -> Result<(bool, bool), Error> {
+        Dsp::get_metering_enabled(self)
+    }
+    #[inline]
+    fn get_metering_info(&self) -> Result<(DspMeteringInfo, DspMeteringInfo), Error> {
+        Dsp::get_metering_info(self)
+    }
+    #[inline]
+    fn get_cpu_usage(&self) -> Result<(u32, u32), Error> {
+        Dsp::get_cpu_usage(self)
     }
 }
 #[derive(Debug, Clone, Copy)]
-pub struct Bank {
-    pointer: *mut ffi::FMOD_STUDIO_BANK,
+pub struct DspConnection {
+    pointer: *mut ffi::FMOD_DSPCONNECTION,
 }
-unsafe impl Send for Bank {}
-unsafe impl Sync for Bank {}
-impl Bank {
+unsafe impl Send for DspConnection {}
+unsafe impl Sync for DspConnection {}
+impl DspConnection {
     #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_STUDIO_BANK) -> Self {
+    pub fn from(pointer: *mut ffi::FMOD_DSPCONNECTION) -> Self {
         Self { pointer }
     }
     #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_BANK {
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_DSPCONNECTION {
         self.pointer
     }
-    pub fn is_valid(&self) -> bool {
-        unsafe { to_bool!(ffi::FMOD_Studio_Bank_IsValid(self.pointer)) }
-    }
-    pub fn get_id(&self) -> Result<Guid, Error> {
+    pub fn get_input(&self) -> Result<Dsp, Error> {
         unsafe {
-            let mut id = ffi::FMOD_GUID::default();
-            match ffi::FMOD_Studio_Bank_GetID(self.pointer, &mut id) {
-                ffi::FMOD_OK => Ok(Guid::try_from(id)?),
-                error => Err(err_fmod!("FMOD_Studio_Bank_GetID", error)),
+            let mut input = null_mut();
+            match ffi::FMOD_DSPConnection_GetInput(self.pointer, &mut input) {
+                ffi::FMOD_OK => Ok(Dsp::from(input)),
+                error => Err(err_fmod!("FMOD_DSPConnection_GetInput", error)),
             }
         }
     }
-    pub fn get_path(&self) -> Result<String, Error> {
+    pub fn get_output(&self) -> Result<Dsp, Error> {
         unsafe {
-            let mut retrieved = i32::default();
-            match ffi::FMOD_Studio_Bank_GetPath(self.pointer, null_mut(), 0, &mut retrieved) {
-                ffi::FMOD_OK => {
-                    let mut buf = vec![0u8; retrieved as usize];
-                    match ffi::FMOD_Studio_Bank_GetPath(
-                        self.pointer,
-                        buf.as_mut_ptr() as *mut _,
-                        retrieved,
-                        &mut retrieved,
-                    ) {
-                        ffi::FMOD_OK => Ok(CString::from_vec_with_nul_unchecked(buf)
-                            .into_string()
-                            .map_err(Error::String)?),
-                        error => Err(err_fmod!("FMOD_Studio_Bank_GetPath", error)),
-                    }
-                }
-                error => Err(err_fmod!("FMOD_Studio_Bank_GetPath", error)),
+            let mut output = null_mut();
+            match ffi::FMOD_DSPConnection_GetOutput(self.pointer, &mut output) {
+                ffi::FMOD_OK => Ok(Dsp::from(output)),
+                error => Err(err_fmod!("FMOD_DSPConnection_GetOutput", error)),
             }
         }
     }
-    pub fn unload(&self) -> Result<(), Error> {
+    pub fn set_mix(&self, volume: f32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_Bank_Unload(self.pointer) {
+            match ffi::FMOD_DSPConnection_SetMix(self.pointer, volume) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_Bank_Unload", error)),
+                error => Err(err_fmod!("FMOD_DSPConnection_SetMix", error)),
             }
         }
     }
-    pub fn load_sample_data(&self) -> Result<(), Error> {
+    pub fn get_mix(&self) -> Result<f32, Error> {
         unsafe {
-            match ffi::FMOD_Studio_Bank_LoadSampleData(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_Bank_LoadSampleData", error)),
+            let mut volume = f32::default();
+            match ffi::FMOD_DSPConnection_GetMix(self.pointer, &mut volume) {
+                ffi::FMOD_OK => Ok(volume),
+                error => Err(err_fmod!("FMOD_DSPConnection_GetMix", error)),
             }
         }
     }
-    pub fn unload_sample_data(&self) -> Result<(), Error> {
+    pub fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_Bank_UnloadSampleData(self.pointer) {
+            match ffi::FMOD_DSPConnection_SetMixMatrix(
+                self.pointer,
+                matrix.as_slice().as_ptr() as *mut f32,
+                matrix.out_channels() as i32,
+                matrix.in_channels() as i32,
+                0,
+            ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_Bank_UnloadSampleData", error)),
+                error => Err(err_fmod!("FMOD_DSPConnection_SetMixMatrix", error)),
             }
         }
     }
-    pub fn get_loading_state(&self) -> Result<LoadingState, Error> {
+    pub fn get_mix_matrix(&self) -> Result<MixMatrix, Error> {
         unsafe {
-            let mut state = ffi::FMOD_STUDIO_LOADING_STATE::default();
-            match ffi::FMOD_Studio_Bank_GetLoadingState(self.pointer, &mut state) {
-                ffi::FMOD_OK => Ok(LoadingState::from(state)?),
-                error => Err(err_fmod!("FMOD_Studio_Bank_GetLoadingState", error)),
+            let mut outchannels = i32::default();
+            let mut inchannels = i32::default();
+            match ffi::FMOD_DSPConnection_GetMixMatrix(
+                self.pointer,
+                null_mut(),
+                &mut outchannels,
+                &mut inchannels,
+                0,
+            ) {
+                ffi::FMOD_OK => {
+                    let mut matrix = MixMatrix::new(outchannels as usize, inchannels as usize);
+                    match ffi::FMOD_DSPConnection_GetMixMatrix(
+                        self.pointer,
+                        matrix.as_mut_ptr(),
+                        &mut outchannels,
+                        &mut inchannels,
+                        0,
+                    ) {
+                        ffi::FMOD_OK => Ok(matrix),
+                        error => Err(err_fmod!("FMOD_DSPConnection_GetMixMatrix", error)),
+                    }
+                }
+                error => Err(err_fmod!("FMOD_DSPConnection_GetMixMatrix", error)),
             }
         }
     }
-    pub fn get_sample_loading_state(&self) -> Result<LoadingState, Error> {
+    pub fn get_type(&self) -> Result<DspConnectionType, Error> {
         unsafe {
-            let mut state = ffi::FMOD_STUDIO_LOADING_STATE::default();
-            match ffi::FMOD_Studio_Bank_GetSampleLoadingState(self.pointer, &mut state) {
-                ffi::FMOD_OK => Ok(LoadingState::from(state)?),
-                error => Err(err_fmod!("FMOD_Studio_Bank_GetSampleLoadingState", error)),
+            let mut type_ = ffi::FMOD_DSPCONNECTION_TYPE::default();
+            match ffi::FMOD_DSPConnection_GetType(self.pointer, &mut type_) {
+                ffi::FMOD_OK => Ok(DspConnectionType::from(type_)?),
+                error => Err(err_fmod!("FMOD_DSPConnection_GetType", error)),
             }
         }
     }
-    pub fn get_string_count(&self) -> Result<i32, Error> {
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
         unsafe {
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_Bank_GetStringCount(self.pointer, &mut count) {
-                ffi::FMOD_OK => Ok(count),
-                error => Err(err_fmod!("FMOD_Studio_Bank_GetStringCount", error)),
+            match ffi::FMOD_DSPConnection_SetUserData(self.pointer, userdata) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_DSPConnection_SetUserData", error)),
             }
         }
     }
-    pub fn get_string_info(&self, index: i32, size: i32) -> Result<(Guid, String, i32), Error> {
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
         unsafe {
-            let mut id = ffi::FMOD_GUID::default();
-            let path = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            let mut retrieved = i32::default();
-            match ffi::FMOD_Studio_Bank_GetStringInfo(
-                self.pointer,
-                index,
-                &mut id,
-                path,
-                size,
-                &mut retrieved,
-            ) {
-                ffi::FMOD_OK => Ok((
-                    Guid::try_from(id)?,
-                    CString::from_raw(path)
-                        .into_string()
-                        .map_err(Error::String)?,
-                    retrieved,
-                )),
-                error => Err(err_fmod!("FMOD_Studio_Bank_GetStringInfo", error)),
+            let mut userdata = null_mut();
+            match ffi::FMOD_DSPConnection_GetUserData(self.pointer, &mut userdata) {
+                ffi::FMOD_OK => Ok(userdata),
+                error => Err(err_fmod!("FMOD_DSPConnection_GetUserData", error)),
             }
         }
     }
-    pub fn get_event_count(&self) -> Result<i32, Error> {
+}
+#[doc = " Methods of [`DspConnection`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait DspConnectionApi {
+    fn get_input(&self) -> Result<Dsp, Error>;
+    fn get_output(&self) -> Result<Dsp, Error>;
+    fn set_mix(&self, volume: f32) -> Result<(), Error>;
+    fn get_mix(&self) -> Result<f32, Error>;
+    fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error>;
+    fn get_mix_matrix(&self) -> Result<MixMatrix, Error>;
+    fn get_type(&self) -> Result<DspConnectionType, Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+}
+impl DspConnectionApi for DspConnection {
+    #[inline]
+    fn get_input(&self) -> Result<Dsp, Error> {
+        DspConnection::get_input(self)
+    }
+    #[inline]
+    fn get_output(&self) -> Result<Dsp, Error> {
+        DspConnection::get_output(self)
+    }
+    #[inline]
+    fn set_mix(&self, volume: f32) -> Result<(), Error> {
+        DspConnection::set_mix(self, volume)
+    }
+    #[inline]
+    fn get_mix(&self) -> Result<f32, Error> {
+        DspConnection::get_mix(self)
+    }
+    #[inline]
+    fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error> {
+        DspConnection::set_mix_matrix(self, matrix)
+    }
+    #[inline]
+    fn get_mix_matrix(&self) -> Result<MixMatrix, Error> {
+        DspConnection::get_mix_matrix(self)
+    }
+    #[inline]
+    fn get_type(&self) -> Result<DspConnectionType, Error> {
+        DspConnection::get_type(self)
+    }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        DspConnection::set_user_data(self, userdata)
+    }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        DspConnection::get_user_data(self)
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct Geometry {
+    pointer: *mut ffi::FMOD_GEOMETRY,
+}
+unsafe impl Send for Geometry {}
+unsafe impl Sync for Geometry {}
+impl Geometry {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_GEOMETRY) -> Self {
+        Self { pointer }
+    }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_GEOMETRY {
+        self.pointer
+    }
+    pub fn release(&self) -> Result<(), Error> {
         unsafe {
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_Bank_GetEventCount(self.pointer, &mut count) {
-                ffi::FMOD_OK => Ok(count),
-                error => Err(err_fmod!("FMOD_Studio_Bank_GetEventCount", error)),
+            match ffi::FMOD_Geometry_Release(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Geometry_Release", error)),
             }
         }
     }
-    pub fn get_event_list(&self, capacity: i32) -> Result<Vec<EventDescription>, Error> {
+    pub fn add_polygon(
+        &self,
+        directocclusion: f32,
+        reverbocclusion: f32,
+        doublesided: bool,
+        vertices: &[Vector],
+    ) -> Result<i32, Error> {
+        let vertices: Vec<ffi::FMOD_VECTOR> =
+            vertices.iter().map(|vertex| (*vertex).into()).collect();
         unsafe {
-            let mut array = vec![null_mut(); capacity as usize];
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_Bank_GetEventList(
+            let mut polygonindex = i32::default();
+            match ffi::FMOD_Geometry_AddPolygon(
                 self.pointer,
-                array.as_mut_ptr(),
-                capacity,
-                &mut count,
+                directocclusion,
+                reverbocclusion,
+                from_bool!(doublesided),
+                vertices.len() as i32,
+                vertices.as_ptr(),
+                &mut polygonindex,
             ) {
-                ffi::FMOD_OK => Ok(array
-                    .into_iter()
-                    .take(count as usize)
-                    .map(EventDescription::from)
-                    .collect()),
-                error => Err(err_fmod!("FMOD_Studio_Bank_GetEventList", error)),
+                ffi::FMOD_OK => Ok(polygonindex),
+                error => Err(err_fmod!("FMOD_Geometry_AddPolygon", error)),
             }
         }
     }
-    pub fn get_bus_count(&self) -> Result<i32, Error> {
+    pub fn get_num_polygons(&self) -> Result<i32, Error> {
         unsafe {
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_Bank_GetBusCount(self.pointer, &mut count) {
-                ffi::FMOD_OK => Ok(count),
-                error => Err(err_fmod!("FMOD_Studio_Bank_GetBusCount", error)),
+            let mut numpolygons = i32::default();
+            match ffi::FMOD_Geometry_GetNumPolygons(self.pointer, &mut numpolygons) {
+                ffi::FMOD_OK => Ok(numpolygons),
+                error => Err(err_fmod!("FMOD_Geometry_GetNumPolygons", error)),
             }
         }
     }
-    pub fn get_bus_list(&self, capacity: i32) -> Result<Vec<Bus>, Error> {
+    pub fn get_max_polygons(&self) -> Result<(i32, i32), Error> {
         unsafe {
-            let mut array = vec![null_mut(); capacity as usize];
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_Bank_GetBusList(
+            let mut maxpolygons = i32::default();
+            let mut maxvertices = i32::default();
+            match ffi::FMOD_Geometry_GetMaxPolygons(
                 self.pointer,
-                array.as_mut_ptr(),
-                capacity,
-                &mut count,
+                &mut maxpolygons,
+                &mut maxvertices,
             ) {
-                ffi::FMOD_OK => Ok(array
-                    .into_iter()
-                    .take(count as usize)
-                    .map(Bus::from)
-                    .collect()),
-                error => Err(err_fmod!("FMOD_Studio_Bank_GetBusList", error)),
+                ffi::FMOD_OK => Ok((maxpolygons, maxvertices)),
+                error => Err(err_fmod!("FMOD_Geometry_GetMaxPolygons", error)),
             }
         }
     }
-    pub fn get_vca_count(&self) -> Result<i32, Error> {
+    pub fn get_polygon_num_vertices(&self, index: i32) -> Result<i32, Error> {
         unsafe {
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_Bank_GetVCACount(self.pointer, &mut count) {
-                ffi::FMOD_OK => Ok(count),
-                error => Err(err_fmod!("FMOD_Studio_Bank_GetVCACount", error)),
+            let mut numvertices = i32::default();
+            match ffi::FMOD_Geometry_GetPolygonNumVertices(self.pointer, index, &mut numvertices) {
+                ffi::FMOD_OK => Ok(numvertices),
+                error => Err(err_fmod!("FMOD_Geometry_GetPolygonNumVertices", error)),
             }
         }
     }
-    pub fn get_vca_list(&self, capacity: i32) -> Result<Vec<Vca>, Error> {
+    pub fn set_polygon_vertex(
+        &self,
+        index: i32,
+        vertexindex: i32,
+        vertex: Vector,
+    ) -> Result<(), Error> {
         unsafe {
-            let mut array = vec![null_mut(); capacity as usize];
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_Bank_GetVCAList(
+            match ffi::FMOD_Geometry_SetPolygonVertex(
                 self.pointer,
-                array.as_mut_ptr(),
-                capacity,
-                &mut count,
+                index,
+                vertexindex,
+                &vertex.into(),
             ) {
-                ffi::FMOD_OK => Ok(array
-                    .into_iter()
-                    .take(count as usize)
-                    .map(Vca::from)
-                    .collect()),
-                error => Err(err_fmod!("FMOD_Studio_Bank_GetVCAList", error)),
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Geometry_SetPolygonVertex", error)),
             }
         }
     }
-    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+    pub fn get_polygon_vertex(&self, index: i32, vertexindex: i32) -> Result<Vector, Error> {
         unsafe {
-            let mut userdata = null_mut();
-            match ffi::FMOD_Studio_Bank_GetUserData(self.pointer, &mut userdata) {
-                ffi::FMOD_OK => Ok(userdata),
-                error => Err(err_fmod!("FMOD_Studio_Bank_GetUserData", error)),
+            let mut vertex = ffi::FMOD_VECTOR::default();
+            match ffi::FMOD_Geometry_GetPolygonVertex(self.pointer, index, vertexindex, &mut vertex)
+            {
+                ffi::FMOD_OK => Ok(Vector::try_from(vertex)?),
+                error => Err(err_fmod!("FMOD_Geometry_GetPolygonVertex", error)),
             }
         }
     }
-    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+    pub fn set_polygon_attributes(
+        &self,
+        index: i32,
+        directocclusion: f32,
+        reverbocclusion: f32,
+        doublesided: bool,
+    ) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_Bank_SetUserData(self.pointer, userdata) {
+            match ffi::FMOD_Geometry_SetPolygonAttributes(
+                self.pointer,
+                index,
+                directocclusion,
+                reverbocclusion,
+                from_bool!(doublesided),
+            ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_Bank_SetUserData", error)),
+                error => Err(err_fmod!("FMOD_Geometry_SetPolygonAttributes", error)),
             }
         }
     }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct Bus {
-    pointer: *mut ffi::FMOD_STUDIO_BUS,
-}
-unsafe impl Send for Bus {}
-unsafe impl Sync for Bus {}
-impl Bus {
-    #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_STUDIO_BUS) -> Self {
-        Self { pointer }
-    }
-    #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_BUS {
-        self.pointer
-    }
-    pub fn is_valid(&self) -> bool {
-        unsafe { to_bool!(ffi::FMOD_Studio_Bus_IsValid(self.pointer)) }
-    }
-    pub fn get_id(&self) -> Result<Guid, Error> {
+    pub fn get_polygon_attributes(&self, index: i32) -> Result<(f32, f32, bool), Error> {
         unsafe {
-            let mut id = ffi::FMOD_GUID::default();
-            match ffi::FMOD_Studio_Bus_GetID(self.pointer, &mut id) {
-                ffi::FMOD_OK => Ok(Guid::try_from(id)?),
-                error => Err(err_fmod!("FMOD_Studio_Bus_GetID", error)),
+            let mut directocclusion = f32::default();
+            let mut reverbocclusion = f32::default();
+            let mut doublesided = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Geometry_GetPolygonAttributes(
+                self.pointer,
+                index,
+                &mut directocclusion,
+                &mut reverbocclusion,
+                &mut doublesided,
+            ) {
+                ffi::FMOD_OK => Ok((directocclusion, reverbocclusion, to_bool!(doublesided))),
+                error => Err(err_fmod!("FMOD_Geometry_GetPolygonAttributes", error)),
             }
         }
     }
-    pub fn get_path(&self) -> Result<String, Error> {
+    pub fn set_active(&self, active: bool) -> Result<(), Error> {
         unsafe {
-            let mut retrieved = i32::default();
-            match ffi::FMOD_Studio_Bus_GetPath(self.pointer, null_mut(), 0, &mut retrieved) {
-                ffi::FMOD_OK => {
-                    let mut buf = vec![0u8; retrieved as usize];
-                    match ffi::FMOD_Studio_Bus_GetPath(
-                        self.pointer,
-                        buf.as_mut_ptr() as *mut _,
-                        retrieved,
-                        &mut retrieved,
-                    ) {
-                        ffi::FMOD_OK => Ok(CString::from_vec_with_nul_unchecked(buf)
-                            .into_string()
-                            .map_err(Error::String)?),
-                        error => Err(err_fmod!("FMOD_Studio_Bus_GetPath", error)),
-                    }
-                }
-                error => Err(err_fmod!("FMOD_Studio_Bus_GetPath", error)),
+            match ffi::FMOD_Geometry_SetActive(self.pointer, from_bool!(active)) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Geometry_SetActive", error)),
             }
         }
     }
-    pub fn get_volume(&self) -> Result<(f32, f32), Error> {
+    pub fn get_active(&self) -> Result<bool, Error> {
         unsafe {
-            let mut volume = f32::default();
-            let mut finalvolume = f32::default();
-            match ffi::FMOD_Studio_Bus_GetVolume(self.pointer, &mut volume, &mut finalvolume) {
-                ffi::FMOD_OK => Ok((volume, finalvolume)),
-                error => Err(err_fmod!("FMOD_Studio_Bus_GetVolume", error)),
+            let mut active = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Geometry_GetActive(self.pointer, &mut active) {
+                ffi::FMOD_OK => Ok(to_bool!(active)),
+                error => Err(err_fmod!("FMOD_Geometry_GetActive", error)),
             }
         }
     }
-    pub fn set_volume(&self, volume: f32) -> Result<(), Error> {
+    pub fn set_rotation(&self, forward: Option<Vector>, up: Option<Vector>) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_Bus_SetVolume(self.pointer, volume) {
+            match ffi::FMOD_Geometry_SetRotation(
+                self.pointer,
+                forward
+                    .map(Vector::into)
+                    .as_ref()
+                    .map(from_ref)
+                    .unwrap_or_else(null),
+                up.map(Vector::into)
+                    .as_ref()
+                    .map(from_ref)
+                    .unwrap_or_else(null),
+            ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_Bus_SetVolume", error)),
+                error => Err(err_fmod!("FMOD_Geometry_SetRotation", error)),
             }
         }
     }
-    pub fn get_paused(&self) -> Result<bool, Error> {
+    pub fn get_rotation(&self) -> Result<(Vector, Vector), Error> {
         unsafe {
-            let mut paused = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Studio_Bus_GetPaused(self.pointer, &mut paused) {
-                ffi::FMOD_OK => Ok(to_bool!(paused)),
-                error => Err(err_fmod!("FMOD_Studio_Bus_GetPaused", error)),
+            let mut forward = ffi::FMOD_VECTOR::default();
+            let mut up = ffi::FMOD_VECTOR::default();
+            match ffi::FMOD_Geometry_GetRotation(self.pointer, &mut forward, &mut up) {
+                ffi::FMOD_OK => Ok((Vector::try_from(forward)?, Vector::try_from(up)?)),
+                error => Err(err_fmod!("FMOD_Geometry_GetRotation", error)),
             }
         }
     }
-    pub fn set_paused(&self, paused: bool) -> Result<(), Error> {
+    pub fn set_position(&self, position: Vector) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_Bus_SetPaused(self.pointer, from_bool!(paused)) {
+            match ffi::FMOD_Geometry_SetPosition(self.pointer, &position.into()) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_Bus_SetPaused", error)),
+                error => Err(err_fmod!("FMOD_Geometry_SetPosition", error)),
             }
         }
     }
-    pub fn get_mute(&self) -> Result<bool, Error> {
+    pub fn get_position(&self) -> Result<Vector, Error> {
         unsafe {
-            let mut mute = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Studio_Bus_GetMute(self.pointer, &mut mute) {
-                ffi::FMOD_OK => Ok(to_bool!(mute)),
-                error => Err(err_fmod!("FMOD_Studio_Bus_GetMute", error)),
+            let mut position = ffi::FMOD_VECTOR::default();
+            match ffi::FMOD_Geometry_GetPosition(self.pointer, &mut position) {
+                ffi::FMOD_OK => Ok(Vector::try_from(position)?),
+                error => Err(err_fmod!("FMOD_Geometry_GetPosition", error)),
             }
         }
     }
-    pub fn set_mute(&self, mute: bool) -> Result<(), Error> {
+    pub fn set_scale(&self, scale: Vector) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_Bus_SetMute(self.pointer, from_bool!(mute)) {
+            match ffi::FMOD_Geometry_SetScale(self.pointer, &scale.into()) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_Bus_SetMute", error)),
+                error => Err(err_fmod!("FMOD_Geometry_SetScale", error)),
             }
         }
     }
-    pub fn stop_all_events(&self, mode: StopMode) -> Result<(), Error> {
+    pub fn get_scale(&self) -> Result<Vector, Error> {
         unsafe {
-            match ffi::FMOD_Studio_Bus_StopAllEvents(self.pointer, mode.into()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_Bus_StopAllEvents", error)),
+            let mut scale = ffi::FMOD_VECTOR::default();
+            match ffi::FMOD_Geometry_GetScale(self.pointer, &mut scale) {
+                ffi::FMOD_OK => Ok(Vector::try_from(scale)?),
+                error => Err(err_fmod!("FMOD_Geometry_GetScale", error)),
             }
         }
     }
-    pub fn get_port_index(&self) -> Result<ffi::FMOD_PORT_INDEX, Error> {
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
         unsafe {
-            let mut index = ffi::FMOD_PORT_INDEX::default();
-            match ffi::FMOD_Studio_Bus_GetPortIndex(self.pointer, &mut index) {
-                ffi::FMOD_OK => Ok(index),
-                error => Err(err_fmod!("FMOD_Studio_Bus_GetPortIndex", error)),
+            let mut datasize = i32::default();
+            match ffi::FMOD_Geometry_Save(self.pointer, null_mut(), &mut datasize) {
+                ffi::FMOD_OK => {
+                    let mut data = vec![0u8; datasize as usize];
+                    match ffi::FMOD_Geometry_Save(
+                        self.pointer,
+                        data.as_mut_ptr() as *mut c_void,
+                        &mut datasize,
+                    ) {
+                        ffi::FMOD_OK => Ok(data),
+                        error => Err(err_fmod!("FMOD_Geometry_Save", error)),
+                    }
+                }
+                error => Err(err_fmod!("FMOD_Geometry_Save", error)),
             }
         }
     }
-    pub fn set_port_index(&self, index: impl Into<ffi::FMOD_PORT_INDEX>) -> Result<(), Error> {
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_Bus_SetPortIndex(self.pointer, index.into()) {
+            match ffi::FMOD_Geometry_SetUserData(self.pointer, userdata) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_Bus_SetPortIndex", error)),
+                error => Err(err_fmod!("FMOD_Geometry_SetUserData", error)),
             }
         }
     }
-    pub fn lock_channel_group(&self) -> Result<(), Error> {
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
         unsafe {
-            match ffi::FMOD_Studio_Bus_LockChannelGroup(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_Bus_LockChannelGroup", error)),
+            let mut userdata = null_mut();
+            match ffi::FMOD_Geometry_GetUserData(self.pointer, &mut userdata) {
+                ffi::FMOD_OK => Ok(userdata),
+                error => Err(err_fmod!("FMOD_Geometry_GetUserData", error)),
             }
         }
     }
-    pub fn unlock_channel_group(&self) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Studio_Bus_UnlockChannelGroup(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_Bus_UnlockChannelGroup", error)),
-            }
-        }
+}
+#[doc = " Methods of [`Geometry`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait GeometryApi {
+    fn release(&self) -> Result<(), Error>;
+    fn add_polygon(
+        &self,
+        directocclusion: f32,
+        reverbocclusion: f32,
+        doublesided: bool,
+        vertices: &[Vector],
+    ) -> Result<i32, Error>;
+    fn get_num_polygons(&self) -> Result<i32, Error>;
+    fn get_max_polygons(&self) -> Result<(i32, i32), Error>;
+    fn get_polygon_num_vertices(&self, index: i32) -> Result<i32, Error>;
+    fn set_polygon_vertex(&self, index: i32, vertexindex: i32, vertex: Vector)
+        -> Result<(), Error>;
+    fn get_polygon_vertex(&self, index: i32, vertexindex: i32) -> Result<Vector, Error>;
+    fn set_polygon_attributes(
+        &self,
+        index: i32,
+        directocclusion: f32,
+        reverbocclusion: f32,
+        doublesided: bool,
+    ) -> Result<(), Error>;
+    fn get_polygon_attributes(&self, index: i32) -> Result<(f32, f32, bool), Error>;
+    fn set_active(&self, active: bool) -> Result<(), Error>;
+    fn get_active(&self) -> Result<bool, Error>;
+    fn set_rotation(&self, forward: Option<Vector>, up: Option<Vector>) -> Result<(), Error>;
+    fn get_rotation(&self) -> Result<(Vector, Vector), Error>;
+    fn set_position(&self, position: Vector) -> Result<(), Error>;
+    fn get_position(&self) -> Result<Vector, Error>;
+    fn set_scale(&self, scale: Vector) -> Result<(), Error>;
+    fn get_scale(&self) -> Result<Vector, Error>;
+    fn to_bytes(&self) -> Result<Vec<u8>, Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+}
+impl GeometryApi for Geometry {
+    #[inline]
+    fn release(&self) -> Result<(), Error> {
+        Geometry::release(self)
     }
-    pub fn get_channel_group(&self) -> Result<ChannelGroup, Error> {
-        unsafe {
-            let mut group = null_mut();
-            match ffi::FMOD_Studio_Bus_GetChannelGroup(self.pointer, &mut group) {
-                ffi::FMOD_OK => Ok(ChannelGroup::from(group)),
-                error => Err(err_fmod!("FMOD_Studio_Bus_GetChannelGroup", error)),
-            }
-        }
+    #[inline]
+    fn add_polygon(
+        &self,
+        directocclusion: f32,
+        reverbocclusion: f32,
+        doublesided: bool,
+        vertices: &[Vector],
+    ) -> Result<i32, Error> {
+        Geometry::add_polygon(
+            self,
+            directocclusion,
+            reverbocclusion,
+            doublesided,
+            vertices,
+        )
     }
-    pub fn get_cpu_usage(&self) -> Result<(u32, u32), Error> {
-        unsafe {
-            let mut exclusive = u32::default();
-            let mut inclusive = u32::default();
-            match ffi::FMOD_Studio_Bus_GetCPUUsage(self.pointer, &mut exclusive, &mut inclusive) {
-                ffi::FMOD_OK => Ok((exclusive, inclusive)),
-                error => Err(err_fmod!("FMOD_Studio_Bus_GetCPUUsage", error)),
-            }
-        }
+    #[inline]
+    fn get_num_polygons(&self) -> Result<i32, Error> {
+        Geometry::get_num_polygons(self)
     }
-    pub fn get_memory_usage(&self) -> Result<MemoryUsage, Error> {
-        unsafe {
-            let mut memoryusage = ffi::FMOD_STUDIO_MEMORY_USAGE::default();
-            match ffi::FMOD_Studio_Bus_GetMemoryUsage(self.pointer, &mut memoryusage) {
-                ffi::FMOD_OK => Ok(MemoryUsage::try_from(memoryusage)?),
-                error => Err(err_fmod!("FMOD_Studio_Bus_GetMemoryUsage", error)),
-            }
-        }
+    #[inline]
+    fn get_max_polygons(&self) -> Result<(i32, i32), Error> {
+        Geometry::get_max_polygons(self)
+    }
+    #[inline]
+    fn get_polygon_num_vertices(&self, index: i32) -> Result<i32, Error> {
+        Geometry::get_polygon_num_vertices(self, index)
+    }
+    #[inline]
+    fn set_polygon_vertex(
+        &self,
+        index: i32,
+        vertexindex: i32,
+        vertex: Vector,
+    ) -> Result<(), Error> {
+        Geometry::set_polygon_vertex(self, index, vertexindex, vertex)
+    }
+    #[inline]
+    fn get_polygon_vertex(&self, index: i32, vertexindex: i32) -> Result<Vector, Error> {
+        Geometry::get_polygon_vertex(self, index, vertexindex)
+    }
+    #[inline]
+    fn set_polygon_attributes(
+        &self,
+        index: i32,
+        directocclusion: f32,
+        reverbocclusion: f32,
+        doublesided: bool,
+    ) -> Result<(), Error> {
+        Geometry::set_polygon_attributes(self, index, directocclusion, reverbocclusion, doublesided)
+    }
+    #[inline]
+    fn get_polygon_attributes(&self, index: i32) -> Result<(f32, f32, bool), Error> {
+        Geometry::get_polygon_attributes(self, index)
+    }
+    #[inline]
+    fn set_active(&self, active: bool) -> Result<(), Error> {
+        Geometry::set_active(self, active)
+    }
+    #[inline]
+    fn get_active(&self) -> Result<bool, Error> {
+        Geometry::get_active(self)
+    }
+    #[inline]
+    fn set_rotation(&self, forward: Option<Vector>, up: Option<Vector>) -> Result<(), Error> {
+        Geometry::set_rotation(self, forward, up)
+    }
+    #[inline]
+    fn get_rotation(&self) -> Result<(Vector, Vector), Error> {
+        Geometry::get_rotation(self)
+    }
+    #[inline]
+    fn set_position(&self, position: Vector) -> Result<(), Error> {
+        Geometry::set_position(self, position)
+    }
+    #[inline]
+    fn get_position(&self) -> Result<Vector, Error> {
+        Geometry::get_position(self)
+    }
+    #[inline]
+    fn set_scale(&self, scale: Vector) -> Result<(), Error> {
+        Geometry::set_scale(self, scale)
+    }
+    #[inline]
+    fn get_scale(&self) -> Result<Vector, Error> {
+        Geometry::get_scale(self)
+    }
+    #[inline]
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Geometry::to_bytes(self)
+    }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        Geometry::set_user_data(self, userdata)
+    }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        Geometry::get_user_data(self)
     }
 }
 #[derive(Debug, Clone, Copy)]
-pub struct CommandReplay {
-    pointer: *mut ffi::FMOD_STUDIO_COMMANDREPLAY,
+pub struct Polygon {
+    pointer: *mut ffi::FMOD_POLYGON,
 }
-unsafe impl Send for CommandReplay {}
-unsafe impl Sync for CommandReplay {}
-impl CommandReplay {
+unsafe impl Send for Polygon {}
+unsafe impl Sync for Polygon {}
+impl Polygon {
     #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_STUDIO_COMMANDREPLAY) -> Self {
+    pub fn from(pointer: *mut ffi::FMOD_POLYGON) -> Self {
         Self { pointer }
     }
     #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_COMMANDREPLAY {
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_POLYGON {
         self.pointer
     }
-    pub fn is_valid(&self) -> bool {
-        unsafe { to_bool!(ffi::FMOD_Studio_CommandReplay_IsValid(self.pointer)) }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct Reverb3d {
+    pointer: *mut ffi::FMOD_REVERB3D,
+}
+unsafe impl Send for Reverb3d {}
+unsafe impl Sync for Reverb3d {}
+impl Reverb3d {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_REVERB3D) -> Self {
+        Self { pointer }
     }
-    pub fn get_system(&self) -> Result<Studio, Error> {
-        unsafe {
-            let mut system = null_mut();
-            match ffi::FMOD_Studio_CommandReplay_GetSystem(self.pointer, &mut system) {
-                ffi::FMOD_OK => Ok(Studio::from(system)),
-                error => Err(err_fmod!("FMOD_Studio_CommandReplay_GetSystem", error)),
-            }
-        }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_REVERB3D {
+        self.pointer
     }
-    pub fn get_length(&self) -> Result<f32, Error> {
+    pub fn release(&self) -> Result<(), Error> {
         unsafe {
-            let mut length = f32::default();
-            match ffi::FMOD_Studio_CommandReplay_GetLength(self.pointer, &mut length) {
-                ffi::FMOD_OK => Ok(length),
-                error => Err(err_fmod!("FMOD_Studio_CommandReplay_GetLength", error)),
+            match ffi::FMOD_Reverb3D_Release(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Reverb3D_Release", error)),
             }
         }
     }
-    pub fn get_command_count(&self) -> Result<i32, Error> {
+    pub fn set_3d_attributes(
+        &self,
+        position: Option<Vector>,
+        mindistance: f32,
+        maxdistance: f32,
+    ) -> Result<(), Error> {
         unsafe {
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_CommandReplay_GetCommandCount(self.pointer, &mut count) {
-                ffi::FMOD_OK => Ok(count),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_CommandReplay_GetCommandCount",
-                    error
-                )),
+            match ffi::FMOD_Reverb3D_Set3DAttributes(
+                self.pointer,
+                position
+                    .map(Vector::into)
+                    .as_ref()
+                    .map(from_ref)
+                    .unwrap_or_else(null),
+                mindistance,
+                maxdistance,
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Reverb3D_Set3DAttributes", error)),
             }
         }
     }
-    pub fn get_command_info(&self, commandindex: i32) -> Result<CommandInfo, Error> {
+    pub fn get_3d_attributes(&self) -> Result<(Vector, f32, f32), Error> {
         unsafe {
-            let mut info = ffi::FMOD_STUDIO_COMMAND_INFO::default();
-            match ffi::FMOD_Studio_CommandReplay_GetCommandInfo(
+            let mut position = ffi::FMOD_VECTOR::default();
+            let mut mindistance = f32::default();
+            let mut maxdistance = f32::default();
+            match ffi::FMOD_Reverb3D_Get3DAttributes(
                 self.pointer,
-                commandindex,
-                &mut info,
+                &mut position,
+                &mut mindistance,
+                &mut maxdistance,
             ) {
-                ffi::FMOD_OK => Ok(CommandInfo::try_from(info)?),
-                error => Err(err_fmod!("FMOD_Studio_CommandReplay_GetCommandInfo", error)),
+                ffi::FMOD_OK => Ok((Vector::try_from(position)?, mindistance, maxdistance)),
+                error => Err(err_fmod!("FMOD_Reverb3D_Get3DAttributes", error)),
             }
         }
     }
-    pub fn get_command_string(&self, commandindex: i32, length: i32) -> Result<String, Error> {
+    pub fn set_properties(&self, properties: ReverbProperties) -> Result<(), Error> {
         unsafe {
-            let buffer = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            match ffi::FMOD_Studio_CommandReplay_GetCommandString(
-                self.pointer,
-                commandindex,
-                buffer,
-                length,
-            ) {
-                ffi::FMOD_OK => Ok(CString::from_raw(buffer)
-                    .into_string()
-                    .map_err(Error::String)?),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_CommandReplay_GetCommandString",
-                    error
-                )),
+            match ffi::FMOD_Reverb3D_SetProperties(self.pointer, &properties.into()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Reverb3D_SetProperties", error)),
             }
         }
     }
-    pub fn get_command_at_time(&self, time: f32) -> Result<i32, Error> {
+    pub fn get_properties(&self) -> Result<ReverbProperties, Error> {
         unsafe {
-            let mut commandindex = i32::default();
-            match ffi::FMOD_Studio_CommandReplay_GetCommandAtTime(
-                self.pointer,
-                time,
-                &mut commandindex,
-            ) {
-                ffi::FMOD_OK => Ok(commandindex),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_CommandReplay_GetCommandAtTime",
-                    error
-                )),
+            let mut properties = ffi::FMOD_REVERB_PROPERTIES::default();
+            match ffi::FMOD_Reverb3D_GetProperties(self.pointer, &mut properties) {
+                ffi::FMOD_OK => Ok(ReverbProperties::try_from(properties)?),
+                error => Err(err_fmod!("FMOD_Reverb3D_GetProperties", error)),
             }
         }
     }
-    pub fn set_bank_path(&self, bank_path: &str) -> Result<(), Error> {
+    pub fn set_active(&self, active: bool) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_CommandReplay_SetBankPath(
-                self.pointer,
-                CString::new(bank_path)?.as_ptr(),
-            ) {
+            match ffi::FMOD_Reverb3D_SetActive(self.pointer, from_bool!(active)) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_CommandReplay_SetBankPath", error)),
+                error => Err(err_fmod!("FMOD_Reverb3D_SetActive", error)),
             }
         }
     }
-    pub fn start(&self) -> Result<(), Error> {
+    pub fn get_active(&self) -> Result<bool, Error> {
         unsafe {
-            match ffi::FMOD_Studio_CommandReplay_Start(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_CommandReplay_Start", error)),
+            let mut active = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Reverb3D_GetActive(self.pointer, &mut active) {
+                ffi::FMOD_OK => Ok(to_bool!(active)),
+                error => Err(err_fmod!("FMOD_Reverb3D_GetActive", error)),
             }
         }
     }
-    pub fn stop(&self) -> Result<(), Error> {
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_CommandReplay_Stop(self.pointer) {
+            match ffi::FMOD_Reverb3D_SetUserData(self.pointer, userdata) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_CommandReplay_Stop", error)),
+                error => Err(err_fmod!("FMOD_Reverb3D_SetUserData", error)),
             }
         }
     }
-    pub fn seek_to_time(&self, time: f32) -> Result<(), Error> {
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
         unsafe {
-            match ffi::FMOD_Studio_CommandReplay_SeekToTime(self.pointer, time) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_CommandReplay_SeekToTime", error)),
+            let mut userdata = null_mut();
+            match ffi::FMOD_Reverb3D_GetUserData(self.pointer, &mut userdata) {
+                ffi::FMOD_OK => Ok(userdata),
+                error => Err(err_fmod!("FMOD_Reverb3D_GetUserData", error)),
             }
         }
     }
-    pub fn seek_to_command(&self, commandindex: i32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Studio_CommandReplay_SeekToCommand(self.pointer, commandindex) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_CommandReplay_SeekToCommand", error)),
-            }
-        }
+}
+#[doc = " Methods of [`Reverb3d`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait Reverb3dApi {
+    fn release(&self) -> Result<(), Error>;
+    fn set_3d_attributes(
+        &self,
+        position: Option<Vector>,
+        mindistance: f32,
+        maxdistance: f32,
+    ) -> Result<(), Error>;
+    fn get_3d_attributes(&self) -> Result<(Vector, f32, f32), Error>;
+    fn set_properties(&self, properties: ReverbProperties) -> Result<(), Error>;
+    fn get_properties(&self) -> Result<ReverbProperties, Error>;
+    fn set_active(&self, active: bool) -> Result<(), Error>;
+    fn get_active(&self) -> Result<bool, Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+}
+impl Reverb3dApi for Reverb3d {
+    #[inline]
+    fn release(&self) -> Result<(), Error> {
+        Reverb3d::release(self)
     }
-    pub fn get_paused(&self) -> Result<bool, Error> {
-        unsafe {
-            let mut paused = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Studio_CommandReplay_GetPaused(self.pointer, &mut paused) {
-                ffi::FMOD_OK => Ok(to_bool!(paused)),
-                error => Err(err_fmod!("FMOD_Studio_CommandReplay_GetPaused", error)),
-            }
-        }
+    #[inline]
+    fn set_3d_attributes(
+        &self,
+        position: Option<Vector>,
+        mindistance: f32,
+        maxdistance: f32,
+    ) -> Result<(), Error> {
+        Reverb3d::set_3d_attributes(self, position, mindistance, maxdistance)
     }
-    pub fn set_paused(&self, paused: bool) -> Result<(), Error> {
+    #[inline]
+    fn get_3d_attributes(&self) -> Result<(Vector, f32, f32), Error> {
+        Reverb3d::get_3d_attributes(self)
+    }
+    #[inline]
+    fn set_properties(&self, properties: ReverbProperties) -> Result<(), Error> {
+        Reverb3d::set_properties(self, properties)
+    }
+    #[inline]
+    fn get_properties(&self) -> Result<ReverbProperties, Error> {
+        Reverb3d::get_properties(self)
+    }
+    #[inline]
+    fn set_active(&self, active: bool) -> Result<(), Error> {
+        Reverb3d::set_active(self, active)
+    }
+    #[inline]
+    fn get_active(&self) -> Result<bool, Error> {
+        Reverb3d::get_active(self)
+    }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        Reverb3d::set_user_data(self, userdata)
+    }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        Reverb3d::get_user_data(self)
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct Sound {
+    pointer: *mut ffi::FMOD_SOUND,
+}
+unsafe impl Send for Sound {}
+unsafe impl Sync for Sound {}
+impl Sound {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_SOUND) -> Self {
+        Self { pointer }
+    }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_SOUND {
+        self.pointer
+    }
+    pub fn release(&self) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_CommandReplay_SetPaused(self.pointer, from_bool!(paused)) {
+            match ffi::FMOD_Sound_Release(self.pointer) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_CommandReplay_SetPaused", error)),
+                error => Err(err_fmod!("FMOD_Sound_Release", error)),
             }
         }
     }
-    pub fn get_playback_state(&self) -> Result<PlaybackState, Error> {
+    pub fn get_system_object(&self) -> Result<System, Error> {
         unsafe {
-            let mut state = ffi::FMOD_STUDIO_PLAYBACK_STATE::default();
-            match ffi::FMOD_Studio_CommandReplay_GetPlaybackState(self.pointer, &mut state) {
-                ffi::FMOD_OK => Ok(PlaybackState::from(state)?),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_CommandReplay_GetPlaybackState",
-                    error
-                )),
+            let mut system = null_mut();
+            match ffi::FMOD_Sound_GetSystemObject(self.pointer, &mut system) {
+                ffi::FMOD_OK => Ok(System::from(system)),
+                error => Err(err_fmod!("FMOD_Sound_GetSystemObject", error)),
             }
         }
     }
-    pub fn get_current_command(&self) -> Result<(i32, f32), Error> {
+    pub fn lock(
+        &self,
+        offset: u32,
+        length: u32,
+    ) -> Result<(*mut c_void, *mut c_void, u32, u32), Error> {
         unsafe {
-            let mut commandindex = i32::default();
-            let mut currenttime = f32::default();
-            match ffi::FMOD_Studio_CommandReplay_GetCurrentCommand(
+            let mut ptr_1 = null_mut();
+            let mut ptr_2 = null_mut();
+            let mut len_1 = u32::default();
+            let mut len_2 = u32::default();
+            match ffi::FMOD_Sound_Lock(
                 self.pointer,
-                &mut commandindex,
-                &mut currenttime,
+                offset,
+                length,
+                &mut ptr_1,
+                &mut ptr_2,
+                &mut len_1,
+                &mut len_2,
             ) {
-                ffi::FMOD_OK => Ok((commandindex, currenttime)),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_CommandReplay_GetCurrentCommand",
-                    error
-                )),
+                ffi::FMOD_OK => Ok((ptr_1, ptr_2, len_1, len_2)),
+                error => Err(err_fmod!("FMOD_Sound_Lock", error)),
             }
         }
     }
-    pub fn release(&self) -> Result<(), Error> {
+    pub fn unlock(
+        &self,
+        ptr_1: *mut c_void,
+        ptr_2: *mut c_void,
+        len_1: u32,
+        len_2: u32,
+    ) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_CommandReplay_Release(self.pointer) {
+            match ffi::FMOD_Sound_Unlock(self.pointer, ptr_1, ptr_2, len_1, len_2) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_CommandReplay_Release", error)),
+                error => Err(err_fmod!("FMOD_Sound_Unlock", error)),
             }
         }
     }
-    pub fn set_frame_callback(
-        &self,
-        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_FRAME_CALLBACK,
-    ) -> Result<(), Error> {
+    pub fn set_defaults(&self, frequency: f32, priority: i32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_CommandReplay_SetFrameCallback(self.pointer, callback) {
+            match ffi::FMOD_Sound_SetDefaults(self.pointer, frequency, priority) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_CommandReplay_SetFrameCallback",
-                    error
-                )),
+                error => Err(err_fmod!("FMOD_Sound_SetDefaults", error)),
             }
         }
     }
-    pub fn set_load_bank_callback(
-        &self,
-        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_LOAD_BANK_CALLBACK,
-    ) -> Result<(), Error> {
+    pub fn get_defaults(&self) -> Result<(f32, i32), Error> {
         unsafe {
-            match ffi::FMOD_Studio_CommandReplay_SetLoadBankCallback(self.pointer, callback) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_CommandReplay_SetLoadBankCallback",
-                    error
-                )),
+            let mut frequency = f32::default();
+            let mut priority = i32::default();
+            match ffi::FMOD_Sound_GetDefaults(self.pointer, &mut frequency, &mut priority) {
+                ffi::FMOD_OK => Ok((frequency, priority)),
+                error => Err(err_fmod!("FMOD_Sound_GetDefaults", error)),
             }
         }
     }
-    pub fn set_create_instance_callback(
-        &self,
-        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_CREATE_INSTANCE_CALLBACK,
-    ) -> Result<(), Error> {
+    pub fn set_3d_min_max_distance(&self, min: f32, max: f32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_CommandReplay_SetCreateInstanceCallback(self.pointer, callback) {
+            match ffi::FMOD_Sound_Set3DMinMaxDistance(self.pointer, min, max) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_CommandReplay_SetCreateInstanceCallback",
-                    error
-                )),
+                error => Err(err_fmod!("FMOD_Sound_Set3DMinMaxDistance", error)),
             }
         }
     }
-    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+    pub fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error> {
         unsafe {
-            let mut userdata = null_mut();
-            match ffi::FMOD_Studio_CommandReplay_GetUserData(self.pointer, &mut userdata) {
-                ffi::FMOD_OK => Ok(userdata),
-                error => Err(err_fmod!("FMOD_Studio_CommandReplay_GetUserData", error)),
+            let mut min = f32::default();
+            let mut max = f32::default();
+            match ffi::FMOD_Sound_Get3DMinMaxDistance(self.pointer, &mut min, &mut max) {
+                ffi::FMOD_OK => Ok((min, max)),
+                error => Err(err_fmod!("FMOD_Sound_Get3DMinMaxDistance", error)),
             }
         }
     }
-    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+    pub fn set_3d_cone_settings(
+        &self,
+        insideconeangle: f32,
+        outsideconeangle: f32,
+        outsidevolume: f32,
+    ) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_CommandReplay_SetUserData(self.pointer, userdata) {
+            match ffi::FMOD_Sound_Set3DConeSettings(
+                self.pointer,
+                insideconeangle,
+                outsideconeangle,
+                outsidevolume,
+            ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_CommandReplay_SetUserData", error)),
+                error => Err(err_fmod!("FMOD_Sound_Set3DConeSettings", error)),
             }
         }
     }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct EventDescription {
-    pointer: *mut ffi::FMOD_STUDIO_EVENTDESCRIPTION,
-}
-unsafe impl Send for EventDescription {}
-unsafe impl Sync for EventDescription {}
-impl EventDescription {
-    #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_STUDIO_EVENTDESCRIPTION) -> Self {
-        Self { pointer }
-    }
-    #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_EVENTDESCRIPTION {
-        self.pointer
-    }
-    pub fn is_valid(&self) -> bool {
-        unsafe { to_bool!(ffi::FMOD_Studio_EventDescription_IsValid(self.pointer)) }
-    }
-    pub fn get_id(&self) -> Result<Guid, Error> {
-        unsafe {
-            let mut id = ffi::FMOD_GUID::default();
-            match ffi::FMOD_Studio_EventDescription_GetID(self.pointer, &mut id) {
-                ffi::FMOD_OK => Ok(Guid::try_from(id)?),
-                error => Err(err_fmod!("FMOD_Studio_EventDescription_GetID", error)),
-            }
-        }
-    }
-    pub fn get_path(&self) -> Result<String, Error> {
+    pub fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error> {
         unsafe {
-            let mut retrieved = i32::default();
-            match ffi::FMOD_Studio_EventDescription_GetPath(
+            let mut insideconeangle = f32::default();
+            let mut outsideconeangle = f32::default();
+            let mut outsidevolume = f32::default();
+            match ffi::FMOD_Sound_Get3DConeSettings(
                 self.pointer,
-                null_mut(),
-                0,
-                &mut retrieved,
+                &mut insideconeangle,
+                &mut outsideconeangle,
+                &mut outsidevolume,
             ) {
-                ffi::FMOD_OK => {
-                    let mut buf = vec![0u8; retrieved as usize];
-                    match ffi::FMOD_Studio_EventDescription_GetPath(
-                        self.pointer,
-                        buf.as_mut_ptr() as *mut _,
-                        retrieved,
-                        &mut retrieved,
-                    ) {
-                        ffi::FMOD_OK => Ok(CString::from_vec_with_nul_unchecked(buf)
-                            .into_string()
-                            .map_err(Error::String)?),
-                        error => Err(err_fmod!("FMOD_Studio_EventDescription_GetPath", error)),
-                    }
-                }
-                error => Err(err_fmod!("FMOD_Studio_EventDescription_GetPath", error)),
+                ffi::FMOD_OK => Ok((insideconeangle, outsideconeangle, outsidevolume)),
+                error => Err(err_fmod!("FMOD_Sound_Get3DConeSettings", error)),
             }
         }
     }
-    pub fn get_parameter_description_count(&self) -> Result<i32, Error> {
+    pub fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error> {
         unsafe {
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_EventDescription_GetParameterDescriptionCount(
+            let numpoints = points.len() as i32;
+            match ffi::FMOD_Sound_Set3DCustomRolloff(
                 self.pointer,
-                &mut count,
+                vec_as_mut_ptr(points, |point| point.into()),
+                numpoints,
             ) {
-                ffi::FMOD_OK => Ok(count),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetParameterDescriptionCount",
-                    error
-                )),
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Sound_Set3DCustomRolloff", error)),
             }
         }
     }
-    pub fn get_parameter_description_by_index(
-        &self,
-        index: i32,
-    ) -> Result<ParameterDescription, Error> {
+    pub fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error> {
         unsafe {
-            let mut parameter = ffi::FMOD_STUDIO_PARAMETER_DESCRIPTION::default();
-            match ffi::FMOD_Studio_EventDescription_GetParameterDescriptionByIndex(
-                self.pointer,
-                index,
-                &mut parameter,
-            ) {
-                ffi::FMOD_OK => Ok(ParameterDescription::try_from(parameter)?),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetParameterDescriptionByIndex",
-                    error
-                )),
+            let mut points = null_mut();
+            let mut numpoints = i32::default();
+            match ffi::FMOD_Sound_Get3DCustomRolloff(self.pointer, &mut points, &mut numpoints) {
+                ffi::FMOD_OK => Ok(to_vec!(points, numpoints, Vector::try_from)?),
+                error => Err(err_fmod!("FMOD_Sound_Get3DCustomRolloff", error)),
             }
         }
     }
-    pub fn get_parameter_description_by_name(
-        &self,
-        name: &str,
-    ) -> Result<ParameterDescription, Error> {
+    pub fn get_sub_sound(&self, index: i32) -> Result<Sound, Error> {
         unsafe {
-            let mut parameter = ffi::FMOD_STUDIO_PARAMETER_DESCRIPTION::default();
-            match ffi::FMOD_Studio_EventDescription_GetParameterDescriptionByName(
-                self.pointer,
-                CString::new(name)?.as_ptr(),
-                &mut parameter,
-            ) {
-                ffi::FMOD_OK => Ok(ParameterDescription::try_from(parameter)?),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetParameterDescriptionByName",
-                    error
-                )),
+            let mut subsound = null_mut();
+            match ffi::FMOD_Sound_GetSubSound(self.pointer, index, &mut subsound) {
+                ffi::FMOD_OK => Ok(Sound::from(subsound)),
+                error => Err(err_fmod!("FMOD_Sound_GetSubSound", error)),
             }
         }
     }
-    pub fn get_parameter_description_by_id(
-        &self,
-        id: ParameterId,
-    ) -> Result<ParameterDescription, Error> {
+    pub fn get_sub_sound_parent(&self) -> Result<Sound, Error> {
         unsafe {
-            let mut parameter = ffi::FMOD_STUDIO_PARAMETER_DESCRIPTION::default();
-            match ffi::FMOD_Studio_EventDescription_GetParameterDescriptionByID(
-                self.pointer,
-                id.into(),
-                &mut parameter,
-            ) {
-                ffi::FMOD_OK => Ok(ParameterDescription::try_from(parameter)?),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetParameterDescriptionByID",
-                    error
-                )),
+            let mut parentsound = null_mut();
+            match ffi::FMOD_Sound_GetSubSoundParent(self.pointer, &mut parentsound) {
+                ffi::FMOD_OK => Ok(Sound::from(parentsound)),
+                error => Err(err_fmod!("FMOD_Sound_GetSubSoundParent", error)),
             }
         }
     }
-    pub fn get_parameter_label_by_index(
-        &self,
-        index: i32,
-        labelindex: i32,
-        size: i32,
-    ) -> Result<(String, i32), Error> {
+    pub fn get_name(&self, namelen: i32) -> Result<String, Error> {
         unsafe {
-            let label = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            let mut retrieved = i32::default();
-            match ffi::FMOD_Studio_EventDescription_GetParameterLabelByIndex(
-                self.pointer,
-                index,
-                labelindex,
-                label,
-                size,
-                &mut retrieved,
-            ) {
-                ffi::FMOD_OK => Ok((
-                    CString::from_raw(label)
-                        .into_string()
-                        .map_err(Error::String)?,
-                    retrieved,
-                )),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetParameterLabelByIndex",
-                    error
-                )),
+            let mut name = vec![0 as c_char; namelen.max(1) as usize];
+            match ffi::FMOD_Sound_GetName(self.pointer, name.as_mut_ptr(), name.len() as i32) {
+                ffi::FMOD_OK => Ok(CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned()),
+                error => Err(err_fmod!("FMOD_Sound_GetName", error)),
             }
         }
     }
-    pub fn get_parameter_label_by_name(
-        &self,
-        name: &str,
-        labelindex: i32,
-        size: i32,
-    ) -> Result<(String, i32), Error> {
+    pub fn get_length(&self, lengthtype: impl Into<ffi::FMOD_TIMEUNIT>) -> Result<u32, Error> {
         unsafe {
-            let label = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            let mut retrieved = i32::default();
-            match ffi::FMOD_Studio_EventDescription_GetParameterLabelByName(
-                self.pointer,
-                CString::new(name)?.as_ptr(),
-                labelindex,
-                label,
-                size,
-                &mut retrieved,
-            ) {
-                ffi::FMOD_OK => Ok((
-                    CString::from_raw(label)
-                        .into_string()
-                        .map_err(Error::String)?,
-                    retrieved,
-                )),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetParameterLabelByName",
-                    error
-                )),
+            let mut length = u32::default();
+            match ffi::FMOD_Sound_GetLength(self.pointer, &mut length, lengthtype.into()) {
+                ffi::FMOD_OK => Ok(length),
+                error => Err(err_fmod!("FMOD_Sound_GetLength", error)),
             }
         }
     }
-    pub fn get_parameter_label_by_id(
-        &self,
-        id: ParameterId,
-        labelindex: i32,
-        size: i32,
-    ) -> Result<(String, i32), Error> {
+    pub fn get_format(&self) -> Result<(SoundType, SoundFormat, i32, i32), Error> {
         unsafe {
-            let label = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            let mut retrieved = i32::default();
-            match ffi::FMOD_Studio_EventDescription_GetParameterLabelByID(
+            let mut type_ = ffi::FMOD_SOUND_TYPE::default();
+            let mut format = ffi::FMOD_SOUND_FORMAT::default();
+            let mut channels = i32::default();
+            let mut bits = i32::default();
+            match ffi::FMOD_Sound_GetFormat(
                 self.pointer,
-                id.into(),
-                labelindex,
-                label,
-                size,
-                &mut retrieved,
+                &mut type_,
+                &mut format,
+                &mut channels,
+                &mut bits,
             ) {
                 ffi::FMOD_OK => Ok((
-                    CString::from_raw(label)
-                        .into_string()
-                        .map_err(Error::String)?,
-                    retrieved,
-                )),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetParameterLabelByID",
-                    error
+                    SoundType::from(type_)?,
+                    SoundFormat::from(format)?,
+                    channels,
+                    bits,
                 )),
+                error => Err(err_fmod!("FMOD_Sound_GetFormat", error)),
             }
         }
     }
-    pub fn get_user_property_count(&self) -> Result<i32, Error> {
+    pub fn get_num_sub_sounds(&self) -> Result<i32, Error> {
         unsafe {
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_EventDescription_GetUserPropertyCount(self.pointer, &mut count) {
-                ffi::FMOD_OK => Ok(count),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetUserPropertyCount",
-                    error
-                )),
+            let mut numsubsounds = i32::default();
+            match ffi::FMOD_Sound_GetNumSubSounds(self.pointer, &mut numsubsounds) {
+                ffi::FMOD_OK => Ok(numsubsounds),
+                error => Err(err_fmod!("FMOD_Sound_GetNumSubSounds", error)),
             }
         }
     }
-    pub fn get_user_property_by_index(&self, index: i32) -> Result<UserProperty, Error> {
+    pub fn get_num_tags(&self) -> Result<(i32, i32), Error> {
         unsafe {
-            let mut property = ffi::FMOD_STUDIO_USER_PROPERTY::default();
-            match ffi::FMOD_Studio_EventDescription_GetUserPropertyByIndex(
-                self.pointer,
-                index,
-                &mut property,
-            ) {
-                ffi::FMOD_OK => Ok(UserProperty::try_from(property)?),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetUserPropertyByIndex",
-                    error
-                )),
+            let mut numtags = i32::default();
+            let mut numtagsupdated = i32::default();
+            match ffi::FMOD_Sound_GetNumTags(self.pointer, &mut numtags, &mut numtagsupdated) {
+                ffi::FMOD_OK => Ok((numtags, numtagsupdated)),
+                error => Err(err_fmod!("FMOD_Sound_GetNumTags", error)),
             }
         }
     }
-    pub fn get_user_property(&self, name: &str) -> Result<UserProperty, Error> {
+    pub fn get_tag(&self, name: &str, index: Option<i32>) -> Result<Tag, Error> {
         unsafe {
-            let mut property = ffi::FMOD_STUDIO_USER_PROPERTY::default();
-            match ffi::FMOD_Studio_EventDescription_GetUserProperty(
+            let mut tag = ffi::FMOD_TAG::default();
+            match ffi::FMOD_Sound_GetTag(
                 self.pointer,
                 CString::new(name)?.as_ptr(),
-                &mut property,
+                index.unwrap_or(0),
+                &mut tag,
             ) {
-                ffi::FMOD_OK => Ok(UserProperty::try_from(property)?),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetUserProperty",
-                    error
-                )),
+                ffi::FMOD_OK => Ok(Tag::try_from(tag)?),
+                error => Err(err_fmod!("FMOD_Sound_GetTag", error)),
             }
         }
     }
-    pub fn get_length(&self) -> Result<i32, Error> {
+    pub fn get_open_state(&self) -> Result<(OpenState, u32, bool, bool), Error> {
         unsafe {
-            let mut length = i32::default();
-            match ffi::FMOD_Studio_EventDescription_GetLength(self.pointer, &mut length) {
-                ffi::FMOD_OK => Ok(length),
-                error => Err(err_fmod!("FMOD_Studio_EventDescription_GetLength", error)),
-            }
-        }
-    }
-    pub fn get_min_max_distance(&self) -> Result<(f32, f32), Error> {
-        unsafe {
-            let mut min = f32::default();
-            let mut max = f32::default();
-            match ffi::FMOD_Studio_EventDescription_GetMinMaxDistance(
+            let mut openstate = ffi::FMOD_OPENSTATE::default();
+            let mut percentbuffered = u32::default();
+            let mut starving = ffi::FMOD_BOOL::default();
+            let mut diskbusy = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Sound_GetOpenState(
                 self.pointer,
-                &mut min,
-                &mut max,
+                &mut openstate,
+                &mut percentbuffered,
+                &mut starving,
+                &mut diskbusy,
             ) {
-                ffi::FMOD_OK => Ok((min, max)),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetMinMaxDistance",
-                    error
+                ffi::FMOD_OK => Ok((
+                    OpenState::from(openstate)?,
+                    percentbuffered,
+                    to_bool!(starving),
+                    to_bool!(diskbusy),
                 )),
+                error => Err(err_fmod!("FMOD_Sound_GetOpenState", error)),
             }
         }
     }
-    pub fn get_sound_size(&self) -> Result<f32, Error> {
+    pub fn read_data(&self, buffer: *mut c_void, length: u32) -> Result<u32, Error> {
         unsafe {
-            let mut size = f32::default();
-            match ffi::FMOD_Studio_EventDescription_GetSoundSize(self.pointer, &mut size) {
-                ffi::FMOD_OK => Ok(size),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetSoundSize",
-                    error
-                )),
+            let mut read = u32::default();
+            match ffi::FMOD_Sound_ReadData(self.pointer, buffer, length, &mut read) {
+                ffi::FMOD_OK => Ok(read),
+                error => Err(err_fmod!("FMOD_Sound_ReadData", error)),
             }
         }
     }
-    pub fn is_snapshot(&self) -> Result<bool, Error> {
+    pub fn seek_data(&self, pcm: u32) -> Result<(), Error> {
         unsafe {
-            let mut snapshot = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Studio_EventDescription_IsSnapshot(self.pointer, &mut snapshot) {
-                ffi::FMOD_OK => Ok(to_bool!(snapshot)),
-                error => Err(err_fmod!("FMOD_Studio_EventDescription_IsSnapshot", error)),
+            match ffi::FMOD_Sound_SeekData(self.pointer, pcm) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Sound_SeekData", error)),
             }
         }
     }
-    pub fn is_oneshot(&self) -> Result<bool, Error> {
+    pub fn set_sound_group(&self, soundgroup: SoundGroup) -> Result<(), Error> {
         unsafe {
-            let mut oneshot = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Studio_EventDescription_IsOneshot(self.pointer, &mut oneshot) {
-                ffi::FMOD_OK => Ok(to_bool!(oneshot)),
-                error => Err(err_fmod!("FMOD_Studio_EventDescription_IsOneshot", error)),
+            match ffi::FMOD_Sound_SetSoundGroup(self.pointer, soundgroup.as_mut_ptr()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Sound_SetSoundGroup", error)),
             }
         }
     }
-    pub fn is_stream(&self) -> Result<bool, Error> {
+    pub fn get_sound_group(&self) -> Result<SoundGroup, Error> {
         unsafe {
-            let mut is_stream = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Studio_EventDescription_IsStream(self.pointer, &mut is_stream) {
-                ffi::FMOD_OK => Ok(to_bool!(is_stream)),
-                error => Err(err_fmod!("FMOD_Studio_EventDescription_IsStream", error)),
+            let mut soundgroup = null_mut();
+            match ffi::FMOD_Sound_GetSoundGroup(self.pointer, &mut soundgroup) {
+                ffi::FMOD_OK => Ok(SoundGroup::from(soundgroup)),
+                error => Err(err_fmod!("FMOD_Sound_GetSoundGroup", error)),
             }
         }
     }
-    pub fn is_3d(&self) -> Result<bool, Error> {
+    pub fn get_num_sync_points(&self) -> Result<i32, Error> {
         unsafe {
-            let mut is_3_d = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Studio_EventDescription_Is3D(self.pointer, &mut is_3_d) {
-                ffi::FMOD_OK => Ok(to_bool!(is_3_d)),
-                error => Err(err_fmod!("FMOD_Studio_EventDescription_Is3D", error)),
+            let mut numsyncpoints = i32::default();
+            match ffi::FMOD_Sound_GetNumSyncPoints(self.pointer, &mut numsyncpoints) {
+                ffi::FMOD_OK => Ok(numsyncpoints),
+                error => Err(err_fmod!("FMOD_Sound_GetNumSyncPoints", error)),
             }
         }
     }
-    pub fn is_doppler_enabled(&self) -> Result<bool, Error> {
+    pub fn get_sync_point(&self, index: i32) -> Result<SyncPoint, Error> {
         unsafe {
-            let mut doppler = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Studio_EventDescription_IsDopplerEnabled(self.pointer, &mut doppler) {
-                ffi::FMOD_OK => Ok(to_bool!(doppler)),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_IsDopplerEnabled",
-                    error
-                )),
+            let mut point = null_mut();
+            match ffi::FMOD_Sound_GetSyncPoint(self.pointer, index, &mut point) {
+                ffi::FMOD_OK => Ok(SyncPoint::from(point)),
+                error => Err(err_fmod!("FMOD_Sound_GetSyncPoint", error)),
             }
         }
     }
-    pub fn has_sustain_point(&self) -> Result<bool, Error> {
+    pub fn get_sync_point_info(
+        &self,
+        point: SyncPoint,
+        namelen: i32,
+        offsettype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(String, u32), Error> {
         unsafe {
-            let mut sustain_point = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Studio_EventDescription_HasSustainPoint(
+            let mut name = vec![0 as c_char; namelen.max(1) as usize];
+            let mut offset = u32::default();
+            match ffi::FMOD_Sound_GetSyncPointInfo(
                 self.pointer,
-                &mut sustain_point,
+                point.as_mut_ptr(),
+                name.as_mut_ptr(),
+                name.len() as i32,
+                &mut offset,
+                offsettype.into(),
             ) {
-                ffi::FMOD_OK => Ok(to_bool!(sustain_point)),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_HasSustainPoint",
-                    error
+                ffi::FMOD_OK => Ok((
+                    CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned(),
+                    offset,
                 )),
+                error => Err(err_fmod!("FMOD_Sound_GetSyncPointInfo", error)),
             }
         }
     }
-    pub fn create_instance(&self) -> Result<EventInstance, Error> {
+    pub fn add_sync_point(
+        &self,
+        offset: u32,
+        offsettype: impl Into<ffi::FMOD_TIMEUNIT>,
+        name: Option<String>,
+    ) -> Result<SyncPoint, Error> {
         unsafe {
-            let mut instance = null_mut();
-            match ffi::FMOD_Studio_EventDescription_CreateInstance(self.pointer, &mut instance) {
-                ffi::FMOD_OK => Ok(EventInstance::from(instance)),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_CreateInstance",
-                    error
-                )),
+            let mut point = null_mut();
+            match ffi::FMOD_Sound_AddSyncPoint(
+                self.pointer,
+                offset,
+                offsettype.into(),
+                name.map(CString::new)
+                    .transpose()?
+                    .as_deref()
+                    .map_or(null(), CStr::as_ptr),
+                &mut point,
+            ) {
+                ffi::FMOD_OK => Ok(SyncPoint::from(point)),
+                error => Err(err_fmod!("FMOD_Sound_AddSyncPoint", error)),
             }
         }
     }
-    pub fn get_instance_count(&self) -> Result<i32, Error> {
+    pub fn delete_sync_point(&self, point: SyncPoint) -> Result<(), Error> {
         unsafe {
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_EventDescription_GetInstanceCount(self.pointer, &mut count) {
-                ffi::FMOD_OK => Ok(count),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetInstanceCount",
-                    error
-                )),
+            match ffi::FMOD_Sound_DeleteSyncPoint(self.pointer, point.as_mut_ptr()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Sound_DeleteSyncPoint", error)),
             }
         }
     }
-    pub fn get_instance_list(&self, capacity: i32) -> Result<Vec<EventInstance>, Error> {
+    pub fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error> {
         unsafe {
-            let mut array = vec![null_mut(); capacity as usize];
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_EventDescription_GetInstanceList(
-                self.pointer,
-                array.as_mut_ptr(),
-                capacity,
-                &mut count,
-            ) {
-                ffi::FMOD_OK => Ok(array
-                    .into_iter()
-                    .take(count as usize)
-                    .map(EventInstance::from)
-                    .collect()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetInstanceList",
-                    error
-                )),
+            match ffi::FMOD_Sound_SetMode(self.pointer, mode.into()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Sound_SetMode", error)),
             }
         }
     }
-    pub fn load_sample_data(&self) -> Result<(), Error> {
+    pub fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventDescription_LoadSampleData(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_LoadSampleData",
-                    error
-                )),
+            let mut mode = ffi::FMOD_MODE::default();
+            match ffi::FMOD_Sound_GetMode(self.pointer, &mut mode) {
+                ffi::FMOD_OK => Ok(mode),
+                error => Err(err_fmod!("FMOD_Sound_GetMode", error)),
             }
         }
     }
-    pub fn unload_sample_data(&self) -> Result<(), Error> {
+    pub fn set_loop_count(&self, loopcount: i32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventDescription_UnloadSampleData(self.pointer) {
+            match ffi::FMOD_Sound_SetLoopCount(self.pointer, loopcount) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_UnloadSampleData",
-                    error
-                )),
+                error => Err(err_fmod!("FMOD_Sound_SetLoopCount", error)),
             }
         }
     }
-    pub fn get_sample_loading_state(&self) -> Result<LoadingState, Error> {
+    pub fn get_loop_count(&self) -> Result<i32, Error> {
         unsafe {
-            let mut state = ffi::FMOD_STUDIO_LOADING_STATE::default();
-            match ffi::FMOD_Studio_EventDescription_GetSampleLoadingState(self.pointer, &mut state)
-            {
-                ffi::FMOD_OK => Ok(LoadingState::from(state)?),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_GetSampleLoadingState",
-                    error
-                )),
+            let mut loopcount = i32::default();
+            match ffi::FMOD_Sound_GetLoopCount(self.pointer, &mut loopcount) {
+                ffi::FMOD_OK => Ok(loopcount),
+                error => Err(err_fmod!("FMOD_Sound_GetLoopCount", error)),
             }
         }
     }
-    pub fn release_all_instances(&self) -> Result<(), Error> {
+    pub fn set_loop_points(
+        &self,
+        loopstart: u32,
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopend: u32,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventDescription_ReleaseAllInstances(self.pointer) {
+            match ffi::FMOD_Sound_SetLoopPoints(
+                self.pointer,
+                loopstart,
+                loopstarttype.into(),
+                loopend,
+                loopendtype.into(),
+            ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventDescription_ReleaseAllInstances",
-                    error
-                )),
+                error => Err(err_fmod!("FMOD_Sound_SetLoopPoints", error)),
             }
         }
     }
-    pub fn set_callback(
+    pub fn get_loop_points(
         &self,
-        callback: ffi::FMOD_STUDIO_EVENT_CALLBACK,
-        callbackmask: impl Into<ffi::FMOD_STUDIO_EVENT_CALLBACK_TYPE>,
-    ) -> Result<(), Error> {
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(u32, u32), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventDescription_SetCallback(
+            let mut loopstart = u32::default();
+            let mut loopend = u32::default();
+            match ffi::FMOD_Sound_GetLoopPoints(
                 self.pointer,
-                callback,
-                callbackmask.into(),
+                &mut loopstart,
+                loopstarttype.into(),
+                &mut loopend,
+                loopendtype.into(),
             ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_EventDescription_SetCallback", error)),
+                ffi::FMOD_OK => Ok((loopstart, loopend)),
+                error => Err(err_fmod!("FMOD_Sound_GetLoopPoints", error)),
             }
         }
     }
-    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+    pub fn get_music_num_channels(&self) -> Result<i32, Error> {
         unsafe {
-            let mut userdata = null_mut();
-            match ffi::FMOD_Studio_EventDescription_GetUserData(self.pointer, &mut userdata) {
-                ffi::FMOD_OK => Ok(userdata),
-                error => Err(err_fmod!("FMOD_Studio_EventDescription_GetUserData", error)),
+            let mut numchannels = i32::default();
+            match ffi::FMOD_Sound_GetMusicNumChannels(self.pointer, &mut numchannels) {
+                ffi::FMOD_OK => Ok(numchannels),
+                error => Err(err_fmod!("FMOD_Sound_GetMusicNumChannels", error)),
             }
         }
     }
-    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+    pub fn set_music_channel_volume(&self, channel: i32, volume: f32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventDescription_SetUserData(self.pointer, userdata) {
+            match ffi::FMOD_Sound_SetMusicChannelVolume(self.pointer, channel, volume) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_EventDescription_SetUserData", error)),
-            }
-        }
-    }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct EventInstance {
-    pointer: *mut ffi::FMOD_STUDIO_EVENTINSTANCE,
-}
-unsafe impl Send for EventInstance {}
-unsafe impl Sync for EventInstance {}
-impl EventInstance {
-    #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_STUDIO_EVENTINSTANCE) -> Self {
-        Self { pointer }
-    }
-    #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_EVENTINSTANCE {
-        self.pointer
-    }
-    pub fn is_valid(&self) -> bool {
-        unsafe { to_bool!(ffi::FMOD_Studio_EventInstance_IsValid(self.pointer)) }
-    }
-    pub fn get_description(&self) -> Result<EventDescription, Error> {
-        unsafe {
-            let mut description = null_mut();
-            match ffi::FMOD_Studio_EventInstance_GetDescription(self.pointer, &mut description) {
-                ffi::FMOD_OK => Ok(EventDescription::from(description)),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetDescription", error)),
+                error => Err(err_fmod!("FMOD_Sound_SetMusicChannelVolume", error)),
             }
         }
     }
-    pub fn get_volume(&self) -> Result<(f32, f32), Error> {
+    pub fn get_music_channel_volume(&self, channel: i32) -> Result<f32, Error> {
         unsafe {
             let mut volume = f32::default();
-            let mut finalvolume = f32::default();
-            match ffi::FMOD_Studio_EventInstance_GetVolume(
-                self.pointer,
-                &mut volume,
-                &mut finalvolume,
-            ) {
-                ffi::FMOD_OK => Ok((volume, finalvolume)),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetVolume", error)),
+            match ffi::FMOD_Sound_GetMusicChannelVolume(self.pointer, channel, &mut volume) {
+                ffi::FMOD_OK => Ok(volume),
+                error => Err(err_fmod!("FMOD_Sound_GetMusicChannelVolume", error)),
             }
         }
     }
-    pub fn set_volume(&self, volume: f32) -> Result<(), Error> {
+    pub fn set_music_speed(&self, speed: f32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetVolume(self.pointer, volume) {
+            match ffi::FMOD_Sound_SetMusicSpeed(self.pointer, speed) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetVolume", error)),
+                error => Err(err_fmod!("FMOD_Sound_SetMusicSpeed", error)),
             }
         }
     }
-    pub fn get_pitch(&self) -> Result<(f32, f32), Error> {
+    pub fn get_music_speed(&self) -> Result<f32, Error> {
         unsafe {
-            let mut pitch = f32::default();
-            let mut finalpitch = f32::default();
-            match ffi::FMOD_Studio_EventInstance_GetPitch(self.pointer, &mut pitch, &mut finalpitch)
-            {
-                ffi::FMOD_OK => Ok((pitch, finalpitch)),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetPitch", error)),
+            let mut speed = f32::default();
+            match ffi::FMOD_Sound_GetMusicSpeed(self.pointer, &mut speed) {
+                ffi::FMOD_OK => Ok(speed),
+                error => Err(err_fmod!("FMOD_Sound_GetMusicSpeed", error)),
             }
         }
     }
-    pub fn set_pitch(&self, pitch: f32) -> Result<(), Error> {
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetPitch(self.pointer, pitch) {
+            match ffi::FMOD_Sound_SetUserData(self.pointer, userdata) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetPitch", error)),
+                error => Err(err_fmod!("FMOD_Sound_SetUserData", error)),
             }
         }
     }
-    pub fn get_3d_attributes(&self) -> Result<Attributes3d, Error> {
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
         unsafe {
-            let mut attributes = ffi::FMOD_3D_ATTRIBUTES::default();
-            match ffi::FMOD_Studio_EventInstance_Get3DAttributes(self.pointer, &mut attributes) {
-                ffi::FMOD_OK => Ok(Attributes3d::try_from(attributes)?),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_Get3DAttributes",
-                    error
-                )),
+            let mut userdata = null_mut();
+            match ffi::FMOD_Sound_GetUserData(self.pointer, &mut userdata) {
+                ffi::FMOD_OK => Ok(userdata),
+                error => Err(err_fmod!("FMOD_Sound_GetUserData", error)),
             }
         }
     }
-    pub fn set_3d_attributes(&self, attributes: Attributes3d) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Studio_EventInstance_Set3DAttributes(
-                self.pointer,
-                &mut attributes.into(),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_Set3DAttributes",
-                    error
-                )),
-            }
-        }
+}
+#[doc = " Methods of [`Sound`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait SoundApi {
+    fn release(&self) -> Result<(), Error>;
+    fn get_system_object(&self) -> Result<System, Error>;
+    fn lock(&self, offset: u32, length: u32)
+        -> Result<(*mut c_void, *mut c_void, u32, u32), Error>;
+    fn unlock(
+        &self,
+        ptr_1: *mut c_void,
+        ptr_2: *mut c_void,
+        len_1: u32,
+        len_2: u32,
+    ) -> Result<(), Error>;
+    fn set_defaults(&self, frequency: f32, priority: i32) -> Result<(), Error>;
+    fn get_defaults(&self) -> Result<(f32, i32), Error>;
+    fn set_3d_min_max_distance(&self, min: f32, max: f32) -> Result<(), Error>;
+    fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error>;
+    fn set_3d_cone_settings(
+        &self,
+        insideconeangle: f32,
+        outsideconeangle: f32,
+        outsidevolume: f32,
+    ) -> Result<(), Error>;
+    fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error>;
+    fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error>;
+    fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error>;
+    fn get_sub_sound(&self, index: i32) -> Result<Sound, Error>;
+    fn get_sub_sound_parent(&self) -> Result<Sound, Error>;
+    fn get_name(&self, namelen: i32) -> Result<String, Error>;
+    fn get_length(&self, lengthtype: impl Into<ffi::FMOD_TIMEUNIT>) -> Result<u32, Error>;
+    fn get_format(&self) -> Result<(SoundType, SoundFormat, i32, i32), Error>;
+    fn get_num_sub_sounds(&self) -> Result<i32, Error>;
+    fn get_num_tags(&self) -> Result<(i32, i32), Error>;
+    fn get_tag(&self, name: &str, index: Option<i32>) -> Result<Tag, Error>;
+    fn get_open_state(&self) -> Result<(OpenState, u32, bool, bool), Error>;
+    fn read_data(&self, buffer: *mut c_void, length: u32) -> Result<u32, Error>;
+    fn seek_data(&self, pcm: u32) -> Result<(), Error>;
+    fn set_sound_group(&self, soundgroup: SoundGroup) -> Result<(), Error>;
+    fn get_sound_group(&self) -> Result<SoundGroup, Error>;
+    fn get_num_sync_points(&self) -> Result<i32, Error>;
+    fn get_sync_point(&self, index: i32) -> Result<SyncPoint, Error>;
+    fn get_sync_point_info(
+        &self,
+        point: SyncPoint,
+        namelen: i32,
+        offsettype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(String, u32), Error>;
+    fn add_sync_point(
+        &self,
+        offset: u32,
+        offsettype: impl Into<ffi::FMOD_TIMEUNIT>,
+        name: Option<String>,
+    ) -> Result<SyncPoint, Error>;
+    fn delete_sync_point(&self, point: SyncPoint) -> Result<(), Error>;
+    fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error>;
+    fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error>;
+    fn set_loop_count(&self, loopcount: i32) -> Result<(), Error>;
+    fn get_loop_count(&self) -> Result<i32, Error>;
+    fn set_loop_points(
+        &self,
+        loopstart: u32,
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopend: u32,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(), Error>;
+    fn get_loop_points(
+        &self,
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(u32, u32), Error>;
+    fn get_music_num_channels(&self) -> Result<i32, Error>;
+    fn set_music_channel_volume(&self, channel: i32, volume: f32) -> Result<(), Error>;
+    fn get_music_channel_volume(&self, channel: i32) -> Result<f32, Error>;
+    fn set_music_speed(&self, speed: f32) -> Result<(), Error>;
+    fn get_music_speed(&self) -> Result<f32, Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+}
+impl SoundApi for Sound {
+    #[inline]
+    fn release(&self) -> Result<(), Error> {
+        Sound::release(self)
     }
-    pub fn get_listener_mask(&self) -> Result<u32, Error> {
-        unsafe {
-            let mut mask = u32::default();
-            match ffi::FMOD_Studio_EventInstance_GetListenerMask(self.pointer, &mut mask) {
-                ffi::FMOD_OK => Ok(mask),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_GetListenerMask",
-                    error
-                )),
-            }
-        }
+    #[inline]
+    fn get_system_object(&self) -> Result<System, Error> {
+        Sound::get_system_object(self)
     }
-    pub fn set_listener_mask(&self, mask: u32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetListenerMask(self.pointer, mask) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_SetListenerMask",
-                    error
-                )),
-            }
-        }
+    #[inline]
+    fn lock(
+        &self,
+        offset: u32,
+        length: u32,
+    ) -> Result<(*mut c_void, *mut c_void, u32, u32), Error> {
+        Sound::lock(self, offset, length)
     }
-    pub fn get_property(&self, index: EventProperty) -> Result<f32, Error> {
-        unsafe {
-            let mut value = f32::default();
-            match ffi::FMOD_Studio_EventInstance_GetProperty(self.pointer, index.into(), &mut value)
-            {
-                ffi::FMOD_OK => Ok(value),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetProperty", error)),
-            }
-        }
+    #[inline]
+    fn unlock(
+        &self,
+        ptr_1: *mut c_void,
+        ptr_2: *mut c_void,
+        len_1: u32,
+        len_2: u32,
+    ) -> Result<(), Error> {
+        Sound::unlock(self, ptr_1, ptr_2, len_1, len_2)
     }
-    pub fn set_property(&self, index: EventProperty, value: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetProperty(self.pointer, index.into(), value) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetProperty", error)),
-            }
-        }
+    #[inline]
+    fn set_defaults(&self, frequency: f32, priority: i32) -> Result<(), Error> {
+        Sound::set_defaults(self, frequency, priority)
+    }
+    #[inline]
+    fn get_defaults(&self) -> Result<(f32, i32), Error> {
+        Sound::get_defaults(self)
+    }
+    #[inline]
+    fn set_3d_min_max_distance(&self, min: f32, max: f32) -> Result<(), Error> {
+        Sound::set_3d_min_max_distance(self, min, max)
+    }
+    #[inline]
+    fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        Sound::get_3d_min_max_distance(self)
+    }
+    #[inline]
+    fn set_3d_cone_settings(
+        &self,
+        insideconeangle: f32,
+        outsideconeangle: f32,
+        outsidevolume: f32,
+    ) -> Result<(), Error> {
+        Sound::set_3d_cone_settings(self, insideconeangle, outsideconeangle, outsidevolume)
+    }
+    #[inline]
+    fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error> {
+        Sound::get_3d_cone_settings(self)
+    }
+    #[inline]
+    fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error> {
+        Sound::set_3d_custom_rolloff(self, points)
+    }
+    #[inline]
+    fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error> {
+        Sound::get_3d_custom_rolloff(self)
+    }
+    #[inline]
+    fn get_sub_sound(&self, index: i32) -> Result<Sound, Error> {
+        Sound::get_sub_sound(self, index)
+    }
+    #[inline]
+    fn get_sub_sound_parent(&self) -> Result<Sound, Error> {
+        Sound::get_sub_sound_parent(self)
+    }
+    #[inline]
+    fn get_name(&self, namelen: i32) -> Result<String, Error> {
+        Sound::get_name(self, namelen)
+    }
+    #[inline]
+    fn get_length(&self, lengthtype: impl Into<ffi::FMOD_TIMEUNIT>) -> Result<u32, Error> {
+        Sound::get_length(self, lengthtype)
+    }
+    #[inline]
+    fn get_format(&self) -> Result<(SoundType, SoundFormat, i32, i32), Error> {
+        Sound::get_format(self)
+    }
+    #[inline]
+    fn get_num_sub_sounds(&self) -> Result<i32, Error> {
+        Sound::get_num_sub_sounds(self)
+    }
+    #[inline]
+    fn get_num_tags(&self) -> Result<(i32, i32), Error> {
+        Sound::get_num_tags(self)
+    }
+    #[inline]
+    fn get_tag(&self, name: &str, index: Option<i32>) -> Result<Tag, Error> {
+        Sound::get_tag(self, name, index)
+    }
+    #[inline]
+    fn get_open_state(&self) -> Result<(OpenState, u32, bool, bool), Error> {
+        Sound::get_open_state(self)
+    }
+    #[inline]
+    fn read_data(&self, buffer: *mut c_void, length: u32) -> Result<u32, Error> {
+        Sound::read_data(self, buffer, length)
+    }
+    #[inline]
+    fn seek_data(&self, pcm: u32) -> Result<(), Error> {
+        Sound::seek_data(self, pcm)
+    }
+    #[inline]
+    fn set_sound_group(&self, soundgroup: SoundGroup) -> Result<(), Error> {
+        Sound::set_sound_group(self, soundgroup)
+    }
+    #[inline]
+    fn get_sound_group(&self) -> Result<SoundGroup, Error> {
+        Sound::get_sound_group(self)
+    }
+    #[inline]
+    fn get_num_sync_points(&self) -> Result<i32, Error> {
+        Sound::get_num_sync_points(self)
+    }
+    #[inline]
+    fn get_sync_point(&self, index: i32) -> Result<SyncPoint, Error> {
+        Sound::get_sync_point(self, index)
+    }
+    #[inline]
+    fn get_sync_point_info(
+        &self,
+        point: SyncPoint,
+        namelen: i32,
+        offsettype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(String, u32), Error> {
+        Sound::get_sync_point_info(self, point, namelen, offsettype)
+    }
+    #[inline]
+    fn add_sync_point(
+        &self,
+        offset: u32,
+        offsettype: impl Into<ffi::FMOD_TIMEUNIT>,
+        name: Option<String>,
+    ) -> Result<SyncPoint, Error> {
+        Sound::add_sync_point(self, offset, offsettype, name)
+    }
+    #[inline]
+    fn delete_sync_point(&self, point: SyncPoint) -> Result<(), Error> {
+        Sound::delete_sync_point(self, point)
+    }
+    #[inline]
+    fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error> {
+        Sound::set_mode(self, mode)
+    }
+    #[inline]
+    fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error> {
+        Sound::get_mode(self)
+    }
+    #[inline]
+    fn set_loop_count(&self, loopcount: i32) -> Result<(), Error> {
+        Sound::set_loop_count(self, loopcount)
+    }
+    #[inline]
+    fn get_loop_count(&self) -> Result<i32, Error> {
+        Sound::get_loop_count(self)
+    }
+    #[inline]
+    fn set_loop_points(
+        &self,
+        loopstart: u32,
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopend: u32,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(), Error> {
+        Sound::set_loop_points(self, loopstart, loopstarttype, loopend, loopendtype)
+    }
+    #[inline]
+    fn get_loop_points(
+        &self,
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(u32, u32), Error> {
+        Sound::get_loop_points(self, loopstarttype, loopendtype)
+    }
+    #[inline]
+    fn get_music_num_channels(&self) -> Result<i32, Error> {
+        Sound::get_music_num_channels(self)
+    }
+    #[inline]
+    fn set_music_channel_volume(&self, channel: i32, volume: f32) -> Result<(), Error> {
+        Sound::set_music_channel_volume(self, channel, volume)
+    }
+    #[inline]
+    fn get_music_channel_volume(&self, channel: i32) -> Result<f32, Error> {
+        Sound::get_music_channel_volume(self, channel)
+    }
+    #[inline]
+    fn set_music_speed(&self, speed: f32) -> Result<(), Error> {
+        Sound::set_music_speed(self, speed)
+    }
+    #[inline]
+    fn get_music_speed(&self) -> Result<f32, Error> {
+        Sound::get_music_speed(self)
+    }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        Sound::set_user_data(self, userdata)
+    }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        Sound::get_user_data(self)
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct SoundGroup {
+    pointer: *mut ffi::FMOD_SOUNDGROUP,
+}
+unsafe impl Send for SoundGroup {}
+unsafe impl Sync for SoundGroup {}
+impl SoundGroup {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_SOUNDGROUP) -> Self {
+        Self { pointer }
+    }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_SOUNDGROUP {
+        self.pointer
+    }
+    pub fn release(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_SoundGroup_Release(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_SoundGroup_Release", error)),
+            }
+        }
+    }
+    pub fn get_system_object(&self) -> Result<System, Error> {
+        unsafe {
+            let mut system = null_mut();
+            match ffi::FMOD_SoundGroup_GetSystemObject(self.pointer, &mut system) {
+                ffi::FMOD_OK => Ok(System::from(system)),
+                error => Err(err_fmod!("FMOD_SoundGroup_GetSystemObject", error)),
+            }
+        }
+    }
+    pub fn set_max_audible(&self, maxaudible: i32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_SoundGroup_SetMaxAudible(self.pointer, maxaudible) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_SoundGroup_SetMaxAudible", error)),
+            }
+        }
+    }
+    pub fn get_max_audible(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut maxaudible = i32::default();
+            match ffi::FMOD_SoundGroup_GetMaxAudible(self.pointer, &mut maxaudible) {
+                ffi::FMOD_OK => Ok(maxaudible),
+                error => Err(err_fmod!("FMOD_SoundGroup_GetMaxAudible", error)),
+            }
+        }
+    }
+    pub fn set_max_audible_behavior(&self, behavior: SoundGroupBehavior) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_SoundGroup_SetMaxAudibleBehavior(self.pointer, behavior.into()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_SoundGroup_SetMaxAudibleBehavior", error)),
+            }
+        }
+    }
+    pub fn get_max_audible_behavior(&self) -> Result<SoundGroupBehavior, Error> {
+        unsafe {
+            let mut behavior = ffi::FMOD_SOUNDGROUP_BEHAVIOR::default();
+            match ffi::FMOD_SoundGroup_GetMaxAudibleBehavior(self.pointer, &mut behavior) {
+                ffi::FMOD_OK => Ok(SoundGroupBehavior::from(behavior)?),
+                error => Err(err_fmod!("FMOD_SoundGroup_GetMaxAudibleBehavior", error)),
+            }
+        }
+    }
+    pub fn set_mute_fade_speed(&self, speed: f32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_SoundGroup_SetMuteFadeSpeed(self.pointer, speed) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_SoundGroup_SetMuteFadeSpeed", error)),
+            }
+        }
+    }
+    pub fn get_mute_fade_speed(&self) -> Result<f32, Error> {
+        unsafe {
+            let mut speed = f32::default();
+            match ffi::FMOD_SoundGroup_GetMuteFadeSpeed(self.pointer, &mut speed) {
+                ffi::FMOD_OK => Ok(speed),
+                error => Err(err_fmod!("FMOD_SoundGroup_GetMuteFadeSpeed", error)),
+            }
+        }
+    }
+    pub fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_SoundGroup_SetVolume(self.pointer, volume) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_SoundGroup_SetVolume", error)),
+            }
+        }
+    }
+    pub fn get_volume(&self) -> Result<f32, Error> {
+        unsafe {
+            let mut volume = f32::default();
+            match ffi::FMOD_SoundGroup_GetVolume(self.pointer, &mut volume) {
+                ffi::FMOD_OK => Ok(volume),
+                error => Err(err_fmod!("FMOD_SoundGroup_GetVolume", error)),
+            }
+        }
+    }
+    pub fn stop(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_SoundGroup_Stop(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_SoundGroup_Stop", error)),
+            }
+        }
+    }
+    pub fn get_name(&self, namelen: i32) -> Result<String, Error> {
+        unsafe {
+            let mut name = vec![0 as c_char; namelen.max(1) as usize];
+            match ffi::FMOD_SoundGroup_GetName(self.pointer, name.as_mut_ptr(), name.len() as i32) {
+                ffi::FMOD_OK => Ok(CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned()),
+                error => Err(err_fmod!("FMOD_SoundGroup_GetName", error)),
+            }
+        }
+    }
+    pub fn get_num_sounds(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut numsounds = i32::default();
+            match ffi::FMOD_SoundGroup_GetNumSounds(self.pointer, &mut numsounds) {
+                ffi::FMOD_OK => Ok(numsounds),
+                error => Err(err_fmod!("FMOD_SoundGroup_GetNumSounds", error)),
+            }
+        }
+    }
+    pub fn get_sound(&self, index: i32) -> Result<Sound, Error> {
+        unsafe {
+            let mut sound = null_mut();
+            match ffi::FMOD_SoundGroup_GetSound(self.pointer, index, &mut sound) {
+                ffi::FMOD_OK => Ok(Sound::from(sound)),
+                error => Err(err_fmod!("FMOD_SoundGroup_GetSound", error)),
+            }
+        }
+    }
+    pub fn get_num_playing(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut numplaying = i32::default();
+            match ffi::FMOD_SoundGroup_GetNumPlaying(self.pointer, &mut numplaying) {
+                ffi::FMOD_OK => Ok(numplaying),
+                error => Err(err_fmod!("FMOD_SoundGroup_GetNumPlaying", error)),
+            }
+        }
+    }
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_SoundGroup_SetUserData(self.pointer, userdata) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_SoundGroup_SetUserData", error)),
+            }
+        }
+    }
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        unsafe {
+            let mut userdata = null_mut();
+            match ffi::FMOD_SoundGroup_GetUserData(self.pointer, &mut userdata) {
+                ffi::FMOD_OK => Ok(userdata),
+                error => Err(err_fmod!("FMOD_SoundGroup_GetUserData", error)),
+            }
+        }
+    }
+}
+#[doc = " Methods of [`SoundGroup`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait SoundGroupApi {
+    fn release(&self) -> Result<(), Error>;
+    fn get_system_object(&self) -> Result<System, Error>;
+    fn set_max_audible(&self, maxaudible: i32) -> Result<(), Error>;
+    fn get_max_audible(&self) -> Result<i32, Error>;
+    fn set_max_audible_behavior(&self, behavior: SoundGroupBehavior) -> Result<(), Error>;
+    fn get_max_audible_behavior(&self) -> Result<SoundGroupBehavior, Error>;
+    fn set_mute_fade_speed(&self, speed: f32) -> Result<(), Error>;
+    fn get_mute_fade_speed(&self) -> Result<f32, Error>;
+    fn set_volume(&self, volume: f32) -> Result<(), Error>;
+    fn get_volume(&self) -> Result<f32, Error>;
+    fn stop(&self) -> Result<(), Error>;
+    fn get_name(&self, namelen: i32) -> Result<String, Error>;
+    fn get_num_sounds(&self) -> Result<i32, Error>;
+    fn get_sound(&self, index: i32) -> Result<Sound, Error>;
+    fn get_num_playing(&self) -> Result<i32, Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+}
+impl SoundGroupApi for SoundGroup {
+    #[inline]
+    fn release(&self) -> Result<(), Error> {
+        SoundGroup::release(self)
+    }
+    #[inline]
+    fn get_system_object(&self) -> Result<System, Error> {
+        SoundGroup::get_system_object(self)
+    }
+    #[inline]
+    fn set_max_audible(&self, maxaudible: i32) -> Result<(), Error> {
+        SoundGroup::set_max_audible(self, maxaudible)
+    }
+    #[inline]
+    fn get_max_audible(&self) -> Result<i32, Error> {
+        SoundGroup::get_max_audible(self)
+    }
+    #[inline]
+    fn set_max_audible_behavior(&self, behavior: SoundGroupBehavior) -> Result<(), Error> {
+        SoundGroup::set_max_audible_behavior(self, behavior)
+    }
+    #[inline]
+    fn get_max_audible_behavior(&self) -> Result<SoundGroupBehavior, Error> {
+        SoundGroup::get_max_audible_behavior(self)
+    }
+    #[inline]
+    fn set_mute_fade_speed(&self, speed: f32) -> Result<(), Error> {
+        SoundGroup::set_mute_fade_speed(self, speed)
+    }
+    #[inline]
+    fn get_mute_fade_speed(&self) -> Result<f32, Error> {
+        SoundGroup::get_mute_fade_speed(self)
+    }
+    #[inline]
+    fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        SoundGroup::set_volume(self, volume)
+    }
+    #[inline]
+    fn get_volume(&self) -> Result<f32, Error> {
+        SoundGroup::get_volume(self)
+    }
+    #[inline]
+    fn stop(&self) -> Result<(), Error> {
+        SoundGroup::stop(self)
+    }
+    #[inline]
+    fn get_name(&self, namelen: i32) -> Result<String, Error> {
+        SoundGroup::get_name(self, namelen)
+    }
+    #[inline]
+    fn get_num_sounds(&self) -> Result<i32, Error> {
+        SoundGroup::get_num_sounds(self)
+    }
+    #[inline]
+    fn get_sound(&self, index: i32) -> Result<Sound, Error> {
+        SoundGroup::get_sound(self, index)
+    }
+    #[inline]
+    fn get_num_playing(&self) -> Result<i32, Error> {
+        SoundGroup::get_num_playing(self)
+    }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        SoundGroup::set_user_data(self, userdata)
+    }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        SoundGroup::get_user_data(self)
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct Bank {
+    pointer: *mut ffi::FMOD_STUDIO_BANK,
+}
+unsafe impl Send for Bank {}
+unsafe impl Sync for Bank {}
+impl Bank {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_STUDIO_BANK) -> Self {
+        Self { pointer }
+    }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_BANK {
+        self.pointer
+    }
+    pub fn is_valid(&self) -> bool {
+        unsafe { to_bool!(ffi::FMOD_Studio_Bank_IsValid(self.pointer)) }
+    }
+    pub fn get_id(&self) -> Result<Guid, Error> {
+        unsafe {
+            let mut id = ffi::FMOD_GUID::default();
+            match ffi::FMOD_Studio_Bank_GetID(self.pointer, &mut id) {
+                ffi::FMOD_OK => Ok(Guid::try_from(id)?),
+                error => Err(err_fmod!("FMOD_Studio_Bank_GetID", error)),
+            }
+        }
+    }
+    pub fn get_path(&self) -> Result<String, Error> {
+        unsafe {
+            let mut retrieved = i32::default();
+            match ffi::FMOD_Studio_Bank_GetPath(self.pointer, null_mut(), 0, &mut retrieved) {
+                ffi::FMOD_OK => {
+                    let mut buf = vec![0u8; retrieved as usize];
+                    match ffi::FMOD_Studio_Bank_GetPath(
+                        self.pointer,
+                        buf.as_mut_ptr() as *mut _,
+                        retrieved,
+                        &mut retrieved,
+                    ) {
+                        ffi::FMOD_OK => Ok(CString::from_vec_with_nul_unchecked(buf)
+                            .into_string()
+                            .map_err(Error::String)?),
+                        error => Err(err_fmod!("FMOD_Studio_Bank_GetPath", error)),
+                    }
+                }
+                error => Err(err_fmod!("FMOD_Studio_Bank_GetPath", error)),
+            }
+        }
+    }
+    pub fn unload(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_Bank_Unload(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_Bank_Unload", error)),
+            }
+        }
+    }
+    pub fn load_sample_data(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_Bank_LoadSampleData(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_Bank_LoadSampleData", error)),
+            }
+        }
+    }
+    pub fn unload_sample_data(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_Bank_UnloadSampleData(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_Bank_UnloadSampleData", error)),
+            }
+        }
+    }
+    pub fn get_loading_state(&self) -> Result<LoadingState, Error> {
+        unsafe {
+            let mut state = ffi::FMOD_STUDIO_LOADING_STATE::default();
+            match ffi::FMOD_Studio_Bank_GetLoadingState(self.pointer, &mut state) {
+                ffi::FMOD_OK => Ok(LoadingState::from(state)?),
+                error => Err(err_fmod!("FMOD_Studio_Bank_GetLoadingState", error)),
+            }
+        }
+    }
+    pub fn get_sample_loading_state(&self) -> Result<LoadingState, Error> {
+        unsafe {
+            let mut state = ffi::FMOD_STUDIO_LOADING_STATE::default();
+            match ffi::FMOD_Studio_Bank_GetSampleLoadingState(self.pointer, &mut state) {
+                ffi::FMOD_OK => Ok(LoadingState::from(state)?),
+                error => Err(err_fmod!("FMOD_Studio_Bank_GetSampleLoadingState", error)),
+            }
+        }
+    }
+    pub fn get_string_count(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_Bank_GetStringCount(self.pointer, &mut count) {
+                ffi::FMOD_OK => Ok(count),
+                error => Err(err_fmod!("FMOD_Studio_Bank_GetStringCount", error)),
+            }
+        }
+    }
+    pub fn get_string_info(&self, index: i32, size: i32) -> Result<(Guid, String, i32), Error> {
+        unsafe {
+            let mut id = ffi::FMOD_GUID::default();
+            let mut path = vec![0 as c_char; size.max(1) as usize];
+            let mut retrieved = i32::default();
+            match ffi::FMOD_Studio_Bank_GetStringInfo(
+                self.pointer,
+                index,
+                &mut id,
+                path.as_mut_ptr(),
+                path.len() as i32,
+                &mut retrieved,
+            ) {
+                ffi::FMOD_OK => Ok((
+                    Guid::try_from(id)?,
+                    CStr::from_ptr(path.as_ptr()).to_string_lossy().into_owned(),
+                    retrieved,
+                )),
+                error => Err(err_fmod!("FMOD_Studio_Bank_GetStringInfo", error)),
+            }
+        }
+    }
+    pub fn get_event_count(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_Bank_GetEventCount(self.pointer, &mut count) {
+                ffi::FMOD_OK => Ok(count),
+                error => Err(err_fmod!("FMOD_Studio_Bank_GetEventCount", error)),
+            }
+        }
+    }
+    pub fn get_event_list(&self, capacity: i32) -> Result<Vec<EventDescription>, Error> {
+        unsafe {
+            let mut array = vec![null_mut(); capacity as usize];
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_Bank_GetEventList(
+                self.pointer,
+                array.as_mut_ptr(),
+                capacity,
+                &mut count,
+            ) {
+                ffi::FMOD_OK => Ok(array
+                    .into_iter()
+                    .take(count as usize)
+                    .map(EventDescription::from)
+                    .collect()),
+                error => Err(err_fmod!("FMOD_Studio_Bank_GetEventList", error)),
+            }
+        }
+    }
+    pub fn get_bus_count(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_Bank_GetBusCount(self.pointer, &mut count) {
+                ffi::FMOD_OK => Ok(count),
+                error => Err(err_fmod!("FMOD_Studio_Bank_GetBusCount", error)),
+            }
+        }
+    }
+    pub fn get_bus_list(&self, capacity: i32) -> Result<Vec<Bus>, Error> {
+        unsafe {
+            let mut array = vec![null_mut(); capacity as usize];
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_Bank_GetBusList(
+                self.pointer,
+                array.as_mut_ptr(),
+                capacity,
+                &mut count,
+            ) {
+                ffi::FMOD_OK => Ok(array
+                    .into_iter()
+                    .take(count as usize)
+                    .map(Bus::from)
+                    .collect()),
+                error => Err(err_fmod!("FMOD_Studio_Bank_GetBusList", error)),
+            }
+        }
+    }
+    pub fn get_vca_count(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_Bank_GetVCACount(self.pointer, &mut count) {
+                ffi::FMOD_OK => Ok(count),
+                error => Err(err_fmod!("FMOD_Studio_Bank_GetVCACount", error)),
+            }
+        }
+    }
+    pub fn get_vca_list(&self, capacity: i32) -> Result<Vec<Vca>, Error> {
+        unsafe {
+            let mut array = vec![null_mut(); capacity as usize];
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_Bank_GetVCAList(
+                self.pointer,
+                array.as_mut_ptr(),
+                capacity,
+                &mut count,
+            ) {
+                ffi::FMOD_OK => Ok(array
+                    .into_iter()
+                    .take(count as usize)
+                    .map(Vca::from)
+                    .collect()),
+                error => Err(err_fmod!("FMOD_Studio_Bank_GetVCAList", error)),
+            }
+        }
+    }
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        unsafe {
+            let mut userdata = null_mut();
+            match ffi::FMOD_Studio_Bank_GetUserData(self.pointer, &mut userdata) {
+                ffi::FMOD_OK => Ok(userdata),
+                error => Err(err_fmod!("FMOD_Studio_Bank_GetUserData", error)),
+            }
+        }
+    }
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_Bank_SetUserData(self.pointer, userdata) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_Bank_SetUserData", error)),
+            }
+        }
+    }
+}
+#[doc = " Methods of [`Bank`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait BankApi {
+    fn is_valid(&self) -> bool;
+    fn get_id(&self) -> Result<Guid, Error>;
+    fn get_path(&self) -> Result<String, Error>;
+    fn unload(&self) -> Result<(), Error>;
+    fn load_sample_data(&self) -> Result<(), Error>;
+    fn unload_sample_data(&self) -> Result<(), Error>;
+    fn get_loading_state(&self) -> Result<LoadingState, Error>;
+    fn get_sample_loading_state(&self) -> Result<LoadingState, Error>;
+    fn get_string_count(&self) -> Result<i32, Error>;
+    fn get_string_info(&self, index: i32, size: i32) -> Result<(Guid, String, i32), Error>;
+    fn get_event_count(&self) -> Result<i32, Error>;
+    fn get_event_list(&self, capacity: i32) -> Result<Vec<EventDescription>, Error>;
+    fn get_bus_count(&self) -> Result<i32, Error>;
+    fn get_bus_list(&self, capacity: i32) -> Result<Vec<Bus>, Error>;
+    fn get_vca_count(&self) -> Result<i32, Error>;
+    fn get_vca_list(&self, capacity: i32) -> Result<Vec<Vca>, Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+}
+impl BankApi for Bank {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        Bank::is_valid(self)
+    }
+    #[inline]
+    fn get_id(&self) -> Result<Guid, Error> {
+        Bank::get_id(self)
+    }
+    #[inline]
+    fn get_path(&self) -> Result<String, Error> {
+        Bank::get_path(self)
+    }
+    #[inline]
+    fn unload(&self) -> Result<(), Error> {
+        Bank::unload(self)
+    }
+    #[inline]
+    fn load_sample_data(&self) -> Result<(), Error> {
+        Bank::load_sample_data(self)
+    }
+    #[inline]
+    fn unload_sample_data(&self) -> Result<(), Error> {
+        Bank::unload_sample_data(self)
+    }
+    #[inline]
+    fn get_loading_state(&self) -> Result<LoadingState, Error> {
+        Bank::get_loading_state(self)
+    }
+    #[inline]
+    fn get_sample_loading_state(&self) -> Result<LoadingState, Error> {
+        Bank::get_sample_loading_state(self)
+    }
+    #[inline]
+    fn get_string_count(&self) -> Result<i32, Error> {
+        Bank::get_string_count(self)
+    }
+    #[inline]
+    fn get_string_info(&self, index: i32, size: i32) -> Result<(Guid, String, i32), Error> {
+        Bank::get_string_info(self, index, size)
+    }
+    #[inline]
+    fn get_event_count(&self) -> Result<i32, Error> {
+        Bank::get_event_count(self)
+    }
+    #[inline]
+    fn get_event_list(&self, capacity: i32) -> Result<Vec<EventDescription>, Error> {
+        Bank::get_event_list(self, capacity)
+    }
+    #[inline]
+    fn get_bus_count(&self) -> Result<i32, Error> {
+        Bank::get_bus_count(self)
+    }
+    #[inline]
+    fn get_bus_list(&self, capacity: i32) -> Result<Vec<Bus>, Error> {
+        Bank::get_bus_list(self, capacity)
+    }
+    #[inline]
+    fn get_vca_count(&self) -> Result<i32, Error> {
+        Bank::get_vca_count(self)
+    }
+    #[inline]
+    fn get_vca_list(&self, capacity: i32) -> Result<Vec<Vca>, Error> {
+        Bank::get_vca_list(self, capacity)
+    }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        Bank::get_user_data(self)
+    }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        Bank::set_user_data(self, userdata)
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct Bus {
+    pointer: *mut ffi::FMOD_STUDIO_BUS,
+}
+unsafe impl Send for Bus {}
+unsafe impl Sync for Bus {}
+impl Bus {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_STUDIO_BUS) -> Self {
+        Self { pointer }
+    }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_BUS {
+        self.pointer
+    }
+    pub fn is_valid(&self) -> bool {
+        unsafe { to_bool!(ffi::FMOD_Studio_Bus_IsValid(self.pointer)) }
+    }
+    pub fn get_id(&self) -> Result<Guid, Error> {
+        unsafe {
+            let mut id = ffi::FMOD_GUID::default();
+            match ffi::FMOD_Studio_Bus_GetID(self.pointer, &mut id) {
+                ffi::FMOD_OK => Ok(Guid::try_from(id)?),
+                error => Err(err_fmod!("FMOD_Studio_Bus_GetID", error)),
+            }
+        }
+    }
+    pub fn get_path(&self) -> Result<String, Error> {
+        unsafe {
+            let mut retrieved = i32::default();
+            match ffi::FMOD_Studio_Bus_GetPath(self.pointer, null_mut(), 0, &mut retrieved) {
+                ffi::FMOD_OK => {
+                    let mut buf = vec![0u8; retrieved as usize];
+                    match ffi::FMOD_Studio_Bus_GetPath(
+                        self.pointer,
+                        buf.as_mut_ptr() as *mut _,
+                        retrieved,
+                        &mut retrieved,
+                    ) {
+                        ffi::FMOD_OK => Ok(CString::from_vec_with_nul_unchecked(buf)
+                            .into_string()
+                            .map_err(Error::String)?),
+                        error => Err(err_fmod!("FMOD_Studio_Bus_GetPath", error)),
+                    }
+                }
+                error => Err(err_fmod!("FMOD_Studio_Bus_GetPath", error)),
+            }
+        }
+    }
+    pub fn get_volume(&self) -> Result<(f32, f32), Error> {
+        unsafe {
+            let mut volume = f32::default();
+            let mut finalvolume = f32::default();
+            match ffi::FMOD_Studio_Bus_GetVolume(self.pointer, &mut volume, &mut finalvolume) {
+                ffi::FMOD_OK => Ok((volume, finalvolume)),
+                error => Err(err_fmod!("FMOD_Studio_Bus_GetVolume", error)),
+            }
+        }
+    }
+    pub fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_Bus_SetVolume(self.pointer, volume) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_Bus_SetVolume", error)),
+            }
+        }
+    }
+    pub fn get_paused(&self) -> Result<bool, Error> {
+        unsafe {
+            let mut paused = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Studio_Bus_GetPaused(self.pointer, &mut paused) {
+                ffi::FMOD_OK => Ok(to_bool!(paused)),
+                error => Err(err_fmod!("FMOD_Studio_Bus_GetPaused", error)),
+            }
+        }
+    }
+    pub fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_Bus_SetPaused(self.pointer, from_bool!(paused)) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_Bus_SetPaused", error)),
+            }
+        }
+    }
+    pub fn get_mute(&self) -> Result<bool, Error> {
+        unsafe {
+            let mut mute = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Studio_Bus_GetMute(self.pointer, &mut mute) {
+                ffi::FMOD_OK => Ok(to_bool!(mute)),
+                error => Err(err_fmod!("FMOD_Studio_Bus_GetMute", error)),
+            }
+        }
+    }
+    pub fn set_mute(&self, mute: bool) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_Bus_SetMute(self.pointer, from_bool!(mute)) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_Bus_SetMute", error)),
+            }
+        }
+    }
+    pub fn stop_all_events(&self, mode: StopMode) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_Bus_StopAllEvents(self.pointer, mode.into()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_Bus_StopAllEvents", error)),
+            }
+        }
+    }
+    pub fn get_port_index(&self) -> Result<ffi::FMOD_PORT_INDEX, Error> {
+        unsafe {
+            let mut index = ffi::FMOD_PORT_INDEX::default();
+            match ffi::FMOD_Studio_Bus_GetPortIndex(self.pointer, &mut index) {
+                ffi::FMOD_OK => Ok(index),
+                error => Err(err_fmod!("FMOD_Studio_Bus_GetPortIndex", error)),
+            }
+        }
+    }
+    pub fn set_port_index(&self, index: impl Into<ffi::FMOD_PORT_INDEX>) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_Bus_SetPortIndex(self.pointer, index.into()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_Bus_SetPortIndex", error)),
+            }
+        }
+    }
+    pub fn lock_channel_group(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_Bus_LockChannelGroup(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_Bus_LockChannelGroup", error)),
+            }
+        }
+    }
+    pub fn unlock_channel_group(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_Bus_UnlockChannelGroup(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_Bus_UnlockChannelGroup", error)),
+            }
+        }
+    }
+    pub fn get_channel_group(&self) -> Result<ChannelGroup, Error> {
+        unsafe {
+            let mut group = null_mut();
+            match ffi::FMOD_Studio_Bus_GetChannelGroup(self.pointer, &mut group) {
+                ffi::FMOD_OK => Ok(ChannelGroup::from(group)),
+                error => Err(err_fmod!("FMOD_Studio_Bus_GetChannelGroup", error)),
+            }
+        }
+    }
+    pub fn get_cpu_usage(&self) -> Result<(u32, u32), Error> {
+        unsafe {
+            let mut exclusive = u32::default();
+            let mut inclusive = u32::default();
+            match ffi::FMOD_Studio_Bus_GetCPUUsage(self.pointer, &mut exclusive, &mut inclusive) {
+                ffi::FMOD_OK => Ok((exclusive, inclusive)),
+                error => Err(err_fmod!("FMOD_Studio_Bus_GetCPUUsage", error)),
+            }
+        }
+    }
+    pub fn get_memory_usage(&self) -> Result<MemoryUsage, Error> {
+        unsafe {
+            let mut memoryusage = ffi::FMOD_STUDIO_MEMORY_USAGE::default();
+            match ffi::FMOD_Studio_Bus_GetMemoryUsage(self.pointer, &mut memoryusage) {
+                ffi::FMOD_OK => Ok(MemoryUsage::try_from(memoryusage)?),
+                error => Err(err_fmod!("FMOD_Studio_Bus_GetMemoryUsage", error)),
+            }
+        }
+    }
+}
+#[doc = " Methods of [`Bus`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait BusApi {
+    fn is_valid(&self) -> bool;
+    fn get_id(&self) -> Result<Guid, Error>;
+    fn get_path(&self) -> Result<String, Error>;
+    fn get_volume(&self) -> Result<(f32, f32), Error>;
+    fn set_volume(&self, volume: f32) -> Result<(), Error>;
+    fn get_paused(&self) -> Result<bool, Error>;
+    fn set_paused(&self, paused: bool) -> Result<(), Error>;
+    fn get_mute(&self) -> Result<bool, Error>;
+    fn set_mute(&self, mute: bool) -> Result<(), Error>;
+    fn stop_all_events(&self, mode: StopMode) -> Result<(), Error>;
+    fn get_port_index(&self) -> Result<ffi::FMOD_PORT_INDEX, Error>;
+    fn set_port_index(&self, index: impl Into<ffi::FMOD_PORT_INDEX>) -> Result<(), Error>;
+    fn lock_channel_group(&self) -> Result<(), Error>;
+    fn unlock_channel_group(&self) -> Result<(), Error>;
+    fn get_channel_group(&self) -> Result<ChannelGroup, Error>;
+    fn get_cpu_usage(&self) -> Result<(u32, u32), Error>;
+    fn get_memory_usage(&self) -> Result<MemoryUsage, Error>;
+}
+impl BusApi for Bus {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        Bus::is_valid(self)
+    }
+    #[inline]
+    fn get_id(&self) -> Result<Guid, Error> {
+        Bus::get_id(self)
+    }
+    #[inline]
+    fn get_path(&self) -> Result<String, Error> {
+        Bus::get_path(self)
+    }
+    #[inline]
+    fn get_volume(&self) -> Result<(f32, f32), Error> {
+        Bus::get_volume(self)
+    }
+    #[inline]
+    fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        Bus::set_volume(self, volume)
+    }
+    #[inline]
+    fn get_paused(&self) -> Result<bool, Error> {
+        Bus::get_paused(self)
+    }
+    #[inline]
+    fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        Bus::set_paused(self, paused)
+    }
+    #[inline]
+    fn get_mute(&self) -> Result<bool, Error> {
+        Bus::get_mute(self)
+    }
+    #[inline]
+    fn set_mute(&self, mute: bool) -> Result<(), Error> {
+        Bus::set_mute(self, mute)
+    }
+    #[inline]
+    fn stop_all_events(&self, mode: StopMode) -> Result<(), Error> {
+        Bus::stop_all_events(self, mode)
+    }
+    #[inline]
+    fn get_port_index(&self) -> Result<ffi::FMOD_PORT_INDEX, Error> {
+        Bus::get_port_index(self)
+    }
+    #[inline]
+    fn set_port_index(&self, index: impl Into<ffi::FMOD_PORT_INDEX>) -> Result<(), Error> {
+        Bus::set_port_index(self, index)
+    }
+    #[inline]
+    fn lock_channel_group(&self) -> Result<(), Error> {
+        Bus::lock_channel_group(self)
+    }
+    #[inline]
+    fn unlock_channel_group(&self) -> Result<(), Error> {
+        Bus::unlock_channel_group(self)
+    }
+    #[inline]
+    fn get_channel_group(&self) -> Result<ChannelGroup, Error> {
+        Bus::get_channel_group(self)
+    }
+    #[inline]
+    fn get_cpu_usage(&self) -> Result<(u32, u32), Error> {
+        Bus::get_cpu_usage(self)
+    }
+    #[inline]
+    fn get_memory_usage(&self) -> Result<MemoryUsage, Error> {
+        Bus::get_memory_usage(self)
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct CommandReplay {
+    pointer: *mut ffi::FMOD_STUDIO_COMMANDREPLAY,
+}
+unsafe impl Send for CommandReplay {}
+unsafe impl Sync for CommandReplay {}
+impl CommandReplay {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_STUDIO_COMMANDREPLAY) -> Self {
+        Self { pointer }
+    }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_COMMANDREPLAY {
+        self.pointer
+    }
+    pub fn is_valid(&self) -> bool {
+        unsafe { to_bool!(ffi::FMOD_Studio_CommandReplay_IsValid(self.pointer)) }
+    }
+    pub fn get_system(&self) -> Result<Studio, Error> {
+        unsafe {
+            let mut system = null_mut();
+            match ffi::FMOD_Studio_CommandReplay_GetSystem(self.pointer, &mut system) {
+                ffi::FMOD_OK => Ok(Studio::from(system)),
+                error => Err(err_fmod!("FMOD_Studio_CommandReplay_GetSystem", error)),
+            }
+        }
+    }
+    pub fn get_length(&self) -> Result<f32, Error> {
+        unsafe {
+            let mut length = f32::default();
+            match ffi::FMOD_Studio_CommandReplay_GetLength(self.pointer, &mut length) {
+                ffi::FMOD_OK => Ok(length),
+                error => Err(err_fmod!("FMOD_Studio_CommandReplay_GetLength", error)),
+            }
+        }
+    }
+    pub fn get_command_count(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_CommandReplay_GetCommandCount(self.pointer, &mut count) {
+                ffi::FMOD_OK => Ok(count),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_CommandReplay_GetCommandCount",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_command_info(&self, commandindex: i32) -> Result<CommandInfo, Error> {
+        unsafe {
+            let mut info = ffi::FMOD_STUDIO_COMMAND_INFO::default();
+            match ffi::FMOD_Studio_CommandReplay_GetCommandInfo(
+                self.pointer,
+                commandindex,
+                &mut info,
+            ) {
+                ffi::FMOD_OK => Ok(CommandInfo::try_from(info)?),
+                error => Err(err_fmod!("FMOD_Studio_CommandReplay_GetCommandInfo", error)),
+            }
+        }
+    }
+    pub fn get_command_string(&self, commandindex: i32, length: i32) -> Result<String, Error> {
+        unsafe {
+            let mut buffer = vec![0 as c_char; length.max(1) as usize];
+            match ffi::FMOD_Studio_CommandReplay_GetCommandString(
+                self.pointer,
+                commandindex,
+                buffer.as_mut_ptr(),
+                buffer.len() as i32,
+            ) {
+                ffi::FMOD_OK => Ok(CStr::from_ptr(buffer.as_ptr())
+                    .to_string_lossy()
+                    .into_owned()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_CommandReplay_GetCommandString",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_command_at_time(&self, time: f32) -> Result<i32, Error> {
+        unsafe {
+            let mut commandindex = i32::default();
+            match ffi::FMOD_Studio_CommandReplay_GetCommandAtTime(
+                self.pointer,
+                time,
+                &mut commandindex,
+            ) {
+                ffi::FMOD_OK => Ok(commandindex),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_CommandReplay_GetCommandAtTime",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn set_bank_path(&self, bank_path: &str) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_CommandReplay_SetBankPath(
+                self.pointer,
+                CString::new(bank_path)?.as_ptr(),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_CommandReplay_SetBankPath", error)),
+            }
+        }
+    }
+    pub fn start(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_CommandReplay_Start(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_CommandReplay_Start", error)),
+            }
+        }
+    }
+    pub fn stop(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_CommandReplay_Stop(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_CommandReplay_Stop", error)),
+            }
+        }
+    }
+    pub fn seek_to_time(&self, time: f32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_CommandReplay_SeekToTime(self.pointer, time) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_CommandReplay_SeekToTime", error)),
+            }
+        }
+    }
+    pub fn seek_to_command(&self, commandindex: i32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_CommandReplay_SeekToCommand(self.pointer, commandindex) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_CommandReplay_SeekToCommand", error)),
+            }
+        }
+    }
+    pub fn get_paused(&self) -> Result<bool, Error> {
+        unsafe {
+            let mut paused = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Studio_CommandReplay_GetPaused(self.pointer, &mut paused) {
+                ffi::FMOD_OK => Ok(to_bool!(paused)),
+                error => Err(err_fmod!("FMOD_Studio_CommandReplay_GetPaused", error)),
+            }
+        }
+    }
+    pub fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_CommandReplay_SetPaused(self.pointer, from_bool!(paused)) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_CommandReplay_SetPaused", error)),
+            }
+        }
+    }
+    pub fn get_playback_state(&self) -> Result<PlaybackState, Error> {
+        unsafe {
+            let mut state = ffi::FMOD_STUDIO_PLAYBACK_STATE::default();
+            match ffi::FMOD_Studio_CommandReplay_GetPlaybackState(self.pointer, &mut state) {
+                ffi::FMOD_OK => Ok(PlaybackState::from(state)?),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_CommandReplay_GetPlaybackState",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_current_command(&self) -> Result<(i32, f32), Error> {
+        unsafe {
+            let mut commandindex = i32::default();
+            let mut currenttime = f32::default();
+            match ffi::FMOD_Studio_CommandReplay_GetCurrentCommand(
+                self.pointer,
+                &mut commandindex,
+                &mut currenttime,
+            ) {
+                ffi::FMOD_OK => Ok((commandindex, currenttime)),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_CommandReplay_GetCurrentCommand",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn release(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_CommandReplay_Release(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_CommandReplay_Release", error)),
+            }
+        }
+    }
+    pub fn set_frame_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_FRAME_CALLBACK,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_CommandReplay_SetFrameCallback(self.pointer, callback) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_CommandReplay_SetFrameCallback",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn set_load_bank_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_LOAD_BANK_CALLBACK,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_CommandReplay_SetLoadBankCallback(self.pointer, callback) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_CommandReplay_SetLoadBankCallback",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn set_create_instance_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_CREATE_INSTANCE_CALLBACK,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_CommandReplay_SetCreateInstanceCallback(self.pointer, callback) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_CommandReplay_SetCreateInstanceCallback",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        unsafe {
+            let mut userdata = null_mut();
+            match ffi::FMOD_Studio_CommandReplay_GetUserData(self.pointer, &mut userdata) {
+                ffi::FMOD_OK => Ok(userdata),
+                error => Err(err_fmod!("FMOD_Studio_CommandReplay_GetUserData", error)),
+            }
+        }
+    }
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_CommandReplay_SetUserData(self.pointer, userdata) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_CommandReplay_SetUserData", error)),
+            }
+        }
+    }
+}
+#[doc = " Methods of [`CommandReplay`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait CommandReplayApi {
+    fn is_valid(&self) -> bool;
+    fn get_system(&self) -> Result<Studio, Error>;
+    fn get_length(&self) -> Result<f32, Error>;
+    fn get_command_count(&self) -> Result<i32, Error>;
+    fn get_command_info(&self, commandindex: i32) -> Result<CommandInfo, Error>;
+    fn get_command_string(&self, commandindex: i32, length: i32) -> Result<String, Error>;
+    fn get_command_at_time(&self, time: f32) -> Result<i32, Error>;
+    fn set_bank_path(&self, bank_path: &str) -> Result<(), Error>;
+    fn start(&self) -> Result<(), Error>;
+    fn stop(&self) -> Result<(), Error>;
+    fn seek_to_time(&self, time: f32) -> Result<(), Error>;
+    fn seek_to_command(&self, commandindex: i32) -> Result<(), Error>;
+    fn get_paused(&self) -> Result<bool, Error>;
+    fn set_paused(&self, paused: bool) -> Result<(), Error>;
+    fn get_playback_state(&self) -> Result<PlaybackState, Error>;
+    fn get_current_command(&self) -> Result<(i32, f32), Error>;
+    fn release(&self) -> Result<(), Error>;
+    fn set_frame_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_FRAME_CALLBACK,
+    ) -> Result<(), Error>;
+    fn set_load_bank_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_LOAD_BANK_CALLBACK,
+    ) -> Result<(), Error>;
+    fn set_create_instance_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_CREATE_INSTANCE_CALLBACK,
+    ) -> Result<(), Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+}
+impl CommandReplayApi for CommandReplay {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        CommandReplay::is_valid(self)
+    }
+    #[inline]
+    fn get_system(&self) -> Result<Studio, Error> {
+        CommandReplay::get_system(self)
+    }
+    #[inline]
+    fn get_length(&self) -> Result<f32, Error> {
+        CommandReplay::get_length(self)
+    }
+    #[inline]
+    fn get_command_count(&self) -> Result<i32, Error> {
+        CommandReplay::get_command_count(self)
+    }
+    #[inline]
+    fn get_command_info(&self, commandindex: i32) -> Result<CommandInfo, Error> {
+        CommandReplay::get_command_info(self, commandindex)
+    }
+    #[inline]
+    fn get_command_string(&self, commandindex: i32, length: i32) -> Result<String, Error> {
+        CommandReplay::get_command_string(self, commandindex, length)
+    }
+    #[inline]
+    fn get_command_at_time(&self, time: f32) -> Result<i32, Error> {
+        CommandReplay::get_command_at_time(self, time)
+    }
+    #[inline]
+    fn set_bank_path(&self, bank_path: &str) -> Result<(), Error> {
+        CommandReplay::set_bank_path(self, bank_path)
+    }
+    #[inline]
+    fn start(&self) -> Result<(), Error> {
+        CommandReplay::start(self)
+    }
+    #[inline]
+    fn stop(&self) -> Result<(), Error> {
+        CommandReplay::stop(self)
+    }
+    #[inline]
+    fn seek_to_time(&self, time: f32) -> Result<(), Error> {
+        CommandReplay::seek_to_time(self, time)
+    }
+    #[inline]
+    fn seek_to_command(&self, commandindex: i32) -> Result<(), Error> {
+        CommandReplay::seek_to_command(self, commandindex)
+    }
+    #[inline]
+    fn get_paused(&self) -> Result<bool, Error> {
+        CommandReplay::get_paused(self)
+    }
+    #[inline]
+    fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        CommandReplay::set_paused(self, paused)
+    }
+    #[inline]
+    fn get_playback_state(&self) -> Result<PlaybackState, Error> {
+        CommandReplay::get_playback_state(self)
+    }
+    #[inline]
+    fn get_current_command(&self) -> Result<(i32, f32), Error> {
+        CommandReplay::get_current_command(self)
+    }
+    #[inline]
+    fn release(&self) -> Result<(), Error> {
+        CommandReplay::release(self)
+    }
+    #[inline]
+    fn set_frame_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_FRAME_CALLBACK,
+    ) -> Result<(), Error> {
+        CommandReplay::set_frame_callback(self, callback)
+    }
+    #[inline]
+    fn set_load_bank_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_LOAD_BANK_CALLBACK,
+    ) -> Result<(), Error> {
+        CommandReplay::set_load_bank_callback(self, callback)
+    }
+    #[inline]
+    fn set_create_instance_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_CREATE_INSTANCE_CALLBACK,
+    ) -> Result<(), Error> {
+        CommandReplay::set_create_instance_callback(self, callback)
+    }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        CommandReplay::get_user_data(self)
+    }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        CommandReplay::set_user_data(self, userdata)
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct EventDescription {
+    pointer: *mut ffi::FMOD_STUDIO_EVENTDESCRIPTION,
+}
+unsafe impl Send for EventDescription {}
+unsafe impl Sync for EventDescription {}
+impl EventDescription {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_STUDIO_EVENTDESCRIPTION) -> Self {
+        Self { pointer }
+    }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_EVENTDESCRIPTION {
+        self.pointer
+    }
+    pub fn is_valid(&self) -> bool {
+        unsafe { to_bool!(ffi::FMOD_Studio_EventDescription_IsValid(self.pointer)) }
+    }
+    pub fn get_id(&self) -> Result<Guid, Error> {
+        unsafe {
+            let mut id = ffi::FMOD_GUID::default();
+            match ffi::FMOD_Studio_EventDescription_GetID(self.pointer, &mut id) {
+                ffi::FMOD_OK => Ok(Guid::try_from(id)?),
+                error => Err(err_fmod!("FMOD_Studio_EventDescription_GetID", error)),
+            }
+        }
+    }
+    pub fn get_path(&self) -> Result<String, Error> {
+        unsafe {
+            let mut retrieved = i32::default();
+            match ffi::FMOD_Studio_EventDescription_GetPath(
+                self.pointer,
+                null_mut(),
+                0,
+                &mut retrieved,
+            ) {
+                ffi::FMOD_OK => {
+                    let mut buf = vec![0u8; retrieved as usize];
+                    match ffi::FMOD_Studio_EventDescription_GetPath(
+                        self.pointer,
+                        buf.as_mut_ptr() as *mut _,
+                        retrieved,
+                        &mut retrieved,
+                    ) {
+                        ffi::FMOD_OK => Ok(CString::from_vec_with_nul_unchecked(buf)
+                            .into_string()
+                            .map_err(Error::String)?),
+                        error => Err(err_fmod!("FMOD_Studio_EventDescription_GetPath", error)),
+                    }
+                }
+                error => Err(err_fmod!("FMOD_Studio_EventDescription_GetPath", error)),
+            }
+        }
+    }
+    pub fn get_parameter_description_count(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_EventDescription_GetParameterDescriptionCount(
+                self.pointer,
+                &mut count,
+            ) {
+                ffi::FMOD_OK => Ok(count),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetParameterDescriptionCount",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_parameter_description_by_index(
+        &self,
+        index: i32,
+    ) -> Result<ParameterDescription, Error> {
+        unsafe {
+            let mut parameter = ffi::FMOD_STUDIO_PARAMETER_DESCRIPTION::default();
+            match ffi::FMOD_Studio_EventDescription_GetParameterDescriptionByIndex(
+                self.pointer,
+                index,
+                &mut parameter,
+            ) {
+                ffi::FMOD_OK => Ok(ParameterDescription::try_from(parameter)?),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetParameterDescriptionByIndex",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_parameter_description_by_name(
+        &self,
+        name: &str,
+    ) -> Result<ParameterDescription, Error> {
+        unsafe {
+            let mut parameter = ffi::FMOD_STUDIO_PARAMETER_DESCRIPTION::default();
+            match ffi::FMOD_Studio_EventDescription_GetParameterDescriptionByName(
+                self.pointer,
+                CString::new(name)?.as_ptr(),
+                &mut parameter,
+            ) {
+                ffi::FMOD_OK => Ok(ParameterDescription::try_from(parameter)?),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetParameterDescriptionByName",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_parameter_description_by_id(
+        &self,
+        id: ParameterId,
+    ) -> Result<ParameterDescription, Error> {
+        unsafe {
+            let mut parameter = ffi::FMOD_STUDIO_PARAMETER_DESCRIPTION::default();
+            match ffi::FMOD_Studio_EventDescription_GetParameterDescriptionByID(
+                self.pointer,
+                id.into(),
+                &mut parameter,
+            ) {
+                ffi::FMOD_OK => Ok(ParameterDescription::try_from(parameter)?),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetParameterDescriptionByID",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_parameter_label_by_index(
+        &self,
+        index: i32,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        unsafe {
+            let mut label = vec![0 as c_char; size.max(1) as usize];
+            let mut retrieved = i32::default();
+            match ffi::FMOD_Studio_EventDescription_GetParameterLabelByIndex(
+                self.pointer,
+                index,
+                labelindex,
+                label.as_mut_ptr(),
+                label.len() as i32,
+                &mut retrieved,
+            ) {
+                ffi::FMOD_OK => Ok((
+                    CStr::from_ptr(label.as_ptr())
+                        .to_string_lossy()
+                        .into_owned(),
+                    retrieved,
+                )),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetParameterLabelByIndex",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_parameter_label_by_name(
+        &self,
+        name: &str,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        unsafe {
+            let mut label = vec![0 as c_char; size.max(1) as usize];
+            let mut retrieved = i32::default();
+            match ffi::FMOD_Studio_EventDescription_GetParameterLabelByName(
+                self.pointer,
+                CString::new(name)?.as_ptr(),
+                labelindex,
+                label.as_mut_ptr(),
+                label.len() as i32,
+                &mut retrieved,
+            ) {
+                ffi::FMOD_OK => Ok((
+                    CStr::from_ptr(label.as_ptr())
+                        .to_string_lossy()
+                        .into_owned(),
+                    retrieved,
+                )),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetParameterLabelByName",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_parameter_label_by_id(
+        &self,
+        id: ParameterId,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        unsafe {
+            let mut label = vec![0 as c_char; size.max(1) as usize];
+            let mut retrieved = i32::default();
+            match ffi::FMOD_Studio_EventDescription_GetParameterLabelByID(
+                self.pointer,
+                id.into(),
+                labelindex,
+                label.as_mut_ptr(),
+                label.len() as i32,
+                &mut retrieved,
+            ) {
+                ffi::FMOD_OK => Ok((
+                    CStr::from_ptr(label.as_ptr())
+                        .to_string_lossy()
+                        .into_owned(),
+                    retrieved,
+                )),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetParameterLabelByID",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_user_property_count(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_EventDescription_GetUserPropertyCount(self.pointer, &mut count) {
+                ffi::FMOD_OK => Ok(count),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetUserPropertyCount",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_user_property_by_index(&self, index: i32) -> Result<UserProperty, Error> {
+        unsafe {
+            let mut property = ffi::FMOD_STUDIO_USER_PROPERTY::default();
+            match ffi::FMOD_Studio_EventDescription_GetUserPropertyByIndex(
+                self.pointer,
+                index,
+                &mut property,
+            ) {
+                ffi::FMOD_OK => Ok(UserProperty::try_from(property)?),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetUserPropertyByIndex",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_user_property(&self, name: &str) -> Result<UserProperty, Error> {
+        unsafe {
+            let mut property = ffi::FMOD_STUDIO_USER_PROPERTY::default();
+            match ffi::FMOD_Studio_EventDescription_GetUserProperty(
+                self.pointer,
+                CString::new(name)?.as_ptr(),
+                &mut property,
+            ) {
+                ffi::FMOD_OK => Ok(UserProperty::try_from(property)?),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetUserProperty",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_length(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut length = i32::default();
+            match ffi::FMOD_Studio_EventDescription_GetLength(self.pointer, &mut length) {
+                ffi::FMOD_OK => Ok(length),
+                error => Err(err_fmod!("FMOD_Studio_EventDescription_GetLength", error)),
+            }
+        }
+    }
+    pub fn get_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        unsafe {
+            let mut min = f32::default();
+            let mut max = f32::default();
+            match ffi::FMOD_Studio_EventDescription_GetMinMaxDistance(
+                self.pointer,
+                &mut min,
+                &mut max,
+            ) {
+                ffi::FMOD_OK => Ok((min, max)),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetMinMaxDistance",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_sound_size(&self) -> Result<f32, Error> {
+        unsafe {
+            let mut size = f32::default();
+            match ffi::FMOD_Studio_EventDescription_GetSoundSize(self.pointer, &mut size) {
+                ffi::FMOD_OK => Ok(size),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetSoundSize",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn is_snapshot(&self) -> Result<bool, Error> {
+        unsafe {
+            let mut snapshot = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Studio_EventDescription_IsSnapshot(self.pointer, &mut snapshot) {
+                ffi::FMOD_OK => Ok(to_bool!(snapshot)),
+                error => Err(err_fmod!("FMOD_Studio_EventDescription_IsSnapshot", error)),
+            }
+        }
+    }
+    pub fn is_oneshot(&self) -> Result<bool, Error> {
+        unsafe {
+            let mut oneshot = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Studio_EventDescription_IsOneshot(self.pointer, &mut oneshot) {
+                ffi::FMOD_OK => Ok(to_bool!(oneshot)),
+                error => Err(err_fmod!("FMOD_Studio_EventDescription_IsOneshot", error)),
+            }
+        }
+    }
+    pub fn is_stream(&self) -> Result<bool, Error> {
+        unsafe {
+            let mut is_stream = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Studio_EventDescription_IsStream(self.pointer, &mut is_stream) {
+                ffi::FMOD_OK => Ok(to_bool!(is_stream)),
+                error => Err(err_fmod!("FMOD_Studio_EventDescription_IsStream", error)),
+            }
+        }
+    }
+    pub fn is_3d(&self) -> Result<bool, Error> {
+        unsafe {
+            let mut is_3_d = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Studio_EventDescription_Is3D(self.pointer, &mut is_3_d) {
+                ffi::FMOD_OK => Ok(to_bool!(is_3_d)),
+                error => Err(err_fmod!("FMOD_Studio_EventDescription_Is3D", error)),
+            }
+        }
+    }
+    pub fn is_doppler_enabled(&self) -> Result<bool, Error> {
+        unsafe {
+            let mut doppler = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Studio_EventDescription_IsDopplerEnabled(self.pointer, &mut doppler) {
+                ffi::FMOD_OK => Ok(to_bool!(doppler)),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_IsDopplerEnabled",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn has_sustain_point(&self) -> Result<bool, Error> {
+        unsafe {
+            let mut sustain_point = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Studio_EventDescription_HasSustainPoint(
+                self.pointer,
+                &mut sustain_point,
+            ) {
+                ffi::FMOD_OK => Ok(to_bool!(sustain_point)),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_HasSustainPoint",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn create_instance(&self) -> Result<EventInstance, Error> {
+        unsafe {
+            let mut instance = null_mut();
+            match ffi::FMOD_Studio_EventDescription_CreateInstance(self.pointer, &mut instance) {
+                ffi::FMOD_OK => Ok(EventInstance::from(instance)),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_CreateInstance",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_instance_count(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_EventDescription_GetInstanceCount(self.pointer, &mut count) {
+                ffi::FMOD_OK => Ok(count),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetInstanceCount",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_instance_list(&self, capacity: i32) -> Result<Vec<EventInstance>, Error> {
+        unsafe {
+            let mut array = vec![null_mut(); capacity as usize];
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_EventDescription_GetInstanceList(
+                self.pointer,
+                array.as_mut_ptr(),
+                capacity,
+                &mut count,
+            ) {
+                ffi::FMOD_OK => Ok(array
+                    .into_iter()
+                    .take(count as usize)
+                    .map(EventInstance::from)
+                    .collect()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetInstanceList",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn load_sample_data(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventDescription_LoadSampleData(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_LoadSampleData",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn unload_sample_data(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventDescription_UnloadSampleData(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_UnloadSampleData",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_sample_loading_state(&self) -> Result<LoadingState, Error> {
+        unsafe {
+            let mut state = ffi::FMOD_STUDIO_LOADING_STATE::default();
+            match ffi::FMOD_Studio_EventDescription_GetSampleLoadingState(self.pointer, &mut state)
+            {
+                ffi::FMOD_OK => Ok(LoadingState::from(state)?),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_GetSampleLoadingState",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn release_all_instances(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventDescription_ReleaseAllInstances(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventDescription_ReleaseAllInstances",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn set_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_EVENT_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_STUDIO_EVENT_CALLBACK_TYPE>,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventDescription_SetCallback(
+                self.pointer,
+                callback,
+                callbackmask.into(),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_EventDescription_SetCallback", error)),
+            }
+        }
+    }
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        unsafe {
+            let mut userdata = null_mut();
+            match ffi::FMOD_Studio_EventDescription_GetUserData(self.pointer, &mut userdata) {
+                ffi::FMOD_OK => Ok(userdata),
+                error => Err(err_fmod!("FMOD_Studio_EventDescription_GetUserData", error)),
+            }
+        }
+    }
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventDescription_SetUserData(self.pointer, userdata) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_EventDescription_SetUserData", error)),
+            }
+        }
+    }
+}
+#[doc = " Methods of [`EventDescription`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait EventDescriptionApi {
+    fn is_valid(&self) -> bool;
+    fn get_id(&self) -> Result<Guid, Error>;
+    fn get_path(&self) -> Result<String, Error>;
+    fn get_parameter_description_count(&self) -> Result<i32, Error>;
+    fn get_parameter_description_by_index(&self, index: i32)
+        -> Result<ParameterDescription, Error>;
+    fn get_parameter_description_by_name(&self, name: &str) -> Result<ParameterDescription, Error>;
+    fn get_parameter_description_by_id(
+        &self,
+        id: ParameterId,
+    ) -> Result<ParameterDescription, Error>;
+    fn get_parameter_label_by_index(
+        &self,
+        index: i32,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error>;
+    fn get_parameter_label_by_name(
+        &self,
+        name: &str,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error>;
+    fn get_parameter_label_by_id(
+        &self,
+        id: ParameterId,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error>;
+    fn get_user_property_count(&self) -> Result<i32, Error>;
+    fn get_user_property_by_index(&self, index: i32) -> Result<UserProperty, Error>;
+    fn get_user_property(&self, name: &str) -> Result<UserProperty, Error>;
+    fn get_length(&self) -> Result<i32, Error>;
+    fn get_min_max_distance(&self) -> Result<(f32, f32), Error>;
+    fn get_sound_size(&self) -> Result<f32, Error>;
+    fn is_snapshot(&self) -> Result<bool, Error>;
+    fn is_oneshot(&self) -> Result<bool, Error>;
+    fn is_stream(&self) -> Result<bool, Error>;
+    fn is_3d(&self) -> Result<bool, Error>;
+    fn is_doppler_enabled(&self) -> Result<bool, Error>;
+    fn has_sustain_point(&self) -> Result<bool, Error>;
+    fn create_instance(&self) -> Result<EventInstance, Error>;
+    fn get_instance_count(&self) -> Result<i32, Error>;
+    fn get_instance_list(&self, capacity: i32) -> Result<Vec<EventInstance>, Error>;
+    fn load_sample_data(&self) -> Result<(), Error>;
+    fn unload_sample_data(&self) -> Result<(), Error>;
+    fn get_sample_loading_state(&self) -> Result<LoadingState, Error>;
+    fn release_all_instances(&self) -> Result<(), Error>;
+    fn set_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_EVENT_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_STUDIO_EVENT_CALLBACK_TYPE>,
+    ) -> Result<(), Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+}
+impl EventDescriptionApi for EventDescription {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        EventDescription::is_valid(self)
+    }
+    #[inline]
+    fn get_id(&self) -> Result<Guid, Error> {
+        EventDescription::get_id(self)
+    }
+    #[inline]
+    fn get_path(&self) -> Result<String, Error> {
+        EventDescription::get_path(self)
+    }
+    #[inline]
+    fn get_parameter_description_count(&self) -> Result<i32, Error> {
+        EventDescription::get_parameter_description_count(self)
+    }
+    #[inline]
+    fn get_parameter_description_by_index(
+        &self,
+        index: i32,
+    ) -> Result<ParameterDescription, Error> {
+        EventDescription::get_parameter_description_by_index(self, index)
+    }
+    #[inline]
+    fn get_parameter_description_by_name(&self, name: &str) -> Result<ParameterDescription, Error> {
+        EventDescription::get_parameter_description_by_name(self, name)
+    }
+    #[inline]
+    fn get_parameter_description_by_id(
+        &self,
+        id: ParameterId,
+    ) -> Result<ParameterDescription, Error> {
+        EventDescription::get_parameter_description_by_id(self, id)
+    }
+    #[inline]
+    fn get_parameter_label_by_index(
+        &self,
+        index: i32,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        EventDescription::get_parameter_label_by_index(self, index, labelindex, size)
+    }
+    #[inline]
+    fn get_parameter_label_by_name(
+        &self,
+        name: &str,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        EventDescription::get_parameter_label_by_name(self, name, labelindex, size)
+    }
+    #[inline]
+    fn get_parameter_label_by_id(
+        &self,
+        id: ParameterId,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        EventDescription::get_parameter_label_by_id(self, id, labelindex, size)
+    }
+    #[inline]
+    fn get_user_property_count(&self) -> Result<i32, Error> {
+        EventDescription::get_user_property_count(self)
+    }
+    #[inline]
+    fn get_user_property_by_index(&self, index: i32) -> Result<UserProperty, Error> {
+        EventDescription::get_user_property_by_index(self, index)
+    }
+    #[inline]
+    fn get_user_property(&self, name: &str) -> Result<UserProperty, Error> {
+        EventDescription::get_user_property(self, name)
+    }
+    #[inline]
+    fn get_length(&self) -> Result<i32, Error> {
+        EventDescription::get_length(self)
+    }
+    #[inline]
+    fn get_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        EventDescription::get_min_max_distance(self)
+    }
+    #[inline]
+    fn get_sound_size(&self) -> Result<f32, Error> {
+        EventDescription::get_sound_size(self)
+    }
+    #[inline]
+    fn is_snapshot(&self) -> Result<bool, Error> {
+        EventDescription::is_snapshot(self)
+    }
+    #[inline]
+    fn is_oneshot(&self) -> Result<bool, Error> {
+        EventDescription::is_oneshot(self)
+    }
+    #[inline]
+    fn is_stream(&self) -> Result<bool, Error> {
+        EventDescription::is_stream(self)
+    }
+    #[inline]
+    fn is_3d(&self) -> Result<bool, Error> {
+        EventDescription::is_3d(self)
+    }
+    #[inline]
+    fn is_doppler_enabled(&self) -> Result<bool, Error> {
+        EventDescription::is_doppler_enabled(self)
+    }
+    #[inline]
+    fn has_sustain_point(&self) -> Result<bool, Error> {
+        EventDescription::has_sustain_point(self)
+    }
+    #[inline]
+    fn create_instance(&self) -> Result<EventInstance, Error> {
+        EventDescription::create_instance(self)
+    }
+    #[inline]
+    fn get_instance_count(&self) -> Result<i32, Error> {
+        EventDescription::get_instance_count(self)
+    }
+    #[inline]
+    fn get_instance_list(&self, capacity: i32) -> Result<Vec<EventInstance>, Error> {
+        EventDescription::get_instance_list(self, capacity)
+    }
+    #[inline]
+    fn load_sample_data(&self) -> Result<(), Error> {
+        EventDescription::load_sample_data(self)
+    }
+    #[inline]
+    fn unload_sample_data(&self) -> Result<(), Error> {
+        EventDescription::unload_sample_data(self)
+    }
+    #[inline]
+    fn get_sample_loading_state(&self) -> Result<LoadingState, Error> {
+        EventDescription::get_sample_loading_state(self)
+    }
+    #[inline]
+    fn release_all_instances(&self) -> Result<(), Error> {
+        EventDescription::release_all_instances(self)
+    }
+    #[inline]
+    fn set_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_EVENT_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_STUDIO_EVENT_CALLBACK_TYPE>,
+    ) -> Result<(), Error> {
+        EventDescription::set_callback(self, callback, callbackmask)
+    }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        EventDescription::get_user_data(self)
+    }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        EventDescription::set_user_data(self, userdata)
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct EventInstance {
+    pointer: *mut ffi::FMOD_STUDIO_EVENTINSTANCE,
+}
+unsafe impl Send for EventInstance {}
+unsafe impl Sync for EventInstance {}
+impl EventInstance {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_STUDIO_EVENTINSTANCE) -> Self {
+        Self { pointer }
+    }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_EVENTINSTANCE {
+        self.pointer
+    }
+    pub fn is_valid(&self) -> bool {
+        unsafe { to_bool!(ffi::FMOD_Studio_EventInstance_IsValid(self.pointer)) }
+    }
+    pub fn get_description(&self) -> Result<EventDescription, Error> {
+        unsafe {
+            let mut description = null_mut();
+            match ffi::FMOD_Studio_EventInstance_GetDescription(self.pointer, &mut description) {
+                ffi::FMOD_OK => Ok(EventDescription::from(description)),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetDescription", error)),
+            }
+        }
+    }
+    pub fn get_volume(&self) -> Result<(f32, f32), Error> {
+        unsafe {
+            let mut volume = f32::default();
+            let mut finalvolume = f32::default();
+            match ffi::FMOD_Studio_EventInstance_GetVolume(
+                self.pointer,
+                &mut volume,
+                &mut finalvolume,
+            ) {
+                ffi::FMOD_OK => Ok((volume, finalvolume)),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetVolume", error)),
+            }
+        }
+    }
+    pub fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetVolume(self.pointer, volume) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetVolume", error)),
+            }
+        }
+    }
+    pub fn get_pitch(&self) -> Result<(f32, f32), Error> {
+        unsafe {
+            let mut pitch = f32::default();
+            let mut finalpitch = f32::default();
+            match ffi::FMOD_Studio_EventInstance_GetPitch(self.pointer, &mut pitch, &mut finalpitch)
+            {
+                ffi::FMOD_OK => Ok((pitch, finalpitch)),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetPitch", error)),
+            }
+        }
+    }
+    pub fn set_pitch(&self, pitch: f32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetPitch(self.pointer, pitch) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetPitch", error)),
+            }
+        }
+    }
+    pub fn get_3d_attributes(&self) -> Result<Attributes3d, Error> {
+        unsafe {
+            let mut attributes = ffi::FMOD_3D_ATTRIBUTES::default();
+            match ffi::FMOD_Studio_EventInstance_Get3DAttributes(self.pointer, &mut attributes) {
+                ffi::FMOD_OK => Ok(Attributes3d::try_from(attributes)?),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_Get3DAttributes",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn set_3d_attributes(&self, attributes: Attributes3d) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_Set3DAttributes(
+                self.pointer,
+                &mut attributes.into(),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_Set3DAttributes",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_listener_mask(&self) -> Result<u32, Error> {
+        unsafe {
+            let mut mask = u32::default();
+            match ffi::FMOD_Studio_EventInstance_GetListenerMask(self.pointer, &mut mask) {
+                ffi::FMOD_OK => Ok(mask),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_GetListenerMask",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn set_listener_mask(&self, mask: u32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetListenerMask(self.pointer, mask) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_SetListenerMask",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_property(&self, index: EventProperty) -> Result<f32, Error> {
+        unsafe {
+            let mut value = f32::default();
+            match ffi::FMOD_Studio_EventInstance_GetProperty(self.pointer, index.into(), &mut value)
+            {
+                ffi::FMOD_OK => Ok(value),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetProperty", error)),
+            }
+        }
+    }
+    pub fn set_property(&self, index: EventProperty, value: f32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetProperty(self.pointer, index.into(), value) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetProperty", error)),
+            }
+        }
+    }
+    pub fn get_reverb_level(&self, index: i32) -> Result<f32, Error> {
+        unsafe {
+            let mut level = f32::default();
+            match ffi::FMOD_Studio_EventInstance_GetReverbLevel(self.pointer, index, &mut level) {
+                ffi::FMOD_OK => Ok(level),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetReverbLevel", error)),
+            }
+        }
+    }
+    pub fn set_reverb_level(&self, index: i32, level: f32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetReverbLevel(self.pointer, index, level) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetReverbLevel", error)),
+            }
+        }
+    }
+    pub fn get_paused(&self) -> Result<bool, Error> {
+        unsafe {
+            let mut paused = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Studio_EventInstance_GetPaused(self.pointer, &mut paused) {
+                ffi::FMOD_OK => Ok(to_bool!(paused)),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetPaused", error)),
+            }
+        }
+    }
+    pub fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetPaused(self.pointer, from_bool!(paused)) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetPaused", error)),
+            }
+        }
+    }
+    pub fn start(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_Start(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_Start", error)),
+            }
+        }
+    }
+    pub fn stop(&self, mode: StopMode) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_Stop(self.pointer, mode.into()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_Stop", error)),
+            }
+        }
+    }
+    pub fn get_timeline_position(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut position = i32::default();
+            match ffi::FMOD_Studio_EventInstance_GetTimelinePosition(self.pointer, &mut position) {
+                ffi::FMOD_OK => Ok(position),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_GetTimelinePosition",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn set_timeline_position(&self, position: i32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetTimelinePosition(self.pointer, position) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_SetTimelinePosition",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_playback_state(&self) -> Result<PlaybackState, Error> {
+        unsafe {
+            let mut state = ffi::FMOD_STUDIO_PLAYBACK_STATE::default();
+            match ffi::FMOD_Studio_EventInstance_GetPlaybackState(self.pointer, &mut state) {
+                ffi::FMOD_OK => Ok(PlaybackState::from(state)?),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_GetPlaybackState",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_channel_group(&self) -> Result<ChannelGroup, Error> {
+        unsafe {
+            let mut group = null_mut();
+            match ffi::FMOD_Studio_EventInstance_GetChannelGroup(self.pointer, &mut group) {
+                ffi::FMOD_OK => Ok(ChannelGroup::from(group)),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_GetChannelGroup",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        unsafe {
+            let mut min = f32::default();
+            let mut max = f32::default();
+            match ffi::FMOD_Studio_EventInstance_GetMinMaxDistance(self.pointer, &mut min, &mut max)
+            {
+                ffi::FMOD_OK => Ok((min, max)),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_GetMinMaxDistance",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn release(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_Release(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_Release", error)),
+            }
+        }
+    }
+    pub fn is_virtual(&self) -> Result<bool, Error> {
+        unsafe {
+            let mut virtualstate = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_Studio_EventInstance_IsVirtual(self.pointer, &mut virtualstate) {
+                ffi::FMOD_OK => Ok(to_bool!(virtualstate)),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_IsVirtual", error)),
+            }
+        }
+    }
+    pub fn get_parameter_by_name(&self, name: &str) -> Result<(f32, f32), Error> {
+        unsafe {
+            let mut value = f32::default();
+            let mut finalvalue = f32::default();
+            match ffi::FMOD_Studio_EventInstance_GetParameterByName(
+                self.pointer,
+                CString::new(name)?.as_ptr(),
+                &mut value,
+                &mut finalvalue,
+            ) {
+                ffi::FMOD_OK => Ok((value, finalvalue)),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_GetParameterByName",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn set_parameter_by_name(
+        &self,
+        name: &str,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetParameterByName(
+                self.pointer,
+                CString::new(name)?.as_ptr(),
+                value,
+                from_bool!(ignoreseekspeed),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_SetParameterByName",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn set_parameter_by_name_with_label(
+        &self,
+        name: &str,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetParameterByNameWithLabel(
+                self.pointer,
+                CString::new(name)?.as_ptr(),
+                CString::new(label)?.as_ptr(),
+                from_bool!(ignoreseekspeed),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_SetParameterByNameWithLabel",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_parameter_by_id(&self, id: ParameterId) -> Result<(f32, f32), Error> {
+        unsafe {
+            let mut value = f32::default();
+            let mut finalvalue = f32::default();
+            match ffi::FMOD_Studio_EventInstance_GetParameterByID(
+                self.pointer,
+                id.into(),
+                &mut value,
+                &mut finalvalue,
+            ) {
+                ffi::FMOD_OK => Ok((value, finalvalue)),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_GetParameterByID",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn set_parameter_by_id(
+        &self,
+        id: ParameterId,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetParameterByID(
+                self.pointer,
+                id.into(),
+                value,
+                from_bool!(ignoreseekspeed),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_SetParameterByID",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn set_parameter_by_id_with_label(
+        &self,
+        id: ParameterId,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetParameterByIDWithLabel(
+                self.pointer,
+                id.into(),
+                CString::new(label)?.as_ptr(),
+                from_bool!(ignoreseekspeed),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_SetParameterByIDWithLabel",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn set_parameters_by_i_ds(
+        &self,
+        ids: ParameterId,
+        values: *mut f32,
+        count: i32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetParametersByIDs(
+                self.pointer,
+                &ids.into(),
+                values,
+                count,
+                from_bool!(ignoreseekspeed),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_EventInstance_SetParametersByIDs",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn key_off(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_KeyOff(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_KeyOff", error)),
+            }
+        }
+    }
+    pub fn set_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_EVENT_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_STUDIO_EVENT_CALLBACK_TYPE>,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetCallback(
+                self.pointer,
+                callback,
+                callbackmask.into(),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetCallback", error)),
+            }
+        }
+    }
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        unsafe {
+            let mut userdata = null_mut();
+            match ffi::FMOD_Studio_EventInstance_GetUserData(self.pointer, &mut userdata) {
+                ffi::FMOD_OK => Ok(userdata),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetUserData", error)),
+            }
+        }
+    }
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_EventInstance_SetUserData(self.pointer, userdata) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetUserData", error)),
+            }
+        }
+    }
+    pub fn get_cpu_usage(&self) -> Result<(u32, u32), Error> {
+        unsafe {
+            let mut exclusive = u32::default();
+            let mut inclusive = u32::default();
+            match ffi::FMOD_Studio_EventInstance_GetCPUUsage(
+                self.pointer,
+                &mut exclusive,
+                &mut inclusive,
+            ) {
+                ffi::FMOD_OK => Ok((exclusive, inclusive)),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetCPUUsage", error)),
+            }
+        }
+    }
+    pub fn get_memory_usage(&self) -> Result<MemoryUsage, Error> {
+        unsafe {
+            let mut memoryusage = ffi::FMOD_STUDIO_MEMORY_USAGE::default();
+            match ffi::FMOD_Studio_EventInstance_GetMemoryUsage(self.pointer, &mut memoryusage) {
+                ffi::FMOD_OK => Ok(MemoryUsage::try_from(memoryusage)?),
+                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetMemoryUsage", error)),
+            }
+        }
+    }
+}
+#[doc = " Methods of [`EventInstance`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait EventInstanceApi {
+    fn is_valid(&self) -> bool;
+    fn get_description(&self) -> Result<EventDescription, Error>;
+    fn get_volume(&self) -> Result<(f32, f32), Error>;
+    fn set_volume(&self, volume: f32) -> Result<(), Error>;
+    fn get_pitch(&self) -> Result<(f32, f32), Error>;
+    fn set_pitch(&self, pitch: f32) -> Result<(), Error>;
+    fn get_3d_attributes(&self) -> Result<Attributes3d, Error>;
+    fn set_3d_attributes(&self, attributes: Attributes3d) -> Result<(), Error>;
+    fn get_listener_mask(&self) -> Result<u32, Error>;
+    fn set_listener_mask(&self, mask: u32) -> Result<(), Error>;
+    fn get_property(&self, index: EventProperty) -> Result<f32, Error>;
+    fn set_property(&self, index: EventProperty, value: f32) -> Result<(), Error>;
+    fn get_reverb_level(&self, index: i32) -> Result<f32, Error>;
+    fn set_reverb_level(&self, index: i32, level: f32) -> Result<(), Error>;
+    fn get_paused(&self) -> Result<bool, Error>;
+    fn set_paused(&self, paused: bool) -> Result<(), Error>;
+    fn start(&self) -> Result<(), Error>;
+    fn stop(&self, mode: StopMode) -> Result<(), Error>;
+    fn get_timeline_position(&self) -> Result<i32, Error>;
+    fn set_timeline_position(&self, position: i32) -> Result<(), Error>;
+    fn get_playback_state(&self) -> Result<PlaybackState, Error>;
+    fn get_channel_group(&self) -> Result<ChannelGroup, Error>;
+    fn get_min_max_distance(&self) -> Result<(f32, f32), Error>;
+    fn release(&self) -> Result<(), Error>;
+    fn is_virtual(&self) -> Result<bool, Error>;
+    fn get_parameter_by_name(&self, name: &str) -> Result<(f32, f32), Error>;
+    fn set_parameter_by_name(
+        &self,
+        name: &str,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error>;
+    fn set_parameter_by_name_with_label(
+        &self,
+        name: &str,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error>;
+    fn get_parameter_by_id(&self, id: ParameterId) -> Result<(f32, f32), Error>;
+    fn set_parameter_by_id(
+        &self,
+        id: ParameterId,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error>;
+    fn set_parameter_by_id_with_label(
+        &self,
+        id: ParameterId,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error>;
+    fn set_parameters_by_i_ds(
+        &self,
+        ids: ParameterId,
+        values: *mut f32,
+        count: i32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error>;
+    fn key_off(&self) -> Result<(), Error>;
+    fn set_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_EVENT_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_STUDIO_EVENT_CALLBACK_TYPE>,
+    ) -> Result<(), Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+    fn get_cpu_usage(&self) -> Result<(u32, u32), Error>;
+    fn get_memory_usage(&self) -> Result<MemoryUsage, Error>;
+}
+impl EventInstanceApi for EventInstance {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        EventInstance::is_valid(self)
+    }
+    #[inline]
+    fn get_description(&self) -> Result<EventDescription, Error> {
+        EventInstance::get_description(self)
+    }
+    #[inline]
+    fn get_volume(&self) -> Result<(f32, f32), Error> {
+        EventInstance::get_volume(self)
+    }
+    #[inline]
+    fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        EventInstance::set_volume(self, volume)
+    }
+    #[inline]
+    fn get_pitch(&self) -> Result<(f32, f32), Error> {
+        EventInstance::get_pitch(self)
+    }
+    #[inline]
+    fn set_pitch(&self, pitch: f32) -> Result<(), Error> {
+        EventInstance::set_pitch(self, pitch)
+    }
+    #[inline]
+    fn get_3d_attributes(&self) -> Result<Attributes3d, Error> {
+        EventInstance::get_3d_attributes(self)
+    }
+    #[inline]
+    fn set_3d_attributes(&self, attributes: Attributes3d) -> Result<(), Error> {
+        EventInstance::set_3d_attributes(self, attributes)
+    }
+    #[inline]
+    fn get_listener_mask(&self) -> Result<u32, Error> {
+        EventInstance::get_listener_mask(self)
+    }
+    #[inline]
+    fn set_listener_mask(&self, mask: u32) -> Result<(), Error> {
+        EventInstance::set_listener_mask(self, mask)
+    }
+    #[inline]
+    fn get_property(&self, index: EventProperty) -> Result<f32, Error> {
+        EventInstance::get_property(self, index)
+    }
+    #[inline]
+    fn set_property(&self, index: EventProperty, value: f32) -> Result<(), Error> {
+        EventInstance::set_property(self, index, value)
+    }
+    #[inline]
+    fn get_reverb_level(&self, index: i32) -> Result<f32, Error> {
+        EventInstance::get_reverb_level(self, index)
+    }
+    #[inline]
+    fn set_reverb_level(&self, index: i32, level: f32) -> Result<(), Error> {
+        EventInstance::set_reverb_level(self, index, level)
+    }
+    #[inline]
+    fn get_paused(&self) -> Result<bool, Error> {
+        EventInstance::get_paused(self)
+    }
+    #[inline]
+    fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        EventInstance::set_paused(self, paused)
+    }
+    #[inline]
+    fn start(&self) -> Result<(), Error> {
+        EventInstance::start(self)
+    }
+    #[inline]
+    fn stop(&self, mode: StopMode) -> Result<(), Error> {
+        EventInstance::stop(self, mode)
+    }
+    #[inline]
+    fn get_timeline_position(&self) -> Result<i32, Error> {
+        EventInstance::get_timeline_position(self)
+    }
+    #[inline]
+    fn set_timeline_position(&self, position: i32) -> Result<(), Error> {
+        EventInstance::set_timeline_position(self, position)
+    }
+    #[inline]
+    fn get_playback_state(&self) -> Result<PlaybackState, Error> {
+        EventInstance::get_playback_state(self)
+    }
+    #[inline]
+    fn get_channel_group(&self) -> Result<ChannelGroup, Error> {
+        EventInstance::get_channel_group(self)
+    }
+    #[inline]
+    fn get_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        EventInstance::get_min_max_distance(self)
+    }
+    #[inline]
+    fn release(&self) -> Result<(), Error> {
+        EventInstance::release(self)
+    }
+    #[inline]
+    fn is_virtual(&self) -> Result<bool, Error> {
+        EventInstance::is_virtual(self)
+    }
+    #[inline]
+    fn get_parameter_by_name(&self, name: &str) -> Result<(f32, f32), Error> {
+        EventInstance::get_parameter_by_name(self, name)
+    }
+    #[inline]
+    fn set_parameter_by_name(
+        &self,
+        name: &str,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        EventInstance::set_parameter_by_name(self, name, value, ignoreseekspeed)
+    }
+    #[inline]
+    fn set_parameter_by_name_with_label(
+        &self,
+        name: &str,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        EventInstance::set_parameter_by_name_with_label(self, name, label, ignoreseekspeed)
+    }
+    #[inline]
+    fn get_parameter_by_id(&self, id: ParameterId) -> Result<(f32, f32), Error> {
+        EventInstance::get_parameter_by_id(self, id)
+    }
+    #[inline]
+    fn set_parameter_by_id(
+        &self,
+        id: ParameterId,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        EventInstance::set_parameter_by_id(self, id, value, ignoreseekspeed)
+    }
+    #[inline]
+    fn set_parameter_by_id_with_label(
+        &self,
+        id: ParameterId,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        EventInstance::set_parameter_by_id_with_label(self, id, label, ignoreseekspeed)
+    }
+    #[inline]
+    fn set_parameters_by_i_ds(
+        &self,
+        ids: ParameterId,
+        values: *mut f32,
+        count: i32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        EventInstance::set_parameters_by_i_ds(self, ids, values, count, ignoreseekspeed)
+    }
+    #[inline]
+    fn key_off(&self) -> Result<(), Error> {
+        EventInstance::key_off(self)
+    }
+    #[inline]
+    fn set_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_EVENT_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_STUDIO_EVENT_CALLBACK_TYPE>,
+    ) -> Result<(), Error> {
+        EventInstance::set_callback(self, callback, callbackmask)
+    }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        EventInstance::get_user_data(self)
+    }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        EventInstance::set_user_data(self, userdata)
+    }
+    #[inline]
+    fn get_cpu_usage(&self) -> Result<(u32, u32), Error> {
+        EventInstance::get_cpu_usage(self)
+    }
+    #[inline]
+    fn get_memory_usage(&self) -> Result<MemoryUsage, Error> {
+        EventInstance::get_memory_usage(self)
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct Studio {
+    pointer: *mut ffi::FMOD_STUDIO_SYSTEM,
+}
+unsafe impl Send for Studio {}
+unsafe impl Sync for Studio {}
+impl Studio {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_STUDIO_SYSTEM) -> Self {
+        Self { pointer }
+    }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_SYSTEM {
+        self.pointer
+    }
+    pub fn create() -> Result<Studio, Error> {
+        unsafe {
+            let mut system = null_mut();
+            match ffi::FMOD_Studio_System_Create(&mut system, ffi::FMOD_VERSION) {
+                ffi::FMOD_OK => Ok(Studio::from(system)),
+                error => Err(err_fmod!("FMOD_Studio_System_Create", error)),
+            }
+        }
+    }
+    pub fn is_valid(&self) -> bool {
+        unsafe { to_bool!(ffi::FMOD_Studio_System_IsValid(self.pointer)) }
+    }
+    pub fn set_advanced_settings(&self, settings: StudioAdvancedSettings) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_SetAdvancedSettings(self.pointer, &mut settings.into()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_SetAdvancedSettings", error)),
+            }
+        }
+    }
+    pub fn get_advanced_settings(&self) -> Result<StudioAdvancedSettings, Error> {
+        unsafe {
+            let mut settings = ffi::FMOD_STUDIO_ADVANCEDSETTINGS::default();
+            match ffi::FMOD_Studio_System_GetAdvancedSettings(self.pointer, &mut settings) {
+                ffi::FMOD_OK => Ok(StudioAdvancedSettings::try_from(settings)?),
+                error => Err(err_fmod!("FMOD_Studio_System_GetAdvancedSettings", error)),
+            }
+        }
+    }
+    pub fn initialize(
+        &self,
+        maxchannels: i32,
+        studioflags: impl Into<ffi::FMOD_STUDIO_INITFLAGS>,
+        flags: impl Into<ffi::FMOD_INITFLAGS>,
+        extradriverdata: Option<*mut c_void>,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_Initialize(
+                self.pointer,
+                maxchannels,
+                studioflags.into(),
+                flags.into(),
+                extradriverdata.unwrap_or(null_mut()),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_Initialize", error)),
+            }
+        }
+    }
+    pub fn release(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_Release(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_Release", error)),
+            }
+        }
+    }
+    pub fn update(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_Update(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_Update", error)),
+            }
+        }
+    }
+    pub fn get_core_system(&self) -> Result<System, Error> {
+        unsafe {
+            let mut coresystem = null_mut();
+            match ffi::FMOD_Studio_System_GetCoreSystem(self.pointer, &mut coresystem) {
+                ffi::FMOD_OK => Ok(System::from(coresystem)),
+                error => Err(err_fmod!("FMOD_Studio_System_GetCoreSystem", error)),
+            }
+        }
+    }
+    pub fn get_event(&self, path_or_id: &str) -> Result<EventDescription, Error> {
+        unsafe {
+            let mut event = null_mut();
+            match ffi::FMOD_Studio_System_GetEvent(
+                self.pointer,
+                CString::new(path_or_id)?.as_ptr(),
+                &mut event,
+            ) {
+                ffi::FMOD_OK => Ok(EventDescription::from(event)),
+                error => Err(err_fmod!("FMOD_Studio_System_GetEvent", error)),
+            }
+        }
+    }
+    pub fn get_bus(&self, path_or_id: &str) -> Result<Bus, Error> {
+        unsafe {
+            let mut bus = null_mut();
+            match ffi::FMOD_Studio_System_GetBus(
+                self.pointer,
+                CString::new(path_or_id)?.as_ptr(),
+                &mut bus,
+            ) {
+                ffi::FMOD_OK => Ok(Bus::from(bus)),
+                error => Err(err_fmod!("FMOD_Studio_System_GetBus", error)),
+            }
+        }
+    }
+    pub fn get_vca(&self, path_or_id: &str) -> Result<Vca, Error> {
+        unsafe {
+            let mut vca = null_mut();
+            match ffi::FMOD_Studio_System_GetVCA(
+                self.pointer,
+                CString::new(path_or_id)?.as_ptr(),
+                &mut vca,
+            ) {
+                ffi::FMOD_OK => Ok(Vca::from(vca)),
+                error => Err(err_fmod!("FMOD_Studio_System_GetVCA", error)),
+            }
+        }
+    }
+    pub fn get_bank(&self, path_or_id: &str) -> Result<Bank, Error> {
+        unsafe {
+            let mut bank = null_mut();
+            match ffi::FMOD_Studio_System_GetBank(
+                self.pointer,
+                CString::new(path_or_id)?.as_ptr(),
+                &mut bank,
+            ) {
+                ffi::FMOD_OK => Ok(Bank::from(bank)),
+                error => Err(err_fmod!("FMOD_Studio_System_GetBank", error)),
+            }
+        }
+    }
+    pub fn get_event_by_id(&self, id: Guid) -> Result<EventDescription, Error> {
+        unsafe {
+            let mut event = null_mut();
+            match ffi::FMOD_Studio_System_GetEventByID(self.pointer, &id.into(), &mut event) {
+                ffi::FMOD_OK => Ok(EventDescription::from(event)),
+                error => Err(err_fmod!("FMOD_Studio_System_GetEventByID", error)),
+            }
+        }
+    }
+    pub fn get_bus_by_id(&self, id: Guid) -> Result<Bus, Error> {
+        unsafe {
+            let mut bus = null_mut();
+            match ffi::FMOD_Studio_System_GetBusByID(self.pointer, &id.into(), &mut bus) {
+                ffi::FMOD_OK => Ok(Bus::from(bus)),
+                error => Err(err_fmod!("FMOD_Studio_System_GetBusByID", error)),
+            }
+        }
+    }
+    pub fn get_vca_by_id(&self, id: Guid) -> Result<Vca, Error> {
+        unsafe {
+            let mut vca = null_mut();
+            match ffi::FMOD_Studio_System_GetVCAByID(self.pointer, &id.into(), &mut vca) {
+                ffi::FMOD_OK => Ok(Vca::from(vca)),
+                error => Err(err_fmod!("FMOD_Studio_System_GetVCAByID", error)),
+            }
+        }
+    }
+    pub fn get_bank_by_id(&self, id: Guid) -> Result<Bank, Error> {
+        unsafe {
+            let mut bank = null_mut();
+            match ffi::FMOD_Studio_System_GetBankByID(self.pointer, &id.into(), &mut bank) {
+                ffi::FMOD_OK => Ok(Bank::from(bank)),
+                error => Err(err_fmod!("FMOD_Studio_System_GetBankByID", error)),
+            }
+        }
+    }
+    pub fn get_sound_info(&self, key: &str) -> Result<SoundInfo, Error> {
+        unsafe {
+            let mut info = ffi::FMOD_STUDIO_SOUND_INFO::default();
+            match ffi::FMOD_Studio_System_GetSoundInfo(
+                self.pointer,
+                CString::new(key)?.as_ptr(),
+                &mut info,
+            ) {
+                ffi::FMOD_OK => Ok(SoundInfo::try_from(info)?),
+                error => Err(err_fmod!("FMOD_Studio_System_GetSoundInfo", error)),
+            }
+        }
+    }
+    pub fn get_parameter_description_by_name(
+        &self,
+        name: &str,
+    ) -> Result<ParameterDescription, Error> {
+        unsafe {
+            let mut parameter = ffi::FMOD_STUDIO_PARAMETER_DESCRIPTION::default();
+            match ffi::FMOD_Studio_System_GetParameterDescriptionByName(
+                self.pointer,
+                CString::new(name)?.as_ptr(),
+                &mut parameter,
+            ) {
+                ffi::FMOD_OK => Ok(ParameterDescription::try_from(parameter)?),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_System_GetParameterDescriptionByName",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_parameter_description_by_id(
+        &self,
+        id: ParameterId,
+    ) -> Result<ParameterDescription, Error> {
+        unsafe {
+            let mut parameter = ffi::FMOD_STUDIO_PARAMETER_DESCRIPTION::default();
+            match ffi::FMOD_Studio_System_GetParameterDescriptionByID(
+                self.pointer,
+                id.into(),
+                &mut parameter,
+            ) {
+                ffi::FMOD_OK => Ok(ParameterDescription::try_from(parameter)?),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_System_GetParameterDescriptionByID",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_parameter_label_by_name(
+        &self,
+        name: &str,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        unsafe {
+            let mut label = vec![0 as c_char; size.max(1) as usize];
+            let mut retrieved = i32::default();
+            match ffi::FMOD_Studio_System_GetParameterLabelByName(
+                self.pointer,
+                CString::new(name)?.as_ptr(),
+                labelindex,
+                label.as_mut_ptr(),
+                label.len() as i32,
+                &mut retrieved,
+            ) {
+                ffi::FMOD_OK => Ok((
+                    CStr::from_ptr(label.as_ptr())
+                        .to_string_lossy()
+                        .into_owned(),
+                    retrieved,
+                )),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_System_GetParameterLabelByName",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_parameter_label_by_id(
+        &self,
+        id: ParameterId,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        unsafe {
+            let mut label = vec![0 as c_char; size.max(1) as usize];
+            let mut retrieved = i32::default();
+            match ffi::FMOD_Studio_System_GetParameterLabelByID(
+                self.pointer,
+                id.into(),
+                labelindex,
+                label.as_mut_ptr(),
+                label.len() as i32,
+                &mut retrieved,
+            ) {
+                ffi::FMOD_OK => Ok((
+                    CStr::from_ptr(label.as_ptr())
+                        .to_string_lossy()
+                        .into_owned(),
+                    retrieved,
+                )),
+                error => Err(err_fmod!("FMOD_Studio_System_GetParameterLabelByID", error)),
+            }
+        }
+    }
+    pub fn get_parameter_by_id(&self, id: ParameterId) -> Result<(f32, f32), Error> {
+        unsafe {
+            let mut value = f32::default();
+            let mut finalvalue = f32::default();
+            match ffi::FMOD_Studio_System_GetParameterByID(
+                self.pointer,
+                id.into(),
+                &mut value,
+                &mut finalvalue,
+            ) {
+                ffi::FMOD_OK => Ok((value, finalvalue)),
+                error => Err(err_fmod!("FMOD_Studio_System_GetParameterByID", error)),
+            }
+        }
+    }
+    pub fn set_parameter_by_id(
+        &self,
+        id: ParameterId,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_SetParameterByID(
+                self.pointer,
+                id.into(),
+                value,
+                from_bool!(ignoreseekspeed),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_SetParameterByID", error)),
+            }
+        }
+    }
+    pub fn set_parameter_by_id_with_label(
+        &self,
+        id: ParameterId,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_SetParameterByIDWithLabel(
+                self.pointer,
+                id.into(),
+                CString::new(label)?.as_ptr(),
+                from_bool!(ignoreseekspeed),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_System_SetParameterByIDWithLabel",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn set_parameters_by_i_ds(
+        &self,
+        ids: ParameterId,
+        values: *mut f32,
+        count: i32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_SetParametersByIDs(
+                self.pointer,
+                &ids.into(),
+                values,
+                count,
+                from_bool!(ignoreseekspeed),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_SetParametersByIDs", error)),
+            }
+        }
+    }
+    pub fn get_parameter_by_name(&self, name: &str) -> Result<(f32, f32), Error> {
+        unsafe {
+            let mut value = f32::default();
+            let mut finalvalue = f32::default();
+            match ffi::FMOD_Studio_System_GetParameterByName(
+                self.pointer,
+                CString::new(name)?.as_ptr(),
+                &mut value,
+                &mut finalvalue,
+            ) {
+                ffi::FMOD_OK => Ok((value, finalvalue)),
+                error => Err(err_fmod!("FMOD_Studio_System_GetParameterByName", error)),
+            }
+        }
+    }
+    pub fn set_parameter_by_name(
+        &self,
+        name: &str,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_SetParameterByName(
+                self.pointer,
+                CString::new(name)?.as_ptr(),
+                value,
+                from_bool!(ignoreseekspeed),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_SetParameterByName", error)),
+            }
+        }
+    }
+    pub fn set_parameter_by_name_with_label(
+        &self,
+        name: &str,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_SetParameterByNameWithLabel(
+                self.pointer,
+                CString::new(name)?.as_ptr(),
+                CString::new(label)?.as_ptr(),
+                from_bool!(ignoreseekspeed),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_System_SetParameterByNameWithLabel",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn lookup_id(&self, path: &str) -> Result<Guid, Error> {
+        unsafe {
+            let mut id = ffi::FMOD_GUID::default();
+            match ffi::FMOD_Studio_System_LookupID(
+                self.pointer,
+                CString::new(path)?.as_ptr(),
+                &mut id,
+            ) {
+                ffi::FMOD_OK => Ok(Guid::try_from(id)?),
+                error => Err(err_fmod!("FMOD_Studio_System_LookupID", error)),
+            }
+        }
+    }
+    pub fn lookup_path(&self, id: Guid) -> Result<String, Error> {
+        unsafe {
+            let mut retrieved = i32::default();
+            let id = id.into();
+            match ffi::FMOD_Studio_System_LookupPath(
+                self.pointer,
+                &id,
+                null_mut(),
+                0,
+                &mut retrieved,
+            ) {
+                ffi::FMOD_OK => {
+                    let mut buf = vec![0u8; retrieved as usize];
+                    match ffi::FMOD_Studio_System_LookupPath(
+                        self.pointer,
+                        &id,
+                        buf.as_mut_ptr() as *mut _,
+                        retrieved,
+                        &mut retrieved,
+                    ) {
+                        ffi::FMOD_OK => Ok(CString::from_vec_with_nul_unchecked(buf)
+                            .into_string()
+                            .map_err(Error::String)?),
+                        error => Err(err_fmod!("FMOD_Studio_System_LookupPath", error)),
+                    }
+                }
+                error => Err(err_fmod!("FMOD_Studio_System_LookupPath", error)),
+            }
+        }
+    }
+    pub fn get_num_listeners(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut numlisteners = i32::default();
+            match ffi::FMOD_Studio_System_GetNumListeners(self.pointer, &mut numlisteners) {
+                ffi::FMOD_OK => Ok(numlisteners),
+                error => Err(err_fmod!("FMOD_Studio_System_GetNumListeners", error)),
+            }
+        }
+    }
+    pub fn set_num_listeners(&self, numlisteners: i32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_SetNumListeners(self.pointer, numlisteners) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_SetNumListeners", error)),
+            }
+        }
+    }
+    pub fn get_listener_attributes(&self, index: i32) -> Result<(Attributes3d, Vector), Error> {
+        unsafe {
+            let mut attributes = ffi::FMOD_3D_ATTRIBUTES::default();
+            let mut attenuationposition = ffi::FMOD_VECTOR::default();
+            match ffi::FMOD_Studio_System_GetListenerAttributes(
+                self.pointer,
+                index,
+                &mut attributes,
+                &mut attenuationposition,
+            ) {
+                ffi::FMOD_OK => Ok((
+                    Attributes3d::try_from(attributes)?,
+                    Vector::try_from(attenuationposition)?,
+                )),
+                error => Err(err_fmod!("FMOD_Studio_System_GetListenerAttributes", error)),
+            }
+        }
+    }
+    pub fn set_listener_attributes(
+        &self,
+        index: i32,
+        attributes: Attributes3d,
+        attenuationposition: Option<Vector>,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_SetListenerAttributes(
+                self.pointer,
+                index,
+                &attributes.into(),
+                attenuationposition
+                    .map(Vector::into)
+                    .as_ref()
+                    .map(from_ref)
+                    .unwrap_or_else(null),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_SetListenerAttributes", error)),
+            }
+        }
+    }
+    pub fn get_listener_weight(&self, index: i32) -> Result<f32, Error> {
+        unsafe {
+            let mut weight = f32::default();
+            match ffi::FMOD_Studio_System_GetListenerWeight(self.pointer, index, &mut weight) {
+                ffi::FMOD_OK => Ok(weight),
+                error => Err(err_fmod!("FMOD_Studio_System_GetListenerWeight", error)),
+            }
+        }
+    }
+    pub fn set_listener_weight(&self, index: i32, weight: f32) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_SetListenerWeight(self.pointer, index, weight) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_SetListenerWeight", error)),
+            }
+        }
+    }
+    pub fn load_bank_file(
+        &self,
+        filename: &str,
+        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
+    ) -> Result<Bank, Error> {
+        unsafe {
+            let mut bank = null_mut();
+            match ffi::FMOD_Studio_System_LoadBankFile(
+                self.pointer,
+                CString::new(filename)?.as_ptr(),
+                flags.into(),
+                &mut bank,
+            ) {
+                ffi::FMOD_OK => Ok(Bank::from(bank)),
+                error => Err(err_fmod!("FMOD_Studio_System_LoadBankFile", error)),
+            }
+        }
+    }
+    pub fn load_bank_memory(
+        &self,
+        buffer: &[u8],
+        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
+    ) -> Result<Bank, Error> {
+        unsafe {
+            let mut bank = null_mut();
+            match ffi::FMOD_Studio_System_LoadBankMemory(
+                self.pointer,
+                buffer.as_ptr() as *const std::os::raw::c_char,
+                buffer.len() as std::os::raw::c_int,
+                LoadMemoryMode::Memory.into(),
+                flags.into(),
+                &mut bank,
+            ) {
+                ffi::FMOD_OK => Ok(Bank::from(bank)),
+                error => Err(err_fmod!("FMOD_Studio_System_LoadBankMemory", error)),
+            }
+        }
+    }
+    pub fn load_bank_custom(
+        &self,
+        info: BankInfo,
+        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
+    ) -> Result<Bank, Error> {
+        unsafe {
+            let mut bank = null_mut();
+            match ffi::FMOD_Studio_System_LoadBankCustom(
+                self.pointer,
+                &info.into(),
+                flags.into(),
+                &mut bank,
+            ) {
+                ffi::FMOD_OK => Ok(Bank::from(bank)),
+                error => Err(err_fmod!("FMOD_Studio_System_LoadBankCustom", error)),
+            }
+        }
+    }
+    pub fn register_plugin(&self, description: DspDescription) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_RegisterPlugin(self.pointer, &description.into()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_RegisterPlugin", error)),
+            }
+        }
+    }
+    pub fn unregister_plugin(&self, name: &str) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_UnregisterPlugin(
+                self.pointer,
+                CString::new(name)?.as_ptr(),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_UnregisterPlugin", error)),
+            }
+        }
+    }
+    pub fn unload_all(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_UnloadAll(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_UnloadAll", error)),
+            }
+        }
+    }
+    pub fn flush_commands(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_FlushCommands(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_FlushCommands", error)),
+            }
+        }
+    }
+    pub fn flush_sample_loading(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_FlushSampleLoading(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_FlushSampleLoading", error)),
+            }
+        }
+    }
+    pub fn start_command_capture(
+        &self,
+        filename: &str,
+        flags: impl Into<ffi::FMOD_STUDIO_COMMANDCAPTURE_FLAGS>,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_StartCommandCapture(
+                self.pointer,
+                CString::new(filename)?.as_ptr(),
+                flags.into(),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_StartCommandCapture", error)),
+            }
+        }
+    }
+    pub fn stop_command_capture(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_StopCommandCapture(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_StopCommandCapture", error)),
+            }
+        }
+    }
+    pub fn load_command_replay(
+        &self,
+        filename: &str,
+        flags: impl Into<ffi::FMOD_STUDIO_COMMANDREPLAY_FLAGS>,
+    ) -> Result<CommandReplay, Error> {
+        unsafe {
+            let mut replay = null_mut();
+            match ffi::FMOD_Studio_System_LoadCommandReplay(
+                self.pointer,
+                CString::new(filename)?.as_ptr(),
+                flags.into(),
+                &mut replay,
+            ) {
+                ffi::FMOD_OK => Ok(CommandReplay::from(replay)),
+                error => Err(err_fmod!("FMOD_Studio_System_LoadCommandReplay", error)),
+            }
+        }
+    }
+    pub fn get_bank_count(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_System_GetBankCount(self.pointer, &mut count) {
+                ffi::FMOD_OK => Ok(count),
+                error => Err(err_fmod!("FMOD_Studio_System_GetBankCount", error)),
+            }
+        }
+    }
+    pub fn get_bank_list(&self, capacity: i32) -> Result<Vec<Bank>, Error> {
+        unsafe {
+            let mut array = vec![null_mut(); capacity as usize];
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_System_GetBankList(
+                self.pointer,
+                array.as_mut_ptr(),
+                capacity,
+                &mut count,
+            ) {
+                ffi::FMOD_OK => Ok(array
+                    .into_iter()
+                    .take(count as usize)
+                    .map(Bank::from)
+                    .collect()),
+                error => Err(err_fmod!("FMOD_Studio_System_GetBankList", error)),
+            }
+        }
+    }
+    pub fn get_parameter_description_count(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_System_GetParameterDescriptionCount(self.pointer, &mut count) {
+                ffi::FMOD_OK => Ok(count),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_System_GetParameterDescriptionCount",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_parameter_description_list(
+        &self,
+        capacity: i32,
+    ) -> Result<Vec<ParameterDescription>, Error> {
+        unsafe {
+            let mut array =
+                vec![ffi::FMOD_STUDIO_PARAMETER_DESCRIPTION::default(); capacity as usize];
+            let mut count = i32::default();
+            match ffi::FMOD_Studio_System_GetParameterDescriptionList(
+                self.pointer,
+                array.as_mut_ptr(),
+                capacity,
+                &mut count,
+            ) {
+                ffi::FMOD_OK => Ok(array
+                    .into_iter()
+                    .take(count as usize)
+                    .map(ParameterDescription::try_from)
+                    .collect::<Result<_, Error>>()?),
+                error => Err(err_fmod!(
+                    "FMOD_Studio_System_GetParameterDescriptionList",
+                    error
+                )),
+            }
+        }
+    }
+    pub fn get_cpu_usage(&self) -> Result<(StudioCpuUsage, CpuUsage), Error> {
+        unsafe {
+            let mut usage = ffi::FMOD_STUDIO_CPU_USAGE::default();
+            let mut usage_core = ffi::FMOD_CPU_USAGE::default();
+            match ffi::FMOD_Studio_System_GetCPUUsage(self.pointer, &mut usage, &mut usage_core) {
+                ffi::FMOD_OK => Ok((
+                    StudioCpuUsage::try_from(usage)?,
+                    CpuUsage::try_from(usage_core)?,
+                )),
+                error => Err(err_fmod!("FMOD_Studio_System_GetCPUUsage", error)),
+            }
+        }
+    }
+    pub fn get_buffer_usage(&self) -> Result<BufferUsage, Error> {
+        unsafe {
+            let mut usage = ffi::FMOD_STUDIO_BUFFER_USAGE::default();
+            match ffi::FMOD_Studio_System_GetBufferUsage(self.pointer, &mut usage) {
+                ffi::FMOD_OK => Ok(BufferUsage::try_from(usage)?),
+                error => Err(err_fmod!("FMOD_Studio_System_GetBufferUsage", error)),
+            }
+        }
+    }
+    pub fn reset_buffer_usage(&self) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_ResetBufferUsage(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_ResetBufferUsage", error)),
+            }
+        }
+    }
+    pub fn set_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_SYSTEM_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_STUDIO_SYSTEM_CALLBACK_TYPE>,
+    ) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_SetCallback(self.pointer, callback, callbackmask.into()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_SetCallback", error)),
+            }
+        }
+    }
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        unsafe {
+            match ffi::FMOD_Studio_System_SetUserData(self.pointer, userdata) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_Studio_System_SetUserData", error)),
+            }
+        }
+    }
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        unsafe {
+            let mut userdata = null_mut();
+            match ffi::FMOD_Studio_System_GetUserData(self.pointer, &mut userdata) {
+                ffi::FMOD_OK => Ok(userdata),
+                error => Err(err_fmod!("FMOD_Studio_System_GetUserData", error)),
+            }
+        }
+    }
+    pub fn get_memory_usage(&self) -> Result<MemoryUsage, Error> {
+        unsafe {
+            let mut memoryusage = ffi::FMOD_STUDIO_MEMORY_USAGE::default();
+            match ffi::FMOD_Studio_System_GetMemoryUsage(self.pointer, &mut memoryusage) {
+                ffi::FMOD_OK => Ok(MemoryUsage::try_from(memoryusage)?),
+                error => Err(err_fmod!("FMOD_Studio_System_GetMemoryUsage", error)),
+            }
+        }
+    }
+}
+#[doc = " Methods of [`Studio`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait StudioApi {
+    fn is_valid(&self) -> bool;
+    fn set_advanced_settings(&self, settings: StudioAdvancedSettings) -> Result<(), Error>;
+    fn get_advanced_settings(&self) -> Result<StudioAdvancedSettings, Error>;
+    fn initialize(
+        &self,
+        maxchannels: i32,
+        studioflags: impl Into<ffi::FMOD_STUDIO_INITFLAGS>,
+        flags: impl Into<ffi::FMOD_INITFLAGS>,
+        extradriverdata: Option<*mut c_void>,
+    ) -> Result<(), Error>;
+    fn release(&self) -> Result<(), Error>;
+    fn update(&self) -> Result<(), Error>;
+    fn get_core_system(&self) -> Result<System, Error>;
+    fn get_event(&self, path_or_id: &str) -> Result<EventDescription, Error>;
+    fn get_bus(&self, path_or_id: &str) -> Result<Bus, Error>;
+    fn get_vca(&self, path_or_id: &str) -> Result<Vca, Error>;
+    fn get_bank(&self, path_or_id: &str) -> Result<Bank, Error>;
+    fn get_event_by_id(&self, id: Guid) -> Result<EventDescription, Error>;
+    fn get_bus_by_id(&self, id: Guid) -> Result<Bus, Error>;
+    fn get_vca_by_id(&self, id: Guid) -> Result<Vca, Error>;
+    fn get_bank_by_id(&self, id: Guid) -> Result<Bank, Error>;
+    fn get_sound_info(&self, key: &str) -> Result<SoundInfo, Error>;
+    fn get_parameter_description_by_name(&self, name: &str) -> Result<ParameterDescription, Error>;
+    fn get_parameter_description_by_id(
+        &self,
+        id: ParameterId,
+    ) -> Result<ParameterDescription, Error>;
+    fn get_parameter_label_by_name(
+        &self,
+        name: &str,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error>;
+    fn get_parameter_label_by_id(
+        &self,
+        id: ParameterId,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error>;
+    fn get_parameter_by_id(&self, id: ParameterId) -> Result<(f32, f32), Error>;
+    fn set_parameter_by_id(
+        &self,
+        id: ParameterId,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error>;
+    fn set_parameter_by_id_with_label(
+        &self,
+        id: ParameterId,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error>;
+    fn set_parameters_by_i_ds(
+        &self,
+        ids: ParameterId,
+        values: *mut f32,
+        count: i32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error>;
+    fn get_parameter_by_name(&self, name: &str) -> Result<(f32, f32), Error>;
+    fn set_parameter_by_name(
+        &self,
+        name: &str,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error>;
+    fn set_parameter_by_name_with_label(
+        &self,
+        name: &str,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error>;
+    fn lookup_id(&self, path: &str) -> Result<Guid, Error>;
+    fn lookup_path(&self, id: Guid) -> Result<String, Error>;
+    fn get_num_listeners(&self) -> Result<i32, Error>;
+    fn set_num_listeners(&self, numlisteners: i32) -> Result<(), Error>;
+    fn get_listener_attributes(&self, index: i32) -> Result<(Attributes3d, Vector), Error>;
+    fn set_listener_attributes(
+        &self,
+        index: i32,
+        attributes: Attributes3d,
+        attenuationposition: Option<Vector>,
+    ) -> Result<(), Error>;
+    fn get_listener_weight(&self, index: i32) -> Result<f32, Error>;
+    fn set_listener_weight(&self, index: i32, weight: f32) -> Result<(), Error>;
+    fn load_bank_file(
+        &self,
+        filename: &str,
+        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
+    ) -> Result<Bank, Error>;
+    fn load_bank_memory(
+        &self,
+        buffer: &[u8],
+        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
+    ) -> Result<Bank, Error>;
+    fn load_bank_custom(
+        &self,
+        info: BankInfo,
+        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
+    ) -> Result<Bank, Error>;
+    fn register_plugin(&self, description: DspDescription) -> Result<(), Error>;
+    fn unregister_plugin(&self, name: &str) -> Result<(), Error>;
+    fn unload_all(&self) -> Result<(), Error>;
+    fn flush_commands(&self) -> Result<(), Error>;
+    fn flush_sample_loading(&self) -> Result<(), Error>;
+    fn start_command_capture(
+        &self,
+        filename: &str,
+        flags: impl Into<ffi::FMOD_STUDIO_COMMANDCAPTURE_FLAGS>,
+    ) -> Result<(), Error>;
+    fn stop_command_capture(&self) -> Result<(), Error>;
+    fn load_command_replay(
+        &self,
+        filename: &str,
+        flags: impl Into<ffi::FMOD_STUDIO_COMMANDREPLAY_FLAGS>,
+    ) -> Result<CommandReplay, Error>;
+    fn get_bank_count(&self) -> Result<i32, Error>;
+    fn get_bank_list(&self, capacity: i32) -> Result<Vec<Bank>, Error>;
+    fn get_parameter_description_count(&self) -> Result<i32, Error>;
+    fn get_parameter_description_list(
+        &self,
+        capacity: i32,
+    ) -> Result<Vec<ParameterDescription>, Error>;
+    fn get_cpu_usage(&self) -> Result<(StudioCpuUsage, CpuUsage), Error>;
+    fn get_buffer_usage(&self) -> Result<BufferUsage, Error>;
+    fn reset_buffer_usage(&self) -> Result<(), Error>;
+    fn set_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_SYSTEM_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_STUDIO_SYSTEM_CALLBACK_TYPE>,
+    ) -> Result<(), Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+    fn get_memory_usage(&self) -> Result<MemoryUsage, Error>;
+}
+impl StudioApi for Studio {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        Studio::is_valid(self)
+    }
+    #[inline]
+    fn set_advanced_settings(&self, settings: StudioAdvancedSettings) -> Result<(), Error> {
+        Studio::set_advanced_settings(self, settings)
+    }
+    #[inline]
+    fn get_advanced_settings(&self) -> Result<StudioAdvancedSettings, Error> {
+        Studio::get_advanced_settings(self)
+    }
+    #[inline]
+    fn initialize(
+        &self,
+        maxchannels: i32,
+        studioflags: impl Into<ffi::FMOD_STUDIO_INITFLAGS>,
+        flags: impl Into<ffi::FMOD_INITFLAGS>,
+        extradriverdata: Option<*mut c_void>,
+    ) -> Result<(), Error> {
+        Studio::initialize(self, maxchannels, studioflags, flags, extradriverdata)
+    }
+    #[inline]
+    fn release(&self) -> Result<(), Error> {
+        Studio::release(self)
+    }
+    #[inline]
+    fn update(&self) -> Result<(), Error> {
+        Studio::update(self)
+    }
+    #[inline]
+    fn get_core_system(&self) -> Result<System, Error> {
+        Studio::get_core_system(self)
+    }
+    #[inline]
+    fn get_event(&self, path_or_id: &str) -> Result<EventDescription, Error> {
+        Studio::get_event(self, path_or_id)
+    }
+    #[inline]
+    fn get_bus(&self, path_or_id: &str) -> Result<Bus, Error> {
+        Studio::get_bus(self, path_or_id)
+    }
+    #[inline]
+    fn get_vca(&self, path_or_id: &str) -> Result<Vca, Error> {
+        Studio::get_vca(self, path_or_id)
+    }
+    #[inline]
+    fn get_bank(&self, path_or_id: &str) -> Result<Bank, Error> {
+        Studio::get_bank(self, path_or_id)
+    }
+    #[inline]
+    fn get_event_by_id(&self, id: Guid) -> Result<EventDescription, Error> {
+        Studio::get_event_by_id(self, id)
+    }
+    #[inline]
+    fn get_bus_by_id(&self, id: Guid) -> Result<Bus, Error> {
+        Studio::get_bus_by_id(self, id)
+    }
+    #[inline]
+    fn get_vca_by_id(&self, id: Guid) -> Result<Vca, Error> {
+        Studio::get_vca_by_id(self, id)
+    }
+    #[inline]
+    fn get_bank_by_id(&self, id: Guid) -> Result<Bank, Error> {
+        Studio::get_bank_by_id(self, id)
+    }
+    #[inline]
+    fn get_sound_info(&self, key: &str) -> Result<SoundInfo, Error> {
+        Studio::get_sound_info(self, key)
+    }
+    #[inline]
+    fn get_parameter_description_by_name(&self, name: &str) -> Result<ParameterDescription, Error> {
+        Studio::get_parameter_description_by_name(self, name)
+    }
+    #[inline]
+    fn get_parameter_description_by_id(
+        &self,
+        id: ParameterId,
+    ) -> Result<ParameterDescription, Error> {
+        Studio::get_parameter_description_by_id(self, id)
+    }
+    #[inline]
+    fn get_parameter_label_by_name(
+        &self,
+        name: &str,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        Studio::get_parameter_label_by_name(self, name, labelindex, size)
+    }
+    #[inline]
+    fn get_parameter_label_by_id(
+        &self,
+        id: ParameterId,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        Studio::get_parameter_label_by_id(self, id, labelindex, size)
+    }
+    #[inline]
+    fn get_parameter_by_id(&self, id: ParameterId) -> Result<(f32, f32), Error> {
+        Studio::get_parameter_by_id(self, id)
+    }
+    #[inline]
+    fn set_parameter_by_id(
+        &self,
+        id: ParameterId,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        Studio::set_parameter_by_id(self, id, value, ignoreseekspeed)
+    }
+    #[inline]
+    fn set_parameter_by_id_with_label(
+        &self,
+        id: ParameterId,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        Studio::set_parameter_by_id_with_label(self, id, label, ignoreseekspeed)
+    }
+    #[inline]
+    fn set_parameters_by_i_ds(
+        &self,
+        ids: ParameterId,
+        values: *mut f32,
+        count: i32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        Studio::set_parameters_by_i_ds(self, ids, values, count, ignoreseekspeed)
+    }
+    #[inline]
+    fn get_parameter_by_name(&self, name: &str) -> Result<(f32, f32), Error> {
+        Studio::get_parameter_by_name(self, name)
+    }
+    #[inline]
+    fn set_parameter_by_name(
+        &self,
+        name: &str,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        Studio::set_parameter_by_name(self, name, value, ignoreseekspeed)
+    }
+    #[inline]
+    fn set_parameter_by_name_with_label(
+        &self,
+        name: &str,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        Studio::set_parameter_by_name_with_label(self, name, label, ignoreseekspeed)
+    }
+    #[inline]
+    fn lookup_id(&self, path: &str) -> Result<Guid, Error> {
+        Studio::lookup_id(self, path)
+    }
+    #[inline]
+    fn lookup_path(&self, id: Guid) -> Result<String, Error> {
+        Studio::lookup_path(self, id)
+    }
+    #[inline]
+    fn get_num_listeners(&self) -> Result<i32, Error> {
+        Studio::get_num_listeners(self)
+    }
+    #[inline]
+    fn set_num_listeners(&self, numlisteners: i32) -> Result<(), Error> {
+        Studio::set_num_listeners(self, numlisteners)
+    }
+    #[inline]
+    fn get_listener_attributes(&self, index: i32) -> Result<(Attributes3d, Vector), Error> {
+        Studio::get_listener_attributes(self, index)
+    }
+    #[inline]
+    fn set_listener_attributes(
+        &self,
+        index: i32,
+        attributes: Attributes3d,
+        attenuationposition: Option<Vector>,
+    ) -> Result<(), Error> {
+        Studio::set_listener_attributes(self, index, attributes, attenuationposition)
+    }
+    #[inline]
+    fn get_listener_weight(&self, index: i32) -> Result<f32, Error> {
+        Studio::get_listener_weight(self, index)
+    }
+    #[inline]
+    fn set_listener_weight(&self, index: i32, weight: f32) -> Result<(), Error> {
+        Studio::set_listener_weight(self, index, weight)
+    }
+    #[inline]
+    fn load_bank_file(
+        &self,
+        filename: &str,
+        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
+    ) -> Result<Bank, Error> {
+        Studio::load_bank_file(self, filename, flags)
+    }
+    #[inline]
+    fn load_bank_memory(
+        &self,
+        buffer: &[u8],
+        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
+    ) -> Result<Bank, Error> {
+        Studio::load_bank_memory(self, buffer, flags)
+    }
+    #[inline]
+    fn load_bank_custom(
+        &self,
+        info: BankInfo,
+        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
+    ) -> Result<Bank, Error> {
+        Studio::load_bank_custom(self, info, flags)
+    }
+    #[inline]
+    fn register_plugin(&self, description: DspDescription) -> Result<(), Error> {
+        Studio::register_plugin(self, description)
+    }
+    #[inline]
+    fn unregister_plugin(&self, name: &str) -> Result<(), Error> {
+        Studio::unregister_plugin(self, name)
+    }
+    #[inline]
+    fn unload_all(&self) -> Result<(), Error> {
+        Studio::unload_all(self)
+    }
+    #[inline]
+    fn flush_commands(&self) -> Result<(), Error> {
+        Studio::flush_commands(self)
+    }
+    #[inline]
+    fn flush_sample_loading(&self) -> Result<(), Error> {
+        Studio::flush_sample_loading(self)
+    }
+    #[inline]
+    fn start_command_capture(
+        &self,
+        filename: &str,
+        flags: impl Into<ffi::FMOD_STUDIO_COMMANDCAPTURE_FLAGS>,
+    ) -> Result<(), Error> {
+        Studio::start_command_capture(self, filename, flags)
+    }
+    #[inline]
+    fn stop_command_capture(&self) -> Result<(), Error> {
+        Studio::stop_command_capture(self)
+    }
+    #[inline]
+    fn load_command_replay(
+        &self,
+        filename: &str,
+        flags: impl Into<ffi::FMOD_STUDIO_COMMANDREPLAY_FLAGS>,
+    ) -> Result<CommandReplay, Error> {
+        Studio::load_command_replay(self, filename, flags)
+    }
+    #[inline]
+    fn get_bank_count(&self) -> Result<i32, Error> {
+        Studio::get_bank_count(self)
+    }
+    #[inline]
+    fn get_bank_list(&self, capacity: i32) -> Result<Vec<Bank>, Error> {
+        Studio::get_bank_list(self, capacity)
+    }
+    #[inline]
+    fn get_parameter_description_count(&self) -> Result<i32, Error> {
+        Studio::get_parameter_description_count(self)
+    }
+    #[inline]
+    fn get_parameter_description_list(
+        &self,
+        capacity: i32,
+    ) -> Result<Vec<ParameterDescription>, Error> {
+        Studio::get_parameter_description_list(self, capacity)
+    }
+    #[inline]
+    fn get_cpu_usage(&self) -> Result<(StudioCpuUsage, CpuUsage), Error> {
+        Studio::get_cpu_usage(self)
+    }
+    #[inline]
+    fn get_buffer_usage(&self) -> Result<BufferUsage, Error> {
+        Studio::get_buffer_usage(self)
+    }
+    #[inline]
+    fn reset_buffer_usage(&self) -> Result<(), Error> {
+        Studio::reset_buffer_usage(self)
+    }
+    #[inline]
+    fn set_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_SYSTEM_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_STUDIO_SYSTEM_CALLBACK_TYPE>,
+    ) -> Result<(), Error> {
+        Studio::set_callback(self, callback, callbackmask)
+    }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        Studio::set_user_data(self, userdata)
+    }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        Studio::get_user_data(self)
+    }
+    #[inline]
+    fn get_memory_usage(&self) -> Result<MemoryUsage, Error> {
+        Studio::get_memory_usage(self)
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct Vca {
+    pointer: *mut ffi::FMOD_STUDIO_VCA,
+}
+unsafe impl Send for Vca {}
+unsafe impl Sync for Vca {}
+impl Vca {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_STUDIO_VCA) -> Self {
+        Self { pointer }
+    }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_VCA {
+        self.pointer
     }
-    pub fn get_reverb_level(&self, index: i32) -> Result<f32, Error> {
-        unsafe {
-            let mut level = f32::default();
-            match ffi::FMOD_Studio_EventInstance_GetReverbLevel(self.pointer, index, &mut level) {
-                ffi::FMOD_OK => Ok(level),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetReverbLevel", error)),
-            }
-        }
+    pub fn is_valid(&self) -> bool {
+        unsafe { to_bool!(ffi::FMOD_Studio_VCA_IsValid(self.pointer)) }
     }
-    pub fn set_reverb_level(&self, index: i32, level: f32) -> Result<(), Error> {
+    pub fn get_id(&self) -> Result<Guid, Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetReverbLevel(self.pointer, index, level) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetReverbLevel", error)),
+            let mut id = ffi::FMOD_GUID::default();
+            match ffi::FMOD_Studio_VCA_GetID(self.pointer, &mut id) {
+                ffi::FMOD_OK => Ok(Guid::try_from(id)?),
+                error => Err(err_fmod!("FMOD_Studio_VCA_GetID", error)),
             }
         }
     }
-    pub fn get_paused(&self) -> Result<bool, Error> {
+    pub fn get_path(&self) -> Result<String, Error> {
         unsafe {
-            let mut paused = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Studio_EventInstance_GetPaused(self.pointer, &mut paused) {
-                ffi::FMOD_OK => Ok(to_bool!(paused)),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetPaused", error)),
+            let mut retrieved = i32::default();
+            match ffi::FMOD_Studio_VCA_GetPath(self.pointer, null_mut(), 0, &mut retrieved) {
+                ffi::FMOD_OK => {
+                    let mut buf = vec![0u8; retrieved as usize];
+                    match ffi::FMOD_Studio_VCA_GetPath(
+                        self.pointer,
+                        buf.as_mut_ptr() as *mut _,
+                        retrieved,
+                        &mut retrieved,
+                    ) {
+                        ffi::FMOD_OK => Ok(CString::from_vec_with_nul_unchecked(buf)
+                            .into_string()
+                            .map_err(Error::String)?),
+                        error => Err(err_fmod!("FMOD_Studio_VCA_GetPath", error)),
+                    }
+                }
+                error => Err(err_fmod!("FMOD_Studio_VCA_GetPath", error)),
             }
         }
     }
-    pub fn set_paused(&self, paused: bool) -> Result<(), Error> {
+    pub fn get_volume(&self) -> Result<(f32, f32), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetPaused(self.pointer, from_bool!(paused)) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetPaused", error)),
+            let mut volume = f32::default();
+            let mut finalvolume = f32::default();
+            match ffi::FMOD_Studio_VCA_GetVolume(self.pointer, &mut volume, &mut finalvolume) {
+                ffi::FMOD_OK => Ok((volume, finalvolume)),
+                error => Err(err_fmod!("FMOD_Studio_VCA_GetVolume", error)),
             }
         }
     }
-    pub fn start(&self) -> Result<(), Error> {
+    pub fn set_volume(&self, volume: f32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_Start(self.pointer) {
+            match ffi::FMOD_Studio_VCA_SetVolume(self.pointer, volume) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_Start", error)),
+                error => Err(err_fmod!("FMOD_Studio_VCA_SetVolume", error)),
             }
         }
     }
-    pub fn stop(&self, mode: StopMode) -> Result<(), Error> {
+}
+#[doc = " Methods of [`Vca`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait VcaApi {
+    fn is_valid(&self) -> bool;
+    fn get_id(&self) -> Result<Guid, Error>;
+    fn get_path(&self) -> Result<String, Error>;
+    fn get_volume(&self) -> Result<(f32, f32), Error>;
+    fn set_volume(&self, volume: f32) -> Result<(), Error>;
+}
+impl VcaApi for Vca {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        Vca::is_valid(self)
+    }
+    #[inline]
+    fn get_id(&self) -> Result<Guid, Error> {
+        Vca::get_id(self)
+    }
+    #[inline]
+    fn get_path(&self) -> Result<String, Error> {
+        Vca::get_path(self)
+    }
+    #[inline]
+    fn get_volume(&self) -> Result<(f32, f32), Error> {
+        Vca::get_volume(self)
+    }
+    #[inline]
+    fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        Vca::set_volume(self, volume)
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct SyncPoint {
+    pointer: *mut ffi::FMOD_SYNCPOINT,
+}
+unsafe impl Send for SyncPoint {}
+unsafe impl Sync for SyncPoint {}
+impl SyncPoint {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_SYNCPOINT) -> Self {
+        Self { pointer }
+    }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_SYNCPOINT {
+        self.pointer
+    }
+}
+#[derive(Debug, Clone, Copy)]
+pub struct System {
+    pointer: *mut ffi::FMOD_SYSTEM,
+}
+unsafe impl Send for System {}
+unsafe impl Sync for System {}
+impl System {
+    #[inline]
+    pub fn from(pointer: *mut ffi::FMOD_SYSTEM) -> Self {
+        Self { pointer }
+    }
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_SYSTEM {
+        self.pointer
+    }
+    pub fn create() -> Result<System, Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_Stop(self.pointer, mode.into()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_Stop", error)),
+            let mut system = null_mut();
+            match ffi::FMOD_System_Create(&mut system, ffi::FMOD_VERSION) {
+                ffi::FMOD_OK => Ok(System::from(system)),
+                error => Err(err_fmod!("FMOD_System_Create", error)),
             }
         }
     }
-    pub fn get_timeline_position(&self) -> Result<i32, Error> {
+    pub fn release(&self) -> Result<(), Error> {
         unsafe {
-            let mut position = i32::default();
-            match ffi::FMOD_Studio_EventInstance_GetTimelinePosition(self.pointer, &mut position) {
-                ffi::FMOD_OK => Ok(position),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_GetTimelinePosition",
-                    error
-                )),
+            match ffi::FMOD_System_Release(self.pointer) {
+                ffi::FMOD_OK => {
+                    forget_followed_driver(self.pointer);
+                    Ok(())
+                }
+                error => Err(err_fmod!("FMOD_System_Release", error)),
             }
         }
     }
-    pub fn set_timeline_position(&self, position: i32) -> Result<(), Error> {
+    pub fn set_output(&self, output: OutputType) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetTimelinePosition(self.pointer, position) {
+            match ffi::FMOD_System_SetOutput(self.pointer, output.into()) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_SetTimelinePosition",
-                    error
-                )),
+                error => Err(err_fmod!("FMOD_System_SetOutput", error)),
             }
         }
     }
-    pub fn get_playback_state(&self) -> Result<PlaybackState, Error> {
+    pub fn get_output(&self) -> Result<OutputType, Error> {
         unsafe {
-            let mut state = ffi::FMOD_STUDIO_PLAYBACK_STATE::default();
-            match ffi::FMOD_Studio_EventInstance_GetPlaybackState(self.pointer, &mut state) {
-                ffi::FMOD_OK => Ok(PlaybackState::from(state)?),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_GetPlaybackState",
-                    error
-                )),
+            let mut output = ffi::FMOD_OUTPUTTYPE::default();
+            match ffi::FMOD_System_GetOutput(self.pointer, &mut output) {
+                ffi::FMOD_OK => Ok(OutputType::from(output)?),
+                error => Err(err_fmod!("FMOD_System_GetOutput", error)),
             }
         }
     }
-    pub fn get_channel_group(&self) -> Result<ChannelGroup, Error> {
+    pub fn get_num_drivers(&self) -> Result<i32, Error> {
         unsafe {
-            let mut group = null_mut();
-            match ffi::FMOD_Studio_EventInstance_GetChannelGroup(self.pointer, &mut group) {
-                ffi::FMOD_OK => Ok(ChannelGroup::from(group)),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_GetChannelGroup",
-                    error
-                )),
+            let mut numdrivers = i32::default();
+            match ffi::FMOD_System_GetNumDrivers(self.pointer, &mut numdrivers) {
+                ffi::FMOD_OK => Ok(numdrivers),
+                error => Err(err_fmod!("FMOD_System_GetNumDrivers", error)),
             }
         }
     }
-    pub fn get_min_max_distance(&self) -> Result<(f32, f32), Error> {
+    pub fn get_driver_info(
+        &self,
+        id: i32,
+        namelen: i32,
+    ) -> Result<(String, Guid, i32, SpeakerMode, i32), Error> {
         unsafe {
-            let mut min = f32::default();
-            let mut max = f32::default();
-            match ffi::FMOD_Studio_EventInstance_GetMinMaxDistance(self.pointer, &mut min, &mut max)
-            {
-                ffi::FMOD_OK => Ok((min, max)),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_GetMinMaxDistance",
-                    error
+            let mut name = vec![0 as c_char; namelen.max(1) as usize];
+            let mut guid = ffi::FMOD_GUID::default();
+            let mut systemrate = i32::default();
+            let mut speakermode = ffi::FMOD_SPEAKERMODE::default();
+            let mut speakermodechannels = i32::default();
+            match ffi::FMOD_System_GetDriverInfo(
+                self.pointer,
+                id,
+                name.as_mut_ptr(),
+                name.len() as i32,
+                &mut guid,
+                &mut systemrate,
+                &mut speakermode,
+                &mut speakermodechannels,
+            ) {
+                ffi::FMOD_OK => Ok((
+                    CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned(),
+                    Guid::try_from(guid)?,
+                    systemrate,
+                    SpeakerMode::from(speakermode)?,
+                    speakermodechannels,
                 )),
+                error => Err(err_fmod!("FMOD_System_GetDriverInfo", error)),
             }
         }
     }
-    pub fn release(&self) -> Result<(), Error> {
+    pub fn set_driver(&self, driver: i32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_Release(self.pointer) {
+            match ffi::FMOD_System_SetDriver(self.pointer, driver) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_Release", error)),
+                error => Err(err_fmod!("FMOD_System_SetDriver", error)),
             }
         }
     }
-    pub fn is_virtual(&self) -> Result<bool, Error> {
+    pub fn get_driver(&self) -> Result<i32, Error> {
         unsafe {
-            let mut virtualstate = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_Studio_EventInstance_IsVirtual(self.pointer, &mut virtualstate) {
-                ffi::FMOD_OK => Ok(to_bool!(virtualstate)),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_IsVirtual", error)),
+            let mut driver = i32::default();
+            match ffi::FMOD_System_GetDriver(self.pointer, &mut driver) {
+                ffi::FMOD_OK => Ok(driver),
+                error => Err(err_fmod!("FMOD_System_GetDriver", error)),
             }
         }
     }
-    pub fn get_parameter_by_name(&self, name: &str) -> Result<(f32, f32), Error> {
+    pub fn set_software_channels(&self, numsoftwarechannels: i32) -> Result<(), Error> {
         unsafe {
-            let mut value = f32::default();
-            let mut finalvalue = f32::default();
-            match ffi::FMOD_Studio_EventInstance_GetParameterByName(
-                self.pointer,
-                CString::new(name)?.as_ptr(),
-                &mut value,
-                &mut finalvalue,
-            ) {
-                ffi::FMOD_OK => Ok((value, finalvalue)),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_GetParameterByName",
-                    error
-                )),
+            match ffi::FMOD_System_SetSoftwareChannels(self.pointer, numsoftwarechannels) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_SetSoftwareChannels", error)),
             }
         }
     }
-    pub fn set_parameter_by_name(
-        &self,
-        name: &str,
-        value: f32,
-        ignoreseekspeed: bool,
-    ) -> Result<(), Error> {
+    pub fn get_software_channels(&self) -> Result<i32, Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetParameterByName(
-                self.pointer,
-                CString::new(name)?.as_ptr(),
-                value,
-                from_bool!(ignoreseekspeed),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_SetParameterByName",
-                    error
-                )),
+            let mut numsoftwarechannels = i32::default();
+            match ffi::FMOD_System_GetSoftwareChannels(self.pointer, &mut numsoftwarechannels) {
+                ffi::FMOD_OK => Ok(numsoftwarechannels),
+                error => Err(err_fmod!("FMOD_System_GetSoftwareChannels", error)),
             }
         }
     }
-    pub fn set_parameter_by_name_with_label(
+    pub fn set_software_format(
         &self,
-        name: &str,
-        label: &str,
-        ignoreseekspeed: bool,
+        samplerate: Option<i32>,
+        speakermode: Option<SpeakerMode>,
+        numrawspeakers: Option<i32>,
     ) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetParameterByNameWithLabel(
+            match ffi::FMOD_System_SetSoftwareFormat(
                 self.pointer,
-                CString::new(name)?.as_ptr(),
-                CString::new(label)?.as_ptr(),
-                from_bool!(ignoreseekspeed),
+                samplerate.unwrap_or(0),
+                speakermode.map(|value| value.into()).unwrap_or(0),
+                numrawspeakers.unwrap_or(0),
             ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_SetParameterByNameWithLabel",
-                    error
-                )),
+                error => Err(err_fmod!("FMOD_System_SetSoftwareFormat", error)),
             }
         }
     }
-    pub fn get_parameter_by_id(&self, id: ParameterId) -> Result<(f32, f32), Error> {
+    pub fn get_software_format(&self) -> Result<(i32, SpeakerMode, i32), Error> {
         unsafe {
-            let mut value = f32::default();
-            let mut finalvalue = f32::default();
-            match ffi::FMOD_Studio_EventInstance_GetParameterByID(
+            let mut samplerate = i32::default();
+            let mut speakermode = ffi::FMOD_SPEAKERMODE::default();
+            let mut numrawspeakers = i32::default();
+            match ffi::FMOD_System_GetSoftwareFormat(
                 self.pointer,
-                id.into(),
-                &mut value,
-                &mut finalvalue,
+                &mut samplerate,
+                &mut speakermode,
+                &mut numrawspeakers,
             ) {
-                ffi::FMOD_OK => Ok((value, finalvalue)),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_GetParameterByID",
-                    error
-                )),
+                ffi::FMOD_OK => Ok((samplerate, SpeakerMode::from(speakermode)?, numrawspeakers)),
+                error => Err(err_fmod!("FMOD_System_GetSoftwareFormat", error)),
             }
         }
     }
-    pub fn set_parameter_by_id(
-        &self,
-        id: ParameterId,
-        value: f32,
-        ignoreseekspeed: bool,
-    ) -> Result<(), Error> {
+    pub fn set_dsp_buffer_size(&self, bufferlength: u32, numbuffers: i32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetParameterByID(
+            match ffi::FMOD_System_SetDSPBufferSize(self.pointer, bufferlength, numbuffers) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_SetDSPBufferSize", error)),
+            }
+        }
+    }
+    pub fn get_dsp_buffer_size(&self) -> Result<(u32, i32), Error> {
+        unsafe {
+            let mut bufferlength = u32::default();
+            let mut numbuffers = i32::default();
+            match ffi::FMOD_System_GetDSPBufferSize(
                 self.pointer,
-                id.into(),
-                value,
-                from_bool!(ignoreseekspeed),
+                &mut bufferlength,
+                &mut numbuffers,
             ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_SetParameterByID",
-                    error
-                )),
+                ffi::FMOD_OK => Ok((bufferlength, numbuffers)),
+                error => Err(err_fmod!("FMOD_System_GetDSPBufferSize", error)),
             }
         }
     }
-    pub fn set_parameter_by_id_with_label(
+    pub fn set_file_system(
         &self,
-        id: ParameterId,
-        label: &str,
-        ignoreseekspeed: bool,
+        useropen: ffi::FMOD_FILE_OPEN_CALLBACK,
+        userclose: ffi::FMOD_FILE_CLOSE_CALLBACK,
+        userread: ffi::FMOD_FILE_READ_CALLBACK,
+        userseek: ffi::FMOD_FILE_SEEK_CALLBACK,
+        userasyncread: ffi::FMOD_FILE_ASYNCREAD_CALLBACK,
+        userasynccancel: ffi::FMOD_FILE_ASYNCCANCEL_CALLBACK,
+        blockalign: Option<i32>,
     ) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetParameterByIDWithLabel(
+            match ffi::FMOD_System_SetFileSystem(
                 self.pointer,
-                id.into(),
-                CString::new(label)?.as_ptr(),
-                from_bool!(ignoreseekspeed),
+                useropen,
+                userclose,
+                userread,
+                userseek,
+                userasyncread,
+                userasynccancel,
+                blockalign.unwrap_or(0),
             ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_SetParameterByIDWithLabel",
-                    error
-                )),
+                error => Err(err_fmod!("FMOD_System_SetFileSystem", error)),
             }
         }
     }
-    pub fn set_parameters_by_i_ds(
+    pub fn attach_file_system(
         &self,
-        ids: ParameterId,
-        values: *mut f32,
-        count: i32,
-        ignoreseekspeed: bool,
+        useropen: ffi::FMOD_FILE_OPEN_CALLBACK,
+        userclose: ffi::FMOD_FILE_CLOSE_CALLBACK,
+        userread: ffi::FMOD_FILE_READ_CALLBACK,
+        userseek: ffi::FMOD_FILE_SEEK_CALLBACK,
     ) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetParametersByIDs(
+            match ffi::FMOD_System_AttachFileSystem(
                 self.pointer,
-                &ids.into(),
-                values,
-                count,
-                from_bool!(ignoreseekspeed),
+                useropen,
+                userclose,
+                userread,
+                userseek,
             ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_EventInstance_SetParametersByIDs",
-                    error
-                )),
+                error => Err(err_fmod!("FMOD_System_AttachFileSystem", error)),
             }
         }
     }
-    pub fn key_off(&self) -> Result<(), Error> {
+    pub fn set_advanced_settings(&self, settings: AdvancedSettings) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_KeyOff(self.pointer) {
+            match ffi::FMOD_System_SetAdvancedSettings(self.pointer, &mut settings.into()) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_KeyOff", error)),
+                error => Err(err_fmod!("FMOD_System_SetAdvancedSettings", error)),
             }
         }
     }
-    pub fn set_callback(
-        &self,
-        callback: ffi::FMOD_STUDIO_EVENT_CALLBACK,
-        callbackmask: impl Into<ffi::FMOD_STUDIO_EVENT_CALLBACK_TYPE>,
-    ) -> Result<(), Error> {
+    pub fn get_advanced_settings(&self) -> Result<AdvancedSettings, Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetCallback(
-                self.pointer,
-                callback,
-                callbackmask.into(),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetCallback", error)),
+            let mut settings = ffi::FMOD_ADVANCEDSETTINGS::default();
+            match ffi::FMOD_System_GetAdvancedSettings(self.pointer, &mut settings) {
+                ffi::FMOD_OK => Ok(AdvancedSettings::try_from(settings)?),
+                error => Err(err_fmod!("FMOD_System_GetAdvancedSettings", error)),
             }
         }
     }
-    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+    pub fn set_callback(
+        &self,
+        callback: ffi::FMOD_SYSTEM_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_SYSTEM_CALLBACK_TYPE>,
+    ) -> Result<(), Error> {
         unsafe {
-            let mut userdata = null_mut();
-            match ffi::FMOD_Studio_EventInstance_GetUserData(self.pointer, &mut userdata) {
-                ffi::FMOD_OK => Ok(userdata),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetUserData", error)),
+            match ffi::FMOD_System_SetCallback(self.pointer, callback, callbackmask.into()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_SetCallback", error)),
             }
         }
     }
-    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+    pub fn set_plugin_path(&self, path: &str) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_EventInstance_SetUserData(self.pointer, userdata) {
+            match ffi::FMOD_System_SetPluginPath(self.pointer, CString::new(path)?.as_ptr()) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_SetUserData", error)),
+                error => Err(err_fmod!("FMOD_System_SetPluginPath", error)),
             }
         }
     }
-    pub fn get_cpu_usage(&self) -> Result<(u32, u32), Error> {
+    pub fn load_plugin(&self, filename: &str, priority: Option<u32>) -> Result<u32, Error> {
         unsafe {
-            let mut exclusive = u32::default();
-            let mut inclusive = u32::default();
-            match ffi::FMOD_Studio_EventInstance_GetCPUUsage(
+            let mut handle = u32::default();
+            match ffi::FMOD_System_LoadPlugin(
                 self.pointer,
-                &mut exclusive,
-                &mut inclusive,
+                CString::new(filename)?.as_ptr(),
+                &mut handle,
+                priority.unwrap_or(0),
             ) {
-                ffi::FMOD_OK => Ok((exclusive, inclusive)),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetCPUUsage", error)),
+                ffi::FMOD_OK => Ok(handle),
+                error => Err(err_fmod!("FMOD_System_LoadPlugin", error)),
             }
         }
     }
-    pub fn get_memory_usage(&self) -> Result<MemoryUsage, Error> {
+    pub fn unload_plugin(&self, handle: u32) -> Result<(), Error> {
         unsafe {
-            let mut memoryusage = ffi::FMOD_STUDIO_MEMORY_USAGE::default();
-            match ffi::FMOD_Studio_EventInstance_GetMemoryUsage(self.pointer, &mut memoryusage) {
-                ffi::FMOD_OK => Ok(MemoryUsage::try_from(memoryusage)?),
-                error => Err(err_fmod!("FMOD_Studio_EventInstance_GetMemoryUsage", error)),
+            match ffi::FMOD_System_UnloadPlugin(self.pointer, handle) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_UnloadPlugin", error)),
             }
         }
     }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct Studio {
-    pointer: *mut ffi::FMOD_STUDIO_SYSTEM,
-}
-unsafe impl Send for Studio {}
-unsafe impl Sync for Studio {}
-impl Studio {
-    #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_STUDIO_SYSTEM) -> Self {
-        Self { pointer }
-    }
-    #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_SYSTEM {
-        self.pointer
-    }
-    pub fn create() -> Result<Studio, Error> {
+    pub fn get_num_nested_plugins(&self, handle: u32) -> Result<i32, Error> {
         unsafe {
-            let mut system = null_mut();
-            match ffi::FMOD_Studio_System_Create(&mut system, ffi::FMOD_VERSION) {
-                ffi::FMOD_OK => Ok(Studio::from(system)),
-                error => Err(err_fmod!("FMOD_Studio_System_Create", error)),
+            let mut count = i32::default();
+            match ffi::FMOD_System_GetNumNestedPlugins(self.pointer, handle, &mut count) {
+                ffi::FMOD_OK => Ok(count),
+                error => Err(err_fmod!("FMOD_System_GetNumNestedPlugins", error)),
             }
         }
     }
-    pub fn is_valid(&self) -> bool {
-        unsafe { to_bool!(ffi::FMOD_Studio_System_IsValid(self.pointer)) }
-    }
-    pub fn set_advanced_settings(&self, settings: StudioAdvancedSettings) -> Result<(), Error> {
+    pub fn get_nested_plugin(&self, handle: u32, index: i32) -> Result<u32, Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_SetAdvancedSettings(self.pointer, &mut settings.into()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_SetAdvancedSettings", error)),
+            let mut nestedhandle = u32::default();
+            match ffi::FMOD_System_GetNestedPlugin(self.pointer, handle, index, &mut nestedhandle) {
+                ffi::FMOD_OK => Ok(nestedhandle),
+                error => Err(err_fmod!("FMOD_System_GetNestedPlugin", error)),
             }
         }
     }
-    pub fn get_advanced_settings(&self) -> Result<StudioAdvancedSettings, Error> {
+    pub fn get_num_plugins(&self, plugintype: PluginType) -> Result<i32, Error> {
         unsafe {
-            let mut settings = ffi::FMOD_STUDIO_ADVANCEDSETTINGS::default();
-            match ffi::FMOD_Studio_System_GetAdvancedSettings(self.pointer, &mut settings) {
-                ffi::FMOD_OK => Ok(StudioAdvancedSettings::try_from(settings)?),
-                error => Err(err_fmod!("FMOD_Studio_System_GetAdvancedSettings", error)),
+            let mut numplugins = i32::default();
+            match ffi::FMOD_System_GetNumPlugins(self.pointer, plugintype.into(), &mut numplugins) {
+                ffi::FMOD_OK => Ok(numplugins),
+                error => Err(err_fmod!("FMOD_System_GetNumPlugins", error)),
             }
         }
     }
-    pub fn initialize(
-        &self,
-        maxchannels: i32,
-        studioflags: impl Into<ffi::FMOD_STUDIO_INITFLAGS>,
-        flags: impl Into<ffi::FMOD_INITFLAGS>,
-        extradriverdata: Option<*mut c_void>,
-    ) -> Result<(), Error> {
+    pub fn get_plugin_handle(&self, plugintype: PluginType, index: i32) -> Result<u32, Error> {
+        unsafe {
+            let mut handle = u32::default();
+            match ffi::FMOD_System_GetPluginHandle(
+                self.pointer,
+                plugintype.into(),
+                index,
+                &mut handle,
+            ) {
+                ffi::FMOD_OK => Ok(handle),
+                error => Err(err_fmod!("FMOD_System_GetPluginHandle", error)),
+            }
+        }
+    }
+    pub fn get_plugin_info(
+        &self,
+        handle: u32,
+        namelen: i32,
+    ) -> Result<(PluginType, String, u32), Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_Initialize(
+            let mut plugintype = ffi::FMOD_PLUGINTYPE::default();
+            let mut name = vec![0 as c_char; namelen.max(1) as usize];
+            let mut version = u32::default();
+            match ffi::FMOD_System_GetPluginInfo(
                 self.pointer,
-                maxchannels,
-                studioflags.into(),
-                flags.into(),
-                extradriverdata.unwrap_or(null_mut()),
+                handle,
+                &mut plugintype,
+                name.as_mut_ptr(),
+                name.len() as i32,
+                &mut version,
             ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_Initialize", error)),
+                ffi::FMOD_OK => Ok((
+                    PluginType::from(plugintype)?,
+                    CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned(),
+                    version,
+                )),
+                error => Err(err_fmod!("FMOD_System_GetPluginInfo", error)),
             }
         }
     }
-    pub fn release(&self) -> Result<(), Error> {
+    pub fn set_output_by_plugin(&self, handle: u32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_Release(self.pointer) {
+            match ffi::FMOD_System_SetOutputByPlugin(self.pointer, handle) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_Release", error)),
+                error => Err(err_fmod!("FMOD_System_SetOutputByPlugin", error)),
             }
         }
     }
-    pub fn update(&self) -> Result<(), Error> {
+    pub fn get_output_by_plugin(&self) -> Result<u32, Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_Update(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_Update", error)),
+            let mut handle = u32::default();
+            match ffi::FMOD_System_GetOutputByPlugin(self.pointer, &mut handle) {
+                ffi::FMOD_OK => Ok(handle),
+                error => Err(err_fmod!("FMOD_System_GetOutputByPlugin", error)),
             }
         }
     }
-    pub fn get_core_system(&self) -> Result<System, Error> {
+    pub fn create_dsp_by_plugin(&self, handle: u32) -> Result<Dsp, Error> {
         unsafe {
-            let mut coresystem = null_mut();
-            match ffi::FMOD_Studio_System_GetCoreSystem(self.pointer, &mut coresystem) {
-                ffi::FMOD_OK => Ok(System::from(coresystem)),
-                error => Err(err_fmod!("FMOD_Studio_System_GetCoreSystem", error)),
+            let mut dsp = null_mut();
+            match ffi::FMOD_System_CreateDSPByPlugin(self.pointer, handle, &mut dsp) {
+                ffi::FMOD_OK => Ok(Dsp::from(dsp)),
+                error => Err(err_fmod!("FMOD_System_CreateDSPByPlugin", error)),
             }
         }
     }
-    pub fn get_event(&self, path_or_id: &str) -> Result<EventDescription, Error> {
+    pub fn get_dsp_info_by_plugin(&self, handle: u32) -> Result<DspDescription, Error> {
         unsafe {
-            let mut event = null_mut();
-            match ffi::FMOD_Studio_System_GetEvent(
-                self.pointer,
-                CString::new(path_or_id)?.as_ptr(),
-                &mut event,
-            ) {
-                ffi::FMOD_OK => Ok(EventDescription::from(event)),
-                error => Err(err_fmod!("FMOD_Studio_System_GetEvent", error)),
+            let mut description = null();
+            match ffi::FMOD_System_GetDSPInfoByPlugin(self.pointer, handle, &mut description) {
+                ffi::FMOD_OK => Ok(DspDescription::try_from(*description)?),
+                error => Err(err_fmod!("FMOD_System_GetDSPInfoByPlugin", error)),
             }
         }
     }
-    pub fn get_bus(&self, path_or_id: &str) -> Result<Bus, Error> {
+    pub fn register_codec(
+        &self,
+        description: CodecDescription,
+        priority: Option<u32>,
+    ) -> Result<u32, Error> {
         unsafe {
-            let mut bus = null_mut();
-            match ffi::FMOD_Studio_System_GetBus(
+            let mut handle = u32::default();
+            match ffi::FMOD_System_RegisterCodec(
                 self.pointer,
-                CString::new(path_or_id)?.as_ptr(),
-                &mut bus,
+                &mut description.into(),
+                &mut handle,
+                priority.unwrap_or(0),
             ) {
-                ffi::FMOD_OK => Ok(Bus::from(bus)),
-                error => Err(err_fmod!("FMOD_Studio_System_GetBus", error)),
+                ffi::FMOD_OK => Ok(handle),
+                error => Err(err_fmod!("FMOD_System_RegisterCodec", error)),
             }
         }
     }
-    pub fn get_vca(&self, path_or_id: &str) -> Result<Vca, Error> {
+    pub fn register_dsp(&self, description: DspDescription) -> Result<u32, Error> {
         unsafe {
-            let mut vca = null_mut();
-            match ffi::FMOD_Studio_System_GetVCA(
-                self.pointer,
-                CString::new(path_or_id)?.as_ptr(),
-                &mut vca,
-            ) {
-                ffi::FMOD_OK => Ok(Vca::from(vca)),
-                error => Err(err_fmod!("FMOD_Studio_System_GetVCA", error)),
+            let mut handle = u32::default();
+            match ffi::FMOD_System_RegisterDSP(self.pointer, &description.into(), &mut handle) {
+                ffi::FMOD_OK => Ok(handle),
+                error => Err(err_fmod!("FMOD_System_RegisterDSP", error)),
             }
         }
     }
-    pub fn get_bank(&self, path_or_id: &str) -> Result<Bank, Error> {
+    pub fn register_output(&self, description: OutputDescription) -> Result<u32, Error> {
         unsafe {
-            let mut bank = null_mut();
-            match ffi::FMOD_Studio_System_GetBank(
-                self.pointer,
-                CString::new(path_or_id)?.as_ptr(),
-                &mut bank,
-            ) {
-                ffi::FMOD_OK => Ok(Bank::from(bank)),
-                error => Err(err_fmod!("FMOD_Studio_System_GetBank", error)),
+            let mut handle = u32::default();
+            match ffi::FMOD_System_RegisterOutput(self.pointer, &description.into(), &mut handle) {
+                ffi::FMOD_OK => Ok(handle),
+                error => Err(err_fmod!("FMOD_System_RegisterOutput", error)),
             }
         }
     }
-    pub fn get_event_by_id(&self, id: Guid) -> Result<EventDescription, Error> {
+    pub fn init(
+        &self,
+        maxchannels: i32,
+        flags: impl Into<ffi::FMOD_INITFLAGS>,
+        extradriverdata: Option<*mut c_void>,
+    ) -> Result<(), Error> {
         unsafe {
-            let mut event = null_mut();
-            match ffi::FMOD_Studio_System_GetEventByID(self.pointer, &id.into(), &mut event) {
-                ffi::FMOD_OK => Ok(EventDescription::from(event)),
-                error => Err(err_fmod!("FMOD_Studio_System_GetEventByID", error)),
+            match ffi::FMOD_System_Init(
+                self.pointer,
+                maxchannels,
+                flags.into(),
+                extradriverdata.unwrap_or(null_mut()),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_Init", error)),
             }
         }
     }
-    pub fn get_bus_by_id(&self, id: Guid) -> Result<Bus, Error> {
+    pub fn close(&self) -> Result<(), Error> {
         unsafe {
-            let mut bus = null_mut();
-            match ffi::FMOD_Studio_System_GetBusByID(self.pointer, &id.into(), &mut bus) {
-                ffi::FMOD_OK => Ok(Bus::from(bus)),
-                error => Err(err_fmod!("FMOD_Studio_System_GetBusByID", error)),
+            match ffi::FMOD_System_Close(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_Close", error)),
             }
         }
     }
-    pub fn get_vca_by_id(&self, id: Guid) -> Result<Vca, Error> {
+    pub fn update(&self) -> Result<(), Error> {
         unsafe {
-            let mut vca = null_mut();
-            match ffi::FMOD_Studio_System_GetVCAByID(self.pointer, &id.into(), &mut vca) {
-                ffi::FMOD_OK => Ok(Vca::from(vca)),
-                error => Err(err_fmod!("FMOD_Studio_System_GetVCAByID", error)),
+            match ffi::FMOD_System_Update(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_Update", error)),
             }
         }
     }
-    pub fn get_bank_by_id(&self, id: Guid) -> Result<Bank, Error> {
+    pub fn set_speaker_position(
+        &self,
+        speaker: Speaker,
+        x: f32,
+        y: f32,
+        active: bool,
+    ) -> Result<(), Error> {
         unsafe {
-            let mut bank = null_mut();
-            match ffi::FMOD_Studio_System_GetBankByID(self.pointer, &id.into(), &mut bank) {
-                ffi::FMOD_OK => Ok(Bank::from(bank)),
-                error => Err(err_fmod!("FMOD_Studio_System_GetBankByID", error)),
+            match ffi::FMOD_System_SetSpeakerPosition(
+                self.pointer,
+                speaker.into(),
+                x,
+                y,
+                from_bool!(active),
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_SetSpeakerPosition", error)),
             }
         }
     }
-    pub fn get_sound_info(&self, key: &str) -> Result<SoundInfo, Error> {
+    pub fn get_speaker_position(&self, speaker: Speaker) -> Result<(f32, f32, bool), Error> {
         unsafe {
-            let mut info = ffi::FMOD_STUDIO_SOUND_INFO::default();
-            match ffi::FMOD_Studio_System_GetSoundInfo(
+            let mut x = f32::default();
+            let mut y = f32::default();
+            let mut active = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_System_GetSpeakerPosition(
                 self.pointer,
-                CString::new(key)?.as_ptr(),
-                &mut info,
+                speaker.into(),
+                &mut x,
+                &mut y,
+                &mut active,
             ) {
-                ffi::FMOD_OK => Ok(SoundInfo::try_from(info)?),
-                error => Err(err_fmod!("FMOD_Studio_System_GetSoundInfo", error)),
+                ffi::FMOD_OK => Ok((x, y, to_bool!(active))),
+                error => Err(err_fmod!("FMOD_System_GetSpeakerPosition", error)),
             }
         }
     }
-    pub fn get_parameter_description_by_name(
+    pub fn set_stream_buffer_size(
         &self,
-        name: &str,
-    ) -> Result<ParameterDescription, Error> {
+        filebuffersize: u32,
+        filebuffersizetype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(), Error> {
         unsafe {
-            let mut parameter = ffi::FMOD_STUDIO_PARAMETER_DESCRIPTION::default();
-            match ffi::FMOD_Studio_System_GetParameterDescriptionByName(
+            match ffi::FMOD_System_SetStreamBufferSize(
                 self.pointer,
-                CString::new(name)?.as_ptr(),
-                &mut parameter,
+                filebuffersize,
+                filebuffersizetype.into(),
             ) {
-                ffi::FMOD_OK => Ok(ParameterDescription::try_from(parameter)?),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_System_GetParameterDescriptionByName",
-                    error
-                )),
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_SetStreamBufferSize", error)),
             }
         }
     }
-    pub fn get_parameter_description_by_id(
-        &self,
-        id: ParameterId,
-    ) -> Result<ParameterDescription, Error> {
+    pub fn get_stream_buffer_size(&self) -> Result<(u32, ffi::FMOD_TIMEUNIT), Error> {
         unsafe {
-            let mut parameter = ffi::FMOD_STUDIO_PARAMETER_DESCRIPTION::default();
-            match ffi::FMOD_Studio_System_GetParameterDescriptionByID(
+            let mut filebuffersize = u32::default();
+            let mut filebuffersizetype = ffi::FMOD_TIMEUNIT::default();
+            match ffi::FMOD_System_GetStreamBufferSize(
                 self.pointer,
-                id.into(),
-                &mut parameter,
+                &mut filebuffersize,
+                &mut filebuffersizetype,
             ) {
-                ffi::FMOD_OK => Ok(ParameterDescription::try_from(parameter)?),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_System_GetParameterDescriptionByID",
-                    error
-                )),
+                ffi::FMOD_OK => Ok((filebuffersize, filebuffersizetype)),
+                error => Err(err_fmod!("FMOD_System_GetStreamBufferSize", error)),
             }
         }
     }
-    pub fn get_parameter_label_by_name(
+    pub fn set_3d_settings(
         &self,
-        name: &str,
-        labelindex: i32,
-        size: i32,
-    ) -> Result<(String, i32), Error> {
+        dopplerscale: f32,
+        distancefactor: f32,
+        rolloffscale: f32,
+    ) -> Result<(), Error> {
         unsafe {
-            let label = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            let mut retrieved = i32::default();
-            match ffi::FMOD_Studio_System_GetParameterLabelByName(
+            match ffi::FMOD_System_Set3DSettings(
                 self.pointer,
-                CString::new(name)?.as_ptr(),
-                labelindex,
-                label,
-                size,
-                &mut retrieved,
-            ) {
-                ffi::FMOD_OK => Ok((
-                    CString::from_raw(label)
-                        .into_string()
-                        .map_err(Error::String)?,
-                    retrieved,
-                )),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_System_GetParameterLabelByName",
-                    error
-                )),
+                dopplerscale,
+                distancefactor,
+                rolloffscale,
+            ) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_Set3DSettings", error)),
             }
         }
     }
-    pub fn get_parameter_label_by_id(
-        &self,
-        id: ParameterId,
-        labelindex: i32,
-        size: i32,
-    ) -> Result<(String, i32), Error> {
+    pub fn get_3d_settings(&self) -> Result<(f32, f32, f32), Error> {
         unsafe {
-            let label = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            let mut retrieved = i32::default();
-            match ffi::FMOD_Studio_System_GetParameterLabelByID(
+            let mut dopplerscale = f32::default();
+            let mut distancefactor = f32::default();
+            let mut rolloffscale = f32::default();
+            match ffi::FMOD_System_Get3DSettings(
                 self.pointer,
-                id.into(),
-                labelindex,
-                label,
-                size,
-                &mut retrieved,
+                &mut dopplerscale,
+                &mut distancefactor,
+                &mut rolloffscale,
             ) {
-                ffi::FMOD_OK => Ok((
-                    CString::from_raw(label)
-                        .into_string()
-                        .map_err(Error::String)?,
-                    retrieved,
-                )),
-                error => Err(err_fmod!("FMOD_Studio_System_GetParameterLabelByID", error)),
+                ffi::FMOD_OK => Ok((dopplerscale, distancefactor, rolloffscale)),
+                error => Err(err_fmod!("FMOD_System_Get3DSettings", error)),
             }
         }
     }
-    pub fn get_parameter_by_id(&self, id: ParameterId) -> Result<(f32, f32), Error> {
+    pub fn set_3d_num_listeners(&self, numlisteners: i32) -> Result<(), Error> {
         unsafe {
-            let mut value = f32::default();
-            let mut finalvalue = f32::default();
-            match ffi::FMOD_Studio_System_GetParameterByID(
-                self.pointer,
-                id.into(),
-                &mut value,
-                &mut finalvalue,
-            ) {
-                ffi::FMOD_OK => Ok((value, finalvalue)),
-                error => Err(err_fmod!("FMOD_Studio_System_GetParameterByID", error)),
+            match ffi::FMOD_System_Set3DNumListeners(self.pointer, numlisteners) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_Set3DNumListeners", error)),
             }
         }
     }
-    pub fn set_parameter_by_id(
+    pub fn get_3d_num_listeners(&self) -> Result<i32, Error> {
+        unsafe {
+            let mut numlisteners = i32::default();
+            match ffi::FMOD_System_Get3DNumListeners(self.pointer, &mut numlisteners) {
+                ffi::FMOD_OK => Ok(numlisteners),
+                error => Err(err_fmod!("FMOD_System_Get3DNumListeners", error)),
+            }
+        }
+    }
+    pub fn set_3d_listener_attributes(
         &self,
-        id: ParameterId,
-        value: f32,
-        ignoreseekspeed: bool,
+        listener: i32,
+        pos: Option<Vector>,
+        vel: Option<Vector>,
+        forward: Option<Vector>,
+        up: Option<Vector>,
     ) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_SetParameterByID(
+            match ffi::FMOD_System_Set3DListenerAttributes(
                 self.pointer,
-                id.into(),
-                value,
-                from_bool!(ignoreseekspeed),
+                listener,
+                pos.map(Vector::into)
+                    .as_ref()
+                    .map(from_ref)
+                    .unwrap_or_else(null),
+                vel.map(Vector::into)
+                    .as_ref()
+                    .map(from_ref)
+                    .unwrap_or_else(null),
+                forward
+                    .map(Vector::into)
+                    .as_ref()
+                    .map(from_ref)
+                    .unwrap_or_else(null),
+                up.map(Vector::into)
+                    .as_ref()
+                    .map(from_ref)
+                    .unwrap_or_else(null),
             ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_SetParameterByID", error)),
+                error => Err(err_fmod!("FMOD_System_Set3DListenerAttributes", error)),
             }
         }
     }
-    pub fn set_parameter_by_id_with_label(
+    pub fn get_3d_listener_attributes(
         &self,
-        id: ParameterId,
-        label: &str,
-        ignoreseekspeed: bool,
-    ) -> Result<(), Error> {
+        listener: i32,
+    ) -> Result<(Vector, Vector, Vector, Vector), Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_SetParameterByIDWithLabel(
+            let mut pos = ffi::FMOD_VECTOR::default();
+            let mut vel = ffi::FMOD_VECTOR::default();
+            let mut forward = ffi::FMOD_VECTOR::default();
+            let mut up = ffi::FMOD_VECTOR::default();
+            match ffi::FMOD_System_Get3DListenerAttributes(
                 self.pointer,
-                id.into(),
-                CString::new(label)?.as_ptr(),
-                from_bool!(ignoreseekspeed),
+                listener,
+                &mut pos,
+                &mut vel,
+                &mut forward,
+                &mut up,
             ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_System_SetParameterByIDWithLabel",
-                    error
+                ffi::FMOD_OK => Ok((
+                    Vector::try_from(pos)?,
+                    Vector::try_from(vel)?,
+                    Vector::try_from(forward)?,
+                    Vector::try_from(up)?,
                 )),
+                error => Err(err_fmod!("FMOD_System_Get3DListenerAttributes", error)),
             }
         }
     }
-    pub fn set_parameters_by_i_ds(
+    pub fn set_3d_rolloff_callback(
         &self,
-        ids: ParameterId,
-        values: *mut f32,
-        count: i32,
-        ignoreseekspeed: bool,
+        callback: ffi::FMOD_3D_ROLLOFF_CALLBACK,
     ) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_SetParametersByIDs(
-                self.pointer,
-                &ids.into(),
-                values,
-                count,
-                from_bool!(ignoreseekspeed),
-            ) {
+            match ffi::FMOD_System_Set3DRolloffCallback(self.pointer, callback) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_SetParametersByIDs", error)),
+                error => Err(err_fmod!("FMOD_System_Set3DRolloffCallback", error)),
             }
         }
     }
-    pub fn get_parameter_by_name(&self, name: &str) -> Result<(f32, f32), Error> {
+    pub fn mixer_suspend(&self) -> Result<(), Error> {
         unsafe {
-            let mut value = f32::default();
-            let mut finalvalue = f32::default();
-            match ffi::FMOD_Studio_System_GetParameterByName(
-                self.pointer,
-                CString::new(name)?.as_ptr(),
-                &mut value,
-                &mut finalvalue,
-            ) {
-                ffi::FMOD_OK => Ok((value, finalvalue)),
-                error => Err(err_fmod!("FMOD_Studio_System_GetParameterByName", error)),
+            match ffi::FMOD_System_MixerSuspend(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_MixerSuspend", error)),
             }
         }
     }
-    pub fn set_parameter_by_name(
-        &self,
-        name: &str,
-        value: f32,
-        ignoreseekspeed: bool,
-    ) -> Result<(), Error> {
+    pub fn mixer_resume(&self) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_SetParameterByName(
-                self.pointer,
-                CString::new(name)?.as_ptr(),
-                value,
-                from_bool!(ignoreseekspeed),
-            ) {
+            match ffi::FMOD_System_MixerResume(self.pointer) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_SetParameterByName", error)),
+                error => Err(err_fmod!("FMOD_System_MixerResume", error)),
             }
         }
     }
-    pub fn set_parameter_by_name_with_label(
+    pub fn get_default_mix_matrix(
         &self,
-        name: &str,
-        label: &str,
-        ignoreseekspeed: bool,
-    ) -> Result<(), Error> {
+        sourcespeakermode: SpeakerMode,
+        targetspeakermode: SpeakerMode,
+    ) -> Result<MixMatrix, Error> {
+        let inchannels = self.get_speaker_mode_channels(sourcespeakermode)?;
+        let outchannels = self.get_speaker_mode_channels(targetspeakermode)?;
+        let mut matrix = MixMatrix::new(outchannels as usize, inchannels as usize);
         unsafe {
-            match ffi::FMOD_Studio_System_SetParameterByNameWithLabel(
+            match ffi::FMOD_System_GetDefaultMixMatrix(
                 self.pointer,
-                CString::new(name)?.as_ptr(),
-                CString::new(label)?.as_ptr(),
-                from_bool!(ignoreseekspeed),
+                sourcespeakermode.into(),
+                targetspeakermode.into(),
+                matrix.as_mut_ptr(),
+                0,
             ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_System_SetParameterByNameWithLabel",
-                    error
-                )),
+                ffi::FMOD_OK => Ok(matrix),
+                error => Err(err_fmod!("FMOD_System_GetDefaultMixMatrix", error)),
             }
         }
     }
-    pub fn lookup_id(&self, path: &str) -> Result<Guid, Error> {
+    pub fn get_speaker_mode_channels(&self, mode: SpeakerMode) -> Result<i32, Error> {
         unsafe {
-            let mut id = ffi::FMOD_GUID::default();
-            match ffi::FMOD_Studio_System_LookupID(
+            let mut channels = i32::default();
+            match ffi::FMOD_System_GetSpeakerModeChannels(self.pointer, mode.into(), &mut channels)
+            {
+                ffi::FMOD_OK => Ok(channels),
+                error => Err(err_fmod!("FMOD_System_GetSpeakerModeChannels", error)),
+            }
+        }
+    }
+    pub fn get_version(&self) -> Result<u32, Error> {
+        unsafe {
+            let mut version = u32::default();
+            match ffi::FMOD_System_GetVersion(self.pointer, &mut version) {
+                ffi::FMOD_OK => Ok(version),
+                error => Err(err_fmod!("FMOD_System_GetVersion", error)),
+            }
+        }
+    }
+    pub fn get_output_handle(&self) -> Result<*mut c_void, Error> {
+        unsafe {
+            let mut handle = null_mut();
+            match ffi::FMOD_System_GetOutputHandle(self.pointer, &mut handle) {
+                ffi::FMOD_OK => Ok(handle),
+                error => Err(err_fmod!("FMOD_System_GetOutputHandle", error)),
+            }
+        }
+    }
+    pub fn get_channels_playing(&self) -> Result<(i32, i32), Error> {
+        unsafe {
+            let mut channels = i32::default();
+            let mut realchannels = i32::default();
+            match ffi::FMOD_System_GetChannelsPlaying(
                 self.pointer,
-                CString::new(path)?.as_ptr(),
-                &mut id,
+                &mut channels,
+                &mut realchannels,
             ) {
-                ffi::FMOD_OK => Ok(Guid::try_from(id)?),
-                error => Err(err_fmod!("FMOD_Studio_System_LookupID", error)),
+                ffi::FMOD_OK => Ok((channels, realchannels)),
+                error => Err(err_fmod!("FMOD_System_GetChannelsPlaying", error)),
             }
         }
     }
-    pub fn lookup_path(&self, id: Guid) -> Result<String, Error> {
+    pub fn get_cpu_usage(&self) -> Result<CpuUsage, Error> {
         unsafe {
-            let mut retrieved = i32::default();
-            let id = id.into();
-            match ffi::FMOD_Studio_System_LookupPath(
-                self.pointer,
-                &id,
-                null_mut(),
-                0,
-                &mut retrieved,
+            let mut usage = ffi::FMOD_CPU_USAGE::default();
+            match ffi::FMOD_System_GetCPUUsage(self.pointer, &mut usage) {
+                ffi::FMOD_OK => Ok(CpuUsage::try_from(usage)?),
+                error => Err(err_fmod!("FMOD_System_GetCPUUsage", error)),
+            }
+        }
+    }
+    pub fn get_file_usage(&self) -> Result<(i64, i64, i64), Error> {
+        unsafe {
+            let mut sample_bytes_read = i64::default();
+            let mut stream_bytes_read = i64::default();
+            let mut other_bytes_read = i64::default();
+            match ffi::FMOD_System_GetFileUsage(
+                self.pointer,
+                &mut sample_bytes_read,
+                &mut stream_bytes_read,
+                &mut other_bytes_read,
             ) {
-                ffi::FMOD_OK => {
-                    let mut buf = vec![0u8; retrieved as usize];
-                    match ffi::FMOD_Studio_System_LookupPath(
-                        self.pointer,
-                        &id,
-                        buf.as_mut_ptr() as *mut _,
-                        retrieved,
-                        &mut retrieved,
-                    ) {
-                        ffi::FMOD_OK => Ok(CString::from_vec_with_nul_unchecked(buf)
-                            .into_string()
-                            .map_err(Error::String)?),
-                        error => Err(err_fmod!("FMOD_Studio_System_LookupPath", error)),
-                    }
-                }
-                error => Err(err_fmod!("FMOD_Studio_System_LookupPath", error)),
+                ffi::FMOD_OK => Ok((sample_bytes_read, stream_bytes_read, other_bytes_read)),
+                error => Err(err_fmod!("FMOD_System_GetFileUsage", error)),
             }
         }
     }
-    pub fn get_num_listeners(&self) -> Result<i32, Error> {
+    pub fn create_sound(
+        &self,
+        name_or_data: &str,
+        mode: impl Into<ffi::FMOD_MODE>,
+        exinfo: Option<CreateSoundexInfo>,
+    ) -> Result<Sound, Error> {
         unsafe {
-            let mut numlisteners = i32::default();
-            match ffi::FMOD_Studio_System_GetNumListeners(self.pointer, &mut numlisteners) {
-                ffi::FMOD_OK => Ok(numlisteners),
-                error => Err(err_fmod!("FMOD_Studio_System_GetNumListeners", error)),
+            let mut sound = null_mut();
+            match ffi::FMOD_System_CreateSound(
+                self.pointer,
+                CString::new(name_or_data)?.as_ptr(),
+                mode.into(),
+                exinfo
+                    .map(|value| &mut value.into() as *mut _)
+                    .unwrap_or(null_mut()),
+                &mut sound,
+            ) {
+                ffi::FMOD_OK => Ok(Sound::from(sound)),
+                error => Err(err_fmod!("FMOD_System_CreateSound", error)),
             }
         }
     }
-    pub fn set_num_listeners(&self, numlisteners: i32) -> Result<(), Error> {
+    pub fn create_sound_from(
+        &self,
+        data: &[u8],
+        mode: impl Into<ffi::FMOD_MODE>,
+        exinfo: CreateSoundexInfo,
+    ) -> Result<Sound, Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_SetNumListeners(self.pointer, numlisteners) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_SetNumListeners", error)),
+            let mut sound = null_mut();
+            match ffi::FMOD_System_CreateSound(
+                self.pointer,
+                data.as_ptr() as *const _,
+                mode.into(),
+                &mut exinfo.into() as *mut _,
+                &mut sound,
+            ) {
+                ffi::FMOD_OK => Ok(Sound::from(sound)),
+                error => Err(err_fmod!("FMOD_System_CreateSound", error)),
             }
         }
     }
-    pub fn get_listener_attributes(&self, index: i32) -> Result<(Attributes3d, Vector), Error> {
+    pub fn create_stream(
+        &self,
+        name_or_data: &str,
+        mode: impl Into<ffi::FMOD_MODE>,
+        exinfo: Option<CreateSoundexInfo>,
+    ) -> Result<Sound, Error> {
         unsafe {
-            let mut attributes = ffi::FMOD_3D_ATTRIBUTES::default();
-            let mut attenuationposition = ffi::FMOD_VECTOR::default();
-            match ffi::FMOD_Studio_System_GetListenerAttributes(
+            let mut sound = null_mut();
+            match ffi::FMOD_System_CreateStream(
                 self.pointer,
-                index,
-                &mut attributes,
-                &mut attenuationposition,
+                CString::new(name_or_data)?.as_ptr(),
+                mode.into(),
+                exinfo
+                    .map(|value| &mut value.into() as *mut _)
+                    .unwrap_or(null_mut()),
+                &mut sound,
             ) {
-                ffi::FMOD_OK => Ok((
-                    Attributes3d::try_from(attributes)?,
-                    Vector::try_from(attenuationposition)?,
-                )),
-                error => Err(err_fmod!("FMOD_Studio_System_GetListenerAttributes", error)),
+                ffi::FMOD_OK => Ok(Sound::from(sound)),
+                error => Err(err_fmod!("FMOD_System_CreateStream", error)),
             }
         }
     }
-    pub fn set_listener_attributes(
+    pub fn create_stream_from(
         &self,
-        index: i32,
-        attributes: Attributes3d,
-        attenuationposition: Option<Vector>,
-    ) -> Result<(), Error> {
+        data: &[u8],
+        mode: impl Into<ffi::FMOD_MODE>,
+        exinfo: CreateSoundexInfo,
+    ) -> Result<Sound, Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_SetListenerAttributes(
+            let mut sound = null_mut();
+            match ffi::FMOD_System_CreateStream(
                 self.pointer,
-                index,
-                &attributes.into(),
-                attenuationposition
-                    .map(Vector::into)
-                    .as_ref()
-                    .map(from_ref)
-                    .unwrap_or_else(null),
+                data.as_ptr() as *const _,
+                mode.into(),
+                &mut exinfo.into() as *mut _,
+                &mut sound,
             ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_SetListenerAttributes", error)),
+                ffi::FMOD_OK => Ok(Sound::from(sound)),
+                error => Err(err_fmod!("FMOD_System_CreateStream", error)),
             }
         }
     }
-    pub fn get_listener_weight(&self, index: i32) -> Result<f32, Error> {
+    pub fn create_dsp(&self, description: DspDescription) -> Result<Dsp, Error> {
         unsafe {
-            let mut weight = f32::default();
-            match ffi::FMOD_Studio_System_GetListenerWeight(self.pointer, index, &mut weight) {
-                ffi::FMOD_OK => Ok(weight),
-                error => Err(err_fmod!("FMOD_Studio_System_GetListenerWeight", error)),
+            let mut dsp = null_mut();
+            match ffi::FMOD_System_CreateDSP(self.pointer, &description.into(), &mut dsp) {
+                ffi::FMOD_OK => Ok(Dsp::from(dsp)),
+                error => Err(err_fmod!("FMOD_System_CreateDSP", error)),
             }
         }
     }
-    pub fn set_listener_weight(&self, index: i32, weight: f32) -> Result<(), Error> {
+    pub fn create_dsp_by_type(&self, type_: DspType) -> Result<Dsp, Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_SetListenerWeight(self.pointer, index, weight) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_SetListenerWeight", error)),
+            let mut dsp = null_mut();
+            match ffi::FMOD_System_CreateDSPByType(self.pointer, type_.into(), &mut dsp) {
+                ffi::FMOD_OK => Ok(Dsp::from(dsp)),
+                error => Err(err_fmod!("FMOD_System_CreateDSPByType", error)),
             }
         }
     }
-    pub fn load_bank_file(
-        &self,
-        filename: &str,
-        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
-    ) -> Result<Bank, Error> {
+    pub fn create_channel_group(&self, name: Option<String>) -> Result<ChannelGroup, Error> {
         unsafe {
-            let mut bank = null_mut();
-            match ffi::FMOD_Studio_System_LoadBankFile(
+            let mut channelgroup = null_mut();
+            match ffi::FMOD_System_CreateChannelGroup(
                 self.pointer,
-                CString::new(filename)?.as_ptr(),
-                flags.into(),
-                &mut bank,
+                name.map(CString::new)
+                    .transpose()?
+                    .as_deref()
+                    .map_or(null(), CStr::as_ptr),
+                &mut channelgroup,
             ) {
-                ffi::FMOD_OK => Ok(Bank::from(bank)),
-                error => Err(err_fmod!("FMOD_Studio_System_LoadBankFile", error)),
+                ffi::FMOD_OK => Ok(ChannelGroup::from(channelgroup)),
+                error => Err(err_fmod!("FMOD_System_CreateChannelGroup", error)),
             }
         }
     }
-    pub fn load_bank_memory(
-        &self,
-        buffer: &[u8],
-        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
-    ) -> Result<Bank, Error> {
+    pub fn create_sound_group(&self, name: &str) -> Result<SoundGroup, Error> {
         unsafe {
-            let mut bank = null_mut();
-            match ffi::FMOD_Studio_System_LoadBankMemory(
+            let mut soundgroup = null_mut();
+            match ffi::FMOD_System_CreateSoundGroup(
                 self.pointer,
-                buffer.as_ptr() as *const std::os::raw::c_char,
-                buffer.len() as std::os::raw::c_int,
-                LoadMemoryMode::Memory.into(),
-                flags.into(),
-                &mut bank,
+                CString::new(name)?.as_ptr(),
+                &mut soundgroup,
             ) {
-                ffi::FMOD_OK => Ok(Bank::from(bank)),
-                error => Err(err_fmod!("FMOD_Studio_System_LoadBankMemory", error)),
+                ffi::FMOD_OK => Ok(SoundGroup::from(soundgroup)),
+                error => Err(err_fmod!("FMOD_System_CreateSoundGroup", error)),
             }
         }
     }
-    pub fn load_bank_custom(
+    pub fn create_reverb_3d(&self) -> Result<Reverb3d, Error> {
+        unsafe {
+            let mut reverb = null_mut();
+            match ffi::FMOD_System_CreateReverb3D(self.pointer, &mut reverb) {
+                ffi::FMOD_OK => Ok(Reverb3d::from(reverb)),
+                error => Err(err_fmod!("FMOD_System_CreateReverb3D", error)),
+            }
+        }
+    }
+    pub fn play_sound(
         &self,
-        info: BankInfo,
-        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
-    ) -> Result<Bank, Error> {
+        sound: Sound,
+        channelgroup: Option<ChannelGroup>,
+        paused: bool,
+    ) -> Result<Channel, Error> {
         unsafe {
-            let mut bank = null_mut();
-            match ffi::FMOD_Studio_System_LoadBankCustom(
+            let mut channel = null_mut();
+            match ffi::FMOD_System_PlaySound(
                 self.pointer,
-                &info.into(),
-                flags.into(),
-                &mut bank,
+                sound.as_mut_ptr(),
+                channelgroup
+                    .map(|value| value.as_mut_ptr())
+                    .unwrap_or(null_mut()),
+                from_bool!(paused),
+                &mut channel,
             ) {
-                ffi::FMOD_OK => Ok(Bank::from(bank)),
-                error => Err(err_fmod!("FMOD_Studio_System_LoadBankCustom", error)),
+                ffi::FMOD_OK => Ok(Channel::from(channel)),
+                error => Err(err_fmod!("FMOD_System_PlaySound", error)),
             }
         }
     }
-    pub fn register_plugin(&self, description: DspDescription) -> Result<(), Error> {
+    pub fn play_dsp(
+        &self,
+        dsp: Dsp,
+        channelgroup: Option<ChannelGroup>,
+        paused: bool,
+    ) -> Result<Channel, Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_RegisterPlugin(self.pointer, &description.into()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_RegisterPlugin", error)),
+            let mut channel = null_mut();
+            match ffi::FMOD_System_PlayDSP(
+                self.pointer,
+                dsp.as_mut_ptr(),
+                channelgroup
+                    .map(|value| value.as_mut_ptr())
+                    .unwrap_or(null_mut()),
+                from_bool!(paused),
+                &mut channel,
+            ) {
+                ffi::FMOD_OK => Ok(Channel::from(channel)),
+                error => Err(err_fmod!("FMOD_System_PlayDSP", error)),
             }
         }
     }
-    pub fn unregister_plugin(&self, name: &str) -> Result<(), Error> {
+    pub fn get_channel(&self, channelid: i32) -> Result<Channel, Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_UnregisterPlugin(
-                self.pointer,
-                CString::new(name)?.as_ptr(),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_UnregisterPlugin", error)),
+            let mut channel = null_mut();
+            match ffi::FMOD_System_GetChannel(self.pointer, channelid, &mut channel) {
+                ffi::FMOD_OK => Ok(Channel::from(channel)),
+                error => Err(err_fmod!("FMOD_System_GetChannel", error)),
             }
         }
     }
-    pub fn unload_all(&self) -> Result<(), Error> {
+    pub fn get_dsp_info_by_type(&self, type_: DspType) -> Result<DspDescription, Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_UnloadAll(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_UnloadAll", error)),
+            let mut description = null();
+            match ffi::FMOD_System_GetDSPInfoByType(self.pointer, type_.into(), &mut description) {
+                ffi::FMOD_OK => Ok(DspDescription::try_from(*description)?),
+                error => Err(err_fmod!("FMOD_System_GetDSPInfoByType", error)),
             }
         }
     }
-    pub fn flush_commands(&self) -> Result<(), Error> {
+    pub fn get_master_channel_group(&self) -> Result<ChannelGroup, Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_FlushCommands(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_FlushCommands", error)),
+            let mut channelgroup = null_mut();
+            match ffi::FMOD_System_GetMasterChannelGroup(self.pointer, &mut channelgroup) {
+                ffi::FMOD_OK => Ok(ChannelGroup::from(channelgroup)),
+                error => Err(err_fmod!("FMOD_System_GetMasterChannelGroup", error)),
             }
         }
     }
-    pub fn flush_sample_loading(&self) -> Result<(), Error> {
+    pub fn get_master_sound_group(&self) -> Result<SoundGroup, Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_FlushSampleLoading(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_FlushSampleLoading", error)),
+            let mut soundgroup = null_mut();
+            match ffi::FMOD_System_GetMasterSoundGroup(self.pointer, &mut soundgroup) {
+                ffi::FMOD_OK => Ok(SoundGroup::from(soundgroup)),
+                error => Err(err_fmod!("FMOD_System_GetMasterSoundGroup", error)),
             }
         }
     }
-    pub fn start_command_capture(
+    pub fn attach_channel_group_to_port(
         &self,
-        filename: &str,
-        flags: impl Into<ffi::FMOD_STUDIO_COMMANDCAPTURE_FLAGS>,
+        port_type: PortType,
+        port_index: impl Into<ffi::FMOD_PORT_INDEX>,
+        channelgroup: ChannelGroup,
+        pass_thru: bool,
     ) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_StartCommandCapture(
+            match ffi::FMOD_System_AttachChannelGroupToPort(
                 self.pointer,
-                CString::new(filename)?.as_ptr(),
-                flags.into(),
+                port_type.into(),
+                port_index.into(),
+                channelgroup.as_mut_ptr(),
+                from_bool!(pass_thru),
             ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_StartCommandCapture", error)),
+                error => Err(err_fmod!("FMOD_System_AttachChannelGroupToPort", error)),
             }
         }
     }
-    pub fn stop_command_capture(&self) -> Result<(), Error> {
+    pub fn detach_channel_group_from_port(&self, channelgroup: ChannelGroup) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_StopCommandCapture(self.pointer) {
+            match ffi::FMOD_System_DetachChannelGroupFromPort(
+                self.pointer,
+                channelgroup.as_mut_ptr(),
+            ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_StopCommandCapture", error)),
+                error => Err(err_fmod!("FMOD_System_DetachChannelGroupFromPort", error)),
             }
         }
     }
-    pub fn load_command_replay(
+    pub fn set_reverb_properties(
         &self,
-        filename: &str,
-        flags: impl Into<ffi::FMOD_STUDIO_COMMANDREPLAY_FLAGS>,
-    ) -> Result<CommandReplay, Error> {
+        instance: i32,
+        prop: ReverbProperties,
+    ) -> Result<(), Error> {
         unsafe {
-            let mut replay = null_mut();
-            match ffi::FMOD_Studio_System_LoadCommandReplay(
-                self.pointer,
-                CString::new(filename)?.as_ptr(),
-                flags.into(),
-                &mut replay,
-            ) {
-                ffi::FMOD_OK => Ok(CommandReplay::from(replay)),
-                error => Err(err_fmod!("FMOD_Studio_System_LoadCommandReplay", error)),
+            match ffi::FMOD_System_SetReverbProperties(self.pointer, instance, &prop.into()) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_SetReverbProperties", error)),
             }
         }
     }
-    pub fn get_bank_count(&self) -> Result<i32, Error> {
+    pub fn get_reverb_properties(&self, instance: i32) -> Result<ReverbProperties, Error> {
         unsafe {
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_System_GetBankCount(self.pointer, &mut count) {
-                ffi::FMOD_OK => Ok(count),
-                error => Err(err_fmod!("FMOD_Studio_System_GetBankCount", error)),
+            let mut prop = ffi::FMOD_REVERB_PROPERTIES::default();
+            match ffi::FMOD_System_GetReverbProperties(self.pointer, instance, &mut prop) {
+                ffi::FMOD_OK => Ok(ReverbProperties::try_from(prop)?),
+                error => Err(err_fmod!("FMOD_System_GetReverbProperties", error)),
             }
         }
     }
-    pub fn get_bank_list(&self, capacity: i32) -> Result<Vec<Bank>, Error> {
+    pub fn lock_dsp(&self) -> Result<(), Error> {
         unsafe {
-            let mut array = vec![null_mut(); capacity as usize];
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_System_GetBankList(
-                self.pointer,
-                array.as_mut_ptr(),
-                capacity,
-                &mut count,
-            ) {
-                ffi::FMOD_OK => Ok(array
-                    .into_iter()
-                    .take(count as usize)
-                    .map(Bank::from)
-                    .collect()),
-                error => Err(err_fmod!("FMOD_Studio_System_GetBankList", error)),
+            match ffi::FMOD_System_LockDSP(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_LockDSP", error)),
             }
         }
     }
-    pub fn get_parameter_description_count(&self) -> Result<i32, Error> {
+    pub fn unlock_dsp(&self) -> Result<(), Error> {
         unsafe {
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_System_GetParameterDescriptionCount(self.pointer, &mut count) {
-                ffi::FMOD_OK => Ok(count),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_System_GetParameterDescriptionCount",
-                    error
-                )),
+            match ffi::FMOD_System_UnlockDSP(self.pointer) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_UnlockDSP", error)),
             }
         }
     }
-    pub fn get_parameter_description_list(
-        &self,
-        capacity: i32,
-    ) -> Result<Vec<ParameterDescription>, Error> {
+    pub fn get_record_num_drivers(&self) -> Result<(i32, i32), Error> {
         unsafe {
-            let mut array =
-                vec![ffi::FMOD_STUDIO_PARAMETER_DESCRIPTION::default(); capacity as usize];
-            let mut count = i32::default();
-            match ffi::FMOD_Studio_System_GetParameterDescriptionList(
+            let mut numdrivers = i32::default();
+            let mut numconnected = i32::default();
+            match ffi::FMOD_System_GetRecordNumDrivers(
                 self.pointer,
-                array.as_mut_ptr(),
-                capacity,
-                &mut count,
+                &mut numdrivers,
+                &mut numconnected,
             ) {
-                ffi::FMOD_OK => Ok(array
-                    .into_iter()
-                    .take(count as usize)
-                    .map(ParameterDescription::try_from)
-                    .collect::<Result<_, Error>>()?),
-                error => Err(err_fmod!(
-                    "FMOD_Studio_System_GetParameterDescriptionList",
-                    error
-                )),
+                ffi::FMOD_OK => Ok((numdrivers, numconnected)),
+                error => Err(err_fmod!("FMOD_System_GetRecordNumDrivers", error)),
             }
         }
     }
-    pub fn get_cpu_usage(&self) -> Result<(StudioCpuUsage, CpuUsage), Error> {
+    pub fn get_record_driver_info(
+        &self,
+        id: i32,
+        namelen: i32,
+    ) -> Result<(String, Guid, i32, SpeakerMode, i32, ffi::FMOD_DRIVER_STATE), Error> {
         unsafe {
-            let mut usage = ffi::FMOD_STUDIO_CPU_USAGE::default();
-            let mut usage_core = ffi::FMOD_CPU_USAGE::default();
-            match ffi::FMOD_Studio_System_GetCPUUsage(self.pointer, &mut usage, &mut usage_core) {
+            let mut name = vec![0 as c_char; namelen.max(1) as usize];
+            let mut guid = ffi::FMOD_GUID::default();
+            let mut systemrate = i32::default();
+            let mut speakermode = ffi::FMOD_SPEAKERMODE::default();
+            let mut speakermodechannels = i32::default();
+            let mut state = ffi::FMOD_DRIVER_STATE::default();
+            match ffi::FMOD_System_GetRecordDriverInfo(
+                self.pointer,
+                id,
+                name.as_mut_ptr(),
+                name.len() as i32,
+                &mut guid,
+                &mut systemrate,
+                &mut speakermode,
+                &mut speakermodechannels,
+                &mut state,
+            ) {
                 ffi::FMOD_OK => Ok((
-                    StudioCpuUsage::try_from(usage)?,
-                    CpuUsage::try_from(usage_core)?,
+                    CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned(),
+                    Guid::try_from(guid)?,
+                    systemrate,
+                    SpeakerMode::from(speakermode)?,
+                    speakermodechannels,
+                    state,
                 )),
-                error => Err(err_fmod!("FMOD_Studio_System_GetCPUUsage", error)),
+                error => Err(err_fmod!("FMOD_System_GetRecordDriverInfo", error)),
             }
         }
     }
-    pub fn get_buffer_usage(&self) -> Result<BufferUsage, Error> {
+    pub fn get_record_position(&self, id: i32) -> Result<u32, Error> {
         unsafe {
-            let mut usage = ffi::FMOD_STUDIO_BUFFER_USAGE::default();
-            match ffi::FMOD_Studio_System_GetBufferUsage(self.pointer, &mut usage) {
-                ffi::FMOD_OK => Ok(BufferUsage::try_from(usage)?),
-                error => Err(err_fmod!("FMOD_Studio_System_GetBufferUsage", error)),
+            let mut position = u32::default();
+            match ffi::FMOD_System_GetRecordPosition(self.pointer, id, &mut position) {
+                ffi::FMOD_OK => Ok(position),
+                error => Err(err_fmod!("FMOD_System_GetRecordPosition", error)),
             }
         }
     }
-    pub fn reset_buffer_usage(&self) -> Result<(), Error> {
+    pub fn record_start(&self, id: i32, sound: Sound, loop_: bool) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_ResetBufferUsage(self.pointer) {
+            match ffi::FMOD_System_RecordStart(
+                self.pointer,
+                id,
+                sound.as_mut_ptr(),
+                from_bool!(loop_),
+            ) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_ResetBufferUsage", error)),
+                error => Err(err_fmod!("FMOD_System_RecordStart", error)),
             }
         }
     }
-    pub fn set_callback(
-        &self,
-        callback: ffi::FMOD_STUDIO_SYSTEM_CALLBACK,
-        callbackmask: impl Into<ffi::FMOD_STUDIO_SYSTEM_CALLBACK_TYPE>,
-    ) -> Result<(), Error> {
+    pub fn record_stop(&self, id: i32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_SetCallback(self.pointer, callback, callbackmask.into()) {
+            match ffi::FMOD_System_RecordStop(self.pointer, id) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_SetCallback", error)),
+                error => Err(err_fmod!("FMOD_System_RecordStop", error)),
             }
         }
     }
-    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+    pub fn is_recording(&self, id: i32) -> Result<bool, Error> {
         unsafe {
-            match ffi::FMOD_Studio_System_SetUserData(self.pointer, userdata) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_System_SetUserData", error)),
+            let mut recording = ffi::FMOD_BOOL::default();
+            match ffi::FMOD_System_IsRecording(self.pointer, id, &mut recording) {
+                ffi::FMOD_OK => Ok(to_bool!(recording)),
+                error => Err(err_fmod!("FMOD_System_IsRecording", error)),
             }
         }
     }
-    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
+    pub fn create_geometry(&self, maxpolygons: i32, maxvertices: i32) -> Result<Geometry, Error> {
         unsafe {
-            let mut userdata = null_mut();
-            match ffi::FMOD_Studio_System_GetUserData(self.pointer, &mut userdata) {
-                ffi::FMOD_OK => Ok(userdata),
-                error => Err(err_fmod!("FMOD_Studio_System_GetUserData", error)),
+            let mut geometry = null_mut();
+            match ffi::FMOD_System_CreateGeometry(
+                self.pointer,
+                maxpolygons,
+                maxvertices,
+                &mut geometry,
+            ) {
+                ffi::FMOD_OK => Ok(Geometry::from(geometry)),
+                error => Err(err_fmod!("FMOD_System_CreateGeometry", error)),
             }
         }
     }
-    pub fn get_memory_usage(&self) -> Result<MemoryUsage, Error> {
+    pub fn set_geometry_settings(&self, maxworldsize: f32) -> Result<(), Error> {
         unsafe {
-            let mut memoryusage = ffi::FMOD_STUDIO_MEMORY_USAGE::default();
-            match ffi::FMOD_Studio_System_GetMemoryUsage(self.pointer, &mut memoryusage) {
-                ffi::FMOD_OK => Ok(MemoryUsage::try_from(memoryusage)?),
-                error => Err(err_fmod!("FMOD_Studio_System_GetMemoryUsage", error)),
+            match ffi::FMOD_System_SetGeometrySettings(self.pointer, maxworldsize) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_SetGeometrySettings", error)),
             }
         }
     }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct Vca {
-    pointer: *mut ffi::FMOD_STUDIO_VCA,
-}
-unsafe impl Send for Vca {}
-unsafe impl Sync for Vca {}
-impl Vca {
-    #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_STUDIO_VCA) -> Self {
-        Self { pointer }
-    }
-    #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_STUDIO_VCA {
-        self.pointer
-    }
-    pub fn is_valid(&self) -> bool {
-        unsafe { to_bool!(ffi::FMOD_Studio_VCA_IsValid(self.pointer)) }
-    }
-    pub fn get_id(&self) -> Result<Guid, Error> {
+    pub fn get_geometry_settings(&self) -> Result<f32, Error> {
         unsafe {
-            let mut id = ffi::FMOD_GUID::default();
-            match ffi::FMOD_Studio_VCA_GetID(self.pointer, &mut id) {
-                ffi::FMOD_OK => Ok(Guid::try_from(id)?),
-                error => Err(err_fmod!("FMOD_Studio_VCA_GetID", error)),
+            let mut maxworldsize = f32::default();
+            match ffi::FMOD_System_GetGeometrySettings(self.pointer, &mut maxworldsize) {
+                ffi::FMOD_OK => Ok(maxworldsize),
+                error => Err(err_fmod!("FMOD_System_GetGeometrySettings", error)),
             }
         }
     }
-    pub fn get_path(&self) -> Result<String, Error> {
+    pub fn load_geometry_bytes(&self, data: &[u8]) -> Result<Geometry, Error> {
         unsafe {
-            let mut retrieved = i32::default();
-            match ffi::FMOD_Studio_VCA_GetPath(self.pointer, null_mut(), 0, &mut retrieved) {
-                ffi::FMOD_OK => {
-                    let mut buf = vec![0u8; retrieved as usize];
-                    match ffi::FMOD_Studio_VCA_GetPath(
-                        self.pointer,
-                        buf.as_mut_ptr() as *mut _,
-                        retrieved,
-                        &mut retrieved,
-                    ) {
-                        ffi::FMOD_OK => Ok(CString::from_vec_with_nul_unchecked(buf)
-                            .into_string()
-                            .map_err(Error::String)?),
-                        error => Err(err_fmod!("FMOD_Studio_VCA_GetPath", error)),
-                    }
-                }
-                error => Err(err_fmod!("FMOD_Studio_VCA_GetPath", error)),
+            let mut geometry = null_mut();
+            match ffi::FMOD_System_LoadGeometry(
+                self.pointer,
+                data.as_ptr() as *const c_void,
+                data.len() as i32,
+                &mut geometry,
+            ) {
+                ffi::FMOD_OK => Ok(Geometry::from(geometry)),
+                error => Err(err_fmod!("FMOD_System_LoadGeometry", error)),
             }
         }
     }
-    pub fn get_volume(&self) -> Result<(f32, f32), Error> {
+    pub fn get_geometry_occlusion(
+        &self,
+        listener: Vector,
+        source: Vector,
+    ) -> Result<(f32, f32), Error> {
         unsafe {
-            let mut volume = f32::default();
-            let mut finalvolume = f32::default();
-            match ffi::FMOD_Studio_VCA_GetVolume(self.pointer, &mut volume, &mut finalvolume) {
-                ffi::FMOD_OK => Ok((volume, finalvolume)),
-                error => Err(err_fmod!("FMOD_Studio_VCA_GetVolume", error)),
+            let mut direct = f32::default();
+            let mut reverb = f32::default();
+            match ffi::FMOD_System_GetGeometryOcclusion(
+                self.pointer,
+                &listener.into(),
+                &source.into(),
+                &mut direct,
+                &mut reverb,
+            ) {
+                ffi::FMOD_OK => Ok((direct, reverb)),
+                error => Err(err_fmod!("FMOD_System_GetGeometryOcclusion", error)),
             }
         }
     }
-    pub fn set_volume(&self, volume: f32) -> Result<(), Error> {
+    pub fn set_network_proxy(&self, proxy: &str) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_Studio_VCA_SetVolume(self.pointer, volume) {
+            match ffi::FMOD_System_SetNetworkProxy(self.pointer, CString::new(proxy)?.as_ptr()) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_Studio_VCA_SetVolume", error)),
+                error => Err(err_fmod!("FMOD_System_SetNetworkProxy", error)),
             }
         }
     }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct SyncPoint {
-    pointer: *mut ffi::FMOD_SYNCPOINT,
-}
-unsafe impl Send for SyncPoint {}
-unsafe impl Sync for SyncPoint {}
-impl SyncPoint {
-    #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_SYNCPOINT) -> Self {
-        Self { pointer }
-    }
-    #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_SYNCPOINT {
-        self.pointer
-    }
-}
-#[derive(Debug, Clone, Copy)]
-pub struct System {
-    pointer: *mut ffi::FMOD_SYSTEM,
-}
-unsafe impl Send for System {}
-unsafe impl Sync for System {}
-impl System {
-    #[inline]
-    pub fn from(pointer: *mut ffi::FMOD_SYSTEM) -> Self {
-        Self { pointer }
-    }
-    #[inline]
-    pub fn as_mut_ptr(&self) -> *mut ffi::FMOD_SYSTEM {
-        self.pointer
-    }
-    pub fn create() -> Result<System, Error> {
+    pub fn get_network_proxy(&self, proxylen: i32) -> Result<String, Error> {
         unsafe {
-            let mut system = null_mut();
-            match ffi::FMOD_System_Create(&mut system, ffi::FMOD_VERSION) {
-                ffi::FMOD_OK => Ok(System::from(system)),
-                error => Err(err_fmod!("FMOD_System_Create", error)),
+            let mut proxy = vec![0 as c_char; proxylen.max(1) as usize];
+            match ffi::FMOD_System_GetNetworkProxy(
+                self.pointer,
+                proxy.as_mut_ptr(),
+                proxy.len() as i32,
+            ) {
+                ffi::FMOD_OK => Ok(CStr::from_ptr(proxy.as_ptr())
+                    .to_string_lossy()
+                    .into_owned()),
+                error => Err(err_fmod!("FMOD_System_GetNetworkProxy", error)),
             }
         }
     }
-    pub fn release(&self) -> Result<(), Error> {
+    pub fn set_network_timeout(&self, timeout: i32) -> Result<(), Error> {
         unsafe {
-            match ffi::FMOD_System_Release(self.pointer) {
+            match ffi::FMOD_System_SetNetworkTimeout(self.pointer, timeout) {
                 ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_Release", error)),
+                error => Err(err_fmod!("FMOD_System_SetNetworkTimeout", error)),
             }
         }
     }
-    pub fn set_output(&self, output: OutputType) -> Result<(), Error> {
+    pub fn get_network_timeout(&self) -> Result<i32, Error> {
         unsafe {
-            match ffi::FMOD_System_SetOutput(self.pointer, output.into()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetOutput", error)),
+            let mut timeout = i32::default();
+            match ffi::FMOD_System_GetNetworkTimeout(self.pointer, &mut timeout) {
+                ffi::FMOD_OK => Ok(timeout),
+                error => Err(err_fmod!("FMOD_System_GetNetworkTimeout", error)),
             }
         }
     }
-    pub fn get_output(&self) -> Result<OutputType, Error> {
+    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
         unsafe {
-            let mut output = ffi::FMOD_OUTPUTTYPE::default();
-            match ffi::FMOD_System_GetOutput(self.pointer, &mut output) {
-                ffi::FMOD_OK => Ok(OutputType::from(output)?),
-                error => Err(err_fmod!("FMOD_System_GetOutput", error)),
+            match ffi::FMOD_System_SetUserData(self.pointer, userdata) {
+                ffi::FMOD_OK => Ok(()),
+                error => Err(err_fmod!("FMOD_System_SetUserData", error)),
             }
         }
     }
-    pub fn get_num_drivers(&self) -> Result<i32, Error> {
+    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
         unsafe {
-            let mut numdrivers = i32::default();
-            match ffi::FMOD_System_GetNumDrivers(self.pointer, &mut numdrivers) {
-                ffi::FMOD_OK => Ok(numdrivers),
-                error => Err(err_fmod!("FMOD_System_GetNumDrivers", error)),
+            let mut userdata = null_mut();
+            match ffi::FMOD_System_GetUserData(self.pointer, &mut userdata) {
+                ffi::FMOD_OK => Ok(userdata),
+                error => Err(err_fmod!("FMOD_System_GetUserData", error)),
             }
         }
     }
-    pub fn get_driver_info(
+}
+#[doc = " Methods of [`System`] as trait, so code using them can be tested with mock."]
+#[allow(clippy::too_many_arguments)]
+pub trait SystemApi {
+    fn release(&self) -> Result<(), Error>;
+    fn set_output(&self, output: OutputType) -> Result<(), Error>;
+    fn get_output(&self) -> Result<OutputType, Error>;
+    fn get_num_drivers(&self) -> Result<i32, Error>;
+    fn get_driver_info(
         &self,
         id: i32,
         namelen: i32,
-    ) -> Result<(String, Guid, i32, SpeakerMode, i32), Error> {
-        unsafe {
-            let name = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            let mut guid = ffi::FMOD_GUID::default();
-            let mut systemrate = i32::default();
-            let mut speakermode = ffi::FMOD_SPEAKERMODE::default();
-            let mut speakermodechannels = i32::default();
-            match ffi::FMOD_System_GetDriverInfo(
-                self.pointer,
-                id,
-                name,
-                namelen,
-                &mut guid,
-                &mut systemrate,
-                &mut speakermode,
-                &mut speakermodechannels,
-            ) {
-                ffi::FMOD_OK => Ok((
-                    CString::from_raw(name)
-                        .into_string()
-                        .map_err(Error::String)?,
-                    Guid::try_from(guid)?,
-                    systemrate,
-                    SpeakerMode::from(speakermode)?,
-                    speakermodechannels,
-                )),
-                error => Err(err_fmod!("FMOD_System_GetDriverInfo", error)),
-            }
-        }
+    ) -> Result<(String, Guid, i32, SpeakerMode, i32), Error>;
+    fn set_driver(&self, driver: i32) -> Result<(), Error>;
+    fn get_driver(&self) -> Result<i32, Error>;
+    fn set_software_channels(&self, numsoftwarechannels: i32) -> Result<(), Error>;
+    fn get_software_channels(&self) -> Result<i32, Error>;
+    fn set_software_format(
+        &self,
+        samplerate: Option<i32>,
+        speakermode: Option<SpeakerMode>,
+        numrawspeakers: Option<i32>,
+    ) -> Result<(), Error>;
+    fn get_software_format(&self) -> Result<(i32, SpeakerMode, i32), Error>;
+    fn set_dsp_buffer_size(&self, bufferlength: u32, numbuffers: i32) -> Result<(), Error>;
+    fn get_dsp_buffer_size(&self) -> Result<(u32, i32), Error>;
+    fn set_file_system(
+        &self,
+        useropen: ffi::FMOD_FILE_OPEN_CALLBACK,
+        userclose: ffi::FMOD_FILE_CLOSE_CALLBACK,
+        userread: ffi::FMOD_FILE_READ_CALLBACK,
+        userseek: ffi::FMOD_FILE_SEEK_CALLBACK,
+        userasyncread: ffi::FMOD_FILE_ASYNCREAD_CALLBACK,
+        userasynccancel: ffi::FMOD_FILE_ASYNCCANCEL_CALLBACK,
+        blockalign: Option<i32>,
+    ) -> Result<(), Error>;
+    fn attach_file_system(
+        &self,
+        useropen: ffi::FMOD_FILE_OPEN_CALLBACK,
+        userclose: ffi::FMOD_FILE_CLOSE_CALLBACK,
+        userread: ffi::FMOD_FILE_READ_CALLBACK,
+        userseek: ffi::FMOD_FILE_SEEK_CALLBACK,
+    ) -> Result<(), Error>;
+    fn set_advanced_settings(&self, settings: AdvancedSettings) -> Result<(), Error>;
+    fn get_advanced_settings(&self) -> Result<AdvancedSettings, Error>;
+    fn set_callback(
+        &self,
+        callback: ffi::FMOD_SYSTEM_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_SYSTEM_CALLBACK_TYPE>,
+    ) -> Result<(), Error>;
+    fn set_plugin_path(&self, path: &str) -> Result<(), Error>;
+    fn load_plugin(&self, filename: &str, priority: Option<u32>) -> Result<u32, Error>;
+    fn unload_plugin(&self, handle: u32) -> Result<(), Error>;
+    fn get_num_nested_plugins(&self, handle: u32) -> Result<i32, Error>;
+    fn get_nested_plugin(&self, handle: u32, index: i32) -> Result<u32, Error>;
+    fn get_num_plugins(&self, plugintype: PluginType) -> Result<i32, Error>;
+    fn get_plugin_handle(&self, plugintype: PluginType, index: i32) -> Result<u32, Error>;
+    fn get_plugin_info(
+        &self,
+        handle: u32,
+        namelen: i32,
+    ) -> Result<(PluginType, String, u32), Error>;
+    fn set_output_by_plugin(&self, handle: u32) -> Result<(), Error>;
+    fn get_output_by_plugin(&self) -> Result<u32, Error>;
+    fn create_dsp_by_plugin(&self, handle: u32) -> Result<Dsp, Error>;
+    fn get_dsp_info_by_plugin(&self, handle: u32) -> Result<DspDescription, Error>;
+    fn register_codec(
+        &self,
+        description: CodecDescription,
+        priority: Option<u32>,
+    ) -> Result<u32, Error>;
+    fn register_dsp(&self, description: DspDescription) -> Result<u32, Error>;
+    fn register_output(&self, description: OutputDescription) -> Result<u32, Error>;
+    fn init(
+        &self,
+        maxchannels: i32,
+        flags: impl Into<ffi::FMOD_INITFLAGS>,
+        extradriverdata: Option<*mut c_void>,
+    ) -> Result<(), Error>;
+    fn close(&self) -> Result<(), Error>;
+    fn update(&self) -> Result<(), Error>;
+    fn set_speaker_position(
+        &self,
+        speaker: Speaker,
+        x: f32,
+        y: f32,
+        active: bool,
+    ) -> Result<(), Error>;
+    fn get_speaker_position(&self, speaker: Speaker) -> Result<(f32, f32, bool), Error>;
+    fn set_stream_buffer_size(
+        &self,
+        filebuffersize: u32,
+        filebuffersizetype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(), Error>;
+    fn get_stream_buffer_size(&self) -> Result<(u32, ffi::FMOD_TIMEUNIT), Error>;
+    fn set_3d_settings(
+        &self,
+        dopplerscale: f32,
+        distancefactor: f32,
+        rolloffscale: f32,
+    ) -> Result<(), Error>;
+    fn get_3d_settings(&self) -> Result<(f32, f32, f32), Error>;
+    fn set_3d_num_listeners(&self, numlisteners: i32) -> Result<(), Error>;
+    fn get_3d_num_listeners(&self) -> Result<i32, Error>;
+    fn set_3d_listener_attributes(
+        &self,
+        listener: i32,
+        pos: Option<Vector>,
+        vel: Option<Vector>,
+        forward: Option<Vector>,
+        up: Option<Vector>,
+    ) -> Result<(), Error>;
+    fn get_3d_listener_attributes(
+        &self,
+        listener: i32,
+    ) -> Result<(Vector, Vector, Vector, Vector), Error>;
+    fn set_3d_rolloff_callback(&self, callback: ffi::FMOD_3D_ROLLOFF_CALLBACK)
+        -> Result<(), Error>;
+    fn mixer_suspend(&self) -> Result<(), Error>;
+    fn mixer_resume(&self) -> Result<(), Error>;
+    fn get_default_mix_matrix(
+        &self,
+        sourcespeakermode: SpeakerMode,
+        targetspeakermode: SpeakerMode,
+    ) -> Result<MixMatrix, Error>;
+    fn get_speaker_mode_channels(&self, mode: SpeakerMode) -> Result<i32, Error>;
+    fn get_version(&self) -> Result<u32, Error>;
+    fn get_output_handle(&self) -> Result<*mut c_void, Error>;
+    fn get_channels_playing(&self) -> Result<(i32, i32), Error>;
+    fn get_cpu_usage(&self) -> Result<CpuUsage, Error>;
+    fn get_file_usage(&self) -> Result<(i64, i64, i64), Error>;
+    fn create_sound(
+        &self,
+        name_or_data: &str,
+        mode: impl Into<ffi::FMOD_MODE>,
+        exinfo: Option<CreateSoundexInfo>,
+    ) -> Result<Sound, Error>;
+    fn create_sound_from(
+        &self,
+        data: &[u8],
+        mode: impl Into<ffi::FMOD_MODE>,
+        exinfo: CreateSoundexInfo,
+    ) -> Result<Sound, Error>;
+    fn create_stream(
+        &self,
+        name_or_data: &str,
+        mode: impl Into<ffi::FMOD_MODE>,
+        exinfo: Option<CreateSoundexInfo>,
+    ) -> Result<Sound, Error>;
+    fn create_stream_from(
+        &self,
+        data: &[u8],
+        mode: impl Into<ffi::FMOD_MODE>,
+        exinfo: CreateSoundexInfo,
+    ) -> Result<Sound, Error>;
+    fn create_dsp(&self, description: DspDescription) -> Result<Dsp, Error>;
+    fn create_dsp_by_type(&self, type_: DspType) -> Result<Dsp, Error>;
+    fn create_channel_group(&self, name: Option<String>) -> Result<ChannelGroup, Error>;
+    fn create_sound_group(&self, name: &str) -> Result<SoundGroup, Error>;
+    fn create_reverb_3d(&self) -> Result<Reverb3d, Error>;
+    fn play_sound(
+        &self,
+        sound: Sound,
+        channelgroup: Option<ChannelGroup>,
+        paused: bool,
+    ) -> Result<Channel, Error>;
+    fn play_dsp(
+        &self,
+        dsp: Dsp,
+        channelgroup: Option<ChannelGroup>,
+        paused: bool,
+    ) -> Result<Channel, Error>;
+    fn get_channel(&self, channelid: i32) -> Result<Channel, Error>;
+    fn get_dsp_info_by_type(&self, type_: DspType) -> Result<DspDescription, Error>;
+    fn get_master_channel_group(&self) -> Result<ChannelGroup, Error>;
+    fn get_master_sound_group(&self) -> Result<SoundGroup, Error>;
+    fn attach_channel_group_to_port(
+        &self,
+        port_type: PortType,
+        port_index: impl Into<ffi::FMOD_PORT_INDEX>,
+        channelgroup: ChannelGroup,
+        pass_thru: bool,
+    ) -> Result<(), Error>;
+    fn detach_channel_group_from_port(&self, channelgroup: ChannelGroup) -> Result<(), Error>;
+    fn set_reverb_properties(&self, instance: i32, prop: ReverbProperties) -> Result<(), Error>;
+    fn get_reverb_properties(&self, instance: i32) -> Result<ReverbProperties, Error>;
+    fn lock_dsp(&self) -> Result<(), Error>;
+    fn unlock_dsp(&self) -> Result<(), Error>;
+    fn get_record_num_drivers(&self) -> Result<(i32, i32), Error>;
+    fn get_record_driver_info(
+        &self,
+        id: i32,
+        namelen: i32,
+    ) -> Result<(String, Guid, i32, SpeakerMode, i32, ffi::FMOD_DRIVER_STATE), Error>;
+    fn get_record_position(&self, id: i32) -> Result<u32, Error>;
+    fn record_start(&self, id: i32, sound: Sound, loop_: bool) -> Result<(), Error>;
+    fn record_stop(&self, id: i32) -> Result<(), Error>;
+    fn is_recording(&self, id: i32) -> Result<bool, Error>;
+    fn create_geometry(&self, maxpolygons: i32, maxvertices: i32) -> Result<Geometry, Error>;
+    fn set_geometry_settings(&self, maxworldsize: f32) -> Result<(), Error>;
+    fn get_geometry_settings(&self) -> Result<f32, Error>;
+    fn load_geometry_bytes(&self, data: &[u8]) -> Result<Geometry, Error>;
+    fn get_geometry_occlusion(&self, listener: Vector, source: Vector)
+        -> Result<(f32, f32), Error>;
+    fn set_network_proxy(&self, proxy: &str) -> Result<(), Error>;
+    fn get_network_proxy(&self, proxylen: i32) -> Result<String, Error>;
+    fn set_network_timeout(&self, timeout: i32) -> Result<(), Error>;
+    fn get_network_timeout(&self) -> Result<i32, Error>;
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error>;
+    fn get_user_data(&self) -> Result<*mut c_void, Error>;
+}
+impl SystemApi for System {
+    #[inline]
+    fn release(&self) -> Result<(), Error> {
+        System::release(self)
     }
-    pub fn set_driver(&self, driver: i32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetDriver(self.pointer, driver) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetDriver", error)),
-            }
-        }
+    #[inline]
+    fn set_output(&self, output: OutputType) -> Result<(), Error> {
+        System::set_output(self, output)
+    }
+    #[inline]
+    fn get_output(&self) -> Result<OutputType, Error> {
+        System::get_output(self)
+    }
+    #[inline]
+    fn get_num_drivers(&self) -> Result<i32, Error> {
+        System::get_num_drivers(self)
+    }
+    #[inline]
+    fn get_driver_info(
+        &self,
+        id: i32,
+        namelen: i32,
+    ) -> Result<(String, Guid, i32, SpeakerMode, i32), Error> {
+        System::get_driver_info(self, id, namelen)
     }
-    pub fn get_driver(&self) -> Result<i32, Error> {
-        unsafe {
-            let mut driver = i32::default();
-            match ffi::FMOD_System_GetDriver(self.pointer, &mut driver) {
-                ffi::FMOD_OK => Ok(driver),
-                error => Err(err_fmod!("FMOD_System_GetDriver", error)),
-            }
-        }
+    #[inline]
+    fn set_driver(&self, driver: i32) -> Result<(), Error> {
+        System::set_driver(self, driver)
     }
-    pub fn set_software_channels(&self, numsoftwarechannels: i32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetSoftwareChannels(self.pointer, numsoftwarechannels) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetSoftwareChannels", error)),
-            }
-        }
+    #[inline]
+    fn get_driver(&self) -> Result<i32, Error> {
+        System::get_driver(self)
     }
-    pub fn get_software_channels(&self) -> Result<i32, Error> {
-        unsafe {
-            let mut numsoftwarechannels = i32::default();
-            match ffi::FMOD_System_GetSoftwareChannels(self.pointer, &mut numsoftwarechannels) {
-                ffi::FMOD_OK => Ok(numsoftwarechannels),
-                error => Err(err_fmod!("FMOD_System_GetSoftwareChannels", error)),
-            }
-        }
+    #[inline]
+    fn set_software_channels(&self, numsoftwarechannels: i32) -> Result<(), Error> {
+        System::set_software_channels(self, numsoftwarechannels)
     }
-    pub fn set_software_format(
+    #[inline]
+    fn get_software_channels(&self) -> Result<i32, Error> {
+        System::get_software_channels(self)
+    }
+    #[inline]
+    fn set_software_format(
         &self,
         samplerate: Option<i32>,
         speakermode: Option<SpeakerMode>,
         numrawspeakers: Option<i32>,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetSoftwareFormat(
-                self.pointer,
-                samplerate.unwrap_or(0),
-                speakermode.map(|value| value.into()).unwrap_or(0),
-                numrawspeakers.unwrap_or(0),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetSoftwareFormat", error)),
-            }
-        }
+        System::set_software_format(self, samplerate, speakermode, numrawspeakers)
     }
-    pub fn get_software_format(&self) -> Result<(i32, SpeakerMode, i32), Error> {
-        unsafe {
-            let mut samplerate = i32::default();
-            let mut speakermode = ffi::FMOD_SPEAKERMODE::default();
-            let mut numrawspeakers = i32::default();
-            match ffi::FMOD_System_GetSoftwareFormat(
-                self.pointer,
-                &mut samplerate,
-                &mut speakermode,
-                &mut numrawspeakers,
-            ) {
-                ffi::FMOD_OK => Ok((samplerate, SpeakerMode::from(speakermode)?, numrawspeakers)),
-                error => Err(err_fmod!("FMOD_System_GetSoftwareFormat", error)),
-            }
-        }
+    #[inline]
+    fn get_software_format(&self) -> Result<(i32, SpeakerMode, i32), Error> {
+        System::get_software_format(self)
     }
-    pub fn set_dsp_buffer_size(&self, bufferlength: u32, numbuffers: i32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetDSPBufferSize(self.pointer, bufferlength, numbuffers) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetDSPBufferSize", error)),
-            }
-        }
+    #[inline]
+    fn set_dsp_buffer_size(&self, bufferlength: u32, numbuffers: i32) -> Result<(), Error> {
+        System::set_dsp_buffer_size(self, bufferlength, numbuffers)
     }
-    pub fn get_dsp_buffer_size(&self) -> Result<(u32, i32), Error> {
-        unsafe {
-            let mut bufferlength = u32::default();
-            let mut numbuffers = i32::default();
-            match ffi::FMOD_System_GetDSPBufferSize(
-                self.pointer,
-                &mut bufferlength,
-                &mut numbuffers,
-            ) {
-                ffi::FMOD_OK => Ok((bufferlength, numbuffers)),
-                error => Err(err_fmod!("FMOD_System_GetDSPBufferSize", error)),
-            }
-        }
+    #[inline]
+    fn get_dsp_buffer_size(&self) -> Result<(u32, i32), Error> {
+        System::get_dsp_buffer_size(self)
     }
-    pub fn set_file_system(
+    #[inline]
+    fn set_file_system(
         &self,
         useropen: ffi::FMOD_FILE_OPEN_CALLBACK,
         userclose: ffi::FMOD_FILE_CLOSE_CALLBACK,
@@ -11871,394 +16816,177 @@ impl System {
         userasynccancel: ffi::FMOD_FILE_ASYNCCANCEL_CALLBACK,
         blockalign: Option<i32>,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetFileSystem(
-                self.pointer,
-                useropen,
-                userclose,
-                userread,
-                userseek,
-                userasyncread,
-                userasynccancel,
-                blockalign.unwrap_or(0),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetFileSystem", error)),
-            }
-        }
+        System::set_file_system(
+            self,
+            useropen,
+            userclose,
+            userread,
+            userseek,
+            userasyncread,
+            userasynccancel,
+            blockalign,
+        )
     }
-    pub fn attach_file_system(
+    #[inline]
+    fn attach_file_system(
         &self,
         useropen: ffi::FMOD_FILE_OPEN_CALLBACK,
         userclose: ffi::FMOD_FILE_CLOSE_CALLBACK,
         userread: ffi::FMOD_FILE_READ_CALLBACK,
         userseek: ffi::FMOD_FILE_SEEK_CALLBACK,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_AttachFileSystem(
-                self.pointer,
-                useropen,
-                userclose,
-                userread,
-                userseek,
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_AttachFileSystem", error)),
-            }
-        }
+        System::attach_file_system(self, useropen, userclose, userread, userseek)
     }
-    pub fn set_advanced_settings(&self, settings: AdvancedSettings) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetAdvancedSettings(self.pointer, &mut settings.into()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetAdvancedSettings", error)),
-            }
-        }
+    #[inline]
+    fn set_advanced_settings(&self, settings: AdvancedSettings) -> Result<(), Error> {
+        System::set_advanced_settings(self, settings)
     }
-    pub fn get_advanced_settings(&self) -> Result<AdvancedSettings, Error> {
-        unsafe {
-            let mut settings = ffi::FMOD_ADVANCEDSETTINGS::default();
-            match ffi::FMOD_System_GetAdvancedSettings(self.pointer, &mut settings) {
-                ffi::FMOD_OK => Ok(AdvancedSettings::try_from(settings)?),
-                error => Err(err_fmod!("FMOD_System_GetAdvancedSettings", error)),
-            }
-        }
+    #[inline]
+    fn get_advanced_settings(&self) -> Result<AdvancedSettings, Error> {
+        System::get_advanced_settings(self)
     }
-    pub fn set_callback(
+    #[inline]
+    fn set_callback(
         &self,
         callback: ffi::FMOD_SYSTEM_CALLBACK,
         callbackmask: impl Into<ffi::FMOD_SYSTEM_CALLBACK_TYPE>,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetCallback(self.pointer, callback, callbackmask.into()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetCallback", error)),
-            }
-        }
+        System::set_callback(self, callback, callbackmask)
     }
-    pub fn set_plugin_path(&self, path: &str) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetPluginPath(self.pointer, CString::new(path)?.as_ptr()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetPluginPath", error)),
-            }
-        }
+    #[inline]
+    fn set_plugin_path(&self, path: &str) -> Result<(), Error> {
+        System::set_plugin_path(self, path)
     }
-    pub fn load_plugin(&self, filename: &str, priority: Option<u32>) -> Result<u32, Error> {
-        unsafe {
-            let mut handle = u32::default();
-            match ffi::FMOD_System_LoadPlugin(
-                self.pointer,
-                CString::new(filename)?.as_ptr(),
-                &mut handle,
-                priority.unwrap_or(0),
-            ) {
-                ffi::FMOD_OK => Ok(handle),
-                error => Err(err_fmod!("FMOD_System_LoadPlugin", error)),
-            }
-        }
+    #[inline]
+    fn load_plugin(&self, filename: &str, priority: Option<u32>) -> Result<u32, Error> {
+        System::load_plugin(self, filename, priority)
     }
-    pub fn unload_plugin(&self, handle: u32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_UnloadPlugin(self.pointer, handle) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_UnloadPlugin", error)),
-            }
-        }
+    #[inline]
+    fn unload_plugin(&self, handle: u32) -> Result<(), Error> {
+        System::unload_plugin(self, handle)
     }
-    pub fn get_num_nested_plugins(&self, handle: u32) -> Result<i32, Error> {
-        unsafe {
-            let mut count = i32::default();
-            match ffi::FMOD_System_GetNumNestedPlugins(self.pointer, handle, &mut count) {
-                ffi::FMOD_OK => Ok(count),
-                error => Err(err_fmod!("FMOD_System_GetNumNestedPlugins", error)),
-            }
-        }
+    #[inline]
+    fn get_num_nested_plugins(&self, handle: u32) -> Result<i32, Error> {
+        System::get_num_nested_plugins(self, handle)
     }
-    pub fn get_nested_plugin(&self, handle: u32, index: i32) -> Result<u32, Error> {
-        unsafe {
-            let mut nestedhandle = u32::default();
-            match ffi::FMOD_System_GetNestedPlugin(self.pointer, handle, index, &mut nestedhandle) {
-                ffi::FMOD_OK => Ok(nestedhandle),
-                error => Err(err_fmod!("FMOD_System_GetNestedPlugin", error)),
-            }
-        }
+    #[inline]
+    fn get_nested_plugin(&self, handle: u32, index: i32) -> Result<u32, Error> {
+        System::get_nested_plugin(self, handle, index)
     }
-    pub fn get_num_plugins(&self, plugintype: PluginType) -> Result<i32, Error> {
-        unsafe {
-            let mut numplugins = i32::default();
-            match ffi::FMOD_System_GetNumPlugins(self.pointer, plugintype.into(), &mut numplugins) {
-                ffi::FMOD_OK => Ok(numplugins),
-                error => Err(err_fmod!("FMOD_System_GetNumPlugins", error)),
-            }
-        }
+    #[inline]
+    fn get_num_plugins(&self, plugintype: PluginType) -> Result<i32, Error> {
+        System::get_num_plugins(self, plugintype)
     }
-    pub fn get_plugin_handle(&self, plugintype: PluginType, index: i32) -> Result<u32, Error> {
-        unsafe {
-            let mut handle = u32::default();
-            match ffi::FMOD_System_GetPluginHandle(
-                self.pointer,
-                plugintype.into(),
-                index,
-                &mut handle,
-            ) {
-                ffi::FMOD_OK => Ok(handle),
-                error => Err(err_fmod!("FMOD_System_GetPluginHandle", error)),
-            }
-        }
+    #[inline]
+    fn get_plugin_handle(&self, plugintype: PluginType, index: i32) -> Result<u32, Error> {
+        System::get_plugin_handle(self, plugintype, index)
     }
-    pub fn get_plugin_info(
+    #[inline]
+    fn get_plugin_info(
         &self,
         handle: u32,
-        namelen: i32,
-    ) -> Result<(PluginType, String, u32), Error> {
-        unsafe {
-            let mut plugintype = ffi::FMOD_PLUGINTYPE::default();
-            let name = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            let mut version = u32::default();
-            match ffi::FMOD_System_GetPluginInfo(
-                self.pointer,
-                handle,
-                &mut plugintype,
-                name,
-                namelen,
-                &mut version,
-            ) {
-                ffi::FMOD_OK => Ok((
-                    PluginType::from(plugintype)?,
-                    CString::from_raw(name)
-                        .into_string()
-                        .map_err(Error::String)?,
-                    version,
-                )),
-                error => Err(err_fmod!("FMOD_System_GetPluginInfo", error)),
-            }
-        }
-    }
-    pub fn set_output_by_plugin(&self, handle: u32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetOutputByPlugin(self.pointer, handle) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetOutputByPlugin", error)),
-            }
-        }
-    }
-    pub fn get_output_by_plugin(&self) -> Result<u32, Error> {
-        unsafe {
-            let mut handle = u32::default();
-            match ffi::FMOD_System_GetOutputByPlugin(self.pointer, &mut handle) {
-                ffi::FMOD_OK => Ok(handle),
-                error => Err(err_fmod!("FMOD_System_GetOutputByPlugin", error)),
-            }
-        }
-    }
-    pub fn create_dsp_by_plugin(&self, handle: u32) -> Result<Dsp, Error> {
-        unsafe {
-            let mut dsp = null_mut();
-            match ffi::FMOD_System_CreateDSPByPlugin(self.pointer, handle, &mut dsp) {
-                ffi::FMOD_OK => Ok(Dsp::from(dsp)),
-                error => Err(err_fmod!("FMOD_System_CreateDSPByPlugin", error)),
-            }
-        }
+        namelen: i32,
+    ) -> Result<(PluginType, String, u32), Error> {
+        System::get_plugin_info(self, handle, namelen)
     }
-    pub fn get_dsp_info_by_plugin(&self, handle: u32) -> Result<DspDescription, Error> {
-        unsafe {
-            let mut description = null();
-            match ffi::FMOD_System_GetDSPInfoByPlugin(self.pointer, handle, &mut description) {
-                ffi::FMOD_OK => Ok(DspDescription::try_from(*description)?),
-                error => Err(err_fmod!("FMOD_System_GetDSPInfoByPlugin", error)),
-            }
-        }
+    #[inline]
+    fn set_output_by_plugin(&self, handle: u32) -> Result<(), Error> {
+        System::set_output_by_plugin(self, handle)
     }
-    pub fn register_codec(
+    #[inline]
+    fn get_output_by_plugin(&self) -> Result<u32, Error> {
+        System::get_output_by_plugin(self)
+    }
+    #[inline]
+    fn create_dsp_by_plugin(&self, handle: u32) -> Result<Dsp, Error> {
+        System::create_dsp_by_plugin(self, handle)
+    }
+    #[inline]
+    fn get_dsp_info_by_plugin(&self, handle: u32) -> Result<DspDescription, Error> {
+        System::get_dsp_info_by_plugin(self, handle)
+    }
+    #[inline]
+    fn register_codec(
         &self,
         description: CodecDescription,
         priority: Option<u32>,
     ) -> Result<u32, Error> {
-        unsafe {
-            let mut handle = u32::default();
-            match ffi::FMOD_System_RegisterCodec(
-                self.pointer,
-                &mut description.into(),
-                &mut handle,
-                priority.unwrap_or(0),
-            ) {
-                ffi::FMOD_OK => Ok(handle),
-                error => Err(err_fmod!("FMOD_System_RegisterCodec", error)),
-            }
-        }
+        System::register_codec(self, description, priority)
     }
-    pub fn register_dsp(&self, description: DspDescription) -> Result<u32, Error> {
-        unsafe {
-            let mut handle = u32::default();
-            match ffi::FMOD_System_RegisterDSP(self.pointer, &description.into(), &mut handle) {
-                ffi::FMOD_OK => Ok(handle),
-                error => Err(err_fmod!("FMOD_System_RegisterDSP", error)),
-            }
-        }
+    #[inline]
+    fn register_dsp(&self, description: DspDescription) -> Result<u32, Error> {
+        System::register_dsp(self, description)
     }
-    pub fn register_output(&self, description: OutputDescription) -> Result<u32, Error> {
-        unsafe {
-            let mut handle = u32::default();
-            match ffi::FMOD_System_RegisterOutput(self.pointer, &description.into(), &mut handle) {
-                ffi::FMOD_OK => Ok(handle),
-                error => Err(err_fmod!("FMOD_System_RegisterOutput", error)),
-            }
-        }
+    #[inline]
+    fn register_output(&self, description: OutputDescription) -> Result<u32, Error> {
+        System::register_output(self, description)
     }
-    pub fn init(
+    #[inline]
+    fn init(
         &self,
         maxchannels: i32,
         flags: impl Into<ffi::FMOD_INITFLAGS>,
         extradriverdata: Option<*mut c_void>,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_Init(
-                self.pointer,
-                maxchannels,
-                flags.into(),
-                extradriverdata.unwrap_or(null_mut()),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_Init", error)),
-            }
-        }
+        System::init(self, maxchannels, flags, extradriverdata)
     }
-    pub fn close(&self) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_Close(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_Close", error)),
-            }
-        }
+    #[inline]
+    fn close(&self) -> Result<(), Error> {
+        System::close(self)
     }
-    pub fn update(&self) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_Update(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_Update", error)),
-            }
-        }
+    #[inline]
+    fn update(&self) -> Result<(), Error> {
+        System::update(self)
     }
-    pub fn set_speaker_position(
+    #[inline]
+    fn set_speaker_position(
         &self,
         speaker: Speaker,
         x: f32,
         y: f32,
         active: bool,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetSpeakerPosition(
-                self.pointer,
-                speaker.into(),
-                x,
-                y,
-                from_bool!(active),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetSpeakerPosition", error)),
-            }
-        }
+        System::set_speaker_position(self, speaker, x, y, active)
     }
-    pub fn get_speaker_position(&self, speaker: Speaker) -> Result<(f32, f32, bool), Error> {
-        unsafe {
-            let mut x = f32::default();
-            let mut y = f32::default();
-            let mut active = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_System_GetSpeakerPosition(
-                self.pointer,
-                speaker.into(),
-                &mut x,
-                &mut y,
-                &mut active,
-            ) {
-                ffi::FMOD_OK => Ok((x, y, to_bool!(active))),
-                error => Err(err_fmod!("FMOD_System_GetSpeakerPosition", error)),
-            }
-        }
+    #[inline]
+    fn get_speaker_position(&self, speaker: Speaker) -> Result<(f32, f32, bool), Error> {
+        System::get_speaker_position(self, speaker)
     }
-    pub fn set_stream_buffer_size(
+    #[inline]
+    fn set_stream_buffer_size(
         &self,
         filebuffersize: u32,
         filebuffersizetype: impl Into<ffi::FMOD_TIMEUNIT>,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetStreamBufferSize(
-                self.pointer,
-                filebuffersize,
-                filebuffersizetype.into(),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetStreamBufferSize", error)),
-            }
-        }
+        System::set_stream_buffer_size(self, filebuffersize, filebuffersizetype)
     }
-    pub fn get_stream_buffer_size(&self) -> Result<(u32, ffi::FMOD_TIMEUNIT), Error> {
-        unsafe {
-            let mut filebuffersize = u32::default();
-            let mut filebuffersizetype = ffi::FMOD_TIMEUNIT::default();
-            match ffi::FMOD_System_GetStreamBufferSize(
-                self.pointer,
-                &mut filebuffersize,
-                &mut filebuffersizetype,
-            ) {
-                ffi::FMOD_OK => Ok((filebuffersize, filebuffersizetype)),
-                error => Err(err_fmod!("FMOD_System_GetStreamBufferSize", error)),
-            }
-        }
+    #[inline]
+    fn get_stream_buffer_size(&self) -> Result<(u32, ffi::FMOD_TIMEUNIT), Error> {
+        System::get_stream_buffer_size(self)
     }
-    pub fn set_3d_settings(
+    #[inline]
+    fn set_3d_settings(
         &self,
         dopplerscale: f32,
         distancefactor: f32,
         rolloffscale: f32,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_Set3DSettings(
-                self.pointer,
-                dopplerscale,
-                distancefactor,
-                rolloffscale,
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_Set3DSettings", error)),
-            }
-        }
+        System::set_3d_settings(self, dopplerscale, distancefactor, rolloffscale)
     }
-    pub fn get_3d_settings(&self) -> Result<(f32, f32, f32), Error> {
-        unsafe {
-            let mut dopplerscale = f32::default();
-            let mut distancefactor = f32::default();
-            let mut rolloffscale = f32::default();
-            match ffi::FMOD_System_Get3DSettings(
-                self.pointer,
-                &mut dopplerscale,
-                &mut distancefactor,
-                &mut rolloffscale,
-            ) {
-                ffi::FMOD_OK => Ok((dopplerscale, distancefactor, rolloffscale)),
-                error => Err(err_fmod!("FMOD_System_Get3DSettings", error)),
-            }
-        }
+    #[inline]
+    fn get_3d_settings(&self) -> Result<(f32, f32, f32), Error> {
+        System::get_3d_settings(self)
     }
-    pub fn set_3d_num_listeners(&self, numlisteners: i32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_Set3DNumListeners(self.pointer, numlisteners) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_Set3DNumListeners", error)),
-            }
-        }
+    #[inline]
+    fn set_3d_num_listeners(&self, numlisteners: i32) -> Result<(), Error> {
+        System::set_3d_num_listeners(self, numlisteners)
     }
-    pub fn get_3d_num_listeners(&self) -> Result<i32, Error> {
-        unsafe {
-            let mut numlisteners = i32::default();
-            match ffi::FMOD_System_Get3DNumListeners(self.pointer, &mut numlisteners) {
-                ffi::FMOD_OK => Ok(numlisteners),
-                error => Err(err_fmod!("FMOD_System_Get3DNumListeners", error)),
-            }
-        }
+    #[inline]
+    fn get_3d_num_listeners(&self) -> Result<i32, Error> {
+        System::get_3d_num_listeners(self)
     }
-    pub fn set_3d_listener_attributes(
+    #[inline]
+    fn set_3d_listener_attributes(
         &self,
         listener: i32,
         pos: Option<Vector>,
@@ -12266,661 +16994,256 @@ impl System {
         forward: Option<Vector>,
         up: Option<Vector>,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_Set3DListenerAttributes(
-                self.pointer,
-                listener,
-                pos.map(Vector::into)
-                    .as_ref()
-                    .map(from_ref)
-                    .unwrap_or_else(null),
-                vel.map(Vector::into)
-                    .as_ref()
-                    .map(from_ref)
-                    .unwrap_or_else(null),
-                forward
-                    .map(Vector::into)
-                    .as_ref()
-                    .map(from_ref)
-                    .unwrap_or_else(null),
-                up.map(Vector::into)
-                    .as_ref()
-                    .map(from_ref)
-                    .unwrap_or_else(null),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_Set3DListenerAttributes", error)),
-            }
-        }
+        System::set_3d_listener_attributes(self, listener, pos, vel, forward, up)
     }
-    pub fn get_3d_listener_attributes(
+    #[inline]
+    fn get_3d_listener_attributes(
         &self,
         listener: i32,
     ) -> Result<(Vector, Vector, Vector, Vector), Error> {
-        unsafe {
-            let mut pos = ffi::FMOD_VECTOR::default();
-            let mut vel = ffi::FMOD_VECTOR::default();
-            let mut forward = ffi::FMOD_VECTOR::default();
-            let mut up = ffi::FMOD_VECTOR::default();
-            match ffi::FMOD_System_Get3DListenerAttributes(
-                self.pointer,
-                listener,
-                &mut pos,
-                &mut vel,
-                &mut forward,
-                &mut up,
-            ) {
-                ffi::FMOD_OK => Ok((
-                    Vector::try_from(pos)?,
-                    Vector::try_from(vel)?,
-                    Vector::try_from(forward)?,
-                    Vector::try_from(up)?,
-                )),
-                error => Err(err_fmod!("FMOD_System_Get3DListenerAttributes", error)),
-            }
-        }
+        System::get_3d_listener_attributes(self, listener)
     }
-    pub fn set_3d_rolloff_callback(
+    #[inline]
+    fn set_3d_rolloff_callback(
         &self,
         callback: ffi::FMOD_3D_ROLLOFF_CALLBACK,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_Set3DRolloffCallback(self.pointer, callback) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_Set3DRolloffCallback", error)),
-            }
-        }
+        System::set_3d_rolloff_callback(self, callback)
     }
-    pub fn mixer_suspend(&self) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_MixerSuspend(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_MixerSuspend", error)),
-            }
-        }
+    #[inline]
+    fn mixer_suspend(&self) -> Result<(), Error> {
+        System::mixer_suspend(self)
     }
-    pub fn mixer_resume(&self) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_MixerResume(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_MixerResume", error)),
-            }
-        }
+    #[inline]
+    fn mixer_resume(&self) -> Result<(), Error> {
+        System::mixer_resume(self)
     }
-    pub fn get_default_mix_matrix(
+    #[inline]
+    fn get_default_mix_matrix(
         &self,
         sourcespeakermode: SpeakerMode,
         targetspeakermode: SpeakerMode,
-        matrixhop: Option<i32>,
-    ) -> Result<f32, Error> {
-        unsafe {
-            let mut matrix = f32::default();
-            match ffi::FMOD_System_GetDefaultMixMatrix(
-                self.pointer,
-                sourcespeakermode.into(),
-                targetspeakermode.into(),
-                &mut matrix,
-                matrixhop.unwrap_or(0),
-            ) {
-                ffi::FMOD_OK => Ok(matrix),
-                error => Err(err_fmod!("FMOD_System_GetDefaultMixMatrix", error)),
-            }
-        }
+    ) -> Result<MixMatrix, Error> {
+        System::get_default_mix_matrix(self, sourcespeakermode, targetspeakermode)
     }
-    pub fn get_speaker_mode_channels(&self, mode: SpeakerMode) -> Result<i32, Error> {
-        unsafe {
-            let mut channels = i32::default();
-            match ffi::FMOD_System_GetSpeakerModeChannels(self.pointer, mode.into(), &mut channels)
-            {
-                ffi::FMOD_OK => Ok(channels),
-                error => Err(err_fmod!("FMOD_System_GetSpeakerModeChannels", error)),
-            }
-        }
+    #[inline]
+    fn get_speaker_mode_channels(&self, mode: SpeakerMode) -> Result<i32, Error> {
+        System::get_speaker_mode_channels(self, mode)
     }
-    pub fn get_version(&self) -> Result<u32, Error> {
-        unsafe {
-            let mut version = u32::default();
-            match ffi::FMOD_System_GetVersion(self.pointer, &mut version) {
-                ffi::FMOD_OK => Ok(version),
-                error => Err(err_fmod!("FMOD_System_GetVersion", error)),
-            }
-        }
+    #[inline]
+    fn get_version(&self) -> Result<u32, Error> {
+        System::get_version(self)
     }
-    pub fn get_output_handle(&self) -> Result<*mut c_void, Error> {
-        unsafe {
-            let mut handle = null_mut();
-            match ffi::FMOD_System_GetOutputHandle(self.pointer, &mut handle) {
-                ffi::FMOD_OK => Ok(handle),
-                error => Err(err_fmod!("FMOD_System_GetOutputHandle", error)),
-            }
-        }
+    #[inline]
+    fn get_output_handle(&self) -> Result<*mut c_void, Error> {
+        System::get_output_handle(self)
     }
-    pub fn get_channels_playing(&self) -> Result<(i32, i32), Error> {
-        unsafe {
-            let mut channels = i32::default();
-            let mut realchannels = i32::default();
-            match ffi::FMOD_System_GetChannelsPlaying(
-                self.pointer,
-                &mut channels,
-                &mut realchannels,
-            ) {
-                ffi::FMOD_OK => Ok((channels, realchannels)),
-                error => Err(err_fmod!("FMOD_System_GetChannelsPlaying", error)),
-            }
-        }
+    #[inline]
+    fn get_channels_playing(&self) -> Result<(i32, i32), Error> {
+        System::get_channels_playing(self)
     }
-    pub fn get_cpu_usage(&self) -> Result<CpuUsage, Error> {
-        unsafe {
-            let mut usage = ffi::FMOD_CPU_USAGE::default();
-            match ffi::FMOD_System_GetCPUUsage(self.pointer, &mut usage) {
-                ffi::FMOD_OK => Ok(CpuUsage::try_from(usage)?),
-                error => Err(err_fmod!("FMOD_System_GetCPUUsage", error)),
-            }
-        }
+    #[inline]
+    fn get_cpu_usage(&self) -> Result<CpuUsage, Error> {
+        System::get_cpu_usage(self)
     }
-    pub fn get_file_usage(&self) -> Result<(i64, i64, i64), Error> {
-        unsafe {
-            let mut sample_bytes_read = i64::default();
-            let mut stream_bytes_read = i64::default();
-            let mut other_bytes_read = i64::default();
-            match ffi::FMOD_System_GetFileUsage(
-                self.pointer,
-                &mut sample_bytes_read,
-                &mut stream_bytes_read,
-                &mut other_bytes_read,
-            ) {
-                ffi::FMOD_OK => Ok((sample_bytes_read, stream_bytes_read, other_bytes_read)),
-                error => Err(err_fmod!("FMOD_System_GetFileUsage", error)),
-            }
-        }
+    #[inline]
+    fn get_file_usage(&self) -> Result<(i64, i64, i64), Error> {
+        System::get_file_usage(self)
     }
-    pub fn create_sound(
+    #[inline]
+    fn create_sound(
         &self,
         name_or_data: &str,
         mode: impl Into<ffi::FMOD_MODE>,
         exinfo: Option<CreateSoundexInfo>,
     ) -> Result<Sound, Error> {
-        unsafe {
-            let mut sound = null_mut();
-            match ffi::FMOD_System_CreateSound(
-                self.pointer,
-                CString::new(name_or_data)?.as_ptr(),
-                mode.into(),
-                exinfo
-                    .map(|value| &mut value.into() as *mut _)
-                    .unwrap_or(null_mut()),
-                &mut sound,
-            ) {
-                ffi::FMOD_OK => Ok(Sound::from(sound)),
-                error => Err(err_fmod!("FMOD_System_CreateSound", error)),
-            }
-        }
+        System::create_sound(self, name_or_data, mode, exinfo)
     }
-    pub fn create_sound_from(
+    #[inline]
+    fn create_sound_from(
         &self,
         data: &[u8],
         mode: impl Into<ffi::FMOD_MODE>,
         exinfo: CreateSoundexInfo,
     ) -> Result<Sound, Error> {
-        unsafe {
-            let mut sound = null_mut();
-            match ffi::FMOD_System_CreateSound(
-                self.pointer,
-                data.as_ptr() as *const _,
-                mode.into(),
-                &mut exinfo.into() as *mut _,
-                &mut sound,
-            ) {
-                ffi::FMOD_OK => Ok(Sound::from(sound)),
-                error => Err(err_fmod!("FMOD_System_CreateSound", error)),
-            }
-        }
+        System::create_sound_from(self, data, mode, exinfo)
     }
-    pub fn create_stream(
+    #[inline]
+    fn create_stream(
         &self,
         name_or_data: &str,
         mode: impl Into<ffi::FMOD_MODE>,
         exinfo: Option<CreateSoundexInfo>,
     ) -> Result<Sound, Error> {
-        unsafe {
-            let mut sound = null_mut();
-            match ffi::FMOD_System_CreateStream(
-                self.pointer,
-                CString::new(name_or_data)?.as_ptr(),
-                mode.into(),
-                exinfo
-                    .map(|value| &mut value.into() as *mut _)
-                    .unwrap_or(null_mut()),
-                &mut sound,
-            ) {
-                ffi::FMOD_OK => Ok(Sound::from(sound)),
-                error => Err(err_fmod!("FMOD_System_CreateStream", error)),
-            }
-        }
+        System::create_stream(self, name_or_data, mode, exinfo)
     }
-    pub fn create_stream_from(
+    #[inline]
+    fn create_stream_from(
         &self,
         data: &[u8],
         mode: impl Into<ffi::FMOD_MODE>,
         exinfo: CreateSoundexInfo,
     ) -> Result<Sound, Error> {
-        unsafe {
-            let mut sound = null_mut();
-            match ffi::FMOD_System_CreateStream(
-                self.pointer,
-                data.as_ptr() as *const _,
-                mode.into(),
-                &mut exinfo.into() as *mut _,
-                &mut sound,
-            ) {
-                ffi::FMOD_OK => Ok(Sound::from(sound)),
-                error => Err(err_fmod!("FMOD_System_CreateStream", error)),
-            }
-        }
+        System::create_stream_from(self, data, mode, exinfo)
     }
-    pub fn create_dsp(&self, description: DspDescription) -> Result<Dsp, Error> {
-        unsafe {
-            let mut dsp = null_mut();
-            match ffi::FMOD_System_CreateDSP(self.pointer, &description.into(), &mut dsp) {
-                ffi::FMOD_OK => Ok(Dsp::from(dsp)),
-                error => Err(err_fmod!("FMOD_System_CreateDSP", error)),
-            }
-        }
+    #[inline]
+    fn create_dsp(&self, description: DspDescription) -> Result<Dsp, Error> {
+        System::create_dsp(self, description)
     }
-    pub fn create_dsp_by_type(&self, type_: DspType) -> Result<Dsp, Error> {
-        unsafe {
-            let mut dsp = null_mut();
-            match ffi::FMOD_System_CreateDSPByType(self.pointer, type_.into(), &mut dsp) {
-                ffi::FMOD_OK => Ok(Dsp::from(dsp)),
-                error => Err(err_fmod!("FMOD_System_CreateDSPByType", error)),
-            }
-        }
+    #[inline]
+    fn create_dsp_by_type(&self, type_: DspType) -> Result<Dsp, Error> {
+        System::create_dsp_by_type(self, type_)
     }
-    pub fn create_channel_group(&self, name: Option<String>) -> Result<ChannelGroup, Error> {
-        unsafe {
-            let mut channelgroup = null_mut();
-            match ffi::FMOD_System_CreateChannelGroup(
-                self.pointer,
-                name.map(|value| CString::new(value).map(|value| value.as_ptr()))
-                    .unwrap_or(Ok(null_mut()))?,
-                &mut channelgroup,
-            ) {
-                ffi::FMOD_OK => Ok(ChannelGroup::from(channelgroup)),
-                error => Err(err_fmod!("FMOD_System_CreateChannelGroup", error)),
-            }
-        }
+    #[inline]
+    fn create_channel_group(&self, name: Option<String>) -> Result<ChannelGroup, Error> {
+        System::create_channel_group(self, name)
     }
-    pub fn create_sound_group(&self, name: &str) -> Result<SoundGroup, Error> {
-        unsafe {
-            let mut soundgroup = null_mut();
-            match ffi::FMOD_System_CreateSoundGroup(
-                self.pointer,
-                CString::new(name)?.as_ptr(),
-                &mut soundgroup,
-            ) {
-                ffi::FMOD_OK => Ok(SoundGroup::from(soundgroup)),
-                error => Err(err_fmod!("FMOD_System_CreateSoundGroup", error)),
-            }
-        }
+    #[inline]
+    fn create_sound_group(&self, name: &str) -> Result<SoundGroup, Error> {
+        System::create_sound_group(self, name)
     }
-    pub fn create_reverb_3d(&self) -> Result<Reverb3d, Error> {
-        unsafe {
-            let mut reverb = null_mut();
-            match ffi::FMOD_System_CreateReverb3D(self.pointer, &mut reverb) {
-                ffi::FMOD_OK => Ok(Reverb3d::from(reverb)),
-                error => Err(err_fmod!("FMOD_System_CreateReverb3D", error)),
-            }
-        }
+    #[inline]
+    fn create_reverb_3d(&self) -> Result<Reverb3d, Error> {
+        System::create_reverb_3d(self)
     }
-    pub fn play_sound(
+    #[inline]
+    fn play_sound(
         &self,
         sound: Sound,
         channelgroup: Option<ChannelGroup>,
         paused: bool,
     ) -> Result<Channel, Error> {
-        unsafe {
-            let mut channel = null_mut();
-            match ffi::FMOD_System_PlaySound(
-                self.pointer,
-                sound.as_mut_ptr(),
-                channelgroup
-                    .map(|value| value.as_mut_ptr())
-                    .unwrap_or(null_mut()),
-                from_bool!(paused),
-                &mut channel,
-            ) {
-                ffi::FMOD_OK => Ok(Channel::from(channel)),
-                error => Err(err_fmod!("FMOD_System_PlaySound", error)),
-            }
-        }
+        System::play_sound(self, sound, channelgroup, paused)
     }
-    pub fn play_dsp(
+    #[inline]
+    fn play_dsp(
         &self,
         dsp: Dsp,
         channelgroup: Option<ChannelGroup>,
         paused: bool,
     ) -> Result<Channel, Error> {
-        unsafe {
-            let mut channel = null_mut();
-            match ffi::FMOD_System_PlayDSP(
-                self.pointer,
-                dsp.as_mut_ptr(),
-                channelgroup
-                    .map(|value| value.as_mut_ptr())
-                    .unwrap_or(null_mut()),
-                from_bool!(paused),
-                &mut channel,
-            ) {
-                ffi::FMOD_OK => Ok(Channel::from(channel)),
-                error => Err(err_fmod!("FMOD_System_PlayDSP", error)),
-            }
-        }
-    }
-    pub fn get_channel(&self, channelid: i32) -> Result<Channel, Error> {
-        unsafe {
-            let mut channel = null_mut();
-            match ffi::FMOD_System_GetChannel(self.pointer, channelid, &mut channel) {
-                ffi::FMOD_OK => Ok(Channel::from(channel)),
-                error => Err(err_fmod!("FMOD_System_GetChannel", error)),
-            }
-        }
+        System::play_dsp(self, dsp, channelgroup, paused)
     }
-    pub fn get_dsp_info_by_type(&self, type_: DspType) -> Result<DspDescription, Error> {
-        unsafe {
-            let mut description = null();
-            match ffi::FMOD_System_GetDSPInfoByType(self.pointer, type_.into(), &mut description) {
-                ffi::FMOD_OK => Ok(DspDescription::try_from(*description)?),
-                error => Err(err_fmod!("FMOD_System_GetDSPInfoByType", error)),
-            }
-        }
+    #[inline]
+    fn get_channel(&self, channelid: i32) -> Result<Channel, Error> {
+        System::get_channel(self, channelid)
     }
-    pub fn get_master_channel_group(&self) -> Result<ChannelGroup, Error> {
-        unsafe {
-            let mut channelgroup = null_mut();
-            match ffi::FMOD_System_GetMasterChannelGroup(self.pointer, &mut channelgroup) {
-                ffi::FMOD_OK => Ok(ChannelGroup::from(channelgroup)),
-                error => Err(err_fmod!("FMOD_System_GetMasterChannelGroup", error)),
-            }
-        }
+    #[inline]
+    fn get_dsp_info_by_type(&self, type_: DspType) -> Result<DspDescription, Error> {
+        System::get_dsp_info_by_type(self, type_)
     }
-    pub fn get_master_sound_group(&self) -> Result<SoundGroup, Error> {
-        unsafe {
-            let mut soundgroup = null_mut();
-            match ffi::FMOD_System_GetMasterSoundGroup(self.pointer, &mut soundgroup) {
-                ffi::FMOD_OK => Ok(SoundGroup::from(soundgroup)),
-                error => Err(err_fmod!("FMOD_System_GetMasterSoundGroup", error)),
-            }
-        }
+    #[inline]
+    fn get_master_channel_group(&self) -> Result<ChannelGroup, Error> {
+        System::get_master_channel_group(self)
     }
-    pub fn attach_channel_group_to_port(
+    #[inline]
+    fn get_master_sound_group(&self) -> Result<SoundGroup, Error> {
+        System::get_master_sound_group(self)
+    }
+    #[inline]
+    fn attach_channel_group_to_port(
         &self,
         port_type: PortType,
         port_index: impl Into<ffi::FMOD_PORT_INDEX>,
         channelgroup: ChannelGroup,
         pass_thru: bool,
     ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_AttachChannelGroupToPort(
-                self.pointer,
-                port_type.into(),
-                port_index.into(),
-                channelgroup.as_mut_ptr(),
-                from_bool!(pass_thru),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_AttachChannelGroupToPort", error)),
-            }
-        }
+        System::attach_channel_group_to_port(self, port_type, port_index, channelgroup, pass_thru)
     }
-    pub fn detach_channel_group_from_port(&self, channelgroup: ChannelGroup) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_DetachChannelGroupFromPort(
-                self.pointer,
-                channelgroup.as_mut_ptr(),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_DetachChannelGroupFromPort", error)),
-            }
-        }
+    #[inline]
+    fn detach_channel_group_from_port(&self, channelgroup: ChannelGroup) -> Result<(), Error> {
+        System::detach_channel_group_from_port(self, channelgroup)
     }
-    pub fn set_reverb_properties(
-        &self,
-        instance: i32,
-        prop: ReverbProperties,
-    ) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetReverbProperties(self.pointer, instance, &prop.into()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetReverbProperties", error)),
-            }
-        }
+    #[inline]
+    fn set_reverb_properties(&self, instance: i32, prop: ReverbProperties) -> Result<(), Error> {
+        System::set_reverb_properties(self, instance, prop)
     }
-    pub fn get_reverb_properties(&self, instance: i32) -> Result<ReverbProperties, Error> {
-        unsafe {
-            let mut prop = ffi::FMOD_REVERB_PROPERTIES::default();
-            match ffi::FMOD_System_GetReverbProperties(self.pointer, instance, &mut prop) {
-                ffi::FMOD_OK => Ok(ReverbProperties::try_from(prop)?),
-                error => Err(err_fmod!("FMOD_System_GetReverbProperties", error)),
-            }
-        }
+    #[inline]
+    fn get_reverb_properties(&self, instance: i32) -> Result<ReverbProperties, Error> {
+        System::get_reverb_properties(self, instance)
     }
-    pub fn lock_dsp(&self) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_LockDSP(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_LockDSP", error)),
-            }
-        }
+    #[inline]
+    fn lock_dsp(&self) -> Result<(), Error> {
+        System::lock_dsp(self)
     }
-    pub fn unlock_dsp(&self) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_UnlockDSP(self.pointer) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_UnlockDSP", error)),
-            }
-        }
+    #[inline]
+    fn unlock_dsp(&self) -> Result<(), Error> {
+        System::unlock_dsp(self)
     }
-    pub fn get_record_num_drivers(&self) -> Result<(i32, i32), Error> {
-        unsafe {
-            let mut numdrivers = i32::default();
-            let mut numconnected = i32::default();
-            match ffi::FMOD_System_GetRecordNumDrivers(
-                self.pointer,
-                &mut numdrivers,
-                &mut numconnected,
-            ) {
-                ffi::FMOD_OK => Ok((numdrivers, numconnected)),
-                error => Err(err_fmod!("FMOD_System_GetRecordNumDrivers", error)),
-            }
-        }
+    #[inline]
+    fn get_record_num_drivers(&self) -> Result<(i32, i32), Error> {
+        System::get_record_num_drivers(self)
     }
-    pub fn get_record_driver_info(
+    #[inline]
+    fn get_record_driver_info(
         &self,
         id: i32,
         namelen: i32,
     ) -> Result<(String, Guid, i32, SpeakerMode, i32, ffi::FMOD_DRIVER_STATE), Error> {
-        unsafe {
-            let name = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            let mut guid = ffi::FMOD_GUID::default();
-            let mut systemrate = i32::default();
-            let mut speakermode = ffi::FMOD_SPEAKERMODE::default();
-            let mut speakermodechannels = i32::default();
-            let mut state = ffi::FMOD_DRIVER_STATE::default();
-            match ffi::FMOD_System_GetRecordDriverInfo(
-                self.pointer,
-                id,
-                name,
-                namelen,
-                &mut guid,
-                &mut systemrate,
-                &mut speakermode,
-                &mut speakermodechannels,
-                &mut state,
-            ) {
-                ffi::FMOD_OK => Ok((
-                    CString::from_raw(name)
-                        .into_string()
-                        .map_err(Error::String)?,
-                    Guid::try_from(guid)?,
-                    systemrate,
-                    SpeakerMode::from(speakermode)?,
-                    speakermodechannels,
-                    state,
-                )),
-                error => Err(err_fmod!("FMOD_System_GetRecordDriverInfo", error)),
-            }
-        }
+        System::get_record_driver_info(self, id, namelen)
     }
-    pub fn get_record_position(&self, id: i32) -> Result<u32, Error> {
-        unsafe {
-            let mut position = u32::default();
-            match ffi::FMOD_System_GetRecordPosition(self.pointer, id, &mut position) {
-                ffi::FMOD_OK => Ok(position),
-                error => Err(err_fmod!("FMOD_System_GetRecordPosition", error)),
-            }
-        }
+    #[inline]
+    fn get_record_position(&self, id: i32) -> Result<u32, Error> {
+        System::get_record_position(self, id)
     }
-    pub fn record_start(&self, id: i32, sound: Sound, loop_: bool) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_RecordStart(
-                self.pointer,
-                id,
-                sound.as_mut_ptr(),
-                from_bool!(loop_),
-            ) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_RecordStart", error)),
-            }
-        }
+    #[inline]
+    fn record_start(&self, id: i32, sound: Sound, loop_: bool) -> Result<(), Error> {
+        System::record_start(self, id, sound, loop_)
     }
-    pub fn record_stop(&self, id: i32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_RecordStop(self.pointer, id) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_RecordStop", error)),
-            }
-        }
+    #[inline]
+    fn record_stop(&self, id: i32) -> Result<(), Error> {
+        System::record_stop(self, id)
     }
-    pub fn is_recording(&self, id: i32) -> Result<bool, Error> {
-        unsafe {
-            let mut recording = ffi::FMOD_BOOL::default();
-            match ffi::FMOD_System_IsRecording(self.pointer, id, &mut recording) {
-                ffi::FMOD_OK => Ok(to_bool!(recording)),
-                error => Err(err_fmod!("FMOD_System_IsRecording", error)),
-            }
-        }
+    #[inline]
+    fn is_recording(&self, id: i32) -> Result<bool, Error> {
+        System::is_recording(self, id)
     }
-    pub fn create_geometry(&self, maxpolygons: i32, maxvertices: i32) -> Result<Geometry, Error> {
-        unsafe {
-            let mut geometry = null_mut();
-            match ffi::FMOD_System_CreateGeometry(
-                self.pointer,
-                maxpolygons,
-                maxvertices,
-                &mut geometry,
-            ) {
-                ffi::FMOD_OK => Ok(Geometry::from(geometry)),
-                error => Err(err_fmod!("FMOD_System_CreateGeometry", error)),
-            }
-        }
+    #[inline]
+    fn create_geometry(&self, maxpolygons: i32, maxvertices: i32) -> Result<Geometry, Error> {
+        System::create_geometry(self, maxpolygons, maxvertices)
     }
-    pub fn set_geometry_settings(&self, maxworldsize: f32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetGeometrySettings(self.pointer, maxworldsize) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetGeometrySettings", error)),
-            }
-        }
+    #[inline]
+    fn set_geometry_settings(&self, maxworldsize: f32) -> Result<(), Error> {
+        System::set_geometry_settings(self, maxworldsize)
     }
-    pub fn get_geometry_settings(&self) -> Result<f32, Error> {
-        unsafe {
-            let mut maxworldsize = f32::default();
-            match ffi::FMOD_System_GetGeometrySettings(self.pointer, &mut maxworldsize) {
-                ffi::FMOD_OK => Ok(maxworldsize),
-                error => Err(err_fmod!("FMOD_System_GetGeometrySettings", error)),
-            }
-        }
+    #[inline]
+    fn get_geometry_settings(&self) -> Result<f32, Error> {
+        System::get_geometry_settings(self)
     }
-    pub fn load_geometry(&self, data: *const c_void, datasize: i32) -> Result<Geometry, Error> {
-        unsafe {
-            let mut geometry = null_mut();
-            match ffi::FMOD_System_LoadGeometry(self.pointer, data, datasize, &mut geometry) {
-                ffi::FMOD_OK => Ok(Geometry::from(geometry)),
-                error => Err(err_fmod!("FMOD_System_LoadGeometry", error)),
-            }
-        }
+    #[inline]
+    fn load_geometry_bytes(&self, data: &[u8]) -> Result<Geometry, Error> {
+        System::load_geometry_bytes(self, data)
     }
-    pub fn get_geometry_occlusion(
+    #[inline]
+    fn get_geometry_occlusion(
         &self,
         listener: Vector,
         source: Vector,
     ) -> Result<(f32, f32), Error> {
-        unsafe {
-            let mut direct = f32::default();
-            let mut reverb = f32::default();
-            match ffi::FMOD_System_GetGeometryOcclusion(
-                self.pointer,
-                &listener.into(),
-                &source.into(),
-                &mut direct,
-                &mut reverb,
-            ) {
-                ffi::FMOD_OK => Ok((direct, reverb)),
-                error => Err(err_fmod!("FMOD_System_GetGeometryOcclusion", error)),
-            }
-        }
+        System::get_geometry_occlusion(self, listener, source)
     }
-    pub fn set_network_proxy(&self, proxy: &str) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetNetworkProxy(self.pointer, CString::new(proxy)?.as_ptr()) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetNetworkProxy", error)),
-            }
-        }
+    #[inline]
+    fn set_network_proxy(&self, proxy: &str) -> Result<(), Error> {
+        System::set_network_proxy(self, proxy)
     }
-    pub fn get_network_proxy(&self, proxylen: i32) -> Result<String, Error> {
-        unsafe {
-            let proxy = CString::from_vec_unchecked(b"".to_vec()).into_raw();
-            match ffi::FMOD_System_GetNetworkProxy(self.pointer, proxy, proxylen) {
-                ffi::FMOD_OK => Ok(CString::from_raw(proxy)
-                    .into_string()
-                    .map_err(Error::String)?),
-                error => Err(err_fmod!("FMOD_System_GetNetworkProxy", error)),
-            }
-        }
+    #[inline]
+    fn get_network_proxy(&self, proxylen: i32) -> Result<String, Error> {
+        System::get_network_proxy(self, proxylen)
     }
-    pub fn set_network_timeout(&self, timeout: i32) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetNetworkTimeout(self.pointer, timeout) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetNetworkTimeout", error)),
-            }
-        }
+    #[inline]
+    fn set_network_timeout(&self, timeout: i32) -> Result<(), Error> {
+        System::set_network_timeout(self, timeout)
     }
-    pub fn get_network_timeout(&self) -> Result<i32, Error> {
-        unsafe {
-            let mut timeout = i32::default();
-            match ffi::FMOD_System_GetNetworkTimeout(self.pointer, &mut timeout) {
-                ffi::FMOD_OK => Ok(timeout),
-                error => Err(err_fmod!("FMOD_System_GetNetworkTimeout", error)),
-            }
-        }
+    #[inline]
+    fn get_network_timeout(&self) -> Result<i32, Error> {
+        System::get_network_timeout(self)
     }
-    pub fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
-        unsafe {
-            match ffi::FMOD_System_SetUserData(self.pointer, userdata) {
-                ffi::FMOD_OK => Ok(()),
-                error => Err(err_fmod!("FMOD_System_SetUserData", error)),
-            }
-        }
+    #[inline]
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        System::set_user_data(self, userdata)
     }
-    pub fn get_user_data(&self) -> Result<*mut c_void, Error> {
-        unsafe {
-            let mut userdata = null_mut();
-            match ffi::FMOD_System_GetUserData(self.pointer, &mut userdata) {
-                ffi::FMOD_OK => Ok(userdata),
-                error => Err(err_fmod!("FMOD_System_GetUserData", error)),
-            }
-        }
+    #[inline]
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        System::get_user_data(self)
     }
 }
@@ -0,0 +1,140 @@
+use std::ffi::c_void;
+use std::mem::size_of;
+
+use crate::{ffi, ChannelGroup, Dsp, DspType, Error};
+
+/// EBU R128 loudness of the signal measured since the last reset.
+///
+/// Loudness values are in LUFS, true peak is in dBTP.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoudnessInfo {
+    /// Loudness of the last 400 ms.
+    pub momentary: f32,
+    /// Loudness of the last 3 seconds.
+    pub short_term: f32,
+    pub integrated: f32,
+    pub max_momentary: f32,
+    pub max_true_peak: f32,
+    pub percentile_10: f32,
+    pub percentile_95: f32,
+    pub histogram: Vec<f32>,
+}
+
+impl LoudnessInfo {
+    /// Loudness range (LRA) in LU, difference between 95th and 10th percentiles of short-term loudness.
+    pub fn loudness_range(&self) -> f32 {
+        self.percentile_95 - self.percentile_10
+    }
+}
+
+impl From<ffi::FMOD_DSP_LOUDNESS_METER_INFO_TYPE> for LoudnessInfo {
+    fn from(value: ffi::FMOD_DSP_LOUDNESS_METER_INFO_TYPE) -> Self {
+        Self {
+            momentary: value.momentaryloudness,
+            short_term: value.shorttermloudness,
+            integrated: value.integratedloudness,
+            max_momentary: value.maxmomentaryloudness,
+            max_true_peak: value.maxtruepeak,
+            percentile_10: value.loudness10thpercentile,
+            percentile_95: value.loudness95thpercentile,
+            histogram: value.loudnesshistogram.to_vec(),
+        }
+    }
+}
+
+/// Loudness meter DSP placed at the head of channel group, so it measures the final
+/// signal of the group. The DSP is removed and released when the meter is dropped.
+///
+/// ```ignore
+/// let mut meter = LoudnessMeter::attach(&system.get_master_channel_group()?)?;
+/// // play the level for a while
+/// let loudness = meter.read()?;
+/// assert!(loudness.integrated > -24.0 && loudness.max_true_peak < -1.0);
+/// ```
+#[derive(Debug)]
+pub struct LoudnessMeter {
+    group: ChannelGroup,
+    dsp: Dsp,
+}
+
+impl LoudnessMeter {
+    pub fn attach(group: &ChannelGroup) -> Result<Self, Error> {
+        let dsp = group
+            .get_system_object()?
+            .create_dsp_by_type(DspType::LoudnessMeter)?;
+        if let Err(error) = group.add_dsp(ffi::FMOD_CHANNELCONTROL_DSP_HEAD, dsp) {
+            let _ = dsp.release();
+            return Err(error);
+        }
+        let meter = Self { group: *group, dsp };
+        meter.set_state(ffi::FMOD_DSP_LOUDNESS_METER_STATE_ANALYZING)?;
+        Ok(meter)
+    }
+
+    pub fn dsp(&self) -> Dsp {
+        self.dsp
+    }
+
+    fn set_state(&self, state: ffi::FMOD_DSP_LOUDNESS_METER_STATE_TYPE) -> Result<(), Error> {
+        self.dsp
+            .set_parameter_int(ffi::FMOD_DSP_LOUDNESS_METER_STATE, state)
+    }
+
+    /// Clears integrated loudness, maximum values and histogram, then continues analysis.
+    pub fn reset(&self) -> Result<(), Error> {
+        self.set_state(ffi::FMOD_DSP_LOUDNESS_METER_STATE_RESET_ALL)?;
+        self.set_state(ffi::FMOD_DSP_LOUDNESS_METER_STATE_ANALYZING)
+    }
+
+    /// Stops analysis keeping measured values, e.g. while the game is paused.
+    pub fn pause(&self) -> Result<(), Error> {
+        self.set_state(ffi::FMOD_DSP_LOUDNESS_METER_STATE_PAUSED)
+    }
+
+    pub fn resume(&self) -> Result<(), Error> {
+        self.set_state(ffi::FMOD_DSP_LOUDNESS_METER_STATE_ANALYZING)
+    }
+
+    /// Sets weight of every channel, channels beyond given weights keep their values.
+    pub fn set_weighting(&self, weights: &[f32]) -> Result<(), Error> {
+        let mut weighting: ffi::FMOD_DSP_LOUDNESS_METER_WEIGHTING_TYPE = self
+            .dsp
+            .parameter_struct(ffi::FMOD_DSP_LOUDNESS_METER_WEIGHTING)?;
+        if weights.len() > weighting.channelweight.len() {
+            return Err(err_validation!(
+                "LoudnessMeter::set_weighting",
+                "{} weights given, at most {} channels are supported",
+                weights.len(),
+                weighting.channelweight.len()
+            ));
+        }
+        weighting.channelweight[..weights.len()].copy_from_slice(weights);
+        self.dsp.set_parameter_data(
+            ffi::FMOD_DSP_LOUDNESS_METER_WEIGHTING,
+            &mut weighting as *mut _ as *mut c_void,
+            size_of::<ffi::FMOD_DSP_LOUDNESS_METER_WEIGHTING_TYPE>() as u32,
+        )
+    }
+
+    /// Weights of all 32 channels.
+    pub fn weighting(&self) -> Result<[f32; 32], Error> {
+        let weighting: ffi::FMOD_DSP_LOUDNESS_METER_WEIGHTING_TYPE = self
+            .dsp
+            .parameter_struct(ffi::FMOD_DSP_LOUDNESS_METER_WEIGHTING)?;
+        Ok(weighting.channelweight)
+    }
+
+    pub fn read(&self) -> Result<LoudnessInfo, Error> {
+        let info: ffi::FMOD_DSP_LOUDNESS_METER_INFO_TYPE = self
+            .dsp
+            .parameter_struct(ffi::FMOD_DSP_LOUDNESS_METER_INFO)?;
+        Ok(info.into())
+    }
+}
+
+impl Drop for LoudnessMeter {
+    fn drop(&mut self) {
+        let _ = self.group.remove_dsp(self.dsp);
+        let _ = self.dsp.release();
+    }
+}
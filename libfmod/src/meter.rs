@@ -0,0 +1,152 @@
+use crate::{ffi, Bus, ChannelControlDspIndex, ChannelGroup, Dsp, Error, Speaker, SpeakerMode};
+
+/// Channel group to meter, either directly or through Studio bus.
+#[derive(Debug, Clone, Copy)]
+pub enum MeterSource {
+    ChannelGroup(ChannelGroup),
+    Bus(Bus),
+}
+
+impl From<&ChannelGroup> for MeterSource {
+    fn from(group: &ChannelGroup) -> Self {
+        MeterSource::ChannelGroup(*group)
+    }
+}
+
+impl From<&Bus> for MeterSource {
+    fn from(bus: &Bus) -> Self {
+        MeterSource::Bus(*bus)
+    }
+}
+
+/// Level of one channel in dBFS, `f32::NEG_INFINITY` for silence.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChannelLevel {
+    /// Speaker of the channel in current speaker mode, `Speaker::None` if layout is unknown
+    /// or channel count of metered DSP differs from the speaker mode.
+    pub speaker: Speaker,
+    pub peak: f32,
+    pub rms: f32,
+}
+
+/// Output metering of channel group DSP, e.g. for mixer UI.
+///
+/// Channel group of Studio bus exists only while the bus is used, so meter locks it and
+/// releases the lock when dropped. The group is created by Studio update after locking,
+/// until then meter reports no levels.
+///
+/// ```ignore
+/// let mut meter = Meter::attach(&studio.get_bus("bus:/SFX")?)?;
+/// studio.update()?;
+/// for level in meter.levels()? {
+///     println!("{:?} {:.1} dBFS", level.speaker, level.peak);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Meter {
+    source: MeterSource,
+    index: ChannelControlDspIndex,
+    dsp: Option<(Dsp, SpeakerMode)>,
+    enabled: (bool, bool),
+}
+
+impl Meter {
+    /// Converts linear level to dBFS.
+    pub fn dbfs(level: f32) -> f32 {
+        20.0 * level.log10()
+    }
+
+    /// Meters the head DSP of channel group, its output is the final signal of the group.
+    pub fn attach(source: impl Into<MeterSource>) -> Result<Self, Error> {
+        Self::attach_at(source, ChannelControlDspIndex::Head)
+    }
+
+    /// Meters DSP at given position of channel group, e.g. `Tail` for the signal
+    /// entering the group before any effect or fader.
+    pub fn attach_at(
+        source: impl Into<MeterSource>,
+        index: ChannelControlDspIndex,
+    ) -> Result<Self, Error> {
+        let source = source.into();
+        if let MeterSource::Bus(bus) = source {
+            bus.lock_channel_group()?;
+        }
+        // dropping meter unlocks bus on error
+        let mut meter = Self {
+            source,
+            index,
+            dsp: None,
+            enabled: (false, false),
+        };
+        meter.resolve()?;
+        Ok(meter)
+    }
+
+    /// Enables metering when channel group is available, bus group may not be created yet.
+    fn resolve(&mut self) -> Result<Option<(Dsp, SpeakerMode)>, Error> {
+        if self.dsp.is_some() {
+            return Ok(self.dsp);
+        }
+        let group = match self.source {
+            MeterSource::ChannelGroup(group) => group,
+            MeterSource::Bus(bus) => match bus.get_channel_group() {
+                Ok(group) => group,
+                Err(Error::Fmod { code, .. }) if code == ffi::FMOD_ERR_STUDIO_NOT_LOADED => {
+                    return Ok(None);
+                }
+                Err(error) => return Err(error),
+            },
+        };
+        let dsp = group.get_dsp(self.index.into())?;
+        self.enabled = dsp.get_metering_enabled()?;
+        dsp.set_metering_enabled(self.enabled.0, true)?;
+        let (_, speaker_mode, _) = group.get_system_object()?.get_software_format()?;
+        self.dsp = Some((dsp, speaker_mode));
+        Ok(self.dsp)
+    }
+
+    /// Peak and RMS levels of the last mixer block per channel, empty until bus group is created.
+    pub fn levels(&mut self) -> Result<Vec<ChannelLevel>, Error> {
+        let Some((dsp, speaker_mode)) = self.resolve()? else {
+            return Ok(vec![]);
+        };
+        let (_, info) = dsp.get_metering_info()?;
+        // channels are labelled only if DSP outputs the layout of speaker mode
+        let speakers = speaker_mode
+            .speakers()
+            .filter(|speakers| speakers.len() == info.numchannels as usize)
+            .unwrap_or_default();
+        let channels = (info.numchannels.max(0) as usize).min(info.peaklevel.len());
+        let levels = (0..channels)
+            .map(|channel| ChannelLevel {
+                speaker: speakers.get(channel).copied().unwrap_or(Speaker::None),
+                peak: Self::dbfs(info.peaklevel[channel]),
+                rms: Self::dbfs(info.rmslevel[channel]),
+            })
+            .collect();
+        Ok(levels)
+    }
+}
+
+impl Drop for Meter {
+    fn drop(&mut self) {
+        if let Some((dsp, _)) = self.dsp {
+            let _ = dsp.set_metering_enabled(self.enabled.0, self.enabled.1);
+        }
+        if let MeterSource::Bus(bus) = self.source {
+            let _ = bus.unlock_channel_group();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Meter;
+
+    #[test]
+    fn test_dbfs() {
+        assert_eq!(Meter::dbfs(1.0), 0.0);
+        assert!((Meter::dbfs(0.5) + 6.0206).abs() < 0.001);
+        assert_eq!(Meter::dbfs(0.0), f32::NEG_INFINITY);
+    }
+}
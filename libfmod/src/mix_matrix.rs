@@ -0,0 +1,242 @@
+use crate::{Error, Speaker, SpeakerMode};
+
+/// Levels of input channels mapped to output channels.
+///
+/// Matrix has `out` rows and `in_` columns, `get(out, in_)` is the level of input channel `in_`
+/// going to output channel `out`. It is laid out the same way as FMOD expects mix matrix,
+/// so it can be passed to `set_mix_matrix` as is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MixMatrix {
+    out: usize,
+    in_: usize,
+    data: Vec<f32>,
+}
+
+const MONO: &[Speaker] = &[Speaker::FrontLeft];
+const STEREO: &[Speaker] = &[Speaker::FrontLeft, Speaker::FrontRight];
+const QUAD: &[Speaker] = &[
+    Speaker::FrontLeft,
+    Speaker::FrontRight,
+    Speaker::SurroundLeft,
+    Speaker::SurroundRight,
+];
+const SURROUND: &[Speaker] = &[
+    Speaker::FrontLeft,
+    Speaker::FrontRight,
+    Speaker::FrontCenter,
+    Speaker::SurroundLeft,
+    Speaker::SurroundRight,
+];
+const MODE_5POINT1: &[Speaker] = &[
+    Speaker::FrontLeft,
+    Speaker::FrontRight,
+    Speaker::FrontCenter,
+    Speaker::LowFrequency,
+    Speaker::SurroundLeft,
+    Speaker::SurroundRight,
+];
+const MODE_7POINT1: &[Speaker] = &[
+    Speaker::FrontLeft,
+    Speaker::FrontRight,
+    Speaker::FrontCenter,
+    Speaker::LowFrequency,
+    Speaker::SurroundLeft,
+    Speaker::SurroundRight,
+    Speaker::BackLeft,
+    Speaker::BackRight,
+];
+const MODE_7POINT1POINT4: &[Speaker] = &[
+    Speaker::FrontLeft,
+    Speaker::FrontRight,
+    Speaker::FrontCenter,
+    Speaker::LowFrequency,
+    Speaker::SurroundLeft,
+    Speaker::SurroundRight,
+    Speaker::BackLeft,
+    Speaker::BackRight,
+    Speaker::TopFrontLeft,
+    Speaker::TopFrontRight,
+    Speaker::TopBackLeft,
+    Speaker::TopBackRight,
+];
+
+impl SpeakerMode {
+    /// Speakers in order of channels for speaker modes with fixed layout,
+    /// `None` for `Default`, `Raw` and `Max`.
+    pub fn speakers(&self) -> Option<&'static [Speaker]> {
+        match self {
+            SpeakerMode::Mono => Some(MONO),
+            SpeakerMode::Stereo => Some(STEREO),
+            SpeakerMode::Quad => Some(QUAD),
+            SpeakerMode::Surround => Some(SURROUND),
+            SpeakerMode::Mode5Point1 => Some(MODE_5POINT1),
+            SpeakerMode::Mode7Point1 => Some(MODE_7POINT1),
+            SpeakerMode::Mode7Point1Point4 => Some(MODE_7POINT1POINT4),
+            SpeakerMode::Default | SpeakerMode::Raw | SpeakerMode::Max => None,
+        }
+    }
+
+    /// Channel index of speaker in this speaker mode.
+    pub fn speaker_index(&self, speaker: Speaker) -> Option<usize> {
+        self.speakers()?.iter().position(|value| *value == speaker)
+    }
+}
+
+impl MixMatrix {
+    /// Creates silent matrix, all levels are zero.
+    pub fn new(out: usize, in_: usize) -> Self {
+        Self {
+            out,
+            in_,
+            data: vec![0.0; out * in_],
+        }
+    }
+
+    /// Creates matrix which maps input channel N to output channel N at full level.
+    pub fn identity(out: usize, in_: usize) -> Self {
+        let mut matrix = Self::new(out, in_);
+        for index in 0..out.min(in_) {
+            matrix.set(index, index, 1.0);
+        }
+        matrix
+    }
+
+    /// Creates matrix from levels stored row by row.
+    pub fn from_vec(out: usize, in_: usize, data: Vec<f32>) -> Result<Self, Error> {
+        if data.len() != out * in_ {
+            return Err(err_validation!(
+                "MixMatrix::from_vec",
+                "{} levels don't fill {}x{} matrix",
+                data.len(),
+                out,
+                in_
+            ));
+        }
+        Ok(Self { out, in_, data })
+    }
+
+    /// Creates matrix which maps each source speaker to the same target speaker,
+    /// speakers missing in target layout are silent.
+    pub fn from_speaker_modes(source: SpeakerMode, target: SpeakerMode) -> Result<Self, Error> {
+        let (sources, targets) = match (source.speakers(), target.speakers()) {
+            (Some(sources), Some(targets)) => (sources, targets),
+            _ => {
+                return Err(err_validation!(
+                    "MixMatrix::from_speaker_modes",
+                    "speaker layout of {:?} or {:?} is unknown",
+                    source,
+                    target
+                ))
+            }
+        };
+        let mut matrix = Self::new(targets.len(), sources.len());
+        for (in_, speaker) in sources.iter().enumerate() {
+            if let Some(out) = target.speaker_index(*speaker) {
+                matrix.set(out, in_, 1.0);
+            }
+        }
+        Ok(matrix)
+    }
+
+    pub fn out_channels(&self) -> usize {
+        self.out
+    }
+
+    pub fn in_channels(&self) -> usize {
+        self.in_
+    }
+
+    /// Level of input channel going to output channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if channel is out of matrix bounds.
+    pub fn get(&self, out: usize, in_: usize) -> f32 {
+        assert!(in_ < self.in_, "input channel {} is out of bounds", in_);
+        self.data[out * self.in_ + in_]
+    }
+
+    /// Sets level of input channel going to output channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if channel is out of matrix bounds.
+    pub fn set(&mut self, out: usize, in_: usize, level: f32) {
+        assert!(in_ < self.in_, "input channel {} is out of bounds", in_);
+        self.data[out * self.in_ + in_] = level;
+    }
+
+    /// Levels of all input channels going to output channel.
+    pub fn row(&self, out: usize) -> &[f32] {
+        &self.data[out * self.in_..(out + 1) * self.in_]
+    }
+
+    pub fn row_mut(&mut self, out: usize) -> &mut [f32] {
+        &mut self.data[out * self.in_..(out + 1) * self.in_]
+    }
+
+    /// Levels of input channel going to all output channels.
+    pub fn column(&self, in_: usize) -> Vec<f32> {
+        (0..self.out).map(|out| self.get(out, in_)).collect()
+    }
+
+    pub fn as_slice(&self) -> &[f32] {
+        &self.data
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut f32 {
+        self.data.as_mut_ptr()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MixMatrix;
+    use crate::{Speaker, SpeakerMode};
+
+    #[test]
+    fn test_from_vec_laid_out_row_by_row() {
+        let matrix = MixMatrix::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        assert_eq!((matrix.out_channels(), matrix.in_channels()), (2, 3));
+        assert_eq!(matrix.get(1, 0), 4.0);
+        assert_eq!(matrix.row(0), &[1.0, 2.0, 3.0]);
+        assert_eq!(matrix.column(2), vec![3.0, 6.0]);
+    }
+
+    #[test]
+    fn test_from_vec_of_wrong_length() {
+        assert!(MixMatrix::from_vec(2, 2, vec![1.0]).is_err());
+        assert!(MixMatrix::from_vec(2, 2, vec![1.0; 5]).is_err());
+    }
+
+    #[test]
+    fn test_identity_of_different_channel_counts() {
+        let matrix = MixMatrix::identity(3, 2);
+        assert_eq!(matrix.as_slice(), &[1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_from_speaker_modes_maps_same_speakers() {
+        let matrix = MixMatrix::from_speaker_modes(SpeakerMode::Mode5Point1, SpeakerMode::Quad);
+        let matrix = matrix.unwrap();
+        assert_eq!((matrix.out_channels(), matrix.in_channels()), (4, 6));
+        assert_eq!(matrix.row(2), &[0.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+        // center and LFE have no place in quad layout
+        assert_eq!(matrix.column(2), vec![0.0; 4]);
+        assert_eq!(matrix.column(3), vec![0.0; 4]);
+    }
+
+    #[test]
+    fn test_from_speaker_modes_without_layout() {
+        assert!(MixMatrix::from_speaker_modes(SpeakerMode::Raw, SpeakerMode::Stereo).is_err());
+        assert!(MixMatrix::from_speaker_modes(SpeakerMode::Stereo, SpeakerMode::Default).is_err());
+    }
+
+    #[test]
+    fn test_speaker_index() {
+        let mode = SpeakerMode::Mode7Point1;
+        assert_eq!(mode.speaker_index(Speaker::BackRight), Some(7));
+        assert_eq!(mode.speaker_index(Speaker::TopFrontLeft), None);
+        assert_eq!(SpeakerMode::Raw.speaker_index(Speaker::FrontLeft), None);
+    }
+}
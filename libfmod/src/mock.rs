@@ -0,0 +1,5676 @@
+#![allow(unused_variables)]
+use crate::*;
+use std::any::{type_name, Any};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Deref;
+#[doc = " Method call recorded by mock, arguments are formatted with `Debug`."]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call {
+    pub method: &'static str,
+    pub arguments: Vec<String>,
+}
+impl Display for Call {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.method, self.arguments.join(", "))
+    }
+}
+#[doc = " Records method calls and returns scripted values."]
+#[doc = ""]
+#[doc = " Methods returning `Result<(), Error>` return `Ok(())` unless other value is scripted,"]
+#[doc = " other methods panic if called without scripted value."]
+#[derive(Default)]
+pub struct Mock {
+    calls: RefCell<Vec<Call>>,
+    returns: RefCell<HashMap<&'static str, VecDeque<Box<dyn Any>>>>,
+}
+impl Debug for Mock {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Mock")
+            .field("calls", &self.calls.borrow())
+            .finish()
+    }
+}
+impl Mock {
+    fn returns<T: 'static>(&self, method: &'static str, value: T) {
+        self.returns
+            .borrow_mut()
+            .entry(method)
+            .or_default()
+            .push_back(Box::new(value));
+    }
+    fn scripted<T: 'static>(&self, method: &'static str, arguments: &[&dyn Debug]) -> Option<T> {
+        self.calls.borrow_mut().push(Call {
+            method,
+            arguments: arguments
+                .iter()
+                .map(|argument| format!("{:?}", argument))
+                .collect(),
+        });
+        let value = self.returns.borrow_mut().get_mut(method)?.pop_front()?;
+        match value.downcast::<T>() {
+            Ok(value) => Some(*value),
+            Err(_) => panic!(
+                "{} returns {}, but other type was scripted",
+                method,
+                type_name::<T>()
+            ),
+        }
+    }
+    fn call<T: 'static>(&self, method: &'static str, arguments: &[&dyn Debug]) -> T {
+        match self.scripted(method, arguments) {
+            Some(value) => value,
+            None => panic!("{} is called, but return value is not scripted", method),
+        }
+    }
+    fn call_or<T: 'static>(&self, method: &'static str, arguments: &[&dyn Debug], default: T) -> T {
+        self.scripted(method, arguments).unwrap_or(default)
+    }
+    #[doc = " Returns called methods with their arguments in order of calls."]
+    pub fn calls(&self) -> Vec<Call> {
+        self.calls.borrow().clone()
+    }
+    #[doc = " Asserts that exactly given methods were called in given order."]
+    #[doc = ""]
+    #[doc = " Call is matched by method name, e.g. `\"set_volume\"`, or by name with arguments,"]
+    #[doc = " e.g. `\"set_volume(0.5)\"`."]
+    pub fn assert_calls(&self, expected: &[&str]) {
+        let calls: Vec<String> = self
+            .calls()
+            .iter()
+            .enumerate()
+            .map(|(index, call)| match expected.get(index) {
+                Some(expected) if !expected.contains('(') => call.method.to_string(),
+                _ => call.to_string(),
+            })
+            .collect();
+        assert_eq!(calls, expected, "unexpected call sequence");
+    }
+    #[doc = " Forgets recorded calls and scripted values."]
+    pub fn reset(&self) {
+        self.calls.borrow_mut().clear();
+        self.returns.borrow_mut().clear();
+    }
+}
+#[doc = " Recording mock of [`ChannelApi`]."]
+#[derive(Debug, Default)]
+pub struct MockChannel {
+    mock: Mock,
+}
+impl MockChannel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `get_system_object` call."]
+    pub fn get_system_object_returns(&self, value: Result<System, Error>) -> &Self {
+        self.mock.returns("get_system_object", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `stop` call."]
+    pub fn stop_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("stop", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_paused` call."]
+    pub fn set_paused_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_paused", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_paused` call."]
+    pub fn get_paused_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_paused", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_volume` call."]
+    pub fn set_volume_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_volume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_volume` call."]
+    pub fn get_volume_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_volume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_volume_ramp` call."]
+    pub fn set_volume_ramp_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_volume_ramp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_volume_ramp` call."]
+    pub fn get_volume_ramp_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_volume_ramp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_audibility` call."]
+    pub fn get_audibility_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_audibility", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_pitch` call."]
+    pub fn set_pitch_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_pitch", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_pitch` call."]
+    pub fn get_pitch_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_pitch", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mute` call."]
+    pub fn set_mute_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mute", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_mute` call."]
+    pub fn get_mute_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_mute", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_reverb_properties` call."]
+    pub fn set_reverb_properties_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_reverb_properties", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_reverb_properties` call."]
+    pub fn get_reverb_properties_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_reverb_properties", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_low_pass_gain` call."]
+    pub fn set_low_pass_gain_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_low_pass_gain", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_low_pass_gain` call."]
+    pub fn get_low_pass_gain_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_low_pass_gain", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mode` call."]
+    pub fn set_mode_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mode", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_mode` call."]
+    pub fn get_mode_returns(&self, value: Result<ffi::FMOD_MODE, Error>) -> &Self {
+        self.mock.returns("get_mode", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_callback` call."]
+    pub fn set_callback_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_callback", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `is_playing` call."]
+    pub fn is_playing_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("is_playing", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_pan` call."]
+    pub fn set_pan_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_pan", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mix_levels_output` call."]
+    pub fn set_mix_levels_output_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mix_levels_output", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mix_levels_input` call."]
+    pub fn set_mix_levels_input_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mix_levels_input", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mix_matrix` call."]
+    pub fn set_mix_matrix_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mix_matrix", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_mix_matrix` call."]
+    pub fn get_mix_matrix_returns(&self, value: Result<MixMatrix, Error>) -> &Self {
+        self.mock.returns("get_mix_matrix", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_dsp_clock` call."]
+    pub fn get_dsp_clock_returns(&self, value: Result<(u64, u64), Error>) -> &Self {
+        self.mock.returns("get_dsp_clock", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_delay` call."]
+    pub fn set_delay_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_delay", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_delay` call."]
+    pub fn get_delay_returns(&self, value: Result<(u64, u64, bool), Error>) -> &Self {
+        self.mock.returns("get_delay", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `add_fade_point` call."]
+    pub fn add_fade_point_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("add_fade_point", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_fade_point_ramp` call."]
+    pub fn set_fade_point_ramp_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_fade_point_ramp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `remove_fade_points` call."]
+    pub fn remove_fade_points_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("remove_fade_points", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_fade_points` call."]
+    pub fn get_fade_points_returns(&self, value: Result<Vec<(u64, f32)>, Error>) -> &Self {
+        self.mock.returns("get_fade_points", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_dsp` call."]
+    pub fn get_dsp_returns(&self, value: Result<Dsp, Error>) -> &Self {
+        self.mock.returns("get_dsp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `add_dsp` call."]
+    pub fn add_dsp_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("add_dsp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `remove_dsp` call."]
+    pub fn remove_dsp_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("remove_dsp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_ds_ps` call."]
+    pub fn get_num_ds_ps_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_ds_ps", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_dsp_index` call."]
+    pub fn set_dsp_index_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_dsp_index", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_dsp_index` call."]
+    pub fn get_dsp_index_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_dsp_index", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_attributes` call."]
+    pub fn set_3d_attributes_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_attributes` call."]
+    pub fn get_3d_attributes_returns(&self, value: Result<(Vector, Vector), Error>) -> &Self {
+        self.mock.returns("get_3d_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_min_max_distance` call."]
+    pub fn set_3d_min_max_distance_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_min_max_distance", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_min_max_distance` call."]
+    pub fn get_3d_min_max_distance_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_3d_min_max_distance", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_cone_settings` call."]
+    pub fn set_3d_cone_settings_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_cone_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_cone_settings` call."]
+    pub fn get_3d_cone_settings_returns(&self, value: Result<(f32, f32, f32), Error>) -> &Self {
+        self.mock.returns("get_3d_cone_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_cone_orientation` call."]
+    pub fn set_3d_cone_orientation_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_cone_orientation", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_cone_orientation` call."]
+    pub fn get_3d_cone_orientation_returns(&self, value: Result<Vector, Error>) -> &Self {
+        self.mock.returns("get_3d_cone_orientation", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_custom_rolloff` call."]
+    pub fn set_3d_custom_rolloff_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_custom_rolloff", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_custom_rolloff` call."]
+    pub fn get_3d_custom_rolloff_returns(&self, value: Result<Vec<Vector>, Error>) -> &Self {
+        self.mock.returns("get_3d_custom_rolloff", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_occlusion` call."]
+    pub fn set_3d_occlusion_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_occlusion", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_occlusion` call."]
+    pub fn get_3d_occlusion_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_3d_occlusion", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_spread` call."]
+    pub fn set_3d_spread_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_spread", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_spread` call."]
+    pub fn get_3d_spread_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_3d_spread", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_level` call."]
+    pub fn set_3d_level_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_level", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_level` call."]
+    pub fn get_3d_level_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_3d_level", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_doppler_level` call."]
+    pub fn set_3d_doppler_level_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_doppler_level", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_doppler_level` call."]
+    pub fn get_3d_doppler_level_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_3d_doppler_level", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_distance_filter` call."]
+    pub fn set_3d_distance_filter_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_distance_filter", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_distance_filter` call."]
+    pub fn get_3d_distance_filter_returns(&self, value: Result<(bool, f32, f32), Error>) -> &Self {
+        self.mock.returns("get_3d_distance_filter", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_frequency` call."]
+    pub fn set_frequency_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_frequency", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_frequency` call."]
+    pub fn get_frequency_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_frequency", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_priority` call."]
+    pub fn set_priority_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_priority", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_priority` call."]
+    pub fn get_priority_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_priority", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_position` call."]
+    pub fn set_position_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_position", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_position` call."]
+    pub fn get_position_returns(&self, value: Result<u32, Error>) -> &Self {
+        self.mock.returns("get_position", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_channel_group` call."]
+    pub fn set_channel_group_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_channel_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_channel_group` call."]
+    pub fn get_channel_group_returns(&self, value: Result<ChannelGroup, Error>) -> &Self {
+        self.mock.returns("get_channel_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_loop_count` call."]
+    pub fn set_loop_count_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_loop_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_loop_count` call."]
+    pub fn get_loop_count_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_loop_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_loop_points` call."]
+    pub fn set_loop_points_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_loop_points", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_loop_points` call."]
+    pub fn get_loop_points_returns(&self, value: Result<(u32, u32), Error>) -> &Self {
+        self.mock.returns("get_loop_points", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `is_virtual` call."]
+    pub fn is_virtual_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("is_virtual", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_current_sound` call."]
+    pub fn get_current_sound_returns(&self, value: Result<Sound, Error>) -> &Self {
+        self.mock.returns("get_current_sound", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_index` call."]
+    pub fn get_index_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_index", value);
+        self
+    }
+}
+impl Deref for MockChannel {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl ChannelApi for MockChannel {
+    fn get_system_object(&self) -> Result<System, Error> {
+        self.mock.call("get_system_object", &[])
+    }
+    fn stop(&self) -> Result<(), Error> {
+        self.mock.call_or("stop", &[], Ok(()))
+    }
+    fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        self.mock.call_or("set_paused", &[&paused], Ok(()))
+    }
+    fn get_paused(&self) -> Result<bool, Error> {
+        self.mock.call("get_paused", &[])
+    }
+    fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        self.mock.call_or("set_volume", &[&volume], Ok(()))
+    }
+    fn get_volume(&self) -> Result<f32, Error> {
+        self.mock.call("get_volume", &[])
+    }
+    fn set_volume_ramp(&self, ramp: bool) -> Result<(), Error> {
+        self.mock.call_or("set_volume_ramp", &[&ramp], Ok(()))
+    }
+    fn get_volume_ramp(&self) -> Result<bool, Error> {
+        self.mock.call("get_volume_ramp", &[])
+    }
+    fn get_audibility(&self) -> Result<f32, Error> {
+        self.mock.call("get_audibility", &[])
+    }
+    fn set_pitch(&self, pitch: f32) -> Result<(), Error> {
+        self.mock.call_or("set_pitch", &[&pitch], Ok(()))
+    }
+    fn get_pitch(&self) -> Result<f32, Error> {
+        self.mock.call("get_pitch", &[])
+    }
+    fn set_mute(&self, mute: bool) -> Result<(), Error> {
+        self.mock.call_or("set_mute", &[&mute], Ok(()))
+    }
+    fn get_mute(&self) -> Result<bool, Error> {
+        self.mock.call("get_mute", &[])
+    }
+    fn set_reverb_properties(&self, instance: i32, wet: f32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_reverb_properties", &[&instance, &wet], Ok(()))
+    }
+    fn get_reverb_properties(&self, instance: i32) -> Result<f32, Error> {
+        self.mock.call("get_reverb_properties", &[&instance])
+    }
+    fn set_low_pass_gain(&self, gain: f32) -> Result<(), Error> {
+        self.mock.call_or("set_low_pass_gain", &[&gain], Ok(()))
+    }
+    fn get_low_pass_gain(&self) -> Result<f32, Error> {
+        self.mock.call("get_low_pass_gain", &[])
+    }
+    fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error> {
+        let mode: ffi::FMOD_MODE = mode.into();
+        self.mock.call_or("set_mode", &[&mode], Ok(()))
+    }
+    fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error> {
+        self.mock.call("get_mode", &[])
+    }
+    fn set_callback(&self, callback: ffi::FMOD_CHANNELCONTROL_CALLBACK) -> Result<(), Error> {
+        self.mock.call_or("set_callback", &[&callback], Ok(()))
+    }
+    fn is_playing(&self) -> Result<bool, Error> {
+        self.mock.call("is_playing", &[])
+    }
+    fn set_pan(&self, pan: f32) -> Result<(), Error> {
+        self.mock.call_or("set_pan", &[&pan], Ok(()))
+    }
+    fn set_mix_levels_output(&self, levels: &SpeakerLevels) -> Result<(), Error> {
+        self.mock
+            .call_or("set_mix_levels_output", &[&levels], Ok(()))
+    }
+    fn set_mix_levels_input(&self, levels: &[f32]) -> Result<(), Error> {
+        self.mock
+            .call_or("set_mix_levels_input", &[&levels], Ok(()))
+    }
+    fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error> {
+        self.mock.call_or("set_mix_matrix", &[&matrix], Ok(()))
+    }
+    fn get_mix_matrix(&self) -> Result<MixMatrix, Error> {
+        self.mock.call("get_mix_matrix", &[])
+    }
+    fn get_dsp_clock(&self) -> Result<(u64, u64), Error> {
+        self.mock.call("get_dsp_clock", &[])
+    }
+    fn set_delay(
+        &self,
+        dspclock_start: Option<u64>,
+        dspclock_end: Option<u64>,
+        stopchannels: bool,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_delay",
+            &[&dspclock_start, &dspclock_end, &stopchannels],
+            Ok(()),
+        )
+    }
+    fn get_delay(&self) -> Result<(u64, u64, bool), Error> {
+        self.mock.call("get_delay", &[])
+    }
+    fn add_fade_point(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
+        self.mock
+            .call_or("add_fade_point", &[&dspclock, &volume], Ok(()))
+    }
+    fn set_fade_point_ramp(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_fade_point_ramp", &[&dspclock, &volume], Ok(()))
+    }
+    fn remove_fade_points(&self, dspclock_start: u64, dspclock_end: u64) -> Result<(), Error> {
+        self.mock.call_or(
+            "remove_fade_points",
+            &[&dspclock_start, &dspclock_end],
+            Ok(()),
+        )
+    }
+    fn get_fade_points(&self) -> Result<Vec<(u64, f32)>, Error> {
+        self.mock.call("get_fade_points", &[])
+    }
+    fn get_dsp(&self, index: i32) -> Result<Dsp, Error> {
+        self.mock.call("get_dsp", &[&index])
+    }
+    fn add_dsp(&self, index: i32, dsp: Dsp) -> Result<(), Error> {
+        self.mock.call_or("add_dsp", &[&index, &dsp], Ok(()))
+    }
+    fn remove_dsp(&self, dsp: Dsp) -> Result<(), Error> {
+        self.mock.call_or("remove_dsp", &[&dsp], Ok(()))
+    }
+    fn get_num_ds_ps(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_ds_ps", &[])
+    }
+    fn set_dsp_index(&self, dsp: Dsp, index: i32) -> Result<(), Error> {
+        self.mock.call_or("set_dsp_index", &[&dsp, &index], Ok(()))
+    }
+    fn get_dsp_index(&self, dsp: Dsp) -> Result<i32, Error> {
+        self.mock.call("get_dsp_index", &[&dsp])
+    }
+    fn set_3d_attributes(&self, pos: Option<Vector>, vel: Option<Vector>) -> Result<(), Error> {
+        self.mock
+            .call_or("set_3d_attributes", &[&pos, &vel], Ok(()))
+    }
+    fn get_3d_attributes(&self) -> Result<(Vector, Vector), Error> {
+        self.mock.call("get_3d_attributes", &[])
+    }
+    fn set_3d_min_max_distance(&self, mindistance: f32, maxdistance: f32) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_3d_min_max_distance",
+            &[&mindistance, &maxdistance],
+            Ok(()),
+        )
+    }
+    fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        self.mock.call("get_3d_min_max_distance", &[])
+    }
+    fn set_3d_cone_settings(
+        &self,
+        insideconeangle: f32,
+        outsideconeangle: f32,
+        outsidevolume: f32,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_3d_cone_settings",
+            &[&insideconeangle, &outsideconeangle, &outsidevolume],
+            Ok(()),
+        )
+    }
+    fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error> {
+        self.mock.call("get_3d_cone_settings", &[])
+    }
+    fn set_3d_cone_orientation(&self, orientation: Vector) -> Result<(), Error> {
+        self.mock
+            .call_or("set_3d_cone_orientation", &[&orientation], Ok(()))
+    }
+    fn get_3d_cone_orientation(&self) -> Result<Vector, Error> {
+        self.mock.call("get_3d_cone_orientation", &[])
+    }
+    fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error> {
+        self.mock
+            .call_or("set_3d_custom_rolloff", &[&points], Ok(()))
+    }
+    fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error> {
+        self.mock.call("get_3d_custom_rolloff", &[])
+    }
+    fn set_3d_occlusion(&self, directocclusion: f32, reverbocclusion: f32) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_3d_occlusion",
+            &[&directocclusion, &reverbocclusion],
+            Ok(()),
+        )
+    }
+    fn get_3d_occlusion(&self) -> Result<(f32, f32), Error> {
+        self.mock.call("get_3d_occlusion", &[])
+    }
+    fn set_3d_spread(&self, angle: f32) -> Result<(), Error> {
+        self.mock.call_or("set_3d_spread", &[&angle], Ok(()))
+    }
+    fn get_3d_spread(&self) -> Result<f32, Error> {
+        self.mock.call("get_3d_spread", &[])
+    }
+    fn set_3d_level(&self, level: f32) -> Result<(), Error> {
+        self.mock.call_or("set_3d_level", &[&level], Ok(()))
+    }
+    fn get_3d_level(&self) -> Result<f32, Error> {
+        self.mock.call("get_3d_level", &[])
+    }
+    fn set_3d_doppler_level(&self, level: f32) -> Result<(), Error> {
+        self.mock.call_or("set_3d_doppler_level", &[&level], Ok(()))
+    }
+    fn get_3d_doppler_level(&self) -> Result<f32, Error> {
+        self.mock.call("get_3d_doppler_level", &[])
+    }
+    fn set_3d_distance_filter(
+        &self,
+        custom: bool,
+        custom_level: f32,
+        center_freq: Option<f32>,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_3d_distance_filter",
+            &[&custom, &custom_level, &center_freq],
+            Ok(()),
+        )
+    }
+    fn get_3d_distance_filter(&self) -> Result<(bool, f32, f32), Error> {
+        self.mock.call("get_3d_distance_filter", &[])
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+    fn set_frequency(&self, frequency: f32) -> Result<(), Error> {
+        self.mock.call_or("set_frequency", &[&frequency], Ok(()))
+    }
+    fn get_frequency(&self) -> Result<f32, Error> {
+        self.mock.call("get_frequency", &[])
+    }
+    fn set_priority(&self, priority: i32) -> Result<(), Error> {
+        self.mock.call_or("set_priority", &[&priority], Ok(()))
+    }
+    fn get_priority(&self) -> Result<i32, Error> {
+        self.mock.call("get_priority", &[])
+    }
+    fn set_position(
+        &self,
+        position: u32,
+        postype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(), Error> {
+        let postype: ffi::FMOD_TIMEUNIT = postype.into();
+        self.mock
+            .call_or("set_position", &[&position, &postype], Ok(()))
+    }
+    fn get_position(&self, postype: impl Into<ffi::FMOD_TIMEUNIT>) -> Result<u32, Error> {
+        let postype: ffi::FMOD_TIMEUNIT = postype.into();
+        self.mock.call("get_position", &[&postype])
+    }
+    fn set_channel_group(&self, channelgroup: ChannelGroup) -> Result<(), Error> {
+        self.mock
+            .call_or("set_channel_group", &[&channelgroup], Ok(()))
+    }
+    fn get_channel_group(&self) -> Result<ChannelGroup, Error> {
+        self.mock.call("get_channel_group", &[])
+    }
+    fn set_loop_count(&self, loopcount: i32) -> Result<(), Error> {
+        self.mock.call_or("set_loop_count", &[&loopcount], Ok(()))
+    }
+    fn get_loop_count(&self) -> Result<i32, Error> {
+        self.mock.call("get_loop_count", &[])
+    }
+    fn set_loop_points(
+        &self,
+        loopstart: u32,
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopend: u32,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(), Error> {
+        let loopstarttype: ffi::FMOD_TIMEUNIT = loopstarttype.into();
+        let loopendtype: ffi::FMOD_TIMEUNIT = loopendtype.into();
+        self.mock.call_or(
+            "set_loop_points",
+            &[&loopstart, &loopstarttype, &loopend, &loopendtype],
+            Ok(()),
+        )
+    }
+    fn get_loop_points(
+        &self,
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(u32, u32), Error> {
+        let loopstarttype: ffi::FMOD_TIMEUNIT = loopstarttype.into();
+        let loopendtype: ffi::FMOD_TIMEUNIT = loopendtype.into();
+        self.mock
+            .call("get_loop_points", &[&loopstarttype, &loopendtype])
+    }
+    fn is_virtual(&self) -> Result<bool, Error> {
+        self.mock.call("is_virtual", &[])
+    }
+    fn get_current_sound(&self) -> Result<Sound, Error> {
+        self.mock.call("get_current_sound", &[])
+    }
+    fn get_index(&self) -> Result<i32, Error> {
+        self.mock.call("get_index", &[])
+    }
+}
+#[doc = " Recording mock of [`ChannelGroupApi`]."]
+#[derive(Debug, Default)]
+pub struct MockChannelGroup {
+    mock: Mock,
+}
+impl MockChannelGroup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `get_system_object` call."]
+    pub fn get_system_object_returns(&self, value: Result<System, Error>) -> &Self {
+        self.mock.returns("get_system_object", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `stop` call."]
+    pub fn stop_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("stop", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_paused` call."]
+    pub fn set_paused_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_paused", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_paused` call."]
+    pub fn get_paused_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_paused", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_volume` call."]
+    pub fn set_volume_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_volume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_volume` call."]
+    pub fn get_volume_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_volume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_volume_ramp` call."]
+    pub fn set_volume_ramp_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_volume_ramp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_volume_ramp` call."]
+    pub fn get_volume_ramp_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_volume_ramp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_audibility` call."]
+    pub fn get_audibility_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_audibility", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_pitch` call."]
+    pub fn set_pitch_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_pitch", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_pitch` call."]
+    pub fn get_pitch_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_pitch", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mute` call."]
+    pub fn set_mute_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mute", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_mute` call."]
+    pub fn get_mute_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_mute", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_reverb_properties` call."]
+    pub fn set_reverb_properties_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_reverb_properties", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_reverb_properties` call."]
+    pub fn get_reverb_properties_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_reverb_properties", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_low_pass_gain` call."]
+    pub fn set_low_pass_gain_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_low_pass_gain", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_low_pass_gain` call."]
+    pub fn get_low_pass_gain_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_low_pass_gain", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mode` call."]
+    pub fn set_mode_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mode", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_mode` call."]
+    pub fn get_mode_returns(&self, value: Result<ffi::FMOD_MODE, Error>) -> &Self {
+        self.mock.returns("get_mode", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_callback` call."]
+    pub fn set_callback_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_callback", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `is_playing` call."]
+    pub fn is_playing_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("is_playing", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_pan` call."]
+    pub fn set_pan_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_pan", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mix_levels_output` call."]
+    pub fn set_mix_levels_output_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mix_levels_output", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mix_levels_input` call."]
+    pub fn set_mix_levels_input_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mix_levels_input", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mix_matrix` call."]
+    pub fn set_mix_matrix_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mix_matrix", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_mix_matrix` call."]
+    pub fn get_mix_matrix_returns(&self, value: Result<MixMatrix, Error>) -> &Self {
+        self.mock.returns("get_mix_matrix", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_dsp_clock` call."]
+    pub fn get_dsp_clock_returns(&self, value: Result<(u64, u64), Error>) -> &Self {
+        self.mock.returns("get_dsp_clock", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_delay` call."]
+    pub fn set_delay_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_delay", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_delay` call."]
+    pub fn get_delay_returns(&self, value: Result<(u64, u64, bool), Error>) -> &Self {
+        self.mock.returns("get_delay", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `add_fade_point` call."]
+    pub fn add_fade_point_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("add_fade_point", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_fade_point_ramp` call."]
+    pub fn set_fade_point_ramp_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_fade_point_ramp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `remove_fade_points` call."]
+    pub fn remove_fade_points_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("remove_fade_points", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_fade_points` call."]
+    pub fn get_fade_points_returns(&self, value: Result<Vec<(u64, f32)>, Error>) -> &Self {
+        self.mock.returns("get_fade_points", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_dsp` call."]
+    pub fn get_dsp_returns(&self, value: Result<Dsp, Error>) -> &Self {
+        self.mock.returns("get_dsp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `add_dsp` call."]
+    pub fn add_dsp_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("add_dsp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `remove_dsp` call."]
+    pub fn remove_dsp_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("remove_dsp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_ds_ps` call."]
+    pub fn get_num_ds_ps_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_ds_ps", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_dsp_index` call."]
+    pub fn set_dsp_index_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_dsp_index", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_dsp_index` call."]
+    pub fn get_dsp_index_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_dsp_index", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_attributes` call."]
+    pub fn set_3d_attributes_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_attributes` call."]
+    pub fn get_3d_attributes_returns(&self, value: Result<(Vector, Vector), Error>) -> &Self {
+        self.mock.returns("get_3d_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_min_max_distance` call."]
+    pub fn set_3d_min_max_distance_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_min_max_distance", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_min_max_distance` call."]
+    pub fn get_3d_min_max_distance_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_3d_min_max_distance", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_cone_settings` call."]
+    pub fn set_3d_cone_settings_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_cone_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_cone_settings` call."]
+    pub fn get_3d_cone_settings_returns(&self, value: Result<(f32, f32, f32), Error>) -> &Self {
+        self.mock.returns("get_3d_cone_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_cone_orientation` call."]
+    pub fn set_3d_cone_orientation_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_cone_orientation", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_cone_orientation` call."]
+    pub fn get_3d_cone_orientation_returns(&self, value: Result<Vector, Error>) -> &Self {
+        self.mock.returns("get_3d_cone_orientation", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_custom_rolloff` call."]
+    pub fn set_3d_custom_rolloff_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_custom_rolloff", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_custom_rolloff` call."]
+    pub fn get_3d_custom_rolloff_returns(&self, value: Result<Vec<Vector>, Error>) -> &Self {
+        self.mock.returns("get_3d_custom_rolloff", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_occlusion` call."]
+    pub fn set_3d_occlusion_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_occlusion", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_occlusion` call."]
+    pub fn get_3d_occlusion_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_3d_occlusion", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_spread` call."]
+    pub fn set_3d_spread_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_spread", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_spread` call."]
+    pub fn get_3d_spread_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_3d_spread", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_level` call."]
+    pub fn set_3d_level_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_level", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_level` call."]
+    pub fn get_3d_level_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_3d_level", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_doppler_level` call."]
+    pub fn set_3d_doppler_level_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_doppler_level", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_doppler_level` call."]
+    pub fn get_3d_doppler_level_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_3d_doppler_level", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_distance_filter` call."]
+    pub fn set_3d_distance_filter_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_distance_filter", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_distance_filter` call."]
+    pub fn get_3d_distance_filter_returns(&self, value: Result<(bool, f32, f32), Error>) -> &Self {
+        self.mock.returns("get_3d_distance_filter", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `release` call."]
+    pub fn release_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("release", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `add_group` call."]
+    pub fn add_group_returns(&self, value: Result<DspConnection, Error>) -> &Self {
+        self.mock.returns("add_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_groups` call."]
+    pub fn get_num_groups_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_groups", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_group` call."]
+    pub fn get_group_returns(&self, value: Result<ChannelGroup, Error>) -> &Self {
+        self.mock.returns("get_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parent_group` call."]
+    pub fn get_parent_group_returns(&self, value: Result<ChannelGroup, Error>) -> &Self {
+        self.mock.returns("get_parent_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_name` call."]
+    pub fn get_name_returns(&self, value: Result<String, Error>) -> &Self {
+        self.mock.returns("get_name", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_channels` call."]
+    pub fn get_num_channels_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_channels", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_channel` call."]
+    pub fn get_channel_returns(&self, value: Result<Channel, Error>) -> &Self {
+        self.mock.returns("get_channel", value);
+        self
+    }
+}
+impl Deref for MockChannelGroup {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl ChannelGroupApi for MockChannelGroup {
+    fn get_system_object(&self) -> Result<System, Error> {
+        self.mock.call("get_system_object", &[])
+    }
+    fn stop(&self) -> Result<(), Error> {
+        self.mock.call_or("stop", &[], Ok(()))
+    }
+    fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        self.mock.call_or("set_paused", &[&paused], Ok(()))
+    }
+    fn get_paused(&self) -> Result<bool, Error> {
+        self.mock.call("get_paused", &[])
+    }
+    fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        self.mock.call_or("set_volume", &[&volume], Ok(()))
+    }
+    fn get_volume(&self) -> Result<f32, Error> {
+        self.mock.call("get_volume", &[])
+    }
+    fn set_volume_ramp(&self, ramp: bool) -> Result<(), Error> {
+        self.mock.call_or("set_volume_ramp", &[&ramp], Ok(()))
+    }
+    fn get_volume_ramp(&self) -> Result<bool, Error> {
+        self.mock.call("get_volume_ramp", &[])
+    }
+    fn get_audibility(&self) -> Result<f32, Error> {
+        self.mock.call("get_audibility", &[])
+    }
+    fn set_pitch(&self, pitch: f32) -> Result<(), Error> {
+        self.mock.call_or("set_pitch", &[&pitch], Ok(()))
+    }
+    fn get_pitch(&self) -> Result<f32, Error> {
+        self.mock.call("get_pitch", &[])
+    }
+    fn set_mute(&self, mute: bool) -> Result<(), Error> {
+        self.mock.call_or("set_mute", &[&mute], Ok(()))
+    }
+    fn get_mute(&self) -> Result<bool, Error> {
+        self.mock.call("get_mute", &[])
+    }
+    fn set_reverb_properties(&self, instance: i32, wet: f32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_reverb_properties", &[&instance, &wet], Ok(()))
+    }
+    fn get_reverb_properties(&self, instance: i32) -> Result<f32, Error> {
+        self.mock.call("get_reverb_properties", &[&instance])
+    }
+    fn set_low_pass_gain(&self, gain: f32) -> Result<(), Error> {
+        self.mock.call_or("set_low_pass_gain", &[&gain], Ok(()))
+    }
+    fn get_low_pass_gain(&self) -> Result<f32, Error> {
+        self.mock.call("get_low_pass_gain", &[])
+    }
+    fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error> {
+        let mode: ffi::FMOD_MODE = mode.into();
+        self.mock.call_or("set_mode", &[&mode], Ok(()))
+    }
+    fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error> {
+        self.mock.call("get_mode", &[])
+    }
+    fn set_callback(&self, callback: ffi::FMOD_CHANNELCONTROL_CALLBACK) -> Result<(), Error> {
+        self.mock.call_or("set_callback", &[&callback], Ok(()))
+    }
+    fn is_playing(&self) -> Result<bool, Error> {
+        self.mock.call("is_playing", &[])
+    }
+    fn set_pan(&self, pan: f32) -> Result<(), Error> {
+        self.mock.call_or("set_pan", &[&pan], Ok(()))
+    }
+    fn set_mix_levels_output(&self, levels: &SpeakerLevels) -> Result<(), Error> {
+        self.mock
+            .call_or("set_mix_levels_output", &[&levels], Ok(()))
+    }
+    fn set_mix_levels_input(&self, levels: &[f32]) -> Result<(), Error> {
+        self.mock
+            .call_or("set_mix_levels_input", &[&levels], Ok(()))
+    }
+    fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error> {
+        self.mock.call_or("set_mix_matrix", &[&matrix], Ok(()))
+    }
+    fn get_mix_matrix(&self) -> Result<MixMatrix, Error> {
+        self.mock.call("get_mix_matrix", &[])
+    }
+    fn get_dsp_clock(&self) -> Result<(u64, u64), Error> {
+        self.mock.call("get_dsp_clock", &[])
+    }
+    fn set_delay(
+        &self,
+        dspclock_start: Option<u64>,
+        dspclock_end: Option<u64>,
+        stopchannels: bool,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_delay",
+            &[&dspclock_start, &dspclock_end, &stopchannels],
+            Ok(()),
+        )
+    }
+    fn get_delay(&self) -> Result<(u64, u64, bool), Error> {
+        self.mock.call("get_delay", &[])
+    }
+    fn add_fade_point(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
+        self.mock
+            .call_or("add_fade_point", &[&dspclock, &volume], Ok(()))
+    }
+    fn set_fade_point_ramp(&self, dspclock: u64, volume: f32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_fade_point_ramp", &[&dspclock, &volume], Ok(()))
+    }
+    fn remove_fade_points(&self, dspclock_start: u64, dspclock_end: u64) -> Result<(), Error> {
+        self.mock.call_or(
+            "remove_fade_points",
+            &[&dspclock_start, &dspclock_end],
+            Ok(()),
+        )
+    }
+    fn get_fade_points(&self) -> Result<Vec<(u64, f32)>, Error> {
+        self.mock.call("get_fade_points", &[])
+    }
+    fn get_dsp(&self, index: i32) -> Result<Dsp, Error> {
+        self.mock.call("get_dsp", &[&index])
+    }
+    fn add_dsp(&self, index: i32, dsp: Dsp) -> Result<(), Error> {
+        self.mock.call_or("add_dsp", &[&index, &dsp], Ok(()))
+    }
+    fn remove_dsp(&self, dsp: Dsp) -> Result<(), Error> {
+        self.mock.call_or("remove_dsp", &[&dsp], Ok(()))
+    }
+    fn get_num_ds_ps(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_ds_ps", &[])
+    }
+    fn set_dsp_index(&self, dsp: Dsp, index: i32) -> Result<(), Error> {
+        self.mock.call_or("set_dsp_index", &[&dsp, &index], Ok(()))
+    }
+    fn get_dsp_index(&self, dsp: Dsp) -> Result<i32, Error> {
+        self.mock.call("get_dsp_index", &[&dsp])
+    }
+    fn set_3d_attributes(&self, pos: Option<Vector>, vel: Option<Vector>) -> Result<(), Error> {
+        self.mock
+            .call_or("set_3d_attributes", &[&pos, &vel], Ok(()))
+    }
+    fn get_3d_attributes(&self) -> Result<(Vector, Vector), Error> {
+        self.mock.call("get_3d_attributes", &[])
+    }
+    fn set_3d_min_max_distance(&self, mindistance: f32, maxdistance: f32) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_3d_min_max_distance",
+            &[&mindistance, &maxdistance],
+            Ok(()),
+        )
+    }
+    fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        self.mock.call("get_3d_min_max_distance", &[])
+    }
+    fn set_3d_cone_settings(
+        &self,
+        insideconeangle: f32,
+        outsideconeangle: f32,
+        outsidevolume: f32,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_3d_cone_settings",
+            &[&insideconeangle, &outsideconeangle, &outsidevolume],
+            Ok(()),
+        )
+    }
+    fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error> {
+        self.mock.call("get_3d_cone_settings", &[])
+    }
+    fn set_3d_cone_orientation(&self, orientation: Vector) -> Result<(), Error> {
+        self.mock
+            .call_or("set_3d_cone_orientation", &[&orientation], Ok(()))
+    }
+    fn get_3d_cone_orientation(&self) -> Result<Vector, Error> {
+        self.mock.call("get_3d_cone_orientation", &[])
+    }
+    fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error> {
+        self.mock
+            .call_or("set_3d_custom_rolloff", &[&points], Ok(()))
+    }
+    fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error> {
+        self.mock.call("get_3d_custom_rolloff", &[])
+    }
+    fn set_3d_occlusion(&self, directocclusion: f32, reverbocclusion: f32) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_3d_occlusion",
+            &[&directocclusion, &reverbocclusion],
+            Ok(()),
+        )
+    }
+    fn get_3d_occlusion(&self) -> Result<(f32, f32), Error> {
+        self.mock.call("get_3d_occlusion", &[])
+    }
+    fn set_3d_spread(&self, angle: f32) -> Result<(), Error> {
+        self.mock.call_or("set_3d_spread", &[&angle], Ok(()))
+    }
+    fn get_3d_spread(&self) -> Result<f32, Error> {
+        self.mock.call("get_3d_spread", &[])
+    }
+    fn set_3d_level(&self, level: f32) -> Result<(), Error> {
+        self.mock.call_or("set_3d_level", &[&level], Ok(()))
+    }
+    fn get_3d_level(&self) -> Result<f32, Error> {
+        self.mock.call("get_3d_level", &[])
+    }
+    fn set_3d_doppler_level(&self, level: f32) -> Result<(), Error> {
+        self.mock.call_or("set_3d_doppler_level", &[&level], Ok(()))
+    }
+    fn get_3d_doppler_level(&self) -> Result<f32, Error> {
+        self.mock.call("get_3d_doppler_level", &[])
+    }
+    fn set_3d_distance_filter(
+        &self,
+        custom: bool,
+        custom_level: f32,
+        center_freq: Option<f32>,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_3d_distance_filter",
+            &[&custom, &custom_level, &center_freq],
+            Ok(()),
+        )
+    }
+    fn get_3d_distance_filter(&self) -> Result<(bool, f32, f32), Error> {
+        self.mock.call("get_3d_distance_filter", &[])
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+    fn release(&self) -> Result<(), Error> {
+        self.mock.call_or("release", &[], Ok(()))
+    }
+    fn add_group(
+        &self,
+        group: ChannelGroup,
+        propagatedspclock: bool,
+    ) -> Result<DspConnection, Error> {
+        self.mock.call("add_group", &[&group, &propagatedspclock])
+    }
+    fn get_num_groups(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_groups", &[])
+    }
+    fn get_group(&self, index: i32) -> Result<ChannelGroup, Error> {
+        self.mock.call("get_group", &[&index])
+    }
+    fn get_parent_group(&self) -> Result<ChannelGroup, Error> {
+        self.mock.call("get_parent_group", &[])
+    }
+    fn get_name(&self, namelen: i32) -> Result<String, Error> {
+        self.mock.call("get_name", &[&namelen])
+    }
+    fn get_num_channels(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_channels", &[])
+    }
+    fn get_channel(&self, index: i32) -> Result<Channel, Error> {
+        self.mock.call("get_channel", &[&index])
+    }
+}
+#[doc = " Recording mock of [`DspApi`]."]
+#[derive(Debug, Default)]
+pub struct MockDsp {
+    mock: Mock,
+}
+impl MockDsp {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `release` call."]
+    pub fn release_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("release", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_system_object` call."]
+    pub fn get_system_object_returns(&self, value: Result<System, Error>) -> &Self {
+        self.mock.returns("get_system_object", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `add_input` call."]
+    pub fn add_input_returns(&self, value: Result<DspConnection, Error>) -> &Self {
+        self.mock.returns("add_input", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `disconnect_from` call."]
+    pub fn disconnect_from_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("disconnect_from", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `disconnect_all` call."]
+    pub fn disconnect_all_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("disconnect_all", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_inputs` call."]
+    pub fn get_num_inputs_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_inputs", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_outputs` call."]
+    pub fn get_num_outputs_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_outputs", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_input` call."]
+    pub fn get_input_returns(&self, value: Result<(Dsp, DspConnection), Error>) -> &Self {
+        self.mock.returns("get_input", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_output` call."]
+    pub fn get_output_returns(&self, value: Result<(Dsp, DspConnection), Error>) -> &Self {
+        self.mock.returns("get_output", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_active` call."]
+    pub fn set_active_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_active", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_active` call."]
+    pub fn get_active_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_active", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_bypass` call."]
+    pub fn set_bypass_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_bypass", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_bypass` call."]
+    pub fn get_bypass_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_bypass", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_wet_dry_mix` call."]
+    pub fn set_wet_dry_mix_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_wet_dry_mix", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_wet_dry_mix` call."]
+    pub fn get_wet_dry_mix_returns(&self, value: Result<(f32, f32, f32), Error>) -> &Self {
+        self.mock.returns("get_wet_dry_mix", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_channel_format` call."]
+    pub fn set_channel_format_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_channel_format", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_channel_format` call."]
+    pub fn get_channel_format_returns(
+        &self,
+        value: Result<(ffi::FMOD_CHANNELMASK, i32, SpeakerMode), Error>,
+    ) -> &Self {
+        self.mock.returns("get_channel_format", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_output_channel_format` call."]
+    pub fn get_output_channel_format_returns(
+        &self,
+        value: Result<(ffi::FMOD_CHANNELMASK, i32, SpeakerMode), Error>,
+    ) -> &Self {
+        self.mock.returns("get_output_channel_format", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `reset` call."]
+    pub fn reset_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("reset", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_callback` call."]
+    pub fn set_callback_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_callback", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameter_float` call."]
+    pub fn set_parameter_float_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameter_float", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameter_int` call."]
+    pub fn set_parameter_int_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameter_int", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameter_bool` call."]
+    pub fn set_parameter_bool_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameter_bool", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameter_data` call."]
+    pub fn set_parameter_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameter_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_float` call."]
+    pub fn get_parameter_float_returns(&self, value: Result<(f32, String), Error>) -> &Self {
+        self.mock.returns("get_parameter_float", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_int` call."]
+    pub fn get_parameter_int_returns(&self, value: Result<(i32, String), Error>) -> &Self {
+        self.mock.returns("get_parameter_int", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_bool` call."]
+    pub fn get_parameter_bool_returns(&self, value: Result<(bool, String), Error>) -> &Self {
+        self.mock.returns("get_parameter_bool", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_data` call."]
+    pub fn get_parameter_data_returns(
+        &self,
+        value: Result<(*mut c_void, u32, String), Error>,
+    ) -> &Self {
+        self.mock.returns("get_parameter_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_parameters` call."]
+    pub fn get_num_parameters_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_parameters", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_info` call."]
+    pub fn get_parameter_info_returns(&self, value: Result<DspParameterDesc, Error>) -> &Self {
+        self.mock.returns("get_parameter_info", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_data_parameter_index` call."]
+    pub fn get_data_parameter_index_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_data_parameter_index", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `show_config_dialog` call."]
+    pub fn show_config_dialog_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("show_config_dialog", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_info` call."]
+    pub fn get_info_returns(&self, value: Result<(String, u32, i32, i32, i32), Error>) -> &Self {
+        self.mock.returns("get_info", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_type` call."]
+    pub fn get_type_returns(&self, value: Result<DspType, Error>) -> &Self {
+        self.mock.returns("get_type", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_idle` call."]
+    pub fn get_idle_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_idle", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_metering_enabled` call."]
+    pub fn set_metering_enabled_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_metering_enabled", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_metering_enabled` call."]
+    pub fn get_metering_enabled_returns(&self, value: Result<(bool, bool), Error>) -> &Self {
+        self.mock.returns("get_metering_enabled", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_metering_info` call."]
+    pub fn get_metering_info_returns(
+        &self,
+        value: Result<(DspMeteringInfo, DspMeteringInfo), Error>,
+    ) -> &Self {
+        self.mock.returns("get_metering_info", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_cpu_usage` call."]
+    pub fn get_cpu_usage_returns(&self, value: Result<(u32, u32), Error>) -> &Self {
+        self.mock.returns("get_cpu_usage", value);
+        self
+    }
+}
+impl Deref for MockDsp {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl DspApi for MockDsp {
+    fn release(&self) -> Result<(), Error> {
+        self.mock.call_or("release", &[], Ok(()))
+    }
+    fn get_system_object(&self) -> Result<System, Error> {
+        self.mock.call("get_system_object", &[])
+    }
+    fn add_input(&self, input: Dsp, type_: DspConnectionType) -> Result<DspConnection, Error> {
+        self.mock.call("add_input", &[&input, &type_])
+    }
+    fn disconnect_from(
+        &self,
+        target: Option<Dsp>,
+        connection: Option<DspConnection>,
+    ) -> Result<(), Error> {
+        self.mock
+            .call_or("disconnect_from", &[&target, &connection], Ok(()))
+    }
+    fn disconnect_all(&self, inputs: bool, outputs: bool) -> Result<(), Error> {
+        self.mock
+            .call_or("disconnect_all", &[&inputs, &outputs], Ok(()))
+    }
+    fn get_num_inputs(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_inputs", &[])
+    }
+    fn get_num_outputs(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_outputs", &[])
+    }
+    fn get_input(&self, index: i32) -> Result<(Dsp, DspConnection), Error> {
+        self.mock.call("get_input", &[&index])
+    }
+    fn get_output(&self, index: i32) -> Result<(Dsp, DspConnection), Error> {
+        self.mock.call("get_output", &[&index])
+    }
+    fn set_active(&self, active: bool) -> Result<(), Error> {
+        self.mock.call_or("set_active", &[&active], Ok(()))
+    }
+    fn get_active(&self) -> Result<bool, Error> {
+        self.mock.call("get_active", &[])
+    }
+    fn set_bypass(&self, bypass: bool) -> Result<(), Error> {
+        self.mock.call_or("set_bypass", &[&bypass], Ok(()))
+    }
+    fn get_bypass(&self) -> Result<bool, Error> {
+        self.mock.call("get_bypass", &[])
+    }
+    fn set_wet_dry_mix(&self, prewet: f32, postwet: f32, dry: f32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_wet_dry_mix", &[&prewet, &postwet, &dry], Ok(()))
+    }
+    fn get_wet_dry_mix(&self) -> Result<(f32, f32, f32), Error> {
+        self.mock.call("get_wet_dry_mix", &[])
+    }
+    fn set_channel_format(
+        &self,
+        channelmask: impl Into<ffi::FMOD_CHANNELMASK>,
+        numchannels: i32,
+        source_speakermode: SpeakerMode,
+    ) -> Result<(), Error> {
+        let channelmask: ffi::FMOD_CHANNELMASK = channelmask.into();
+        self.mock.call_or(
+            "set_channel_format",
+            &[&channelmask, &numchannels, &source_speakermode],
+            Ok(()),
+        )
+    }
+    fn get_channel_format(&self) -> Result<(ffi::FMOD_CHANNELMASK, i32, SpeakerMode), Error> {
+        self.mock.call("get_channel_format", &[])
+    }
+    fn get_output_channel_format(
+        &self,
+        inmask: impl Into<ffi::FMOD_CHANNELMASK>,
+        inchannels: i32,
+        inspeakermode: SpeakerMode,
+    ) -> Result<(ffi::FMOD_CHANNELMASK, i32, SpeakerMode), Error> {
+        let inmask: ffi::FMOD_CHANNELMASK = inmask.into();
+        self.mock.call(
+            "get_output_channel_format",
+            &[&inmask, &inchannels, &inspeakermode],
+        )
+    }
+    fn reset(&self) -> Result<(), Error> {
+        self.mock.call_or("reset", &[], Ok(()))
+    }
+    fn set_callback(&self, callback: ffi::FMOD_DSP_CALLBACK) -> Result<(), Error> {
+        self.mock.call_or("set_callback", &[&callback], Ok(()))
+    }
+    fn set_parameter_float(&self, index: i32, value: f32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_parameter_float", &[&index, &value], Ok(()))
+    }
+    fn set_parameter_int(&self, index: i32, value: i32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_parameter_int", &[&index, &value], Ok(()))
+    }
+    fn set_parameter_bool(&self, index: i32, value: bool) -> Result<(), Error> {
+        self.mock
+            .call_or("set_parameter_bool", &[&index, &value], Ok(()))
+    }
+    fn set_parameter_data(&self, index: i32, data: *mut c_void, length: u32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_parameter_data", &[&index, &data, &length], Ok(()))
+    }
+    fn get_parameter_float(&self, index: i32, valuestrlen: i32) -> Result<(f32, String), Error> {
+        self.mock
+            .call("get_parameter_float", &[&index, &valuestrlen])
+    }
+    fn get_parameter_int(&self, index: i32, valuestrlen: i32) -> Result<(i32, String), Error> {
+        self.mock.call("get_parameter_int", &[&index, &valuestrlen])
+    }
+    fn get_parameter_bool(&self, index: i32, valuestrlen: i32) -> Result<(bool, String), Error> {
+        self.mock
+            .call("get_parameter_bool", &[&index, &valuestrlen])
+    }
+    fn get_parameter_data(
+        &self,
+        index: i32,
+        valuestrlen: i32,
+    ) -> Result<(*mut c_void, u32, String), Error> {
+        self.mock
+            .call("get_parameter_data", &[&index, &valuestrlen])
+    }
+    fn get_num_parameters(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_parameters", &[])
+    }
+    fn get_parameter_info(&self, index: i32) -> Result<DspParameterDesc, Error> {
+        self.mock.call("get_parameter_info", &[&index])
+    }
+    fn get_data_parameter_index(&self, datatype: i32) -> Result<i32, Error> {
+        self.mock.call("get_data_parameter_index", &[&datatype])
+    }
+    fn show_config_dialog(&self, hwnd: *mut c_void, show: bool) -> Result<(), Error> {
+        self.mock
+            .call_or("show_config_dialog", &[&hwnd, &show], Ok(()))
+    }
+    fn get_info(&self) -> Result<(String, u32, i32, i32, i32), Error> {
+        self.mock.call("get_info", &[])
+    }
+    fn get_type(&self) -> Result<DspType, Error> {
+        self.mock.call("get_type", &[])
+    }
+    fn get_idle(&self) -> Result<bool, Error> {
+        self.mock.call("get_idle", &[])
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+    fn set_metering_enabled(&self, input_enabled: bool, output_enabled: bool) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_metering_enabled",
+            &[&input_enabled, &output_enabled],
+            Ok(()),
+        )
+    }
+    fn get_metering_enabled(&self) -> Result<(bool, bool), Error> {
+        self.mock.call("get_metering_enabled", &[])
+    }
+    fn get_metering_info(&self) -> Result<(DspMeteringInfo, DspMeteringInfo), Error> {
+        self.mock.call("get_metering_info", &[])
+    }
+    fn get_cpu_usage(&self) -> Result<(u32, u32), Error> {
+        self.mock.call("get_cpu_usage", &[])
+    }
+}
+#[doc = " Recording mock of [`DspConnectionApi`]."]
+#[derive(Debug, Default)]
+pub struct MockDspConnection {
+    mock: Mock,
+}
+impl MockDspConnection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `get_input` call."]
+    pub fn get_input_returns(&self, value: Result<Dsp, Error>) -> &Self {
+        self.mock.returns("get_input", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_output` call."]
+    pub fn get_output_returns(&self, value: Result<Dsp, Error>) -> &Self {
+        self.mock.returns("get_output", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mix` call."]
+    pub fn set_mix_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mix", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_mix` call."]
+    pub fn get_mix_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_mix", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mix_matrix` call."]
+    pub fn set_mix_matrix_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mix_matrix", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_mix_matrix` call."]
+    pub fn get_mix_matrix_returns(&self, value: Result<MixMatrix, Error>) -> &Self {
+        self.mock.returns("get_mix_matrix", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_type` call."]
+    pub fn get_type_returns(&self, value: Result<DspConnectionType, Error>) -> &Self {
+        self.mock.returns("get_type", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+}
+impl Deref for MockDspConnection {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl DspConnectionApi for MockDspConnection {
+    fn get_input(&self) -> Result<Dsp, Error> {
+        self.mock.call("get_input", &[])
+    }
+    fn get_output(&self) -> Result<Dsp, Error> {
+        self.mock.call("get_output", &[])
+    }
+    fn set_mix(&self, volume: f32) -> Result<(), Error> {
+        self.mock.call_or("set_mix", &[&volume], Ok(()))
+    }
+    fn get_mix(&self) -> Result<f32, Error> {
+        self.mock.call("get_mix", &[])
+    }
+    fn set_mix_matrix(&self, matrix: &MixMatrix) -> Result<(), Error> {
+        self.mock.call_or("set_mix_matrix", &[&matrix], Ok(()))
+    }
+    fn get_mix_matrix(&self) -> Result<MixMatrix, Error> {
+        self.mock.call("get_mix_matrix", &[])
+    }
+    fn get_type(&self) -> Result<DspConnectionType, Error> {
+        self.mock.call("get_type", &[])
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+}
+#[doc = " Recording mock of [`GeometryApi`]."]
+#[derive(Debug, Default)]
+pub struct MockGeometry {
+    mock: Mock,
+}
+impl MockGeometry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `release` call."]
+    pub fn release_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("release", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `add_polygon` call."]
+    pub fn add_polygon_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("add_polygon", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_polygons` call."]
+    pub fn get_num_polygons_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_polygons", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_max_polygons` call."]
+    pub fn get_max_polygons_returns(&self, value: Result<(i32, i32), Error>) -> &Self {
+        self.mock.returns("get_max_polygons", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_polygon_num_vertices` call."]
+    pub fn get_polygon_num_vertices_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_polygon_num_vertices", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_polygon_vertex` call."]
+    pub fn set_polygon_vertex_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_polygon_vertex", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_polygon_vertex` call."]
+    pub fn get_polygon_vertex_returns(&self, value: Result<Vector, Error>) -> &Self {
+        self.mock.returns("get_polygon_vertex", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_polygon_attributes` call."]
+    pub fn set_polygon_attributes_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_polygon_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_polygon_attributes` call."]
+    pub fn get_polygon_attributes_returns(&self, value: Result<(f32, f32, bool), Error>) -> &Self {
+        self.mock.returns("get_polygon_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_active` call."]
+    pub fn set_active_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_active", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_active` call."]
+    pub fn get_active_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_active", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_rotation` call."]
+    pub fn set_rotation_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_rotation", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_rotation` call."]
+    pub fn get_rotation_returns(&self, value: Result<(Vector, Vector), Error>) -> &Self {
+        self.mock.returns("get_rotation", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_position` call."]
+    pub fn set_position_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_position", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_position` call."]
+    pub fn get_position_returns(&self, value: Result<Vector, Error>) -> &Self {
+        self.mock.returns("get_position", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_scale` call."]
+    pub fn set_scale_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_scale", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_scale` call."]
+    pub fn get_scale_returns(&self, value: Result<Vector, Error>) -> &Self {
+        self.mock.returns("get_scale", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `to_bytes` call."]
+    pub fn to_bytes_returns(&self, value: Result<Vec<u8>, Error>) -> &Self {
+        self.mock.returns("to_bytes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+}
+impl Deref for MockGeometry {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl GeometryApi for MockGeometry {
+    fn release(&self) -> Result<(), Error> {
+        self.mock.call_or("release", &[], Ok(()))
+    }
+    fn add_polygon(
+        &self,
+        directocclusion: f32,
+        reverbocclusion: f32,
+        doublesided: bool,
+        vertices: &[Vector],
+    ) -> Result<i32, Error> {
+        self.mock.call(
+            "add_polygon",
+            &[&directocclusion, &reverbocclusion, &doublesided, &vertices],
+        )
+    }
+    fn get_num_polygons(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_polygons", &[])
+    }
+    fn get_max_polygons(&self) -> Result<(i32, i32), Error> {
+        self.mock.call("get_max_polygons", &[])
+    }
+    fn get_polygon_num_vertices(&self, index: i32) -> Result<i32, Error> {
+        self.mock.call("get_polygon_num_vertices", &[&index])
+    }
+    fn set_polygon_vertex(
+        &self,
+        index: i32,
+        vertexindex: i32,
+        vertex: Vector,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_polygon_vertex",
+            &[&index, &vertexindex, &vertex],
+            Ok(()),
+        )
+    }
+    fn get_polygon_vertex(&self, index: i32, vertexindex: i32) -> Result<Vector, Error> {
+        self.mock
+            .call("get_polygon_vertex", &[&index, &vertexindex])
+    }
+    fn set_polygon_attributes(
+        &self,
+        index: i32,
+        directocclusion: f32,
+        reverbocclusion: f32,
+        doublesided: bool,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_polygon_attributes",
+            &[&index, &directocclusion, &reverbocclusion, &doublesided],
+            Ok(()),
+        )
+    }
+    fn get_polygon_attributes(&self, index: i32) -> Result<(f32, f32, bool), Error> {
+        self.mock.call("get_polygon_attributes", &[&index])
+    }
+    fn set_active(&self, active: bool) -> Result<(), Error> {
+        self.mock.call_or("set_active", &[&active], Ok(()))
+    }
+    fn get_active(&self) -> Result<bool, Error> {
+        self.mock.call("get_active", &[])
+    }
+    fn set_rotation(&self, forward: Option<Vector>, up: Option<Vector>) -> Result<(), Error> {
+        self.mock.call_or("set_rotation", &[&forward, &up], Ok(()))
+    }
+    fn get_rotation(&self) -> Result<(Vector, Vector), Error> {
+        self.mock.call("get_rotation", &[])
+    }
+    fn set_position(&self, position: Vector) -> Result<(), Error> {
+        self.mock.call_or("set_position", &[&position], Ok(()))
+    }
+    fn get_position(&self) -> Result<Vector, Error> {
+        self.mock.call("get_position", &[])
+    }
+    fn set_scale(&self, scale: Vector) -> Result<(), Error> {
+        self.mock.call_or("set_scale", &[&scale], Ok(()))
+    }
+    fn get_scale(&self) -> Result<Vector, Error> {
+        self.mock.call("get_scale", &[])
+    }
+    fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.mock.call("to_bytes", &[])
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+}
+#[doc = " Recording mock of [`Reverb3dApi`]."]
+#[derive(Debug, Default)]
+pub struct MockReverb3d {
+    mock: Mock,
+}
+impl MockReverb3d {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `release` call."]
+    pub fn release_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("release", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_attributes` call."]
+    pub fn set_3d_attributes_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_attributes` call."]
+    pub fn get_3d_attributes_returns(&self, value: Result<(Vector, f32, f32), Error>) -> &Self {
+        self.mock.returns("get_3d_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_properties` call."]
+    pub fn set_properties_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_properties", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_properties` call."]
+    pub fn get_properties_returns(&self, value: Result<ReverbProperties, Error>) -> &Self {
+        self.mock.returns("get_properties", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_active` call."]
+    pub fn set_active_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_active", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_active` call."]
+    pub fn get_active_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_active", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+}
+impl Deref for MockReverb3d {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl Reverb3dApi for MockReverb3d {
+    fn release(&self) -> Result<(), Error> {
+        self.mock.call_or("release", &[], Ok(()))
+    }
+    fn set_3d_attributes(
+        &self,
+        position: Option<Vector>,
+        mindistance: f32,
+        maxdistance: f32,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_3d_attributes",
+            &[&position, &mindistance, &maxdistance],
+            Ok(()),
+        )
+    }
+    fn get_3d_attributes(&self) -> Result<(Vector, f32, f32), Error> {
+        self.mock.call("get_3d_attributes", &[])
+    }
+    fn set_properties(&self, properties: ReverbProperties) -> Result<(), Error> {
+        self.mock.call_or("set_properties", &[&properties], Ok(()))
+    }
+    fn get_properties(&self) -> Result<ReverbProperties, Error> {
+        self.mock.call("get_properties", &[])
+    }
+    fn set_active(&self, active: bool) -> Result<(), Error> {
+        self.mock.call_or("set_active", &[&active], Ok(()))
+    }
+    fn get_active(&self) -> Result<bool, Error> {
+        self.mock.call("get_active", &[])
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+}
+#[doc = " Recording mock of [`SoundApi`]."]
+#[derive(Debug, Default)]
+pub struct MockSound {
+    mock: Mock,
+}
+impl MockSound {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `release` call."]
+    pub fn release_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("release", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_system_object` call."]
+    pub fn get_system_object_returns(&self, value: Result<System, Error>) -> &Self {
+        self.mock.returns("get_system_object", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `lock` call."]
+    pub fn lock_returns(
+        &self,
+        value: Result<(*mut c_void, *mut c_void, u32, u32), Error>,
+    ) -> &Self {
+        self.mock.returns("lock", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `unlock` call."]
+    pub fn unlock_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("unlock", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_defaults` call."]
+    pub fn set_defaults_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_defaults", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_defaults` call."]
+    pub fn get_defaults_returns(&self, value: Result<(f32, i32), Error>) -> &Self {
+        self.mock.returns("get_defaults", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_min_max_distance` call."]
+    pub fn set_3d_min_max_distance_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_min_max_distance", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_min_max_distance` call."]
+    pub fn get_3d_min_max_distance_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_3d_min_max_distance", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_cone_settings` call."]
+    pub fn set_3d_cone_settings_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_cone_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_cone_settings` call."]
+    pub fn get_3d_cone_settings_returns(&self, value: Result<(f32, f32, f32), Error>) -> &Self {
+        self.mock.returns("get_3d_cone_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_custom_rolloff` call."]
+    pub fn set_3d_custom_rolloff_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_custom_rolloff", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_custom_rolloff` call."]
+    pub fn get_3d_custom_rolloff_returns(&self, value: Result<Vec<Vector>, Error>) -> &Self {
+        self.mock.returns("get_3d_custom_rolloff", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_sub_sound` call."]
+    pub fn get_sub_sound_returns(&self, value: Result<Sound, Error>) -> &Self {
+        self.mock.returns("get_sub_sound", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_sub_sound_parent` call."]
+    pub fn get_sub_sound_parent_returns(&self, value: Result<Sound, Error>) -> &Self {
+        self.mock.returns("get_sub_sound_parent", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_name` call."]
+    pub fn get_name_returns(&self, value: Result<String, Error>) -> &Self {
+        self.mock.returns("get_name", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_length` call."]
+    pub fn get_length_returns(&self, value: Result<u32, Error>) -> &Self {
+        self.mock.returns("get_length", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_format` call."]
+    pub fn get_format_returns(
+        &self,
+        value: Result<(SoundType, SoundFormat, i32, i32), Error>,
+    ) -> &Self {
+        self.mock.returns("get_format", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_sub_sounds` call."]
+    pub fn get_num_sub_sounds_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_sub_sounds", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_tags` call."]
+    pub fn get_num_tags_returns(&self, value: Result<(i32, i32), Error>) -> &Self {
+        self.mock.returns("get_num_tags", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_tag` call."]
+    pub fn get_tag_returns(&self, value: Result<Tag, Error>) -> &Self {
+        self.mock.returns("get_tag", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_open_state` call."]
+    pub fn get_open_state_returns(
+        &self,
+        value: Result<(OpenState, u32, bool, bool), Error>,
+    ) -> &Self {
+        self.mock.returns("get_open_state", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `read_data` call."]
+    pub fn read_data_returns(&self, value: Result<u32, Error>) -> &Self {
+        self.mock.returns("read_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `seek_data` call."]
+    pub fn seek_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("seek_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_sound_group` call."]
+    pub fn set_sound_group_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_sound_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_sound_group` call."]
+    pub fn get_sound_group_returns(&self, value: Result<SoundGroup, Error>) -> &Self {
+        self.mock.returns("get_sound_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_sync_points` call."]
+    pub fn get_num_sync_points_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_sync_points", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_sync_point` call."]
+    pub fn get_sync_point_returns(&self, value: Result<SyncPoint, Error>) -> &Self {
+        self.mock.returns("get_sync_point", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_sync_point_info` call."]
+    pub fn get_sync_point_info_returns(&self, value: Result<(String, u32), Error>) -> &Self {
+        self.mock.returns("get_sync_point_info", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `add_sync_point` call."]
+    pub fn add_sync_point_returns(&self, value: Result<SyncPoint, Error>) -> &Self {
+        self.mock.returns("add_sync_point", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `delete_sync_point` call."]
+    pub fn delete_sync_point_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("delete_sync_point", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mode` call."]
+    pub fn set_mode_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mode", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_mode` call."]
+    pub fn get_mode_returns(&self, value: Result<ffi::FMOD_MODE, Error>) -> &Self {
+        self.mock.returns("get_mode", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_loop_count` call."]
+    pub fn set_loop_count_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_loop_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_loop_count` call."]
+    pub fn get_loop_count_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_loop_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_loop_points` call."]
+    pub fn set_loop_points_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_loop_points", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_loop_points` call."]
+    pub fn get_loop_points_returns(&self, value: Result<(u32, u32), Error>) -> &Self {
+        self.mock.returns("get_loop_points", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_music_num_channels` call."]
+    pub fn get_music_num_channels_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_music_num_channels", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_music_channel_volume` call."]
+    pub fn set_music_channel_volume_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_music_channel_volume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_music_channel_volume` call."]
+    pub fn get_music_channel_volume_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_music_channel_volume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_music_speed` call."]
+    pub fn set_music_speed_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_music_speed", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_music_speed` call."]
+    pub fn get_music_speed_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_music_speed", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+}
+impl Deref for MockSound {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl SoundApi for MockSound {
+    fn release(&self) -> Result<(), Error> {
+        self.mock.call_or("release", &[], Ok(()))
+    }
+    fn get_system_object(&self) -> Result<System, Error> {
+        self.mock.call("get_system_object", &[])
+    }
+    fn lock(
+        &self,
+        offset: u32,
+        length: u32,
+    ) -> Result<(*mut c_void, *mut c_void, u32, u32), Error> {
+        self.mock.call("lock", &[&offset, &length])
+    }
+    fn unlock(
+        &self,
+        ptr_1: *mut c_void,
+        ptr_2: *mut c_void,
+        len_1: u32,
+        len_2: u32,
+    ) -> Result<(), Error> {
+        self.mock
+            .call_or("unlock", &[&ptr_1, &ptr_2, &len_1, &len_2], Ok(()))
+    }
+    fn set_defaults(&self, frequency: f32, priority: i32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_defaults", &[&frequency, &priority], Ok(()))
+    }
+    fn get_defaults(&self) -> Result<(f32, i32), Error> {
+        self.mock.call("get_defaults", &[])
+    }
+    fn set_3d_min_max_distance(&self, min: f32, max: f32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_3d_min_max_distance", &[&min, &max], Ok(()))
+    }
+    fn get_3d_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        self.mock.call("get_3d_min_max_distance", &[])
+    }
+    fn set_3d_cone_settings(
+        &self,
+        insideconeangle: f32,
+        outsideconeangle: f32,
+        outsidevolume: f32,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_3d_cone_settings",
+            &[&insideconeangle, &outsideconeangle, &outsidevolume],
+            Ok(()),
+        )
+    }
+    fn get_3d_cone_settings(&self) -> Result<(f32, f32, f32), Error> {
+        self.mock.call("get_3d_cone_settings", &[])
+    }
+    fn set_3d_custom_rolloff(&self, points: Vec<Vector>) -> Result<(), Error> {
+        self.mock
+            .call_or("set_3d_custom_rolloff", &[&points], Ok(()))
+    }
+    fn get_3d_custom_rolloff(&self) -> Result<Vec<Vector>, Error> {
+        self.mock.call("get_3d_custom_rolloff", &[])
+    }
+    fn get_sub_sound(&self, index: i32) -> Result<Sound, Error> {
+        self.mock.call("get_sub_sound", &[&index])
+    }
+    fn get_sub_sound_parent(&self) -> Result<Sound, Error> {
+        self.mock.call("get_sub_sound_parent", &[])
+    }
+    fn get_name(&self, namelen: i32) -> Result<String, Error> {
+        self.mock.call("get_name", &[&namelen])
+    }
+    fn get_length(&self, lengthtype: impl Into<ffi::FMOD_TIMEUNIT>) -> Result<u32, Error> {
+        let lengthtype: ffi::FMOD_TIMEUNIT = lengthtype.into();
+        self.mock.call("get_length", &[&lengthtype])
+    }
+    fn get_format(&self) -> Result<(SoundType, SoundFormat, i32, i32), Error> {
+        self.mock.call("get_format", &[])
+    }
+    fn get_num_sub_sounds(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_sub_sounds", &[])
+    }
+    fn get_num_tags(&self) -> Result<(i32, i32), Error> {
+        self.mock.call("get_num_tags", &[])
+    }
+    fn get_tag(&self, name: &str, index: Option<i32>) -> Result<Tag, Error> {
+        self.mock.call("get_tag", &[&name, &index])
+    }
+    fn get_open_state(&self) -> Result<(OpenState, u32, bool, bool), Error> {
+        self.mock.call("get_open_state", &[])
+    }
+    fn read_data(&self, buffer: *mut c_void, length: u32) -> Result<u32, Error> {
+        self.mock.call("read_data", &[&buffer, &length])
+    }
+    fn seek_data(&self, pcm: u32) -> Result<(), Error> {
+        self.mock.call_or("seek_data", &[&pcm], Ok(()))
+    }
+    fn set_sound_group(&self, soundgroup: SoundGroup) -> Result<(), Error> {
+        self.mock.call_or("set_sound_group", &[&soundgroup], Ok(()))
+    }
+    fn get_sound_group(&self) -> Result<SoundGroup, Error> {
+        self.mock.call("get_sound_group", &[])
+    }
+    fn get_num_sync_points(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_sync_points", &[])
+    }
+    fn get_sync_point(&self, index: i32) -> Result<SyncPoint, Error> {
+        self.mock.call("get_sync_point", &[&index])
+    }
+    fn get_sync_point_info(
+        &self,
+        point: SyncPoint,
+        namelen: i32,
+        offsettype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(String, u32), Error> {
+        let offsettype: ffi::FMOD_TIMEUNIT = offsettype.into();
+        self.mock
+            .call("get_sync_point_info", &[&point, &namelen, &offsettype])
+    }
+    fn add_sync_point(
+        &self,
+        offset: u32,
+        offsettype: impl Into<ffi::FMOD_TIMEUNIT>,
+        name: Option<String>,
+    ) -> Result<SyncPoint, Error> {
+        let offsettype: ffi::FMOD_TIMEUNIT = offsettype.into();
+        self.mock
+            .call("add_sync_point", &[&offset, &offsettype, &name])
+    }
+    fn delete_sync_point(&self, point: SyncPoint) -> Result<(), Error> {
+        self.mock.call_or("delete_sync_point", &[&point], Ok(()))
+    }
+    fn set_mode(&self, mode: impl Into<ffi::FMOD_MODE>) -> Result<(), Error> {
+        let mode: ffi::FMOD_MODE = mode.into();
+        self.mock.call_or("set_mode", &[&mode], Ok(()))
+    }
+    fn get_mode(&self) -> Result<ffi::FMOD_MODE, Error> {
+        self.mock.call("get_mode", &[])
+    }
+    fn set_loop_count(&self, loopcount: i32) -> Result<(), Error> {
+        self.mock.call_or("set_loop_count", &[&loopcount], Ok(()))
+    }
+    fn get_loop_count(&self) -> Result<i32, Error> {
+        self.mock.call("get_loop_count", &[])
+    }
+    fn set_loop_points(
+        &self,
+        loopstart: u32,
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopend: u32,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(), Error> {
+        let loopstarttype: ffi::FMOD_TIMEUNIT = loopstarttype.into();
+        let loopendtype: ffi::FMOD_TIMEUNIT = loopendtype.into();
+        self.mock.call_or(
+            "set_loop_points",
+            &[&loopstart, &loopstarttype, &loopend, &loopendtype],
+            Ok(()),
+        )
+    }
+    fn get_loop_points(
+        &self,
+        loopstarttype: impl Into<ffi::FMOD_TIMEUNIT>,
+        loopendtype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(u32, u32), Error> {
+        let loopstarttype: ffi::FMOD_TIMEUNIT = loopstarttype.into();
+        let loopendtype: ffi::FMOD_TIMEUNIT = loopendtype.into();
+        self.mock
+            .call("get_loop_points", &[&loopstarttype, &loopendtype])
+    }
+    fn get_music_num_channels(&self) -> Result<i32, Error> {
+        self.mock.call("get_music_num_channels", &[])
+    }
+    fn set_music_channel_volume(&self, channel: i32, volume: f32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_music_channel_volume", &[&channel, &volume], Ok(()))
+    }
+    fn get_music_channel_volume(&self, channel: i32) -> Result<f32, Error> {
+        self.mock.call("get_music_channel_volume", &[&channel])
+    }
+    fn set_music_speed(&self, speed: f32) -> Result<(), Error> {
+        self.mock.call_or("set_music_speed", &[&speed], Ok(()))
+    }
+    fn get_music_speed(&self) -> Result<f32, Error> {
+        self.mock.call("get_music_speed", &[])
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+}
+#[doc = " Recording mock of [`SoundGroupApi`]."]
+#[derive(Debug, Default)]
+pub struct MockSoundGroup {
+    mock: Mock,
+}
+impl MockSoundGroup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `release` call."]
+    pub fn release_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("release", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_system_object` call."]
+    pub fn get_system_object_returns(&self, value: Result<System, Error>) -> &Self {
+        self.mock.returns("get_system_object", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_max_audible` call."]
+    pub fn set_max_audible_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_max_audible", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_max_audible` call."]
+    pub fn get_max_audible_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_max_audible", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_max_audible_behavior` call."]
+    pub fn set_max_audible_behavior_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_max_audible_behavior", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_max_audible_behavior` call."]
+    pub fn get_max_audible_behavior_returns(
+        &self,
+        value: Result<SoundGroupBehavior, Error>,
+    ) -> &Self {
+        self.mock.returns("get_max_audible_behavior", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mute_fade_speed` call."]
+    pub fn set_mute_fade_speed_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mute_fade_speed", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_mute_fade_speed` call."]
+    pub fn get_mute_fade_speed_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_mute_fade_speed", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_volume` call."]
+    pub fn set_volume_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_volume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_volume` call."]
+    pub fn get_volume_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_volume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `stop` call."]
+    pub fn stop_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("stop", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_name` call."]
+    pub fn get_name_returns(&self, value: Result<String, Error>) -> &Self {
+        self.mock.returns("get_name", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_sounds` call."]
+    pub fn get_num_sounds_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_sounds", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_sound` call."]
+    pub fn get_sound_returns(&self, value: Result<Sound, Error>) -> &Self {
+        self.mock.returns("get_sound", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_playing` call."]
+    pub fn get_num_playing_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_playing", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+}
+impl Deref for MockSoundGroup {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl SoundGroupApi for MockSoundGroup {
+    fn release(&self) -> Result<(), Error> {
+        self.mock.call_or("release", &[], Ok(()))
+    }
+    fn get_system_object(&self) -> Result<System, Error> {
+        self.mock.call("get_system_object", &[])
+    }
+    fn set_max_audible(&self, maxaudible: i32) -> Result<(), Error> {
+        self.mock.call_or("set_max_audible", &[&maxaudible], Ok(()))
+    }
+    fn get_max_audible(&self) -> Result<i32, Error> {
+        self.mock.call("get_max_audible", &[])
+    }
+    fn set_max_audible_behavior(&self, behavior: SoundGroupBehavior) -> Result<(), Error> {
+        self.mock
+            .call_or("set_max_audible_behavior", &[&behavior], Ok(()))
+    }
+    fn get_max_audible_behavior(&self) -> Result<SoundGroupBehavior, Error> {
+        self.mock.call("get_max_audible_behavior", &[])
+    }
+    fn set_mute_fade_speed(&self, speed: f32) -> Result<(), Error> {
+        self.mock.call_or("set_mute_fade_speed", &[&speed], Ok(()))
+    }
+    fn get_mute_fade_speed(&self) -> Result<f32, Error> {
+        self.mock.call("get_mute_fade_speed", &[])
+    }
+    fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        self.mock.call_or("set_volume", &[&volume], Ok(()))
+    }
+    fn get_volume(&self) -> Result<f32, Error> {
+        self.mock.call("get_volume", &[])
+    }
+    fn stop(&self) -> Result<(), Error> {
+        self.mock.call_or("stop", &[], Ok(()))
+    }
+    fn get_name(&self, namelen: i32) -> Result<String, Error> {
+        self.mock.call("get_name", &[&namelen])
+    }
+    fn get_num_sounds(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_sounds", &[])
+    }
+    fn get_sound(&self, index: i32) -> Result<Sound, Error> {
+        self.mock.call("get_sound", &[&index])
+    }
+    fn get_num_playing(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_playing", &[])
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+}
+#[doc = " Recording mock of [`BankApi`]."]
+#[derive(Debug, Default)]
+pub struct MockBank {
+    mock: Mock,
+}
+impl MockBank {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `is_valid` call."]
+    pub fn is_valid_returns(&self, value: bool) -> &Self {
+        self.mock.returns("is_valid", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_id` call."]
+    pub fn get_id_returns(&self, value: Result<Guid, Error>) -> &Self {
+        self.mock.returns("get_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_path` call."]
+    pub fn get_path_returns(&self, value: Result<String, Error>) -> &Self {
+        self.mock.returns("get_path", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `unload` call."]
+    pub fn unload_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("unload", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `load_sample_data` call."]
+    pub fn load_sample_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("load_sample_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `unload_sample_data` call."]
+    pub fn unload_sample_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("unload_sample_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_loading_state` call."]
+    pub fn get_loading_state_returns(&self, value: Result<LoadingState, Error>) -> &Self {
+        self.mock.returns("get_loading_state", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_sample_loading_state` call."]
+    pub fn get_sample_loading_state_returns(&self, value: Result<LoadingState, Error>) -> &Self {
+        self.mock.returns("get_sample_loading_state", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_string_count` call."]
+    pub fn get_string_count_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_string_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_string_info` call."]
+    pub fn get_string_info_returns(&self, value: Result<(Guid, String, i32), Error>) -> &Self {
+        self.mock.returns("get_string_info", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_event_count` call."]
+    pub fn get_event_count_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_event_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_event_list` call."]
+    pub fn get_event_list_returns(&self, value: Result<Vec<EventDescription>, Error>) -> &Self {
+        self.mock.returns("get_event_list", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_bus_count` call."]
+    pub fn get_bus_count_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_bus_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_bus_list` call."]
+    pub fn get_bus_list_returns(&self, value: Result<Vec<Bus>, Error>) -> &Self {
+        self.mock.returns("get_bus_list", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_vca_count` call."]
+    pub fn get_vca_count_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_vca_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_vca_list` call."]
+    pub fn get_vca_list_returns(&self, value: Result<Vec<Vca>, Error>) -> &Self {
+        self.mock.returns("get_vca_list", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+}
+impl Deref for MockBank {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl BankApi for MockBank {
+    fn is_valid(&self) -> bool {
+        self.mock.call("is_valid", &[])
+    }
+    fn get_id(&self) -> Result<Guid, Error> {
+        self.mock.call("get_id", &[])
+    }
+    fn get_path(&self) -> Result<String, Error> {
+        self.mock.call("get_path", &[])
+    }
+    fn unload(&self) -> Result<(), Error> {
+        self.mock.call_or("unload", &[], Ok(()))
+    }
+    fn load_sample_data(&self) -> Result<(), Error> {
+        self.mock.call_or("load_sample_data", &[], Ok(()))
+    }
+    fn unload_sample_data(&self) -> Result<(), Error> {
+        self.mock.call_or("unload_sample_data", &[], Ok(()))
+    }
+    fn get_loading_state(&self) -> Result<LoadingState, Error> {
+        self.mock.call("get_loading_state", &[])
+    }
+    fn get_sample_loading_state(&self) -> Result<LoadingState, Error> {
+        self.mock.call("get_sample_loading_state", &[])
+    }
+    fn get_string_count(&self) -> Result<i32, Error> {
+        self.mock.call("get_string_count", &[])
+    }
+    fn get_string_info(&self, index: i32, size: i32) -> Result<(Guid, String, i32), Error> {
+        self.mock.call("get_string_info", &[&index, &size])
+    }
+    fn get_event_count(&self) -> Result<i32, Error> {
+        self.mock.call("get_event_count", &[])
+    }
+    fn get_event_list(&self, capacity: i32) -> Result<Vec<EventDescription>, Error> {
+        self.mock.call("get_event_list", &[&capacity])
+    }
+    fn get_bus_count(&self) -> Result<i32, Error> {
+        self.mock.call("get_bus_count", &[])
+    }
+    fn get_bus_list(&self, capacity: i32) -> Result<Vec<Bus>, Error> {
+        self.mock.call("get_bus_list", &[&capacity])
+    }
+    fn get_vca_count(&self) -> Result<i32, Error> {
+        self.mock.call("get_vca_count", &[])
+    }
+    fn get_vca_list(&self, capacity: i32) -> Result<Vec<Vca>, Error> {
+        self.mock.call("get_vca_list", &[&capacity])
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+}
+#[doc = " Recording mock of [`BusApi`]."]
+#[derive(Debug, Default)]
+pub struct MockBus {
+    mock: Mock,
+}
+impl MockBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `is_valid` call."]
+    pub fn is_valid_returns(&self, value: bool) -> &Self {
+        self.mock.returns("is_valid", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_id` call."]
+    pub fn get_id_returns(&self, value: Result<Guid, Error>) -> &Self {
+        self.mock.returns("get_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_path` call."]
+    pub fn get_path_returns(&self, value: Result<String, Error>) -> &Self {
+        self.mock.returns("get_path", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_volume` call."]
+    pub fn get_volume_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_volume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_volume` call."]
+    pub fn set_volume_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_volume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_paused` call."]
+    pub fn get_paused_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_paused", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_paused` call."]
+    pub fn set_paused_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_paused", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_mute` call."]
+    pub fn get_mute_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_mute", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_mute` call."]
+    pub fn set_mute_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_mute", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `stop_all_events` call."]
+    pub fn stop_all_events_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("stop_all_events", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_port_index` call."]
+    pub fn get_port_index_returns(&self, value: Result<ffi::FMOD_PORT_INDEX, Error>) -> &Self {
+        self.mock.returns("get_port_index", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_port_index` call."]
+    pub fn set_port_index_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_port_index", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `lock_channel_group` call."]
+    pub fn lock_channel_group_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("lock_channel_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `unlock_channel_group` call."]
+    pub fn unlock_channel_group_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("unlock_channel_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_channel_group` call."]
+    pub fn get_channel_group_returns(&self, value: Result<ChannelGroup, Error>) -> &Self {
+        self.mock.returns("get_channel_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_cpu_usage` call."]
+    pub fn get_cpu_usage_returns(&self, value: Result<(u32, u32), Error>) -> &Self {
+        self.mock.returns("get_cpu_usage", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_memory_usage` call."]
+    pub fn get_memory_usage_returns(&self, value: Result<MemoryUsage, Error>) -> &Self {
+        self.mock.returns("get_memory_usage", value);
+        self
+    }
+}
+impl Deref for MockBus {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl BusApi for MockBus {
+    fn is_valid(&self) -> bool {
+        self.mock.call("is_valid", &[])
+    }
+    fn get_id(&self) -> Result<Guid, Error> {
+        self.mock.call("get_id", &[])
+    }
+    fn get_path(&self) -> Result<String, Error> {
+        self.mock.call("get_path", &[])
+    }
+    fn get_volume(&self) -> Result<(f32, f32), Error> {
+        self.mock.call("get_volume", &[])
+    }
+    fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        self.mock.call_or("set_volume", &[&volume], Ok(()))
+    }
+    fn get_paused(&self) -> Result<bool, Error> {
+        self.mock.call("get_paused", &[])
+    }
+    fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        self.mock.call_or("set_paused", &[&paused], Ok(()))
+    }
+    fn get_mute(&self) -> Result<bool, Error> {
+        self.mock.call("get_mute", &[])
+    }
+    fn set_mute(&self, mute: bool) -> Result<(), Error> {
+        self.mock.call_or("set_mute", &[&mute], Ok(()))
+    }
+    fn stop_all_events(&self, mode: StopMode) -> Result<(), Error> {
+        self.mock.call_or("stop_all_events", &[&mode], Ok(()))
+    }
+    fn get_port_index(&self) -> Result<ffi::FMOD_PORT_INDEX, Error> {
+        self.mock.call("get_port_index", &[])
+    }
+    fn set_port_index(&self, index: impl Into<ffi::FMOD_PORT_INDEX>) -> Result<(), Error> {
+        let index: ffi::FMOD_PORT_INDEX = index.into();
+        self.mock.call_or("set_port_index", &[&index], Ok(()))
+    }
+    fn lock_channel_group(&self) -> Result<(), Error> {
+        self.mock.call_or("lock_channel_group", &[], Ok(()))
+    }
+    fn unlock_channel_group(&self) -> Result<(), Error> {
+        self.mock.call_or("unlock_channel_group", &[], Ok(()))
+    }
+    fn get_channel_group(&self) -> Result<ChannelGroup, Error> {
+        self.mock.call("get_channel_group", &[])
+    }
+    fn get_cpu_usage(&self) -> Result<(u32, u32), Error> {
+        self.mock.call("get_cpu_usage", &[])
+    }
+    fn get_memory_usage(&self) -> Result<MemoryUsage, Error> {
+        self.mock.call("get_memory_usage", &[])
+    }
+}
+#[doc = " Recording mock of [`CommandReplayApi`]."]
+#[derive(Debug, Default)]
+pub struct MockCommandReplay {
+    mock: Mock,
+}
+impl MockCommandReplay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `is_valid` call."]
+    pub fn is_valid_returns(&self, value: bool) -> &Self {
+        self.mock.returns("is_valid", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_system` call."]
+    pub fn get_system_returns(&self, value: Result<Studio, Error>) -> &Self {
+        self.mock.returns("get_system", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_length` call."]
+    pub fn get_length_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_length", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_command_count` call."]
+    pub fn get_command_count_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_command_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_command_info` call."]
+    pub fn get_command_info_returns(&self, value: Result<CommandInfo, Error>) -> &Self {
+        self.mock.returns("get_command_info", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_command_string` call."]
+    pub fn get_command_string_returns(&self, value: Result<String, Error>) -> &Self {
+        self.mock.returns("get_command_string", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_command_at_time` call."]
+    pub fn get_command_at_time_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_command_at_time", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_bank_path` call."]
+    pub fn set_bank_path_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_bank_path", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `start` call."]
+    pub fn start_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("start", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `stop` call."]
+    pub fn stop_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("stop", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `seek_to_time` call."]
+    pub fn seek_to_time_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("seek_to_time", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `seek_to_command` call."]
+    pub fn seek_to_command_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("seek_to_command", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_paused` call."]
+    pub fn get_paused_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_paused", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_paused` call."]
+    pub fn set_paused_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_paused", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_playback_state` call."]
+    pub fn get_playback_state_returns(&self, value: Result<PlaybackState, Error>) -> &Self {
+        self.mock.returns("get_playback_state", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_current_command` call."]
+    pub fn get_current_command_returns(&self, value: Result<(i32, f32), Error>) -> &Self {
+        self.mock.returns("get_current_command", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `release` call."]
+    pub fn release_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("release", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_frame_callback` call."]
+    pub fn set_frame_callback_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_frame_callback", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_load_bank_callback` call."]
+    pub fn set_load_bank_callback_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_load_bank_callback", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_create_instance_callback` call."]
+    pub fn set_create_instance_callback_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_create_instance_callback", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+}
+impl Deref for MockCommandReplay {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl CommandReplayApi for MockCommandReplay {
+    fn is_valid(&self) -> bool {
+        self.mock.call("is_valid", &[])
+    }
+    fn get_system(&self) -> Result<Studio, Error> {
+        self.mock.call("get_system", &[])
+    }
+    fn get_length(&self) -> Result<f32, Error> {
+        self.mock.call("get_length", &[])
+    }
+    fn get_command_count(&self) -> Result<i32, Error> {
+        self.mock.call("get_command_count", &[])
+    }
+    fn get_command_info(&self, commandindex: i32) -> Result<CommandInfo, Error> {
+        self.mock.call("get_command_info", &[&commandindex])
+    }
+    fn get_command_string(&self, commandindex: i32, length: i32) -> Result<String, Error> {
+        self.mock
+            .call("get_command_string", &[&commandindex, &length])
+    }
+    fn get_command_at_time(&self, time: f32) -> Result<i32, Error> {
+        self.mock.call("get_command_at_time", &[&time])
+    }
+    fn set_bank_path(&self, bank_path: &str) -> Result<(), Error> {
+        self.mock.call_or("set_bank_path", &[&bank_path], Ok(()))
+    }
+    fn start(&self) -> Result<(), Error> {
+        self.mock.call_or("start", &[], Ok(()))
+    }
+    fn stop(&self) -> Result<(), Error> {
+        self.mock.call_or("stop", &[], Ok(()))
+    }
+    fn seek_to_time(&self, time: f32) -> Result<(), Error> {
+        self.mock.call_or("seek_to_time", &[&time], Ok(()))
+    }
+    fn seek_to_command(&self, commandindex: i32) -> Result<(), Error> {
+        self.mock
+            .call_or("seek_to_command", &[&commandindex], Ok(()))
+    }
+    fn get_paused(&self) -> Result<bool, Error> {
+        self.mock.call("get_paused", &[])
+    }
+    fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        self.mock.call_or("set_paused", &[&paused], Ok(()))
+    }
+    fn get_playback_state(&self) -> Result<PlaybackState, Error> {
+        self.mock.call("get_playback_state", &[])
+    }
+    fn get_current_command(&self) -> Result<(i32, f32), Error> {
+        self.mock.call("get_current_command", &[])
+    }
+    fn release(&self) -> Result<(), Error> {
+        self.mock.call_or("release", &[], Ok(()))
+    }
+    fn set_frame_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_FRAME_CALLBACK,
+    ) -> Result<(), Error> {
+        self.mock
+            .call_or("set_frame_callback", &[&callback], Ok(()))
+    }
+    fn set_load_bank_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_LOAD_BANK_CALLBACK,
+    ) -> Result<(), Error> {
+        self.mock
+            .call_or("set_load_bank_callback", &[&callback], Ok(()))
+    }
+    fn set_create_instance_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_COMMANDREPLAY_CREATE_INSTANCE_CALLBACK,
+    ) -> Result<(), Error> {
+        self.mock
+            .call_or("set_create_instance_callback", &[&callback], Ok(()))
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+}
+#[doc = " Recording mock of [`EventDescriptionApi`]."]
+#[derive(Debug, Default)]
+pub struct MockEventDescription {
+    mock: Mock,
+}
+impl MockEventDescription {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `is_valid` call."]
+    pub fn is_valid_returns(&self, value: bool) -> &Self {
+        self.mock.returns("is_valid", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_id` call."]
+    pub fn get_id_returns(&self, value: Result<Guid, Error>) -> &Self {
+        self.mock.returns("get_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_path` call."]
+    pub fn get_path_returns(&self, value: Result<String, Error>) -> &Self {
+        self.mock.returns("get_path", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_description_count` call."]
+    pub fn get_parameter_description_count_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_parameter_description_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_description_by_index` call."]
+    pub fn get_parameter_description_by_index_returns(
+        &self,
+        value: Result<ParameterDescription, Error>,
+    ) -> &Self {
+        self.mock
+            .returns("get_parameter_description_by_index", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_description_by_name` call."]
+    pub fn get_parameter_description_by_name_returns(
+        &self,
+        value: Result<ParameterDescription, Error>,
+    ) -> &Self {
+        self.mock
+            .returns("get_parameter_description_by_name", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_description_by_id` call."]
+    pub fn get_parameter_description_by_id_returns(
+        &self,
+        value: Result<ParameterDescription, Error>,
+    ) -> &Self {
+        self.mock.returns("get_parameter_description_by_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_label_by_index` call."]
+    pub fn get_parameter_label_by_index_returns(
+        &self,
+        value: Result<(String, i32), Error>,
+    ) -> &Self {
+        self.mock.returns("get_parameter_label_by_index", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_label_by_name` call."]
+    pub fn get_parameter_label_by_name_returns(
+        &self,
+        value: Result<(String, i32), Error>,
+    ) -> &Self {
+        self.mock.returns("get_parameter_label_by_name", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_label_by_id` call."]
+    pub fn get_parameter_label_by_id_returns(&self, value: Result<(String, i32), Error>) -> &Self {
+        self.mock.returns("get_parameter_label_by_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_property_count` call."]
+    pub fn get_user_property_count_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_user_property_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_property_by_index` call."]
+    pub fn get_user_property_by_index_returns(&self, value: Result<UserProperty, Error>) -> &Self {
+        self.mock.returns("get_user_property_by_index", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_property` call."]
+    pub fn get_user_property_returns(&self, value: Result<UserProperty, Error>) -> &Self {
+        self.mock.returns("get_user_property", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_length` call."]
+    pub fn get_length_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_length", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_min_max_distance` call."]
+    pub fn get_min_max_distance_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_min_max_distance", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_sound_size` call."]
+    pub fn get_sound_size_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_sound_size", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `is_snapshot` call."]
+    pub fn is_snapshot_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("is_snapshot", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `is_oneshot` call."]
+    pub fn is_oneshot_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("is_oneshot", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `is_stream` call."]
+    pub fn is_stream_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("is_stream", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `is_3d` call."]
+    pub fn is_3d_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("is_3d", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `is_doppler_enabled` call."]
+    pub fn is_doppler_enabled_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("is_doppler_enabled", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `has_sustain_point` call."]
+    pub fn has_sustain_point_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("has_sustain_point", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `create_instance` call."]
+    pub fn create_instance_returns(&self, value: Result<EventInstance, Error>) -> &Self {
+        self.mock.returns("create_instance", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_instance_count` call."]
+    pub fn get_instance_count_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_instance_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_instance_list` call."]
+    pub fn get_instance_list_returns(&self, value: Result<Vec<EventInstance>, Error>) -> &Self {
+        self.mock.returns("get_instance_list", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `load_sample_data` call."]
+    pub fn load_sample_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("load_sample_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `unload_sample_data` call."]
+    pub fn unload_sample_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("unload_sample_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_sample_loading_state` call."]
+    pub fn get_sample_loading_state_returns(&self, value: Result<LoadingState, Error>) -> &Self {
+        self.mock.returns("get_sample_loading_state", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `release_all_instances` call."]
+    pub fn release_all_instances_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("release_all_instances", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_callback` call."]
+    pub fn set_callback_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_callback", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+}
+impl Deref for MockEventDescription {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl EventDescriptionApi for MockEventDescription {
+    fn is_valid(&self) -> bool {
+        self.mock.call("is_valid", &[])
+    }
+    fn get_id(&self) -> Result<Guid, Error> {
+        self.mock.call("get_id", &[])
+    }
+    fn get_path(&self) -> Result<String, Error> {
+        self.mock.call("get_path", &[])
+    }
+    fn get_parameter_description_count(&self) -> Result<i32, Error> {
+        self.mock.call("get_parameter_description_count", &[])
+    }
+    fn get_parameter_description_by_index(
+        &self,
+        index: i32,
+    ) -> Result<ParameterDescription, Error> {
+        self.mock
+            .call("get_parameter_description_by_index", &[&index])
+    }
+    fn get_parameter_description_by_name(&self, name: &str) -> Result<ParameterDescription, Error> {
+        self.mock
+            .call("get_parameter_description_by_name", &[&name])
+    }
+    fn get_parameter_description_by_id(
+        &self,
+        id: ParameterId,
+    ) -> Result<ParameterDescription, Error> {
+        self.mock.call("get_parameter_description_by_id", &[&id])
+    }
+    fn get_parameter_label_by_index(
+        &self,
+        index: i32,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        self.mock.call(
+            "get_parameter_label_by_index",
+            &[&index, &labelindex, &size],
+        )
+    }
+    fn get_parameter_label_by_name(
+        &self,
+        name: &str,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        self.mock
+            .call("get_parameter_label_by_name", &[&name, &labelindex, &size])
+    }
+    fn get_parameter_label_by_id(
+        &self,
+        id: ParameterId,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        self.mock
+            .call("get_parameter_label_by_id", &[&id, &labelindex, &size])
+    }
+    fn get_user_property_count(&self) -> Result<i32, Error> {
+        self.mock.call("get_user_property_count", &[])
+    }
+    fn get_user_property_by_index(&self, index: i32) -> Result<UserProperty, Error> {
+        self.mock.call("get_user_property_by_index", &[&index])
+    }
+    fn get_user_property(&self, name: &str) -> Result<UserProperty, Error> {
+        self.mock.call("get_user_property", &[&name])
+    }
+    fn get_length(&self) -> Result<i32, Error> {
+        self.mock.call("get_length", &[])
+    }
+    fn get_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        self.mock.call("get_min_max_distance", &[])
+    }
+    fn get_sound_size(&self) -> Result<f32, Error> {
+        self.mock.call("get_sound_size", &[])
+    }
+    fn is_snapshot(&self) -> Result<bool, Error> {
+        self.mock.call("is_snapshot", &[])
+    }
+    fn is_oneshot(&self) -> Result<bool, Error> {
+        self.mock.call("is_oneshot", &[])
+    }
+    fn is_stream(&self) -> Result<bool, Error> {
+        self.mock.call("is_stream", &[])
+    }
+    fn is_3d(&self) -> Result<bool, Error> {
+        self.mock.call("is_3d", &[])
+    }
+    fn is_doppler_enabled(&self) -> Result<bool, Error> {
+        self.mock.call("is_doppler_enabled", &[])
+    }
+    fn has_sustain_point(&self) -> Result<bool, Error> {
+        self.mock.call("has_sustain_point", &[])
+    }
+    fn create_instance(&self) -> Result<EventInstance, Error> {
+        self.mock.call("create_instance", &[])
+    }
+    fn get_instance_count(&self) -> Result<i32, Error> {
+        self.mock.call("get_instance_count", &[])
+    }
+    fn get_instance_list(&self, capacity: i32) -> Result<Vec<EventInstance>, Error> {
+        self.mock.call("get_instance_list", &[&capacity])
+    }
+    fn load_sample_data(&self) -> Result<(), Error> {
+        self.mock.call_or("load_sample_data", &[], Ok(()))
+    }
+    fn unload_sample_data(&self) -> Result<(), Error> {
+        self.mock.call_or("unload_sample_data", &[], Ok(()))
+    }
+    fn get_sample_loading_state(&self) -> Result<LoadingState, Error> {
+        self.mock.call("get_sample_loading_state", &[])
+    }
+    fn release_all_instances(&self) -> Result<(), Error> {
+        self.mock.call_or("release_all_instances", &[], Ok(()))
+    }
+    fn set_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_EVENT_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_STUDIO_EVENT_CALLBACK_TYPE>,
+    ) -> Result<(), Error> {
+        let callbackmask: ffi::FMOD_STUDIO_EVENT_CALLBACK_TYPE = callbackmask.into();
+        self.mock
+            .call_or("set_callback", &[&callback, &callbackmask], Ok(()))
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+}
+#[doc = " Recording mock of [`EventInstanceApi`]."]
+#[derive(Debug, Default)]
+pub struct MockEventInstance {
+    mock: Mock,
+}
+impl MockEventInstance {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `is_valid` call."]
+    pub fn is_valid_returns(&self, value: bool) -> &Self {
+        self.mock.returns("is_valid", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_description` call."]
+    pub fn get_description_returns(&self, value: Result<EventDescription, Error>) -> &Self {
+        self.mock.returns("get_description", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_volume` call."]
+    pub fn get_volume_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_volume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_volume` call."]
+    pub fn set_volume_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_volume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_pitch` call."]
+    pub fn get_pitch_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_pitch", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_pitch` call."]
+    pub fn set_pitch_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_pitch", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_attributes` call."]
+    pub fn get_3d_attributes_returns(&self, value: Result<Attributes3d, Error>) -> &Self {
+        self.mock.returns("get_3d_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_attributes` call."]
+    pub fn set_3d_attributes_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_listener_mask` call."]
+    pub fn get_listener_mask_returns(&self, value: Result<u32, Error>) -> &Self {
+        self.mock.returns("get_listener_mask", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_listener_mask` call."]
+    pub fn set_listener_mask_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_listener_mask", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_property` call."]
+    pub fn get_property_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_property", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_property` call."]
+    pub fn set_property_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_property", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_reverb_level` call."]
+    pub fn get_reverb_level_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_reverb_level", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_reverb_level` call."]
+    pub fn set_reverb_level_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_reverb_level", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_paused` call."]
+    pub fn get_paused_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("get_paused", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_paused` call."]
+    pub fn set_paused_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_paused", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `start` call."]
+    pub fn start_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("start", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `stop` call."]
+    pub fn stop_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("stop", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_timeline_position` call."]
+    pub fn get_timeline_position_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_timeline_position", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_timeline_position` call."]
+    pub fn set_timeline_position_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_timeline_position", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_playback_state` call."]
+    pub fn get_playback_state_returns(&self, value: Result<PlaybackState, Error>) -> &Self {
+        self.mock.returns("get_playback_state", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_channel_group` call."]
+    pub fn get_channel_group_returns(&self, value: Result<ChannelGroup, Error>) -> &Self {
+        self.mock.returns("get_channel_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_min_max_distance` call."]
+    pub fn get_min_max_distance_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_min_max_distance", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `release` call."]
+    pub fn release_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("release", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `is_virtual` call."]
+    pub fn is_virtual_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("is_virtual", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_by_name` call."]
+    pub fn get_parameter_by_name_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_parameter_by_name", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameter_by_name` call."]
+    pub fn set_parameter_by_name_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameter_by_name", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameter_by_name_with_label` call."]
+    pub fn set_parameter_by_name_with_label_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameter_by_name_with_label", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_by_id` call."]
+    pub fn get_parameter_by_id_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_parameter_by_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameter_by_id` call."]
+    pub fn set_parameter_by_id_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameter_by_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameter_by_id_with_label` call."]
+    pub fn set_parameter_by_id_with_label_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameter_by_id_with_label", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameters_by_i_ds` call."]
+    pub fn set_parameters_by_i_ds_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameters_by_i_ds", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `key_off` call."]
+    pub fn key_off_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("key_off", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_callback` call."]
+    pub fn set_callback_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_callback", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_cpu_usage` call."]
+    pub fn get_cpu_usage_returns(&self, value: Result<(u32, u32), Error>) -> &Self {
+        self.mock.returns("get_cpu_usage", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_memory_usage` call."]
+    pub fn get_memory_usage_returns(&self, value: Result<MemoryUsage, Error>) -> &Self {
+        self.mock.returns("get_memory_usage", value);
+        self
+    }
+}
+impl Deref for MockEventInstance {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl EventInstanceApi for MockEventInstance {
+    fn is_valid(&self) -> bool {
+        self.mock.call("is_valid", &[])
+    }
+    fn get_description(&self) -> Result<EventDescription, Error> {
+        self.mock.call("get_description", &[])
+    }
+    fn get_volume(&self) -> Result<(f32, f32), Error> {
+        self.mock.call("get_volume", &[])
+    }
+    fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        self.mock.call_or("set_volume", &[&volume], Ok(()))
+    }
+    fn get_pitch(&self) -> Result<(f32, f32), Error> {
+        self.mock.call("get_pitch", &[])
+    }
+    fn set_pitch(&self, pitch: f32) -> Result<(), Error> {
+        self.mock.call_or("set_pitch", &[&pitch], Ok(()))
+    }
+    fn get_3d_attributes(&self) -> Result<Attributes3d, Error> {
+        self.mock.call("get_3d_attributes", &[])
+    }
+    fn set_3d_attributes(&self, attributes: Attributes3d) -> Result<(), Error> {
+        self.mock
+            .call_or("set_3d_attributes", &[&attributes], Ok(()))
+    }
+    fn get_listener_mask(&self) -> Result<u32, Error> {
+        self.mock.call("get_listener_mask", &[])
+    }
+    fn set_listener_mask(&self, mask: u32) -> Result<(), Error> {
+        self.mock.call_or("set_listener_mask", &[&mask], Ok(()))
+    }
+    fn get_property(&self, index: EventProperty) -> Result<f32, Error> {
+        self.mock.call("get_property", &[&index])
+    }
+    fn set_property(&self, index: EventProperty, value: f32) -> Result<(), Error> {
+        self.mock.call_or("set_property", &[&index, &value], Ok(()))
+    }
+    fn get_reverb_level(&self, index: i32) -> Result<f32, Error> {
+        self.mock.call("get_reverb_level", &[&index])
+    }
+    fn set_reverb_level(&self, index: i32, level: f32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_reverb_level", &[&index, &level], Ok(()))
+    }
+    fn get_paused(&self) -> Result<bool, Error> {
+        self.mock.call("get_paused", &[])
+    }
+    fn set_paused(&self, paused: bool) -> Result<(), Error> {
+        self.mock.call_or("set_paused", &[&paused], Ok(()))
+    }
+    fn start(&self) -> Result<(), Error> {
+        self.mock.call_or("start", &[], Ok(()))
+    }
+    fn stop(&self, mode: StopMode) -> Result<(), Error> {
+        self.mock.call_or("stop", &[&mode], Ok(()))
+    }
+    fn get_timeline_position(&self) -> Result<i32, Error> {
+        self.mock.call("get_timeline_position", &[])
+    }
+    fn set_timeline_position(&self, position: i32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_timeline_position", &[&position], Ok(()))
+    }
+    fn get_playback_state(&self) -> Result<PlaybackState, Error> {
+        self.mock.call("get_playback_state", &[])
+    }
+    fn get_channel_group(&self) -> Result<ChannelGroup, Error> {
+        self.mock.call("get_channel_group", &[])
+    }
+    fn get_min_max_distance(&self) -> Result<(f32, f32), Error> {
+        self.mock.call("get_min_max_distance", &[])
+    }
+    fn release(&self) -> Result<(), Error> {
+        self.mock.call_or("release", &[], Ok(()))
+    }
+    fn is_virtual(&self) -> Result<bool, Error> {
+        self.mock.call("is_virtual", &[])
+    }
+    fn get_parameter_by_name(&self, name: &str) -> Result<(f32, f32), Error> {
+        self.mock.call("get_parameter_by_name", &[&name])
+    }
+    fn set_parameter_by_name(
+        &self,
+        name: &str,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_parameter_by_name",
+            &[&name, &value, &ignoreseekspeed],
+            Ok(()),
+        )
+    }
+    fn set_parameter_by_name_with_label(
+        &self,
+        name: &str,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_parameter_by_name_with_label",
+            &[&name, &label, &ignoreseekspeed],
+            Ok(()),
+        )
+    }
+    fn get_parameter_by_id(&self, id: ParameterId) -> Result<(f32, f32), Error> {
+        self.mock.call("get_parameter_by_id", &[&id])
+    }
+    fn set_parameter_by_id(
+        &self,
+        id: ParameterId,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_parameter_by_id",
+            &[&id, &value, &ignoreseekspeed],
+            Ok(()),
+        )
+    }
+    fn set_parameter_by_id_with_label(
+        &self,
+        id: ParameterId,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_parameter_by_id_with_label",
+            &[&id, &label, &ignoreseekspeed],
+            Ok(()),
+        )
+    }
+    fn set_parameters_by_i_ds(
+        &self,
+        ids: ParameterId,
+        values: *mut f32,
+        count: i32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_parameters_by_i_ds",
+            &[&ids, &values, &count, &ignoreseekspeed],
+            Ok(()),
+        )
+    }
+    fn key_off(&self) -> Result<(), Error> {
+        self.mock.call_or("key_off", &[], Ok(()))
+    }
+    fn set_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_EVENT_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_STUDIO_EVENT_CALLBACK_TYPE>,
+    ) -> Result<(), Error> {
+        let callbackmask: ffi::FMOD_STUDIO_EVENT_CALLBACK_TYPE = callbackmask.into();
+        self.mock
+            .call_or("set_callback", &[&callback, &callbackmask], Ok(()))
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+    fn get_cpu_usage(&self) -> Result<(u32, u32), Error> {
+        self.mock.call("get_cpu_usage", &[])
+    }
+    fn get_memory_usage(&self) -> Result<MemoryUsage, Error> {
+        self.mock.call("get_memory_usage", &[])
+    }
+}
+#[doc = " Recording mock of [`StudioApi`]."]
+#[derive(Debug, Default)]
+pub struct MockStudio {
+    mock: Mock,
+}
+impl MockStudio {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `is_valid` call."]
+    pub fn is_valid_returns(&self, value: bool) -> &Self {
+        self.mock.returns("is_valid", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_advanced_settings` call."]
+    pub fn set_advanced_settings_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_advanced_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_advanced_settings` call."]
+    pub fn get_advanced_settings_returns(
+        &self,
+        value: Result<StudioAdvancedSettings, Error>,
+    ) -> &Self {
+        self.mock.returns("get_advanced_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `initialize` call."]
+    pub fn initialize_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("initialize", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `release` call."]
+    pub fn release_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("release", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `update` call."]
+    pub fn update_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("update", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_core_system` call."]
+    pub fn get_core_system_returns(&self, value: Result<System, Error>) -> &Self {
+        self.mock.returns("get_core_system", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_event` call."]
+    pub fn get_event_returns(&self, value: Result<EventDescription, Error>) -> &Self {
+        self.mock.returns("get_event", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_bus` call."]
+    pub fn get_bus_returns(&self, value: Result<Bus, Error>) -> &Self {
+        self.mock.returns("get_bus", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_vca` call."]
+    pub fn get_vca_returns(&self, value: Result<Vca, Error>) -> &Self {
+        self.mock.returns("get_vca", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_bank` call."]
+    pub fn get_bank_returns(&self, value: Result<Bank, Error>) -> &Self {
+        self.mock.returns("get_bank", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_event_by_id` call."]
+    pub fn get_event_by_id_returns(&self, value: Result<EventDescription, Error>) -> &Self {
+        self.mock.returns("get_event_by_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_bus_by_id` call."]
+    pub fn get_bus_by_id_returns(&self, value: Result<Bus, Error>) -> &Self {
+        self.mock.returns("get_bus_by_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_vca_by_id` call."]
+    pub fn get_vca_by_id_returns(&self, value: Result<Vca, Error>) -> &Self {
+        self.mock.returns("get_vca_by_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_bank_by_id` call."]
+    pub fn get_bank_by_id_returns(&self, value: Result<Bank, Error>) -> &Self {
+        self.mock.returns("get_bank_by_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_sound_info` call."]
+    pub fn get_sound_info_returns(&self, value: Result<SoundInfo, Error>) -> &Self {
+        self.mock.returns("get_sound_info", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_description_by_name` call."]
+    pub fn get_parameter_description_by_name_returns(
+        &self,
+        value: Result<ParameterDescription, Error>,
+    ) -> &Self {
+        self.mock
+            .returns("get_parameter_description_by_name", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_description_by_id` call."]
+    pub fn get_parameter_description_by_id_returns(
+        &self,
+        value: Result<ParameterDescription, Error>,
+    ) -> &Self {
+        self.mock.returns("get_parameter_description_by_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_label_by_name` call."]
+    pub fn get_parameter_label_by_name_returns(
+        &self,
+        value: Result<(String, i32), Error>,
+    ) -> &Self {
+        self.mock.returns("get_parameter_label_by_name", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_label_by_id` call."]
+    pub fn get_parameter_label_by_id_returns(&self, value: Result<(String, i32), Error>) -> &Self {
+        self.mock.returns("get_parameter_label_by_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_by_id` call."]
+    pub fn get_parameter_by_id_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_parameter_by_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameter_by_id` call."]
+    pub fn set_parameter_by_id_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameter_by_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameter_by_id_with_label` call."]
+    pub fn set_parameter_by_id_with_label_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameter_by_id_with_label", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameters_by_i_ds` call."]
+    pub fn set_parameters_by_i_ds_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameters_by_i_ds", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_by_name` call."]
+    pub fn get_parameter_by_name_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_parameter_by_name", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameter_by_name` call."]
+    pub fn set_parameter_by_name_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameter_by_name", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_parameter_by_name_with_label` call."]
+    pub fn set_parameter_by_name_with_label_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_parameter_by_name_with_label", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `lookup_id` call."]
+    pub fn lookup_id_returns(&self, value: Result<Guid, Error>) -> &Self {
+        self.mock.returns("lookup_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `lookup_path` call."]
+    pub fn lookup_path_returns(&self, value: Result<String, Error>) -> &Self {
+        self.mock.returns("lookup_path", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_listeners` call."]
+    pub fn get_num_listeners_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_listeners", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_num_listeners` call."]
+    pub fn set_num_listeners_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_num_listeners", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_listener_attributes` call."]
+    pub fn get_listener_attributes_returns(
+        &self,
+        value: Result<(Attributes3d, Vector), Error>,
+    ) -> &Self {
+        self.mock.returns("get_listener_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_listener_attributes` call."]
+    pub fn set_listener_attributes_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_listener_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_listener_weight` call."]
+    pub fn get_listener_weight_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_listener_weight", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_listener_weight` call."]
+    pub fn set_listener_weight_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_listener_weight", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `load_bank_file` call."]
+    pub fn load_bank_file_returns(&self, value: Result<Bank, Error>) -> &Self {
+        self.mock.returns("load_bank_file", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `load_bank_memory` call."]
+    pub fn load_bank_memory_returns(&self, value: Result<Bank, Error>) -> &Self {
+        self.mock.returns("load_bank_memory", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `load_bank_custom` call."]
+    pub fn load_bank_custom_returns(&self, value: Result<Bank, Error>) -> &Self {
+        self.mock.returns("load_bank_custom", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `register_plugin` call."]
+    pub fn register_plugin_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("register_plugin", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `unregister_plugin` call."]
+    pub fn unregister_plugin_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("unregister_plugin", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `unload_all` call."]
+    pub fn unload_all_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("unload_all", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `flush_commands` call."]
+    pub fn flush_commands_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("flush_commands", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `flush_sample_loading` call."]
+    pub fn flush_sample_loading_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("flush_sample_loading", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `start_command_capture` call."]
+    pub fn start_command_capture_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("start_command_capture", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `stop_command_capture` call."]
+    pub fn stop_command_capture_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("stop_command_capture", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `load_command_replay` call."]
+    pub fn load_command_replay_returns(&self, value: Result<CommandReplay, Error>) -> &Self {
+        self.mock.returns("load_command_replay", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_bank_count` call."]
+    pub fn get_bank_count_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_bank_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_bank_list` call."]
+    pub fn get_bank_list_returns(&self, value: Result<Vec<Bank>, Error>) -> &Self {
+        self.mock.returns("get_bank_list", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_description_count` call."]
+    pub fn get_parameter_description_count_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_parameter_description_count", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_parameter_description_list` call."]
+    pub fn get_parameter_description_list_returns(
+        &self,
+        value: Result<Vec<ParameterDescription>, Error>,
+    ) -> &Self {
+        self.mock.returns("get_parameter_description_list", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_cpu_usage` call."]
+    pub fn get_cpu_usage_returns(&self, value: Result<(StudioCpuUsage, CpuUsage), Error>) -> &Self {
+        self.mock.returns("get_cpu_usage", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_buffer_usage` call."]
+    pub fn get_buffer_usage_returns(&self, value: Result<BufferUsage, Error>) -> &Self {
+        self.mock.returns("get_buffer_usage", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `reset_buffer_usage` call."]
+    pub fn reset_buffer_usage_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("reset_buffer_usage", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_callback` call."]
+    pub fn set_callback_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_callback", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_memory_usage` call."]
+    pub fn get_memory_usage_returns(&self, value: Result<MemoryUsage, Error>) -> &Self {
+        self.mock.returns("get_memory_usage", value);
+        self
+    }
+}
+impl Deref for MockStudio {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl StudioApi for MockStudio {
+    fn is_valid(&self) -> bool {
+        self.mock.call("is_valid", &[])
+    }
+    fn set_advanced_settings(&self, settings: StudioAdvancedSettings) -> Result<(), Error> {
+        self.mock
+            .call_or("set_advanced_settings", &[&settings], Ok(()))
+    }
+    fn get_advanced_settings(&self) -> Result<StudioAdvancedSettings, Error> {
+        self.mock.call("get_advanced_settings", &[])
+    }
+    fn initialize(
+        &self,
+        maxchannels: i32,
+        studioflags: impl Into<ffi::FMOD_STUDIO_INITFLAGS>,
+        flags: impl Into<ffi::FMOD_INITFLAGS>,
+        extradriverdata: Option<*mut c_void>,
+    ) -> Result<(), Error> {
+        let studioflags: ffi::FMOD_STUDIO_INITFLAGS = studioflags.into();
+        let flags: ffi::FMOD_INITFLAGS = flags.into();
+        self.mock.call_or(
+            "initialize",
+            &[&maxchannels, &studioflags, &flags, &extradriverdata],
+            Ok(()),
+        )
+    }
+    fn release(&self) -> Result<(), Error> {
+        self.mock.call_or("release", &[], Ok(()))
+    }
+    fn update(&self) -> Result<(), Error> {
+        self.mock.call_or("update", &[], Ok(()))
+    }
+    fn get_core_system(&self) -> Result<System, Error> {
+        self.mock.call("get_core_system", &[])
+    }
+    fn get_event(&self, path_or_id: &str) -> Result<EventDescription, Error> {
+        self.mock.call("get_event", &[&path_or_id])
+    }
+    fn get_bus(&self, path_or_id: &str) -> Result<Bus, Error> {
+        self.mock.call("get_bus", &[&path_or_id])
+    }
+    fn get_vca(&self, path_or_id: &str) -> Result<Vca, Error> {
+        self.mock.call("get_vca", &[&path_or_id])
+    }
+    fn get_bank(&self, path_or_id: &str) -> Result<Bank, Error> {
+        self.mock.call("get_bank", &[&path_or_id])
+    }
+    fn get_event_by_id(&self, id: Guid) -> Result<EventDescription, Error> {
+        self.mock.call("get_event_by_id", &[&id])
+    }
+    fn get_bus_by_id(&self, id: Guid) -> Result<Bus, Error> {
+        self.mock.call("get_bus_by_id", &[&id])
+    }
+    fn get_vca_by_id(&self, id: Guid) -> Result<Vca, Error> {
+        self.mock.call("get_vca_by_id", &[&id])
+    }
+    fn get_bank_by_id(&self, id: Guid) -> Result<Bank, Error> {
+        self.mock.call("get_bank_by_id", &[&id])
+    }
+    fn get_sound_info(&self, key: &str) -> Result<SoundInfo, Error> {
+        self.mock.call("get_sound_info", &[&key])
+    }
+    fn get_parameter_description_by_name(&self, name: &str) -> Result<ParameterDescription, Error> {
+        self.mock
+            .call("get_parameter_description_by_name", &[&name])
+    }
+    fn get_parameter_description_by_id(
+        &self,
+        id: ParameterId,
+    ) -> Result<ParameterDescription, Error> {
+        self.mock.call("get_parameter_description_by_id", &[&id])
+    }
+    fn get_parameter_label_by_name(
+        &self,
+        name: &str,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        self.mock
+            .call("get_parameter_label_by_name", &[&name, &labelindex, &size])
+    }
+    fn get_parameter_label_by_id(
+        &self,
+        id: ParameterId,
+        labelindex: i32,
+        size: i32,
+    ) -> Result<(String, i32), Error> {
+        self.mock
+            .call("get_parameter_label_by_id", &[&id, &labelindex, &size])
+    }
+    fn get_parameter_by_id(&self, id: ParameterId) -> Result<(f32, f32), Error> {
+        self.mock.call("get_parameter_by_id", &[&id])
+    }
+    fn set_parameter_by_id(
+        &self,
+        id: ParameterId,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_parameter_by_id",
+            &[&id, &value, &ignoreseekspeed],
+            Ok(()),
+        )
+    }
+    fn set_parameter_by_id_with_label(
+        &self,
+        id: ParameterId,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_parameter_by_id_with_label",
+            &[&id, &label, &ignoreseekspeed],
+            Ok(()),
+        )
+    }
+    fn set_parameters_by_i_ds(
+        &self,
+        ids: ParameterId,
+        values: *mut f32,
+        count: i32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_parameters_by_i_ds",
+            &[&ids, &values, &count, &ignoreseekspeed],
+            Ok(()),
+        )
+    }
+    fn get_parameter_by_name(&self, name: &str) -> Result<(f32, f32), Error> {
+        self.mock.call("get_parameter_by_name", &[&name])
+    }
+    fn set_parameter_by_name(
+        &self,
+        name: &str,
+        value: f32,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_parameter_by_name",
+            &[&name, &value, &ignoreseekspeed],
+            Ok(()),
+        )
+    }
+    fn set_parameter_by_name_with_label(
+        &self,
+        name: &str,
+        label: &str,
+        ignoreseekspeed: bool,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_parameter_by_name_with_label",
+            &[&name, &label, &ignoreseekspeed],
+            Ok(()),
+        )
+    }
+    fn lookup_id(&self, path: &str) -> Result<Guid, Error> {
+        self.mock.call("lookup_id", &[&path])
+    }
+    fn lookup_path(&self, id: Guid) -> Result<String, Error> {
+        self.mock.call("lookup_path", &[&id])
+    }
+    fn get_num_listeners(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_listeners", &[])
+    }
+    fn set_num_listeners(&self, numlisteners: i32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_num_listeners", &[&numlisteners], Ok(()))
+    }
+    fn get_listener_attributes(&self, index: i32) -> Result<(Attributes3d, Vector), Error> {
+        self.mock.call("get_listener_attributes", &[&index])
+    }
+    fn set_listener_attributes(
+        &self,
+        index: i32,
+        attributes: Attributes3d,
+        attenuationposition: Option<Vector>,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_listener_attributes",
+            &[&index, &attributes, &attenuationposition],
+            Ok(()),
+        )
+    }
+    fn get_listener_weight(&self, index: i32) -> Result<f32, Error> {
+        self.mock.call("get_listener_weight", &[&index])
+    }
+    fn set_listener_weight(&self, index: i32, weight: f32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_listener_weight", &[&index, &weight], Ok(()))
+    }
+    fn load_bank_file(
+        &self,
+        filename: &str,
+        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
+    ) -> Result<Bank, Error> {
+        let flags: ffi::FMOD_STUDIO_LOAD_BANK_FLAGS = flags.into();
+        self.mock.call("load_bank_file", &[&filename, &flags])
+    }
+    fn load_bank_memory(
+        &self,
+        buffer: &[u8],
+        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
+    ) -> Result<Bank, Error> {
+        let flags: ffi::FMOD_STUDIO_LOAD_BANK_FLAGS = flags.into();
+        self.mock.call("load_bank_memory", &[&buffer, &flags])
+    }
+    fn load_bank_custom(
+        &self,
+        info: BankInfo,
+        flags: impl Into<ffi::FMOD_STUDIO_LOAD_BANK_FLAGS>,
+    ) -> Result<Bank, Error> {
+        let flags: ffi::FMOD_STUDIO_LOAD_BANK_FLAGS = flags.into();
+        self.mock.call("load_bank_custom", &[&info, &flags])
+    }
+    fn register_plugin(&self, description: DspDescription) -> Result<(), Error> {
+        self.mock
+            .call_or("register_plugin", &[&description], Ok(()))
+    }
+    fn unregister_plugin(&self, name: &str) -> Result<(), Error> {
+        self.mock.call_or("unregister_plugin", &[&name], Ok(()))
+    }
+    fn unload_all(&self) -> Result<(), Error> {
+        self.mock.call_or("unload_all", &[], Ok(()))
+    }
+    fn flush_commands(&self) -> Result<(), Error> {
+        self.mock.call_or("flush_commands", &[], Ok(()))
+    }
+    fn flush_sample_loading(&self) -> Result<(), Error> {
+        self.mock.call_or("flush_sample_loading", &[], Ok(()))
+    }
+    fn start_command_capture(
+        &self,
+        filename: &str,
+        flags: impl Into<ffi::FMOD_STUDIO_COMMANDCAPTURE_FLAGS>,
+    ) -> Result<(), Error> {
+        let flags: ffi::FMOD_STUDIO_COMMANDCAPTURE_FLAGS = flags.into();
+        self.mock
+            .call_or("start_command_capture", &[&filename, &flags], Ok(()))
+    }
+    fn stop_command_capture(&self) -> Result<(), Error> {
+        self.mock.call_or("stop_command_capture", &[], Ok(()))
+    }
+    fn load_command_replay(
+        &self,
+        filename: &str,
+        flags: impl Into<ffi::FMOD_STUDIO_COMMANDREPLAY_FLAGS>,
+    ) -> Result<CommandReplay, Error> {
+        let flags: ffi::FMOD_STUDIO_COMMANDREPLAY_FLAGS = flags.into();
+        self.mock.call("load_command_replay", &[&filename, &flags])
+    }
+    fn get_bank_count(&self) -> Result<i32, Error> {
+        self.mock.call("get_bank_count", &[])
+    }
+    fn get_bank_list(&self, capacity: i32) -> Result<Vec<Bank>, Error> {
+        self.mock.call("get_bank_list", &[&capacity])
+    }
+    fn get_parameter_description_count(&self) -> Result<i32, Error> {
+        self.mock.call("get_parameter_description_count", &[])
+    }
+    fn get_parameter_description_list(
+        &self,
+        capacity: i32,
+    ) -> Result<Vec<ParameterDescription>, Error> {
+        self.mock
+            .call("get_parameter_description_list", &[&capacity])
+    }
+    fn get_cpu_usage(&self) -> Result<(StudioCpuUsage, CpuUsage), Error> {
+        self.mock.call("get_cpu_usage", &[])
+    }
+    fn get_buffer_usage(&self) -> Result<BufferUsage, Error> {
+        self.mock.call("get_buffer_usage", &[])
+    }
+    fn reset_buffer_usage(&self) -> Result<(), Error> {
+        self.mock.call_or("reset_buffer_usage", &[], Ok(()))
+    }
+    fn set_callback(
+        &self,
+        callback: ffi::FMOD_STUDIO_SYSTEM_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_STUDIO_SYSTEM_CALLBACK_TYPE>,
+    ) -> Result<(), Error> {
+        let callbackmask: ffi::FMOD_STUDIO_SYSTEM_CALLBACK_TYPE = callbackmask.into();
+        self.mock
+            .call_or("set_callback", &[&callback, &callbackmask], Ok(()))
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+    fn get_memory_usage(&self) -> Result<MemoryUsage, Error> {
+        self.mock.call("get_memory_usage", &[])
+    }
+}
+#[doc = " Recording mock of [`VcaApi`]."]
+#[derive(Debug, Default)]
+pub struct MockVca {
+    mock: Mock,
+}
+impl MockVca {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `is_valid` call."]
+    pub fn is_valid_returns(&self, value: bool) -> &Self {
+        self.mock.returns("is_valid", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_id` call."]
+    pub fn get_id_returns(&self, value: Result<Guid, Error>) -> &Self {
+        self.mock.returns("get_id", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_path` call."]
+    pub fn get_path_returns(&self, value: Result<String, Error>) -> &Self {
+        self.mock.returns("get_path", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_volume` call."]
+    pub fn get_volume_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_volume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_volume` call."]
+    pub fn set_volume_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_volume", value);
+        self
+    }
+}
+impl Deref for MockVca {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl VcaApi for MockVca {
+    fn is_valid(&self) -> bool {
+        self.mock.call("is_valid", &[])
+    }
+    fn get_id(&self) -> Result<Guid, Error> {
+        self.mock.call("get_id", &[])
+    }
+    fn get_path(&self) -> Result<String, Error> {
+        self.mock.call("get_path", &[])
+    }
+    fn get_volume(&self) -> Result<(f32, f32), Error> {
+        self.mock.call("get_volume", &[])
+    }
+    fn set_volume(&self, volume: f32) -> Result<(), Error> {
+        self.mock.call_or("set_volume", &[&volume], Ok(()))
+    }
+}
+#[doc = " Recording mock of [`SystemApi`]."]
+#[derive(Debug, Default)]
+pub struct MockSystem {
+    mock: Mock,
+}
+impl MockSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = " Scripts value returned by next `release` call."]
+    pub fn release_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("release", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_output` call."]
+    pub fn set_output_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_output", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_output` call."]
+    pub fn get_output_returns(&self, value: Result<OutputType, Error>) -> &Self {
+        self.mock.returns("get_output", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_drivers` call."]
+    pub fn get_num_drivers_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_drivers", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_driver_info` call."]
+    pub fn get_driver_info_returns(
+        &self,
+        value: Result<(String, Guid, i32, SpeakerMode, i32), Error>,
+    ) -> &Self {
+        self.mock.returns("get_driver_info", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_driver` call."]
+    pub fn set_driver_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_driver", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_driver` call."]
+    pub fn get_driver_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_driver", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_software_channels` call."]
+    pub fn set_software_channels_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_software_channels", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_software_channels` call."]
+    pub fn get_software_channels_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_software_channels", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_software_format` call."]
+    pub fn set_software_format_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_software_format", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_software_format` call."]
+    pub fn get_software_format_returns(
+        &self,
+        value: Result<(i32, SpeakerMode, i32), Error>,
+    ) -> &Self {
+        self.mock.returns("get_software_format", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_dsp_buffer_size` call."]
+    pub fn set_dsp_buffer_size_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_dsp_buffer_size", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_dsp_buffer_size` call."]
+    pub fn get_dsp_buffer_size_returns(&self, value: Result<(u32, i32), Error>) -> &Self {
+        self.mock.returns("get_dsp_buffer_size", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_file_system` call."]
+    pub fn set_file_system_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_file_system", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `attach_file_system` call."]
+    pub fn attach_file_system_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("attach_file_system", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_advanced_settings` call."]
+    pub fn set_advanced_settings_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_advanced_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_advanced_settings` call."]
+    pub fn get_advanced_settings_returns(&self, value: Result<AdvancedSettings, Error>) -> &Self {
+        self.mock.returns("get_advanced_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_callback` call."]
+    pub fn set_callback_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_callback", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_plugin_path` call."]
+    pub fn set_plugin_path_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_plugin_path", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `load_plugin` call."]
+    pub fn load_plugin_returns(&self, value: Result<u32, Error>) -> &Self {
+        self.mock.returns("load_plugin", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `unload_plugin` call."]
+    pub fn unload_plugin_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("unload_plugin", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_nested_plugins` call."]
+    pub fn get_num_nested_plugins_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_nested_plugins", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_nested_plugin` call."]
+    pub fn get_nested_plugin_returns(&self, value: Result<u32, Error>) -> &Self {
+        self.mock.returns("get_nested_plugin", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_num_plugins` call."]
+    pub fn get_num_plugins_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_num_plugins", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_plugin_handle` call."]
+    pub fn get_plugin_handle_returns(&self, value: Result<u32, Error>) -> &Self {
+        self.mock.returns("get_plugin_handle", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_plugin_info` call."]
+    pub fn get_plugin_info_returns(
+        &self,
+        value: Result<(PluginType, String, u32), Error>,
+    ) -> &Self {
+        self.mock.returns("get_plugin_info", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_output_by_plugin` call."]
+    pub fn set_output_by_plugin_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_output_by_plugin", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_output_by_plugin` call."]
+    pub fn get_output_by_plugin_returns(&self, value: Result<u32, Error>) -> &Self {
+        self.mock.returns("get_output_by_plugin", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `create_dsp_by_plugin` call."]
+    pub fn create_dsp_by_plugin_returns(&self, value: Result<Dsp, Error>) -> &Self {
+        self.mock.returns("create_dsp_by_plugin", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_dsp_info_by_plugin` call."]
+    pub fn get_dsp_info_by_plugin_returns(&self, value: Result<DspDescription, Error>) -> &Self {
+        self.mock.returns("get_dsp_info_by_plugin", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `register_codec` call."]
+    pub fn register_codec_returns(&self, value: Result<u32, Error>) -> &Self {
+        self.mock.returns("register_codec", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `register_dsp` call."]
+    pub fn register_dsp_returns(&self, value: Result<u32, Error>) -> &Self {
+        self.mock.returns("register_dsp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `register_output` call."]
+    pub fn register_output_returns(&self, value: Result<u32, Error>) -> &Self {
+        self.mock.returns("register_output", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `init` call."]
+    pub fn init_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("init", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `close` call."]
+    pub fn close_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("close", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `update` call."]
+    pub fn update_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("update", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_speaker_position` call."]
+    pub fn set_speaker_position_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_speaker_position", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_speaker_position` call."]
+    pub fn get_speaker_position_returns(&self, value: Result<(f32, f32, bool), Error>) -> &Self {
+        self.mock.returns("get_speaker_position", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_stream_buffer_size` call."]
+    pub fn set_stream_buffer_size_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_stream_buffer_size", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_stream_buffer_size` call."]
+    pub fn get_stream_buffer_size_returns(
+        &self,
+        value: Result<(u32, ffi::FMOD_TIMEUNIT), Error>,
+    ) -> &Self {
+        self.mock.returns("get_stream_buffer_size", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_settings` call."]
+    pub fn set_3d_settings_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_settings` call."]
+    pub fn get_3d_settings_returns(&self, value: Result<(f32, f32, f32), Error>) -> &Self {
+        self.mock.returns("get_3d_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_num_listeners` call."]
+    pub fn set_3d_num_listeners_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_num_listeners", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_num_listeners` call."]
+    pub fn get_3d_num_listeners_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_3d_num_listeners", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_listener_attributes` call."]
+    pub fn set_3d_listener_attributes_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_listener_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_3d_listener_attributes` call."]
+    pub fn get_3d_listener_attributes_returns(
+        &self,
+        value: Result<(Vector, Vector, Vector, Vector), Error>,
+    ) -> &Self {
+        self.mock.returns("get_3d_listener_attributes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_3d_rolloff_callback` call."]
+    pub fn set_3d_rolloff_callback_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_3d_rolloff_callback", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `mixer_suspend` call."]
+    pub fn mixer_suspend_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("mixer_suspend", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `mixer_resume` call."]
+    pub fn mixer_resume_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("mixer_resume", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_default_mix_matrix` call."]
+    pub fn get_default_mix_matrix_returns(&self, value: Result<MixMatrix, Error>) -> &Self {
+        self.mock.returns("get_default_mix_matrix", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_speaker_mode_channels` call."]
+    pub fn get_speaker_mode_channels_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_speaker_mode_channels", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_version` call."]
+    pub fn get_version_returns(&self, value: Result<u32, Error>) -> &Self {
+        self.mock.returns("get_version", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_output_handle` call."]
+    pub fn get_output_handle_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_output_handle", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_channels_playing` call."]
+    pub fn get_channels_playing_returns(&self, value: Result<(i32, i32), Error>) -> &Self {
+        self.mock.returns("get_channels_playing", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_cpu_usage` call."]
+    pub fn get_cpu_usage_returns(&self, value: Result<CpuUsage, Error>) -> &Self {
+        self.mock.returns("get_cpu_usage", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_file_usage` call."]
+    pub fn get_file_usage_returns(&self, value: Result<(i64, i64, i64), Error>) -> &Self {
+        self.mock.returns("get_file_usage", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `create_sound` call."]
+    pub fn create_sound_returns(&self, value: Result<Sound, Error>) -> &Self {
+        self.mock.returns("create_sound", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `create_sound_from` call."]
+    pub fn create_sound_from_returns(&self, value: Result<Sound, Error>) -> &Self {
+        self.mock.returns("create_sound_from", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `create_stream` call."]
+    pub fn create_stream_returns(&self, value: Result<Sound, Error>) -> &Self {
+        self.mock.returns("create_stream", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `create_stream_from` call."]
+    pub fn create_stream_from_returns(&self, value: Result<Sound, Error>) -> &Self {
+        self.mock.returns("create_stream_from", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `create_dsp` call."]
+    pub fn create_dsp_returns(&self, value: Result<Dsp, Error>) -> &Self {
+        self.mock.returns("create_dsp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `create_dsp_by_type` call."]
+    pub fn create_dsp_by_type_returns(&self, value: Result<Dsp, Error>) -> &Self {
+        self.mock.returns("create_dsp_by_type", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `create_channel_group` call."]
+    pub fn create_channel_group_returns(&self, value: Result<ChannelGroup, Error>) -> &Self {
+        self.mock.returns("create_channel_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `create_sound_group` call."]
+    pub fn create_sound_group_returns(&self, value: Result<SoundGroup, Error>) -> &Self {
+        self.mock.returns("create_sound_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `create_reverb_3d` call."]
+    pub fn create_reverb_3d_returns(&self, value: Result<Reverb3d, Error>) -> &Self {
+        self.mock.returns("create_reverb_3d", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `play_sound` call."]
+    pub fn play_sound_returns(&self, value: Result<Channel, Error>) -> &Self {
+        self.mock.returns("play_sound", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `play_dsp` call."]
+    pub fn play_dsp_returns(&self, value: Result<Channel, Error>) -> &Self {
+        self.mock.returns("play_dsp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_channel` call."]
+    pub fn get_channel_returns(&self, value: Result<Channel, Error>) -> &Self {
+        self.mock.returns("get_channel", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_dsp_info_by_type` call."]
+    pub fn get_dsp_info_by_type_returns(&self, value: Result<DspDescription, Error>) -> &Self {
+        self.mock.returns("get_dsp_info_by_type", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_master_channel_group` call."]
+    pub fn get_master_channel_group_returns(&self, value: Result<ChannelGroup, Error>) -> &Self {
+        self.mock.returns("get_master_channel_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_master_sound_group` call."]
+    pub fn get_master_sound_group_returns(&self, value: Result<SoundGroup, Error>) -> &Self {
+        self.mock.returns("get_master_sound_group", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `attach_channel_group_to_port` call."]
+    pub fn attach_channel_group_to_port_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("attach_channel_group_to_port", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `detach_channel_group_from_port` call."]
+    pub fn detach_channel_group_from_port_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("detach_channel_group_from_port", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_reverb_properties` call."]
+    pub fn set_reverb_properties_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_reverb_properties", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_reverb_properties` call."]
+    pub fn get_reverb_properties_returns(&self, value: Result<ReverbProperties, Error>) -> &Self {
+        self.mock.returns("get_reverb_properties", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `lock_dsp` call."]
+    pub fn lock_dsp_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("lock_dsp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `unlock_dsp` call."]
+    pub fn unlock_dsp_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("unlock_dsp", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_record_num_drivers` call."]
+    pub fn get_record_num_drivers_returns(&self, value: Result<(i32, i32), Error>) -> &Self {
+        self.mock.returns("get_record_num_drivers", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_record_driver_info` call."]
+    pub fn get_record_driver_info_returns(
+        &self,
+        value: Result<(String, Guid, i32, SpeakerMode, i32, ffi::FMOD_DRIVER_STATE), Error>,
+    ) -> &Self {
+        self.mock.returns("get_record_driver_info", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_record_position` call."]
+    pub fn get_record_position_returns(&self, value: Result<u32, Error>) -> &Self {
+        self.mock.returns("get_record_position", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `record_start` call."]
+    pub fn record_start_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("record_start", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `record_stop` call."]
+    pub fn record_stop_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("record_stop", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `is_recording` call."]
+    pub fn is_recording_returns(&self, value: Result<bool, Error>) -> &Self {
+        self.mock.returns("is_recording", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `create_geometry` call."]
+    pub fn create_geometry_returns(&self, value: Result<Geometry, Error>) -> &Self {
+        self.mock.returns("create_geometry", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_geometry_settings` call."]
+    pub fn set_geometry_settings_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_geometry_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_geometry_settings` call."]
+    pub fn get_geometry_settings_returns(&self, value: Result<f32, Error>) -> &Self {
+        self.mock.returns("get_geometry_settings", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `load_geometry_bytes` call."]
+    pub fn load_geometry_bytes_returns(&self, value: Result<Geometry, Error>) -> &Self {
+        self.mock.returns("load_geometry_bytes", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_geometry_occlusion` call."]
+    pub fn get_geometry_occlusion_returns(&self, value: Result<(f32, f32), Error>) -> &Self {
+        self.mock.returns("get_geometry_occlusion", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_network_proxy` call."]
+    pub fn set_network_proxy_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_network_proxy", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_network_proxy` call."]
+    pub fn get_network_proxy_returns(&self, value: Result<String, Error>) -> &Self {
+        self.mock.returns("get_network_proxy", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_network_timeout` call."]
+    pub fn set_network_timeout_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_network_timeout", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_network_timeout` call."]
+    pub fn get_network_timeout_returns(&self, value: Result<i32, Error>) -> &Self {
+        self.mock.returns("get_network_timeout", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `set_user_data` call."]
+    pub fn set_user_data_returns(&self, value: Result<(), Error>) -> &Self {
+        self.mock.returns("set_user_data", value);
+        self
+    }
+    #[doc = " Scripts value returned by next `get_user_data` call."]
+    pub fn get_user_data_returns(&self, value: Result<*mut c_void, Error>) -> &Self {
+        self.mock.returns("get_user_data", value);
+        self
+    }
+}
+impl Deref for MockSystem {
+    type Target = Mock;
+    fn deref(&self) -> &Self::Target {
+        &self.mock
+    }
+}
+impl SystemApi for MockSystem {
+    fn release(&self) -> Result<(), Error> {
+        self.mock.call_or("release", &[], Ok(()))
+    }
+    fn set_output(&self, output: OutputType) -> Result<(), Error> {
+        self.mock.call_or("set_output", &[&output], Ok(()))
+    }
+    fn get_output(&self) -> Result<OutputType, Error> {
+        self.mock.call("get_output", &[])
+    }
+    fn get_num_drivers(&self) -> Result<i32, Error> {
+        self.mock.call("get_num_drivers", &[])
+    }
+    fn get_driver_info(
+        &self,
+        id: i32,
+        namelen: i32,
+    ) -> Result<(String, Guid, i32, SpeakerMode, i32), Error> {
+        self.mock.call("get_driver_info", &[&id, &namelen])
+    }
+    fn set_driver(&self, driver: i32) -> Result<(), Error> {
+        self.mock.call_or("set_driver", &[&driver], Ok(()))
+    }
+    fn get_driver(&self) -> Result<i32, Error> {
+        self.mock.call("get_driver", &[])
+    }
+    fn set_software_channels(&self, numsoftwarechannels: i32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_software_channels", &[&numsoftwarechannels], Ok(()))
+    }
+    fn get_software_channels(&self) -> Result<i32, Error> {
+        self.mock.call("get_software_channels", &[])
+    }
+    fn set_software_format(
+        &self,
+        samplerate: Option<i32>,
+        speakermode: Option<SpeakerMode>,
+        numrawspeakers: Option<i32>,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_software_format",
+            &[&samplerate, &speakermode, &numrawspeakers],
+            Ok(()),
+        )
+    }
+    fn get_software_format(&self) -> Result<(i32, SpeakerMode, i32), Error> {
+        self.mock.call("get_software_format", &[])
+    }
+    fn set_dsp_buffer_size(&self, bufferlength: u32, numbuffers: i32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_dsp_buffer_size", &[&bufferlength, &numbuffers], Ok(()))
+    }
+    fn get_dsp_buffer_size(&self) -> Result<(u32, i32), Error> {
+        self.mock.call("get_dsp_buffer_size", &[])
+    }
+    fn set_file_system(
+        &self,
+        useropen: ffi::FMOD_FILE_OPEN_CALLBACK,
+        userclose: ffi::FMOD_FILE_CLOSE_CALLBACK,
+        userread: ffi::FMOD_FILE_READ_CALLBACK,
+        userseek: ffi::FMOD_FILE_SEEK_CALLBACK,
+        userasyncread: ffi::FMOD_FILE_ASYNCREAD_CALLBACK,
+        userasynccancel: ffi::FMOD_FILE_ASYNCCANCEL_CALLBACK,
+        blockalign: Option<i32>,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_file_system",
+            &[
+                &useropen,
+                &userclose,
+                &userread,
+                &userseek,
+                &userasyncread,
+                &userasynccancel,
+                &blockalign,
+            ],
+            Ok(()),
+        )
+    }
+    fn attach_file_system(
+        &self,
+        useropen: ffi::FMOD_FILE_OPEN_CALLBACK,
+        userclose: ffi::FMOD_FILE_CLOSE_CALLBACK,
+        userread: ffi::FMOD_FILE_READ_CALLBACK,
+        userseek: ffi::FMOD_FILE_SEEK_CALLBACK,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "attach_file_system",
+            &[&useropen, &userclose, &userread, &userseek],
+            Ok(()),
+        )
+    }
+    fn set_advanced_settings(&self, settings: AdvancedSettings) -> Result<(), Error> {
+        self.mock
+            .call_or("set_advanced_settings", &[&settings], Ok(()))
+    }
+    fn get_advanced_settings(&self) -> Result<AdvancedSettings, Error> {
+        self.mock.call("get_advanced_settings", &[])
+    }
+    fn set_callback(
+        &self,
+        callback: ffi::FMOD_SYSTEM_CALLBACK,
+        callbackmask: impl Into<ffi::FMOD_SYSTEM_CALLBACK_TYPE>,
+    ) -> Result<(), Error> {
+        let callbackmask: ffi::FMOD_SYSTEM_CALLBACK_TYPE = callbackmask.into();
+        self.mock
+            .call_or("set_callback", &[&callback, &callbackmask], Ok(()))
+    }
+    fn set_plugin_path(&self, path: &str) -> Result<(), Error> {
+        self.mock.call_or("set_plugin_path", &[&path], Ok(()))
+    }
+    fn load_plugin(&self, filename: &str, priority: Option<u32>) -> Result<u32, Error> {
+        self.mock.call("load_plugin", &[&filename, &priority])
+    }
+    fn unload_plugin(&self, handle: u32) -> Result<(), Error> {
+        self.mock.call_or("unload_plugin", &[&handle], Ok(()))
+    }
+    fn get_num_nested_plugins(&self, handle: u32) -> Result<i32, Error> {
+        self.mock.call("get_num_nested_plugins", &[&handle])
+    }
+    fn get_nested_plugin(&self, handle: u32, index: i32) -> Result<u32, Error> {
+        self.mock.call("get_nested_plugin", &[&handle, &index])
+    }
+    fn get_num_plugins(&self, plugintype: PluginType) -> Result<i32, Error> {
+        self.mock.call("get_num_plugins", &[&plugintype])
+    }
+    fn get_plugin_handle(&self, plugintype: PluginType, index: i32) -> Result<u32, Error> {
+        self.mock.call("get_plugin_handle", &[&plugintype, &index])
+    }
+    fn get_plugin_info(
+        &self,
+        handle: u32,
+        namelen: i32,
+    ) -> Result<(PluginType, String, u32), Error> {
+        self.mock.call("get_plugin_info", &[&handle, &namelen])
+    }
+    fn set_output_by_plugin(&self, handle: u32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_output_by_plugin", &[&handle], Ok(()))
+    }
+    fn get_output_by_plugin(&self) -> Result<u32, Error> {
+        self.mock.call("get_output_by_plugin", &[])
+    }
+    fn create_dsp_by_plugin(&self, handle: u32) -> Result<Dsp, Error> {
+        self.mock.call("create_dsp_by_plugin", &[&handle])
+    }
+    fn get_dsp_info_by_plugin(&self, handle: u32) -> Result<DspDescription, Error> {
+        self.mock.call("get_dsp_info_by_plugin", &[&handle])
+    }
+    fn register_codec(
+        &self,
+        description: CodecDescription,
+        priority: Option<u32>,
+    ) -> Result<u32, Error> {
+        self.mock.call("register_codec", &[&description, &priority])
+    }
+    fn register_dsp(&self, description: DspDescription) -> Result<u32, Error> {
+        self.mock.call("register_dsp", &[&description])
+    }
+    fn register_output(&self, description: OutputDescription) -> Result<u32, Error> {
+        self.mock.call("register_output", &[&description])
+    }
+    fn init(
+        &self,
+        maxchannels: i32,
+        flags: impl Into<ffi::FMOD_INITFLAGS>,
+        extradriverdata: Option<*mut c_void>,
+    ) -> Result<(), Error> {
+        let flags: ffi::FMOD_INITFLAGS = flags.into();
+        self.mock
+            .call_or("init", &[&maxchannels, &flags, &extradriverdata], Ok(()))
+    }
+    fn close(&self) -> Result<(), Error> {
+        self.mock.call_or("close", &[], Ok(()))
+    }
+    fn update(&self) -> Result<(), Error> {
+        self.mock.call_or("update", &[], Ok(()))
+    }
+    fn set_speaker_position(
+        &self,
+        speaker: Speaker,
+        x: f32,
+        y: f32,
+        active: bool,
+    ) -> Result<(), Error> {
+        self.mock
+            .call_or("set_speaker_position", &[&speaker, &x, &y, &active], Ok(()))
+    }
+    fn get_speaker_position(&self, speaker: Speaker) -> Result<(f32, f32, bool), Error> {
+        self.mock.call("get_speaker_position", &[&speaker])
+    }
+    fn set_stream_buffer_size(
+        &self,
+        filebuffersize: u32,
+        filebuffersizetype: impl Into<ffi::FMOD_TIMEUNIT>,
+    ) -> Result<(), Error> {
+        let filebuffersizetype: ffi::FMOD_TIMEUNIT = filebuffersizetype.into();
+        self.mock.call_or(
+            "set_stream_buffer_size",
+            &[&filebuffersize, &filebuffersizetype],
+            Ok(()),
+        )
+    }
+    fn get_stream_buffer_size(&self) -> Result<(u32, ffi::FMOD_TIMEUNIT), Error> {
+        self.mock.call("get_stream_buffer_size", &[])
+    }
+    fn set_3d_settings(
+        &self,
+        dopplerscale: f32,
+        distancefactor: f32,
+        rolloffscale: f32,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_3d_settings",
+            &[&dopplerscale, &distancefactor, &rolloffscale],
+            Ok(()),
+        )
+    }
+    fn get_3d_settings(&self) -> Result<(f32, f32, f32), Error> {
+        self.mock.call("get_3d_settings", &[])
+    }
+    fn set_3d_num_listeners(&self, numlisteners: i32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_3d_num_listeners", &[&numlisteners], Ok(()))
+    }
+    fn get_3d_num_listeners(&self) -> Result<i32, Error> {
+        self.mock.call("get_3d_num_listeners", &[])
+    }
+    fn set_3d_listener_attributes(
+        &self,
+        listener: i32,
+        pos: Option<Vector>,
+        vel: Option<Vector>,
+        forward: Option<Vector>,
+        up: Option<Vector>,
+    ) -> Result<(), Error> {
+        self.mock.call_or(
+            "set_3d_listener_attributes",
+            &[&listener, &pos, &vel, &forward, &up],
+            Ok(()),
+        )
+    }
+    fn get_3d_listener_attributes(
+        &self,
+        listener: i32,
+    ) -> Result<(Vector, Vector, Vector, Vector), Error> {
+        self.mock.call("get_3d_listener_attributes", &[&listener])
+    }
+    fn set_3d_rolloff_callback(
+        &self,
+        callback: ffi::FMOD_3D_ROLLOFF_CALLBACK,
+    ) -> Result<(), Error> {
+        self.mock
+            .call_or("set_3d_rolloff_callback", &[&callback], Ok(()))
+    }
+    fn mixer_suspend(&self) -> Result<(), Error> {
+        self.mock.call_or("mixer_suspend", &[], Ok(()))
+    }
+    fn mixer_resume(&self) -> Result<(), Error> {
+        self.mock.call_or("mixer_resume", &[], Ok(()))
+    }
+    fn get_default_mix_matrix(
+        &self,
+        sourcespeakermode: SpeakerMode,
+        targetspeakermode: SpeakerMode,
+    ) -> Result<MixMatrix, Error> {
+        self.mock.call(
+            "get_default_mix_matrix",
+            &[&sourcespeakermode, &targetspeakermode],
+        )
+    }
+    fn get_speaker_mode_channels(&self, mode: SpeakerMode) -> Result<i32, Error> {
+        self.mock.call("get_speaker_mode_channels", &[&mode])
+    }
+    fn get_version(&self) -> Result<u32, Error> {
+        self.mock.call("get_version", &[])
+    }
+    fn get_output_handle(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_output_handle", &[])
+    }
+    fn get_channels_playing(&self) -> Result<(i32, i32), Error> {
+        self.mock.call("get_channels_playing", &[])
+    }
+    fn get_cpu_usage(&self) -> Result<CpuUsage, Error> {
+        self.mock.call("get_cpu_usage", &[])
+    }
+    fn get_file_usage(&self) -> Result<(i64, i64, i64), Error> {
+        self.mock.call("get_file_usage", &[])
+    }
+    fn create_sound(
+        &self,
+        name_or_data: &str,
+        mode: impl Into<ffi::FMOD_MODE>,
+        exinfo: Option<CreateSoundexInfo>,
+    ) -> Result<Sound, Error> {
+        let mode: ffi::FMOD_MODE = mode.into();
+        self.mock
+            .call("create_sound", &[&name_or_data, &mode, &exinfo])
+    }
+    fn create_sound_from(
+        &self,
+        data: &[u8],
+        mode: impl Into<ffi::FMOD_MODE>,
+        exinfo: CreateSoundexInfo,
+    ) -> Result<Sound, Error> {
+        let mode: ffi::FMOD_MODE = mode.into();
+        self.mock
+            .call("create_sound_from", &[&data, &mode, &exinfo])
+    }
+    fn create_stream(
+        &self,
+        name_or_data: &str,
+        mode: impl Into<ffi::FMOD_MODE>,
+        exinfo: Option<CreateSoundexInfo>,
+    ) -> Result<Sound, Error> {
+        let mode: ffi::FMOD_MODE = mode.into();
+        self.mock
+            .call("create_stream", &[&name_or_data, &mode, &exinfo])
+    }
+    fn create_stream_from(
+        &self,
+        data: &[u8],
+        mode: impl Into<ffi::FMOD_MODE>,
+        exinfo: CreateSoundexInfo,
+    ) -> Result<Sound, Error> {
+        let mode: ffi::FMOD_MODE = mode.into();
+        self.mock
+            .call("create_stream_from", &[&data, &mode, &exinfo])
+    }
+    fn create_dsp(&self, description: DspDescription) -> Result<Dsp, Error> {
+        self.mock.call("create_dsp", &[&description])
+    }
+    fn create_dsp_by_type(&self, type_: DspType) -> Result<Dsp, Error> {
+        self.mock.call("create_dsp_by_type", &[&type_])
+    }
+    fn create_channel_group(&self, name: Option<String>) -> Result<ChannelGroup, Error> {
+        self.mock.call("create_channel_group", &[&name])
+    }
+    fn create_sound_group(&self, name: &str) -> Result<SoundGroup, Error> {
+        self.mock.call("create_sound_group", &[&name])
+    }
+    fn create_reverb_3d(&self) -> Result<Reverb3d, Error> {
+        self.mock.call("create_reverb_3d", &[])
+    }
+    fn play_sound(
+        &self,
+        sound: Sound,
+        channelgroup: Option<ChannelGroup>,
+        paused: bool,
+    ) -> Result<Channel, Error> {
+        self.mock
+            .call("play_sound", &[&sound, &channelgroup, &paused])
+    }
+    fn play_dsp(
+        &self,
+        dsp: Dsp,
+        channelgroup: Option<ChannelGroup>,
+        paused: bool,
+    ) -> Result<Channel, Error> {
+        self.mock.call("play_dsp", &[&dsp, &channelgroup, &paused])
+    }
+    fn get_channel(&self, channelid: i32) -> Result<Channel, Error> {
+        self.mock.call("get_channel", &[&channelid])
+    }
+    fn get_dsp_info_by_type(&self, type_: DspType) -> Result<DspDescription, Error> {
+        self.mock.call("get_dsp_info_by_type", &[&type_])
+    }
+    fn get_master_channel_group(&self) -> Result<ChannelGroup, Error> {
+        self.mock.call("get_master_channel_group", &[])
+    }
+    fn get_master_sound_group(&self) -> Result<SoundGroup, Error> {
+        self.mock.call("get_master_sound_group", &[])
+    }
+    fn attach_channel_group_to_port(
+        &self,
+        port_type: PortType,
+        port_index: impl Into<ffi::FMOD_PORT_INDEX>,
+        channelgroup: ChannelGroup,
+        pass_thru: bool,
+    ) -> Result<(), Error> {
+        let port_index: ffi::FMOD_PORT_INDEX = port_index.into();
+        self.mock.call_or(
+            "attach_channel_group_to_port",
+            &[&port_type, &port_index, &channelgroup, &pass_thru],
+            Ok(()),
+        )
+    }
+    fn detach_channel_group_from_port(&self, channelgroup: ChannelGroup) -> Result<(), Error> {
+        self.mock
+            .call_or("detach_channel_group_from_port", &[&channelgroup], Ok(()))
+    }
+    fn set_reverb_properties(&self, instance: i32, prop: ReverbProperties) -> Result<(), Error> {
+        self.mock
+            .call_or("set_reverb_properties", &[&instance, &prop], Ok(()))
+    }
+    fn get_reverb_properties(&self, instance: i32) -> Result<ReverbProperties, Error> {
+        self.mock.call("get_reverb_properties", &[&instance])
+    }
+    fn lock_dsp(&self) -> Result<(), Error> {
+        self.mock.call_or("lock_dsp", &[], Ok(()))
+    }
+    fn unlock_dsp(&self) -> Result<(), Error> {
+        self.mock.call_or("unlock_dsp", &[], Ok(()))
+    }
+    fn get_record_num_drivers(&self) -> Result<(i32, i32), Error> {
+        self.mock.call("get_record_num_drivers", &[])
+    }
+    fn get_record_driver_info(
+        &self,
+        id: i32,
+        namelen: i32,
+    ) -> Result<(String, Guid, i32, SpeakerMode, i32, ffi::FMOD_DRIVER_STATE), Error> {
+        self.mock.call("get_record_driver_info", &[&id, &namelen])
+    }
+    fn get_record_position(&self, id: i32) -> Result<u32, Error> {
+        self.mock.call("get_record_position", &[&id])
+    }
+    fn record_start(&self, id: i32, sound: Sound, loop_: bool) -> Result<(), Error> {
+        self.mock
+            .call_or("record_start", &[&id, &sound, &loop_], Ok(()))
+    }
+    fn record_stop(&self, id: i32) -> Result<(), Error> {
+        self.mock.call_or("record_stop", &[&id], Ok(()))
+    }
+    fn is_recording(&self, id: i32) -> Result<bool, Error> {
+        self.mock.call("is_recording", &[&id])
+    }
+    fn create_geometry(&self, maxpolygons: i32, maxvertices: i32) -> Result<Geometry, Error> {
+        self.mock
+            .call("create_geometry", &[&maxpolygons, &maxvertices])
+    }
+    fn set_geometry_settings(&self, maxworldsize: f32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_geometry_settings", &[&maxworldsize], Ok(()))
+    }
+    fn get_geometry_settings(&self) -> Result<f32, Error> {
+        self.mock.call("get_geometry_settings", &[])
+    }
+    fn load_geometry_bytes(&self, data: &[u8]) -> Result<Geometry, Error> {
+        self.mock.call("load_geometry_bytes", &[&data])
+    }
+    fn get_geometry_occlusion(
+        &self,
+        listener: Vector,
+        source: Vector,
+    ) -> Result<(f32, f32), Error> {
+        self.mock
+            .call("get_geometry_occlusion", &[&listener, &source])
+    }
+    fn set_network_proxy(&self, proxy: &str) -> Result<(), Error> {
+        self.mock.call_or("set_network_proxy", &[&proxy], Ok(()))
+    }
+    fn get_network_proxy(&self, proxylen: i32) -> Result<String, Error> {
+        self.mock.call("get_network_proxy", &[&proxylen])
+    }
+    fn set_network_timeout(&self, timeout: i32) -> Result<(), Error> {
+        self.mock
+            .call_or("set_network_timeout", &[&timeout], Ok(()))
+    }
+    fn get_network_timeout(&self) -> Result<i32, Error> {
+        self.mock.call("get_network_timeout", &[])
+    }
+    fn set_user_data(&self, userdata: *mut c_void) -> Result<(), Error> {
+        self.mock.call_or("set_user_data", &[&userdata], Ok(()))
+    }
+    fn get_user_data(&self) -> Result<*mut c_void, Error> {
+        self.mock.call("get_user_data", &[])
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{Channel, Error, EventInstance, System, Vector};
+
+/// Occlusion of sound path, values are in range 0 (open) to 1 (blocked).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Occlusion {
+    pub direct: f32,
+    pub reverb: f32,
+}
+
+/// Source of occlusion between listener and emitter.
+///
+/// `System` queries FMOD geometry, games with own physics can implement it
+/// (or pass a closure) to raycast against their level instead.
+pub trait OcclusionRaycaster {
+    fn occlusion(&self, listener: Vector, emitter: Vector) -> Result<Occlusion, Error>;
+}
+
+impl OcclusionRaycaster for System {
+    fn occlusion(&self, listener: Vector, emitter: Vector) -> Result<Occlusion, Error> {
+        let (direct, reverb) = self.get_geometry_occlusion(listener, emitter)?;
+        Ok(Occlusion { direct, reverb })
+    }
+}
+
+impl<F> OcclusionRaycaster for F
+where
+    F: Fn(Vector, Vector) -> Result<Occlusion, Error>,
+{
+    fn occlusion(&self, listener: Vector, emitter: Vector) -> Result<Occlusion, Error> {
+        self(listener, emitter)
+    }
+}
+
+/// Where query result goes when `OcclusionQuery::apply` is enabled.
+#[derive(Debug, Clone)]
+pub enum OcclusionTarget {
+    /// Sets `Channel::set_3d_occlusion`.
+    Channel(Channel),
+    /// Sets 3D occlusion of the channel group of the instance, so FMOD applies
+    /// volume and low-pass filter the same way as for channels.
+    EventInstance(EventInstance),
+    /// Sets direct occlusion to the parameter of the instance, e.g. one that automates
+    /// low-pass cutoff in event authored in FMOD Studio.
+    EventParameter(EventInstance, String),
+}
+
+impl OcclusionTarget {
+    pub fn apply(&self, occlusion: Occlusion) -> Result<(), Error> {
+        match self {
+            OcclusionTarget::Channel(channel) => {
+                channel.set_3d_occlusion(occlusion.direct, occlusion.reverb)
+            }
+            OcclusionTarget::EventInstance(instance) => instance
+                .get_channel_group()?
+                .set_3d_occlusion(occlusion.direct, occlusion.reverb),
+            OcclusionTarget::EventParameter(instance, name) => {
+                instance.set_parameter_by_name(name, occlusion.direct, false)
+            }
+        }
+    }
+}
+
+/// Position of sound source with optional target for query result.
+#[derive(Debug, Clone)]
+pub struct Emitter {
+    pub position: Vector,
+    pub target: Option<OcclusionTarget>,
+}
+
+impl Emitter {
+    pub fn new(position: Vector) -> Self {
+        Self {
+            position,
+            target: None,
+        }
+    }
+
+    pub fn channel(position: Vector, channel: Channel) -> Self {
+        Self::target(position, OcclusionTarget::Channel(channel))
+    }
+
+    pub fn event(position: Vector, instance: EventInstance) -> Self {
+        Self::target(position, OcclusionTarget::EventInstance(instance))
+    }
+
+    pub fn event_parameter(position: Vector, instance: EventInstance, name: &str) -> Self {
+        Self::target(
+            position,
+            OcclusionTarget::EventParameter(instance, name.to_string()),
+        )
+    }
+
+    fn target(position: Vector, target: OcclusionTarget) -> Self {
+        Self {
+            position,
+            target: Some(target),
+        }
+    }
+}
+
+/// Batch of occlusion raycasts from one listener to many emitters.
+///
+/// ```ignore
+/// let query = OcclusionQuery::new(system).apply(true);
+/// let emitters = [
+///     Emitter::channel(door, channel),
+///     Emitter::event_parameter(window, instance, "Occlusion"),
+/// ];
+/// let results = query.run(listener, &emitters)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct OcclusionQuery<R> {
+    raycaster: R,
+    apply: bool,
+}
+
+impl<R: OcclusionRaycaster> OcclusionQuery<R> {
+    pub fn new(raycaster: R) -> Self {
+        Self {
+            raycaster,
+            apply: false,
+        }
+    }
+
+    /// Applies every result to target of its emitter right after raycast.
+    pub fn apply(mut self, apply: bool) -> Self {
+        self.apply = apply;
+        self
+    }
+
+    /// Returns occlusion per emitter in the same order, stops on the first error.
+    pub fn run(&self, listener: Vector, emitters: &[Emitter]) -> Result<Vec<Occlusion>, Error> {
+        let mut results = Vec::with_capacity(emitters.len());
+        for emitter in emitters {
+            let occlusion = self.raycaster.occlusion(listener, emitter.position)?;
+            if self.apply {
+                if let Some(target) = &emitter.target {
+                    target.apply(occlusion)?;
+                }
+            }
+            results.push(occlusion);
+        }
+        Ok(results)
+    }
+}
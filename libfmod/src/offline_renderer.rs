@@ -0,0 +1,163 @@
+use std::ffi::CStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::{ffi, Error, OutputFormat, OutputPlugin, SpeakerMode, System};
+
+/// Output plugin which keeps mixed samples in memory.
+struct Capture {
+    format: OutputFormat,
+    samples: Arc<Mutex<Vec<f32>>>,
+}
+
+impl OutputPlugin for Capture {
+    const NAME: &'static CStr = c"libfmod offline renderer";
+
+    fn init(&mut self, _: i32, _: SpeakerMode) -> Result<OutputFormat, Error> {
+        Ok(self.format)
+    }
+
+    fn mix(&mut self, buffer: &[f32]) {
+        if let Ok(mut samples) = self.samples.lock() {
+            samples.extend_from_slice(buffer);
+        }
+    }
+}
+
+/// Renders FMOD mix to memory without sound card, faster than real time.
+///
+/// The system is driven by custom output plugin, every `System::update` mixes one DSP block.
+/// Samples are interleaved by speaker mode channels.
+///
+/// ```ignore
+/// let mut renderer = OfflineRenderer::new(48000, SpeakerMode::Stereo)?;
+/// let system = renderer.system();
+/// let sound = system.create_sound("explosion.wav", Mode::DEFAULT, None)?;
+/// system.play_sound(sound, None, false)?;
+/// let samples = renderer.render(Duration::from_millis(500))?;
+/// assert!(OfflineRenderer::peak(&samples) <= 1.0);
+/// ```
+#[derive(Debug)]
+pub struct OfflineRenderer {
+    system: System,
+    sample_rate: i32,
+    channels: usize,
+    samples: Arc<Mutex<Vec<f32>>>,
+}
+
+impl OfflineRenderer {
+    pub fn new(sample_rate: i32, speaker_mode: SpeakerMode) -> Result<Self, Error> {
+        let mut renderer = Self {
+            system: System::create()?,
+            sample_rate,
+            channels: 0,
+            samples: Arc::default(),
+        };
+        // dropping renderer releases the system on error
+        let system = renderer.system;
+        system.set_software_format(Some(sample_rate), Some(speaker_mode), None)?;
+        renderer.channels = system.get_speaker_mode_channels(speaker_mode)? as usize;
+        let capture = Capture {
+            format: OutputFormat {
+                sample_rate,
+                speaker_mode,
+            },
+            samples: renderer.samples.clone(),
+        };
+        system.init_with_output(512, ffi::FMOD_INIT_STREAM_FROM_UPDATE, capture)?;
+        Ok(renderer)
+    }
+
+    /// System to create and play sounds with, it is released with the renderer.
+    pub fn system(&self) -> System {
+        self.system
+    }
+
+    pub fn sample_rate(&self) -> i32 {
+        self.sample_rate
+    }
+
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// Renders given number of frames, each frame has sample for every channel.
+    pub fn render_frames(&mut self, frames: usize) -> Result<Vec<f32>, Error> {
+        let length = frames * self.channels;
+        // mixer produces whole blocks, the rest is kept for the next call
+        loop {
+            let rendered = self.rendered();
+            if rendered >= length {
+                break;
+            }
+            self.system.update()?;
+            if self.rendered() == rendered {
+                // mixer is suspended
+                return Err(err_validation!(
+                    "OfflineRenderer::render_frames",
+                    "mixer is suspended"
+                ));
+            }
+        }
+        let mut samples = self
+            .samples
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        Ok(samples.drain(..length).collect())
+    }
+
+    /// Renders mix for given duration, rounded down to whole frames.
+    pub fn render(&mut self, duration: Duration) -> Result<Vec<f32>, Error> {
+        let frames = duration.as_secs_f64() * self.sample_rate as f64;
+        self.render_frames(frames as usize)
+    }
+
+    fn rendered(&self) -> usize {
+        self.samples.lock().map_or(0, |samples| samples.len())
+    }
+
+    /// Root mean square of samples, 0 for silence.
+    pub fn rms(samples: &[f32]) -> f32 {
+        if samples.is_empty() {
+            return 0.0;
+        }
+        let sum: f64 = samples.iter().map(|sample| (*sample as f64).powi(2)).sum();
+        (sum / samples.len() as f64).sqrt() as f32
+    }
+
+    /// The largest absolute sample value.
+    pub fn peak(samples: &[f32]) -> f32 {
+        samples
+            .iter()
+            .fold(0.0, |peak, sample| f32::max(peak, sample.abs()))
+    }
+
+    /// Whether no sample exceeds threshold, e.g. `0.0001` (-80 dB).
+    pub fn is_silent(samples: &[f32], threshold: f32) -> bool {
+        Self::peak(samples) <= threshold
+    }
+}
+
+impl Drop for OfflineRenderer {
+    fn drop(&mut self) {
+        let _ = self.system.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OfflineRenderer;
+
+    #[test]
+    fn test_rms() {
+        assert_eq!(OfflineRenderer::rms(&[]), 0.0);
+        assert_eq!(OfflineRenderer::rms(&[0.5, -0.5, 0.5, -0.5]), 0.5);
+    }
+
+    #[test]
+    fn test_peak_and_silence() {
+        assert_eq!(OfflineRenderer::peak(&[0.25, -0.5, 0.1]), 0.5);
+        assert!(OfflineRenderer::is_silent(&[0.00005, -0.00001], 0.0001));
+        assert!(!OfflineRenderer::is_silent(&[0.0, -0.01], 0.0001));
+    }
+}
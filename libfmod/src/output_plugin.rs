@@ -0,0 +1,370 @@
+use std::ffi::{c_int, c_uint, c_void, CStr};
+
+use crate::{errors, ffi, Error, OutputObject3Dinfo, PortType, SpeakerMode, System};
+
+/// Format of output selected by plugin, samples are always 32-bit float.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutputFormat {
+    pub sample_rate: i32,
+    pub speaker_mode: SpeakerMode,
+}
+
+/// Format of auxiliary port (e.g. controller speaker) accepted by plugin.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PortFormat {
+    pub sample_rate: i32,
+    pub channels: i32,
+}
+
+/// Output device implemented in Rust, e.g. streaming encoder or network sink.
+///
+/// FMOD mixes one DSP block on every `System::update` and passes it to `mix`
+/// as interleaved samples of the selected speaker mode.
+///
+/// ```ignore
+/// struct Encoder { stream: Stream }
+///
+/// impl OutputPlugin for Encoder {
+///     const NAME: &'static CStr = c"my encoder";
+///
+///     fn init(&mut self, sample_rate: i32, _: SpeakerMode) -> Result<OutputFormat, Error> {
+///         Ok(OutputFormat { sample_rate, speaker_mode: SpeakerMode::Stereo })
+///     }
+///
+///     fn mix(&mut self, buffer: &[f32]) {
+///         self.stream.encode(buffer);
+///     }
+/// }
+///
+/// system.init_with_output(512, Init::NORMAL, Encoder { stream })?;
+/// ```
+pub trait OutputPlugin: Send + 'static {
+    const NAME: &'static CStr;
+
+    /// Chooses output format, arguments are format of the software mixer.
+    fn init(&mut self, sample_rate: i32, speaker_mode: SpeakerMode) -> Result<OutputFormat, Error>;
+
+    /// Receives mixed block of interleaved samples.
+    fn mix(&mut self, buffer: &[f32]);
+
+    fn start(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Accepts port opened by `System::attach_channel_group_to_port`, ports are refused by default.
+    fn open_port(&mut self, _port_type: PortType, _index: u64) -> Option<PortFormat> {
+        None
+    }
+
+    /// Receives block of port mix, called after `mix`.
+    fn mix_port(&mut self, _port_type: PortType, _index: u64, _buffer: &[f32]) {}
+
+    fn close_port(&mut self, _port_type: PortType, _index: u64) {}
+
+    /// Number of hardware 3D objects, objects are not used by default.
+    fn max_objects(&self) -> i32 {
+        0
+    }
+
+    /// Receives object mix and its position, objects are identified by numbers starting from 1.
+    fn update_object(&mut self, _object: usize, _info: &OutputObject3Dinfo) {}
+
+    fn free_object(&mut self, _object: usize) {}
+}
+
+struct Port {
+    id: c_int,
+    port_type: PortType,
+    index: u64,
+    buffer: Vec<f32>,
+}
+
+/// Plugin with buffers, owned by FMOD output state from init to close.
+struct OutputHost<P> {
+    plugin: P,
+    block_length: c_uint,
+    buffer: Vec<f32>,
+    ports: Vec<Port>,
+    next_port: c_int,
+    next_object: usize,
+}
+
+pub(crate) fn result_code(
+    result: Result<(), Error>,
+    fallback: ffi::FMOD_RESULT,
+) -> ffi::FMOD_RESULT {
+    match result {
+        Ok(()) => ffi::FMOD_OK,
+        Err(Error::Fmod { code, .. }) => code,
+        Err(_) => fallback,
+    }
+}
+
+unsafe fn host<'a, P>(output_state: *mut ffi::FMOD_OUTPUT_STATE) -> Option<&'a mut OutputHost<P>> {
+    ((*output_state).plugindata as *mut OutputHost<P>).as_mut()
+}
+
+/// Takes plugin out of slot passed as `extradriverdata`, from now on FMOD owns it.
+unsafe extern "C" fn init<P: OutputPlugin>(
+    output_state: *mut ffi::FMOD_OUTPUT_STATE,
+    _selecteddriver: c_int,
+    _flags: ffi::FMOD_INITFLAGS,
+    outputrate: *mut c_int,
+    speakermode: *mut ffi::FMOD_SPEAKERMODE,
+    speakermodechannels: *mut c_int,
+    outputformat: *mut ffi::FMOD_SOUND_FORMAT,
+    dspbufferlength: c_int,
+    _dspnumbuffers: *mut c_int,
+    _dspnumadditionalbuffers: *mut c_int,
+    extradriverdata: *mut c_void,
+) -> ffi::FMOD_RESULT {
+    let Some(slot) = (extradriverdata as *mut Option<Box<OutputHost<P>>>).as_mut() else {
+        return ffi::FMOD_ERR_OUTPUT_INIT;
+    };
+    let Some(mut host) = slot.take() else {
+        return ffi::FMOD_ERR_OUTPUT_INIT;
+    };
+    let requested = match SpeakerMode::from(*speakermode) {
+        Ok(speaker_mode) => speaker_mode,
+        Err(_) => return ffi::FMOD_ERR_OUTPUT_FORMAT,
+    };
+    let format = match host.plugin.init(*outputrate, requested) {
+        Ok(format) => format,
+        Err(error) => return result_code(Err(error), ffi::FMOD_ERR_OUTPUT_INIT),
+    };
+    let channels = if format.speaker_mode == requested {
+        *speakermodechannels
+    } else {
+        match format.speaker_mode.speakers() {
+            Some(speakers) => speakers.len() as c_int,
+            None => return ffi::FMOD_ERR_OUTPUT_FORMAT,
+        }
+    };
+    *outputrate = format.sample_rate;
+    *speakermode = format.speaker_mode.into();
+    *speakermodechannels = channels;
+    *outputformat = ffi::FMOD_SOUND_FORMAT_PCMFLOAT;
+    host.block_length = dspbufferlength as c_uint;
+    host.buffer = vec![0.0; dspbufferlength as usize * channels as usize];
+    (*output_state).plugindata = Box::into_raw(host).cast();
+    ffi::FMOD_OK
+}
+
+unsafe extern "C" fn start<P: OutputPlugin>(
+    output_state: *mut ffi::FMOD_OUTPUT_STATE,
+) -> ffi::FMOD_RESULT {
+    match host::<P>(output_state) {
+        Some(host) => result_code(host.plugin.start(), ffi::FMOD_ERR_OUTPUT_INIT),
+        None => ffi::FMOD_ERR_OUTPUT_INIT,
+    }
+}
+
+unsafe extern "C" fn stop<P: OutputPlugin>(
+    output_state: *mut ffi::FMOD_OUTPUT_STATE,
+) -> ffi::FMOD_RESULT {
+    match host::<P>(output_state) {
+        Some(host) => result_code(host.plugin.stop(), ffi::FMOD_ERR_OUTPUT_DRIVERCALL),
+        None => ffi::FMOD_OK,
+    }
+}
+
+unsafe extern "C" fn close<P: OutputPlugin>(
+    output_state: *mut ffi::FMOD_OUTPUT_STATE,
+) -> ffi::FMOD_RESULT {
+    let host = std::mem::replace(&mut (*output_state).plugindata, std::ptr::null_mut());
+    if !host.is_null() {
+        drop(Box::from_raw(host as *mut OutputHost<P>));
+    }
+    ffi::FMOD_OK
+}
+
+unsafe extern "C" fn update<P: OutputPlugin>(
+    output_state: *mut ffi::FMOD_OUTPUT_STATE,
+) -> ffi::FMOD_RESULT {
+    let Some(host) = host::<P>(output_state) else {
+        return ffi::FMOD_ERR_OUTPUT_DRIVERCALL;
+    };
+    let Some(read_from_mixer) = (*output_state).readfrommixer else {
+        return ffi::FMOD_ERR_OUTPUT_DRIVERCALL;
+    };
+    let result = read_from_mixer(
+        output_state,
+        host.buffer.as_mut_ptr().cast(),
+        host.block_length,
+    );
+    if result != ffi::FMOD_OK {
+        return result;
+    }
+    host.plugin.mix(&host.buffer);
+    if let Some(copy_port) = (*output_state).copyport {
+        for port in &mut host.ports {
+            let result = copy_port(
+                output_state,
+                port.id,
+                port.buffer.as_mut_ptr().cast(),
+                host.block_length,
+            );
+            if result == ffi::FMOD_OK {
+                host.plugin
+                    .mix_port(port.port_type, port.index, &port.buffer);
+            }
+        }
+    }
+    ffi::FMOD_OK
+}
+
+unsafe extern "C" fn open_port<P: OutputPlugin>(
+    output_state: *mut ffi::FMOD_OUTPUT_STATE,
+    port_type: ffi::FMOD_PORT_TYPE,
+    port_index: ffi::FMOD_PORT_INDEX,
+    port_id: *mut c_int,
+    port_rate: *mut c_int,
+    port_channels: *mut c_int,
+    port_format: *mut ffi::FMOD_SOUND_FORMAT,
+) -> ffi::FMOD_RESULT {
+    let (Some(host), Ok(port_type)) = (host::<P>(output_state), PortType::from(port_type)) else {
+        return ffi::FMOD_ERR_INVALID_PARAM;
+    };
+    let Some(format) = host.plugin.open_port(port_type, port_index) else {
+        return ffi::FMOD_ERR_UNSUPPORTED;
+    };
+    host.next_port += 1;
+    host.ports.push(Port {
+        id: host.next_port,
+        port_type,
+        index: port_index,
+        buffer: vec![0.0; host.block_length as usize * format.channels as usize],
+    });
+    *port_id = host.next_port;
+    *port_rate = format.sample_rate;
+    *port_channels = format.channels;
+    *port_format = ffi::FMOD_SOUND_FORMAT_PCMFLOAT;
+    ffi::FMOD_OK
+}
+
+unsafe extern "C" fn close_port<P: OutputPlugin>(
+    output_state: *mut ffi::FMOD_OUTPUT_STATE,
+    port_id: c_int,
+) -> ffi::FMOD_RESULT {
+    let Some(host) = host::<P>(output_state) else {
+        return ffi::FMOD_ERR_INVALID_PARAM;
+    };
+    let Some(position) = host.ports.iter().position(|port| port.id == port_id) else {
+        return ffi::FMOD_ERR_INVALID_PARAM;
+    };
+    let port = host.ports.remove(position);
+    host.plugin.close_port(port.port_type, port.index);
+    ffi::FMOD_OK
+}
+
+unsafe extern "C" fn object_3d_get_info<P: OutputPlugin>(
+    output_state: *mut ffi::FMOD_OUTPUT_STATE,
+    maxhardwareobjects: *mut c_int,
+) -> ffi::FMOD_RESULT {
+    *maxhardwareobjects = host::<P>(output_state).map_or(0, |host| host.plugin.max_objects());
+    ffi::FMOD_OK
+}
+
+unsafe extern "C" fn object_3d_alloc<P: OutputPlugin>(
+    output_state: *mut ffi::FMOD_OUTPUT_STATE,
+    object3d: *mut *mut c_void,
+) -> ffi::FMOD_RESULT {
+    let Some(host) = host::<P>(output_state) else {
+        return ffi::FMOD_ERR_INVALID_PARAM;
+    };
+    host.next_object += 1;
+    *object3d = host.next_object as *mut c_void;
+    ffi::FMOD_OK
+}
+
+unsafe extern "C" fn object_3d_free<P: OutputPlugin>(
+    output_state: *mut ffi::FMOD_OUTPUT_STATE,
+    object3d: *mut c_void,
+) -> ffi::FMOD_RESULT {
+    if let Some(host) = host::<P>(output_state) {
+        host.plugin.free_object(object3d as usize);
+    }
+    ffi::FMOD_OK
+}
+
+unsafe extern "C" fn object_3d_update<P: OutputPlugin>(
+    output_state: *mut ffi::FMOD_OUTPUT_STATE,
+    object3d: *mut c_void,
+    info: *const ffi::FMOD_OUTPUT_OBJECT3DINFO,
+) -> ffi::FMOD_RESULT {
+    let (Some(host), Some(info)) = (host::<P>(output_state), info.as_ref()) else {
+        return ffi::FMOD_ERR_INVALID_PARAM;
+    };
+    match OutputObject3Dinfo::try_from(*info) {
+        Ok(info) => {
+            host.plugin.update_object(object3d as usize, &info);
+            ffi::FMOD_OK
+        }
+        Err(error) => result_code(Err(error), ffi::FMOD_ERR_INVALID_PARAM),
+    }
+}
+
+fn description<P: OutputPlugin>() -> ffi::FMOD_OUTPUT_DESCRIPTION {
+    ffi::FMOD_OUTPUT_DESCRIPTION {
+        apiversion: ffi::FMOD_OUTPUT_PLUGIN_VERSION,
+        name: P::NAME.as_ptr(),
+        version: 1,
+        method: ffi::FMOD_OUTPUT_METHOD_MIX_DIRECT,
+        init: Some(init::<P>),
+        start: Some(start::<P>),
+        stop: Some(stop::<P>),
+        close: Some(close::<P>),
+        update: Some(update::<P>),
+        object3dgetinfo: Some(object_3d_get_info::<P>),
+        object3dalloc: Some(object_3d_alloc::<P>),
+        object3dfree: Some(object_3d_free::<P>),
+        object3dupdate: Some(object_3d_update::<P>),
+        openport: Some(open_port::<P>),
+        closeport: Some(close_port::<P>),
+        ..Default::default()
+    }
+}
+
+impl System {
+    /// Registers Rust output plugin, selects it and initializes the system with it.
+    ///
+    /// The plugin is dropped when the system is closed or released. Returns plugin handle.
+    pub fn init_with_output<P: OutputPlugin>(
+        &self,
+        maxchannels: i32,
+        flags: impl Into<ffi::FMOD_INITFLAGS>,
+        plugin: P,
+    ) -> Result<u32, Error> {
+        let mut handle = 0;
+        unsafe {
+            match ffi::FMOD_System_RegisterOutput(
+                self.as_mut_ptr(),
+                &description::<P>(),
+                &mut handle,
+            ) {
+                ffi::FMOD_OK => {}
+                error => return Err(err_fmod!("FMOD_System_RegisterOutput", error)),
+            }
+        }
+        self.set_output_by_plugin(handle)?;
+        let mut slot = Some(Box::new(OutputHost {
+            plugin,
+            block_length: 0,
+            buffer: vec![],
+            ports: vec![],
+            next_port: 0,
+            next_object: 0,
+        }));
+        // plugin stays in the slot (and dropped here) if FMOD fails before output init
+        self.init(
+            maxchannels,
+            flags,
+            Some(&mut slot as *mut Option<Box<OutputHost<P>>> as *mut c_void),
+        )?;
+        Ok(handle)
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::{ffi, Channel, CreateSoundexInfo, Error, Sound, SoundFormat, System};
+
+/// Captures record driver into looping sound and reads new samples from its ring buffer.
+///
+/// The ring buffer holds one second of 32-bit float audio, samples are interleaved by channels.
+/// Recording goes on while the system is updated, so the stream should be read more often
+/// than once a second, otherwise unread samples are overwritten and counted as overrun.
+///
+/// ```ignore
+/// let mut stream = RecordStream::start(&system, 0, 48000, 1)?;
+/// let mut buffer = [0.0; 4096];
+/// loop {
+///     system.update()?;
+///     let count = stream.read(&mut buffer)?;
+///     voice_chat.send(&buffer[..count]);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RecordStream {
+    system: System,
+    driver: i32,
+    sound: Sound,
+    rate: i32,
+    channels: usize,
+    length: u32,
+    read_position: u32,
+    unread: u64,
+    clock: u64,
+    overruns: u32,
+    monitor: Option<Channel>,
+    pending: VecDeque<f32>,
+}
+
+impl RecordStream {
+    /// Starts recording of driver with given index, see `System::record_drivers`.
+    pub fn start(system: &System, driver_id: i32, rate: i32, channels: i32) -> Result<Self, Error> {
+        if rate <= 0 || channels <= 0 {
+            return Err(err_validation!(
+                "RecordStream::start",
+                "rate {} and channels {} should be positive",
+                rate,
+                channels
+            ));
+        }
+        let length = rate as u32;
+        let exinfo = CreateSoundexInfo {
+            length: length * channels as u32 * 4,
+            numchannels: channels,
+            defaultfrequency: rate,
+            format: SoundFormat::PcmFloat,
+            ..Default::default()
+        };
+        let mode = ffi::FMOD_OPENUSER | ffi::FMOD_LOOP_NORMAL;
+        let sound = system.create_sound("", mode, Some(exinfo))?;
+        let mut stream = Self {
+            system: *system,
+            driver: driver_id,
+            sound,
+            rate,
+            channels: channels as usize,
+            length,
+            read_position: 0,
+            unread: 0,
+            clock: 0,
+            overruns: 0,
+            monitor: None,
+            pending: VecDeque::new(),
+        };
+        // dropping stream releases the sound on error
+        stream.clock = stream.mixer_clock()?;
+        system.record_start(driver_id, sound, true)?;
+        Ok(stream)
+    }
+
+    /// Sound which is recorded into, e.g. to be played with custom settings.
+    pub fn sound(&self) -> Sound {
+        self.sound
+    }
+
+    pub fn rate(&self) -> i32 {
+        self.rate
+    }
+
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// How many times samples were overwritten before they were read.
+    pub fn overruns(&self) -> u32 {
+        self.overruns
+    }
+
+    /// Mixer clock in output samples, it measures how much audio was recorded between reads.
+    fn mixer_clock(&self) -> Result<u64, Error> {
+        let (clock, _) = self.system.get_master_channel_group()?.get_dsp_clock()?;
+        Ok(clock)
+    }
+
+    /// Copies samples captured since the previous read into buffer, returns their number.
+    ///
+    /// Only whole frames are read. After overrun the unread samples are dropped and reading
+    /// continues from the current record position.
+    pub fn read(&mut self, buffer: &mut [f32]) -> Result<usize, Error> {
+        let mut count = self.pending.len().min(buffer.len());
+        for (target, sample) in buffer.iter_mut().zip(self.pending.drain(..count)) {
+            *target = sample;
+        }
+        let position = self.system.get_record_position(self.driver)?;
+        let clock = self.mixer_clock()?;
+        let (mixer_rate, _, _) = self.system.get_software_format()?;
+        let elapsed =
+            clock.saturating_sub(self.clock) * self.rate as u64 / mixer_rate.max(1) as u64;
+        self.clock = clock;
+        if self.unread + elapsed >= self.length as u64 {
+            self.overruns += 1;
+            self.read_position = position;
+            self.unread = 0;
+            return Ok(count);
+        }
+        let available = (position + self.length - self.read_position) % self.length;
+        let frames = available.min(((buffer.len() - count) / self.channels) as u32);
+        if frames > 0 {
+            let frame_size = self.channels as u32 * 4;
+            let (ptr_1, ptr_2, len_1, len_2) = self
+                .sound
+                .lock(self.read_position * frame_size, frames * frame_size)?;
+            for (ptr, len) in [(ptr_1, len_1), (ptr_2, len_2)] {
+                if ptr.is_null() || len == 0 {
+                    continue;
+                }
+                let bytes = unsafe { std::slice::from_raw_parts(ptr as *const u8, len as usize) };
+                for sample in bytes.chunks_exact(4) {
+                    buffer[count] =
+                        f32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]);
+                    count += 1;
+                }
+            }
+            self.sound.unlock(ptr_1, ptr_2, len_1, len_2)?;
+            self.read_position = (self.read_position + frames) % self.length;
+        }
+        self.unread = (available - frames) as u64;
+        Ok(count)
+    }
+
+    /// Plays captured audio back with given latency behind record position, so the user
+    /// hears own voice. Too low latency lets playback overtake capture on irregular devices.
+    pub fn start_monitor(&mut self, latency: Duration) -> Result<Channel, Error> {
+        let delay = (latency.as_secs_f64() * self.rate as f64) as u32;
+        if delay >= self.length {
+            return Err(err_validation!(
+                "RecordStream::start_monitor",
+                "latency {:?} doesn't fit record buffer",
+                latency
+            ));
+        }
+        self.stop_monitor();
+        let channel = self.system.play_sound(self.sound, None, true)?;
+        self.monitor = Some(channel);
+        let position = self.system.get_record_position(self.driver)?;
+        let position = (position + self.length - delay) % self.length;
+        channel.set_position(position, ffi::FMOD_TIMEUNIT_PCM)?;
+        channel.set_paused(false)?;
+        Ok(channel)
+    }
+
+    pub fn stop_monitor(&mut self) {
+        if let Some(channel) = self.monitor.take() {
+            // channel may be already stolen by the system
+            let _ = channel.stop();
+        }
+    }
+}
+
+impl Iterator for RecordStream {
+    type Item = f32;
+
+    /// Yields captured samples in order, iteration ends when there are no new samples
+    /// (or reading fails) and may be continued after the next `System::update`.
+    fn next(&mut self) -> Option<f32> {
+        if self.pending.is_empty() {
+            let mut block = vec![0.0; 1024 * self.channels];
+            let count = self.read(&mut block).ok()?;
+            self.pending.extend(&block[..count]);
+        }
+        self.pending.pop_front()
+    }
+}
+
+impl Drop for RecordStream {
+    fn drop(&mut self) {
+        self.stop_monitor();
+        let _ = self.system.record_stop(self.driver);
+        let _ = self.sound.release();
+    }
+}
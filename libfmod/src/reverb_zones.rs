@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::{Error, Reverb3d, ReverbProperties, System, Vector};
+
+impl ReverbProperties {
+    /// Preset by name of its constructor, case, spaces and underscores are ignored,
+    /// so "stoneroom", "Stone Room" and "STONE_ROOM" give the same properties.
+    pub fn preset(name: &str) -> Option<Self> {
+        let name: String = name
+            .chars()
+            .filter(|char| char.is_ascii_alphanumeric())
+            .map(|char| char.to_ascii_lowercase())
+            .collect();
+        let preset = match name.as_str() {
+            "off" => Self::off(),
+            "generic" => Self::generic(),
+            "paddedcell" => Self::paddedcell(),
+            "room" => Self::room(),
+            "bathroom" => Self::bathroom(),
+            "livingroom" => Self::livingroom(),
+            "stoneroom" => Self::stoneroom(),
+            "auditorium" => Self::auditorium(),
+            "concerthall" => Self::concerthall(),
+            "cave" => Self::cave(),
+            "arena" => Self::arena(),
+            "hangar" => Self::hangar(),
+            "carpettedhallway" => Self::carpettedhallway(),
+            "hallway" => Self::hallway(),
+            "stonecorridor" => Self::stonecorridor(),
+            "alley" => Self::alley(),
+            "forest" => Self::forest(),
+            "city" => Self::city(),
+            "mountains" => Self::mountains(),
+            "quarry" => Self::quarry(),
+            "plain" => Self::plain(),
+            "parkinglot" => Self::parkinglot(),
+            "sewerpipe" => Self::sewerpipe(),
+            "underwater" => Self::underwater(),
+            _ => return None,
+        };
+        Some(preset)
+    }
+
+    /// Interpolates every property linearly, `t` is clamped to range 0 (self) to 1 (target).
+    pub fn lerp(&self, target: &ReverbProperties, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |from: f32, to: f32| from + (to - from) * t;
+        Self {
+            decay_time: mix(self.decay_time, target.decay_time),
+            early_delay: mix(self.early_delay, target.early_delay),
+            late_delay: mix(self.late_delay, target.late_delay),
+            hf_reference: mix(self.hf_reference, target.hf_reference),
+            hf_decay_ratio: mix(self.hf_decay_ratio, target.hf_decay_ratio),
+            diffusion: mix(self.diffusion, target.diffusion),
+            density: mix(self.density, target.density),
+            low_shelf_frequency: mix(self.low_shelf_frequency, target.low_shelf_frequency),
+            low_shelf_gain: mix(self.low_shelf_gain, target.low_shelf_gain),
+            high_cut: mix(self.high_cut, target.high_cut),
+            early_late_mix: mix(self.early_late_mix, target.early_late_mix),
+            wet_level: mix(self.wet_level, target.wet_level),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Fade {
+    from: ReverbProperties,
+    to: ReverbProperties,
+    elapsed: Duration,
+    duration: Duration,
+}
+
+/// Named 3D reverb zones and ambient reverb of the system.
+///
+/// Ambient reverb is used where no zone is heard (reverb instance 0), crossfade between
+/// its presets goes on while `update` is called with time elapsed since the previous frame.
+///
+/// ```ignore
+/// let mut zones = ReverbZones::new(system)?;
+/// zones.add_preset_zone("hall", "concerthall", hall_center, 10.0, 30.0)?;
+/// zones.crossfade_ambient(ReverbProperties::forest(), Duration::from_secs(3))?;
+/// loop {
+///     zones.update(frame_time)?;
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ReverbZones {
+    system: System,
+    zones: HashMap<String, Reverb3d>,
+    ambient: ReverbProperties,
+    fade: Option<Fade>,
+}
+
+impl ReverbZones {
+    const AMBIENT_INSTANCE: i32 = 0;
+
+    pub fn new(system: System) -> Result<Self, Error> {
+        Ok(Self {
+            system,
+            zones: HashMap::new(),
+            ambient: system.get_reverb_properties(Self::AMBIENT_INSTANCE)?,
+            fade: None,
+        })
+    }
+
+    /// Creates zone, zone with the same name is released.
+    pub fn add_zone(
+        &mut self,
+        name: &str,
+        properties: ReverbProperties,
+        position: Vector,
+        min_distance: f32,
+        max_distance: f32,
+    ) -> Result<Reverb3d, Error> {
+        let reverb = self.system.create_reverb_3d()?;
+        let setup = reverb
+            .set_properties(properties)
+            .and_then(|_| reverb.set_3d_attributes(Some(position), min_distance, max_distance));
+        if let Err(error) = setup {
+            let _ = reverb.release();
+            return Err(error);
+        }
+        if let Some(previous) = self.zones.insert(name.to_string(), reverb) {
+            previous.release()?;
+        }
+        Ok(reverb)
+    }
+
+    /// Creates zone with properties of preset found by `ReverbProperties::preset`.
+    pub fn add_preset_zone(
+        &mut self,
+        name: &str,
+        preset: &str,
+        position: Vector,
+        min_distance: f32,
+        max_distance: f32,
+    ) -> Result<Reverb3d, Error> {
+        let properties = preset_properties("ReverbZones::add_preset_zone", preset)?;
+        self.add_zone(name, properties, position, min_distance, max_distance)
+    }
+
+    pub fn zone(&self, name: &str) -> Option<Reverb3d> {
+        self.zones.get(name).copied()
+    }
+
+    pub fn set_zone_attributes(
+        &self,
+        name: &str,
+        position: Vector,
+        min_distance: f32,
+        max_distance: f32,
+    ) -> Result<(), Error> {
+        self.find("ReverbZones::set_zone_attributes", name)?
+            .set_3d_attributes(Some(position), min_distance, max_distance)
+    }
+
+    /// Moves zone keeping its min and max distance.
+    pub fn set_zone_position(&self, name: &str, position: Vector) -> Result<(), Error> {
+        let reverb = self.find("ReverbZones::set_zone_position", name)?;
+        let (_, min_distance, max_distance) = reverb.get_3d_attributes()?;
+        reverb.set_3d_attributes(Some(position), min_distance, max_distance)
+    }
+
+    pub fn set_zone_properties(
+        &self,
+        name: &str,
+        properties: ReverbProperties,
+    ) -> Result<(), Error> {
+        self.find("ReverbZones::set_zone_properties", name)?
+            .set_properties(properties)
+    }
+
+    pub fn remove_zone(&mut self, name: &str) -> Result<(), Error> {
+        match self.zones.remove(name) {
+            Some(reverb) => reverb.release(),
+            None => Err(err_validation!(
+                "ReverbZones::remove_zone",
+                "zone {} is not found",
+                name
+            )),
+        }
+    }
+
+    /// Current ambient properties, including the middle of crossfade.
+    pub fn ambient(&self) -> &ReverbProperties {
+        &self.ambient
+    }
+
+    pub fn is_fading(&self) -> bool {
+        self.fade.is_some()
+    }
+
+    /// Sets ambient reverb right away, crossfade in progress is cancelled.
+    pub fn set_ambient(&mut self, properties: ReverbProperties) -> Result<(), Error> {
+        self.fade = None;
+        self.apply(properties)
+    }
+
+    pub fn set_ambient_preset(&mut self, preset: &str) -> Result<(), Error> {
+        self.set_ambient(preset_properties(
+            "ReverbZones::set_ambient_preset",
+            preset,
+        )?)
+    }
+
+    /// Starts crossfade from current ambient properties, zero duration sets target right away.
+    pub fn crossfade_ambient(
+        &mut self,
+        target: ReverbProperties,
+        duration: Duration,
+    ) -> Result<(), Error> {
+        if duration.is_zero() {
+            return self.set_ambient(target);
+        }
+        self.fade = Some(Fade {
+            from: self.ambient.clone(),
+            to: target,
+            elapsed: Duration::ZERO,
+            duration,
+        });
+        Ok(())
+    }
+
+    pub fn crossfade_ambient_preset(
+        &mut self,
+        preset: &str,
+        duration: Duration,
+    ) -> Result<(), Error> {
+        let target = preset_properties("ReverbZones::crossfade_ambient_preset", preset)?;
+        self.crossfade_ambient(target, duration)
+    }
+
+    /// Advances ambient crossfade, should be called every frame.
+    pub fn update(&mut self, elapsed: Duration) -> Result<(), Error> {
+        let Some(mut fade) = self.fade.take() else {
+            return Ok(());
+        };
+        fade.elapsed += elapsed;
+        if fade.elapsed >= fade.duration {
+            return self.apply(fade.to);
+        }
+        let progress = fade.elapsed.as_secs_f32() / fade.duration.as_secs_f32();
+        self.apply(fade.from.lerp(&fade.to, progress))?;
+        self.fade = Some(fade);
+        Ok(())
+    }
+
+    /// Releases all zones, ambient reverb stays as is.
+    pub fn release(mut self) -> Result<(), Error> {
+        let mut result = Ok(());
+        for (_, reverb) in self.zones.drain() {
+            let outcome = reverb.release();
+            if result.is_ok() {
+                result = outcome;
+            }
+        }
+        result
+    }
+
+    fn apply(&mut self, properties: ReverbProperties) -> Result<(), Error> {
+        self.system
+            .set_reverb_properties(Self::AMBIENT_INSTANCE, properties.clone())?;
+        self.ambient = properties;
+        Ok(())
+    }
+
+    fn find(&self, function: &str, name: &str) -> Result<Reverb3d, Error> {
+        self.zone(name)
+            .ok_or_else(|| err_validation!(function, "zone {} is not found", name))
+    }
+}
+
+fn preset_properties(function: &str, name: &str) -> Result<ReverbProperties, Error> {
+    ReverbProperties::preset(name)
+        .ok_or_else(|| err_validation!(function, "preset {} is not found", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ReverbProperties;
+
+    #[test]
+    fn test_preset_names_normalized() {
+        let decay = ReverbProperties::stoneroom().decay_time;
+        for name in ["stoneroom", "Stone Room", "STONE_ROOM"] {
+            let preset = ReverbProperties::preset(name).map(|preset| preset.decay_time);
+            assert_eq!(preset, Some(decay));
+        }
+        assert!(ReverbProperties::preset("basement").is_none());
+    }
+
+    #[test]
+    fn test_lerp_clamped_to_both_ends() {
+        let (off, hall) = (ReverbProperties::off(), ReverbProperties::concerthall());
+        let close = |a: f32, b: f32| (a - b).abs() < 0.001;
+        assert!(close(off.lerp(&hall, -1.0).wet_level, off.wet_level));
+        assert!(close(off.lerp(&hall, 2.0).wet_level, hall.wet_level));
+        let half = off.lerp(&hall, 0.5);
+        assert!(close(
+            half.decay_time,
+            (off.decay_time + hall.decay_time) / 2.0
+        ));
+    }
+}
@@ -0,0 +1,197 @@
+use std::time::Duration;
+
+use crate::{ffi, Channel, ChannelControl, ChannelGroup, Error, Sound, System};
+
+/// Sample accurate scheduling of playback in DSP clocks of the mixer.
+///
+/// Clocks passed to and returned from scheduler are parent clocks, the same ones
+/// `set_delay` and `add_fade_point` expect. Durations are converted to samples using
+/// sample rate of the software mixer.
+///
+/// ```ignore
+/// let scheduler = Scheduler::new(system)?;
+/// let intro = scheduler.play_at(intro, None, scheduler.clock_after(Duration::from_secs(1))?)?;
+/// let music = scheduler.play_after(intro, music)?;
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Scheduler {
+    system: System,
+    rate: u64,
+}
+
+impl Scheduler {
+    /// Creates scheduler for initialized system, sample rate of software format is cached.
+    pub fn new(system: System) -> Result<Self, Error> {
+        let (samplerate, _, _) = system.get_software_format()?;
+        Ok(Self {
+            system,
+            rate: samplerate as u64,
+        })
+    }
+
+    pub fn sample_rate(&self) -> u64 {
+        self.rate
+    }
+
+    /// Current DSP clock of the mixer.
+    pub fn clock(&self) -> Result<u64, Error> {
+        let (clock, _) = self.system.get_master_channel_group()?.get_dsp_clock()?;
+        Ok(clock)
+    }
+
+    /// DSP clock of the mixer after duration from now.
+    pub fn clock_after(&self, duration: Duration) -> Result<u64, Error> {
+        Ok(self.clock()? + self.samples(duration))
+    }
+
+    pub fn samples(&self, duration: Duration) -> u64 {
+        (duration.as_secs_f64() * self.rate as f64).round() as u64
+    }
+
+    pub fn duration(&self, samples: u64) -> Duration {
+        Duration::from_secs_f64(samples as f64 / self.rate as f64)
+    }
+
+    /// Plays sound starting exactly at parent clock.
+    ///
+    /// Channel is created paused and unpaused only after delay is set, so it never starts
+    /// earlier than scheduled. Clock in the past starts playback immediately.
+    pub fn play_at(
+        &self,
+        sound: Sound,
+        group: Option<ChannelGroup>,
+        clock: u64,
+    ) -> Result<Channel, Error> {
+        let channel = self.system.play_sound(sound, group, true)?;
+        channel.set_delay(Some(clock), None, false)?;
+        channel.set_paused(false)?;
+        Ok(channel)
+    }
+
+    /// Plays sound in the same channel group right after channel ends, without gap.
+    pub fn play_after(&self, channel: Channel, sound: Sound) -> Result<Channel, Error> {
+        let clock = self.end_clock(channel)?;
+        self.play_at(sound, Some(channel.get_channel_group()?), clock)
+    }
+
+    /// Parent clock at which sound of channel ends, assuming it does not loop.
+    ///
+    /// Channel scheduled in the future is counted from its start clock.
+    /// Channel with zero frequency or pitch never ends, it is reported as `Error::Validation`.
+    pub fn end_clock(&self, channel: Channel) -> Result<u64, Error> {
+        let length = channel
+            .get_current_sound()?
+            .get_length(ffi::FMOD_TIMEUNIT_PCM)?;
+        let position = channel.get_position(ffi::FMOD_TIMEUNIT_PCM)?;
+        let speed = channel.get_frequency()? as f64 * channel.get_pitch()? as f64;
+        if speed <= 0.0 {
+            return Err(err_validation!(
+                "Scheduler::end_clock",
+                "channel with zero frequency or pitch never ends"
+            ));
+        }
+        let remaining = length.saturating_sub(position) as f64 * self.rate as f64 / speed;
+        Ok(self.start_clock(&channel)? + remaining.round() as u64)
+    }
+
+    /// Stops channel or channel group at parent clock, scheduled start is kept.
+    pub fn stop_at(&self, control: &impl ChannelControl, clock: u64) -> Result<(), Error> {
+        let (start, _, _) = control.get_delay()?;
+        control.set_delay(Some(start), Some(clock), true)
+    }
+
+    /// Ramps volume from its current fade level to target level over duration.
+    ///
+    /// Fade starts when control starts playing, so it may be applied to channel
+    /// scheduled with `play_at` before the start.
+    pub fn fade_to(
+        &self,
+        control: &impl ChannelControl,
+        volume: f32,
+        duration: Duration,
+    ) -> Result<(), Error> {
+        let start = self.start_clock(control)?;
+        self.ramp(control, start, start + self.samples(duration), None, volume)
+    }
+
+    /// Fades out one channel or channel group while fading in another one,
+    /// faded out control is stopped at the end.
+    pub fn crossfade(
+        &self,
+        from: &impl ChannelControl,
+        to: &impl ChannelControl,
+        duration: Duration,
+    ) -> Result<(), Error> {
+        let start = self.start_clock(from)?.max(self.start_clock(to)?);
+        let end = start + self.samples(duration);
+        self.ramp(from, start, end, None, 0.0)?;
+        self.ramp(to, start, end, Some(0.0), 1.0)?;
+        self.stop_at(from, end)
+    }
+
+    /// Parent clock at which control starts or started playing.
+    fn start_clock(&self, control: &impl ChannelControl) -> Result<u64, Error> {
+        let (_, parent) = control.get_dsp_clock()?;
+        let (start, _, _) = control.get_delay()?;
+        Ok(parent.max(start))
+    }
+
+    fn ramp(
+        &self,
+        control: &impl ChannelControl,
+        start: u64,
+        end: u64,
+        from: Option<f32>,
+        to: f32,
+    ) -> Result<(), Error> {
+        let from = match from {
+            Some(level) => level,
+            None => fade_level(&control.get_fade_points()?, start),
+        };
+        control.remove_fade_points(start, u64::MAX)?;
+        control.add_fade_point(start, from)?;
+        control.add_fade_point(end, to)
+    }
+}
+
+/// Fade level at clock, interpolated between fade points.
+fn fade_level(points: &[(u64, f32)], clock: u64) -> f32 {
+    let after = points.iter().position(|(point, _)| *point > clock);
+    match after {
+        _ if points.is_empty() => 1.0,
+        Some(0) => points[0].1,
+        None => points[points.len() - 1].1,
+        Some(index) => {
+            let (start, from) = points[index - 1];
+            let (end, to) = points[index];
+            let progress = (clock - start) as f32 / (end - start) as f32;
+            from + (to - from) * progress
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fade_level;
+
+    #[test]
+    fn test_fade_level_without_points() {
+        assert_eq!(fade_level(&[], 100), 1.0);
+    }
+
+    #[test]
+    fn test_fade_level_outside_points() {
+        let points = [(100, 0.5), (200, 0.0)];
+        assert_eq!(fade_level(&points, 50), 0.5);
+        assert_eq!(fade_level(&points, 200), 0.0);
+        assert_eq!(fade_level(&points, 300), 0.0);
+    }
+
+    #[test]
+    fn test_fade_level_interpolated_between_points() {
+        let points = [(0, 1.0), (100, 0.0), (200, 1.0)];
+        assert_eq!(fade_level(&points, 0), 1.0);
+        assert_eq!(fade_level(&points, 25), 0.75);
+        assert_eq!(fade_level(&points, 150), 0.5);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{ffi, Error, Speaker, SpeakerMode};
+
+/// Speakers which levels can be set by `set_mix_levels_output`.
+const OUTPUT_SPEAKERS: [Speaker; 8] = [
+    Speaker::FrontLeft,
+    Speaker::FrontRight,
+    Speaker::FrontCenter,
+    Speaker::LowFrequency,
+    Speaker::SurroundLeft,
+    Speaker::SurroundRight,
+    Speaker::BackLeft,
+    Speaker::BackRight,
+];
+
+/// Output levels of speakers, speakers not mentioned are silent.
+///
+/// ```ignore
+/// let stinger = SpeakerLevels::new().front_center(1.0).low_frequency(0.5);
+/// channel.set_mix_levels_output(&stinger)?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpeakerLevels {
+    levels: Vec<(Speaker, f32)>,
+}
+
+impl SpeakerLevels {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, speaker: Speaker, level: f32) -> Self {
+        self.set(speaker, level);
+        self
+    }
+
+    pub fn front_left(self, level: f32) -> Self {
+        self.with(Speaker::FrontLeft, level)
+    }
+
+    pub fn front_right(self, level: f32) -> Self {
+        self.with(Speaker::FrontRight, level)
+    }
+
+    pub fn front_center(self, level: f32) -> Self {
+        self.with(Speaker::FrontCenter, level)
+    }
+
+    pub fn low_frequency(self, level: f32) -> Self {
+        self.with(Speaker::LowFrequency, level)
+    }
+
+    pub fn surround_left(self, level: f32) -> Self {
+        self.with(Speaker::SurroundLeft, level)
+    }
+
+    pub fn surround_right(self, level: f32) -> Self {
+        self.with(Speaker::SurroundRight, level)
+    }
+
+    pub fn back_left(self, level: f32) -> Self {
+        self.with(Speaker::BackLeft, level)
+    }
+
+    pub fn back_right(self, level: f32) -> Self {
+        self.with(Speaker::BackRight, level)
+    }
+
+    pub fn set(&mut self, speaker: Speaker, level: f32) {
+        match self.levels.iter_mut().find(|(key, _)| *key == speaker) {
+            Some((_, value)) => *value = level,
+            None => self.levels.push((speaker, level)),
+        }
+    }
+
+    pub fn get(&self, speaker: Speaker) -> f32 {
+        self.levels
+            .iter()
+            .find(|(key, _)| *key == speaker)
+            .map(|(_, level)| *level)
+            .unwrap_or(0.0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Speaker, f32)> + '_ {
+        self.levels.iter().copied()
+    }
+
+    /// Checks that all audible speakers can be set by `set_mix_levels_output` and
+    /// present in speaker mode, modes without fixed layout (`Raw`, `Default`) accept any of them.
+    pub fn validate(&self, mode: SpeakerMode) -> Result<(), Error> {
+        for (speaker, level) in self.iter() {
+            if level == 0.0 {
+                continue;
+            }
+            let present = mode
+                .speakers()
+                .map(|speakers| speakers.contains(&speaker))
+                .unwrap_or(true);
+            if !present || !OUTPUT_SPEAKERS.contains(&speaker) {
+                return Err(err_validation!(
+                    "SpeakerLevels::validate",
+                    "{:?} speaker is not available in {:?} mode",
+                    speaker,
+                    mode
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Checks number of input levels passed to `set_mix_levels_input`, there is one level per
+/// channel of the played sound, so up to `FMOD_MAX_CHANNEL_WIDTH` levels.
+pub(crate) fn validate_input_levels(levels: &[f32]) -> Result<(), Error> {
+    if levels.is_empty() || levels.len() > ffi::FMOD_MAX_CHANNEL_WIDTH as usize {
+        return Err(err_validation!(
+            "SpeakerLevels::validate_input_levels",
+            "{} levels given, expected from 1 to {}",
+            levels.len(),
+            ffi::FMOD_MAX_CHANNEL_WIDTH
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpeakerLevels;
+    use crate::{Speaker, SpeakerMode};
+
+    #[test]
+    fn test_levels_replaced_by_speaker() {
+        let levels = SpeakerLevels::new()
+            .front_left(0.5)
+            .front_right(1.0)
+            .front_left(0.25);
+        assert_eq!(levels.get(Speaker::FrontLeft), 0.25);
+        assert_eq!(levels.get(Speaker::FrontCenter), 0.0);
+        assert_eq!(levels.iter().count(), 2);
+    }
+
+    #[test]
+    fn test_validate_by_speaker_mode_layout() {
+        let stinger = SpeakerLevels::new().front_center(1.0).low_frequency(0.5);
+        assert!(stinger.validate(SpeakerMode::Mode5Point1).is_ok());
+        assert!(stinger.validate(SpeakerMode::Stereo).is_err());
+        assert!(stinger.validate(SpeakerMode::Raw).is_ok());
+    }
+
+    #[test]
+    fn test_validate_ignores_silent_speakers() {
+        let levels = SpeakerLevels::new().front_left(1.0).front_center(0.0);
+        assert!(levels.validate(SpeakerMode::Stereo).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_speakers_not_settable_by_fmod() {
+        let top = SpeakerLevels::new().with(Speaker::TopFrontLeft, 1.0);
+        assert!(top.validate(SpeakerMode::Mode7Point1Point4).is_err());
+    }
+}
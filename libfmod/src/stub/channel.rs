@@ -0,0 +1,1642 @@
+use std::collections::HashMap;
+use std::ffi::{c_char, c_float, c_int, c_uint, c_ulonglong, c_void};
+
+use crate::ffi::*;
+use crate::stub::dsp;
+use crate::stub::parameter::{self, Kind, Parameter};
+use crate::stub::system::{speaker_mode_channels, Sound, System};
+use crate::stub::{call, handle, output, output_string, to_bool, with, Handle, Object, State};
+use crate::{Speaker, SpeakerMode};
+
+/// State shared by Channel and ChannelGroup.
+pub(crate) struct Control {
+    pub system: usize,
+    pub paused: bool,
+    pub mute: bool,
+    pub volume: c_float,
+    pub volume_ramp: bool,
+    pub pitch: c_float,
+    pub mode: FMOD_MODE,
+    pub low_pass_gain: c_float,
+    pub reverb: [c_float; 4],
+    pub position: FMOD_VECTOR,
+    pub velocity: FMOD_VECTOR,
+    pub min_distance: c_float,
+    pub max_distance: c_float,
+    pub direct_occlusion: c_float,
+    pub reverb_occlusion: c_float,
+    pub delay: (u64, u64, bool),
+    pub fade_points: Vec<(u64, c_float)>,
+    pub matrix: Option<(Vec<c_float>, c_int, c_int)>,
+    pub dsps: Vec<usize>,
+    pub fader: usize,
+    pub user_data: usize,
+}
+
+impl Control {
+    fn new(system: usize, fader: usize) -> Self {
+        Self {
+            system,
+            paused: false,
+            mute: false,
+            volume: 1.0,
+            volume_ramp: true,
+            pitch: 1.0,
+            mode: FMOD_DEFAULT,
+            low_pass_gain: 1.0,
+            reverb: [0.0; 4],
+            position: FMOD_VECTOR::default(),
+            velocity: FMOD_VECTOR::default(),
+            min_distance: 1.0,
+            max_distance: 10000.0,
+            direct_occlusion: 0.0,
+            reverb_occlusion: 0.0,
+            delay: (0, 0, true),
+            fade_points: vec![],
+            matrix: None,
+            dsps: vec![fader],
+            fader,
+            user_data: 0,
+        }
+    }
+}
+
+pub(crate) struct Channel {
+    pub control: Control,
+    pub sound: usize,
+    pub group: usize,
+    pub index: c_int,
+    pub position: f64,
+    pub length: c_uint,
+    pub frequency: c_float,
+    pub priority: c_int,
+    pub loop_count: c_int,
+}
+
+pub(crate) struct ChannelGroup {
+    pub control: Control,
+    pub name: String,
+    pub parent: usize,
+}
+
+pub(crate) struct Dsp {
+    pub system: usize,
+    pub type_: FMOD_DSP_TYPE,
+    pub active: bool,
+    pub bypass: bool,
+    pub wet_dry: (c_float, c_float, c_float),
+    pub floats: HashMap<c_int, c_float>,
+    pub ints: HashMap<c_int, c_int>,
+    pub bools: HashMap<c_int, bool>,
+    pub data: HashMap<c_int, Vec<u8>>,
+    pub metering: (bool, bool),
+    /// Channel mask, number of channels and source speaker mode, software format if not set.
+    pub channel_format: Option<(FMOD_CHANNELMASK, c_int, FMOD_SPEAKERMODE)>,
+    pub user_data: usize,
+}
+
+impl Dsp {
+    pub fn new(system: usize, type_: FMOD_DSP_TYPE) -> Self {
+        let (mut floats, mut ints, mut bools) = (HashMap::new(), HashMap::new(), HashMap::new());
+        for (index, parameter) in parameter::parameters(type_).iter().enumerate() {
+            let index = index as c_int;
+            match parameter.kind {
+                Kind::Float(_, _, default) => {
+                    floats.insert(index, default);
+                }
+                Kind::Int(_, _, default, _) => {
+                    ints.insert(index, default);
+                }
+                Kind::Bool(default) => {
+                    bools.insert(index, default);
+                }
+                Kind::Data(_) => {}
+            }
+        }
+        Self {
+            system,
+            type_,
+            active: false,
+            bypass: false,
+            wet_dry: (1.0, 1.0, 0.0),
+            floats,
+            ints,
+            bools,
+            data: HashMap::new(),
+            metering: (false, false),
+            channel_format: None,
+            user_data: 0,
+        }
+    }
+}
+
+pub(crate) trait Controlled: Handle {
+    fn control(&mut self) -> &mut Control;
+}
+
+impl Controlled for Channel {
+    fn control(&mut self) -> &mut Control {
+        &mut self.control
+    }
+}
+
+impl Controlled for ChannelGroup {
+    fn control(&mut self) -> &mut Control {
+        &mut self.control
+    }
+}
+
+fn create_fader(state: &mut State, system: usize) -> usize {
+    let mut fader = Dsp::new(system, FMOD_DSP_TYPE_FADER);
+    fader.active = true;
+    state.insert(Object::Dsp(fader))
+}
+
+pub(crate) fn create_group(state: &mut State, system: usize, name: &str, parent: usize) -> usize {
+    let fader = create_fader(state, system);
+    state.insert(Object::ChannelGroup(ChannelGroup {
+        control: Control::new(system, fader),
+        name: name.to_string(),
+        parent,
+    }))
+}
+
+pub(crate) fn create_channel(
+    state: &mut State,
+    system: usize,
+    sound: usize,
+    group: usize,
+) -> usize {
+    let fader = create_fader(state, system);
+    let mut control = Control::new(system, fader);
+    let (mode, length, frequency, priority, loop_count) = match state.get::<Sound>(sound) {
+        Ok(sound) => (
+            sound.mode,
+            sound.length,
+            sound.frequency,
+            sound.priority,
+            sound.loop_count,
+        ),
+        Err(_) => (FMOD_DEFAULT, 0, 0.0, 128, 0),
+    };
+    control.mode = mode;
+    let index = match state.get::<System>(system) {
+        Ok(system) => {
+            system.next_channel_index += 1;
+            system.next_channel_index - 1
+        }
+        Err(_) => 0,
+    };
+    state.insert(Object::Channel(Channel {
+        control,
+        sound,
+        group,
+        index,
+        position: 0.0,
+        length,
+        frequency,
+        priority,
+        loop_count,
+    }))
+}
+
+pub(crate) fn stop_channel(state: &mut State, channel: usize) {
+    if let Some(Object::Channel(channel)) = state.remove(channel) {
+        remove_fader(state, &channel.control);
+    }
+}
+
+fn remove_fader(state: &mut State, control: &Control) {
+    dsp::disconnect_all(state, control.fader, true, true);
+    state.remove(control.fader);
+}
+
+/// Returns group with all nested groups.
+fn hierarchy(state: &mut State, group: usize) -> Vec<usize> {
+    let mut groups = vec![group];
+    let mut index = 0;
+    while index < groups.len() {
+        let parent = groups[index];
+        groups.extend(state.handles::<ChannelGroup>(|group| group.parent == parent));
+        index += 1;
+    }
+    groups
+}
+
+fn stop_group(state: &mut State, group: usize) {
+    let groups = hierarchy(state, group);
+    for channel in state.handles::<Channel>(|channel| groups.contains(&channel.group)) {
+        stop_channel(state, channel);
+    }
+}
+
+pub(crate) fn release_group(state: &mut State, group: usize) {
+    let parent = match state.get::<ChannelGroup>(group) {
+        Ok(group) => group.parent,
+        Err(_) => return,
+    };
+    for channel in state.handles::<Channel>(|channel| channel.group == group) {
+        if let Ok(channel) = state.get::<Channel>(channel) {
+            channel.group = parent;
+        }
+    }
+    for child in state.handles::<ChannelGroup>(|child| child.parent == group) {
+        if let Ok(child) = state.get::<ChannelGroup>(child) {
+            child.parent = parent;
+        }
+    }
+    if let Some(Object::ChannelGroup(group)) = state.remove(group) {
+        remove_fader(state, &group.control);
+    }
+}
+
+/// Returns accumulated state of channel groups (paused, pitch).
+fn parent_state(state: &mut State, mut group: usize) -> (bool, c_float) {
+    let mut paused = false;
+    let mut pitch = 1.0;
+    while let Ok(parent) = state.get::<ChannelGroup>(group) {
+        paused |= parent.control.paused;
+        pitch *= parent.control.pitch;
+        group = parent.parent;
+    }
+    (paused, pitch)
+}
+
+/// Advances channel playback by one mixer block.
+pub(crate) fn advance(state: &mut State, handle: usize, clock: u64, seconds: f64) {
+    let group = match state.get::<Channel>(handle) {
+        Ok(channel) => channel.group,
+        Err(_) => return,
+    };
+    let (group_paused, group_pitch) = parent_state(state, group);
+    let Ok(channel) = state.get::<Channel>(handle) else {
+        return;
+    };
+    let (start, end, stop_channels) = channel.control.delay;
+    if end > 0 && clock >= end {
+        if stop_channels {
+            stop_channel(state, handle);
+        } else {
+            channel.control.paused = true;
+        }
+        return;
+    }
+    if channel.control.paused || group_paused || clock < start {
+        return;
+    }
+    let pitch = (channel.control.pitch * group_pitch) as f64;
+    channel.position += seconds * channel.frequency as f64 * pitch;
+    let length = channel.length as f64;
+    if channel.position < length {
+        return;
+    }
+    let looping = channel.control.mode & (FMOD_LOOP_NORMAL | FMOD_LOOP_BIDI) != 0;
+    if looping && channel.loop_count != 0 && length > 0.0 {
+        channel.position %= length;
+        if channel.loop_count > 0 {
+            channel.loop_count -= 1;
+        }
+    } else {
+        stop_channel(state, handle);
+    }
+}
+
+fn control<T: Controlled>(state: &mut State, handle: usize) -> Result<&mut Control, FMOD_RESULT> {
+    Ok(state.get::<T>(handle)?.control())
+}
+
+fn dsp_position(control: &Control, index: c_int, inserting: bool) -> Result<usize, FMOD_RESULT> {
+    let count = control.dsps.len();
+    let position = match index {
+        FMOD_CHANNELCONTROL_DSP_HEAD => 0,
+        FMOD_CHANNELCONTROL_DSP_TAIL if inserting => count,
+        FMOD_CHANNELCONTROL_DSP_TAIL => count - 1,
+        FMOD_CHANNELCONTROL_DSP_FADER => control
+            .dsps
+            .iter()
+            .position(|dsp| *dsp == control.fader)
+            .ok_or(FMOD_ERR_DSP_NOTFOUND)?,
+        index if index >= 0 => index as usize,
+        _ => return Err(FMOD_ERR_INVALID_PARAM),
+    };
+    if position > count || (!inserting && position == count) {
+        return Err(FMOD_ERR_INVALID_PARAM);
+    }
+    Ok(position)
+}
+
+unsafe fn stop<T: Controlled>(state: &mut State, handle: usize) -> Result<(), FMOD_RESULT> {
+    state.get::<T>(handle)?;
+    if state.contains::<Channel>(handle) {
+        stop_channel(state, handle);
+    } else {
+        stop_group(state, handle);
+    }
+    Ok(())
+}
+
+unsafe fn get_system_object<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    system: *mut *mut FMOD_SYSTEM,
+) -> Result<(), FMOD_RESULT> {
+    output(system, self::handle(control::<T>(state, handle)?.system));
+    Ok(())
+}
+
+unsafe fn set_paused<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    paused: FMOD_BOOL,
+) -> Result<(), FMOD_RESULT> {
+    control::<T>(state, handle)?.paused = paused != 0;
+    Ok(())
+}
+
+unsafe fn get_paused<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    paused: *mut FMOD_BOOL,
+) -> Result<(), FMOD_RESULT> {
+    output(paused, to_bool(control::<T>(state, handle)?.paused));
+    Ok(())
+}
+
+unsafe fn set_volume<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    volume: c_float,
+) -> Result<(), FMOD_RESULT> {
+    control::<T>(state, handle)?.volume = volume;
+    Ok(())
+}
+
+unsafe fn get_volume<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    volume: *mut c_float,
+) -> Result<(), FMOD_RESULT> {
+    output(volume, control::<T>(state, handle)?.volume);
+    Ok(())
+}
+
+unsafe fn set_volume_ramp<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    ramp: FMOD_BOOL,
+) -> Result<(), FMOD_RESULT> {
+    control::<T>(state, handle)?.volume_ramp = ramp != 0;
+    Ok(())
+}
+
+unsafe fn get_volume_ramp<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    ramp: *mut FMOD_BOOL,
+) -> Result<(), FMOD_RESULT> {
+    output(ramp, to_bool(control::<T>(state, handle)?.volume_ramp));
+    Ok(())
+}
+
+unsafe fn get_audibility<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    audibility: *mut c_float,
+) -> Result<(), FMOD_RESULT> {
+    let control = control::<T>(state, handle)?;
+    let volume = if control.mute { 0.0 } else { control.volume };
+    output(audibility, volume * (1.0 - control.direct_occlusion));
+    Ok(())
+}
+
+unsafe fn set_pitch<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    pitch: c_float,
+) -> Result<(), FMOD_RESULT> {
+    if pitch < 0.0 {
+        return Err(FMOD_ERR_INVALID_PARAM);
+    }
+    control::<T>(state, handle)?.pitch = pitch;
+    Ok(())
+}
+
+unsafe fn get_pitch<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    pitch: *mut c_float,
+) -> Result<(), FMOD_RESULT> {
+    output(pitch, control::<T>(state, handle)?.pitch);
+    Ok(())
+}
+
+unsafe fn set_mute<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    mute: FMOD_BOOL,
+) -> Result<(), FMOD_RESULT> {
+    control::<T>(state, handle)?.mute = mute != 0;
+    Ok(())
+}
+
+unsafe fn get_mute<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    mute: *mut FMOD_BOOL,
+) -> Result<(), FMOD_RESULT> {
+    output(mute, to_bool(control::<T>(state, handle)?.mute));
+    Ok(())
+}
+
+unsafe fn set_reverb_properties<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    instance: c_int,
+    wet: c_float,
+) -> Result<(), FMOD_RESULT> {
+    let control = control::<T>(state, handle)?;
+    *control
+        .reverb
+        .get_mut(instance as usize)
+        .ok_or(FMOD_ERR_INVALID_PARAM)? = wet;
+    Ok(())
+}
+
+unsafe fn get_reverb_properties<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    instance: c_int,
+    wet: *mut c_float,
+) -> Result<(), FMOD_RESULT> {
+    let control = control::<T>(state, handle)?;
+    output(
+        wet,
+        *control
+            .reverb
+            .get(instance as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)?,
+    );
+    Ok(())
+}
+
+unsafe fn set_low_pass_gain<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    gain: c_float,
+) -> Result<(), FMOD_RESULT> {
+    control::<T>(state, handle)?.low_pass_gain = gain.clamp(0.0, 1.0);
+    Ok(())
+}
+
+unsafe fn get_low_pass_gain<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    gain: *mut c_float,
+) -> Result<(), FMOD_RESULT> {
+    output(gain, control::<T>(state, handle)?.low_pass_gain);
+    Ok(())
+}
+
+unsafe fn set_mode<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    mode: FMOD_MODE,
+) -> Result<(), FMOD_RESULT> {
+    control::<T>(state, handle)?.mode = mode;
+    Ok(())
+}
+
+unsafe fn get_mode<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    mode: *mut FMOD_MODE,
+) -> Result<(), FMOD_RESULT> {
+    output(mode, control::<T>(state, handle)?.mode);
+    Ok(())
+}
+
+unsafe fn set_mix_matrix<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    matrix: *mut c_float,
+    outchannels: c_int,
+    inchannels: c_int,
+    inchannel_hop: c_int,
+) -> Result<(), FMOD_RESULT> {
+    let control = control::<T>(state, handle)?;
+    if matrix.is_null() {
+        control.matrix = None;
+        return Ok(());
+    }
+    if !(1..=32).contains(&outchannels) || !(1..=32).contains(&inchannels) {
+        return Err(FMOD_ERR_INVALID_PARAM);
+    }
+    let hop = if inchannel_hop > 0 {
+        inchannel_hop
+    } else {
+        inchannels
+    };
+    if hop < inchannels {
+        return Err(FMOD_ERR_INVALID_PARAM);
+    }
+    let mut values = Vec::with_capacity((outchannels * inchannels) as usize);
+    for out in 0..outchannels {
+        for input in 0..inchannels {
+            values.push(*matrix.add((out * hop + input) as usize));
+        }
+    }
+    control.matrix = Some((values, outchannels, inchannels));
+    Ok(())
+}
+
+/// Speaker mode of system output and its channel count.
+fn speaker_mode<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+) -> Result<(FMOD_SPEAKERMODE, c_int), FMOD_RESULT> {
+    let system = control::<T>(state, handle)?.system;
+    let system = state.get::<System>(system)?;
+    let channels = speaker_mode_channels(system, system.speaker_mode).unwrap_or(2);
+    Ok((system.speaker_mode, channels))
+}
+
+fn set_mix_levels_output<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    frontleft: c_float,
+    frontright: c_float,
+    center: c_float,
+    lfe: c_float,
+    surroundleft: c_float,
+    surroundright: c_float,
+    backleft: c_float,
+    backright: c_float,
+) -> Result<(), FMOD_RESULT> {
+    let (mode, channels) = speaker_mode::<T>(state, handle)?;
+    let layout = SpeakerMode::from(mode)
+        .ok()
+        .and_then(|mode| mode.speakers())
+        .unwrap_or_default();
+    let levels = [
+        (Speaker::FrontLeft, frontleft),
+        (Speaker::FrontRight, frontright),
+        (Speaker::FrontCenter, center),
+        (Speaker::LowFrequency, lfe),
+        (Speaker::SurroundLeft, surroundleft),
+        (Speaker::SurroundRight, surroundright),
+        (Speaker::BackLeft, backleft),
+        (Speaker::BackRight, backright),
+    ];
+    // single input channel is sent to each speaker with its level
+    let mut values = vec![0.0; channels as usize];
+    for (speaker, level) in levels {
+        if let Some(index) = layout.iter().position(|value| *value == speaker) {
+            values[index] = level;
+        }
+    }
+    control::<T>(state, handle)?.matrix = Some((values, channels, 1));
+    Ok(())
+}
+
+unsafe fn set_mix_levels_input<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    levels: *mut c_float,
+    numlevels: c_int,
+) -> Result<(), FMOD_RESULT> {
+    if levels.is_null() || !(1..=FMOD_MAX_CHANNEL_WIDTH as c_int).contains(&numlevels) {
+        return Err(FMOD_ERR_INVALID_PARAM);
+    }
+    let (_, channels) = speaker_mode::<T>(state, handle)?;
+    // input channel N is sent to speaker N with its level
+    let mut values = vec![0.0; (channels * numlevels) as usize];
+    for index in 0..channels.min(numlevels) {
+        values[(index * numlevels + index) as usize] = *levels.add(index as usize);
+    }
+    control::<T>(state, handle)?.matrix = Some((values, channels, numlevels));
+    Ok(())
+}
+
+unsafe fn get_mix_matrix<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    matrix: *mut c_float,
+    outchannels: *mut c_int,
+    inchannels: *mut c_int,
+    inchannel_hop: c_int,
+) -> Result<(), FMOD_RESULT> {
+    let speakers = speaker_mode::<T>(state, handle)?.1 as usize;
+    let control = control::<T>(state, handle)?;
+    // identity matrix is used by default
+    let (values, outs, ins) = control.matrix.clone().unwrap_or_else(|| {
+        let mut values = vec![0.0; speakers * speakers];
+        for index in 0..speakers {
+            values[index * speakers + index] = 1.0;
+        }
+        (values, speakers as c_int, speakers as c_int)
+    });
+    output(outchannels, outs);
+    output(inchannels, ins);
+    if matrix.is_null() {
+        return Ok(());
+    }
+    let hop = if inchannel_hop > 0 {
+        inchannel_hop
+    } else {
+        ins
+    };
+    if hop < ins {
+        return Err(FMOD_ERR_INVALID_PARAM);
+    }
+    for out in 0..outs {
+        for input in 0..ins {
+            *matrix.add((out * hop + input) as usize) = values[(out * ins + input) as usize];
+        }
+    }
+    Ok(())
+}
+
+unsafe fn is_playing<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    isplaying: *mut FMOD_BOOL,
+) -> Result<(), FMOD_RESULT> {
+    state.get::<T>(handle)?;
+    let playing = if state.contains::<Channel>(handle) {
+        true
+    } else {
+        let groups = hierarchy(state, handle);
+        !state
+            .handles::<Channel>(|channel| groups.contains(&channel.group))
+            .is_empty()
+    };
+    output(isplaying, to_bool(playing));
+    Ok(())
+}
+
+unsafe fn get_dsp_clock<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    dspclock: *mut c_ulonglong,
+    parentclock: *mut c_ulonglong,
+) -> Result<(), FMOD_RESULT> {
+    let system = control::<T>(state, handle)?.system;
+    let clock = state.get::<System>(system)?.clock;
+    output(dspclock, clock);
+    output(parentclock, clock);
+    Ok(())
+}
+
+unsafe fn set_delay<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    dspclock_start: c_ulonglong,
+    dspclock_end: c_ulonglong,
+    stopchannels: FMOD_BOOL,
+) -> Result<(), FMOD_RESULT> {
+    control::<T>(state, handle)?.delay = (dspclock_start, dspclock_end, stopchannels != 0);
+    Ok(())
+}
+
+unsafe fn get_delay<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    dspclock_start: *mut c_ulonglong,
+    dspclock_end: *mut c_ulonglong,
+    stopchannels: *mut FMOD_BOOL,
+) -> Result<(), FMOD_RESULT> {
+    let (start, end, stop) = control::<T>(state, handle)?.delay;
+    output(dspclock_start, start);
+    output(dspclock_end, end);
+    output(stopchannels, to_bool(stop));
+    Ok(())
+}
+
+unsafe fn add_fade_point<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    dspclock: c_ulonglong,
+    volume: c_float,
+) -> Result<(), FMOD_RESULT> {
+    let points = &mut control::<T>(state, handle)?.fade_points;
+    points.retain(|(clock, _)| *clock != dspclock);
+    points.push((dspclock, volume));
+    points.sort_by_key(|(clock, _)| *clock);
+    Ok(())
+}
+
+unsafe fn set_fade_point_ramp<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    dspclock: c_ulonglong,
+    volume: c_float,
+) -> Result<(), FMOD_RESULT> {
+    let system = control::<T>(state, handle)?.system;
+    let clock = state.get::<System>(system)?.clock;
+    let control = control::<T>(state, handle)?;
+    let current = control.volume;
+    control
+        .fade_points
+        .retain(|(point, _)| *point < clock || *point > dspclock);
+    control.fade_points.push((clock, current));
+    control.fade_points.push((dspclock, volume));
+    control.fade_points.sort_by_key(|(clock, _)| *clock);
+    Ok(())
+}
+
+unsafe fn remove_fade_points<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    dspclock_start: c_ulonglong,
+    dspclock_end: c_ulonglong,
+) -> Result<(), FMOD_RESULT> {
+    control::<T>(state, handle)?
+        .fade_points
+        .retain(|(clock, _)| *clock < dspclock_start || *clock > dspclock_end);
+    Ok(())
+}
+
+unsafe fn get_fade_points<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    numpoints: *mut c_uint,
+    point_dspclock: *mut c_ulonglong,
+    point_volume: *mut c_float,
+) -> Result<(), FMOD_RESULT> {
+    let points = &control::<T>(state, handle)?.fade_points;
+    output(numpoints, points.len() as c_uint);
+    for (index, (clock, volume)) in points.iter().enumerate() {
+        if !point_dspclock.is_null() {
+            *point_dspclock.add(index) = *clock;
+        }
+        if !point_volume.is_null() {
+            *point_volume.add(index) = *volume;
+        }
+    }
+    Ok(())
+}
+
+unsafe fn get_dsp<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    index: c_int,
+    dsp: *mut *mut FMOD_DSP,
+) -> Result<(), FMOD_RESULT> {
+    let control = control::<T>(state, handle)?;
+    let position = dsp_position(control, index, false)?;
+    output(dsp, self::handle(control.dsps[position]));
+    Ok(())
+}
+
+unsafe fn add_dsp<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    index: c_int,
+    dsp: *mut FMOD_DSP,
+) -> Result<(), FMOD_RESULT> {
+    state.get::<Dsp>(dsp as usize)?;
+    let control = control::<T>(state, handle)?;
+    control.dsps.retain(|existing| *existing != dsp as usize);
+    let position = dsp_position(control, index, true)?;
+    control.dsps.insert(position, dsp as usize);
+    state.get::<Dsp>(dsp as usize)?.active = true;
+    Ok(())
+}
+
+unsafe fn remove_dsp<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    dsp: *mut FMOD_DSP,
+) -> Result<(), FMOD_RESULT> {
+    let control = control::<T>(state, handle)?;
+    let position = control
+        .dsps
+        .iter()
+        .position(|existing| *existing == dsp as usize)
+        .ok_or(FMOD_ERR_DSP_NOTFOUND)?;
+    control.dsps.remove(position);
+    Ok(())
+}
+
+unsafe fn get_num_dsps<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    numdsps: *mut c_int,
+) -> Result<(), FMOD_RESULT> {
+    output(numdsps, control::<T>(state, handle)?.dsps.len() as c_int);
+    Ok(())
+}
+
+unsafe fn set_dsp_index<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+) -> Result<(), FMOD_RESULT> {
+    let control = control::<T>(state, handle)?;
+    let position = control
+        .dsps
+        .iter()
+        .position(|existing| *existing == dsp as usize)
+        .ok_or(FMOD_ERR_DSP_NOTFOUND)?;
+    control.dsps.remove(position);
+    let target = dsp_position(control, index, true)?;
+    control.dsps.insert(target, dsp as usize);
+    Ok(())
+}
+
+unsafe fn get_dsp_index<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    dsp: *mut FMOD_DSP,
+    index: *mut c_int,
+) -> Result<(), FMOD_RESULT> {
+    let control = control::<T>(state, handle)?;
+    let position = control
+        .dsps
+        .iter()
+        .position(|existing| *existing == dsp as usize)
+        .ok_or(FMOD_ERR_DSP_NOTFOUND)?;
+    output(index, position as c_int);
+    Ok(())
+}
+
+unsafe fn set_3d_attributes<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    pos: *const FMOD_VECTOR,
+    vel: *const FMOD_VECTOR,
+) -> Result<(), FMOD_RESULT> {
+    let control = control::<T>(state, handle)?;
+    if !pos.is_null() {
+        control.position = *pos;
+    }
+    if !vel.is_null() {
+        control.velocity = *vel;
+    }
+    Ok(())
+}
+
+unsafe fn get_3d_attributes<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    pos: *mut FMOD_VECTOR,
+    vel: *mut FMOD_VECTOR,
+) -> Result<(), FMOD_RESULT> {
+    let control = control::<T>(state, handle)?;
+    output(pos, control.position);
+    output(vel, control.velocity);
+    Ok(())
+}
+
+unsafe fn set_3d_min_max_distance<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    mindistance: c_float,
+    maxdistance: c_float,
+) -> Result<(), FMOD_RESULT> {
+    if mindistance < 0.0 || maxdistance < mindistance {
+        return Err(FMOD_ERR_INVALID_PARAM);
+    }
+    let control = control::<T>(state, handle)?;
+    control.min_distance = mindistance;
+    control.max_distance = maxdistance;
+    Ok(())
+}
+
+unsafe fn get_3d_min_max_distance<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    mindistance: *mut c_float,
+    maxdistance: *mut c_float,
+) -> Result<(), FMOD_RESULT> {
+    let control = control::<T>(state, handle)?;
+    output(mindistance, control.min_distance);
+    output(maxdistance, control.max_distance);
+    Ok(())
+}
+
+unsafe fn set_3d_occlusion<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    directocclusion: c_float,
+    reverbocclusion: c_float,
+) -> Result<(), FMOD_RESULT> {
+    let control = control::<T>(state, handle)?;
+    control.direct_occlusion = directocclusion.clamp(0.0, 1.0);
+    control.reverb_occlusion = reverbocclusion.clamp(0.0, 1.0);
+    Ok(())
+}
+
+unsafe fn get_3d_occlusion<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    directocclusion: *mut c_float,
+    reverbocclusion: *mut c_float,
+) -> Result<(), FMOD_RESULT> {
+    let control = control::<T>(state, handle)?;
+    output(directocclusion, control.direct_occlusion);
+    output(reverbocclusion, control.reverb_occlusion);
+    Ok(())
+}
+
+unsafe fn set_user_data<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    userdata: *mut c_void,
+) -> Result<(), FMOD_RESULT> {
+    control::<T>(state, handle)?.user_data = userdata as usize;
+    Ok(())
+}
+
+unsafe fn get_user_data<T: Controlled>(
+    state: &mut State,
+    handle: usize,
+    userdata: *mut *mut c_void,
+) -> Result<(), FMOD_RESULT> {
+    output(
+        userdata,
+        control::<T>(state, handle)?.user_data as *mut c_void,
+    );
+    Ok(())
+}
+
+/// Declares functions of Channel and ChannelGroup with shared implementation.
+macro_rules! channel_control {
+    ($($channel:ident, $group:ident => $function:ident($($arg:ident: $type:ty),*);)*) => {
+        $(
+            pub unsafe fn $channel(channel: *mut FMOD_CHANNEL $(, $arg: $type)*) -> FMOD_RESULT {
+                call(|state| $function::<Channel>(state, channel as usize $(, $arg)*))
+            }
+
+            pub unsafe fn $group(channelgroup: *mut FMOD_CHANNELGROUP $(, $arg: $type)*) -> FMOD_RESULT {
+                call(|state| $function::<ChannelGroup>(state, channelgroup as usize $(, $arg)*))
+            }
+        )*
+    };
+}
+
+channel_control! {
+    FMOD_Channel_Stop, FMOD_ChannelGroup_Stop => stop();
+    FMOD_Channel_GetSystemObject, FMOD_ChannelGroup_GetSystemObject => get_system_object(system: *mut *mut FMOD_SYSTEM);
+    FMOD_Channel_SetPaused, FMOD_ChannelGroup_SetPaused => set_paused(paused: FMOD_BOOL);
+    FMOD_Channel_GetPaused, FMOD_ChannelGroup_GetPaused => get_paused(paused: *mut FMOD_BOOL);
+    FMOD_Channel_SetVolume, FMOD_ChannelGroup_SetVolume => set_volume(volume: c_float);
+    FMOD_Channel_GetVolume, FMOD_ChannelGroup_GetVolume => get_volume(volume: *mut c_float);
+    FMOD_Channel_SetVolumeRamp, FMOD_ChannelGroup_SetVolumeRamp => set_volume_ramp(ramp: FMOD_BOOL);
+    FMOD_Channel_GetVolumeRamp, FMOD_ChannelGroup_GetVolumeRamp => get_volume_ramp(ramp: *mut FMOD_BOOL);
+    FMOD_Channel_GetAudibility, FMOD_ChannelGroup_GetAudibility => get_audibility(audibility: *mut c_float);
+    FMOD_Channel_SetPitch, FMOD_ChannelGroup_SetPitch => set_pitch(pitch: c_float);
+    FMOD_Channel_GetPitch, FMOD_ChannelGroup_GetPitch => get_pitch(pitch: *mut c_float);
+    FMOD_Channel_SetMute, FMOD_ChannelGroup_SetMute => set_mute(mute: FMOD_BOOL);
+    FMOD_Channel_GetMute, FMOD_ChannelGroup_GetMute => get_mute(mute: *mut FMOD_BOOL);
+    FMOD_Channel_SetReverbProperties, FMOD_ChannelGroup_SetReverbProperties => set_reverb_properties(instance: c_int, wet: c_float);
+    FMOD_Channel_GetReverbProperties, FMOD_ChannelGroup_GetReverbProperties => get_reverb_properties(instance: c_int, wet: *mut c_float);
+    FMOD_Channel_SetLowPassGain, FMOD_ChannelGroup_SetLowPassGain => set_low_pass_gain(gain: c_float);
+    FMOD_Channel_GetLowPassGain, FMOD_ChannelGroup_GetLowPassGain => get_low_pass_gain(gain: *mut c_float);
+    FMOD_Channel_SetMode, FMOD_ChannelGroup_SetMode => set_mode(mode: FMOD_MODE);
+    FMOD_Channel_GetMode, FMOD_ChannelGroup_GetMode => get_mode(mode: *mut FMOD_MODE);
+    FMOD_Channel_IsPlaying, FMOD_ChannelGroup_IsPlaying => is_playing(isplaying: *mut FMOD_BOOL);
+    FMOD_Channel_SetMixLevelsOutput, FMOD_ChannelGroup_SetMixLevelsOutput => set_mix_levels_output(frontleft: c_float, frontright: c_float, center: c_float, lfe: c_float, surroundleft: c_float, surroundright: c_float, backleft: c_float, backright: c_float);
+    FMOD_Channel_SetMixLevelsInput, FMOD_ChannelGroup_SetMixLevelsInput => set_mix_levels_input(levels: *mut c_float, numlevels: c_int);
+    FMOD_Channel_SetMixMatrix, FMOD_ChannelGroup_SetMixMatrix => set_mix_matrix(matrix: *mut c_float, outchannels: c_int, inchannels: c_int, inchannel_hop: c_int);
+    FMOD_Channel_GetMixMatrix, FMOD_ChannelGroup_GetMixMatrix => get_mix_matrix(matrix: *mut c_float, outchannels: *mut c_int, inchannels: *mut c_int, inchannel_hop: c_int);
+    FMOD_Channel_GetDSPClock, FMOD_ChannelGroup_GetDSPClock => get_dsp_clock(dspclock: *mut c_ulonglong, parentclock: *mut c_ulonglong);
+    FMOD_Channel_SetDelay, FMOD_ChannelGroup_SetDelay => set_delay(dspclock_start: c_ulonglong, dspclock_end: c_ulonglong, stopchannels: FMOD_BOOL);
+    FMOD_Channel_GetDelay, FMOD_ChannelGroup_GetDelay => get_delay(dspclock_start: *mut c_ulonglong, dspclock_end: *mut c_ulonglong, stopchannels: *mut FMOD_BOOL);
+    FMOD_Channel_AddFadePoint, FMOD_ChannelGroup_AddFadePoint => add_fade_point(dspclock: c_ulonglong, volume: c_float);
+    FMOD_Channel_SetFadePointRamp, FMOD_ChannelGroup_SetFadePointRamp => set_fade_point_ramp(dspclock: c_ulonglong, volume: c_float);
+    FMOD_Channel_RemoveFadePoints, FMOD_ChannelGroup_RemoveFadePoints => remove_fade_points(dspclock_start: c_ulonglong, dspclock_end: c_ulonglong);
+    FMOD_Channel_GetFadePoints, FMOD_ChannelGroup_GetFadePoints => get_fade_points(numpoints: *mut c_uint, point_dspclock: *mut c_ulonglong, point_volume: *mut c_float);
+    FMOD_Channel_GetDSP, FMOD_ChannelGroup_GetDSP => get_dsp(index: c_int, dsp: *mut *mut FMOD_DSP);
+    FMOD_Channel_AddDSP, FMOD_ChannelGroup_AddDSP => add_dsp(index: c_int, dsp: *mut FMOD_DSP);
+    FMOD_Channel_RemoveDSP, FMOD_ChannelGroup_RemoveDSP => remove_dsp(dsp: *mut FMOD_DSP);
+    FMOD_Channel_GetNumDSPs, FMOD_ChannelGroup_GetNumDSPs => get_num_dsps(numdsps: *mut c_int);
+    FMOD_Channel_SetDSPIndex, FMOD_ChannelGroup_SetDSPIndex => set_dsp_index(dsp: *mut FMOD_DSP, index: c_int);
+    FMOD_Channel_GetDSPIndex, FMOD_ChannelGroup_GetDSPIndex => get_dsp_index(dsp: *mut FMOD_DSP, index: *mut c_int);
+    FMOD_Channel_Set3DAttributes, FMOD_ChannelGroup_Set3DAttributes => set_3d_attributes(pos: *const FMOD_VECTOR, vel: *const FMOD_VECTOR);
+    FMOD_Channel_Get3DAttributes, FMOD_ChannelGroup_Get3DAttributes => get_3d_attributes(pos: *mut FMOD_VECTOR, vel: *mut FMOD_VECTOR);
+    FMOD_Channel_Set3DMinMaxDistance, FMOD_ChannelGroup_Set3DMinMaxDistance => set_3d_min_max_distance(mindistance: c_float, maxdistance: c_float);
+    FMOD_Channel_Get3DMinMaxDistance, FMOD_ChannelGroup_Get3DMinMaxDistance => get_3d_min_max_distance(mindistance: *mut c_float, maxdistance: *mut c_float);
+    FMOD_Channel_Set3DOcclusion, FMOD_ChannelGroup_Set3DOcclusion => set_3d_occlusion(directocclusion: c_float, reverbocclusion: c_float);
+    FMOD_Channel_Get3DOcclusion, FMOD_ChannelGroup_Get3DOcclusion => get_3d_occlusion(directocclusion: *mut c_float, reverbocclusion: *mut c_float);
+    FMOD_Channel_SetUserData, FMOD_ChannelGroup_SetUserData => set_user_data(userdata: *mut c_void);
+    FMOD_Channel_GetUserData, FMOD_ChannelGroup_GetUserData => get_user_data(userdata: *mut *mut c_void);
+}
+
+pub unsafe fn FMOD_Channel_SetFrequency(
+    channel: *mut FMOD_CHANNEL,
+    frequency: c_float,
+) -> FMOD_RESULT {
+    with(channel, |channel: &mut Channel| {
+        channel.frequency = frequency;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Channel_GetFrequency(
+    channel: *mut FMOD_CHANNEL,
+    frequency: *mut c_float,
+) -> FMOD_RESULT {
+    with(channel, |channel: &mut Channel| {
+        output(frequency, channel.frequency);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Channel_SetPriority(channel: *mut FMOD_CHANNEL, priority: c_int) -> FMOD_RESULT {
+    with(channel, |channel: &mut Channel| {
+        if !(0..=256).contains(&priority) {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        channel.priority = priority;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Channel_GetPriority(
+    channel: *mut FMOD_CHANNEL,
+    priority: *mut c_int,
+) -> FMOD_RESULT {
+    with(channel, |channel: &mut Channel| {
+        output(priority, channel.priority);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Channel_SetPosition(
+    channel: *mut FMOD_CHANNEL,
+    position: c_uint,
+    postype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    call(|state| {
+        let sound = state.get::<Channel>(channel as usize)?.sound;
+        let bytes = state.get::<Sound>(sound)?.bytes_per_sample();
+        let channel = state.get::<Channel>(channel as usize)?;
+        let position = match postype {
+            FMOD_TIMEUNIT_PCM => position as f64,
+            FMOD_TIMEUNIT_PCMBYTES => (position / bytes) as f64,
+            FMOD_TIMEUNIT_MS => position as f64 * channel.frequency as f64 / 1000.0,
+            _ => return Err(FMOD_ERR_FORMAT),
+        };
+        if position > channel.length as f64 {
+            return Err(FMOD_ERR_INVALID_POSITION);
+        }
+        channel.position = position;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Channel_GetPosition(
+    channel: *mut FMOD_CHANNEL,
+    position: *mut c_uint,
+    postype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    call(|state| {
+        let sound = state.get::<Channel>(channel as usize)?.sound;
+        let bytes = state.get::<Sound>(sound)?.bytes_per_sample();
+        let channel = state.get::<Channel>(channel as usize)?;
+        let value = match postype {
+            FMOD_TIMEUNIT_PCM => channel.position as c_uint,
+            FMOD_TIMEUNIT_PCMBYTES => channel.position as c_uint * bytes,
+            FMOD_TIMEUNIT_MS => (channel.position * 1000.0 / channel.frequency as f64) as c_uint,
+            _ => return Err(FMOD_ERR_FORMAT),
+        };
+        output(position, value);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Channel_SetChannelGroup(
+    channel: *mut FMOD_CHANNEL,
+    channelgroup: *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<ChannelGroup>(channelgroup as usize)?;
+        state.get::<Channel>(channel as usize)?.group = channelgroup as usize;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Channel_GetChannelGroup(
+    channel: *mut FMOD_CHANNEL,
+    channelgroup: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    with(channel, |channel: &mut Channel| {
+        output(channelgroup, handle(channel.group));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Channel_SetLoopCount(
+    channel: *mut FMOD_CHANNEL,
+    loopcount: c_int,
+) -> FMOD_RESULT {
+    with(channel, |channel: &mut Channel| {
+        if loopcount < -1 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        channel.loop_count = loopcount;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Channel_GetLoopCount(
+    channel: *mut FMOD_CHANNEL,
+    loopcount: *mut c_int,
+) -> FMOD_RESULT {
+    with(channel, |channel: &mut Channel| {
+        output(loopcount, channel.loop_count);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Channel_IsVirtual(
+    channel: *mut FMOD_CHANNEL,
+    isvirtual: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(channel, |_: &mut Channel| {
+        output(isvirtual, 0);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Channel_GetCurrentSound(
+    channel: *mut FMOD_CHANNEL,
+    sound: *mut *mut FMOD_SOUND,
+) -> FMOD_RESULT {
+    with(channel, |channel: &mut Channel| {
+        output(sound, handle(channel.sound));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Channel_GetIndex(channel: *mut FMOD_CHANNEL, index: *mut c_int) -> FMOD_RESULT {
+    with(channel, |channel: &mut Channel| {
+        output(index, channel.index);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_ChannelGroup_Release(channelgroup: *mut FMOD_CHANNELGROUP) -> FMOD_RESULT {
+    call(|state| {
+        let group = state.get::<ChannelGroup>(channelgroup as usize)?;
+        if group.parent == 0 {
+            // master channel group can't be released
+            return Err(FMOD_ERR_INVALID_HANDLE);
+        }
+        release_group(state, channelgroup as usize);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_ChannelGroup_AddGroup(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    group: *mut FMOD_CHANNELGROUP,
+    _propagatedspclock: FMOD_BOOL,
+    connection: *mut *mut FMOD_DSPCONNECTION,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<ChannelGroup>(channelgroup as usize)?;
+        if hierarchy(state, group as usize).contains(&(channelgroup as usize)) {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        state.get::<ChannelGroup>(group as usize)?.parent = channelgroup as usize;
+        output(connection, std::ptr::null_mut());
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_ChannelGroup_GetNumGroups(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    numgroups: *mut c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<ChannelGroup>(channelgroup as usize)?;
+        let groups = state.handles::<ChannelGroup>(|group| group.parent == channelgroup as usize);
+        output(numgroups, groups.len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_ChannelGroup_GetGroup(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    index: c_int,
+    group: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<ChannelGroup>(channelgroup as usize)?;
+        let groups = state.handles::<ChannelGroup>(|group| group.parent == channelgroup as usize);
+        let found = groups.get(index as usize).ok_or(FMOD_ERR_INVALID_PARAM)?;
+        output(group, handle(*found));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_ChannelGroup_GetParentGroup(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    group: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    with(channelgroup, |channelgroup: &mut ChannelGroup| {
+        output(group, handle(channelgroup.parent));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_ChannelGroup_GetName(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    name: *mut c_char,
+    namelen: c_int,
+) -> FMOD_RESULT {
+    with(channelgroup, |channelgroup: &mut ChannelGroup| {
+        // FMOD silently truncates group name
+        let _ = output_string(name, namelen, std::ptr::null_mut(), &channelgroup.name);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_ChannelGroup_GetNumChannels(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    numchannels: *mut c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<ChannelGroup>(channelgroup as usize)?;
+        let channels = state.handles::<Channel>(|channel| channel.group == channelgroup as usize);
+        output(numchannels, channels.len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_ChannelGroup_GetChannel(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    index: c_int,
+    channel: *mut *mut FMOD_CHANNEL,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<ChannelGroup>(channelgroup as usize)?;
+        let channels = state.handles::<Channel>(|channel| channel.group == channelgroup as usize);
+        let found = channels.get(index as usize).ok_or(FMOD_ERR_INVALID_PARAM)?;
+        output(channel, handle(*found));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_Release(dsp: *mut FMOD_DSP) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Dsp>(dsp as usize)?;
+        let in_use = !state
+            .handles::<Channel>(|channel| channel.control.dsps.contains(&(dsp as usize)))
+            .is_empty()
+            || !state
+                .handles::<ChannelGroup>(|group| group.control.dsps.contains(&(dsp as usize)))
+                .is_empty();
+        if in_use {
+            return Err(FMOD_ERR_DSP_INUSE);
+        }
+        dsp::disconnect_all(state, dsp as usize, true, true);
+        state.remove(dsp as usize);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetSystemObject(
+    dsp: *mut FMOD_DSP,
+    system: *mut *mut FMOD_SYSTEM,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        output(system, handle(dsp.system));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetType(dsp: *mut FMOD_DSP, type_: *mut FMOD_DSP_TYPE) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        output(type_, dsp.type_);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_SetActive(dsp: *mut FMOD_DSP, active: FMOD_BOOL) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        dsp.active = active != 0;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetActive(dsp: *mut FMOD_DSP, active: *mut FMOD_BOOL) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        output(active, to_bool(dsp.active));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_SetBypass(dsp: *mut FMOD_DSP, bypass: FMOD_BOOL) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        dsp.bypass = bypass != 0;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetBypass(dsp: *mut FMOD_DSP, bypass: *mut FMOD_BOOL) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        output(bypass, to_bool(dsp.bypass));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_SetWetDryMix(
+    dsp: *mut FMOD_DSP,
+    prewet: c_float,
+    postwet: c_float,
+    dry: c_float,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        dsp.wet_dry = (prewet, postwet, dry);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetWetDryMix(
+    dsp: *mut FMOD_DSP,
+    prewet: *mut c_float,
+    postwet: *mut c_float,
+    dry: *mut c_float,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        output(prewet, dsp.wet_dry.0);
+        output(postwet, dsp.wet_dry.1);
+        output(dry, dsp.wet_dry.2);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_Reset(dsp: *mut FMOD_DSP) -> FMOD_RESULT {
+    with(dsp, |_: &mut Dsp| Ok(()))
+}
+
+pub unsafe fn FMOD_DSP_SetParameterFloat(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    value: c_float,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        dsp.floats.insert(index, value);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_SetParameterInt(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    value: c_int,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        dsp.ints.insert(index, value);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_SetParameterBool(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    value: FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        dsp.bools.insert(index, value != 0);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetParameterFloat(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    value: *mut c_float,
+    valuestr: *mut c_char,
+    valuestrlen: c_int,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        let parameter = dsp.floats.get(&index).copied().unwrap_or_default();
+        output(value, parameter);
+        let _ = output_string(
+            valuestr,
+            valuestrlen,
+            std::ptr::null_mut(),
+            &format!("{parameter:.2}"),
+        );
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetParameterInt(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    value: *mut c_int,
+    valuestr: *mut c_char,
+    valuestrlen: c_int,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        let parameter = dsp.ints.get(&index).copied().unwrap_or_default();
+        output(value, parameter);
+        let name = match parameter::parameters(dsp.type_).get(index as usize) {
+            Some(Parameter {
+                kind: Kind::Int(min, _, _, names),
+                ..
+            }) => names.get((parameter - min) as usize).copied(),
+            _ => None,
+        };
+        let label = name.map_or_else(|| parameter.to_string(), str::to_string);
+        let _ = output_string(valuestr, valuestrlen, std::ptr::null_mut(), &label);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetParameterBool(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    value: *mut FMOD_BOOL,
+    valuestr: *mut c_char,
+    valuestrlen: c_int,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        let parameter = dsp.bools.get(&index).copied().unwrap_or_default();
+        output(value, to_bool(parameter));
+        let label = if parameter { "On" } else { "Off" };
+        let _ = output_string(valuestr, valuestrlen, std::ptr::null_mut(), label);
+        Ok(())
+    })
+}
+
+fn bytes<T: Copy>(value: T) -> Vec<u8> {
+    let size = std::mem::size_of::<T>();
+    unsafe { std::slice::from_raw_parts(&value as *const T as *const u8, size).to_vec() }
+}
+
+/// Data the stub reports before parameter is set, analysis DSPs describe silence.
+fn default_data(type_: FMOD_DSP_TYPE, index: c_int) -> Vec<u8> {
+    match (type_, index) {
+        (FMOD_DSP_TYPE_LOUDNESS_METER, FMOD_DSP_LOUDNESS_METER_INFO) => {
+            bytes(FMOD_DSP_LOUDNESS_METER_INFO_TYPE {
+                momentaryloudness: f32::NEG_INFINITY,
+                shorttermloudness: f32::NEG_INFINITY,
+                integratedloudness: f32::NEG_INFINITY,
+                loudness10thpercentile: f32::NEG_INFINITY,
+                loudness95thpercentile: f32::NEG_INFINITY,
+                maxtruepeak: f32::NEG_INFINITY,
+                maxmomentaryloudness: f32::NEG_INFINITY,
+                ..Default::default()
+            })
+        }
+        (FMOD_DSP_TYPE_LOUDNESS_METER, FMOD_DSP_LOUDNESS_METER_WEIGHTING) => {
+            bytes(FMOD_DSP_LOUDNESS_METER_WEIGHTING_TYPE {
+                channelweight: [1.0; 32],
+            })
+        }
+        _ => match parameter::data_type(type_, index) {
+            Some(FMOD_DSP_PARAMETER_DATA_TYPE_OVERALLGAIN) => {
+                bytes(FMOD_DSP_PARAMETER_OVERALLGAIN {
+                    linear_gain: 1.0,
+                    linear_gain_additive: 0.0,
+                })
+            }
+            Some(FMOD_DSP_PARAMETER_DATA_TYPE_3DATTRIBUTES) => {
+                bytes(FMOD_DSP_PARAMETER_3DATTRIBUTES::default())
+            }
+            Some(FMOD_DSP_PARAMETER_DATA_TYPE_3DATTRIBUTES_MULTI) => {
+                let mut attributes = FMOD_DSP_PARAMETER_3DATTRIBUTES_MULTI {
+                    numlisteners: 1,
+                    ..Default::default()
+                };
+                attributes.weight[0] = 1.0;
+                bytes(attributes)
+            }
+            Some(FMOD_DSP_PARAMETER_DATA_TYPE_SIDECHAIN) => {
+                bytes(FMOD_DSP_PARAMETER_SIDECHAIN::default())
+            }
+            Some(FMOD_DSP_PARAMETER_DATA_TYPE_ATTENUATION_RANGE) => {
+                bytes(FMOD_DSP_PARAMETER_ATTENUATION_RANGE {
+                    min: 1.0,
+                    max: 20.0,
+                })
+            }
+            _ => vec![],
+        },
+    }
+}
+
+/// Size of built-in data type, user data has any size.
+fn data_size(datatype: FMOD_DSP_PARAMETER_DATA_TYPE) -> Option<usize> {
+    let size = match datatype {
+        FMOD_DSP_PARAMETER_DATA_TYPE_OVERALLGAIN => size_of::<FMOD_DSP_PARAMETER_OVERALLGAIN>(),
+        FMOD_DSP_PARAMETER_DATA_TYPE_3DATTRIBUTES => size_of::<FMOD_DSP_PARAMETER_3DATTRIBUTES>(),
+        FMOD_DSP_PARAMETER_DATA_TYPE_3DATTRIBUTES_MULTI => {
+            size_of::<FMOD_DSP_PARAMETER_3DATTRIBUTES_MULTI>()
+        }
+        FMOD_DSP_PARAMETER_DATA_TYPE_SIDECHAIN => size_of::<FMOD_DSP_PARAMETER_SIDECHAIN>(),
+        FMOD_DSP_PARAMETER_DATA_TYPE_FFT => size_of::<FMOD_DSP_PARAMETER_FFT>(),
+        FMOD_DSP_PARAMETER_DATA_TYPE_ATTENUATION_RANGE => {
+            size_of::<FMOD_DSP_PARAMETER_ATTENUATION_RANGE>()
+        }
+        _ => return None,
+    };
+    Some(size)
+}
+
+pub unsafe fn FMOD_DSP_SetParameterData(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    data: *mut c_void,
+    length: c_uint,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        if data.is_null() && length > 0 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        let datatype = parameter::data_type(dsp.type_, index);
+        if datatype == Some(FMOD_DSP_PARAMETER_DATA_TYPE_FFT) {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        if let Some(size) = datatype.and_then(data_size) {
+            if size != length as usize {
+                return Err(FMOD_ERR_INVALID_PARAM);
+            }
+        }
+        let value = if length == 0 {
+            vec![]
+        } else {
+            std::slice::from_raw_parts(data as *const u8, length as usize).to_vec()
+        };
+        dsp.data.insert(index, value);
+        Ok(())
+    })
+}
+
+/// Spectrum of silence, data holds FFT parameter followed by arrays its pointers refer to.
+fn silent_spectrum(window_size: c_int, channels: c_int) -> Vec<u8> {
+    let header = std::mem::size_of::<FMOD_DSP_PARAMETER_FFT>();
+    let length = (window_size / 2).max(0) as usize;
+    let channels = channels.clamp(0, 32) as usize;
+    let mut data = vec![0u8; header + length * channels * 4];
+    let mut fft = FMOD_DSP_PARAMETER_FFT {
+        length: length as c_int,
+        numchannels: channels as c_int,
+        ..Default::default()
+    };
+    for channel in 0..channels {
+        let offset = header + channel * length * 4;
+        fft.spectrum[channel] = unsafe { data.as_mut_ptr().add(offset) } as *mut c_float;
+    }
+    data[..header].copy_from_slice(&bytes(fft));
+    data
+}
+
+/// Returned pointer stays valid until the parameter is set or FFT spectrum is read again
+/// or DSP is released.
+pub unsafe fn FMOD_DSP_GetParameterData(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    data: *mut *mut c_void,
+    length: *mut c_uint,
+    valuestr: *mut c_char,
+    valuestrlen: c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        let (system, type_, window_size) = {
+            let dsp = state.get::<Dsp>(dsp as usize)?;
+            let window_size = dsp.ints.get(&FMOD_DSP_FFT_WINDOWSIZE).copied();
+            (dsp.system, dsp.type_, window_size.unwrap_or_default())
+        };
+        let spectrum = if (type_, index) == (FMOD_DSP_TYPE_FFT, FMOD_DSP_FFT_SPECTRUMDATA) {
+            let system = state.get::<System>(system)?;
+            let channels = speaker_mode_channels(system, system.speaker_mode)?;
+            Some(silent_spectrum(window_size, channels))
+        } else {
+            None
+        };
+        let dsp = state.get::<Dsp>(dsp as usize)?;
+        if let Some(spectrum) = spectrum {
+            dsp.data.insert(index, spectrum);
+        }
+        let parameter = dsp
+            .data
+            .entry(index)
+            .or_insert_with(|| default_data(type_, index));
+        output(data, parameter.as_mut_ptr() as *mut c_void);
+        output(length, parameter.len() as c_uint);
+        let _ = output_string(valuestr, valuestrlen, std::ptr::null_mut(), "");
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_SetUserData(dsp: *mut FMOD_DSP, userdata: *mut c_void) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        dsp.user_data = userdata as usize;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetUserData(dsp: *mut FMOD_DSP, userdata: *mut *mut c_void) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        output(userdata, dsp.user_data as *mut c_void);
+        Ok(())
+    })
+}
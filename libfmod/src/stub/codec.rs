@@ -0,0 +1,237 @@
+use std::ffi::{c_char, c_uint, c_void};
+
+use crate::ffi::*;
+use crate::stub::system::{Sound, System};
+use crate::stub::{input, input_string, output, state, with};
+
+/// Callbacks of codec plugin. Registered codecs are tried by priority before
+/// the stub reads file itself, an opened codec decodes `Sound::readData` until the sound is released.
+#[derive(Clone, Copy)]
+pub(crate) struct CodecPlugin {
+    priority: c_uint,
+    open: FMOD_CODEC_OPEN_CALLBACK,
+    close: FMOD_CODEC_CLOSE_CALLBACK,
+    read: FMOD_CODEC_READ_CALLBACK,
+    setposition: FMOD_CODEC_SETPOSITION_CALLBACK,
+}
+
+/// State passed to codec callbacks with content of the file, plugins only see the first field.
+#[repr(C)]
+struct CodecState {
+    state: FMOD_CODEC_STATE,
+    functions: FMOD_CODEC_STATE_FUNCTIONS,
+    codec: CodecPlugin,
+    data: Vec<u8>,
+    position: usize,
+}
+
+unsafe fn file<'a>(codec_state: *mut FMOD_CODEC_STATE) -> Option<&'a mut CodecState> {
+    (codec_state as *mut CodecState).as_mut()
+}
+
+unsafe extern "C" fn file_read(
+    codec_state: *mut FMOD_CODEC_STATE,
+    buffer: *mut c_void,
+    sizebytes: c_uint,
+    bytesread: *mut c_uint,
+) -> FMOD_RESULT {
+    let (Some(file), false) = (file(codec_state), buffer.is_null()) else {
+        return FMOD_ERR_INVALID_PARAM;
+    };
+    let start = file.position.min(file.data.len());
+    let count = (sizebytes as usize).min(file.data.len() - start);
+    std::ptr::copy_nonoverlapping(file.data[start..].as_ptr(), buffer as *mut u8, count);
+    file.position = start + count;
+    output(bytesread, count as c_uint);
+    if count < sizebytes as usize {
+        return FMOD_ERR_FILE_EOF;
+    }
+    FMOD_OK
+}
+
+unsafe extern "C" fn file_seek(
+    codec_state: *mut FMOD_CODEC_STATE,
+    pos: c_uint,
+    method: FMOD_CODEC_SEEK_METHOD,
+) -> FMOD_RESULT {
+    let Some(file) = file(codec_state) else {
+        return FMOD_ERR_INVALID_PARAM;
+    };
+    let position = match method {
+        FMOD_CODEC_SEEK_METHOD_SET => pos as usize,
+        FMOD_CODEC_SEEK_METHOD_CURRENT => file.position + pos as usize,
+        FMOD_CODEC_SEEK_METHOD_END => file.data.len() + pos as usize,
+        _ => return FMOD_ERR_INVALID_PARAM,
+    };
+    if position > file.data.len() {
+        return FMOD_ERR_FILE_COULDNOTSEEK;
+    }
+    file.position = position;
+    FMOD_OK
+}
+
+unsafe extern "C" fn file_tell(
+    codec_state: *mut FMOD_CODEC_STATE,
+    pos: *mut c_uint,
+) -> FMOD_RESULT {
+    let Some(file) = file(codec_state) else {
+        return FMOD_ERR_INVALID_PARAM;
+    };
+    output(pos, file.position as c_uint);
+    FMOD_OK
+}
+
+unsafe extern "C" fn file_size(
+    codec_state: *mut FMOD_CODEC_STATE,
+    size: *mut c_uint,
+) -> FMOD_RESULT {
+    let Some(file) = file(codec_state) else {
+        return FMOD_ERR_INVALID_PARAM;
+    };
+    output(size, file.data.len() as c_uint);
+    FMOD_OK
+}
+
+/// Opens sound with the first registered codec that accepts it, callbacks are invoked
+/// without holding the stub lock. Returns codec state or 0 if sound is left to the stub.
+pub(crate) unsafe fn open(
+    system: usize,
+    name_or_data: *const c_char,
+    mode: FMOD_MODE,
+    exinfo: *mut FMOD_CREATESOUNDEXINFO,
+) -> usize {
+    let mut codecs = match state().get::<System>(system) {
+        Ok(system) if system.initialized => system.codecs.clone(),
+        _ => return 0,
+    };
+    if codecs.is_empty() || mode & FMOD_OPENUSER != 0 {
+        return 0;
+    }
+    codecs.sort_by_key(|codec| codec.priority);
+    let data = if mode & (FMOD_OPENMEMORY | FMOD_OPENMEMORY_POINT) != 0 {
+        if name_or_data.is_null() {
+            return 0;
+        }
+        let length = input(exinfo).length as usize;
+        std::slice::from_raw_parts(name_or_data as *const u8, length).to_vec()
+    } else {
+        match input_string(name_or_data).map(std::fs::read) {
+            Ok(Ok(data)) => data,
+            _ => return 0,
+        }
+    };
+    let codec_state = Box::into_raw(Box::new(CodecState {
+        state: FMOD_CODEC_STATE::default(),
+        functions: FMOD_CODEC_STATE_FUNCTIONS {
+            read: Some(file_read),
+            seek: Some(file_seek),
+            tell: Some(file_tell),
+            size: Some(file_size),
+            ..Default::default()
+        },
+        codec: codecs[0],
+        data,
+        position: 0,
+    }));
+    (*codec_state).state.functions = &mut (*codec_state).functions;
+    for codec in codecs {
+        let Some(open) = codec.open else {
+            continue;
+        };
+        (*codec_state).codec = codec;
+        (*codec_state).position = 0;
+        (*codec_state).state.plugindata = std::ptr::null_mut();
+        (*codec_state).state.waveformat = std::ptr::null_mut();
+        (*codec_state).state.numsubsounds = 0;
+        let result = open(codec_state as *mut FMOD_CODEC_STATE, mode, exinfo);
+        if result == FMOD_OK && !(*codec_state).state.waveformat.is_null() {
+            return codec_state as usize;
+        }
+    }
+    drop(Box::from_raw(codec_state));
+    0
+}
+
+/// The first waveformat of opened codec, subsounds are not supported by the stub.
+pub(crate) unsafe fn waveformat(codec_state: usize) -> FMOD_CODEC_WAVEFORMAT {
+    *(*(codec_state as *mut CodecState)).state.waveformat
+}
+
+pub(crate) unsafe fn close(codec_state: usize) {
+    if codec_state == 0 {
+        return;
+    }
+    let codec_state = codec_state as *mut CodecState;
+    if let Some(close) = (*codec_state).codec.close {
+        close(codec_state as *mut FMOD_CODEC_STATE);
+    }
+    drop(Box::from_raw(codec_state));
+}
+
+/// Takes codecs of all system sounds, so they can be closed after sounds are released.
+pub(crate) fn take_sounds(system: usize) -> Vec<usize> {
+    let mut state = state();
+    let mut codecs = vec![];
+    for sound in state.handles::<Sound>(|sound| sound.system == system && sound.codec != 0) {
+        if let Ok(sound) = state.get::<Sound>(sound) {
+            codecs.push(std::mem::take(&mut sound.codec));
+        }
+    }
+    codecs
+}
+
+pub unsafe fn FMOD_System_RegisterCodec(
+    system: *mut FMOD_SYSTEM,
+    description: *mut FMOD_CODEC_DESCRIPTION,
+    handle: *mut c_uint,
+    priority: c_uint,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        if description.is_null() {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        let description = &*description;
+        system.codecs.push(CodecPlugin {
+            priority,
+            open: description.open,
+            close: description.close,
+            read: description.read,
+            setposition: description.setposition,
+        });
+        output(handle, system.codecs.len() as c_uint);
+        Ok(())
+    })
+}
+
+/// Decodes samples (per channel) into buffer, returns number of samples decoded.
+pub(crate) unsafe fn read(
+    codec_state: usize,
+    buffer: *mut c_void,
+    samples: c_uint,
+) -> Result<c_uint, FMOD_RESULT> {
+    let Some(read) = (*(codec_state as *mut CodecState)).codec.read else {
+        return Err(FMOD_ERR_UNSUPPORTED);
+    };
+    let mut decoded: c_uint = 0;
+    match read(
+        codec_state as *mut FMOD_CODEC_STATE,
+        buffer,
+        samples,
+        &mut decoded,
+    ) {
+        FMOD_OK => Ok(decoded.min(samples)),
+        error => Err(error),
+    }
+}
+
+pub(crate) unsafe fn seek(codec_state: usize, pcm: c_uint) -> FMOD_RESULT {
+    match (*(codec_state as *mut CodecState)).codec.setposition {
+        Some(set_position) => set_position(
+            codec_state as *mut FMOD_CODEC_STATE,
+            0,
+            pcm,
+            FMOD_TIMEUNIT_PCM,
+        ),
+        None => FMOD_ERR_UNSUPPORTED,
+    }
+}
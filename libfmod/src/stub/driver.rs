@@ -0,0 +1,206 @@
+use std::ffi::{c_char, c_int, c_void};
+
+use crate::ffi::*;
+use crate::stub::system::{speaker_mode_channels, System};
+use crate::stub::{guid, output, output_string, state, with, DeviceDefinition};
+
+/// Replaces devices of the system, callbacks about the change are invoked on the next update.
+/// Current output driver follows its device or falls back to the default one if device is lost.
+pub(crate) fn define(system: usize, outputs: Vec<DeviceDefinition>, inputs: Vec<DeviceDefinition>) {
+    let mut state = state();
+    let Ok(system) = state.get::<System>(system) else {
+        return;
+    };
+    if system.drivers != outputs {
+        system.callback_pending |= FMOD_SYSTEM_CALLBACK_DEVICELISTCHANGED;
+        let current = system
+            .drivers
+            .get(system.driver as usize)
+            .map(|device| device.name.clone());
+        let position = outputs
+            .iter()
+            .position(|device| Some(&device.name) == current.as_ref());
+        system.driver = match position {
+            Some(position) => position as c_int,
+            None => {
+                system.callback_pending |= FMOD_SYSTEM_CALLBACK_DEVICELOST;
+                0
+            }
+        };
+        system.drivers = outputs;
+    }
+    if system.record_drivers != inputs {
+        system.callback_pending |= FMOD_SYSTEM_CALLBACK_RECORDLISTCHANGED;
+        system.record_drivers = inputs;
+    }
+}
+
+/// Invokes system callback for device changes without holding the stub lock.
+pub(crate) unsafe fn notify(system: usize) -> FMOD_RESULT {
+    let (callback, pending, user_data) = {
+        let mut state = state();
+        let Ok(system) = state.get::<System>(system) else {
+            return FMOD_ERR_INVALID_HANDLE;
+        };
+        let pending = std::mem::take(&mut system.callback_pending) & system.callback_mask;
+        (system.callback, pending, system.user_data)
+    };
+    let Some(callback) = callback else {
+        return FMOD_OK;
+    };
+    for type_ in [
+        FMOD_SYSTEM_CALLBACK_DEVICELISTCHANGED,
+        FMOD_SYSTEM_CALLBACK_DEVICELOST,
+        FMOD_SYSTEM_CALLBACK_RECORDLISTCHANGED,
+    ] {
+        if pending & type_ != 0 {
+            callback(
+                system as *mut FMOD_SYSTEM,
+                type_,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                user_data as *mut c_void,
+            );
+        }
+    }
+    FMOD_OK
+}
+
+unsafe fn device_info(
+    system: &System,
+    device: &DeviceDefinition,
+    name: *mut c_char,
+    namelen: c_int,
+    id: *mut FMOD_GUID,
+    systemrate: *mut c_int,
+    speakermode: *mut FMOD_SPEAKERMODE,
+    speakermodechannels: *mut c_int,
+) -> Result<(), FMOD_RESULT> {
+    let speaker_mode = device.speaker_mode.into();
+    // FMOD silently truncates device name
+    let _ = output_string(name, namelen, std::ptr::null_mut(), &device.name);
+    output(id, guid(&device.name));
+    output(systemrate, device.system_rate);
+    output(speakermode, speaker_mode);
+    output(
+        speakermodechannels,
+        speaker_mode_channels(system, speaker_mode)?,
+    );
+    Ok(())
+}
+
+pub unsafe fn FMOD_System_GetNumDrivers(
+    system: *mut FMOD_SYSTEM,
+    numdrivers: *mut c_int,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        output(numdrivers, system.drivers.len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetDriverInfo(
+    system: *mut FMOD_SYSTEM,
+    id: c_int,
+    name: *mut c_char,
+    namelen: c_int,
+    guid: *mut FMOD_GUID,
+    systemrate: *mut c_int,
+    speakermode: *mut FMOD_SPEAKERMODE,
+    speakermodechannels: *mut c_int,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        let device = system
+            .drivers
+            .get(id as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)?;
+        device_info(
+            system,
+            device,
+            name,
+            namelen,
+            guid,
+            systemrate,
+            speakermode,
+            speakermodechannels,
+        )
+    })
+}
+
+pub unsafe fn FMOD_System_SetDriver(system: *mut FMOD_SYSTEM, driver: c_int) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        if driver < 0 || driver as usize >= system.drivers.len() {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        system.driver = driver;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetDriver(system: *mut FMOD_SYSTEM, driver: *mut c_int) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        output(driver, system.driver);
+        Ok(())
+    })
+}
+
+/// Record devices of the stub are always connected, the first one is default.
+pub unsafe fn FMOD_System_GetRecordNumDrivers(
+    system: *mut FMOD_SYSTEM,
+    numdrivers: *mut c_int,
+    numconnected: *mut c_int,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        output(numdrivers, system.record_drivers.len() as c_int);
+        output(numconnected, system.record_drivers.len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetRecordDriverInfo(
+    system: *mut FMOD_SYSTEM,
+    id: c_int,
+    name: *mut c_char,
+    namelen: c_int,
+    guid: *mut FMOD_GUID,
+    systemrate: *mut c_int,
+    speakermode: *mut FMOD_SPEAKERMODE,
+    speakermodechannels: *mut c_int,
+    state: *mut FMOD_DRIVER_STATE,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        let device = system
+            .record_drivers
+            .get(id as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)?;
+        device_info(
+            system,
+            device,
+            name,
+            namelen,
+            guid,
+            systemrate,
+            speakermode,
+            speakermodechannels,
+        )?;
+        let default = if id == 0 {
+            FMOD_DRIVER_STATE_DEFAULT
+        } else {
+            0
+        };
+        output(state, FMOD_DRIVER_STATE_CONNECTED | default);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_SetCallback(
+    system: *mut FMOD_SYSTEM,
+    callback: FMOD_SYSTEM_CALLBACK,
+    callbackmask: FMOD_SYSTEM_CALLBACK_TYPE,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        system.callback = callback;
+        system.callback_mask = if callback.is_some() { callbackmask } else { 0 };
+        Ok(())
+    })
+}
@@ -0,0 +1,347 @@
+use std::ffi::{c_char, c_float, c_int, c_short, c_uint, c_void};
+
+use crate::ffi::*;
+use crate::stub::channel::Dsp;
+use crate::stub::system::{speaker_mode_channels, System};
+use crate::stub::{call, handle, output, output_string, to_bool, with, Object, State};
+use crate::DspType;
+
+/// Connection between two DSP units, signal goes from input to output.
+pub(crate) struct Connection {
+    pub input: usize,
+    pub output: usize,
+    pub type_: FMOD_DSPCONNECTION_TYPE,
+    pub mix: c_float,
+    pub user_data: usize,
+}
+
+fn inputs(state: &mut State, dsp: usize) -> Vec<usize> {
+    state.handles::<Connection>(|connection| connection.output == dsp)
+}
+
+fn outputs(state: &mut State, dsp: usize) -> Vec<usize> {
+    state.handles::<Connection>(|connection| connection.input == dsp)
+}
+
+/// Removes all connections of DSP unit, used when unit is released.
+pub(crate) fn disconnect_all(state: &mut State, dsp: usize, inputs: bool, outputs: bool) {
+    let connections = state.handles::<Connection>(|connection| {
+        (inputs && connection.output == dsp) || (outputs && connection.input == dsp)
+    });
+    for connection in connections {
+        state.remove(connection);
+    }
+}
+
+pub unsafe fn FMOD_DSP_AddInput(
+    dsp: *mut FMOD_DSP,
+    input: *mut FMOD_DSP,
+    connection: *mut *mut FMOD_DSPCONNECTION,
+    type_: FMOD_DSPCONNECTION_TYPE,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Dsp>(dsp as usize)?;
+        state.get::<Dsp>(input as usize)?;
+        if dsp == input
+            || !(FMOD_DSPCONNECTION_TYPE_STANDARD..FMOD_DSPCONNECTION_TYPE_MAX).contains(&type_)
+        {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        let handle = state.insert(Object::Connection(Connection {
+            input: input as usize,
+            output: dsp as usize,
+            type_,
+            mix: 1.0,
+            user_data: 0,
+        }));
+        output(connection, self::handle(handle));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_DisconnectFrom(
+    dsp: *mut FMOD_DSP,
+    target: *mut FMOD_DSP,
+    connection: *mut FMOD_DSPCONNECTION,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Dsp>(dsp as usize)?;
+        if target.is_null() {
+            disconnect_all(state, dsp as usize, true, true);
+            return Ok(());
+        }
+        let (dsp, target, connection) = (dsp as usize, target as usize, connection as usize);
+        let connections = state.handles::<Connection>(|existing| {
+            (existing.input == dsp && existing.output == target)
+                || (existing.input == target && existing.output == dsp)
+        });
+        for existing in connections {
+            if connection == 0 || connection == existing {
+                state.remove(existing);
+            }
+        }
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_DisconnectAll(
+    dsp: *mut FMOD_DSP,
+    inputs: FMOD_BOOL,
+    outputs: FMOD_BOOL,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Dsp>(dsp as usize)?;
+        disconnect_all(state, dsp as usize, inputs != 0, outputs != 0);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetNumInputs(dsp: *mut FMOD_DSP, numinputs: *mut c_int) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Dsp>(dsp as usize)?;
+        output(numinputs, inputs(state, dsp as usize).len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetNumOutputs(dsp: *mut FMOD_DSP, numoutputs: *mut c_int) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Dsp>(dsp as usize)?;
+        output(numoutputs, outputs(state, dsp as usize).len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetInput(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    input: *mut *mut FMOD_DSP,
+    inputconnection: *mut *mut FMOD_DSPCONNECTION,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Dsp>(dsp as usize)?;
+        let connections = inputs(state, dsp as usize);
+        let found = *connections
+            .get(index as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)?;
+        output(input, handle(state.get::<Connection>(found)?.input));
+        output(inputconnection, handle(found));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetOutput(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    output: *mut *mut FMOD_DSP,
+    outputconnection: *mut *mut FMOD_DSPCONNECTION,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Dsp>(dsp as usize)?;
+        let connections = outputs(state, dsp as usize);
+        let found = *connections
+            .get(index as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)?;
+        self::output(output, handle(state.get::<Connection>(found)?.output));
+        self::output(outputconnection, handle(found));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetInfo(
+    dsp: *mut FMOD_DSP,
+    name: *mut c_char,
+    version: *mut c_uint,
+    channels: *mut c_int,
+    configwidth: *mut c_int,
+    configheight: *mut c_int,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        let label = match DspType::from(dsp.type_) {
+            Ok(type_) => format!("FMOD {:?}", type_),
+            Err(_) => "FMOD DSP".to_string(),
+        };
+        let _ = output_string(name, 32, std::ptr::null_mut(), &label);
+        output(version, 0x00010000);
+        output(channels, 0);
+        output(configwidth, 0);
+        output(configheight, 0);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetIdle(dsp: *mut FMOD_DSP, idle: *mut FMOD_BOOL) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Dsp>(dsp as usize)?;
+        let connected = !inputs(state, dsp as usize).is_empty();
+        output(idle, to_bool(!connected));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_SetMeteringEnabled(
+    dsp: *mut FMOD_DSP,
+    inputEnabled: FMOD_BOOL,
+    outputEnabled: FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        dsp.metering = (inputEnabled != 0, outputEnabled != 0);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetMeteringEnabled(
+    dsp: *mut FMOD_DSP,
+    inputEnabled: *mut FMOD_BOOL,
+    outputEnabled: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        output(inputEnabled, to_bool(dsp.metering.0));
+        output(outputEnabled, to_bool(dsp.metering.1));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_SetChannelFormat(
+    dsp: *mut FMOD_DSP,
+    channelmask: FMOD_CHANNELMASK,
+    numchannels: c_int,
+    source_speakermode: FMOD_SPEAKERMODE,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        if !(0..=FMOD_MAX_CHANNEL_WIDTH as c_int).contains(&numchannels) {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        dsp.channel_format = Some((channelmask, numchannels, source_speakermode));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetChannelFormat(
+    dsp: *mut FMOD_DSP,
+    channelmask: *mut FMOD_CHANNELMASK,
+    numchannels: *mut c_int,
+    source_speakermode: *mut FMOD_SPEAKERMODE,
+) -> FMOD_RESULT {
+    call(|state| {
+        let (mask, channels, mode) = channel_format(state, dsp as usize)?;
+        output(channelmask, mask);
+        output(numchannels, channels);
+        output(source_speakermode, mode);
+        Ok(())
+    })
+}
+
+/// Channel format set to DSP, software format of the system by default.
+fn channel_format(
+    state: &mut State,
+    dsp: usize,
+) -> Result<(FMOD_CHANNELMASK, c_int, FMOD_SPEAKERMODE), FMOD_RESULT> {
+    let (system, format) = {
+        let dsp = state.get::<Dsp>(dsp)?;
+        (dsp.system, dsp.channel_format)
+    };
+    if let Some(format) = format {
+        return Ok(format);
+    }
+    let system = state.get::<System>(system)?;
+    let channels = speaker_mode_channels(system, system.speaker_mode)?;
+    Ok((0, channels, system.speaker_mode))
+}
+
+/// The stub does not mix any signal, so enabled metering reports silent channels
+/// of DSP channel format without analysed samples.
+pub unsafe fn FMOD_DSP_GetMeteringInfo(
+    dsp: *mut FMOD_DSP,
+    inputInfo: *mut FMOD_DSP_METERING_INFO,
+    outputInfo: *mut FMOD_DSP_METERING_INFO,
+) -> FMOD_RESULT {
+    call(|state| {
+        let (input_enabled, output_enabled) = state.get::<Dsp>(dsp as usize)?.metering;
+        let (_, channels, _) = channel_format(state, dsp as usize)?;
+        let silence = FMOD_DSP_METERING_INFO {
+            numchannels: channels as c_short,
+            ..Default::default()
+        };
+        let info = |enabled: bool| {
+            if enabled {
+                silence
+            } else {
+                FMOD_DSP_METERING_INFO::default()
+            }
+        };
+        output(inputInfo, info(input_enabled));
+        output(outputInfo, info(output_enabled));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSPConnection_GetInput(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    input: *mut *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    with(dspconnection, |connection: &mut Connection| {
+        output(input, handle(connection.input));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSPConnection_GetOutput(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    output: *mut *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    with(dspconnection, |connection: &mut Connection| {
+        self::output(output, handle(connection.output));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSPConnection_SetMix(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    volume: c_float,
+) -> FMOD_RESULT {
+    with(dspconnection, |connection: &mut Connection| {
+        connection.mix = volume;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSPConnection_GetMix(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    volume: *mut c_float,
+) -> FMOD_RESULT {
+    with(dspconnection, |connection: &mut Connection| {
+        output(volume, connection.mix);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSPConnection_GetType(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    type_: *mut FMOD_DSPCONNECTION_TYPE,
+) -> FMOD_RESULT {
+    with(dspconnection, |connection: &mut Connection| {
+        output(type_, connection.type_);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSPConnection_SetUserData(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    with(dspconnection, |connection: &mut Connection| {
+        connection.user_data = userdata as usize;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSPConnection_GetUserData(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    with(dspconnection, |connection: &mut Connection| {
+        output(userdata, connection.user_data as *mut c_void);
+        Ok(())
+    })
+}
@@ -0,0 +1,565 @@
+use std::ffi::{c_float, c_int, c_void};
+
+use crate::ffi::*;
+use crate::stub::system::System;
+use crate::stub::{call, handle, input, output, to_bool, with, Object, State};
+
+const MAGIC: &[u8; 8] = b"FMODSTUB";
+
+pub(crate) struct Polygon {
+    pub direct: c_float,
+    pub reverb: c_float,
+    pub double_sided: bool,
+    pub vertices: Vec<FMOD_VECTOR>,
+}
+
+pub(crate) struct Geometry {
+    pub system: usize,
+    pub max_polygons: c_int,
+    pub max_vertices: c_int,
+    pub polygons: Vec<Polygon>,
+    pub active: bool,
+    pub forward: FMOD_VECTOR,
+    pub up: FMOD_VECTOR,
+    pub position: FMOD_VECTOR,
+    pub scale: FMOD_VECTOR,
+    pub user_data: usize,
+}
+
+impl Geometry {
+    fn new(system: usize, max_polygons: c_int, max_vertices: c_int) -> Self {
+        Self {
+            system,
+            max_polygons,
+            max_vertices,
+            polygons: vec![],
+            active: true,
+            forward: FMOD_VECTOR {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            },
+            up: FMOD_VECTOR {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            },
+            position: FMOD_VECTOR::default(),
+            scale: FMOD_VECTOR {
+                x: 1.0,
+                y: 1.0,
+                z: 1.0,
+            },
+            user_data: 0,
+        }
+    }
+
+    fn num_vertices(&self) -> usize {
+        self.polygons
+            .iter()
+            .map(|polygon| polygon.vertices.len())
+            .sum()
+    }
+
+    fn add_polygon(&mut self, polygon: Polygon) -> Result<c_int, FMOD_RESULT> {
+        if polygon.vertices.len() < 3 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        if self.polygons.len() >= self.max_polygons as usize
+            || self.num_vertices() + polygon.vertices.len() > self.max_vertices as usize
+        {
+            return Err(FMOD_ERR_MEMORY);
+        }
+        self.polygons.push(polygon);
+        Ok(self.polygons.len() as c_int - 1)
+    }
+
+    fn polygon(&mut self, index: c_int) -> Result<&mut Polygon, FMOD_RESULT> {
+        self.polygons
+            .get_mut(index as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)
+    }
+
+    /// Vertices of polygon in world space, rotation is built from forward and up vectors.
+    fn world(&self, polygon: &Polygon) -> Vec<FMOD_VECTOR> {
+        let right = cross(self.up, self.forward);
+        polygon
+            .vertices
+            .iter()
+            .map(|vertex| {
+                let local = scale(right, vertex.x * self.scale.x);
+                let local = add(local, scale(self.up, vertex.y * self.scale.y));
+                let local = add(local, scale(self.forward, vertex.z * self.scale.z));
+                add(self.position, local)
+            })
+            .collect()
+    }
+
+    /// The stub uses its own layout of saved geometry, it is not compatible with FMOD.
+    fn save(&self) -> Vec<u8> {
+        let mut data = MAGIC.to_vec();
+        for value in [
+            self.max_polygons,
+            self.max_vertices,
+            self.polygons.len() as c_int,
+        ] {
+            data.extend(value.to_le_bytes());
+        }
+        for polygon in &self.polygons {
+            data.extend(polygon.direct.to_le_bytes());
+            data.extend(polygon.reverb.to_le_bytes());
+            data.push(polygon.double_sided as u8);
+            data.extend((polygon.vertices.len() as c_int).to_le_bytes());
+            for vertex in &polygon.vertices {
+                for value in [vertex.x, vertex.y, vertex.z] {
+                    data.extend(value.to_le_bytes());
+                }
+            }
+        }
+        data
+    }
+
+    fn load(system: usize, data: &[u8]) -> Option<Self> {
+        let mut reader = Reader { data };
+        if reader.take(MAGIC.len())? != MAGIC {
+            return None;
+        }
+        let mut geometry = Geometry::new(system, reader.int()?, reader.int()?);
+        for _ in 0..reader.int()? {
+            let direct = reader.float()?;
+            let reverb = reader.float()?;
+            let double_sided = reader.take(1)?[0] != 0;
+            let mut vertices = vec![];
+            for _ in 0..reader.int()? {
+                vertices.push(FMOD_VECTOR {
+                    x: reader.float()?,
+                    y: reader.float()?,
+                    z: reader.float()?,
+                });
+            }
+            geometry
+                .add_polygon(Polygon {
+                    direct,
+                    reverb,
+                    double_sided,
+                    vertices,
+                })
+                .ok()?;
+        }
+        Some(geometry)
+    }
+}
+
+fn add(a: FMOD_VECTOR, b: FMOD_VECTOR) -> FMOD_VECTOR {
+    FMOD_VECTOR {
+        x: a.x + b.x,
+        y: a.y + b.y,
+        z: a.z + b.z,
+    }
+}
+
+fn sub(a: FMOD_VECTOR, b: FMOD_VECTOR) -> FMOD_VECTOR {
+    FMOD_VECTOR {
+        x: a.x - b.x,
+        y: a.y - b.y,
+        z: a.z - b.z,
+    }
+}
+
+fn scale(a: FMOD_VECTOR, factor: c_float) -> FMOD_VECTOR {
+    FMOD_VECTOR {
+        x: a.x * factor,
+        y: a.y * factor,
+        z: a.z * factor,
+    }
+}
+
+fn dot(a: FMOD_VECTOR, b: FMOD_VECTOR) -> c_float {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
+
+fn cross(a: FMOD_VECTOR, b: FMOD_VECTOR) -> FMOD_VECTOR {
+    FMOD_VECTOR {
+        x: a.y * b.z - a.z * b.y,
+        y: a.z * b.x - a.x * b.z,
+        z: a.x * b.y - a.y * b.x,
+    }
+}
+
+/// Tests segment from listener to source against convex polygon split into triangle fan.
+/// Single sided polygons only block sound coming from their front side (counter-clockwise).
+fn intersects(
+    vertices: &[FMOD_VECTOR],
+    double_sided: bool,
+    from: FMOD_VECTOR,
+    to: FMOD_VECTOR,
+) -> bool {
+    let direction = sub(to, from);
+    let origin = vertices[0];
+    let normal = cross(sub(vertices[1], origin), sub(vertices[2], origin));
+    if !double_sided && dot(normal, direction) > 0.0 {
+        return false;
+    }
+    vertices[1..].windows(2).any(|edge| {
+        let (edge1, edge2) = (sub(edge[0], origin), sub(edge[1], origin));
+        let p = cross(direction, edge2);
+        let determinant = dot(edge1, p);
+        if determinant.abs() < f32::EPSILON {
+            return false;
+        }
+        let t = sub(from, origin);
+        let u = dot(t, p) / determinant;
+        let q = cross(t, edge1);
+        let v = dot(direction, q) / determinant;
+        let distance = dot(edge2, q) / determinant;
+        u >= 0.0 && v >= 0.0 && u + v <= 1.0 && (0.0..=1.0).contains(&distance)
+    })
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Option<&'a [u8]> {
+        if self.data.len() < count {
+            return None;
+        }
+        let (value, rest) = self.data.split_at(count);
+        self.data = rest;
+        Some(value)
+    }
+
+    fn int(&mut self) -> Option<c_int> {
+        Some(c_int::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+
+    fn float(&mut self) -> Option<c_float> {
+        Some(c_float::from_le_bytes(self.take(4)?.try_into().ok()?))
+    }
+}
+
+pub unsafe fn FMOD_System_CreateGeometry(
+    system: *mut FMOD_SYSTEM,
+    maxpolygons: c_int,
+    maxvertices: c_int,
+    geometry: *mut *mut FMOD_GEOMETRY,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<System>(system as usize)?;
+        if maxpolygons <= 0 || maxvertices <= 0 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        let created = Geometry::new(system as usize, maxpolygons, maxvertices);
+        output(geometry, handle(state.insert(Object::Geometry(created))));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_LoadGeometry(
+    system: *mut FMOD_SYSTEM,
+    data: *const c_void,
+    datasize: c_int,
+    geometry: *mut *mut FMOD_GEOMETRY,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<System>(system as usize)?;
+        if data.is_null() || datasize <= 0 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        let bytes = std::slice::from_raw_parts(data as *const u8, datasize as usize);
+        let loaded = Geometry::load(system as usize, bytes).ok_or(FMOD_ERR_FORMAT)?;
+        output(geometry, handle(state.insert(Object::Geometry(loaded))));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetGeometryOcclusion(
+    system: *mut FMOD_SYSTEM,
+    listener: *const FMOD_VECTOR,
+    source: *const FMOD_VECTOR,
+    direct: *mut c_float,
+    reverb: *mut c_float,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<System>(system as usize)?;
+        let (listener, source) = (input(listener), input(source));
+        // every polygon on the way lets through its part of the sound
+        let (mut direct_open, mut reverb_open) = (1.0, 1.0);
+        let geometries = state
+            .handles::<Geometry>(|geometry| geometry.system == system as usize && geometry.active);
+        for geometry in geometries {
+            let geometry = state.get::<Geometry>(geometry)?;
+            for polygon in &geometry.polygons {
+                let vertices = geometry.world(polygon);
+                if intersects(&vertices, polygon.double_sided, listener, source) {
+                    direct_open *= 1.0 - polygon.direct;
+                    reverb_open *= 1.0 - polygon.reverb;
+                }
+            }
+        }
+        output(direct, 1.0 - direct_open);
+        output(reverb, 1.0 - reverb_open);
+        Ok(())
+    })
+}
+
+pub(crate) fn release_geometries(state: &mut State, system: usize) {
+    for geometry in state.handles::<Geometry>(|geometry| geometry.system == system) {
+        state.remove(geometry);
+    }
+}
+
+pub unsafe fn FMOD_Geometry_Release(geometry: *mut FMOD_GEOMETRY) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Geometry>(geometry as usize)?;
+        state.remove(geometry as usize);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_AddPolygon(
+    geometry: *mut FMOD_GEOMETRY,
+    directocclusion: c_float,
+    reverbocclusion: c_float,
+    doublesided: FMOD_BOOL,
+    numvertices: c_int,
+    vertices: *const FMOD_VECTOR,
+    polygonindex: *mut c_int,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        if vertices.is_null() || numvertices < 0 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        let vertices = std::slice::from_raw_parts(vertices, numvertices as usize);
+        let index = geometry.add_polygon(Polygon {
+            direct: directocclusion,
+            reverb: reverbocclusion,
+            double_sided: doublesided != 0,
+            vertices: vertices.to_vec(),
+        })?;
+        output(polygonindex, index);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_GetNumPolygons(
+    geometry: *mut FMOD_GEOMETRY,
+    numpolygons: *mut c_int,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        output(numpolygons, geometry.polygons.len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_GetMaxPolygons(
+    geometry: *mut FMOD_GEOMETRY,
+    maxpolygons: *mut c_int,
+    maxvertices: *mut c_int,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        output(maxpolygons, geometry.max_polygons);
+        output(maxvertices, geometry.max_vertices);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_GetPolygonNumVertices(
+    geometry: *mut FMOD_GEOMETRY,
+    index: c_int,
+    numvertices: *mut c_int,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        output(
+            numvertices,
+            geometry.polygon(index)?.vertices.len() as c_int,
+        );
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_SetPolygonVertex(
+    geometry: *mut FMOD_GEOMETRY,
+    index: c_int,
+    vertexindex: c_int,
+    vertex: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        let polygon = geometry.polygon(index)?;
+        let target = polygon
+            .vertices
+            .get_mut(vertexindex as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)?;
+        *target = input(vertex);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_GetPolygonVertex(
+    geometry: *mut FMOD_GEOMETRY,
+    index: c_int,
+    vertexindex: c_int,
+    vertex: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        let polygon = geometry.polygon(index)?;
+        let value = polygon
+            .vertices
+            .get(vertexindex as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)?;
+        output(vertex, *value);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_SetPolygonAttributes(
+    geometry: *mut FMOD_GEOMETRY,
+    index: c_int,
+    directocclusion: c_float,
+    reverbocclusion: c_float,
+    doublesided: FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        let polygon = geometry.polygon(index)?;
+        polygon.direct = directocclusion;
+        polygon.reverb = reverbocclusion;
+        polygon.double_sided = doublesided != 0;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_GetPolygonAttributes(
+    geometry: *mut FMOD_GEOMETRY,
+    index: c_int,
+    directocclusion: *mut c_float,
+    reverbocclusion: *mut c_float,
+    doublesided: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        let polygon = geometry.polygon(index)?;
+        output(directocclusion, polygon.direct);
+        output(reverbocclusion, polygon.reverb);
+        output(doublesided, to_bool(polygon.double_sided));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_SetActive(
+    geometry: *mut FMOD_GEOMETRY,
+    active: FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        geometry.active = active != 0;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_GetActive(
+    geometry: *mut FMOD_GEOMETRY,
+    active: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        output(active, to_bool(geometry.active));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_SetRotation(
+    geometry: *mut FMOD_GEOMETRY,
+    forward: *const FMOD_VECTOR,
+    up: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        geometry.forward = input(forward);
+        geometry.up = input(up);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_GetRotation(
+    geometry: *mut FMOD_GEOMETRY,
+    forward: *mut FMOD_VECTOR,
+    up: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        output(forward, geometry.forward);
+        output(up, geometry.up);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_SetPosition(
+    geometry: *mut FMOD_GEOMETRY,
+    position: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        geometry.position = input(position);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_GetPosition(
+    geometry: *mut FMOD_GEOMETRY,
+    position: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        output(position, geometry.position);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_SetScale(
+    geometry: *mut FMOD_GEOMETRY,
+    scale: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        geometry.scale = input(scale);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_GetScale(
+    geometry: *mut FMOD_GEOMETRY,
+    scale: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        output(scale, geometry.scale);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_Save(
+    geometry: *mut FMOD_GEOMETRY,
+    data: *mut c_void,
+    datasize: *mut c_int,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        let bytes = geometry.save();
+        output(datasize, bytes.len() as c_int);
+        if !data.is_null() {
+            std::ptr::copy_nonoverlapping(bytes.as_ptr(), data as *mut u8, bytes.len());
+        }
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_SetUserData(
+    geometry: *mut FMOD_GEOMETRY,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        geometry.user_data = userdata as usize;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Geometry_GetUserData(
+    geometry: *mut FMOD_GEOMETRY,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    with(geometry, |geometry: &mut Geometry| {
+        output(userdata, geometry.user_data as *mut c_void);
+        Ok(())
+    })
+}
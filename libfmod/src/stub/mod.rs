@@ -0,0 +1,348 @@
+//! Pure Rust implementation of FMOD C API, enabled by `stub` feature.
+//!
+//! The stub does not produce any sound. It tracks handles in memory, stores values passed
+//! to setters and returns them from getters, advances DSP clock by one mixer block
+//! on every `System::update` and returns the same `FMOD_RESULT` codes as FMOD does
+//! for invalid handles, missing events, double loaded banks, etc. It allows
+//! to build and test code that uses libfmod on machines without FMOD Engine.
+//!
+//! Studio bank files can't be read by the stub, so bank content is described with [`define_bank`]:
+//!
+//! ```ignore
+//! libfmod::stub::define_bank(
+//!     "Master.bank",
+//!     BankDefinition {
+//!         events: vec![EventDefinition {
+//!             path: "event:/Music/Level 01".to_string(),
+//!             length: 5000,
+//!             parameters: vec![ParameterDefinition::new("Intensity", 0.0, 1.0, 0.0)],
+//!             ..Default::default()
+//!         }],
+//!         ..Default::default()
+//!     },
+//! );
+//! ```
+//!
+//! Functions not covered by the stub return `FMOD_ERR_UNSUPPORTED`.
+
+#![allow(non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+#![allow(clippy::too_many_arguments)]
+
+use std::collections::HashMap;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+use crate::ffi::*;
+
+mod channel;
+mod codec;
+mod driver;
+mod dsp;
+mod geometry;
+mod output;
+mod parameter;
+mod record;
+mod reverb;
+mod studio;
+mod system;
+mod unsupported;
+
+pub(crate) mod api {
+    pub use super::channel::*;
+    pub use super::codec::*;
+    pub use super::driver::*;
+    pub use super::dsp::*;
+    pub use super::geometry::*;
+    pub use super::output::*;
+    pub use super::parameter::*;
+    pub use super::record::*;
+    pub use super::reverb::*;
+    pub use super::studio::*;
+    pub use super::system::*;
+    pub use super::unsupported::*;
+}
+
+/// Content of Studio bank file.
+#[derive(Debug, Clone, Default)]
+pub struct BankDefinition {
+    pub events: Vec<EventDefinition>,
+    pub buses: Vec<String>,
+    pub vcas: Vec<String>,
+}
+
+/// Studio event, the path is used to find event by `Studio::get_event`.
+#[derive(Debug, Clone, Default)]
+pub struct EventDefinition {
+    pub path: String,
+    /// Timeline length in milliseconds, event with zero length plays until stopped.
+    pub length: i32,
+    pub is_3d: bool,
+    pub is_oneshot: bool,
+    pub parameters: Vec<ParameterDefinition>,
+}
+
+/// Local parameter of Studio event.
+#[derive(Debug, Clone, Default)]
+pub struct ParameterDefinition {
+    pub name: String,
+    pub minimum: f32,
+    pub maximum: f32,
+    pub default: f32,
+}
+
+impl ParameterDefinition {
+    pub fn new(name: &str, minimum: f32, maximum: f32, default: f32) -> Self {
+        Self {
+            name: name.to_string(),
+            minimum,
+            maximum,
+            default,
+        }
+    }
+}
+
+/// Describes content of bank file with given name (e.g. "Master.bank"),
+/// so events, buses and VCAs become available after bank loading.
+pub fn define_bank(file_name: &str, definition: BankDefinition) {
+    state()
+        .definitions
+        .insert(file_name.to_string(), definition);
+}
+
+/// Output or record device, its GUID is derived from the name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceDefinition {
+    pub name: String,
+    pub system_rate: i32,
+    pub speaker_mode: crate::SpeakerMode,
+}
+
+impl DeviceDefinition {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            system_rate: 48000,
+            speaker_mode: crate::SpeakerMode::Stereo,
+        }
+    }
+}
+
+/// Replaces output and record devices of the system, e.g. to simulate unplugged headphones.
+/// Device change callbacks are invoked on the next `System::update`.
+pub fn define_devices(
+    system: &crate::System,
+    outputs: Vec<DeviceDefinition>,
+    inputs: Vec<DeviceDefinition>,
+) {
+    driver::define(system.as_mut_ptr() as usize, outputs, inputs)
+}
+
+pub(crate) struct State {
+    next_handle: usize,
+    objects: HashMap<usize, Object>,
+    definitions: HashMap<String, BankDefinition>,
+}
+
+pub(crate) enum Object {
+    System(Box<system::System>),
+    Sound(system::Sound),
+    Channel(channel::Channel),
+    ChannelGroup(channel::ChannelGroup),
+    Dsp(channel::Dsp),
+    Connection(dsp::Connection),
+    Geometry(geometry::Geometry),
+    Reverb3d(reverb::Reverb3d),
+    StudioSystem(Box<studio::StudioSystem>),
+    Bank(studio::Bank),
+    EventDescription(studio::EventDescription),
+    EventInstance(studio::EventInstance),
+    Bus(studio::Bus),
+    Vca(studio::Vca),
+}
+
+pub(crate) trait Handle: Sized + 'static {
+    fn cast(object: &mut Object) -> Option<&mut Self>;
+}
+
+macro_rules! impl_handle {
+    ($($variant:ident => $type:ty),*) => {
+        $(
+            impl Handle for $type {
+                fn cast(object: &mut Object) -> Option<&mut Self> {
+                    match object {
+                        Object::$variant(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_handle!(
+    System => system::System,
+    Sound => system::Sound,
+    Channel => channel::Channel,
+    ChannelGroup => channel::ChannelGroup,
+    Dsp => channel::Dsp,
+    Connection => dsp::Connection,
+    Geometry => geometry::Geometry,
+    Reverb3d => reverb::Reverb3d,
+    StudioSystem => studio::StudioSystem,
+    Bank => studio::Bank,
+    EventDescription => studio::EventDescription,
+    EventInstance => studio::EventInstance,
+    Bus => studio::Bus,
+    Vca => studio::Vca
+);
+
+impl State {
+    pub fn insert(&mut self, object: Object) -> usize {
+        self.next_handle += 1;
+        self.objects.insert(self.next_handle, object);
+        self.next_handle
+    }
+
+    pub fn get<T: Handle>(&mut self, handle: usize) -> Result<&mut T, FMOD_RESULT> {
+        self.objects
+            .get_mut(&handle)
+            .and_then(T::cast)
+            .ok_or(FMOD_ERR_INVALID_HANDLE)
+    }
+
+    pub fn contains<T: Handle>(&mut self, handle: usize) -> bool {
+        self.get::<T>(handle).is_ok()
+    }
+
+    pub fn remove(&mut self, handle: usize) -> Option<Object> {
+        self.objects.remove(&handle)
+    }
+
+    pub fn handles<T: Handle>(&mut self, filter: impl Fn(&T) -> bool) -> Vec<usize> {
+        let mut handles: Vec<usize> = self
+            .objects
+            .iter_mut()
+            .filter_map(|(handle, object)| T::cast(object).map(|value| (*handle, value)))
+            .filter(|(_, value)| filter(value))
+            .map(|(handle, _)| handle)
+            .collect();
+        handles.sort();
+        handles
+    }
+
+    pub fn definition(&self, file_name: &str) -> BankDefinition {
+        self.definitions.get(file_name).cloned().unwrap_or_default()
+    }
+}
+
+pub(crate) fn state() -> MutexGuard<'static, State> {
+    static STATE: OnceLock<Mutex<State>> = OnceLock::new();
+    STATE
+        .get_or_init(|| {
+            Mutex::new(State {
+                next_handle: 0,
+                objects: HashMap::new(),
+                definitions: HashMap::new(),
+            })
+        })
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Runs function with exclusive access to stub state and converts its result to FMOD_RESULT.
+pub(crate) fn call<F>(function: F) -> FMOD_RESULT
+where
+    F: FnOnce(&mut State) -> Result<(), FMOD_RESULT>,
+{
+    match function(&mut state()) {
+        Ok(()) => FMOD_OK,
+        Err(error) => error,
+    }
+}
+
+/// Runs function with object of given handle.
+pub(crate) fn with<T, H, F>(handle: *mut H, function: F) -> FMOD_RESULT
+where
+    T: Handle,
+    F: FnOnce(&mut T) -> Result<(), FMOD_RESULT>,
+{
+    call(|state| function(state.get::<T>(handle as usize)?))
+}
+
+pub(crate) fn handle<H>(handle: usize) -> *mut H {
+    handle as *mut H
+}
+
+pub(crate) unsafe fn output<T>(target: *mut T, value: T) {
+    if !target.is_null() {
+        *target = value;
+    }
+}
+
+pub(crate) unsafe fn input<T: Copy + Default>(source: *const T) -> T {
+    if source.is_null() {
+        T::default()
+    } else {
+        *source
+    }
+}
+
+pub(crate) unsafe fn input_string(source: *const c_char) -> Result<String, FMOD_RESULT> {
+    if source.is_null() {
+        return Err(FMOD_ERR_INVALID_PARAM);
+    }
+    CStr::from_ptr(source)
+        .to_str()
+        .map(ToString::to_string)
+        .map_err(|_| FMOD_ERR_INVALID_STRING)
+}
+
+/// Writes string the same way as FMOD does, retrieved length includes null terminator.
+pub(crate) unsafe fn output_string(
+    target: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+    value: &str,
+) -> Result<(), FMOD_RESULT> {
+    let value = CString::new(value).map_err(|_| FMOD_ERR_INVALID_STRING)?;
+    let bytes = value.as_bytes_with_nul();
+    output(retrieved, bytes.len() as c_int);
+    if target.is_null() || size <= 0 {
+        return Ok(());
+    }
+    let count = bytes.len().min(size as usize);
+    std::ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, target, count);
+    if count < bytes.len() {
+        *target.add(count - 1) = 0;
+        return Err(FMOD_ERR_TRUNCATED);
+    }
+    Ok(())
+}
+
+pub(crate) fn guid(path: &str) -> FMOD_GUID {
+    let mut hasher = DefaultHasher::new();
+    path.hash(&mut hasher);
+    let high = hasher.finish();
+    "guid".hash(&mut hasher);
+    let low = hasher.finish();
+    FMOD_GUID {
+        Data1: (high >> 32) as u32,
+        Data2: (high >> 16) as u16,
+        Data3: high as u16,
+        Data4: low.to_be_bytes(),
+    }
+}
+
+pub(crate) fn same_guid(a: &FMOD_GUID, b: &FMOD_GUID) -> bool {
+    a.Data1 == b.Data1 && a.Data2 == b.Data2 && a.Data3 == b.Data3 && a.Data4 == b.Data4
+}
+
+pub(crate) fn to_bool(value: bool) -> FMOD_BOOL {
+    if value {
+        1
+    } else {
+        0
+    }
+}
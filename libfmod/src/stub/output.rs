@@ -0,0 +1,301 @@
+use std::ffi::{c_int, c_uint, c_void};
+use std::fs::File;
+use std::io::{Seek, SeekFrom, Write};
+
+use crate::ffi::*;
+use crate::stub::system::{speaker_mode_channels, System};
+use crate::stub::{output, state, with};
+use crate::wav::wav_header;
+
+/// Callbacks of output plugin, the stub drives them the same way FMOD does
+/// for `FMOD_OUTPUT_METHOD_MIX_DIRECT`: init and start on `System::init`,
+/// update on `System::update` and stop and close when the system is closed.
+/// Unlike FMOD, the plugin can only be selected before `System::init`.
+#[derive(Clone, Copy)]
+pub(crate) struct OutputPlugin {
+    init: FMOD_OUTPUT_INIT_CALLBACK,
+    start: FMOD_OUTPUT_START_CALLBACK,
+    stop: FMOD_OUTPUT_STOP_CALLBACK,
+    close: FMOD_OUTPUT_CLOSE_CALLBACK,
+    update: FMOD_OUTPUT_UPDATE_CALLBACK,
+}
+
+/// State passed to plugin callbacks, plugins only see the first field.
+#[repr(C)]
+struct PluginState {
+    state: FMOD_OUTPUT_STATE,
+    frame_size: usize,
+}
+
+/// The stub produces no sound, so mixer output is always silence.
+unsafe extern "C" fn read_from_mixer(
+    output_state: *mut FMOD_OUTPUT_STATE,
+    buffer: *mut c_void,
+    length: c_uint,
+) -> FMOD_RESULT {
+    if output_state.is_null() || buffer.is_null() {
+        return FMOD_ERR_INVALID_PARAM;
+    }
+    let plugin = output_state as *mut PluginState;
+    std::ptr::write_bytes(buffer as *mut u8, 0, length as usize * (*plugin).frame_size);
+    FMOD_OK
+}
+
+fn sample_size(format: FMOD_SOUND_FORMAT) -> Result<usize, FMOD_RESULT> {
+    match format {
+        FMOD_SOUND_FORMAT_PCM8 => Ok(1),
+        FMOD_SOUND_FORMAT_PCM16 => Ok(2),
+        FMOD_SOUND_FORMAT_PCM24 => Ok(3),
+        FMOD_SOUND_FORMAT_PCM32 | FMOD_SOUND_FORMAT_PCMFLOAT => Ok(4),
+        _ => Err(FMOD_ERR_FORMAT),
+    }
+}
+
+/// Selected plugin and its running state, callbacks are invoked without holding the stub lock.
+fn selected(system: usize) -> Result<Option<(OutputPlugin, usize)>, FMOD_RESULT> {
+    let mut state = state();
+    let system = state.get::<System>(system)?;
+    if system.output == 0 || system.suspended {
+        return Ok(None);
+    }
+    Ok(Some((
+        system.outputs[system.output as usize - 1],
+        system.output_state,
+    )))
+}
+
+pub(crate) unsafe fn start(
+    system: usize,
+    flags: FMOD_INITFLAGS,
+    extradriverdata: *mut c_void,
+) -> FMOD_RESULT {
+    let (plugin, mut rate, mut speaker_mode, mut channels, buffer_length, mut buffer_count) = {
+        let mut state = state();
+        let system = match state.get::<System>(system) {
+            Ok(system) if system.output != 0 => system,
+            Ok(_) => return FMOD_OK,
+            Err(error) => return error,
+        };
+        let channels = match speaker_mode_channels(system, system.speaker_mode) {
+            Ok(channels) => channels,
+            Err(error) => return error,
+        };
+        (
+            system.outputs[system.output as usize - 1],
+            system.sample_rate,
+            system.speaker_mode,
+            channels,
+            system.buffer_length,
+            system.buffer_count,
+        )
+    };
+    let plugin_state = Box::into_raw(Box::new(PluginState {
+        state: FMOD_OUTPUT_STATE {
+            readfrommixer: Some(read_from_mixer),
+            ..Default::default()
+        },
+        frame_size: 0,
+    }));
+    let output_state = plugin_state as *mut FMOD_OUTPUT_STATE;
+    let mut format = FMOD_SOUND_FORMAT_PCMFLOAT;
+    let mut additional_buffers: c_int = 0;
+    let mut result = match plugin.init {
+        Some(init) => init(
+            output_state,
+            0,
+            flags,
+            &mut rate,
+            &mut speaker_mode,
+            &mut channels,
+            &mut format,
+            buffer_length as c_int,
+            &mut buffer_count,
+            &mut additional_buffers,
+            extradriverdata,
+        ),
+        None => FMOD_OK,
+    };
+    if result == FMOD_OK {
+        match sample_size(format) {
+            Ok(size) => (*plugin_state).frame_size = size * channels as usize,
+            Err(error) => result = error,
+        }
+    }
+    if result == FMOD_OK {
+        if let Some(start) = plugin.start {
+            result = start(output_state);
+        }
+    }
+    if result != FMOD_OK {
+        if let Some(close) = plugin.close {
+            close(output_state);
+        }
+        drop(Box::from_raw(plugin_state));
+        return result;
+    }
+    match state().get::<System>(system) {
+        Ok(system) => system.output_state = plugin_state as usize,
+        Err(error) => return error,
+    }
+    FMOD_OK
+}
+
+pub(crate) unsafe fn update(system: usize) -> FMOD_RESULT {
+    match selected(system) {
+        Ok(Some((plugin, output_state))) if output_state != 0 => match plugin.update {
+            Some(update) => update(output_state as *mut FMOD_OUTPUT_STATE),
+            None => FMOD_OK,
+        },
+        Ok(_) => FMOD_OK,
+        Err(error) => error,
+    }
+}
+
+pub(crate) unsafe fn close(system: usize) {
+    let (plugin, output_state) = {
+        let mut state = state();
+        let Ok(system) = state.get::<System>(system) else {
+            return;
+        };
+        if system.output_state == 0 {
+            return;
+        }
+        let output_state = std::mem::take(&mut system.output_state);
+        (system.outputs[system.output as usize - 1], output_state)
+    };
+    let plugin_state = output_state as *mut PluginState;
+    if let Some(stop) = plugin.stop {
+        stop(plugin_state as *mut FMOD_OUTPUT_STATE);
+    }
+    if let Some(close) = plugin.close {
+        close(plugin_state as *mut FMOD_OUTPUT_STATE);
+    }
+    drop(Box::from_raw(plugin_state));
+}
+
+/// Built-in WAV writer output, the file gets 16-bit PCM block on every `System::update`
+/// (for real time and non real time writer alike) and its header is completed on close.
+pub(crate) struct WavWriter {
+    file: File,
+    sample_rate: c_int,
+    channels: c_int,
+    size: u32,
+}
+
+impl WavWriter {
+    pub fn create(path: &str, sample_rate: c_int, channels: c_int) -> Result<Self, FMOD_RESULT> {
+        let mut file = File::create(path).map_err(|_| FMOD_ERR_FILE_NOTFOUND)?;
+        file.write_all(&wav_header(
+            channels as u16,
+            sample_rate as u32,
+            16,
+            false,
+            0,
+        ))
+        .map_err(|_| FMOD_ERR_FILE_BAD)?;
+        Ok(Self {
+            file,
+            sample_rate,
+            channels,
+            size: 0,
+        })
+    }
+
+    /// The stub mixes silence.
+    pub fn write_block(&mut self, length: c_uint) -> Result<(), FMOD_RESULT> {
+        let block = vec![0u8; length as usize * self.channels as usize * 2];
+        self.file.write_all(&block).map_err(|_| FMOD_ERR_FILE_BAD)?;
+        self.size += block.len() as u32;
+        Ok(())
+    }
+
+    pub fn finish(mut self) {
+        let header = wav_header(
+            self.channels as u16,
+            self.sample_rate as u32,
+            16,
+            false,
+            self.size,
+        );
+        let _ = self
+            .file
+            .seek(SeekFrom::Start(0))
+            .and_then(|_| self.file.write_all(&header));
+    }
+}
+
+pub unsafe fn FMOD_System_SetOutput(
+    system: *mut FMOD_SYSTEM,
+    output: FMOD_OUTPUTTYPE,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        if system.initialized {
+            return Err(FMOD_ERR_INITIALIZED);
+        }
+        system.output_type = output;
+        system.output = 0;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetOutput(
+    system: *mut FMOD_SYSTEM,
+    output: *mut FMOD_OUTPUTTYPE,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        self::output(output, system.output_type);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_RegisterOutput(
+    system: *mut FMOD_SYSTEM,
+    description: *const FMOD_OUTPUT_DESCRIPTION,
+    handle: *mut c_uint,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        if description.is_null() {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        let description = &*description;
+        if description.method != FMOD_OUTPUT_METHOD_MIX_DIRECT {
+            return Err(FMOD_ERR_UNSUPPORTED);
+        }
+        system.outputs.push(OutputPlugin {
+            init: description.init,
+            start: description.start,
+            stop: description.stop,
+            close: description.close,
+            update: description.update,
+        });
+        output(handle, system.outputs.len() as c_uint);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_SetOutputByPlugin(
+    system: *mut FMOD_SYSTEM,
+    handle: c_uint,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        if handle == 0 || handle as usize > system.outputs.len() {
+            return Err(FMOD_ERR_PLUGIN);
+        }
+        if system.initialized {
+            return Err(FMOD_ERR_INITIALIZED);
+        }
+        system.output = handle;
+        system.output_type = FMOD_OUTPUTTYPE_UNKNOWN;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetOutputByPlugin(
+    system: *mut FMOD_SYSTEM,
+    handle: *mut c_uint,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        output(handle, system.output);
+        Ok(())
+    })
+}
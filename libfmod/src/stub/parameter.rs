@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+use std::ffi::{c_char, c_float, c_int, CString};
+use std::sync::{Mutex, OnceLock};
+
+use crate::ffi::*;
+use crate::stub::channel::Dsp;
+use crate::stub::{output, with};
+
+/// Range and default value of built-in DSP parameter.
+#[derive(Clone, Copy)]
+pub(crate) enum Kind {
+    Float(c_float, c_float, c_float),
+    Int(c_int, c_int, c_int, &'static [&'static str]),
+    Bool(bool),
+    Data(FMOD_DSP_PARAMETER_DATA_TYPE),
+}
+
+pub(crate) struct Parameter {
+    pub name: &'static str,
+    pub label: &'static str,
+    pub description: &'static str,
+    pub kind: Kind,
+}
+
+const fn float(
+    name: &'static str,
+    label: &'static str,
+    range: (f32, f32),
+    default: f32,
+) -> Parameter {
+    Parameter {
+        name,
+        label,
+        description: name,
+        kind: Kind::Float(range.0, range.1, default),
+    }
+}
+
+const fn int(
+    name: &'static str,
+    range: (c_int, c_int),
+    default: c_int,
+    names: &'static [&'static str],
+) -> Parameter {
+    Parameter {
+        name,
+        label: "",
+        description: name,
+        kind: Kind::Int(range.0, range.1, default, names),
+    }
+}
+
+const fn boolean(name: &'static str, default: bool) -> Parameter {
+    Parameter {
+        name,
+        label: "",
+        description: name,
+        kind: Kind::Bool(default),
+    }
+}
+
+const fn data(name: &'static str, datatype: FMOD_DSP_PARAMETER_DATA_TYPE) -> Parameter {
+    Parameter {
+        name,
+        label: "",
+        description: name,
+        kind: Kind::Data(datatype),
+    }
+}
+
+const ROLLOFF: &[&str] = &[
+    "Linear Squared",
+    "Linear",
+    "Inverse",
+    "Inverse Tapered",
+    "Custom",
+];
+const EXTENT_MODE: &[&str] = &["Auto", "User", "Off"];
+const DISTANCE: (f32, f32) = (0.0, 10000.0);
+
+const FFT: &[Parameter] = &[
+    int("Window Size", (128, 16384), 2048, &[]),
+    int(
+        "Window Type",
+        (0, 5),
+        FMOD_DSP_FFT_WINDOW_HAMMING,
+        &[
+            "Rect",
+            "Triangle",
+            "Hamming",
+            "Hanning",
+            "Blackman",
+            "Blackman-Harris",
+        ],
+    ),
+    data("Spectrum Data", FMOD_DSP_PARAMETER_DATA_TYPE_FFT),
+    float("Dominant Freq", "Hz", (0.0, 22000.0), 0.0),
+];
+
+const LOUDNESS_METER: &[Parameter] = &[
+    int(
+        "State",
+        (-3, 1),
+        FMOD_DSP_LOUDNESS_METER_STATE_PAUSED,
+        &[
+            "Reset Integrated",
+            "Reset Max Peak",
+            "Reset All",
+            "Paused",
+            "Analyzing",
+        ],
+    ),
+    data("Weighting", FMOD_DSP_PARAMETER_DATA_TYPE_USER),
+    data("Info", FMOD_DSP_PARAMETER_DATA_TYPE_USER),
+];
+
+const THREE_EQ: &[Parameter] = &[
+    float("Low Gain", "dB", (-80.0, 10.0), 0.0),
+    float("Mid Gain", "dB", (-80.0, 10.0), 0.0),
+    float("High Gain", "dB", (-80.0, 10.0), 0.0),
+    float("Low Crossover", "Hz", (10.0, 22000.0), 400.0),
+    float("High Crossover", "Hz", (10.0, 22000.0), 4000.0),
+    int("Crossover Slope", (0, 2), 1, &["12dB", "24dB", "48dB"]),
+];
+
+const COMPRESSOR: &[Parameter] = &[
+    float("Threshold", "dB", (-60.0, 0.0), 0.0),
+    float("Ratio", "", (1.0, 50.0), 2.5),
+    float("Attack", "ms", (0.1, 500.0), 20.0),
+    float("Release", "ms", (10.0, 5000.0), 100.0),
+    float("Make Up Gain", "dB", (-30.0, 30.0), 0.0),
+    data("Use Sidechain", FMOD_DSP_PARAMETER_DATA_TYPE_SIDECHAIN),
+    boolean("Linked", true),
+];
+
+const PAN: &[Parameter] = &[
+    int(
+        "Mode",
+        (0, 2),
+        FMOD_DSP_PAN_MODE_SURROUND,
+        &["Mono", "Stereo", "Surround"],
+    ),
+    float("2D Stereo Pos", "", (-100.0, 100.0), 0.0),
+    float("2D Direction", "Degrees", (-180.0, 180.0), 0.0),
+    float("2D Extent", "Degrees", (0.0, 360.0), 360.0),
+    float("2D Rotation", "Degrees", (-180.0, 180.0), 0.0),
+    float("2D LFE Level", "dB", (-80.0, 20.0), 0.0),
+    int("2D Stereo Mode", (0, 1), 1, &["Distributed", "Discrete"]),
+    float("2D Stereo Sep", "Degrees", (-180.0, 180.0), 60.0),
+    float("2D Stereo Axis", "Degrees", (-180.0, 180.0), 0.0),
+    int("Enabled Speakers", (0, 4095), 4095, &[]),
+    data("3D Position", FMOD_DSP_PARAMETER_DATA_TYPE_3DATTRIBUTES),
+    int("3D Rolloff", (0, 4), 0, ROLLOFF),
+    float("3D Min Distance", "", DISTANCE, 1.0),
+    float("3D Max Distance", "", DISTANCE, 20.0),
+    int("3D Extent Mode", (0, 2), 0, EXTENT_MODE),
+    float("3D Sound Size", "", DISTANCE, 0.0),
+    float("3D Min Extent", "Degrees", (0.0, 360.0), 0.0),
+    float("3D Pan Blend", "", (0.0, 1.0), 0.0),
+    int("LFE Upmix", (0, 1), 0, &[]),
+    data("Overall Gain", FMOD_DSP_PARAMETER_DATA_TYPE_OVERALLGAIN),
+    int("Speaker Mode", (0, 9), 0, &[]),
+    float("2D Height Blend", "", (-1.0, 1.0), 0.0),
+    data(
+        "Attenuation",
+        FMOD_DSP_PARAMETER_DATA_TYPE_ATTENUATION_RANGE,
+    ),
+    boolean("Override Range", true),
+];
+
+const OBJECTPAN: &[Parameter] = &[
+    data(
+        "3D Position",
+        FMOD_DSP_PARAMETER_DATA_TYPE_3DATTRIBUTES_MULTI,
+    ),
+    int("3D Rolloff", (0, 4), 0, ROLLOFF),
+    float("3D Min Distance", "", DISTANCE, 1.0),
+    float("3D Max Distance", "", DISTANCE, 20.0),
+    int("3D Extent Mode", (0, 2), 0, EXTENT_MODE),
+    float("3D Sound Size", "", DISTANCE, 0.0),
+    float("3D Min Extent", "Degrees", (0.0, 360.0), 0.0),
+    data("Overall Gain", FMOD_DSP_PARAMETER_DATA_TYPE_OVERALLGAIN),
+    float("Output Gain", "", (0.0, 1.0), 1.0),
+    data(
+        "Attenuation",
+        FMOD_DSP_PARAMETER_DATA_TYPE_ATTENUATION_RANGE,
+    ),
+    boolean("Override Range", true),
+];
+
+/// Parameters of built-in DSP, the stub describes only types used by library helpers,
+/// other types have no parameters.
+pub(crate) fn parameters(type_: FMOD_DSP_TYPE) -> &'static [Parameter] {
+    match type_ {
+        FMOD_DSP_TYPE_FFT => FFT,
+        FMOD_DSP_TYPE_LOUDNESS_METER => LOUDNESS_METER,
+        FMOD_DSP_TYPE_THREE_EQ => THREE_EQ,
+        FMOD_DSP_TYPE_COMPRESSOR => COMPRESSOR,
+        FMOD_DSP_TYPE_PAN => PAN,
+        FMOD_DSP_TYPE_OBJECTPAN => OBJECTPAN,
+        _ => &[],
+    }
+}
+
+pub(crate) fn data_type(
+    type_: FMOD_DSP_TYPE,
+    index: c_int,
+) -> Option<FMOD_DSP_PARAMETER_DATA_TYPE> {
+    match parameters(type_).get(index as usize)?.kind {
+        Kind::Data(datatype) => Some(datatype),
+        _ => None,
+    }
+}
+
+fn text<const N: usize>(value: &str) -> [c_char; N] {
+    let mut text = [0; N];
+    for (target, byte) in text.iter_mut().zip(value.bytes().take(N - 1)) {
+        *target = byte as c_char;
+    }
+    text
+}
+
+fn leak_string(value: &str) -> *const c_char {
+    CString::new(value).unwrap_or_default().into_raw()
+}
+
+fn leak_names(names: &[&str]) -> *const *const c_char {
+    if names.is_empty() {
+        return std::ptr::null();
+    }
+    let names: Vec<*const c_char> = names.iter().map(|name| leak_string(name)).collect();
+    Box::leak(names.into_boxed_slice()).as_ptr()
+}
+
+fn describe(parameter: &Parameter) -> FMOD_DSP_PARAMETER_DESC {
+    let (type_, union) = match parameter.kind {
+        Kind::Float(min, max, defaultval) => (
+            FMOD_DSP_PARAMETER_TYPE_FLOAT,
+            FMOD_DSP_PARAMETER_DESC_UNION {
+                floatdesc: FMOD_DSP_PARAMETER_DESC_FLOAT {
+                    min,
+                    max,
+                    defaultval,
+                    mapping: FMOD_DSP_PARAMETER_FLOAT_MAPPING {
+                        type_: FMOD_DSP_PARAMETER_FLOAT_MAPPING_TYPE_AUTO,
+                        ..Default::default()
+                    },
+                },
+            },
+        ),
+        Kind::Int(min, max, defaultval, names) => (
+            FMOD_DSP_PARAMETER_TYPE_INT,
+            FMOD_DSP_PARAMETER_DESC_UNION {
+                intdesc: FMOD_DSP_PARAMETER_DESC_INT {
+                    min,
+                    max,
+                    defaultval,
+                    goestoinf: 0,
+                    valuenames: leak_names(names),
+                },
+            },
+        ),
+        Kind::Bool(defaultval) => (
+            FMOD_DSP_PARAMETER_TYPE_BOOL,
+            FMOD_DSP_PARAMETER_DESC_UNION {
+                booldesc: FMOD_DSP_PARAMETER_DESC_BOOL {
+                    defaultval: defaultval as FMOD_BOOL,
+                    valuenames: std::ptr::null(),
+                },
+            },
+        ),
+        Kind::Data(datatype) => (
+            FMOD_DSP_PARAMETER_TYPE_DATA,
+            FMOD_DSP_PARAMETER_DESC_UNION {
+                datadesc: FMOD_DSP_PARAMETER_DESC_DATA { datatype },
+            },
+        ),
+    };
+    FMOD_DSP_PARAMETER_DESC {
+        type_,
+        name: text(parameter.name),
+        label: text(parameter.label),
+        description: leak_string(parameter.description),
+        union,
+    }
+}
+
+/// FMOD returns pointers to static descriptions, so they are built once per DSP type.
+fn description(type_: FMOD_DSP_TYPE, index: c_int) -> Option<*mut FMOD_DSP_PARAMETER_DESC> {
+    static DESCRIPTIONS: OnceLock<Mutex<HashMap<FMOD_DSP_TYPE, usize>>> = OnceLock::new();
+    let parameters = parameters(type_);
+    let index = usize::try_from(index)
+        .ok()
+        .filter(|&index| index < parameters.len())?;
+    let mut descriptions = DESCRIPTIONS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    let table = *descriptions.entry(type_).or_insert_with(|| {
+        let table: Vec<FMOD_DSP_PARAMETER_DESC> = parameters.iter().map(describe).collect();
+        Box::leak(table.into_boxed_slice()).as_mut_ptr() as usize
+    });
+    Some(unsafe { (table as *mut FMOD_DSP_PARAMETER_DESC).add(index) })
+}
+
+pub unsafe fn FMOD_DSP_GetNumParameters(dsp: *mut FMOD_DSP, numparams: *mut c_int) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        output(numparams, parameters(dsp.type_).len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetParameterInfo(
+    dsp: *mut FMOD_DSP,
+    index: c_int,
+    desc: *mut *mut FMOD_DSP_PARAMETER_DESC,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        let description = description(dsp.type_, index).ok_or(FMOD_ERR_INVALID_PARAM)?;
+        output(desc, description);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_DSP_GetDataParameterIndex(
+    dsp: *mut FMOD_DSP,
+    datatype: c_int,
+    index: *mut c_int,
+) -> FMOD_RESULT {
+    with(dsp, |dsp: &mut Dsp| {
+        let position = parameters(dsp.type_)
+            .iter()
+            .position(|parameter| matches!(parameter.kind, Kind::Data(value) if value == datatype))
+            .ok_or(FMOD_ERR_INVALID_PARAM)?;
+        output(index, position as c_int);
+        Ok(())
+    })
+}
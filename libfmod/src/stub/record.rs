@@ -0,0 +1,177 @@
+use std::ffi::{c_int, c_uint, c_void};
+
+use crate::ffi::*;
+use crate::stub::system::{Sound, System};
+use crate::stub::{call, output, to_bool, with, State};
+
+/// Capture of record driver into sound. The stub device produces sawtooth:
+/// sample of frame `n` is `(n % rate) / rate` on all channels, where rate is device system rate.
+#[derive(Clone, Copy)]
+pub(crate) struct Recording {
+    driver: c_int,
+    sound: usize,
+    looping: bool,
+    rate: u64,
+    start_clock: u64,
+    captured: u64,
+    position: c_uint,
+}
+
+/// Writes frames captured during the last mixer block to recording sounds.
+pub(crate) fn record(state: &mut State, system: usize) -> Result<(), FMOD_RESULT> {
+    let system_state = state.get::<System>(system)?;
+    let clock = system_state.clock;
+    let sample_rate = system_state.sample_rate as u64;
+    let recordings = std::mem::take(&mut system_state.recordings);
+    let mut active = vec![];
+    for mut recording in recordings {
+        let Ok(sound) = state.get::<Sound>(recording.sound) else {
+            // sound is released, so recording is stopped
+            continue;
+        };
+        let target = (clock - recording.start_clock) * recording.rate / sample_rate;
+        let frame_size = sound.bytes_per_sample() as usize;
+        let mut stopped = false;
+        while recording.captured < target && !stopped {
+            let value = (recording.captured % recording.rate) as f32 / recording.rate as f32;
+            let offset = recording.position as usize * frame_size;
+            if let Some(frame) = sound.data.get_mut(offset..offset + frame_size) {
+                write_frame(frame, sound.format, sound.bits, value);
+            }
+            recording.captured += 1;
+            recording.position += 1;
+            if recording.position >= sound.length {
+                recording.position = 0;
+                stopped = !recording.looping;
+            }
+        }
+        if !stopped {
+            active.push(recording);
+        }
+    }
+    state.get::<System>(system)?.recordings = active;
+    Ok(())
+}
+
+fn write_frame(frame: &mut [u8], format: FMOD_SOUND_FORMAT, bits: c_int, value: f32) {
+    let sample = match format {
+        FMOD_SOUND_FORMAT_PCMFLOAT => value.to_le_bytes().to_vec(),
+        FMOD_SOUND_FORMAT_PCM8 => vec![(value * 127.0 + 128.0) as u8],
+        _ => ((value * 2147483647.0) as i32).to_le_bytes()[(4 - bits as usize / 8)..].to_vec(),
+    };
+    for chunk in frame.chunks_exact_mut(sample.len()) {
+        chunk.copy_from_slice(&sample);
+    }
+}
+
+pub unsafe fn FMOD_System_RecordStart(
+    system: *mut FMOD_SYSTEM,
+    id: c_int,
+    sound: *mut FMOD_SOUND,
+    loop_: FMOD_BOOL,
+) -> FMOD_RESULT {
+    call(|state| {
+        let (length, has_data) = {
+            let sound = state.get::<Sound>(sound as usize)?;
+            (sound.length, !sound.data.is_empty())
+        };
+        let system = state.get::<System>(system as usize)?;
+        if !system.initialized {
+            return Err(FMOD_ERR_UNINITIALIZED);
+        }
+        let device = system
+            .record_drivers
+            .get(id as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)?;
+        // only user created sounds have buffer to record into
+        if length == 0 || !has_data {
+            return Err(FMOD_ERR_FORMAT);
+        }
+        let recording = Recording {
+            driver: id,
+            sound: sound as usize,
+            looping: loop_ != 0,
+            rate: device.system_rate.max(1) as u64,
+            start_clock: system.clock,
+            captured: 0,
+            position: 0,
+        };
+        system.recordings.retain(|recording| recording.driver != id);
+        system.recordings.push(recording);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_RecordStop(system: *mut FMOD_SYSTEM, id: c_int) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        system.recordings.retain(|recording| recording.driver != id);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_IsRecording(
+    system: *mut FMOD_SYSTEM,
+    id: c_int,
+    recording: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        let active = system.recordings.iter().any(|value| value.driver == id);
+        output(recording, to_bool(active));
+        Ok(())
+    })
+}
+
+/// Position is in PCM samples of the recording sound, 0 if driver is not recording.
+pub unsafe fn FMOD_System_GetRecordPosition(
+    system: *mut FMOD_SYSTEM,
+    id: c_int,
+    position: *mut c_uint,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        let recording = system.recordings.iter().find(|value| value.driver == id);
+        output(position, recording.map_or(0, |value| value.position));
+        Ok(())
+    })
+}
+
+/// Gives direct access to sound data, locked range wraps to the start of the sound.
+pub unsafe fn FMOD_Sound_Lock(
+    sound: *mut FMOD_SOUND,
+    offset: c_uint,
+    length: c_uint,
+    ptr1: *mut *mut c_void,
+    ptr2: *mut *mut c_void,
+    len1: *mut c_uint,
+    len2: *mut c_uint,
+) -> FMOD_RESULT {
+    with(sound, |sound: &mut Sound| {
+        let size = sound.data.len();
+        let offset = offset as usize;
+        if offset >= size || length as usize > size {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        let first = (length as usize).min(size - offset);
+        let second = length as usize - first;
+        let data = sound.data.as_mut_ptr();
+        output(ptr1, data.add(offset) as *mut c_void);
+        output(len1, first as c_uint);
+        let second_ptr = if second > 0 {
+            data
+        } else {
+            std::ptr::null_mut()
+        };
+        output(ptr2, second_ptr as *mut c_void);
+        output(len2, second as c_uint);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Sound_Unlock(
+    sound: *mut FMOD_SOUND,
+    _ptr1: *mut c_void,
+    _ptr2: *mut c_void,
+    _len1: c_uint,
+    _len2: c_uint,
+) -> FMOD_RESULT {
+    with(sound, |_: &mut Sound| Ok(()))
+}
@@ -0,0 +1,178 @@
+use std::ffi::{c_float, c_int, c_void};
+
+use crate::ffi::*;
+use crate::stub::system::System;
+use crate::stub::{call, handle, input, output, to_bool, with, Object, State};
+
+pub(crate) struct Reverb3d {
+    pub system: usize,
+    pub position: FMOD_VECTOR,
+    pub min_distance: c_float,
+    pub max_distance: c_float,
+    pub properties: FMOD_REVERB_PROPERTIES,
+    pub active: bool,
+    pub user_data: usize,
+}
+
+pub unsafe fn FMOD_System_CreateReverb3D(
+    system: *mut FMOD_SYSTEM,
+    reverb: *mut *mut FMOD_REVERB3D,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<System>(system as usize)?;
+        let created = Reverb3d {
+            system: system as usize,
+            position: FMOD_VECTOR::default(),
+            min_distance: 0.0,
+            max_distance: 0.0,
+            properties: FMOD_PRESET_OFF,
+            active: true,
+            user_data: 0,
+        };
+        output(reverb, handle(state.insert(Object::Reverb3d(created))));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_SetReverbProperties(
+    system: *mut FMOD_SYSTEM,
+    instance: c_int,
+    prop: *const FMOD_REVERB_PROPERTIES,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        let target = system
+            .reverb
+            .get_mut(instance as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)?;
+        // FMOD turns reverb instance off when properties are not given
+        *target = if prop.is_null() {
+            FMOD_PRESET_OFF
+        } else {
+            *prop
+        };
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetReverbProperties(
+    system: *mut FMOD_SYSTEM,
+    instance: c_int,
+    prop: *mut FMOD_REVERB_PROPERTIES,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        let value = system
+            .reverb
+            .get(instance as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)?;
+        output(prop, *value);
+        Ok(())
+    })
+}
+
+pub(crate) fn release_reverbs(state: &mut State, system: usize) {
+    for reverb in state.handles::<Reverb3d>(|reverb| reverb.system == system) {
+        state.remove(reverb);
+    }
+}
+
+pub unsafe fn FMOD_Reverb3D_Release(reverb3d: *mut FMOD_REVERB3D) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Reverb3d>(reverb3d as usize)?;
+        state.remove(reverb3d as usize);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Reverb3D_Set3DAttributes(
+    reverb3d: *mut FMOD_REVERB3D,
+    position: *const FMOD_VECTOR,
+    mindistance: c_float,
+    maxdistance: c_float,
+) -> FMOD_RESULT {
+    with(reverb3d, |reverb: &mut Reverb3d| {
+        if mindistance < 0.0 || maxdistance < mindistance {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        reverb.position = input(position);
+        reverb.min_distance = mindistance;
+        reverb.max_distance = maxdistance;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Reverb3D_Get3DAttributes(
+    reverb3d: *mut FMOD_REVERB3D,
+    position: *mut FMOD_VECTOR,
+    mindistance: *mut c_float,
+    maxdistance: *mut c_float,
+) -> FMOD_RESULT {
+    with(reverb3d, |reverb: &mut Reverb3d| {
+        output(position, reverb.position);
+        output(mindistance, reverb.min_distance);
+        output(maxdistance, reverb.max_distance);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Reverb3D_SetProperties(
+    reverb3d: *mut FMOD_REVERB3D,
+    properties: *const FMOD_REVERB_PROPERTIES,
+) -> FMOD_RESULT {
+    with(reverb3d, |reverb: &mut Reverb3d| {
+        if properties.is_null() {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        reverb.properties = *properties;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Reverb3D_GetProperties(
+    reverb3d: *mut FMOD_REVERB3D,
+    properties: *mut FMOD_REVERB_PROPERTIES,
+) -> FMOD_RESULT {
+    with(reverb3d, |reverb: &mut Reverb3d| {
+        output(properties, reverb.properties);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Reverb3D_SetActive(
+    reverb3d: *mut FMOD_REVERB3D,
+    active: FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(reverb3d, |reverb: &mut Reverb3d| {
+        reverb.active = active != 0;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Reverb3D_GetActive(
+    reverb3d: *mut FMOD_REVERB3D,
+    active: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(reverb3d, |reverb: &mut Reverb3d| {
+        output(active, to_bool(reverb.active));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Reverb3D_SetUserData(
+    reverb3d: *mut FMOD_REVERB3D,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    with(reverb3d, |reverb: &mut Reverb3d| {
+        reverb.user_data = userdata as usize;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Reverb3D_GetUserData(
+    reverb3d: *mut FMOD_REVERB3D,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    with(reverb3d, |reverb: &mut Reverb3d| {
+        output(userdata, reverb.user_data as *mut c_void);
+        Ok(())
+    })
+}
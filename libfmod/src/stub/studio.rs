@@ -0,0 +1,1715 @@
+use std::collections::HashMap;
+use std::ffi::{c_char, c_float, c_int, c_uint, c_void, CString};
+use std::path::Path;
+
+use crate::ffi::*;
+use crate::stub::channel;
+use crate::stub::system::{self, System};
+use crate::stub::{
+    call, guid, handle, input_string, output, output_string, same_guid, to_bool, with,
+    EventDefinition, Object, State,
+};
+
+pub(crate) struct StudioSystem {
+    pub core: usize,
+    pub initialized: bool,
+    pub listeners: c_int,
+    pub listener_attributes: [(FMOD_3D_ATTRIBUTES, FMOD_VECTOR); 8],
+    pub parameters: HashMap<String, c_float>,
+    pub master_bus: usize,
+    pub user_data: usize,
+}
+
+pub(crate) struct Bank {
+    pub system: usize,
+    pub file_name: String,
+    pub path: String,
+    pub user_data: usize,
+}
+
+pub(crate) struct EventDescription {
+    pub system: usize,
+    pub bank: usize,
+    pub definition: EventDefinition,
+    /// Parameter names with null terminator, pointers to them are passed to parameter descriptions.
+    pub names: Vec<CString>,
+    pub user_data: usize,
+}
+
+impl EventDescription {
+    fn parameter_description(
+        &self,
+        index: usize,
+    ) -> Result<FMOD_STUDIO_PARAMETER_DESCRIPTION, FMOD_RESULT> {
+        let parameter = self
+            .definition
+            .parameters
+            .get(index)
+            .ok_or(FMOD_ERR_INVALID_PARAM)?;
+        Ok(FMOD_STUDIO_PARAMETER_DESCRIPTION {
+            name: self.names[index].as_ptr(),
+            id: parameter_id(&self.definition.path, index),
+            minimum: parameter.minimum,
+            maximum: parameter.maximum,
+            defaultvalue: parameter.default,
+            type_: FMOD_STUDIO_PARAMETER_GAME_CONTROLLED,
+            flags: 0,
+            guid: guid(&format!("{}/{}", self.definition.path, parameter.name)),
+        })
+    }
+
+    fn parameter_index(&self, name: &str) -> Result<usize, FMOD_RESULT> {
+        self.definition
+            .parameters
+            .iter()
+            .position(|parameter| parameter.name.eq_ignore_ascii_case(name))
+            .ok_or(FMOD_ERR_EVENT_NOTFOUND)
+    }
+
+    fn parameter_index_by_id(&self, id: FMOD_STUDIO_PARAMETER_ID) -> Result<usize, FMOD_RESULT> {
+        (0..self.definition.parameters.len())
+            .find(|index| {
+                let expected = parameter_id(&self.definition.path, *index);
+                expected.data1 == id.data1 && expected.data2 == id.data2
+            })
+            .ok_or(FMOD_ERR_EVENT_NOTFOUND)
+    }
+}
+
+fn parameter_id(path: &str, index: usize) -> FMOD_STUDIO_PARAMETER_ID {
+    let guid = guid(path);
+    FMOD_STUDIO_PARAMETER_ID {
+        data1: guid.Data1,
+        data2: index as c_uint,
+    }
+}
+
+pub(crate) struct EventInstance {
+    pub system: usize,
+    pub description: usize,
+    pub state: FMOD_STUDIO_PLAYBACK_STATE,
+    pub position: f64,
+    pub length: c_int,
+    pub is_oneshot: bool,
+    pub paused: bool,
+    pub released: bool,
+    pub volume: c_float,
+    pub pitch: c_float,
+    pub attributes: FMOD_3D_ATTRIBUTES,
+    pub listener_mask: c_uint,
+    pub properties: HashMap<FMOD_STUDIO_EVENT_PROPERTY, c_float>,
+    pub reverb: [c_float; 4],
+    pub parameters: Vec<c_float>,
+    pub user_data: usize,
+}
+
+pub(crate) struct Bus {
+    pub system: usize,
+    pub bank: usize,
+    pub path: String,
+    pub volume: c_float,
+    pub paused: bool,
+    pub mute: bool,
+    pub locks: c_int,
+    pub group: usize,
+}
+
+pub(crate) struct Vca {
+    pub system: usize,
+    pub bank: usize,
+    pub path: String,
+    pub volume: c_float,
+}
+
+fn initialized(state: &mut State, system: usize) -> Result<&mut StudioSystem, FMOD_RESULT> {
+    let studio = state.get::<StudioSystem>(system)?;
+    if !studio.initialized {
+        return Err(FMOD_ERR_UNINITIALIZED);
+    }
+    Ok(studio)
+}
+
+fn parse_guid(text: &str) -> Option<FMOD_GUID> {
+    let text = text.strip_prefix('{')?.strip_suffix('}')?;
+    let parts: Vec<&str> = text.split('-').collect();
+    if parts.len() != 5 || parts[3].len() != 4 || parts[4].len() != 12 {
+        return None;
+    }
+    let mut data4 = [0u8; 8];
+    let tail = format!("{}{}", parts[3], parts[4]);
+    for (index, byte) in data4.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&tail[index * 2..index * 2 + 2], 16).ok()?;
+    }
+    Some(FMOD_GUID {
+        Data1: u32::from_str_radix(parts[0], 16).ok()?,
+        Data2: u16::from_str_radix(parts[1], 16).ok()?,
+        Data3: u16::from_str_radix(parts[2], 16).ok()?,
+        Data4: data4,
+    })
+}
+
+/// Finds object of loaded bank by path or by ID in "{xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx}" format.
+fn find<T: crate::stub::Handle>(
+    state: &mut State,
+    path_or_id: &str,
+    filter: impl Fn(&T) -> bool,
+    path: impl Fn(&T) -> String,
+) -> Result<usize, FMOD_RESULT> {
+    let id = parse_guid(path_or_id);
+    state
+        .handles::<T>(|object| {
+            let object_path = path(object);
+            let matches = match &id {
+                Some(id) => same_guid(id, &guid(&object_path)),
+                None => object_path.eq_ignore_ascii_case(path_or_id),
+            };
+            matches && filter(object)
+        })
+        .first()
+        .copied()
+        .ok_or(FMOD_ERR_EVENT_NOTFOUND)
+}
+
+unsafe fn output_list<T>(
+    handles: Vec<usize>,
+    array: *mut *mut T,
+    capacity: c_int,
+    count: *mut c_int,
+) -> Result<(), FMOD_RESULT> {
+    let written = handles.len().min(capacity.max(0) as usize);
+    if !array.is_null() {
+        for (index, value) in handles.iter().take(written).enumerate() {
+            *array.add(index) = handle(*value);
+        }
+    }
+    output(count, written as c_int);
+    Ok(())
+}
+
+fn unload_bank(state: &mut State, bank: usize) {
+    let descriptions = state.handles::<EventDescription>(|description| description.bank == bank);
+    for instance in
+        state.handles::<EventInstance>(|instance| descriptions.contains(&instance.description))
+    {
+        state.remove(instance);
+    }
+    for handle in descriptions {
+        state.remove(handle);
+    }
+    for handle in state.handles::<Bus>(|bus| bus.bank == bank) {
+        if let Some(Object::Bus(bus)) = state.remove(handle) {
+            if bus.group != 0 {
+                channel::release_group(state, bus.group);
+            }
+        }
+    }
+    for handle in state.handles::<Vca>(|vca| vca.bank == bank) {
+        state.remove(handle);
+    }
+    state.remove(bank);
+}
+
+/// Creates channel groups of locked buses, as FMOD does while executing commands.
+fn create_bus_groups(state: &mut State, system: usize) -> Result<(), FMOD_RESULT> {
+    let core = initialized(state, system)?.core;
+    let master = state.get::<System>(core)?.master;
+    for handle in
+        state.handles::<Bus>(|bus| bus.system == system && bus.locks > 0 && bus.group == 0)
+    {
+        let path = state.get::<Bus>(handle)?.path.clone();
+        let group = channel::create_group(state, core, &path, master);
+        state.get::<Bus>(handle)?.group = group;
+    }
+    Ok(())
+}
+
+fn update(state: &mut State, system: usize) -> Result<(), FMOD_RESULT> {
+    let core = initialized(state, system)?.core;
+    create_bus_groups(state, system)?;
+    system::update(state, core)?;
+    let core = state.get::<System>(core)?;
+    if core.suspended {
+        return Ok(());
+    }
+    let milliseconds = core.buffer_length as f64 * 1000.0 / core.sample_rate as f64;
+    for handle in state.handles::<EventInstance>(|instance| instance.system == system) {
+        let instance = state.get::<EventInstance>(handle)?;
+        match instance.state {
+            FMOD_STUDIO_PLAYBACK_STARTING => instance.state = FMOD_STUDIO_PLAYBACK_PLAYING,
+            FMOD_STUDIO_PLAYBACK_STOPPING => instance.state = FMOD_STUDIO_PLAYBACK_STOPPED,
+            FMOD_STUDIO_PLAYBACK_PLAYING if !instance.paused => {
+                instance.position += milliseconds * instance.pitch as f64;
+                let length = instance.length as f64;
+                if length > 0.0 && instance.position >= length {
+                    if instance.is_oneshot {
+                        instance.position = length;
+                        instance.state = FMOD_STUDIO_PLAYBACK_STOPPED;
+                    } else {
+                        instance.position %= length;
+                    }
+                }
+            }
+            _ => {}
+        }
+        if instance.released && instance.state == FMOD_STUDIO_PLAYBACK_STOPPED {
+            state.remove(handle);
+        }
+    }
+    Ok(())
+}
+
+pub unsafe fn FMOD_Studio_System_Create(
+    system: *mut *mut FMOD_STUDIO_SYSTEM,
+    headerversion: c_uint,
+) -> FMOD_RESULT {
+    let mut core = std::ptr::null_mut();
+    let result = system::FMOD_System_Create(&mut core, headerversion);
+    if result != FMOD_OK {
+        return result;
+    }
+    call(|state| {
+        let identity = FMOD_3D_ATTRIBUTES {
+            forward: FMOD_VECTOR {
+                x: 0.0,
+                y: 0.0,
+                z: 1.0,
+            },
+            up: FMOD_VECTOR {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0,
+            },
+            ..Default::default()
+        };
+        let studio = StudioSystem {
+            core: core as usize,
+            initialized: false,
+            listeners: 1,
+            listener_attributes: [(identity, FMOD_VECTOR::default()); 8],
+            parameters: HashMap::new(),
+            master_bus: 0,
+            user_data: 0,
+        };
+        let handle = state.insert(Object::StudioSystem(Box::new(studio)));
+        output(system, self::handle(handle));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_IsValid(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_BOOL {
+    to_bool(crate::stub::state().contains::<StudioSystem>(system as usize))
+}
+
+pub unsafe fn FMOD_Studio_System_Initialize(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    maxchannels: c_int,
+    _studioflags: FMOD_STUDIO_INITFLAGS,
+    flags: FMOD_INITFLAGS,
+    extradriverdata: *mut c_void,
+) -> FMOD_RESULT {
+    let core = match crate::stub::state().get::<StudioSystem>(system as usize) {
+        Ok(studio) if studio.initialized => return FMOD_ERR_INITIALIZED,
+        Ok(studio) => studio.core,
+        Err(error) => return error,
+    };
+    let result = system::FMOD_System_Init(handle(core), maxchannels, flags, extradriverdata);
+    if result != FMOD_OK {
+        return result;
+    }
+    call(|state| {
+        // master bus mixes into master channel group of core system
+        let group = state.get::<System>(core)?.master;
+        let master_bus = state.insert(Object::Bus(Bus {
+            system: system as usize,
+            bank: 0,
+            path: "bus:/".to_string(),
+            volume: 1.0,
+            paused: false,
+            mute: false,
+            locks: 0,
+            group,
+        }));
+        let studio = state.get::<StudioSystem>(system as usize)?;
+        studio.initialized = true;
+        studio.master_bus = master_bus;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_Release(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT {
+    let core = match crate::stub::state().get::<StudioSystem>(system as usize) {
+        Ok(studio) => studio.core,
+        Err(error) => return error,
+    };
+    let result = call(|state| {
+        for bank in state.handles::<Bank>(|bank| bank.system == system as usize) {
+            unload_bank(state, bank);
+        }
+        for bus in state.handles::<Bus>(|bus| bus.system == system as usize) {
+            state.remove(bus);
+        }
+        state.remove(system as usize);
+        Ok(())
+    });
+    if result != FMOD_OK {
+        return result;
+    }
+    system::FMOD_System_Release(handle(core))
+}
+
+pub unsafe fn FMOD_Studio_System_Update(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT {
+    let mut core = 0;
+    let result = call(|state| {
+        update(state, system as usize)?;
+        core = state.get::<StudioSystem>(system as usize)?.core;
+        Ok(())
+    });
+    match result {
+        FMOD_OK => system::after_update(core),
+        error => error,
+    }
+}
+
+pub unsafe fn FMOD_Studio_System_FlushCommands(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT {
+    call(|state| create_bus_groups(state, system as usize))
+}
+
+pub unsafe fn FMOD_Studio_System_GetCoreSystem(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    coresystem: *mut *mut FMOD_SYSTEM,
+) -> FMOD_RESULT {
+    with(system, |studio: &mut StudioSystem| {
+        output(coresystem, handle(studio.core));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_LoadBankFile(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    filename: *const c_char,
+    _flags: FMOD_STUDIO_LOAD_BANK_FLAGS,
+    bank: *mut *mut FMOD_STUDIO_BANK,
+) -> FMOD_RESULT {
+    call(|state| {
+        initialized(state, system as usize)?;
+        let filename = input_string(filename)?;
+        let path = Path::new(&filename);
+        if !path.is_file() {
+            return Err(FMOD_ERR_FILE_NOTFOUND);
+        }
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let stem = file_name.trim_end_matches(".bank").to_string();
+        let loaded = state
+            .handles::<Bank>(|bank| bank.system == system as usize && bank.file_name == file_name);
+        if !loaded.is_empty() {
+            return Err(FMOD_ERR_EVENT_ALREADY_LOADED);
+        }
+        let handle = state.insert(Object::Bank(Bank {
+            system: system as usize,
+            file_name: file_name.clone(),
+            path: format!("bank:/{stem}"),
+            user_data: 0,
+        }));
+        let definition = state.definition(&file_name);
+        for event in definition.events {
+            let names = event
+                .parameters
+                .iter()
+                .map(|parameter| CString::new(parameter.name.as_str()).unwrap_or_default())
+                .collect();
+            state.insert(Object::EventDescription(EventDescription {
+                system: system as usize,
+                bank: handle,
+                definition: event,
+                names,
+                user_data: 0,
+            }));
+        }
+        for path in definition.buses {
+            state.insert(Object::Bus(Bus {
+                system: system as usize,
+                bank: handle,
+                path,
+                volume: 1.0,
+                paused: false,
+                mute: false,
+                locks: 0,
+                group: 0,
+            }));
+        }
+        for path in definition.vcas {
+            state.insert(Object::Vca(Vca {
+                system: system as usize,
+                bank: handle,
+                path,
+                volume: 1.0,
+            }));
+        }
+        output(bank, self::handle(handle));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_UnloadAll(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT {
+    call(|state| {
+        initialized(state, system as usize)?;
+        for bank in state.handles::<Bank>(|bank| bank.system == system as usize) {
+            unload_bank(state, bank);
+        }
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_GetEvent(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    pathOrID: *const c_char,
+    event: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    call(|state| {
+        initialized(state, system as usize)?;
+        let path = input_string(pathOrID)?;
+        let found = find::<EventDescription>(
+            state,
+            &path,
+            |description| description.system == system as usize,
+            |description| description.definition.path.clone(),
+        )?;
+        output(event, handle(found));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_GetEventByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: *const FMOD_GUID,
+    event: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    call(|state| {
+        initialized(state, system as usize)?;
+        if id.is_null() {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        let id = *id;
+        let found = state
+            .handles::<EventDescription>(|description| {
+                description.system == system as usize
+                    && same_guid(&id, &guid(&description.definition.path))
+            })
+            .first()
+            .copied()
+            .ok_or(FMOD_ERR_EVENT_NOTFOUND)?;
+        output(event, handle(found));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_GetBus(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    pathOrID: *const c_char,
+    bus: *mut *mut FMOD_STUDIO_BUS,
+) -> FMOD_RESULT {
+    call(|state| {
+        initialized(state, system as usize)?;
+        let path = input_string(pathOrID)?;
+        let found = find::<Bus>(
+            state,
+            &path,
+            |bus| bus.system == system as usize,
+            |bus| bus.path.clone(),
+        )?;
+        output(bus, handle(found));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_GetVCA(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    pathOrID: *const c_char,
+    vca: *mut *mut FMOD_STUDIO_VCA,
+) -> FMOD_RESULT {
+    call(|state| {
+        initialized(state, system as usize)?;
+        let path = input_string(pathOrID)?;
+        let found = find::<Vca>(
+            state,
+            &path,
+            |vca| vca.system == system as usize,
+            |vca| vca.path.clone(),
+        )?;
+        output(vca, handle(found));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_GetBank(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    pathOrID: *const c_char,
+    bank: *mut *mut FMOD_STUDIO_BANK,
+) -> FMOD_RESULT {
+    call(|state| {
+        initialized(state, system as usize)?;
+        let path = input_string(pathOrID)?;
+        let found = find::<Bank>(
+            state,
+            &path,
+            |bank| bank.system == system as usize,
+            |bank| bank.path.clone(),
+        )?;
+        output(bank, handle(found));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_GetBankCount(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        initialized(state, system as usize)?;
+        let banks = state.handles::<Bank>(|bank| bank.system == system as usize);
+        output(count, banks.len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_GetBankList(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    array: *mut *mut FMOD_STUDIO_BANK,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        initialized(state, system as usize)?;
+        let banks = state.handles::<Bank>(|bank| bank.system == system as usize);
+        output_list(banks, array, capacity, count)
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_LookupID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    path: *const c_char,
+    id: *mut FMOD_GUID,
+) -> FMOD_RESULT {
+    call(|state| {
+        initialized(state, system as usize)?;
+        let path = input_string(path)?;
+        let system = system as usize;
+        let known = !state
+            .handles::<EventDescription>(|event| {
+                event.system == system && event.definition.path.eq_ignore_ascii_case(&path)
+            })
+            .is_empty()
+            || !state
+                .handles::<Bus>(|bus| bus.system == system && bus.path.eq_ignore_ascii_case(&path))
+                .is_empty()
+            || !state
+                .handles::<Vca>(|vca| vca.system == system && vca.path.eq_ignore_ascii_case(&path))
+                .is_empty()
+            || !state
+                .handles::<Bank>(|bank| {
+                    bank.system == system && bank.path.eq_ignore_ascii_case(&path)
+                })
+                .is_empty();
+        if !known {
+            return Err(FMOD_ERR_EVENT_NOTFOUND);
+        }
+        output(id, guid(&path));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_GetNumListeners(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    numlisteners: *mut c_int,
+) -> FMOD_RESULT {
+    with(system, |studio: &mut StudioSystem| {
+        output(numlisteners, studio.listeners);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_SetNumListeners(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    numlisteners: c_int,
+) -> FMOD_RESULT {
+    with(system, |studio: &mut StudioSystem| {
+        if !(1..=8).contains(&numlisteners) {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        studio.listeners = numlisteners;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_GetListenerAttributes(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    index: c_int,
+    attributes: *mut FMOD_3D_ATTRIBUTES,
+    attenuationposition: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    with(system, |studio: &mut StudioSystem| {
+        if index < 0 || index >= studio.listeners {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        let (value, attenuation) = studio.listener_attributes[index as usize];
+        output(attributes, value);
+        output(attenuationposition, attenuation);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_SetListenerAttributes(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    index: c_int,
+    attributes: *const FMOD_3D_ATTRIBUTES,
+    attenuationposition: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    with(system, |studio: &mut StudioSystem| {
+        if index < 0 || index >= studio.listeners || attributes.is_null() {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        let attenuation = if attenuationposition.is_null() {
+            (*attributes).position
+        } else {
+            *attenuationposition
+        };
+        studio.listener_attributes[index as usize] = (*attributes, attenuation);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_GetParameterByName(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    name: *const c_char,
+    value: *mut c_float,
+    finalvalue: *mut c_float,
+) -> FMOD_RESULT {
+    with(system, |studio: &mut StudioSystem| {
+        let name = input_string(name)?;
+        let parameter = *studio
+            .parameters
+            .get(&name)
+            .ok_or(FMOD_ERR_EVENT_NOTFOUND)?;
+        output(value, parameter);
+        output(finalvalue, parameter);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_SetParameterByName(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    name: *const c_char,
+    value: c_float,
+    _ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(system, |studio: &mut StudioSystem| {
+        let name = input_string(name)?;
+        studio.parameters.insert(name, value);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_SetUserData(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    with(system, |studio: &mut StudioSystem| {
+        studio.user_data = userdata as usize;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_System_GetUserData(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    with(system, |studio: &mut StudioSystem| {
+        output(userdata, studio.user_data as *mut c_void);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bank_IsValid(bank: *mut FMOD_STUDIO_BANK) -> FMOD_BOOL {
+    to_bool(crate::stub::state().contains::<Bank>(bank as usize))
+}
+
+pub unsafe fn FMOD_Studio_Bank_GetID(
+    bank: *mut FMOD_STUDIO_BANK,
+    id: *mut FMOD_GUID,
+) -> FMOD_RESULT {
+    with(bank, |bank: &mut Bank| {
+        output(id, guid(&bank.path));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bank_GetPath(
+    bank: *mut FMOD_STUDIO_BANK,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    with(bank, |bank: &mut Bank| {
+        output_string(path, size, retrieved, &bank.path)
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bank_Unload(bank: *mut FMOD_STUDIO_BANK) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Bank>(bank as usize)?;
+        unload_bank(state, bank as usize);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bank_LoadSampleData(bank: *mut FMOD_STUDIO_BANK) -> FMOD_RESULT {
+    with(bank, |_: &mut Bank| Ok(()))
+}
+
+pub unsafe fn FMOD_Studio_Bank_UnloadSampleData(bank: *mut FMOD_STUDIO_BANK) -> FMOD_RESULT {
+    with(bank, |_: &mut Bank| Ok(()))
+}
+
+pub unsafe fn FMOD_Studio_Bank_GetLoadingState(
+    bank: *mut FMOD_STUDIO_BANK,
+    state: *mut FMOD_STUDIO_LOADING_STATE,
+) -> FMOD_RESULT {
+    with(bank, |_: &mut Bank| {
+        output(state, FMOD_STUDIO_LOADING_STATE_LOADED);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bank_GetSampleLoadingState(
+    bank: *mut FMOD_STUDIO_BANK,
+    state: *mut FMOD_STUDIO_LOADING_STATE,
+) -> FMOD_RESULT {
+    with(bank, |_: &mut Bank| {
+        output(state, FMOD_STUDIO_LOADING_STATE_LOADED);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bank_GetEventCount(
+    bank: *mut FMOD_STUDIO_BANK,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Bank>(bank as usize)?;
+        let events = state.handles::<EventDescription>(|event| event.bank == bank as usize);
+        output(count, events.len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bank_GetEventList(
+    bank: *mut FMOD_STUDIO_BANK,
+    array: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Bank>(bank as usize)?;
+        let events = state.handles::<EventDescription>(|event| event.bank == bank as usize);
+        output_list(events, array, capacity, count)
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bank_GetBusCount(
+    bank: *mut FMOD_STUDIO_BANK,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Bank>(bank as usize)?;
+        let buses = state.handles::<Bus>(|bus| bus.bank == bank as usize);
+        output(count, buses.len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bank_GetBusList(
+    bank: *mut FMOD_STUDIO_BANK,
+    array: *mut *mut FMOD_STUDIO_BUS,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Bank>(bank as usize)?;
+        let buses = state.handles::<Bus>(|bus| bus.bank == bank as usize);
+        output_list(buses, array, capacity, count)
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bank_GetVCACount(
+    bank: *mut FMOD_STUDIO_BANK,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Bank>(bank as usize)?;
+        let vcas = state.handles::<Vca>(|vca| vca.bank == bank as usize);
+        output(count, vcas.len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bank_GetVCAList(
+    bank: *mut FMOD_STUDIO_BANK,
+    array: *mut *mut FMOD_STUDIO_VCA,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<Bank>(bank as usize)?;
+        let vcas = state.handles::<Vca>(|vca| vca.bank == bank as usize);
+        output_list(vcas, array, capacity, count)
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bank_SetUserData(
+    bank: *mut FMOD_STUDIO_BANK,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    with(bank, |bank: &mut Bank| {
+        bank.user_data = userdata as usize;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bank_GetUserData(
+    bank: *mut FMOD_STUDIO_BANK,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    with(bank, |bank: &mut Bank| {
+        output(userdata, bank.user_data as *mut c_void);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_IsValid(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_BOOL {
+    to_bool(crate::stub::state().contains::<EventDescription>(eventdescription as usize))
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetID(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    id: *mut FMOD_GUID,
+) -> FMOD_RESULT {
+    with(eventdescription, |description: &mut EventDescription| {
+        output(id, guid(&description.definition.path));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetPath(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    with(eventdescription, |description: &mut EventDescription| {
+        output_string(path, size, retrieved, &description.definition.path)
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterDescriptionCount(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    with(eventdescription, |description: &mut EventDescription| {
+        output(count, description.definition.parameters.len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterDescriptionByIndex(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    index: c_int,
+    parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+) -> FMOD_RESULT {
+    with(eventdescription, |description: &mut EventDescription| {
+        output(
+            parameter,
+            description.parameter_description(index as usize)?,
+        );
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterDescriptionByName(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    name: *const c_char,
+    parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+) -> FMOD_RESULT {
+    with(eventdescription, |description: &mut EventDescription| {
+        let index = description.parameter_index(&input_string(name)?)?;
+        output(parameter, description.parameter_description(index)?);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterDescriptionByID(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+) -> FMOD_RESULT {
+    with(eventdescription, |description: &mut EventDescription| {
+        let index = description.parameter_index_by_id(id)?;
+        output(parameter, description.parameter_description(index)?);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetLength(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    length: *mut c_int,
+) -> FMOD_RESULT {
+    with(eventdescription, |description: &mut EventDescription| {
+        output(length, description.definition.length);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_IsSnapshot(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    snapshot: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(eventdescription, |description: &mut EventDescription| {
+        output(
+            snapshot,
+            to_bool(description.definition.path.starts_with("snapshot:/")),
+        );
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_IsOneshot(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    oneshot: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(eventdescription, |description: &mut EventDescription| {
+        output(oneshot, to_bool(description.definition.is_oneshot));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_Is3D(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    is3D: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(eventdescription, |description: &mut EventDescription| {
+        output(is3D, to_bool(description.definition.is_3d));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_CreateInstance(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    instance: *mut *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_RESULT {
+    call(|state| {
+        let description = state.get::<EventDescription>(eventdescription as usize)?;
+        let event = EventInstance {
+            system: description.system,
+            description: eventdescription as usize,
+            state: FMOD_STUDIO_PLAYBACK_STOPPED,
+            position: 0.0,
+            length: description.definition.length,
+            is_oneshot: description.definition.is_oneshot,
+            paused: false,
+            released: false,
+            volume: 1.0,
+            pitch: 1.0,
+            attributes: FMOD_3D_ATTRIBUTES::default(),
+            listener_mask: 0xFFFFFFFF,
+            properties: HashMap::new(),
+            reverb: [0.0; 4],
+            parameters: description
+                .definition
+                .parameters
+                .iter()
+                .map(|parameter| parameter.default)
+                .collect(),
+            user_data: 0,
+        };
+        let handle = state.insert(Object::EventInstance(event));
+        output(instance, self::handle(handle));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetInstanceCount(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<EventDescription>(eventdescription as usize)?;
+        let instances = state
+            .handles::<EventInstance>(|instance| instance.description == eventdescription as usize);
+        output(count, instances.len() as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetInstanceList(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    array: *mut *mut FMOD_STUDIO_EVENTINSTANCE,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<EventDescription>(eventdescription as usize)?;
+        let instances = state
+            .handles::<EventInstance>(|instance| instance.description == eventdescription as usize);
+        output_list(instances, array, capacity, count)
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_LoadSampleData(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    with(eventdescription, |_: &mut EventDescription| Ok(()))
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_UnloadSampleData(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    with(eventdescription, |_: &mut EventDescription| Ok(()))
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetSampleLoadingState(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    state: *mut FMOD_STUDIO_LOADING_STATE,
+) -> FMOD_RESULT {
+    with(eventdescription, |_: &mut EventDescription| {
+        output(state, FMOD_STUDIO_LOADING_STATE_LOADED);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_ReleaseAllInstances(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<EventDescription>(eventdescription as usize)?;
+        for instance in state
+            .handles::<EventInstance>(|instance| instance.description == eventdescription as usize)
+        {
+            let instance = state.get::<EventInstance>(instance)?;
+            instance.state = FMOD_STUDIO_PLAYBACK_STOPPED;
+            instance.released = true;
+        }
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_SetUserData(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    with(eventdescription, |description: &mut EventDescription| {
+        description.user_data = userdata as usize;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetUserData(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    with(eventdescription, |description: &mut EventDescription| {
+        output(userdata, description.user_data as *mut c_void);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_IsValid(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_BOOL {
+    to_bool(crate::stub::state().contains::<EventInstance>(eventinstance as usize))
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetDescription(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    description: *mut *mut FMOD_STUDIO_EVENTDESCRIPTION,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        output(description, handle(instance.description));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetVolume(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    volume: *mut c_float,
+    finalvolume: *mut c_float,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        output(volume, instance.volume);
+        output(finalvolume, instance.volume);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetVolume(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    volume: c_float,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        if volume < 0.0 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        instance.volume = volume;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetPitch(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    pitch: *mut c_float,
+    finalpitch: *mut c_float,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        output(pitch, instance.pitch);
+        output(finalpitch, instance.pitch);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetPitch(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    pitch: c_float,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        if pitch < 0.0 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        instance.pitch = pitch;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_Get3DAttributes(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    attributes: *mut FMOD_3D_ATTRIBUTES,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        output(attributes, instance.attributes);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_Set3DAttributes(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    attributes: *mut FMOD_3D_ATTRIBUTES,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        if attributes.is_null() {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        instance.attributes = *attributes;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetListenerMask(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    mask: *mut c_uint,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        output(mask, instance.listener_mask);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetListenerMask(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    mask: c_uint,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        instance.listener_mask = mask;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetProperty(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    index: FMOD_STUDIO_EVENT_PROPERTY,
+    value: *mut c_float,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        // -1 means that property is not overridden by instance
+        output(
+            value,
+            instance.properties.get(&index).copied().unwrap_or(-1.0),
+        );
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetProperty(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    index: FMOD_STUDIO_EVENT_PROPERTY,
+    value: c_float,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        instance.properties.insert(index, value);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetReverbLevel(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    index: c_int,
+    level: *mut c_float,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        output(
+            level,
+            *instance
+                .reverb
+                .get(index as usize)
+                .ok_or(FMOD_ERR_INVALID_PARAM)?,
+        );
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetReverbLevel(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    index: c_int,
+    level: c_float,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        *instance
+            .reverb
+            .get_mut(index as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)? = level;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetPaused(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    paused: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        output(paused, to_bool(instance.paused));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetPaused(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    paused: FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        instance.paused = paused != 0;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_Start(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        instance.position = 0.0;
+        instance.state = FMOD_STUDIO_PLAYBACK_STARTING;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_Stop(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    mode: FMOD_STUDIO_STOP_MODE,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        if instance.state == FMOD_STUDIO_PLAYBACK_STOPPED {
+            return Ok(());
+        }
+        instance.state = match mode {
+            FMOD_STUDIO_STOP_IMMEDIATE => FMOD_STUDIO_PLAYBACK_STOPPED,
+            _ => FMOD_STUDIO_PLAYBACK_STOPPING,
+        };
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetTimelinePosition(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    position: *mut c_int,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        output(position, instance.position as c_int);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetTimelinePosition(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    position: c_int,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        if position < 0 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        instance.position = position as f64;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetPlaybackState(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    state: *mut FMOD_STUDIO_PLAYBACK_STATE,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        output(state, instance.state);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_Release(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        instance.released = true;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_IsVirtual(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    virtualstate: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(eventinstance, |_: &mut EventInstance| {
+        output(virtualstate, 0);
+        Ok(())
+    })
+}
+
+unsafe fn get_parameter(
+    state: &mut State,
+    instance: usize,
+    index: impl FnOnce(&EventDescription) -> Result<usize, FMOD_RESULT>,
+    value: *mut c_float,
+    finalvalue: *mut c_float,
+) -> Result<(), FMOD_RESULT> {
+    let description = state.get::<EventInstance>(instance)?.description;
+    let index = index(state.get::<EventDescription>(description)?)?;
+    let parameter = state.get::<EventInstance>(instance)?.parameters[index];
+    output(value, parameter);
+    output(finalvalue, parameter);
+    Ok(())
+}
+
+fn set_parameter(
+    state: &mut State,
+    instance: usize,
+    index: impl FnOnce(&EventDescription) -> Result<usize, FMOD_RESULT>,
+    value: c_float,
+) -> Result<(), FMOD_RESULT> {
+    let description = state.get::<EventInstance>(instance)?.description;
+    let description = state.get::<EventDescription>(description)?;
+    let index = index(description)?;
+    let parameter = &description.definition.parameters[index];
+    let value = value.clamp(parameter.minimum, parameter.maximum);
+    state.get::<EventInstance>(instance)?.parameters[index] = value;
+    Ok(())
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetParameterByName(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    name: *const c_char,
+    value: *mut c_float,
+    finalvalue: *mut c_float,
+) -> FMOD_RESULT {
+    call(|state| {
+        let name = input_string(name)?;
+        get_parameter(
+            state,
+            eventinstance as usize,
+            |description| description.parameter_index(&name),
+            value,
+            finalvalue,
+        )
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetParameterByName(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    name: *const c_char,
+    value: c_float,
+    _ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    call(|state| {
+        let name = input_string(name)?;
+        set_parameter(
+            state,
+            eventinstance as usize,
+            |description| description.parameter_index(&name),
+            value,
+        )
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetParameterByID(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    value: *mut c_float,
+    finalvalue: *mut c_float,
+) -> FMOD_RESULT {
+    call(|state| {
+        get_parameter(
+            state,
+            eventinstance as usize,
+            |description| description.parameter_index_by_id(id),
+            value,
+            finalvalue,
+        )
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetParameterByID(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    value: c_float,
+    _ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    call(|state| {
+        set_parameter(
+            state,
+            eventinstance as usize,
+            |description| description.parameter_index_by_id(id),
+            value,
+        )
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetParametersByIDs(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    ids: *const FMOD_STUDIO_PARAMETER_ID,
+    values: *mut c_float,
+    count: c_int,
+    _ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    call(|state| {
+        if ids.is_null() || values.is_null() || count < 0 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        for index in 0..count as usize {
+            let id = *ids.add(index);
+            set_parameter(
+                state,
+                eventinstance as usize,
+                |description| description.parameter_index_by_id(id),
+                *values.add(index),
+            )?;
+        }
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetUserData(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        instance.user_data = userdata as usize;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetUserData(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    with(eventinstance, |instance: &mut EventInstance| {
+        output(userdata, instance.user_data as *mut c_void);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bus_IsValid(bus: *mut FMOD_STUDIO_BUS) -> FMOD_BOOL {
+    to_bool(crate::stub::state().contains::<Bus>(bus as usize))
+}
+
+pub unsafe fn FMOD_Studio_Bus_GetID(bus: *mut FMOD_STUDIO_BUS, id: *mut FMOD_GUID) -> FMOD_RESULT {
+    with(bus, |bus: &mut Bus| {
+        output(id, guid(&bus.path));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bus_GetPath(
+    bus: *mut FMOD_STUDIO_BUS,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    with(bus, |bus: &mut Bus| {
+        output_string(path, size, retrieved, &bus.path)
+    })
+}
+
+/// Channel group is created on the next update (or commands flush) after the first lock.
+pub unsafe fn FMOD_Studio_Bus_LockChannelGroup(bus: *mut FMOD_STUDIO_BUS) -> FMOD_RESULT {
+    with(bus, |bus: &mut Bus| {
+        bus.locks += 1;
+        Ok(())
+    })
+}
+
+/// Channel group of bus is released with the last lock, master bus keeps its group.
+pub unsafe fn FMOD_Studio_Bus_UnlockChannelGroup(bus: *mut FMOD_STUDIO_BUS) -> FMOD_RESULT {
+    call(|state| {
+        let bus = state.get::<Bus>(bus as usize)?;
+        if bus.locks == 0 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        bus.locks -= 1;
+        if bus.locks == 0 && bus.bank != 0 && bus.group != 0 {
+            let group = std::mem::take(&mut bus.group);
+            channel::release_group(state, group);
+        }
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bus_GetChannelGroup(
+    bus: *mut FMOD_STUDIO_BUS,
+    group: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    with(bus, |bus: &mut Bus| {
+        if bus.group == 0 {
+            return Err(FMOD_ERR_STUDIO_NOT_LOADED);
+        }
+        output(group, handle(bus.group));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bus_GetVolume(
+    bus: *mut FMOD_STUDIO_BUS,
+    volume: *mut c_float,
+    finalvolume: *mut c_float,
+) -> FMOD_RESULT {
+    with(bus, |bus: &mut Bus| {
+        output(volume, bus.volume);
+        output(finalvolume, if bus.mute { 0.0 } else { bus.volume });
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bus_SetVolume(bus: *mut FMOD_STUDIO_BUS, volume: c_float) -> FMOD_RESULT {
+    with(bus, |bus: &mut Bus| {
+        if volume < 0.0 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        bus.volume = volume;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bus_GetPaused(
+    bus: *mut FMOD_STUDIO_BUS,
+    paused: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(bus, |bus: &mut Bus| {
+        output(paused, to_bool(bus.paused));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bus_SetPaused(
+    bus: *mut FMOD_STUDIO_BUS,
+    paused: FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(bus, |bus: &mut Bus| {
+        bus.paused = paused != 0;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bus_GetMute(
+    bus: *mut FMOD_STUDIO_BUS,
+    mute: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    with(bus, |bus: &mut Bus| {
+        output(mute, to_bool(bus.mute));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bus_SetMute(bus: *mut FMOD_STUDIO_BUS, mute: FMOD_BOOL) -> FMOD_RESULT {
+    with(bus, |bus: &mut Bus| {
+        bus.mute = mute != 0;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_Bus_StopAllEvents(
+    bus: *mut FMOD_STUDIO_BUS,
+    mode: FMOD_STUDIO_STOP_MODE,
+) -> FMOD_RESULT {
+    call(|state| {
+        // all events are routed into master bus
+        let system = state.get::<Bus>(bus as usize)?.system;
+        for instance in state.handles::<EventInstance>(|instance| instance.system == system) {
+            let instance = state.get::<EventInstance>(instance)?;
+            if instance.state != FMOD_STUDIO_PLAYBACK_STOPPED {
+                instance.state = match mode {
+                    FMOD_STUDIO_STOP_IMMEDIATE => FMOD_STUDIO_PLAYBACK_STOPPED,
+                    _ => FMOD_STUDIO_PLAYBACK_STOPPING,
+                };
+            }
+        }
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_VCA_IsValid(vca: *mut FMOD_STUDIO_VCA) -> FMOD_BOOL {
+    to_bool(crate::stub::state().contains::<Vca>(vca as usize))
+}
+
+pub unsafe fn FMOD_Studio_VCA_GetID(vca: *mut FMOD_STUDIO_VCA, id: *mut FMOD_GUID) -> FMOD_RESULT {
+    with(vca, |vca: &mut Vca| {
+        output(id, guid(&vca.path));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_VCA_GetPath(
+    vca: *mut FMOD_STUDIO_VCA,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    with(vca, |vca: &mut Vca| {
+        output_string(path, size, retrieved, &vca.path)
+    })
+}
+
+pub unsafe fn FMOD_Studio_VCA_GetVolume(
+    vca: *mut FMOD_STUDIO_VCA,
+    volume: *mut c_float,
+    finalvolume: *mut c_float,
+) -> FMOD_RESULT {
+    with(vca, |vca: &mut Vca| {
+        output(volume, vca.volume);
+        output(finalvolume, vca.volume);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Studio_VCA_SetVolume(vca: *mut FMOD_STUDIO_VCA, volume: c_float) -> FMOD_RESULT {
+    with(vca, |vca: &mut Vca| {
+        if volume < 0.0 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        vca.volume = volume;
+        Ok(())
+    })
+}
@@ -0,0 +1,1014 @@
+use std::f32::consts::FRAC_1_SQRT_2;
+use std::ffi::{c_char, c_float, c_int, c_uint, c_void, CStr};
+
+use crate::ffi::*;
+use crate::stub::channel::{self, Channel, ChannelGroup, Dsp};
+use crate::stub::codec::{self, CodecPlugin};
+use crate::stub::output::{self as output_plugin, OutputPlugin, WavWriter};
+use crate::stub::record::{self, Recording};
+use crate::stub::{call, handle, input, input_string, output, output_string, with, Object, State};
+use crate::stub::{driver, dsp, geometry, reverb, DeviceDefinition};
+use crate::{Speaker, SpeakerMode};
+
+pub(crate) struct System {
+    pub initialized: bool,
+    pub suspended: bool,
+    pub sample_rate: c_int,
+    pub speaker_mode: FMOD_SPEAKERMODE,
+    pub raw_speakers: c_int,
+    pub buffer_length: c_uint,
+    pub buffer_count: c_int,
+    pub clock: u64,
+    pub master: usize,
+    pub next_channel_index: c_int,
+    pub listeners: [(FMOD_VECTOR, FMOD_VECTOR, FMOD_VECTOR, FMOD_VECTOR); 8],
+    pub reverb: [FMOD_REVERB_PROPERTIES; FMOD_REVERB_MAXINSTANCES as usize],
+    pub output_type: FMOD_OUTPUTTYPE,
+    pub wav_writer: Option<WavWriter>,
+    pub outputs: Vec<OutputPlugin>,
+    pub output: c_uint,
+    pub output_state: usize,
+    pub codecs: Vec<CodecPlugin>,
+    pub drivers: Vec<DeviceDefinition>,
+    pub record_drivers: Vec<DeviceDefinition>,
+    pub driver: c_int,
+    pub callback: FMOD_SYSTEM_CALLBACK,
+    pub callback_mask: FMOD_SYSTEM_CALLBACK_TYPE,
+    pub callback_pending: FMOD_SYSTEM_CALLBACK_TYPE,
+    pub recordings: Vec<Recording>,
+    pub user_data: usize,
+}
+
+impl Default for System {
+    fn default() -> Self {
+        let forward = FMOD_VECTOR {
+            x: 0.0,
+            y: 0.0,
+            z: 1.0,
+        };
+        let up = FMOD_VECTOR {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        };
+        Self {
+            initialized: false,
+            suspended: false,
+            sample_rate: 48000,
+            speaker_mode: FMOD_SPEAKERMODE_STEREO,
+            raw_speakers: 0,
+            buffer_length: 1024,
+            buffer_count: 4,
+            clock: 0,
+            master: 0,
+            next_channel_index: 0,
+            listeners: [(FMOD_VECTOR::default(), FMOD_VECTOR::default(), forward, up); 8],
+            reverb: [FMOD_PRESET_OFF; FMOD_REVERB_MAXINSTANCES as usize],
+            output_type: FMOD_OUTPUTTYPE_AUTODETECT,
+            wav_writer: None,
+            outputs: vec![],
+            output: 0,
+            output_state: 0,
+            codecs: vec![],
+            drivers: vec![DeviceDefinition::new("Stub Output")],
+            record_drivers: vec![DeviceDefinition::new("Stub Input")],
+            driver: 0,
+            callback: None,
+            callback_mask: 0,
+            callback_pending: 0,
+            recordings: vec![],
+            user_data: 0,
+        }
+    }
+}
+
+pub(crate) struct Sound {
+    pub system: usize,
+    pub name: String,
+    pub mode: FMOD_MODE,
+    pub type_: FMOD_SOUND_TYPE,
+    pub format: FMOD_SOUND_FORMAT,
+    pub channels: c_int,
+    pub bits: c_int,
+    pub length: c_uint,
+    pub frequency: c_float,
+    pub priority: c_int,
+    pub loop_count: c_int,
+    pub data: Vec<u8>,
+    pub read_position: usize,
+    pub codec: usize,
+    pub user_data: usize,
+}
+
+impl Sound {
+    pub fn bytes_per_sample(&self) -> c_uint {
+        (self.channels * self.bits / 8).max(1) as c_uint
+    }
+}
+
+/// Format of PCM data (type, format, channels, bits, frequency, length in samples, data).
+struct Format(
+    FMOD_SOUND_TYPE,
+    FMOD_SOUND_FORMAT,
+    c_int,
+    c_int,
+    c_float,
+    c_uint,
+    Vec<u8>,
+);
+
+fn parse_wav(data: &[u8]) -> Option<Format> {
+    if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"WAVE" {
+        return None;
+    }
+    let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+    let read_u32 = |offset: usize| {
+        u32::from_le_bytes([
+            data[offset],
+            data[offset + 1],
+            data[offset + 2],
+            data[offset + 3],
+        ])
+    };
+    let mut offset = 12;
+    let mut header = None;
+    while offset + 8 <= data.len() {
+        let size = read_u32(offset + 4) as usize;
+        let chunk = offset + 8;
+        match &data[offset..offset + 4] {
+            b"fmt " if chunk + 16 <= data.len() => {
+                let tag = read_u16(chunk);
+                let channels = read_u16(chunk + 2) as c_int;
+                let rate = read_u32(chunk + 4) as c_float;
+                let bits = read_u16(chunk + 14) as c_int;
+                let format = match (tag, bits) {
+                    (3, 32) => FMOD_SOUND_FORMAT_PCMFLOAT,
+                    (_, 8) => FMOD_SOUND_FORMAT_PCM8,
+                    (_, 16) => FMOD_SOUND_FORMAT_PCM16,
+                    (_, 24) => FMOD_SOUND_FORMAT_PCM24,
+                    (_, 32) => FMOD_SOUND_FORMAT_PCM32,
+                    _ => return None,
+                };
+                header = Some((format, channels, bits, rate));
+            }
+            b"data" => {
+                let (format, channels, bits, rate) = header?;
+                let size = size.min(data.len() - chunk);
+                let length = size as c_uint / (channels * bits / 8).max(1) as c_uint;
+                return Some(Format(
+                    FMOD_SOUND_TYPE_WAV,
+                    format,
+                    channels,
+                    bits,
+                    rate,
+                    length,
+                    data[chunk..chunk + size].to_vec(),
+                ));
+            }
+            _ => {}
+        }
+        offset = chunk + size + size % 2;
+    }
+    None
+}
+
+unsafe fn create_sound(
+    state: &mut State,
+    system: usize,
+    name_or_data: *const c_char,
+    mode: FMOD_MODE,
+    exinfo: *mut FMOD_CREATESOUNDEXINFO,
+    codec: usize,
+) -> Result<usize, FMOD_RESULT> {
+    let system_state = state.get::<System>(system)?;
+    if !system_state.initialized {
+        return Err(FMOD_ERR_UNINITIALIZED);
+    }
+    let exinfo: FMOD_CREATESOUNDEXINFO = input(exinfo);
+    let (name, format) = if codec != 0 {
+        let waveformat = codec::waveformat(codec);
+        let name = if waveformat.name.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(waveformat.name)
+                .to_string_lossy()
+                .to_string()
+        };
+        let format = Format(
+            FMOD_SOUND_TYPE_USER,
+            waveformat.format,
+            waveformat.channels,
+            bits(waveformat.format),
+            waveformat.frequency as c_float,
+            waveformat.lengthpcm,
+            vec![],
+        );
+        (name, Some(format))
+    } else if mode & FMOD_OPENUSER != 0 {
+        let channels = exinfo.numchannels.max(1);
+        let bits = bits(exinfo.format);
+        let length = exinfo.length / (channels * bits / 8) as c_uint;
+        let format = Format(
+            FMOD_SOUND_TYPE_USER,
+            exinfo.format,
+            channels,
+            bits,
+            exinfo.defaultfrequency as c_float,
+            length,
+            vec![0; exinfo.length as usize],
+        );
+        (String::new(), Some(format))
+    } else if mode & (FMOD_OPENMEMORY | FMOD_OPENMEMORY_POINT) != 0 {
+        if name_or_data.is_null() {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        let data = std::slice::from_raw_parts(name_or_data as *const u8, exinfo.length as usize);
+        (String::new(), parse_wav(data))
+    } else {
+        let path = input_string(name_or_data)?;
+        let data = std::fs::read(&path).map_err(|_| FMOD_ERR_FILE_NOTFOUND)?;
+        let name = std::path::Path::new(&path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or(path);
+        (name, parse_wav(&data))
+    };
+    // compressed formats are not decoded, such sounds are one second long
+    let Format(type_, format, channels, bits, frequency, length, data) = format.unwrap_or(Format(
+        FMOD_SOUND_TYPE_UNKNOWN,
+        FMOD_SOUND_FORMAT_PCM16,
+        2,
+        16,
+        system_state.sample_rate as c_float,
+        system_state.sample_rate as c_uint,
+        vec![],
+    ));
+    let frequency = if frequency > 0.0 {
+        frequency
+    } else {
+        system_state.sample_rate as c_float
+    };
+    let sound = Sound {
+        system,
+        name,
+        mode,
+        type_,
+        format,
+        channels,
+        bits,
+        length,
+        frequency,
+        priority: 128,
+        loop_count: -1,
+        data,
+        read_position: 0,
+        codec,
+        user_data: 0,
+    };
+    Ok(state.insert(Object::Sound(sound)))
+}
+
+fn bits(format: FMOD_SOUND_FORMAT) -> c_int {
+    match format {
+        FMOD_SOUND_FORMAT_PCM8 => 8,
+        FMOD_SOUND_FORMAT_PCM24 => 24,
+        FMOD_SOUND_FORMAT_PCM32 | FMOD_SOUND_FORMAT_PCMFLOAT => 32,
+        _ => 16,
+    }
+}
+
+pub(crate) fn update(state: &mut State, system: usize) -> Result<(), FMOD_RESULT> {
+    let system_state = state.get::<System>(system)?;
+    if system_state.suspended {
+        return Ok(());
+    }
+    system_state.clock += system_state.buffer_length as u64;
+    if let Some(writer) = &mut system_state.wav_writer {
+        writer.write_block(system_state.buffer_length)?;
+    }
+    let clock = system_state.clock;
+    let seconds = system_state.buffer_length as f64 / system_state.sample_rate as f64;
+    record::record(state, system)?;
+    for handle in state.handles::<Channel>(|channel| channel.control.system == system) {
+        channel::advance(state, handle, clock, seconds);
+    }
+    Ok(())
+}
+
+fn release(state: &mut State, system: usize) {
+    if let Some(writer) = state
+        .get::<System>(system)
+        .ok()
+        .and_then(|system| system.wav_writer.take())
+    {
+        writer.finish();
+    }
+    for handle in state.handles::<Channel>(|channel| channel.control.system == system) {
+        channel::stop_channel(state, handle);
+    }
+    for handle in state.handles::<ChannelGroup>(|group| group.control.system == system) {
+        channel::release_group(state, handle);
+    }
+    for handle in state.handles::<Sound>(|sound| sound.system == system) {
+        state.remove(handle);
+    }
+    for handle in state.handles::<Dsp>(|dsp| dsp.system == system) {
+        dsp::disconnect_all(state, handle, true, true);
+        state.remove(handle);
+    }
+    geometry::release_geometries(state, system);
+    reverb::release_reverbs(state, system);
+}
+
+pub unsafe fn FMOD_System_Create(
+    system: *mut *mut FMOD_SYSTEM,
+    headerversion: c_uint,
+) -> FMOD_RESULT {
+    if headerversion != FMOD_VERSION {
+        return FMOD_ERR_HEADER_MISMATCH;
+    }
+    call(|state| {
+        let handle = state.insert(Object::System(Box::default()));
+        output(system, self::handle(handle));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_Release(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    output_plugin::close(system as usize);
+    let codecs = codec::take_sounds(system as usize);
+    let result = call(|state| {
+        state.get::<System>(system as usize)?;
+        release(state, system as usize);
+        state.remove(system as usize);
+        Ok(())
+    });
+    for codec_state in codecs {
+        codec::close(codec_state);
+    }
+    result
+}
+
+pub unsafe fn FMOD_System_Init(
+    system: *mut FMOD_SYSTEM,
+    _maxchannels: c_int,
+    flags: FMOD_INITFLAGS,
+    extradriverdata: *mut c_void,
+) -> FMOD_RESULT {
+    let result = call(|state| {
+        let system_state = state.get::<System>(system as usize)?;
+        if system_state.initialized {
+            return Err(FMOD_ERR_INITIALIZED);
+        }
+        if matches!(
+            system_state.output_type,
+            FMOD_OUTPUTTYPE_WAVWRITER | FMOD_OUTPUTTYPE_WAVWRITER_NRT
+        ) {
+            // FMOD writes to the working directory unless file name is given
+            let path = if extradriverdata.is_null() {
+                "fmodoutput.wav".to_string()
+            } else {
+                input_string(extradriverdata as *const c_char)?
+            };
+            let channels = speaker_mode_channels(system_state, system_state.speaker_mode)?;
+            let writer = WavWriter::create(&path, system_state.sample_rate, channels)?;
+            system_state.wav_writer = Some(writer);
+        }
+        system_state.initialized = true;
+        let master = channel::create_group(state, system as usize, "Master", 0);
+        state.get::<System>(system as usize)?.master = master;
+        Ok(())
+    });
+    if result != FMOD_OK {
+        return result;
+    }
+    let result = output_plugin::start(system as usize, flags, extradriverdata);
+    if result != FMOD_OK {
+        FMOD_System_Close(system);
+    }
+    result
+}
+
+pub unsafe fn FMOD_System_Close(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    output_plugin::close(system as usize);
+    let codecs = codec::take_sounds(system as usize);
+    let result = call(|state| {
+        state.get::<System>(system as usize)?;
+        release(state, system as usize);
+        let system = state.get::<System>(system as usize)?;
+        system.initialized = false;
+        system.master = 0;
+        Ok(())
+    });
+    for codec_state in codecs {
+        codec::close(codec_state);
+    }
+    result
+}
+
+/// Runs user callbacks of the update without holding the stub lock.
+pub(crate) unsafe fn after_update(system: usize) -> FMOD_RESULT {
+    match output_plugin::update(system) {
+        FMOD_OK => driver::notify(system),
+        error => error,
+    }
+}
+
+pub unsafe fn FMOD_System_Update(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    match call(|state| update(state, system as usize)) {
+        FMOD_OK => after_update(system as usize),
+        error => error,
+    }
+}
+
+pub unsafe fn FMOD_System_MixerSuspend(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        system.suspended = true;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_MixerResume(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        system.suspended = false;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_SetSoftwareFormat(
+    system: *mut FMOD_SYSTEM,
+    samplerate: c_int,
+    speakermode: FMOD_SPEAKERMODE,
+    numrawspeakers: c_int,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        if system.initialized {
+            return Err(FMOD_ERR_INITIALIZED);
+        }
+        if !(8000..=192000).contains(&samplerate) {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        system.sample_rate = samplerate;
+        system.speaker_mode = speakermode;
+        system.raw_speakers = numrawspeakers;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetSoftwareFormat(
+    system: *mut FMOD_SYSTEM,
+    samplerate: *mut c_int,
+    speakermode: *mut FMOD_SPEAKERMODE,
+    numrawspeakers: *mut c_int,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        output(samplerate, system.sample_rate);
+        output(speakermode, system.speaker_mode);
+        output(numrawspeakers, system.raw_speakers);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_SetDSPBufferSize(
+    system: *mut FMOD_SYSTEM,
+    bufferlength: c_uint,
+    numbuffers: c_int,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        if system.initialized {
+            return Err(FMOD_ERR_INITIALIZED);
+        }
+        if bufferlength == 0 || numbuffers <= 0 {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        system.buffer_length = bufferlength;
+        system.buffer_count = numbuffers;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetDSPBufferSize(
+    system: *mut FMOD_SYSTEM,
+    bufferlength: *mut c_uint,
+    numbuffers: *mut c_int,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        output(bufferlength, system.buffer_length);
+        output(numbuffers, system.buffer_count);
+        Ok(())
+    })
+}
+
+pub(crate) fn speaker_mode_channels(
+    system: &System,
+    mode: FMOD_SPEAKERMODE,
+) -> Result<c_int, FMOD_RESULT> {
+    match mode {
+        FMOD_SPEAKERMODE_RAW => Ok(system.raw_speakers),
+        FMOD_SPEAKERMODE_MONO => Ok(1),
+        FMOD_SPEAKERMODE_STEREO => Ok(2),
+        FMOD_SPEAKERMODE_QUAD => Ok(4),
+        FMOD_SPEAKERMODE_SURROUND => Ok(5),
+        FMOD_SPEAKERMODE_5POINT1 => Ok(6),
+        FMOD_SPEAKERMODE_7POINT1 => Ok(8),
+        FMOD_SPEAKERMODE_7POINT1POINT4 => Ok(12),
+        _ => Err(FMOD_ERR_INVALID_PARAM),
+    }
+}
+
+pub unsafe fn FMOD_System_GetSpeakerModeChannels(
+    system: *mut FMOD_SYSTEM,
+    mode: FMOD_SPEAKERMODE,
+    channels: *mut c_int,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        output(channels, speaker_mode_channels(system, mode)?);
+        Ok(())
+    })
+}
+
+/// Speakers with the same position are mapped at full level,
+/// mono is spread to (or downmixed from) front left and right speakers at -3 dB.
+pub unsafe fn FMOD_System_GetDefaultMixMatrix(
+    system: *mut FMOD_SYSTEM,
+    sourcespeakermode: FMOD_SPEAKERMODE,
+    targetspeakermode: FMOD_SPEAKERMODE,
+    matrix: *mut c_float,
+    matrixhop: c_int,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        let ins = speaker_mode_channels(system, sourcespeakermode)?;
+        let outs = speaker_mode_channels(system, targetspeakermode)?;
+        if matrix.is_null() || (matrixhop > 0 && matrixhop < ins) {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        let hop = if matrixhop > 0 { matrixhop } else { ins };
+        let layout = |mode| {
+            SpeakerMode::from(mode)
+                .ok()
+                .and_then(|mode| mode.speakers())
+                .unwrap_or_default()
+        };
+        let sources = layout(sourcespeakermode);
+        let targets = layout(targetspeakermode);
+        for out in 0..outs {
+            for input in 0..ins {
+                let source = sources.get(input as usize).copied();
+                let target = targets.get(out as usize).copied();
+                let front =
+                    |speaker| matches!(speaker, Some(Speaker::FrontLeft | Speaker::FrontRight));
+                let level = if sourcespeakermode == targetspeakermode {
+                    if out == input {
+                        1.0
+                    } else {
+                        0.0
+                    }
+                } else if (sources.len() == 1 && front(target))
+                    || (targets.len() == 1 && front(source))
+                {
+                    FRAC_1_SQRT_2
+                } else if source.is_some() && source == target {
+                    1.0
+                } else {
+                    0.0
+                };
+                *matrix.add((out * hop + input) as usize) = level;
+            }
+        }
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetVersion(
+    system: *mut FMOD_SYSTEM,
+    version: *mut c_uint,
+) -> FMOD_RESULT {
+    with(system, |_: &mut System| {
+        output(version, FMOD_VERSION);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_Set3DListenerAttributes(
+    system: *mut FMOD_SYSTEM,
+    listener: c_int,
+    pos: *const FMOD_VECTOR,
+    vel: *const FMOD_VECTOR,
+    forward: *const FMOD_VECTOR,
+    up: *const FMOD_VECTOR,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        let attributes = system
+            .listeners
+            .get_mut(listener as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)?;
+        if !pos.is_null() {
+            attributes.0 = *pos;
+        }
+        if !vel.is_null() {
+            attributes.1 = *vel;
+        }
+        if !forward.is_null() {
+            attributes.2 = *forward;
+        }
+        if !up.is_null() {
+            attributes.3 = *up;
+        }
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_Get3DListenerAttributes(
+    system: *mut FMOD_SYSTEM,
+    listener: c_int,
+    pos: *mut FMOD_VECTOR,
+    vel: *mut FMOD_VECTOR,
+    forward: *mut FMOD_VECTOR,
+    up: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        let attributes = system
+            .listeners
+            .get(listener as usize)
+            .ok_or(FMOD_ERR_INVALID_PARAM)?;
+        output(pos, attributes.0);
+        output(vel, attributes.1);
+        output(forward, attributes.2);
+        output(up, attributes.3);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetChannelsPlaying(
+    system: *mut FMOD_SYSTEM,
+    channels: *mut c_int,
+    realchannels: *mut c_int,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<System>(system as usize)?;
+        let count = state
+            .handles::<Channel>(|channel| channel.control.system == system as usize)
+            .len() as c_int;
+        output(channels, count);
+        output(realchannels, count);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetChannel(
+    system: *mut FMOD_SYSTEM,
+    channelid: c_int,
+    channel: *mut *mut FMOD_CHANNEL,
+) -> FMOD_RESULT {
+    call(|state| {
+        state.get::<System>(system as usize)?;
+        let handles = state.handles::<Channel>(|channel| {
+            channel.control.system == system as usize && channel.index == channelid
+        });
+        let found = handles.first().ok_or(FMOD_ERR_INVALID_PARAM)?;
+        output(channel, handle(*found));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetMasterChannelGroup(
+    system: *mut FMOD_SYSTEM,
+    channelgroup: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        if !system.initialized {
+            return Err(FMOD_ERR_UNINITIALIZED);
+        }
+        output(channelgroup, handle(system.master));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_CreateChannelGroup(
+    system: *mut FMOD_SYSTEM,
+    name: *const c_char,
+    channelgroup: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    call(|state| {
+        let system_state = state.get::<System>(system as usize)?;
+        if !system_state.initialized {
+            return Err(FMOD_ERR_UNINITIALIZED);
+        }
+        let master = system_state.master;
+        let name = if name.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(name).to_string_lossy().to_string()
+        };
+        let group = channel::create_group(state, system as usize, &name, master);
+        output(channelgroup, handle(group));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_CreateSound(
+    system: *mut FMOD_SYSTEM,
+    name_or_data: *const c_char,
+    mode: FMOD_MODE,
+    exinfo: *mut FMOD_CREATESOUNDEXINFO,
+    sound: *mut *mut FMOD_SOUND,
+) -> FMOD_RESULT {
+    let codec = codec::open(system as usize, name_or_data, mode, exinfo);
+    let result = call(|state| {
+        let handle = create_sound(state, system as usize, name_or_data, mode, exinfo, codec)?;
+        output(sound, self::handle(handle));
+        Ok(())
+    });
+    if result != FMOD_OK {
+        codec::close(codec);
+    }
+    result
+}
+
+pub unsafe fn FMOD_System_CreateStream(
+    system: *mut FMOD_SYSTEM,
+    name_or_data: *const c_char,
+    mode: FMOD_MODE,
+    exinfo: *mut FMOD_CREATESOUNDEXINFO,
+    sound: *mut *mut FMOD_SOUND,
+) -> FMOD_RESULT {
+    FMOD_System_CreateSound(
+        system,
+        name_or_data,
+        mode | FMOD_CREATESTREAM,
+        exinfo,
+        sound,
+    )
+}
+
+pub unsafe fn FMOD_System_CreateDSPByType(
+    system: *mut FMOD_SYSTEM,
+    type_: FMOD_DSP_TYPE,
+    dsp: *mut *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    call(|state| {
+        if !state.get::<System>(system as usize)?.initialized {
+            return Err(FMOD_ERR_UNINITIALIZED);
+        }
+        let handle = state.insert(Object::Dsp(Dsp::new(system as usize, type_)));
+        output(dsp, self::handle(handle));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_PlaySound(
+    system: *mut FMOD_SYSTEM,
+    sound: *mut FMOD_SOUND,
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    paused: FMOD_BOOL,
+    channel: *mut *mut FMOD_CHANNEL,
+) -> FMOD_RESULT {
+    call(|state| {
+        let system_state = state.get::<System>(system as usize)?;
+        if !system_state.initialized {
+            return Err(FMOD_ERR_UNINITIALIZED);
+        }
+        let master = system_state.master;
+        let group = if channelgroup.is_null() {
+            master
+        } else {
+            state.get::<ChannelGroup>(channelgroup as usize)?;
+            channelgroup as usize
+        };
+        state.get::<Sound>(sound as usize)?;
+        let handle = channel::create_channel(state, system as usize, sound as usize, group);
+        state.get::<Channel>(handle)?.control.paused = paused != 0;
+        output(channel, self::handle(handle));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_SetUserData(
+    system: *mut FMOD_SYSTEM,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        system.user_data = userdata as usize;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_System_GetUserData(
+    system: *mut FMOD_SYSTEM,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    with(system, |system: &mut System| {
+        output(userdata, system.user_data as *mut c_void);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Sound_Release(sound: *mut FMOD_SOUND) -> FMOD_RESULT {
+    let mut codec = 0;
+    let result = call(|state| {
+        codec = state.get::<Sound>(sound as usize)?.codec;
+        for handle in state.handles::<Channel>(|channel| channel.sound == sound as usize) {
+            channel::stop_channel(state, handle);
+        }
+        state.remove(sound as usize);
+        Ok(())
+    });
+    codec::close(codec);
+    result
+}
+
+/// Reads PCM data of WAV sounds and sounds opened by codec plugin, compressed formats are not decoded.
+pub unsafe fn FMOD_Sound_ReadData(
+    sound: *mut FMOD_SOUND,
+    buffer: *mut c_void,
+    length: c_uint,
+    read: *mut c_uint,
+) -> FMOD_RESULT {
+    output(read, 0);
+    if buffer.is_null() {
+        return FMOD_ERR_INVALID_PARAM;
+    }
+    let mut codec_state = 0;
+    let mut frame_size = 1;
+    let result = call(|state| {
+        let sound = state.get::<Sound>(sound as usize)?;
+        frame_size = sound.bytes_per_sample();
+        if sound.codec != 0 {
+            codec_state = sound.codec;
+            return Ok(());
+        }
+        if sound.type_ == FMOD_SOUND_TYPE_UNKNOWN {
+            return Err(FMOD_ERR_UNSUPPORTED);
+        }
+        let start = sound.read_position.min(sound.data.len());
+        let count = (length as usize).min(sound.data.len() - start);
+        std::ptr::copy_nonoverlapping(sound.data[start..].as_ptr(), buffer as *mut u8, count);
+        sound.read_position = start + count;
+        output(read, count as c_uint);
+        if count < length as usize {
+            return Err(FMOD_ERR_FILE_EOF);
+        }
+        Ok(())
+    });
+    if result != FMOD_OK || codec_state == 0 {
+        return result;
+    }
+    // codec is invoked without holding the stub lock
+    let samples = length / frame_size;
+    match codec::read(codec_state, buffer, samples) {
+        Ok(decoded) => {
+            output(read, decoded * frame_size);
+            if decoded < samples {
+                return FMOD_ERR_FILE_EOF;
+            }
+            FMOD_OK
+        }
+        Err(error) => error,
+    }
+}
+
+pub unsafe fn FMOD_Sound_SeekData(sound: *mut FMOD_SOUND, pcm: c_uint) -> FMOD_RESULT {
+    let mut codec_state = 0;
+    let result = with(sound, |sound: &mut Sound| {
+        if pcm > sound.length {
+            return Err(FMOD_ERR_INVALID_POSITION);
+        }
+        codec_state = sound.codec;
+        sound.read_position = (pcm * sound.bytes_per_sample()) as usize;
+        Ok(())
+    });
+    if result != FMOD_OK || codec_state == 0 {
+        return result;
+    }
+    codec::seek(codec_state, pcm)
+}
+
+pub unsafe fn FMOD_Sound_GetSystemObject(
+    sound: *mut FMOD_SOUND,
+    system: *mut *mut FMOD_SYSTEM,
+) -> FMOD_RESULT {
+    with(sound, |sound: &mut Sound| {
+        output(system, handle(sound.system));
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Sound_SetDefaults(
+    sound: *mut FMOD_SOUND,
+    frequency: c_float,
+    priority: c_int,
+) -> FMOD_RESULT {
+    with(sound, |sound: &mut Sound| {
+        if !(0..=256).contains(&priority) {
+            return Err(FMOD_ERR_INVALID_PARAM);
+        }
+        sound.frequency = frequency;
+        sound.priority = priority;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Sound_GetDefaults(
+    sound: *mut FMOD_SOUND,
+    frequency: *mut c_float,
+    priority: *mut c_int,
+) -> FMOD_RESULT {
+    with(sound, |sound: &mut Sound| {
+        output(frequency, sound.frequency);
+        output(priority, sound.priority);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Sound_GetName(
+    sound: *mut FMOD_SOUND,
+    name: *mut c_char,
+    namelen: c_int,
+) -> FMOD_RESULT {
+    with(sound, |sound: &mut Sound| {
+        // FMOD silently truncates sound name
+        let _ = output_string(name, namelen, std::ptr::null_mut(), &sound.name);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Sound_GetLength(
+    sound: *mut FMOD_SOUND,
+    length: *mut c_uint,
+    lengthtype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    with(sound, |sound: &mut Sound| {
+        let value = match lengthtype {
+            FMOD_TIMEUNIT_PCM => sound.length,
+            FMOD_TIMEUNIT_PCMBYTES => sound.length * sound.bytes_per_sample(),
+            FMOD_TIMEUNIT_MS => (sound.length as f64 * 1000.0 / sound.frequency as f64) as c_uint,
+            _ => return Err(FMOD_ERR_FORMAT),
+        };
+        output(length, value);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Sound_GetFormat(
+    sound: *mut FMOD_SOUND,
+    type_: *mut FMOD_SOUND_TYPE,
+    format: *mut FMOD_SOUND_FORMAT,
+    channels: *mut c_int,
+    bits: *mut c_int,
+) -> FMOD_RESULT {
+    with(sound, |sound: &mut Sound| {
+        output(type_, sound.type_);
+        output(format, sound.format);
+        output(channels, sound.channels);
+        output(bits, sound.bits);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Sound_SetMode(sound: *mut FMOD_SOUND, mode: FMOD_MODE) -> FMOD_RESULT {
+    with(sound, |sound: &mut Sound| {
+        sound.mode = mode;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Sound_GetMode(sound: *mut FMOD_SOUND, mode: *mut FMOD_MODE) -> FMOD_RESULT {
+    with(sound, |sound: &mut Sound| {
+        output(mode, sound.mode);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Sound_SetLoopCount(sound: *mut FMOD_SOUND, loopcount: c_int) -> FMOD_RESULT {
+    with(sound, |sound: &mut Sound| {
+        sound.loop_count = loopcount;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Sound_GetLoopCount(
+    sound: *mut FMOD_SOUND,
+    loopcount: *mut c_int,
+) -> FMOD_RESULT {
+    with(sound, |sound: &mut Sound| {
+        output(loopcount, sound.loop_count);
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Sound_SetUserData(sound: *mut FMOD_SOUND, userdata: *mut c_void) -> FMOD_RESULT {
+    with(sound, |sound: &mut Sound| {
+        sound.user_data = userdata as usize;
+        Ok(())
+    })
+}
+
+pub unsafe fn FMOD_Sound_GetUserData(
+    sound: *mut FMOD_SOUND,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    with(sound, |sound: &mut Sound| {
+        output(userdata, sound.user_data as *mut c_void);
+        Ok(())
+    })
+}
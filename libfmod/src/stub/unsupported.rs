@@ -0,0 +1,1664 @@
+//! Functions not covered by the stub, all of them return FMOD_ERR_UNSUPPORTED.
+#![allow(unused_variables)]
+
+use std::ffi::{c_char, c_float, c_int, c_longlong, c_uint, c_void};
+
+use crate::ffi::*;
+
+pub unsafe fn FMOD_Studio_ParseID(idstring: *const c_char, id: *mut FMOD_GUID) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_SetAdvancedSettings(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    settings: *mut FMOD_STUDIO_ADVANCEDSETTINGS,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetAdvancedSettings(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    settings: *mut FMOD_STUDIO_ADVANCEDSETTINGS,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetBusByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: *const FMOD_GUID,
+    bus: *mut *mut FMOD_STUDIO_BUS,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetVCAByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: *const FMOD_GUID,
+    vca: *mut *mut FMOD_STUDIO_VCA,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetBankByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: *const FMOD_GUID,
+    bank: *mut *mut FMOD_STUDIO_BANK,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetSoundInfo(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    key: *const c_char,
+    info: *mut FMOD_STUDIO_SOUND_INFO,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetParameterDescriptionByName(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    name: *const c_char,
+    parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetParameterDescriptionByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    parameter: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetParameterLabelByName(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    name: *const c_char,
+    labelindex: c_int,
+    label: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetParameterLabelByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    labelindex: c_int,
+    label: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetParameterByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    value: *mut c_float,
+    finalvalue: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_SetParameterByID(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    value: c_float,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_SetParameterByIDWithLabel(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    label: *const c_char,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_SetParametersByIDs(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    ids: *const FMOD_STUDIO_PARAMETER_ID,
+    values: *mut c_float,
+    count: c_int,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_SetParameterByNameWithLabel(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    name: *const c_char,
+    label: *const c_char,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_LookupPath(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    id: *const FMOD_GUID,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetListenerWeight(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    index: c_int,
+    weight: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_SetListenerWeight(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    index: c_int,
+    weight: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_LoadBankMemory(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    buffer: *const c_char,
+    length: c_int,
+    mode: FMOD_STUDIO_LOAD_MEMORY_MODE,
+    flags: FMOD_STUDIO_LOAD_BANK_FLAGS,
+    bank: *mut *mut FMOD_STUDIO_BANK,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_LoadBankCustom(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    info: *const FMOD_STUDIO_BANK_INFO,
+    flags: FMOD_STUDIO_LOAD_BANK_FLAGS,
+    bank: *mut *mut FMOD_STUDIO_BANK,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_RegisterPlugin(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    description: *const FMOD_DSP_DESCRIPTION,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_UnregisterPlugin(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    name: *const c_char,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_FlushSampleLoading(
+    system: *mut FMOD_STUDIO_SYSTEM,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_StartCommandCapture(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    filename: *const c_char,
+    flags: FMOD_STUDIO_COMMANDCAPTURE_FLAGS,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_StopCommandCapture(
+    system: *mut FMOD_STUDIO_SYSTEM,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_LoadCommandReplay(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    filename: *const c_char,
+    flags: FMOD_STUDIO_COMMANDREPLAY_FLAGS,
+    replay: *mut *mut FMOD_STUDIO_COMMANDREPLAY,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetParameterDescriptionCount(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetParameterDescriptionList(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    array: *mut FMOD_STUDIO_PARAMETER_DESCRIPTION,
+    capacity: c_int,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetCPUUsage(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    usage: *mut FMOD_STUDIO_CPU_USAGE,
+    usage_core: *mut FMOD_CPU_USAGE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetBufferUsage(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    usage: *mut FMOD_STUDIO_BUFFER_USAGE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_ResetBufferUsage(system: *mut FMOD_STUDIO_SYSTEM) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_SetCallback(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    callback: FMOD_STUDIO_SYSTEM_CALLBACK,
+    callbackmask: FMOD_STUDIO_SYSTEM_CALLBACK_TYPE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_System_GetMemoryUsage(
+    system: *mut FMOD_STUDIO_SYSTEM,
+    memoryusage: *mut FMOD_STUDIO_MEMORY_USAGE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterLabelByIndex(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    index: c_int,
+    labelindex: c_int,
+    label: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterLabelByName(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    name: *const c_char,
+    labelindex: c_int,
+    label: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetParameterLabelByID(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    labelindex: c_int,
+    label: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetUserPropertyCount(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetUserPropertyByIndex(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    index: c_int,
+    property: *mut FMOD_STUDIO_USER_PROPERTY,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetUserProperty(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    name: *const c_char,
+    property: *mut FMOD_STUDIO_USER_PROPERTY,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetMinMaxDistance(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    min: *mut c_float,
+    max: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_GetSoundSize(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    size: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_IsStream(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    isStream: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_IsDopplerEnabled(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    doppler: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_HasSustainPoint(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    sustainPoint: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventDescription_SetCallback(
+    eventdescription: *mut FMOD_STUDIO_EVENTDESCRIPTION,
+    callback: FMOD_STUDIO_EVENT_CALLBACK,
+    callbackmask: FMOD_STUDIO_EVENT_CALLBACK_TYPE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetChannelGroup(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    group: *mut *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetMinMaxDistance(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    min: *mut c_float,
+    max: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetParameterByNameWithLabel(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    name: *const c_char,
+    label: *const c_char,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetParameterByIDWithLabel(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    id: FMOD_STUDIO_PARAMETER_ID,
+    label: *const c_char,
+    ignoreseekspeed: FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_KeyOff(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_SetCallback(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    callback: FMOD_STUDIO_EVENT_CALLBACK,
+    callbackmask: FMOD_STUDIO_EVENT_CALLBACK_TYPE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetCPUUsage(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    exclusive: *mut c_uint,
+    inclusive: *mut c_uint,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_EventInstance_GetMemoryUsage(
+    eventinstance: *mut FMOD_STUDIO_EVENTINSTANCE,
+    memoryusage: *mut FMOD_STUDIO_MEMORY_USAGE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_Bus_GetPortIndex(
+    bus: *mut FMOD_STUDIO_BUS,
+    index: *mut FMOD_PORT_INDEX,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_Bus_SetPortIndex(
+    bus: *mut FMOD_STUDIO_BUS,
+    index: FMOD_PORT_INDEX,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_Bus_GetCPUUsage(
+    bus: *mut FMOD_STUDIO_BUS,
+    exclusive: *mut c_uint,
+    inclusive: *mut c_uint,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_Bus_GetMemoryUsage(
+    bus: *mut FMOD_STUDIO_BUS,
+    memoryusage: *mut FMOD_STUDIO_MEMORY_USAGE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_Bank_GetStringCount(
+    bank: *mut FMOD_STUDIO_BANK,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_Bank_GetStringInfo(
+    bank: *mut FMOD_STUDIO_BANK,
+    index: c_int,
+    id: *mut FMOD_GUID,
+    path: *mut c_char,
+    size: c_int,
+    retrieved: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_IsValid(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+) -> FMOD_BOOL {
+    0
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_GetSystem(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    system: *mut *mut FMOD_STUDIO_SYSTEM,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_GetLength(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    length: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_GetCommandCount(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_GetCommandInfo(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    commandindex: c_int,
+    info: *mut FMOD_STUDIO_COMMAND_INFO,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_GetCommandString(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    commandindex: c_int,
+    buffer: *mut c_char,
+    length: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_GetCommandAtTime(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    time: c_float,
+    commandindex: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_SetBankPath(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    bankPath: *const c_char,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_Start(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_Stop(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_SeekToTime(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    time: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_SeekToCommand(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    commandindex: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_GetPaused(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    paused: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_SetPaused(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    paused: FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_GetPlaybackState(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    state: *mut FMOD_STUDIO_PLAYBACK_STATE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_GetCurrentCommand(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    commandindex: *mut c_int,
+    currenttime: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_Release(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_SetFrameCallback(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    callback: FMOD_STUDIO_COMMANDREPLAY_FRAME_CALLBACK,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_SetLoadBankCallback(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    callback: FMOD_STUDIO_COMMANDREPLAY_LOAD_BANK_CALLBACK,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_SetCreateInstanceCallback(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    callback: FMOD_STUDIO_COMMANDREPLAY_CREATE_INSTANCE_CALLBACK,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_GetUserData(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Studio_CommandReplay_SetUserData(
+    replay: *mut FMOD_STUDIO_COMMANDREPLAY,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Memory_Initialize(
+    poolmem: *mut c_void,
+    poollen: c_int,
+    useralloc: FMOD_MEMORY_ALLOC_CALLBACK,
+    userrealloc: FMOD_MEMORY_REALLOC_CALLBACK,
+    userfree: FMOD_MEMORY_FREE_CALLBACK,
+    memtypeflags: FMOD_MEMORY_TYPE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Memory_GetStats(
+    currentalloced: *mut c_int,
+    maxalloced: *mut c_int,
+    blocking: FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Debug_Initialize(
+    flags: FMOD_DEBUG_FLAGS,
+    mode: FMOD_DEBUG_MODE,
+    callback: FMOD_DEBUG_CALLBACK,
+    filename: *const c_char,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_File_SetDiskBusy(busy: c_int) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_File_GetDiskBusy(busy: *mut c_int) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Thread_SetAttributes(
+    type_: FMOD_THREAD_TYPE,
+    affinity: FMOD_THREAD_AFFINITY,
+    priority: FMOD_THREAD_PRIORITY,
+    stacksize: FMOD_THREAD_STACK_SIZE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_SetSoftwareChannels(
+    system: *mut FMOD_SYSTEM,
+    numsoftwarechannels: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetSoftwareChannels(
+    system: *mut FMOD_SYSTEM,
+    numsoftwarechannels: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_SetFileSystem(
+    system: *mut FMOD_SYSTEM,
+    useropen: FMOD_FILE_OPEN_CALLBACK,
+    userclose: FMOD_FILE_CLOSE_CALLBACK,
+    userread: FMOD_FILE_READ_CALLBACK,
+    userseek: FMOD_FILE_SEEK_CALLBACK,
+    userasyncread: FMOD_FILE_ASYNCREAD_CALLBACK,
+    userasynccancel: FMOD_FILE_ASYNCCANCEL_CALLBACK,
+    blockalign: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_AttachFileSystem(
+    system: *mut FMOD_SYSTEM,
+    useropen: FMOD_FILE_OPEN_CALLBACK,
+    userclose: FMOD_FILE_CLOSE_CALLBACK,
+    userread: FMOD_FILE_READ_CALLBACK,
+    userseek: FMOD_FILE_SEEK_CALLBACK,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_SetAdvancedSettings(
+    system: *mut FMOD_SYSTEM,
+    settings: *mut FMOD_ADVANCEDSETTINGS,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetAdvancedSettings(
+    system: *mut FMOD_SYSTEM,
+    settings: *mut FMOD_ADVANCEDSETTINGS,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_SetPluginPath(
+    system: *mut FMOD_SYSTEM,
+    path: *const c_char,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_LoadPlugin(
+    system: *mut FMOD_SYSTEM,
+    filename: *const c_char,
+    handle: *mut c_uint,
+    priority: c_uint,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_UnloadPlugin(system: *mut FMOD_SYSTEM, handle: c_uint) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetNumNestedPlugins(
+    system: *mut FMOD_SYSTEM,
+    handle: c_uint,
+    count: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetNestedPlugin(
+    system: *mut FMOD_SYSTEM,
+    handle: c_uint,
+    index: c_int,
+    nestedhandle: *mut c_uint,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetNumPlugins(
+    system: *mut FMOD_SYSTEM,
+    plugintype: FMOD_PLUGINTYPE,
+    numplugins: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetPluginHandle(
+    system: *mut FMOD_SYSTEM,
+    plugintype: FMOD_PLUGINTYPE,
+    index: c_int,
+    handle: *mut c_uint,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetPluginInfo(
+    system: *mut FMOD_SYSTEM,
+    handle: c_uint,
+    plugintype: *mut FMOD_PLUGINTYPE,
+    name: *mut c_char,
+    namelen: c_int,
+    version: *mut c_uint,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_CreateDSPByPlugin(
+    system: *mut FMOD_SYSTEM,
+    handle: c_uint,
+    dsp: *mut *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetDSPInfoByPlugin(
+    system: *mut FMOD_SYSTEM,
+    handle: c_uint,
+    description: *const *const FMOD_DSP_DESCRIPTION,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_RegisterDSP(
+    system: *mut FMOD_SYSTEM,
+    description: *const FMOD_DSP_DESCRIPTION,
+    handle: *mut c_uint,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_SetSpeakerPosition(
+    system: *mut FMOD_SYSTEM,
+    speaker: FMOD_SPEAKER,
+    x: c_float,
+    y: c_float,
+    active: FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetSpeakerPosition(
+    system: *mut FMOD_SYSTEM,
+    speaker: FMOD_SPEAKER,
+    x: *mut c_float,
+    y: *mut c_float,
+    active: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_SetStreamBufferSize(
+    system: *mut FMOD_SYSTEM,
+    filebuffersize: c_uint,
+    filebuffersizetype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetStreamBufferSize(
+    system: *mut FMOD_SYSTEM,
+    filebuffersize: *mut c_uint,
+    filebuffersizetype: *mut FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_Set3DSettings(
+    system: *mut FMOD_SYSTEM,
+    dopplerscale: c_float,
+    distancefactor: c_float,
+    rolloffscale: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_Get3DSettings(
+    system: *mut FMOD_SYSTEM,
+    dopplerscale: *mut c_float,
+    distancefactor: *mut c_float,
+    rolloffscale: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_Set3DNumListeners(
+    system: *mut FMOD_SYSTEM,
+    numlisteners: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_Get3DNumListeners(
+    system: *mut FMOD_SYSTEM,
+    numlisteners: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_Set3DRolloffCallback(
+    system: *mut FMOD_SYSTEM,
+    callback: FMOD_3D_ROLLOFF_CALLBACK,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetOutputHandle(
+    system: *mut FMOD_SYSTEM,
+    handle: *mut *mut c_void,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetCPUUsage(
+    system: *mut FMOD_SYSTEM,
+    usage: *mut FMOD_CPU_USAGE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetFileUsage(
+    system: *mut FMOD_SYSTEM,
+    sampleBytesRead: *mut c_longlong,
+    streamBytesRead: *mut c_longlong,
+    otherBytesRead: *mut c_longlong,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_CreateDSP(
+    system: *mut FMOD_SYSTEM,
+    description: *const FMOD_DSP_DESCRIPTION,
+    dsp: *mut *mut FMOD_DSP,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_CreateSoundGroup(
+    system: *mut FMOD_SYSTEM,
+    name: *const c_char,
+    soundgroup: *mut *mut FMOD_SOUNDGROUP,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_PlayDSP(
+    system: *mut FMOD_SYSTEM,
+    dsp: *mut FMOD_DSP,
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    paused: FMOD_BOOL,
+    channel: *mut *mut FMOD_CHANNEL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetDSPInfoByType(
+    system: *mut FMOD_SYSTEM,
+    type_: FMOD_DSP_TYPE,
+    description: *const *const FMOD_DSP_DESCRIPTION,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetMasterSoundGroup(
+    system: *mut FMOD_SYSTEM,
+    soundgroup: *mut *mut FMOD_SOUNDGROUP,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_AttachChannelGroupToPort(
+    system: *mut FMOD_SYSTEM,
+    portType: FMOD_PORT_TYPE,
+    portIndex: FMOD_PORT_INDEX,
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    passThru: FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_DetachChannelGroupFromPort(
+    system: *mut FMOD_SYSTEM,
+    channelgroup: *mut FMOD_CHANNELGROUP,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_LockDSP(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_UnlockDSP(system: *mut FMOD_SYSTEM) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_SetGeometrySettings(
+    system: *mut FMOD_SYSTEM,
+    maxworldsize: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetGeometrySettings(
+    system: *mut FMOD_SYSTEM,
+    maxworldsize: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_SetNetworkProxy(
+    system: *mut FMOD_SYSTEM,
+    proxy: *const c_char,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetNetworkProxy(
+    system: *mut FMOD_SYSTEM,
+    proxy: *mut c_char,
+    proxylen: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_SetNetworkTimeout(
+    system: *mut FMOD_SYSTEM,
+    timeout: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_System_GetNetworkTimeout(
+    system: *mut FMOD_SYSTEM,
+    timeout: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_Set3DMinMaxDistance(
+    sound: *mut FMOD_SOUND,
+    min: c_float,
+    max: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_Get3DMinMaxDistance(
+    sound: *mut FMOD_SOUND,
+    min: *mut c_float,
+    max: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_Set3DConeSettings(
+    sound: *mut FMOD_SOUND,
+    insideconeangle: c_float,
+    outsideconeangle: c_float,
+    outsidevolume: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_Get3DConeSettings(
+    sound: *mut FMOD_SOUND,
+    insideconeangle: *mut c_float,
+    outsideconeangle: *mut c_float,
+    outsidevolume: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_Set3DCustomRolloff(
+    sound: *mut FMOD_SOUND,
+    points: *mut FMOD_VECTOR,
+    numpoints: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_Get3DCustomRolloff(
+    sound: *mut FMOD_SOUND,
+    points: *mut *mut FMOD_VECTOR,
+    numpoints: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetSubSound(
+    sound: *mut FMOD_SOUND,
+    index: c_int,
+    subsound: *mut *mut FMOD_SOUND,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetSubSoundParent(
+    sound: *mut FMOD_SOUND,
+    parentsound: *mut *mut FMOD_SOUND,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetNumSubSounds(
+    sound: *mut FMOD_SOUND,
+    numsubsounds: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetNumTags(
+    sound: *mut FMOD_SOUND,
+    numtags: *mut c_int,
+    numtagsupdated: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetTag(
+    sound: *mut FMOD_SOUND,
+    name: *const c_char,
+    index: c_int,
+    tag: *mut FMOD_TAG,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetOpenState(
+    sound: *mut FMOD_SOUND,
+    openstate: *mut FMOD_OPENSTATE,
+    percentbuffered: *mut c_uint,
+    starving: *mut FMOD_BOOL,
+    diskbusy: *mut FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_SetSoundGroup(
+    sound: *mut FMOD_SOUND,
+    soundgroup: *mut FMOD_SOUNDGROUP,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetSoundGroup(
+    sound: *mut FMOD_SOUND,
+    soundgroup: *mut *mut FMOD_SOUNDGROUP,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetNumSyncPoints(
+    sound: *mut FMOD_SOUND,
+    numsyncpoints: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetSyncPoint(
+    sound: *mut FMOD_SOUND,
+    index: c_int,
+    point: *mut *mut FMOD_SYNCPOINT,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetSyncPointInfo(
+    sound: *mut FMOD_SOUND,
+    point: *mut FMOD_SYNCPOINT,
+    name: *mut c_char,
+    namelen: c_int,
+    offset: *mut c_uint,
+    offsettype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_AddSyncPoint(
+    sound: *mut FMOD_SOUND,
+    offset: c_uint,
+    offsettype: FMOD_TIMEUNIT,
+    name: *const c_char,
+    point: *mut *mut FMOD_SYNCPOINT,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_DeleteSyncPoint(
+    sound: *mut FMOD_SOUND,
+    point: *mut FMOD_SYNCPOINT,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_SetLoopPoints(
+    sound: *mut FMOD_SOUND,
+    loopstart: c_uint,
+    loopstarttype: FMOD_TIMEUNIT,
+    loopend: c_uint,
+    loopendtype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetLoopPoints(
+    sound: *mut FMOD_SOUND,
+    loopstart: *mut c_uint,
+    loopstarttype: FMOD_TIMEUNIT,
+    loopend: *mut c_uint,
+    loopendtype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetMusicNumChannels(
+    sound: *mut FMOD_SOUND,
+    numchannels: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_SetMusicChannelVolume(
+    sound: *mut FMOD_SOUND,
+    channel: c_int,
+    volume: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetMusicChannelVolume(
+    sound: *mut FMOD_SOUND,
+    channel: c_int,
+    volume: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_SetMusicSpeed(sound: *mut FMOD_SOUND, speed: c_float) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Sound_GetMusicSpeed(sound: *mut FMOD_SOUND, speed: *mut c_float) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_SetCallback(
+    channel: *mut FMOD_CHANNEL,
+    callback: FMOD_CHANNELCONTROL_CALLBACK,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_SetPan(channel: *mut FMOD_CHANNEL, pan: c_float) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Set3DConeSettings(
+    channel: *mut FMOD_CHANNEL,
+    insideconeangle: c_float,
+    outsideconeangle: c_float,
+    outsidevolume: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Get3DConeSettings(
+    channel: *mut FMOD_CHANNEL,
+    insideconeangle: *mut c_float,
+    outsideconeangle: *mut c_float,
+    outsidevolume: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Set3DConeOrientation(
+    channel: *mut FMOD_CHANNEL,
+    orientation: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Get3DConeOrientation(
+    channel: *mut FMOD_CHANNEL,
+    orientation: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Set3DCustomRolloff(
+    channel: *mut FMOD_CHANNEL,
+    points: *mut FMOD_VECTOR,
+    numpoints: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Get3DCustomRolloff(
+    channel: *mut FMOD_CHANNEL,
+    points: *mut *mut FMOD_VECTOR,
+    numpoints: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Set3DSpread(channel: *mut FMOD_CHANNEL, angle: c_float) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Get3DSpread(
+    channel: *mut FMOD_CHANNEL,
+    angle: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Set3DLevel(channel: *mut FMOD_CHANNEL, level: c_float) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Get3DLevel(
+    channel: *mut FMOD_CHANNEL,
+    level: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Set3DDopplerLevel(
+    channel: *mut FMOD_CHANNEL,
+    level: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Get3DDopplerLevel(
+    channel: *mut FMOD_CHANNEL,
+    level: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Set3DDistanceFilter(
+    channel: *mut FMOD_CHANNEL,
+    custom: FMOD_BOOL,
+    customLevel: c_float,
+    centerFreq: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_Get3DDistanceFilter(
+    channel: *mut FMOD_CHANNEL,
+    custom: *mut FMOD_BOOL,
+    customLevel: *mut c_float,
+    centerFreq: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_SetLoopPoints(
+    channel: *mut FMOD_CHANNEL,
+    loopstart: c_uint,
+    loopstarttype: FMOD_TIMEUNIT,
+    loopend: c_uint,
+    loopendtype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_Channel_GetLoopPoints(
+    channel: *mut FMOD_CHANNEL,
+    loopstart: *mut c_uint,
+    loopstarttype: FMOD_TIMEUNIT,
+    loopend: *mut c_uint,
+    loopendtype: FMOD_TIMEUNIT,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_SetCallback(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    callback: FMOD_CHANNELCONTROL_CALLBACK,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_SetPan(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    pan: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Set3DConeSettings(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    insideconeangle: c_float,
+    outsideconeangle: c_float,
+    outsidevolume: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Get3DConeSettings(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    insideconeangle: *mut c_float,
+    outsideconeangle: *mut c_float,
+    outsidevolume: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Set3DConeOrientation(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    orientation: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Get3DConeOrientation(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    orientation: *mut FMOD_VECTOR,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Set3DCustomRolloff(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    points: *mut FMOD_VECTOR,
+    numpoints: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Get3DCustomRolloff(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    points: *mut *mut FMOD_VECTOR,
+    numpoints: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Set3DSpread(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    angle: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Get3DSpread(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    angle: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Set3DLevel(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    level: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Get3DLevel(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    level: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Set3DDopplerLevel(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    level: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Get3DDopplerLevel(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    level: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Set3DDistanceFilter(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    custom: FMOD_BOOL,
+    customLevel: c_float,
+    centerFreq: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_ChannelGroup_Get3DDistanceFilter(
+    channelgroup: *mut FMOD_CHANNELGROUP,
+    custom: *mut FMOD_BOOL,
+    customLevel: *mut c_float,
+    centerFreq: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_Release(soundgroup: *mut FMOD_SOUNDGROUP) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_GetSystemObject(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    system: *mut *mut FMOD_SYSTEM,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_SetMaxAudible(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    maxaudible: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_GetMaxAudible(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    maxaudible: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_SetMaxAudibleBehavior(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    behavior: FMOD_SOUNDGROUP_BEHAVIOR,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_GetMaxAudibleBehavior(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    behavior: *mut FMOD_SOUNDGROUP_BEHAVIOR,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_SetMuteFadeSpeed(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    speed: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_GetMuteFadeSpeed(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    speed: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_SetVolume(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    volume: c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_GetVolume(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    volume: *mut c_float,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_Stop(soundgroup: *mut FMOD_SOUNDGROUP) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_GetName(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    name: *mut c_char,
+    namelen: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_GetNumSounds(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    numsounds: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_GetSound(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    index: c_int,
+    sound: *mut *mut FMOD_SOUND,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_GetNumPlaying(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    numplaying: *mut c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_SetUserData(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    userdata: *mut c_void,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_SoundGroup_GetUserData(
+    soundgroup: *mut FMOD_SOUNDGROUP,
+    userdata: *mut *mut c_void,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_DSP_GetOutputChannelFormat(
+    dsp: *mut FMOD_DSP,
+    inmask: FMOD_CHANNELMASK,
+    inchannels: c_int,
+    inspeakermode: FMOD_SPEAKERMODE,
+    outmask: *mut FMOD_CHANNELMASK,
+    outchannels: *mut c_int,
+    outspeakermode: *mut FMOD_SPEAKERMODE,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_DSP_SetCallback(dsp: *mut FMOD_DSP, callback: FMOD_DSP_CALLBACK) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_DSP_ShowConfigDialog(
+    dsp: *mut FMOD_DSP,
+    hwnd: *mut c_void,
+    show: FMOD_BOOL,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_DSP_GetCPUUsage(
+    dsp: *mut FMOD_DSP,
+    exclusive: *mut c_uint,
+    inclusive: *mut c_uint,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_DSPConnection_SetMixMatrix(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    matrix: *mut c_float,
+    outchannels: c_int,
+    inchannels: c_int,
+    inchannel_hop: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
+
+pub unsafe fn FMOD_DSPConnection_GetMixMatrix(
+    dspconnection: *mut FMOD_DSPCONNECTION,
+    matrix: *mut c_float,
+    outchannels: *mut c_int,
+    inchannels: *mut c_int,
+    inchannel_hop: c_int,
+) -> FMOD_RESULT {
+    FMOD_ERR_UNSUPPORTED
+}
@@ -0,0 +1,162 @@
+use std::ffi::{c_uint, c_void, CString};
+use std::path::Path;
+
+use crate::{errors, ffi, Error, OutputType, Sound, SoundFormat, System};
+
+/// RIFF header of WAV file with PCM (or IEEE float) data of given size in bytes.
+pub(crate) fn wav_header(
+    channels: u16,
+    sample_rate: u32,
+    bits: u16,
+    float: bool,
+    data: u32,
+) -> Vec<u8> {
+    let block_align = channels * bits / 8;
+    let mut header = Vec::with_capacity(44);
+    header.extend_from_slice(b"RIFF");
+    header.extend_from_slice(&(36 + data).to_le_bytes());
+    header.extend_from_slice(b"WAVEfmt ");
+    header.extend_from_slice(&16u32.to_le_bytes());
+    header.extend_from_slice(&(if float { 3u16 } else { 1u16 }).to_le_bytes());
+    header.extend_from_slice(&channels.to_le_bytes());
+    header.extend_from_slice(&sample_rate.to_le_bytes());
+    header.extend_from_slice(&(sample_rate * block_align as u32).to_le_bytes());
+    header.extend_from_slice(&block_align.to_le_bytes());
+    header.extend_from_slice(&bits.to_le_bytes());
+    header.extend_from_slice(b"data");
+    header.extend_from_slice(&data.to_le_bytes());
+    header
+}
+
+impl Sound {
+    /// Decodes whole sound with `read_data` and writes it to WAV file, float sounds
+    /// are written as 32-bit float and other PCM formats as 16-bit PCM.
+    ///
+    /// Sound should be opened with `Mode::OPENONLY` (or be a stream) to be read.
+    pub fn export_wav(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let (_, format, channels, bits) = self.get_format()?;
+        let (frequency, _) = self.get_defaults()?;
+        let float = match format {
+            SoundFormat::PcmFloat => true,
+            SoundFormat::Pcm8 | SoundFormat::Pcm16 | SoundFormat::Pcm24 | SoundFormat::Pcm32 => {
+                false
+            }
+            format => {
+                return Err(err_validation!(
+                    "Sound::export_wav",
+                    "{:?} format can't be written to WAV",
+                    format
+                ))
+            }
+        };
+        let sample_size = (bits / 8) as usize;
+        let frame_size = sample_size * channels.max(1) as usize;
+        self.seek_data(0)?;
+        let mut data = vec![];
+        let mut buffer = vec![0u8; frame_size * 4096];
+        loop {
+            let mut read: c_uint = 0;
+            // the last block is returned together with FMOD_ERR_FILE_EOF
+            let result = unsafe {
+                ffi::FMOD_Sound_ReadData(
+                    self.as_mut_ptr(),
+                    buffer.as_mut_ptr() as *mut c_void,
+                    buffer.len() as c_uint,
+                    &mut read,
+                )
+            };
+            data.extend_from_slice(&buffer[..read as usize]);
+            match result {
+                ffi::FMOD_OK if read > 0 => continue,
+                ffi::FMOD_OK | ffi::FMOD_ERR_FILE_EOF => break,
+                error => return Err(err_fmod!("FMOD_Sound_ReadData", error)),
+            }
+        }
+        let data = if float {
+            data
+        } else {
+            data.chunks_exact(sample_size)
+                .flat_map(|sample| pcm16(sample).to_le_bytes())
+                .collect()
+        };
+        let bits = if float { 32 } else { 16 };
+        let mut file = wav_header(
+            channels as u16,
+            frequency as u32,
+            bits,
+            float,
+            data.len() as u32,
+        );
+        file.extend_from_slice(&data);
+        std::fs::write(path, file)?;
+        Ok(())
+    }
+}
+
+/// Converts little endian integer sample to 16 bits, 8-bit samples are unsigned.
+fn pcm16(sample: &[u8]) -> i16 {
+    match sample.len() {
+        1 => (sample[0] as i16 - 128) << 8,
+        size => i16::from_le_bytes([sample[size - 2], sample[size - 1]]),
+    }
+}
+
+impl System {
+    /// Selects WAV writer output and initializes the system, the mix is written to file at path.
+    ///
+    /// `OutputType::WavWriterNrt` mixes one block on every `System::update`, so a capture
+    /// is produced faster than real time. The file is complete after the system is closed.
+    pub fn init_with_wav_writer(
+        &self,
+        maxchannels: i32,
+        flags: impl Into<ffi::FMOD_INITFLAGS>,
+        output: OutputType,
+        path: impl AsRef<Path>,
+    ) -> Result<(), Error> {
+        if !matches!(output, OutputType::WavWriter | OutputType::WavWriterNrt) {
+            return Err(err_validation!(
+                "System::init_with_wav_writer",
+                "{:?} is not WAV writer output",
+                output
+            ));
+        }
+        let path = CString::new(path.as_ref().to_string_lossy().as_bytes())?;
+        self.set_output(output)?;
+        self.init(maxchannels, flags, Some(path.as_ptr() as *mut c_void))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pcm16, wav_header};
+
+    #[test]
+    fn test_wav_header_of_pcm16() {
+        let header = wav_header(2, 44100, 16, false, 1000);
+        assert_eq!(header.len(), 44);
+        assert_eq!(&header[0..4], b"RIFF");
+        assert_eq!(header[4..8], 1036u32.to_le_bytes());
+        assert_eq!(header[20..22], 1u16.to_le_bytes());
+        assert_eq!(header[28..32], (44100u32 * 4).to_le_bytes());
+        assert_eq!(header[32..34], 4u16.to_le_bytes());
+        assert_eq!(&header[36..40], b"data");
+        assert_eq!(header[40..44], 1000u32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_wav_header_of_float() {
+        let header = wav_header(1, 48000, 32, true, 0);
+        assert_eq!(header[20..22], 3u16.to_le_bytes());
+        assert_eq!(header[34..36], 32u16.to_le_bytes());
+    }
+
+    #[test]
+    fn test_pcm16_conversion() {
+        assert_eq!(pcm16(&[0x80]), 0);
+        assert_eq!(pcm16(&[0x00]), i16::MIN);
+        assert_eq!(pcm16(&[0x34, 0x12]), 0x1234);
+        // 24 and 32 bit samples keep the most significant bytes
+        assert_eq!(pcm16(&[0xff, 0x34, 0x12]), 0x1234);
+        assert_eq!(pcm16(&[0xff, 0xff, 0x34, 0x12]), 0x1234);
+    }
+}
@@ -0,0 +1,15 @@
+#![cfg(feature = "dynamic")]
+
+use libfmod::Error;
+
+#[test]
+fn test_load_from_missing_directory() {
+    let result = libfmod::load("./tests/data/missing");
+    assert!(matches!(result, Err(Error::Library(_))));
+}
+
+#[test]
+fn test_call_before_load() {
+    let result = libfmod::System::create();
+    assert!(matches!(result, Err(Error::Library(_))));
+}
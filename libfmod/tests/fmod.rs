@@ -1,2 +1,4 @@
+#![cfg(not(feature = "stub"))]
+
 mod examples;
 mod manual;
@@ -1,7 +1,11 @@
 use libfmod::ffi::{
-    FMOD_CHANNELCONTROL_DSP_HEAD, FMOD_CHANNELCONTROL_DSP_TAIL, FMOD_DEFAULT, FMOD_INIT_NORMAL,
+    FMOD_CHANNELCONTROL_DSP_HEAD, FMOD_CHANNELCONTROL_DSP_TAIL, FMOD_DEFAULT,
+    FMOD_DSP_HIGHPASS_CUTOFF, FMOD_INIT_NORMAL,
+};
+use libfmod::{
+    ChannelControlDspIndex, DspChain, DspConnectionType, DspParameterFft, DspParameterValue,
+    DspType, Error, FftAnalyzer, Meter, MixMatrix, SpeakerLevels, System,
 };
-use libfmod::{DspConnectionType, DspParameterFft, DspType, Error, System};
 
 /// This unit produces an echo on the sound and fades out at the desired rate.
 #[test]
@@ -120,12 +124,98 @@ fn test_output_pan_matrix() -> Result<(), Error> {
     let channel = system.play_sound(sound, None, true)?;
     let head = channel.get_dsp(FMOD_CHANNELCONTROL_DSP_HEAD)?;
 
-    let mut matrix = [
-        0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0,
-    ];
+    let matrix = MixMatrix::from_vec(
+        4,
+        4,
+        vec![
+            0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0,
+        ],
+    )?;
 
     let (_, connection) = head.get_output(0)?;
-    connection.set_mix_matrix(Some(matrix.as_mut_ptr()), 4, 4, None)?;
-    // get mix matrix TODO
+    connection.set_mix_matrix(&matrix)?;
+    assert_eq!(connection.get_mix_matrix()?, matrix);
+    system.release()
+}
+
+#[test]
+fn test_speaker_levels_of_channel() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(512, FMOD_INIT_NORMAL, None)?;
+
+    let sound = system.create_sound("./tests/data/Assets/1.ogg", FMOD_DEFAULT, None)?;
+    let channel = system.play_sound(sound, None, true)?;
+    let (_, speaker_mode, _) = system.get_software_format()?;
+    let left = SpeakerLevels::new().front_left(1.0);
+    left.validate(speaker_mode)?;
+    channel.set_mix_levels_output(&left)?;
+    let matrix = channel.get_mix_matrix()?;
+    assert_eq!(matrix.get(0, 0), 1.0);
+    assert_eq!(matrix.get(1, 0), 0.0);
+
+    system.release()
+}
+
+#[test]
+fn test_dsp_chain_of_channel_group() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(512, FMOD_INIT_NORMAL, None)?;
+
+    let group = system.create_channel_group(None)?;
+    let dsps = group.get_num_ds_ps()?;
+    let chain = DspChain::new(&system)
+        .then(DspType::Highpass)
+        .float(FMOD_DSP_HIGHPASS_CUTOFF, 500.0)
+        .then(DspType::Echo)
+        .attach(&group, ChannelControlDspIndex::Head)?;
+    assert_eq!(group.get_num_ds_ps()?, dsps + 2);
+    let cutoff = chain.dsps()[0].parameter(FMOD_DSP_HIGHPASS_CUTOFF)?;
+    assert!(matches!(cutoff.value, Some(DspParameterValue::Float(value)) if value == 500.0));
+    chain.release()?;
+    assert_eq!(group.get_num_ds_ps()?, dsps);
+
+    system.release()
+}
+
+#[test]
+fn test_dsp_parameters_of_builtin_effect() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(512, FMOD_INIT_NORMAL, None)?;
+
+    let echo = system.create_dsp_by_type(DspType::Echo)?;
+    let parameters = echo.parameters()?;
+    assert_eq!(parameters.len() as i32, echo.get_num_parameters()?);
+    let delay = echo.parameter_index("Delay")?;
+    echo.set_by_name("Delay", 250.0)?;
+    assert!(
+        matches!(echo.parameter(delay)?.value, Some(DspParameterValue::Float(value)) if value == 250.0)
+    );
+
+    system.release()
+}
+
+#[test]
+fn test_meter_and_fft_analyzer_of_playing_sound() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(512, FMOD_INIT_NORMAL, None)?;
+
+    let master = system.get_master_channel_group()?;
+    let mut meter = Meter::attach(&master)?;
+    let analyzer = FftAnalyzer::attach(&master)?;
+    let sound = system.create_sound("./tests/data/Assets/2.ogg", FMOD_DEFAULT, None)?;
+    let channel = system.play_sound(sound, None, false)?;
+    let mut loudest = f32::NEG_INFINITY;
+    let mut dominant = None;
+    while channel.is_playing()? {
+        system.update()?;
+        for level in meter.levels()? {
+            loudest = loudest.max(level.peak);
+        }
+        dominant = dominant.or(analyzer.spectrum()?.dominant_frequency());
+    }
+    assert!(loudest > -60.0 && loudest <= 0.0);
+    let spectrum = analyzer.spectrum()?;
+    assert!(dominant.is_some_and(|frequency| frequency < spectrum.rate as f32 / 2.0));
+
     system.release()
 }
@@ -41,6 +41,7 @@ fn test_core_system_advanced_settings() -> Result<(), Error> {
         random_seed: 0,
         max_convolution_threads: 3,
         max_opus_codecs: 32,
+        max_spatial_objects: 0,
     };
     system.set_advanced_settings(settings)?;
     let settings = system.get_advanced_settings()?;
@@ -171,3 +172,22 @@ fn test_system_pre_update_callback() -> Result<(), Error> {
     system.set_callback(Some(callback), FMOD_SYSTEM_CALLBACK_PREUPDATE)?;
     system.release()
 }
+
+#[test]
+fn test_output_and_record_drivers() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(512, FMOD_INIT_NORMAL, None)?;
+
+    let drivers = system.drivers()?;
+    assert_eq!(drivers.len() as i32, system.get_num_drivers()?);
+    if let Some(driver) = drivers.first() {
+        assert_eq!(system.set_driver_by_guid(&driver.guid)?, 0);
+    }
+    let record_drivers = system.record_drivers()?;
+    assert_eq!(
+        record_drivers.len() as i32,
+        system.get_record_num_drivers()?.0
+    );
+
+    system.release()
+}
@@ -1,5 +1,7 @@
 use libfmod::ffi::{FMOD_3D, FMOD_3D_LINEARROLLOFF, FMOD_DEFAULT, FMOD_INIT_NORMAL};
-use libfmod::{Error, System, Vector};
+use libfmod::{
+    Emitter, Error, MeshMaterial, ObjImporter, Occlusion, OcclusionQuery, System, Vector,
+};
 
 #[test]
 fn test_3d_sound() -> Result<(), Error> {
@@ -131,3 +133,42 @@ fn test_channel_custom_rolloff() -> Result<(), Error> {
     assert_eq!(curve, rolloff, "rolloff");
     system.release()
 }
+
+const WALL_OBJ: &str = "
+v -5 -5 5
+v 5 -5 5
+v 5 5 5
+v -5 5 5
+g wall
+usemtl concrete
+f 1 2 3 4
+";
+
+#[test]
+fn test_occlusion_by_imported_geometry() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(512, FMOD_INIT_NORMAL, None)?;
+
+    let geometry = ObjImporter::new()
+        .material("concrete", MeshMaterial::new(1.0, 0.8))
+        .load(&system, WALL_OBJ)?;
+    assert_eq!(geometry.get_num_polygons()?, 1);
+    let listener = Vector::new(0.0, 0.0, 0.0);
+    let emitters = [
+        Emitter::new(Vector::new(0.0, 0.0, 10.0)),
+        Emitter::new(Vector::new(0.0, 0.0, -10.0)),
+    ];
+    let results = OcclusionQuery::new(system).run(listener, &emitters)?;
+    assert_eq!(
+        results,
+        [
+            Occlusion {
+                direct: 1.0,
+                reverb: 0.8
+            },
+            Occlusion::default()
+        ]
+    );
+
+    system.release()
+}
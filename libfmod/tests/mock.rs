@@ -0,0 +1,75 @@
+#![cfg(feature = "mock")]
+
+use libfmod::mock::{MockEventInstance, MockSystem};
+use libfmod::{Error, EventInstanceApi, PlaybackState, Speaker, StopMode, SystemApi};
+
+fn restart(instance: &impl EventInstanceApi) -> Result<(), Error> {
+    if instance.get_playback_state()? != PlaybackState::Stopped {
+        instance.stop(StopMode::Immediate)?;
+    }
+    instance.set_timeline_position(0)?;
+    instance.start()
+}
+
+#[test]
+fn test_call_sequence_is_recorded() -> Result<(), Error> {
+    let instance = MockEventInstance::new();
+    instance.get_playback_state_returns(Ok(PlaybackState::Playing));
+    restart(&instance)?;
+    instance.assert_calls(&[
+        "get_playback_state",
+        "stop(Immediate)",
+        "set_timeline_position(0)",
+        "start",
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_call_arguments_are_recorded() {
+    let system = MockSystem::new();
+    let _ = system.set_speaker_position(Speaker::FrontLeft, -0.5, 1.0, true);
+    let calls = system.calls();
+    assert_eq!(calls[0].method, "set_speaker_position");
+    assert_eq!(calls[0].arguments, ["FrontLeft", "-0.5", "1.0", "true"]);
+    system.assert_calls(&["set_speaker_position(FrontLeft, -0.5, 1.0, true)"]);
+}
+
+#[test]
+#[should_panic(expected = "unexpected call sequence")]
+fn test_call_arguments_mismatch() {
+    let instance = MockEventInstance::new();
+    let _ = instance.set_timeline_position(100);
+    instance.assert_calls(&["set_timeline_position(0)"]);
+}
+
+#[test]
+fn test_scripted_values_are_returned_in_order() -> Result<(), Error> {
+    let system = MockSystem::new();
+    system
+        .get_cpu_usage_returns(Err(Error::NotDspFft))
+        .get_channels_playing_returns(Ok((3, 2)))
+        .get_channels_playing_returns(Ok((0, 0)));
+    assert!(matches!(system.get_cpu_usage(), Err(Error::NotDspFft)));
+    assert_eq!(system.get_channels_playing()?, (3, 2));
+    assert_eq!(system.get_channels_playing()?, (0, 0));
+    system.reset();
+    assert!(system.calls().is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_scripted_error_is_propagated() {
+    let instance = MockEventInstance::new();
+    instance.get_playback_state_returns(Ok(PlaybackState::Stopped));
+    instance.start_returns(Err(Error::NotDspFft));
+    assert!(restart(&instance).is_err());
+    instance.assert_calls(&["get_playback_state", "set_timeline_position", "start"]);
+}
+
+#[test]
+#[should_panic(expected = "get_playback_state is called, but return value is not scripted")]
+fn test_unscripted_value() {
+    let instance = MockEventInstance::new();
+    let _ = restart(&instance);
+}
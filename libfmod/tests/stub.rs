@@ -0,0 +1,1490 @@
+#![cfg(feature = "stub")]
+
+use std::ffi::CStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use libfmod::stub::{
+    define_bank, define_devices, BankDefinition, DeviceDefinition, EventDefinition,
+    ParameterDefinition,
+};
+use libfmod::{
+    ffi, Attributes3d, ChannelControl, ChannelControlDspIndex, ChannelControlHandle,
+    ChannelControlType, ChannelOrder, CodecFile, CodecPlugin, CodecWaveformat, CreateSoundexInfo,
+    Dsp, DspChain, DspConnectionType, DspData, DspFftWindow, DspParameterAttenuationRange,
+    DspParameterAttributes3d, DspParameterDataType, DspParameterKind, DspParameterSidechain,
+    DspParameterValue, DspType, Emitter, Error, FftAnalyzer, Init, LoadBank, LoudnessMeter,
+    MeshMaterial, Meter, MixMatrix, Mode, ObjImporter, Occlusion, OcclusionQuery, OfflineRenderer,
+    OutputFormat, OutputPlugin, OutputType, PlaybackState, RecordStream, ReverbProperties,
+    ReverbZones, Scheduler, SoundFormat, SoundType, Speaker, SpeakerLevels, SpeakerMode, StopMode,
+    Studio, StudioInit, System, TimeUnit, Vector,
+};
+
+#[test]
+fn test_sound_playback_until_end() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::DEFAULT, None)?;
+    assert_eq!(sound.get_length(TimeUnit::PCM)?, 22050);
+    assert_eq!(sound.get_length(TimeUnit::MS)?, 500);
+
+    let channel = system.play_sound(sound, None, false)?;
+    for _ in 0..23 {
+        system.update()?;
+    }
+    assert!(channel.is_playing()?);
+    assert_eq!(channel.get_dsp_clock()?.0, 23 * 1024);
+    system.update()?;
+    assert!(matches!(
+        channel.is_playing(),
+        Err(Error::Fmod {
+            code: ffi::FMOD_ERR_INVALID_HANDLE,
+            ..
+        })
+    ));
+    system.release()
+}
+
+#[test]
+fn test_channel_values_round_trip() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::LOOP_NORMAL, None)?;
+    let group = system.create_channel_group(Some("music".to_string()))?;
+    let channel = system.play_sound(sound, Some(group), true)?;
+    channel.set_volume(0.5)?;
+    channel.set_pitch(2.0)?;
+    assert_eq!(channel.get_volume()?, 0.5);
+    assert_eq!(channel.get_pitch()?, 2.0);
+    assert!(channel.get_paused()?);
+    assert_eq!(group.get_num_channels()?, 1);
+    assert_eq!(group.get_name(32)?, "music");
+    assert_eq!(group.get_name(4)?, "mus");
+    assert_eq!(
+        channel.get_channel_group()?.as_mut_ptr(),
+        group.as_mut_ptr()
+    );
+    system.release()
+}
+
+fn fade_out(control: &impl ChannelControl, samples: u64) -> Result<(), Error> {
+    let (_, parent_clock) = control.get_dsp_clock()?;
+    control.add_fade_point(parent_clock + samples, 0.0)?;
+    control.set_delay(None, Some(parent_clock + samples), true)
+}
+
+#[test]
+fn test_channel_control_shared_by_channel_and_group() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::LOOP_NORMAL, None)?;
+    let group = system.create_channel_group(None)?;
+    let channel = system.play_sound(sound, Some(group), false)?;
+    fade_out(&channel, 4096)?;
+    fade_out(&group, 4096)?;
+    assert_eq!(channel.get_fade_points()?, vec![(4096, 0.0)]);
+    assert_eq!(group.get_fade_points()?, vec![(4096, 0.0)]);
+    system.release()
+}
+
+#[test]
+fn test_channel_control_handle_from_pointer() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::LOOP_NORMAL, None)?;
+    let group = system.create_channel_group(None)?;
+    let channel = system.play_sound(sound, Some(group), false)?;
+    let control =
+        ChannelControlHandle::from_raw(channel.as_channel_control(), channel.control_type())?;
+    assert_eq!(
+        control.as_channel().map(|channel| channel.as_mut_ptr()),
+        Some(channel.as_mut_ptr())
+    );
+    assert!(control.as_channel_group().is_none());
+    assert_eq!(
+        group
+            .downcast()?
+            .as_channel_group()
+            .map(|group| group.as_mut_ptr()),
+        Some(group.as_mut_ptr())
+    );
+    let control = ChannelControlHandle::from_raw(
+        group.as_channel_control(),
+        ChannelControlType::ChannelGroup,
+    )?;
+    assert_eq!(control.get_volume()?, 1.0);
+    assert!(
+        ChannelControlHandle::from_raw(group.as_channel_control(), ChannelControlType::Max)
+            .is_err()
+    );
+    system.release()
+}
+
+#[test]
+fn test_mix_matrix_round_trip() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::DEFAULT, None)?;
+    let channel = system.play_sound(sound, None, true)?;
+    assert_eq!(channel.get_mix_matrix()?, MixMatrix::identity(2, 2));
+
+    let mut matrix = MixMatrix::from_speaker_modes(SpeakerMode::Stereo, SpeakerMode::Mode5Point1)?;
+    let center = SpeakerMode::Mode5Point1.speaker_index(Speaker::FrontCenter);
+    assert_eq!(center, Some(2));
+    matrix.row_mut(2).copy_from_slice(&[0.5, 0.5]);
+    assert_eq!(matrix.column(0), vec![1.0, 0.0, 0.5, 0.0, 0.0, 0.0]);
+    channel.set_mix_matrix(&matrix)?;
+    assert_eq!(channel.get_mix_matrix()?, matrix);
+    system.release()
+}
+
+#[test]
+fn test_default_mix_matrix() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let upmix = system.get_default_mix_matrix(SpeakerMode::Mono, SpeakerMode::Stereo)?;
+    assert_eq!((upmix.out_channels(), upmix.in_channels()), (2, 1));
+    assert_eq!(upmix.get(0, 0), upmix.get(1, 0));
+    system.release()
+}
+
+#[test]
+fn test_speaker_levels_set_as_output_levels() -> Result<(), Error> {
+    let system = System::create()?;
+    system.set_software_format(Some(48000), Some(SpeakerMode::Mode5Point1), Some(0))?;
+    system.init(32, Init::NORMAL, None)?;
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::DEFAULT, None)?;
+    let channel = system.play_sound(sound, None, true)?;
+    let stinger = SpeakerLevels::new().front_center(1.0).low_frequency(0.5);
+    channel.set_mix_levels_output(&stinger)?;
+    assert_eq!(
+        channel.get_mix_matrix()?.column(0),
+        vec![0.0, 0.0, 1.0, 0.5, 0.0, 0.0]
+    );
+    system.release()
+}
+
+#[test]
+fn test_speaker_levels_missing_in_software_speaker_mode() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::DEFAULT, None)?;
+    let channel = system.play_sound(sound, None, true)?;
+    let stinger = SpeakerLevels::new().front_center(1.0).low_frequency(0.5);
+    let result = channel.set_mix_levels_output(&stinger);
+    assert!(matches!(
+        result,
+        Err(Error::Validation { function, .. }) if function == "SpeakerLevels::validate"
+    ));
+    system.release()
+}
+
+#[test]
+fn test_mix_levels_input_validated() -> Result<(), Error> {
+    let system = System::create()?;
+    system.set_software_format(Some(48000), Some(SpeakerMode::Stereo), Some(0))?;
+    system.init(32, Init::NORMAL, None)?;
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::DEFAULT, None)?;
+    let channel = system.play_sound(sound, None, true)?;
+    channel.set_mix_levels_input(&[1.0, 0.5])?;
+    assert_eq!(channel.get_mix_matrix()?.row(1), &[0.0, 0.5]);
+    assert!(channel.set_mix_levels_input(&[]).is_err());
+    // levels are per input channel, 5.1 sound is accepted by stereo mixer
+    channel.set_mix_levels_input(&[1.0; 6])?;
+    channel.set_mix_levels_input(&[1.0; ffi::FMOD_MAX_CHANNEL_WIDTH as usize])?;
+    assert!(matches!(
+        channel.set_mix_levels_input(&[1.0; ffi::FMOD_MAX_CHANNEL_WIDTH as usize + 1]),
+        Err(Error::Validation { .. })
+    ));
+    system.release()
+}
+
+#[test]
+fn test_scheduler_converts_durations_at_mixer_rate() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let scheduler = Scheduler::new(system)?;
+    assert_eq!(scheduler.samples(Duration::from_millis(500)), 24000);
+    assert_eq!(scheduler.duration(4800), Duration::from_millis(100));
+    system.release()
+}
+
+#[test]
+fn test_scheduler_plays_at_dsp_clock() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let scheduler = Scheduler::new(system)?;
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::DEFAULT, None)?;
+    let first = scheduler.play_at(sound, None, 2048)?;
+    system.update()?;
+    assert!(!first.get_paused()?);
+    assert_eq!(first.get_position(TimeUnit::PCM)?, 0);
+    assert_eq!(scheduler.end_clock(first)?, 2048 + 24000);
+    first.set_pitch(0.0)?;
+    assert!(scheduler.end_clock(first).is_err());
+    system.release()
+}
+
+#[test]
+fn test_scheduler_queues_and_fades_channel() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let scheduler = Scheduler::new(system)?;
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::DEFAULT, None)?;
+    let first = scheduler.play_at(sound, None, 2048)?;
+    let second = scheduler.play_after(first, sound)?;
+    assert_eq!(second.get_delay()?, (26048, 0, false));
+    scheduler.fade_to(&second, 0.5, Duration::from_secs(1))?;
+    assert_eq!(second.get_fade_points()?, vec![(26048, 1.0), (74048, 0.5)]);
+    scheduler.fade_to(&second, 0.0, Duration::from_secs(1))?;
+    assert_eq!(second.get_fade_points()?, vec![(26048, 1.0), (74048, 0.0)]);
+    system.release()
+}
+
+#[test]
+fn test_scheduler_crossfades_channel_groups() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let scheduler = Scheduler::new(system)?;
+    let (a, b) = (
+        system.create_channel_group(None)?,
+        system.create_channel_group(None)?,
+    );
+    let now = scheduler.clock()?;
+    scheduler.crossfade(&a, &b, Duration::from_millis(100))?;
+    assert_eq!(a.get_fade_points()?, vec![(now, 1.0), (now + 4800, 0.0)]);
+    assert_eq!(b.get_fade_points()?, vec![(now, 0.0), (now + 4800, 1.0)]);
+    assert_eq!(a.get_delay()?, (0, now + 4800, true));
+    system.release()
+}
+
+/// Master head with reverb and echo inputs, echo is also sent to reverb.
+fn build_dsp_graph(system: System) -> Result<Dsp, Error> {
+    let head = system
+        .get_master_channel_group()?
+        .get_dsp(ffi::FMOD_CHANNELCONTROL_DSP_HEAD)?;
+    let reverb = system.create_dsp_by_type(DspType::Sfxreverb)?;
+    let echo = system.create_dsp_by_type(DspType::Echo)?;
+    head.add_input(reverb, DspConnectionType::Standard)?;
+    head.add_input(echo, DspConnectionType::Standard)?;
+    reverb
+        .add_input(echo, DspConnectionType::Send)?
+        .set_mix(0.5)?;
+    reverb.set_bypass(true)?;
+    echo.set_metering_enabled(false, true)?;
+    Ok(head)
+}
+
+#[test]
+fn test_dsp_graph_snapshot() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let head = build_dsp_graph(system)?;
+    let graph = system.dsp_graph()?;
+    let types: Vec<DspType> = graph.nodes.iter().map(|node| node.dsp_type).collect();
+    assert_eq!(
+        types,
+        vec![DspType::Fader, DspType::Sfxreverb, DspType::Echo]
+    );
+    assert_eq!(graph.nodes[1].name, "FMOD Sfxreverb");
+    assert!(graph.nodes[1].bypass);
+    assert!(graph.nodes[2].metering.0.is_none() && graph.nodes[2].metering.1.is_some());
+    let edges: Vec<(usize, usize)> = graph.edges.iter().map(|e| (e.input, e.output)).collect();
+    assert_eq!(edges, vec![(1, 0), (2, 0), (2, 1)]);
+    assert_eq!(graph.edges[2].connection_type, DspConnectionType::Send);
+
+    head.disconnect_all(true, false)?;
+    assert_eq!(system.dsp_graph()?.nodes.len(), 1);
+    system.release()
+}
+
+#[test]
+fn test_dsp_graph_exported_to_dot_and_json() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    build_dsp_graph(system)?;
+    let graph = system.dsp_graph()?;
+    let dot = graph.to_dot();
+    assert!(dot.contains("n1 [label=\"FMOD Sfxreverb\\nSfxreverb\", style=dashed"));
+    assert!(dot.contains("n2 -> n1 [label=\"Send 0.50\"];"));
+    let json = graph.to_json();
+    assert!(json.contains("{\"input\":2,\"output\":1,\"type\":\"Send\",\"mix\":0.5}"));
+    assert!(json.contains("\"metering\":{\"input\":null,\"output\":{\"numsamples\":0"));
+    system.release()
+}
+
+#[test]
+fn test_dsp_chain_attached_to_head() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let group = system.create_channel_group(None)?;
+    let reverb_return = system.create_dsp_by_type(DspType::Return)?;
+    let echo = system.create_dsp_by_type(DspType::Echo)?;
+    let chain = DspChain::new(&system)
+        .then(DspType::Highpass)
+        .float(ffi::FMOD_DSP_HIGHPASS_CUTOFF, 500.0)
+        .then_custom(echo)
+        .send_to(&reverb_return)
+        .then(DspType::Compressor)
+        .parameter(ffi::FMOD_DSP_COMPRESSOR_LINKED, false)
+        .attach(&group, ChannelControlDspIndex::Head)?;
+    let dsps = chain.dsps();
+    let highpass = dsps[0];
+    assert!(
+        !dsps[2]
+            .get_parameter_bool(ffi::FMOD_DSP_COMPRESSOR_LINKED, 0)?
+            .0
+    );
+    assert_eq!(
+        highpass
+            .get_parameter_float(ffi::FMOD_DSP_HIGHPASS_CUTOFF, 0)?
+            .0,
+        500.0
+    );
+    let types: Result<Vec<DspType>, Error> = (0..group.get_num_ds_ps()?)
+        .map(|index| group.get_dsp(index)?.get_type())
+        .collect();
+    assert_eq!(
+        types?,
+        vec![
+            DspType::Compressor,
+            DspType::Echo,
+            DspType::Highpass,
+            DspType::Fader
+        ]
+    );
+    let (input, connection) = reverb_return.get_input(0)?;
+    assert_eq!(input.as_mut_ptr(), echo.as_mut_ptr());
+    assert_eq!(connection.get_type()?, DspConnectionType::Send);
+    system.release()
+}
+
+#[test]
+fn test_dsp_chain_attached_to_tail() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let channel = system.play_sound(
+        system.create_sound("./tests/data/Assets/boop.wav", Mode::DEFAULT, None)?,
+        None,
+        true,
+    )?;
+    let tail = DspChain::new(&system)
+        .then(DspType::Lowpass)
+        .then(DspType::Pan)
+        .attach(&channel, ChannelControlDspIndex::Tail)?;
+    assert_eq!(channel.get_dsp(1)?.get_type()?, DspType::Pan);
+    assert_eq!(channel.get_dsp(2)?.get_type()?, DspType::Lowpass);
+    tail.release()?;
+    assert_eq!(channel.get_num_ds_ps()?, 1);
+    system.release()
+}
+
+#[test]
+fn test_dsp_chain_released_as_one_unit() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let group = system.create_channel_group(None)?;
+    let reverb_return = system.create_dsp_by_type(DspType::Return)?;
+    let echo = system.create_dsp_by_type(DspType::Echo)?;
+    let chain = DspChain::new(&system)
+        .then(DspType::Highpass)
+        .then_custom(echo)
+        .send_to(&reverb_return)
+        .attach(&group, ChannelControlDspIndex::Head)?;
+    let highpass = chain.dsps()[0];
+    chain.release()?;
+    assert_eq!(group.get_num_ds_ps()?, 1);
+    assert_eq!(reverb_return.get_num_inputs()?, 0);
+    assert!(highpass.get_type().is_err());
+    assert_eq!(echo.get_type()?, DspType::Echo);
+    system.release()
+}
+
+const ROOM_OBJ: &str = "
+# exported by level editor
+v 0 0 0
+v 4 0 0
+v 4 3 0
+v 0 3 0
+g wall
+usemtl concrete
+f 1/1/1 2/2/1 3/3/1 4/4/1
+g window
+usemtl window
+f -4 -3 -2
+g door
+usemtl wood
+f 1//1 3//1 4//1
+";
+
+fn room_importer() -> ObjImporter {
+    ObjImporter::new()
+        .material("concrete", MeshMaterial::new(1.0, 0.8))
+        .material("window", MeshMaterial::new(0.3, 0.2))
+        .default_material(MeshMaterial::new(0.5, 0.5))
+}
+
+#[test]
+fn test_geometry_imported_from_obj() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let geometry = room_importer().load(&system, ROOM_OBJ)?;
+    assert_eq!(geometry.get_num_polygons()?, 3);
+    assert_eq!(geometry.get_max_polygons()?, (3, 10));
+    assert_eq!(geometry.get_polygon_num_vertices(0)?, 4);
+    assert_eq!(geometry.get_polygon_attributes(0)?, (1.0, 0.8, true));
+    assert_eq!(geometry.get_polygon_attributes(1)?, (0.3, 0.2, true));
+    assert_eq!(geometry.get_polygon_attributes(2)?, (0.5, 0.5, true));
+    assert_eq!(
+        geometry.get_polygon_vertex(1, 2)?,
+        Vector {
+            x: 4.0,
+            y: 3.0,
+            z: 0.0
+        }
+    );
+    system.release()
+}
+
+#[test]
+fn test_geometry_saved_to_bytes_and_loaded() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let geometry = room_importer().load(&system, ROOM_OBJ)?;
+    let bytes = geometry.to_bytes()?;
+    let loaded = system.load_geometry_bytes(&bytes)?;
+    assert_eq!(loaded.get_max_polygons()?, (3, 10));
+    assert_eq!(
+        loaded.get_polygon_vertex(2, 1)?,
+        Vector {
+            x: 4.0,
+            y: 3.0,
+            z: 0.0
+        }
+    );
+    assert_eq!(loaded.to_bytes()?, bytes);
+    assert!(system.load_geometry_bytes(&bytes[..10]).is_err());
+    system.release()
+}
+
+fn vector(x: f32, y: f32, z: f32) -> Vector {
+    Vector { x, y, z }
+}
+
+#[test]
+fn test_batch_occlusion_by_geometry() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let geometry = system.create_geometry(2, 8)?;
+    let wall = [
+        vector(-1.0, -1.0, 5.0),
+        vector(1.0, -1.0, 5.0),
+        vector(1.0, 1.0, 5.0),
+        vector(-1.0, 1.0, 5.0),
+    ];
+    geometry.add_polygon(0.8, 0.5, true, &wall)?;
+    let one_sided = [
+        vector(2.0, -1.0, 5.0),
+        vector(4.0, -1.0, 5.0),
+        vector(4.0, 1.0, 5.0),
+        vector(2.0, 1.0, 5.0),
+    ];
+    geometry.add_polygon(0.75, 0.25, false, &one_sided)?;
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::DEFAULT, None)?;
+    let channel = system.play_sound(sound, None, false)?;
+
+    let listener = vector(0.0, 0.0, 0.0);
+    let emitters = [
+        Emitter::channel(vector(0.0, 0.0, 8.0), channel),
+        Emitter::new(vector(3.0, 0.0, 8.0)),
+        Emitter::new(vector(-5.0, 0.0, 8.0)),
+    ];
+    let results = OcclusionQuery::new(system)
+        .apply(true)
+        .run(listener, &emitters)?;
+    let wall = Occlusion {
+        direct: 0.8,
+        reverb: 0.5,
+    };
+    assert_eq!(
+        results,
+        vec![wall, Occlusion::default(), Occlusion::default()]
+    );
+    assert_eq!(channel.get_3d_occlusion()?, (0.8, 0.5));
+    assert_eq!(
+        system.get_geometry_occlusion(vector(3.0, 0.0, 8.0), vector(3.0, 0.0, 0.0))?,
+        (0.75, 0.25)
+    );
+    geometry.set_active(false)?;
+    let results = OcclusionQuery::new(system).run(listener, &emitters[..1])?;
+    assert_eq!(results, vec![Occlusion::default()]);
+    system.release()
+}
+
+#[test]
+fn test_occlusion_by_custom_raycaster_applied_to_event_parameter() -> Result<(), Error> {
+    define_bank(
+        "Master.bank",
+        BankDefinition {
+            events: vec![EventDefinition {
+                path: "event:/Ambience/River".to_string(),
+                length: 1000,
+                parameters: vec![ParameterDefinition::new("Occlusion", 0.0, 1.0, 0.0)],
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+    );
+    let studio = Studio::create()?;
+    studio.initialize(32, StudioInit::NORMAL, Init::NORMAL, None)?;
+    studio.load_bank_file("./tests/data/Build/Desktop/Master.bank", LoadBank::NORMAL)?;
+    let instance = studio
+        .get_event("event:/Ambience/River")?
+        .create_instance()?;
+    let listener = vector(0.0, 0.0, 0.0);
+    let physics = |_listener: Vector, emitter: Vector| -> Result<Occlusion, Error> {
+        let direct = if emitter.x > 0.0 { 0.7 } else { 0.0 };
+        Ok(Occlusion {
+            direct,
+            reverb: 0.1,
+        })
+    };
+    let emitters = [Emitter::event_parameter(
+        vector(10.0, 0.0, 0.0),
+        instance,
+        "Occlusion",
+    )];
+    OcclusionQuery::new(physics).run(listener, &emitters)?;
+    assert_eq!(instance.get_parameter_by_name("Occlusion")?.0, 0.0);
+    OcclusionQuery::new(physics)
+        .apply(true)
+        .run(listener, &emitters)?;
+    assert_eq!(instance.get_parameter_by_name("Occlusion")?.0, 0.7);
+    studio.release()
+}
+
+#[test]
+fn test_reverb_zones_added_moved_and_replaced() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let hall = ReverbProperties::concerthall();
+    let mut zones = ReverbZones::new(system)?;
+    let zone = zones.add_preset_zone("hall", "concert_hall", vector(0.0, 0.0, 10.0), 5.0, 20.0)?;
+    assert_eq!(zone.get_properties()?.decay_time, hall.decay_time);
+    zones.set_zone_position("hall", vector(1.0, 0.0, 10.0))?;
+    assert_eq!(
+        zone.get_3d_attributes()?,
+        (vector(1.0, 0.0, 10.0), 5.0, 20.0)
+    );
+    assert!(zones
+        .set_zone_position("cellar", vector(0.0, 0.0, 0.0))
+        .is_err());
+    assert!(zones
+        .add_preset_zone("cave", "basement", vector(0.0, 0.0, 0.0), 1.0, 2.0)
+        .is_err());
+    let replaced = zones.add_zone(
+        "hall",
+        ReverbProperties::cave(),
+        vector(0.0, 0.0, 0.0),
+        1.0,
+        2.0,
+    )?;
+    assert!(zone.get_active().is_err());
+    assert_eq!(
+        zones.zone("hall").map(|zone| zone.as_mut_ptr()),
+        Some(replaced.as_mut_ptr())
+    );
+    zones.release()?;
+    assert!(replaced.get_active().is_err());
+    system.release()
+}
+
+#[test]
+fn test_reverb_zones_ambient_crossfade() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let hall = ReverbProperties::concerthall();
+    let mut zones = ReverbZones::new(system)?;
+    let off = ReverbProperties::off();
+    assert_eq!(zones.ambient().wet_level, off.wet_level);
+    zones.crossfade_ambient_preset("concerthall", Duration::from_secs(2))?;
+    zones.update(Duration::from_secs(1))?;
+    let half = off.lerp(&hall, 0.5);
+    assert!(zones.is_fading());
+    assert_eq!(system.get_reverb_properties(0)?.decay_time, half.decay_time);
+    assert_eq!(zones.ambient().wet_level, half.wet_level);
+    zones.update(Duration::from_secs(5))?;
+    assert!(!zones.is_fading());
+    assert_eq!(system.get_reverb_properties(0)?.wet_level, hall.wet_level);
+    system.release()
+}
+
+#[test]
+fn test_offline_renderer_captures_mix_in_memory() -> Result<(), Error> {
+    let mut renderer = OfflineRenderer::new(44100, SpeakerMode::Quad)?;
+    assert_eq!(renderer.channels(), 4);
+    let system = renderer.system();
+    assert_eq!(system.get_software_format()?, (44100, SpeakerMode::Quad, 0));
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::DEFAULT, None)?;
+    system.play_sound(sound, None, false)?;
+
+    let samples = renderer.render_frames(100)?;
+    assert_eq!(samples.len(), 400);
+    let master = system.get_master_channel_group()?;
+    assert_eq!(master.get_dsp_clock()?.0, 1024);
+    let samples = renderer.render(Duration::from_millis(100))?;
+    assert_eq!(samples.len(), 4410 * 4);
+    assert_eq!(master.get_dsp_clock()?.0, 5 * 1024);
+    // the stub mixes silence
+    assert_eq!(OfflineRenderer::rms(&samples), 0.0);
+    assert!(OfflineRenderer::is_silent(&samples, 0.0001));
+
+    drop(renderer);
+    assert!(system.get_software_format().is_err());
+    Ok(())
+}
+
+struct Recorder {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl Recorder {
+    fn record(&self, event: String) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+impl OutputPlugin for Recorder {
+    const NAME: &'static CStr = c"test recorder";
+
+    fn init(&mut self, sample_rate: i32, speaker_mode: SpeakerMode) -> Result<OutputFormat, Error> {
+        self.record(format!("init {sample_rate} {speaker_mode:?}"));
+        Ok(OutputFormat {
+            sample_rate,
+            speaker_mode: SpeakerMode::Mono,
+        })
+    }
+
+    fn mix(&mut self, buffer: &[f32]) {
+        self.record(format!("mix {}", buffer.len()));
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        self.record("start".into());
+        Ok(())
+    }
+
+    fn stop(&mut self) -> Result<(), Error> {
+        self.record("stop".into());
+        Ok(())
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        self.record("drop".into());
+    }
+}
+
+#[test]
+fn test_output_plugin_receives_mixed_blocks() -> Result<(), Error> {
+    let events = Arc::new(Mutex::new(vec![]));
+    let system = System::create()?;
+    system.set_software_format(Some(48000), Some(SpeakerMode::Stereo), None)?;
+    let recorder = Recorder {
+        events: events.clone(),
+    };
+    let handle = system.init_with_output(32, Init::NORMAL, recorder)?;
+    assert_eq!(system.get_output_by_plugin()?, handle);
+    system.update()?;
+    system.update()?;
+    system.release()?;
+    // plugin asked for mono, so every block has one sample per frame
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            "init 48000 Stereo",
+            "start",
+            "mix 1024",
+            "mix 1024",
+            "stop",
+            "drop"
+        ]
+    );
+    Ok(())
+}
+
+static RUN_LENGTH_CLOSED: AtomicUsize = AtomicUsize::new(0);
+
+/// Mono 16-bit PCM stored as runs: "RUNS", then (count, little endian sample) pairs.
+#[derive(Default)]
+struct RunLengthCodec {
+    samples: Vec<i16>,
+    position: usize,
+}
+
+impl CodecPlugin for RunLengthCodec {
+    const NAME: &'static CStr = c"run length";
+
+    fn open(&mut self, file: &mut CodecFile) -> Result<Vec<CodecWaveformat>, Error> {
+        let mut magic = [0; 4];
+        if file.read(&mut magic)? != 4 || &magic != b"RUNS" {
+            return Err(Error::Fmod {
+                function: "RunLengthCodec::open".to_string(),
+                code: ffi::FMOD_ERR_FORMAT,
+                message: String::new(),
+            });
+        }
+        let mut run = [0; 3];
+        while file.tell()? < file.size()? {
+            file.read_exact(&mut run)?;
+            let sample = i16::from_le_bytes([run[1], run[2]]);
+            let length = self.samples.len() + run[0] as usize;
+            self.samples.resize(length, sample);
+        }
+        Ok(vec![CodecWaveformat {
+            name: "voice".to_string(),
+            format: SoundFormat::Pcm16,
+            channels: 1,
+            frequency: 8000,
+            lengthbytes: file.size()?,
+            lengthpcm: self.samples.len() as u32,
+            pcmblocksize: 0,
+            loopstart: 0,
+            loopend: 0,
+            mode: ffi::FMOD_DEFAULT,
+            channelmask: 0,
+            channelorder: ChannelOrder::Default,
+            peakvolume: 0.0,
+        }])
+    }
+
+    fn read(&mut self, _: &mut CodecFile, buffer: &mut [u8]) -> Result<u32, Error> {
+        let mut count = 0;
+        for (chunk, sample) in buffer
+            .chunks_exact_mut(2)
+            .zip(&self.samples[self.position..])
+        {
+            chunk.copy_from_slice(&sample.to_le_bytes());
+            count += 1;
+        }
+        self.position += count;
+        Ok(count as u32)
+    }
+
+    fn set_position(
+        &mut self,
+        _: &mut CodecFile,
+        _: i32,
+        position: u32,
+        _: u32,
+    ) -> Result<(), Error> {
+        self.position = (position as usize).min(self.samples.len());
+        Ok(())
+    }
+
+    fn get_length(&mut self, _: u32) -> Result<u32, Error> {
+        Ok(self.samples.len() as u32)
+    }
+}
+
+impl Drop for RunLengthCodec {
+    fn drop(&mut self) {
+        if !self.samples.is_empty() {
+            RUN_LENGTH_CLOSED.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+#[test]
+fn test_codec_plugin_decodes_sound() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    assert_eq!(system.register_codec_plugin::<RunLengthCodec>(0)?, 1);
+
+    let data = [b"RUNS".as_slice(), &[3, 0x10, 0x00], &[2, 0xff, 0xff]].concat();
+    let exinfo = CreateSoundexInfo {
+        length: data.len() as u32,
+        ..Default::default()
+    };
+    let sound = system.create_sound_from(&data, Mode::OPENMEMORY, exinfo)?;
+    assert_eq!(
+        sound.get_format()?,
+        (SoundType::User, SoundFormat::Pcm16, 1, 16)
+    );
+    assert_eq!(sound.get_length(TimeUnit::PCM)?, 5);
+    assert_eq!(sound.get_name(32)?, "voice");
+
+    let mut buffer = [0u8; 8];
+    let read = sound.read_data(buffer.as_mut_ptr().cast(), 8)?;
+    assert_eq!(read, 8);
+    assert_eq!(buffer, [0x10, 0, 0x10, 0, 0x10, 0, 0xff, 0xff]);
+    // FMOD reports the end of data even if some bytes are read
+    sound.seek_data(4)?;
+    assert!(matches!(
+        sound.read_data(buffer.as_mut_ptr().cast(), 8),
+        Err(Error::Fmod {
+            code: ffi::FMOD_ERR_FILE_EOF,
+            ..
+        })
+    ));
+
+    // files of other formats are refused by the codec
+    let wav = system.create_sound("./tests/data/Assets/boop.wav", Mode::DEFAULT, None)?;
+    assert_eq!(wav.get_format()?.0, SoundType::Wav);
+
+    sound.release()?;
+    assert_eq!(RUN_LENGTH_CLOSED.load(Ordering::SeqCst), 1);
+    system.release()
+}
+
+#[test]
+fn test_sound_exported_to_wav_file() -> Result<(), Error> {
+    let exported = std::env::temp_dir().join("libfmod_export_boop.wav");
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::OPENONLY, None)?;
+    sound.export_wav(&exported)?;
+    let original = std::fs::read("./tests/data/Assets/boop.wav").unwrap();
+    let file = std::fs::read(&exported).unwrap();
+    // PCM16 data is written as is, the original has extra chunks after data
+    assert_eq!(file.len(), 44 + 88200);
+    assert_eq!(file[8..44], original[8..44]);
+    assert_eq!(file[44..], original[44..44 + 88200]);
+    system.release()
+}
+
+#[test]
+fn test_sound_export_reports_io_error() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let sound = system.create_sound("./tests/data/Assets/boop.wav", Mode::OPENONLY, None)?;
+    let missing = std::env::temp_dir().join("libfmod_missing_dir/boop.wav");
+    let result = sound.export_wav(missing);
+    assert!(
+        matches!(result, Err(Error::Io(error)) if error.kind() == std::io::ErrorKind::NotFound)
+    );
+    system.release()
+}
+
+#[test]
+fn test_wav_writer_output_captures_mix() -> Result<(), Error> {
+    let captured = std::env::temp_dir().join("libfmod_wav_writer.wav");
+    let system = System::create()?;
+    system.set_software_format(Some(48000), Some(SpeakerMode::Mono), None)?;
+    system.init_with_wav_writer(32, Init::NORMAL, OutputType::WavWriterNrt, &captured)?;
+    assert_eq!(system.get_output()?, OutputType::WavWriterNrt);
+    for _ in 0..3 {
+        system.update()?;
+    }
+    system.release()?;
+
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let capture = system.create_sound(captured.to_str().unwrap(), Mode::DEFAULT, None)?;
+    assert_eq!(
+        capture.get_format()?,
+        (SoundType::Wav, SoundFormat::Pcm16, 1, 16)
+    );
+    assert_eq!(capture.get_length(TimeUnit::PCM)?, 3 * 1024);
+    assert_eq!(capture.get_defaults()?.0, 48000.0);
+    system.release()
+}
+
+#[test]
+fn test_wav_writer_output_type_checked() -> Result<(), Error> {
+    let captured = std::env::temp_dir().join("libfmod_wav_writer_unused.wav");
+    let system = System::create()?;
+    let result = system.init_with_wav_writer(32, Init::NORMAL, OutputType::NoSound, &captured);
+    assert!(matches!(
+        result,
+        Err(Error::Validation { function, .. }) if function == "System::init_with_wav_writer"
+    ));
+    system.release()
+}
+
+fn headphones() -> DeviceDefinition {
+    DeviceDefinition {
+        speaker_mode: SpeakerMode::Mono,
+        ..DeviceDefinition::new("Headphones")
+    }
+}
+
+fn define_speakers_headphones_and_microphones(system: &System) {
+    let microphones = vec![
+        DeviceDefinition::new("Microphone"),
+        DeviceDefinition::new("Line In"),
+    ];
+    define_devices(
+        system,
+        vec![DeviceDefinition::new("Speakers"), headphones()],
+        microphones,
+    );
+}
+
+#[test]
+fn test_drivers_enumerated() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    define_speakers_headphones_and_microphones(&system);
+    let drivers = system.drivers()?;
+    let names: Vec<&str> = drivers.iter().map(|driver| driver.name.as_str()).collect();
+    assert_eq!(names, ["Speakers", "Headphones"]);
+    assert_eq!(drivers[1].speaker_mode, SpeakerMode::Mono);
+    assert_eq!(drivers[1].speaker_mode_channels, 1);
+    system.release()
+}
+
+#[test]
+fn test_driver_name_read_into_buffer_of_given_length() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    define_speakers_headphones_and_microphones(&system);
+    assert_eq!(system.get_driver_info(1, 256)?.0, "Headphones");
+    // FMOD truncates name to fit the buffer, including nul terminator
+    assert_eq!(system.get_driver_info(1, 5)?.0, "Head");
+    assert_eq!(system.get_record_driver_info(1, 256)?.0, "Line In");
+    system.release()
+}
+
+#[test]
+fn test_record_drivers_enumerated() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    define_speakers_headphones_and_microphones(&system);
+    let record = system.record_drivers()?;
+    assert_eq!(record[1].driver.name, "Line In");
+    assert_eq!(
+        (record[0].default, record[1].default, record[1].connected),
+        (true, false, true)
+    );
+    system.release()
+}
+
+#[test]
+fn test_followed_driver_selected_again_when_plugged_back() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    define_speakers_headphones_and_microphones(&system);
+    system.follow_driver(system.drivers()?[1].guid.clone())?;
+    assert_eq!(system.get_driver()?, 1);
+    // headphones unplugged, output falls back to the default device
+    let speakers = DeviceDefinition::new("Speakers");
+    define_devices(&system, vec![speakers.clone()], vec![]);
+    system.update()?;
+    assert_eq!(system.get_driver()?, 0);
+    // headphones plugged back after another device appeared
+    let hdmi = DeviceDefinition::new("HDMI");
+    define_devices(&system, vec![speakers, hdmi, headphones()], vec![]);
+    system.update()?;
+    assert_eq!(system.get_driver()?, 2);
+    system.unfollow_driver()?;
+    assert!(system.followed_driver().is_none());
+    system.release()
+}
+
+#[test]
+fn test_driver_set_by_guid() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    define_speakers_headphones_and_microphones(&system);
+    let drivers = system.drivers()?;
+    assert_eq!(system.set_driver_by_guid(&drivers[1].guid)?, 1);
+    assert_eq!(system.get_driver()?, 1);
+    let record = system.record_drivers()?;
+    assert!(system.set_driver_by_guid(&record[0].driver.guid).is_err());
+    system.release()
+}
+
+#[test]
+fn test_followed_driver_forgotten_on_release() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let headphones = DeviceDefinition::new("Headphones");
+    define_devices(
+        &system,
+        vec![DeviceDefinition::new("Speakers"), headphones],
+        vec![],
+    );
+    let preferred = system.drivers()?[1].guid.clone();
+    system.follow_driver(preferred)?;
+    assert!(system.followed_driver().is_some());
+    system.release()?;
+    assert!(system.followed_driver().is_none());
+
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    assert!(system.followed_driver().is_none());
+    assert_eq!(system.get_driver()?, 0);
+    system.release()
+}
+
+/// Stub record device captures sawtooth, frame `n` has value `(n % rate) / rate`.
+fn sawtooth(frame: usize) -> f32 {
+    (frame % 48000) as f32 / 48000.0
+}
+
+#[test]
+fn test_record_stream_reads_captured_samples_in_order() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let mut stream = RecordStream::start(&system, 0, 48000, 2)?;
+    assert!(system.is_recording(0)?);
+
+    // more than one second is captured, so reading wraps around the ring buffer
+    let mut samples = vec![];
+    let mut buffer = vec![0.0; 4096];
+    for _ in 0..60 {
+        system.update()?;
+        let count = stream.read(&mut buffer)?;
+        samples.extend_from_slice(&buffer[..count]);
+    }
+    assert_eq!(samples.len(), 60 * 1024 * 2);
+    for (frame, pair) in samples.chunks_exact(2).enumerate() {
+        assert_eq!(pair, [sawtooth(frame), sawtooth(frame)]);
+    }
+    drop(stream);
+    assert!(!system.is_recording(0)?);
+    system.release()
+}
+
+#[test]
+fn test_record_stream_counts_overruns() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let mut stream = RecordStream::start(&system, 0, 48000, 2)?;
+    let mut buffer = vec![0.0; 4096];
+    for _ in 0..60 {
+        system.update()?;
+        stream.read(&mut buffer)?;
+    }
+    // stream is not read for more than a second
+    for _ in 0..50 {
+        system.update()?;
+    }
+    assert_eq!(stream.read(&mut buffer)?, 0);
+    assert_eq!(stream.overruns(), 1);
+    system.update()?;
+    let tail: Vec<f32> = stream.by_ref().collect();
+    assert_eq!(tail.len(), 1024 * 2);
+    assert_eq!(tail[0], sawtooth(110 * 1024));
+    system.release()
+}
+
+#[test]
+fn test_record_stream_monitored_with_latency() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let mut stream = RecordStream::start(&system, 0, 48000, 2)?;
+    system.update()?;
+    let channel = stream.start_monitor(Duration::from_millis(100))?;
+    let position = system.get_record_position(0)?;
+    assert_eq!(
+        channel.get_position(TimeUnit::PCM)?,
+        (position + 48000 - 4800) % 48000
+    );
+    assert!(stream.start_monitor(Duration::from_secs(1)).is_err());
+    drop(stream);
+    assert!(!system.is_recording(0)?);
+    system.release()
+}
+
+#[test]
+fn test_meter_levels_of_bus() -> Result<(), Error> {
+    define_bank(
+        "Master.strings.bank",
+        BankDefinition {
+            buses: vec!["bus:/SFX".to_string()],
+            ..Default::default()
+        },
+    );
+    let studio = Studio::create()?;
+    studio.initialize(32, StudioInit::NORMAL, Init::NORMAL, None)?;
+    studio.load_bank_file(
+        "./tests/data/Build/Desktop/Master.strings.bank",
+        LoadBank::NORMAL,
+    )?;
+    let bus = studio.get_bus("bus:/SFX")?;
+    let mut meter = Meter::attach(&bus)?;
+    // channel group of bus is created by the next update
+    assert!(meter.levels()?.is_empty());
+    studio.update()?;
+    let levels = meter.levels()?;
+    let speakers: Vec<Speaker> = levels.iter().map(|level| level.speaker).collect();
+    assert_eq!(speakers, [Speaker::FrontLeft, Speaker::FrontRight]);
+    assert_eq!(levels[0].peak, f32::NEG_INFINITY);
+    let head = bus
+        .get_channel_group()?
+        .get_dsp(ffi::FMOD_CHANNELCONTROL_DSP_HEAD)?;
+    assert_eq!(head.get_metering_enabled()?, (false, true));
+    drop(meter);
+    assert!(bus.get_channel_group().is_err());
+    studio.release()
+}
+
+#[test]
+fn test_meter_restores_metering_of_channel_group() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let master = system.get_master_channel_group()?;
+    let tail = master.get_dsp(ffi::FMOD_CHANNELCONTROL_DSP_TAIL)?;
+    tail.set_metering_enabled(true, false)?;
+    let mut meter = Meter::attach_at(&master, ChannelControlDspIndex::Tail)?;
+    assert_eq!(meter.levels()?.len(), 2);
+    assert_eq!(tail.get_metering_enabled()?, (true, true));
+    drop(meter);
+    assert_eq!(tail.get_metering_enabled()?, (true, false));
+    system.release()
+}
+
+#[test]
+fn test_meter_channels_unlabelled_when_format_mismatches_speaker_mode() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let master = system.get_master_channel_group()?;
+    let tail = master.get_dsp(ffi::FMOD_CHANNELCONTROL_DSP_TAIL)?;
+    // upmixed signal doesn't match stereo layout, so channels are not labelled
+    tail.set_channel_format(0u32, 6, SpeakerMode::Mode5Point1)?;
+    let mut meter = Meter::attach_at(&master, ChannelControlDspIndex::Tail)?;
+    let speakers: Vec<Speaker> = meter.levels()?.iter().map(|level| level.speaker).collect();
+    assert_eq!(speakers, [Speaker::None; 6]);
+    drop(meter);
+    system.release()
+}
+
+#[test]
+fn test_loudness_meter_reads_info() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let master = system.get_master_channel_group()?;
+    let dsps = master.get_num_ds_ps()?;
+    let meter = LoudnessMeter::attach(&master)?;
+    assert_eq!(master.get_num_ds_ps()?, dsps + 1);
+    let head = master.get_dsp(ffi::FMOD_CHANNELCONTROL_DSP_HEAD)?;
+    assert_eq!(head.as_mut_ptr(), meter.dsp().as_mut_ptr());
+    system.update()?;
+    let info = meter.read()?;
+    assert_eq!(info.integrated, f32::NEG_INFINITY);
+    assert_eq!(info.max_true_peak, f32::NEG_INFINITY);
+    assert_eq!(info.histogram.len(), 66);
+    meter.pause()?;
+    meter.resume()?;
+    meter.reset()?;
+    drop(meter);
+    assert_eq!(master.get_num_ds_ps()?, dsps);
+    system.release()
+}
+
+#[test]
+fn test_loudness_meter_weighting() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let meter = LoudnessMeter::attach(&system.get_master_channel_group()?)?;
+    assert_eq!(meter.weighting()?, [1.0; 32]);
+    meter.set_weighting(&[1.0, 1.0, 1.0, 0.0, 1.41, 1.41])?;
+    let weighting = meter.weighting()?;
+    assert_eq!(weighting[3..6], [0.0, 1.41, 1.41]);
+    assert_eq!(weighting[6], 1.0);
+    assert!(meter.set_weighting(&[1.0; 33]).is_err());
+    system.release()
+}
+
+#[test]
+fn test_fft_analyzer_window_settings() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let analyzer = FftAnalyzer::attach(&system.get_master_channel_group()?)?;
+    assert_eq!(analyzer.window_size()?, 2048);
+    assert_eq!(analyzer.window()?, DspFftWindow::Hamming);
+    analyzer.set_window(DspFftWindow::BlackManHarris)?;
+    analyzer.set_window_size(1024)?;
+    assert!(analyzer.set_window_size(1000).is_err());
+    assert_eq!(analyzer.window()?, DspFftWindow::BlackManHarris);
+    assert_eq!(analyzer.window_size()?, 1024);
+    system.release()
+}
+
+#[test]
+fn test_fft_analyzer_spectrum_of_silence() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let master = system.get_master_channel_group()?;
+    let dsps = master.get_num_ds_ps()?;
+    let analyzer = FftAnalyzer::attach(&master)?;
+    analyzer.set_window_size(1024)?;
+    system.update()?;
+    let spectrum = analyzer.spectrum()?;
+    assert_eq!((spectrum.rate, spectrum.window_size), (48000, 1024));
+    assert_eq!(spectrum.channels.len(), 2);
+    assert_eq!(spectrum.bins(), 512);
+    assert_eq!(spectrum.dominant_frequency(), None);
+    assert_eq!(analyzer.dominant_frequency()?, 0.0);
+    drop(analyzer);
+    assert_eq!(master.get_num_ds_ps()?, dsps);
+    system.release()
+}
+
+#[test]
+fn test_dsp_data_parameters_of_pan_typed_by_declared_type() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let pan = system.create_dsp_by_type(DspType::Pan)?;
+    let position = Vector::new(1.0, 2.0, 3.0);
+    let attributes = Attributes3d {
+        position,
+        velocity: Vector::new(0.0, 0.0, 0.0),
+        forward: Vector::new(0.0, 0.0, 1.0),
+        up: Vector::new(0.0, 1.0, 0.0),
+    };
+    pan.set_data_parameter(
+        ffi::FMOD_DSP_PAN_3D_POSITION,
+        DspData::Attributes3d(DspParameterAttributes3d {
+            relative: attributes.clone(),
+            absolute: attributes,
+        }),
+    )?;
+    match pan.get_data_parameter(ffi::FMOD_DSP_PAN_3D_POSITION)? {
+        DspData::Attributes3d(value) => assert_eq!(value.relative.position, position),
+        value => panic!("unexpected {value:?}"),
+    }
+    match pan.get_data_parameter(ffi::FMOD_DSP_PAN_OVERALL_GAIN)? {
+        DspData::OverallGain(gain) => assert_eq!(gain.linear_gain, 1.0),
+        value => panic!("unexpected {value:?}"),
+    }
+    let range = DspParameterAttenuationRange {
+        min: 2.0,
+        max: 50.0,
+    };
+    pan.set_data_parameter(
+        ffi::FMOD_DSP_PAN_ATTENUATION_RANGE,
+        DspData::AttenuationRange(range),
+    )?;
+    match pan.get_data_parameter(ffi::FMOD_DSP_PAN_ATTENUATION_RANGE)? {
+        DspData::AttenuationRange(value) => assert_eq!((value.min, value.max), (2.0, 50.0)),
+        value => panic!("unexpected {value:?}"),
+    }
+    let sidechain = DspData::Sidechain(DspParameterSidechain { sidechainenable: 1 });
+    assert!(pan
+        .set_data_parameter(ffi::FMOD_DSP_PAN_OVERALL_GAIN, sidechain.clone())
+        .is_err());
+    assert!(pan.get_data_parameter(ffi::FMOD_DSP_PAN_MODE).is_err());
+    system.release()
+}
+
+#[test]
+fn test_dsp_data_parameter_of_compressor_sidechain() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let compressor = system.create_dsp_by_type(DspType::Compressor)?;
+    let sidechain = DspData::Sidechain(DspParameterSidechain { sidechainenable: 1 });
+    compressor.set_data_parameter(ffi::FMOD_DSP_COMPRESSOR_USESIDECHAIN, sidechain)?;
+    match compressor.get_data_parameter(ffi::FMOD_DSP_COMPRESSOR_USESIDECHAIN)? {
+        DspData::Sidechain(value) => assert_eq!(value.sidechainenable, 1),
+        value => panic!("unexpected {value:?}"),
+    }
+    system.release()
+}
+
+#[test]
+fn test_dsp_data_parameters_read_only() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let object_pan = system.create_dsp_by_type(DspType::Objectpan)?;
+    match object_pan.get_data_parameter(ffi::FMOD_DSP_OBJECTPAN_3D_POSITION)? {
+        DspData::Attributes3dMulti(value) => assert_eq!(value.numlisteners, 1),
+        value => panic!("unexpected {value:?}"),
+    }
+
+    let fft = system.create_dsp_by_type(DspType::Fft)?;
+    let spectrum = fft.get_data_parameter(ffi::FMOD_DSP_FFT_SPECTRUMDATA)?;
+    assert_eq!(spectrum.data_type(), DspParameterDataType::Fft);
+    match &spectrum {
+        DspData::Fft(value) => assert_eq!((value.length, value.spectrum.len()), (1024, 2)),
+        value => panic!("unexpected {value:?}"),
+    }
+    assert!(fft
+        .set_data_parameter(ffi::FMOD_DSP_FFT_SPECTRUMDATA, spectrum)
+        .is_err());
+
+    let meter = system.create_dsp_by_type(DspType::LoudnessMeter)?;
+    match meter.get_data_parameter(ffi::FMOD_DSP_LOUDNESS_METER_INFO)? {
+        DspData::User(bytes) => assert_eq!(
+            bytes.len(),
+            std::mem::size_of::<ffi::FMOD_DSP_LOUDNESS_METER_INFO_TYPE>()
+        ),
+        value => panic!("unexpected {value:?}"),
+    }
+    system.release()
+}
+
+#[test]
+fn test_dsp_parameters_described_with_values() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let equalizer = system.create_dsp_by_type(DspType::ThreeEq)?;
+    let parameters = equalizer.parameters()?;
+    let names: Vec<&str> = parameters
+        .iter()
+        .map(|parameter| parameter.name.as_str())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "Low Gain",
+            "Mid Gain",
+            "High Gain",
+            "Low Crossover",
+            "High Crossover",
+            "Crossover Slope"
+        ]
+    );
+    let low_gain = &parameters[0];
+    assert_eq!(low_gain.label, "dB");
+    assert!(matches!(
+        low_gain.kind,
+        DspParameterKind::Float {
+            min: -80.0,
+            max: 10.0,
+            default: 0.0,
+            ..
+        }
+    ));
+    let slope = &parameters[5];
+    match &slope.kind {
+        DspParameterKind::Int { value_names, .. } => assert_eq!(value_names.len(), 3),
+        kind => panic!("unexpected {kind:?}"),
+    }
+    assert!(matches!(slope.value, Some(DspParameterValue::Int(1))));
+    assert_eq!(slope.value_name(), Some("24dB"));
+    assert_eq!(slope.value_string, "24dB");
+    system.release()
+}
+
+#[test]
+fn test_dsp_parameters_set_by_name() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let equalizer = system.create_dsp_by_type(DspType::ThreeEq)?;
+    equalizer.set_by_name("Low Gain", -6.0)?;
+    equalizer.set_by_name("Crossover Slope", 2)?;
+    let low_gain = equalizer.parameter(0)?;
+    assert!(matches!(low_gain.value, Some(DspParameterValue::Float(value)) if value == -6.0));
+    assert_eq!(low_gain.value_string, "-6.00");
+    let length = ffi::FMOD_DSP_GETPARAM_VALUESTR_LENGTH as i32;
+    assert_eq!(
+        equalizer.get_parameter_float(0, length)?,
+        (-6.0, "-6.00".to_string())
+    );
+    assert_eq!(equalizer.get_parameter_float(0, 3)?.1, "-6");
+    assert_eq!(equalizer.get_parameter_int(5, length)?.1, "48dB");
+    assert_eq!(equalizer.parameter(5)?.value_name(), Some("48dB"));
+    assert!(equalizer.set_by_name("Low Gain", 2).is_err());
+    assert!(equalizer.set_by_name("Missing", 1.0).is_err());
+    system.release()
+}
+
+#[test]
+fn test_dsp_parameters_with_bool_and_data_values() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let compressor = system.create_dsp_by_type(DspType::Compressor)?;
+    let linked = compressor.parameter(compressor.parameter_index("Linked")?)?;
+    assert!(matches!(
+        linked.kind,
+        DspParameterKind::Bool { default: true, .. }
+    ));
+    compressor.set_by_name("Linked", false)?;
+    let sidechain = DspData::Sidechain(DspParameterSidechain { sidechainenable: 1 });
+    compressor.set_by_name("Use Sidechain", sidechain)?;
+    for parameter in compressor.parameters()? {
+        match (parameter.name.as_str(), parameter.kind, parameter.value) {
+            ("Linked", _, Some(DspParameterValue::Bool(value))) => assert!(!value),
+            (
+                "Use Sidechain",
+                DspParameterKind::Data { data_type },
+                Some(DspParameterValue::Data(DspData::Sidechain(value))),
+            ) => {
+                assert_eq!(data_type, DspParameterDataType::Sidechain);
+                assert_eq!(value.sidechainenable, 1);
+            }
+            (_, _, Some(DspParameterValue::Float(_))) => {}
+            (name, kind, value) => panic!("unexpected {name} {kind:?} {value:?}"),
+        }
+    }
+    system.release()
+}
+
+#[test]
+fn test_missing_file() -> Result<(), Error> {
+    let system = System::create()?;
+    system.init(32, Init::NORMAL, None)?;
+    let result = system.create_sound("./tests/data/Assets/missing.wav", Mode::DEFAULT, None);
+    assert!(matches!(
+        result,
+        Err(Error::Fmod {
+            code: ffi::FMOD_ERR_FILE_NOTFOUND,
+            ..
+        })
+    ));
+    system.release()
+}
+
+#[test]
+fn test_studio_event_parameters_and_state() -> Result<(), Error> {
+    define_bank(
+        "SFX.bank",
+        BankDefinition {
+            events: vec![EventDefinition {
+                path: "event:/events/1".to_string(),
+                length: 100,
+                is_oneshot: true,
+                parameters: vec![ParameterDefinition::new("Intensity", 0.0, 1.0, 0.25)],
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+    );
+    let studio = Studio::create()?;
+    studio.initialize(32, StudioInit::NORMAL, Init::NORMAL, None)?;
+    let bank = studio.load_bank_file("./tests/data/Build/Desktop/SFX.bank", LoadBank::NORMAL)?;
+    assert!(matches!(
+        studio.load_bank_file("./tests/data/Build/Desktop/SFX.bank", LoadBank::NORMAL),
+        Err(Error::Fmod {
+            code: ffi::FMOD_ERR_EVENT_ALREADY_LOADED,
+            ..
+        })
+    ));
+    assert_eq!(bank.get_path()?, "bank:/SFX");
+    assert!(matches!(
+        studio.get_event("event:/events/missing"),
+        Err(Error::Fmod {
+            code: ffi::FMOD_ERR_EVENT_NOTFOUND,
+            ..
+        })
+    ));
+
+    let event = studio.get_event("event:/events/1")?;
+    let instance = event.create_instance()?;
+    assert_eq!(instance.get_parameter_by_name("Intensity")?.0, 0.25);
+    instance.set_parameter_by_name("Intensity", 5.0, false)?;
+    assert_eq!(instance.get_parameter_by_name("Intensity")?.0, 1.0);
+
+    instance.start()?;
+    assert_eq!(instance.get_playback_state()?, PlaybackState::Starting);
+    studio.update()?;
+    assert_eq!(instance.get_playback_state()?, PlaybackState::Playing);
+    for _ in 0..5 {
+        studio.update()?;
+    }
+    assert_eq!(instance.get_playback_state()?, PlaybackState::Stopped);
+
+    instance.start()?;
+    studio.update()?;
+    instance.stop(StopMode::AllowFadeout)?;
+    assert_eq!(instance.get_playback_state()?, PlaybackState::Stopping);
+    instance.release()?;
+    studio.update()?;
+    assert!(!instance.is_valid());
+    studio.release()
+}